@@ -45,6 +45,7 @@ void cforge_print_verbose(cforge_cstring_t message);
 #include <fmt/core.h>
 
 #include <string>
+#include <utility>
 #include <vector>
 
 namespace cforge {
@@ -59,6 +60,25 @@ enum class log_verbosity {
   VERBOSITY_VERBOSE /**< Detailed output for debugging */
 };
 
+/**
+ * @enum output_format
+ * @brief Output format for all logger output
+ */
+enum class output_format {
+  HUMAN, /**< Colored, Cargo-style text (default) */
+  JSON   /**< Newline-delimited JSON events for machine consumption */
+};
+
+/**
+ * @enum color_mode
+ * @brief When to emit ANSI colors (`--color auto|always|never`)
+ */
+enum class color_mode {
+  AUTO,   /**< Color if the stream is a terminal and NO_COLOR is unset (default) */
+  ALWAYS, /**< Always color, even when piped */
+  NEVER   /**< Never color */
+};
+
 /**
  * @class logger
  * @brief Static class providing Cargo-style logging functionality
@@ -84,6 +104,85 @@ public:
    */
   static log_verbosity get_verbosity();
 
+  /**
+   * @brief Sets the global output format
+   * @param format The output format to use
+   */
+  static void set_output_format(output_format format);
+
+  /**
+   * @brief Gets the current output format
+   * @return The current output format
+   */
+  static output_format get_output_format();
+
+  /**
+   * @brief Check whether JSON output mode is active
+   */
+  static bool is_json() { return s_format == output_format::JSON; }
+
+  /**
+   * @brief Sets when colors are emitted
+   * @param mode The color mode to use
+   */
+  static void set_color_mode(color_mode mode);
+
+  /**
+   * @brief Gets the current color mode
+   * @return The current color mode
+   */
+  static color_mode get_color_mode();
+
+  /**
+   * @brief Check whether colored output should be written to a stream
+   *
+   * In AUTO mode this is true only if the stream is a terminal and the
+   * NO_COLOR environment variable is unset or empty.
+   *
+   * @param stream stdout or stderr
+   */
+  static bool use_color(FILE *stream = stdout);
+
+  /**
+   * @brief Check whether in-place animations (progress bars, timers) can be drawn
+   *
+   * False when stderr is not a terminal (CI, `| tee`), TERM is "dumb", or the
+   * output format is JSON. Non-interactive output uses permanent lines only.
+   */
+  static bool is_interactive();
+
+  /**
+   * @brief Resolve a text style against the color mode
+   *
+   * Returns the style unchanged if colors are enabled for the stream and an
+   * empty style otherwise, so `fmt::print(logger::style(fg(...)), ...)`
+   * prints plain text when colors are off.
+   *
+   * @param style Style to apply
+   * @param stream Stream the styled text will be written to
+   */
+  static fmt::text_style style(fmt::text_style style, FILE *stream = stdout);
+
+  // Machine-readable output
+
+  /**
+   * @brief Quote and escape a string as a JSON string literal
+   *
+   * Defined inline below, so header-only generators can use it too.
+   */
+  static std::string json_quote(const std::string &value);
+
+  /**
+   * @brief Print a single JSON event line to stdout
+   *
+   * Format: {"event":"<event>","<key>":<value>,...}
+   *
+   * @param event Event name (e.g. "status", "build_step", "diagnostic")
+   * @param fields Key/value pairs; values must already be valid JSON
+   */
+  static void print_json_event(const std::string &event,
+                               const std::vector<std::pair<std::string, std::string>> &fields);
+
   // CARGO-style status messages (right-aligned status word)
 
   /**
@@ -150,6 +249,17 @@ public:
    */
   static void running_timer(const std::string &command, cforge_double_t elapsed_secs);
 
+  /**
+   * @brief End a running_timer display
+   *
+   * Interactive terminals get the timer line cleared; otherwise a permanent
+   * "Running {command}... done ({time})" line is printed instead.
+   *
+   * @param elapsed_secs Total elapsed time in seconds
+   * @param success Whether the command succeeded ("failed" instead of "done")
+   */
+  static void stop_timer(cforge_double_t elapsed_secs, bool success = true);
+
   /**
    * @brief Print "Finished {config} target(s) in {time}"
    */
@@ -247,7 +357,8 @@ public:
    * @brief Display a progress bar
    * @param current Current step (1-based)
    * @param total Total steps
-   * @param in_place If true, update the line in place (use carriage return)
+   * @param in_place If true, update the line in place (use carriage return);
+   *                 skipped when the terminal is not interactive
    * @param elapsed_secs Optional elapsed time in seconds to display
    * @param action Right-aligned status word shown beside the bar
    *               (default "Building"; pass "Formatting", "Linting", etc.)
@@ -259,7 +370,7 @@ public:
                            const std::string &action = "Building");
 
   /**
-   * @brief Clear the current terminal line
+   * @brief Clear the current terminal line (no-op when not interactive)
    */
   static void clear_line();
 
@@ -591,6 +702,8 @@ public:
 
 private:
   static log_verbosity s_verbosity;
+  static output_format s_format;
+  static color_mode s_color_mode;
 
   // Status width for right-alignment (CARGO uses 12)
   static constexpr cforge_int_t STATUS_WIDTH = 12;
@@ -605,6 +718,40 @@ private:
                                 FILE *stream = stdout);
 };
 
+inline std::string logger::json_quote(const std::string &value) {
+  std::string out;
+  out.reserve(value.size() + 2);
+  out += '"';
+  for (unsigned char c : value) {
+    switch (c) {
+      case '"':
+        out += "\\\"";
+        break;
+      case '\\':
+        out += "\\\\";
+        break;
+      case '\n':
+        out += "\\n";
+        break;
+      case '\r':
+        out += "\\r";
+        break;
+      case '\t':
+        out += "\\t";
+        break;
+      default:
+        if (c < 0x20) {
+          out += fmt::format("\\u{:04x}", c);
+        } else {
+          out += static_cast<char>(c);
+        }
+        break;
+    }
+  }
+  out += '"';
+  return out;
+}
+
 }  // namespace cforge
 #endif
 
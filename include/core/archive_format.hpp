@@ -0,0 +1,189 @@
+/**
+ * @file archive_format.hpp
+ * @brief Archive format detection for [dependencies.archive] entries
+ *
+ * Archives are extracted by CMake (FetchContent), which picks the extractor
+ * from the file name. Detection therefore works on the full extension so a
+ * `.tar.xz` is never mistaken for a bare `.xz` stream. URLs without an
+ * extension are recognized by the magic bytes of the downloaded file.
+ */
+
+#pragma once
+
+#include "core/types.h"
+
+#include <algorithm>
+#include <cctype>
+#include <cstdio>
+#include <string>
+#include <utility>
+#include <vector>
+
+namespace cforge {
+
+/**
+ * @brief Detect the archive format of a URL or file name
+ *
+ * Query strings and fragments are ignored, and the check is
+ * case-insensitive. Recognized formats are `zip`, `7z`, `tar`, `tar.gz`
+ * (.tgz), `tar.bz2` (.tbz2) and `tar.xz` (.txz).
+ *
+ * @param name URL or file name
+ * @return Canonical format name, or an empty string if unrecognized
+ */
+inline std::string detect_archive_format(const std::string &name) {
+  static const std::vector<std::pair<std::string, std::string>> suffixes = {
+      {".tar.gz",  "tar.gz" },
+      {".tgz",     "tar.gz" },
+      {".tar.bz2", "tar.bz2"},
+      {".tbz2",    "tar.bz2"},
+      {".tar.xz",  "tar.xz" },
+      {".txz",     "tar.xz" },
+      {".tar",     "tar"    },
+      {".zip",     "zip"    },
+      {".7z",      "7z"     },
+  };
+
+  std::string path = name.substr(0, name.find_first_of("?#"));
+  std::transform(path.begin(), path.end(), path.begin(), [](unsigned char c) {
+    return static_cast<char>(std::tolower(c));
+  });
+  for (const auto &[suffix, format] : suffixes) {
+    if (path.size() > suffix.size()
+        && path.compare(path.size() - suffix.size(), suffix.size(), suffix) == 0) {
+      return format;
+    }
+  }
+  return "";
+}
+
+/**
+ * @brief Normalize a user-supplied `format` value
+ *
+ * Accepts the canonical names with or without a leading dot, plus the short
+ * aliases (`tgz`, `tbz2`, `txz`).
+ *
+ * @param format Format from cforge.toml
+ * @return Canonical format name, or an empty string if unsupported
+ */
+inline std::string normalize_archive_format(const std::string &format) {
+  std::string value = format;
+  if (!value.empty() && value.front() == '.') {
+    value.erase(0, 1);
+  }
+  return detect_archive_format("archive." + value);
+}
+
+/**
+ * @brief Magic bytes that identify an archive format
+ */
+struct archive_signature {
+  cforge_size_t offset;  ///< Position of the magic bytes in the file
+  std::string magic;     ///< Bytes to match
+  std::string format;    ///< Canonical format name
+};
+
+/**
+ * @brief Signatures of the supported archive formats
+ *
+ * Compressed streams are assumed to hold a tarball, which is how source
+ * releases are published.
+ */
+inline const std::vector<archive_signature> &archive_signatures() {
+  static const std::vector<archive_signature> signatures = {
+      {0,   std::string("PK\x03\x04", 4),               "zip"    },
+      {0,   std::string("7z\xBC\xAF\x27\x1C", 6),       "7z"     },
+      {0,   std::string("\x1F\x8B", 2),                 "tar.gz" },
+      {0,   std::string("BZh", 3),                      "tar.bz2"},
+      {0,   std::string("\xFD" "7zXZ\x00", 6),          "tar.xz" },
+      {257, std::string("ustar", 5),                    "tar"    },
+  };
+  return signatures;
+}
+
+/**
+ * @brief Detect the archive format from the start of a file
+ *
+ * @param header At least the first 262 bytes of the file (fewer for short files)
+ * @return Canonical format name, or an empty string if the data is not a
+ *         supported archive (for example an HTML error page)
+ */
+inline std::string detect_archive_format_from_header(const std::string &header) {
+  for (const auto &sig : archive_signatures()) {
+    if (header.size() >= sig.offset + sig.magic.size()
+        && header.compare(sig.offset, sig.magic.size(), sig.magic) == 0) {
+      return sig.format;
+    }
+  }
+  return "";
+}
+
+/**
+ * @brief Generate CMake code that downloads an archive and detects its format
+ *
+ * For URLs without an archive extension. The file is downloaded once to
+ * `_deps/<name>-download`, its magic bytes are compared with
+ * archive_signatures(), and a copy named `<name>.<format>` is stored in
+ * `CFORGE_ARCHIVE_<name>` for FetchContent_Declare(URL ...). With a sha256
+ * the download is checked before it is kept. Nothing is downloaded when
+ * FETCHCONTENT_FULLY_DISCONNECTED is set.
+ *
+ * @param name Dependency name
+ * @param url Download URL
+ * @param sha256 Expected SHA-256 (lower-case hex), or empty
+ * @return CMake code
+ */
+inline std::string generate_archive_sniff_cmake(const std::string &name,
+                                                const std::string &url,
+                                                const std::string &sha256) {
+  auto hex = [](const std::string &bytes) {
+    std::string out;
+    char buf[3];
+    for (unsigned char c : bytes) {
+      std::snprintf(buf, sizeof(buf), "%02x", c);
+      out += buf;
+    }
+    return out;
+  };
+
+  std::string dir  = "${CMAKE_BINARY_DIR}/_deps/" + name + "-download";
+  std::string file = dir + "/" + name + ".download";
+  std::string var  = "CFORGE_ARCHIVE_" + name;
+  std::string out;
+  out += "# " + name + ": archive format is detected from the downloaded file\n";
+  out += "if(NOT FETCHCONTENT_FULLY_DISCONNECTED AND NOT EXISTS \"" + file + "\")\n";
+  out += "    file(DOWNLOAD \"" + url + "\" \"" + file + "\"\n";
+  if (!sha256.empty()) {
+    out += "        EXPECTED_HASH SHA256=" + sha256 + "\n";
+  }
+  out += "        STATUS _cforge_status)\n";
+  out += "    list(GET _cforge_status 0 _cforge_code)\n";
+  out += "    if(NOT _cforge_code EQUAL 0)\n";
+  out += "        file(REMOVE \"" + file + "\")\n";
+  out += "        message(FATAL_ERROR \"archive dependency '" + name
+         + "': download failed: ${_cforge_status}\")\n";
+  out += "    endif()\n";
+  out += "endif()\n";
+  out += "set(_cforge_format \"\")\n";
+  out += "if(EXISTS \"" + file + "\")\n";
+  for (const auto &sig : archive_signatures()) {
+    out += "    if(NOT _cforge_format)\n";
+    out += "        file(READ \"" + file + "\" _cforge_magic OFFSET " + std::to_string(sig.offset)
+           + " LIMIT " + std::to_string(sig.magic.size()) + " HEX)\n";
+    out += "        if(_cforge_magic STREQUAL \"" + hex(sig.magic) + "\")\n";
+    out += "            set(_cforge_format \"" + sig.format + "\")\n";
+    out += "        endif()\n";
+    out += "    endif()\n";
+  }
+  out += "    if(NOT _cforge_format)\n";
+  out += "        message(FATAL_ERROR \"archive dependency '" + name
+         + "': the download is not a zip, 7z or tar archive\")\n";
+  out += "    endif()\n";
+  out += "    configure_file(\"" + file + "\" \"" + dir + "/" + name
+         + ".${_cforge_format}\" COPYONLY)\n";
+  out += "endif()\n";
+  out += "set(" + var + " \"" + dir + "/" + name + ".${_cforge_format}\")\n";
+  return out;
+}
+
+}  // namespace cforge
@@ -71,6 +71,8 @@ struct benchmark_target {
   benchmark_framework framework = benchmark_framework::Auto;
   std::vector<std::string> dependencies;  // Link dependencies
   std::vector<std::string> defines;
+  std::map<std::string, std::vector<std::string>> platform_defines;  // By OS name
+  std::map<std::string, std::vector<std::string>> platform_flags;    // By OS name
   std::vector<std::string> includes;
   bool enabled = true;
 
@@ -80,13 +82,15 @@ struct benchmark_target {
 };
 
 /**
- * @brief Global benchmark configuration (from [benchmark] section)
+ * @brief Global benchmark configuration (from the [benchmark] or [benchmarks] section)
  */
 struct benchmark_config {
   std::filesystem::path directory{"bench"};
   benchmark_framework default_framework = benchmark_framework::Auto;
   std::string default_build_type        = "Release";
   bool auto_link_project                = true;
+  std::vector<std::string> sources;  // Source globs for a single "benchmarks" target
+  std::vector<std::string> links;    // Extra link targets for every benchmark
 
   // Framework-specific settings
   struct FrameworkConfig {
@@ -16,6 +16,25 @@
 
 namespace cforge {
 
+/**
+ * @brief Table of cforge.toml that holds the benchmark settings
+ *
+ * [benchmarks] and the shorter [bench] are accepted when there is no
+ * [benchmark] table.
+ *
+ * @param config Project configuration
+ * @return "benchmark", "benchmarks" or "bench"
+ */
+inline std::string benchmark_config_section(const toml_reader &config) {
+  if (config.has_key("benchmark")) {
+    return "benchmark";
+  }
+  if (config.has_key("benchmarks")) {
+    return "benchmarks";
+  }
+  return config.has_key("bench") ? "bench" : "benchmark";
+}
+
 /**
  * @brief Benchmark execution options
  */
@@ -27,6 +46,7 @@ struct benchmark_run_options {
   bool csv_output  = false;
   bool verbose     = false;
   cforge_int_t repetitions  = 1;
+  std::vector<std::string> extra_args;  // Passed to every benchmark binary (after `--`)
 };
 
 /**
@@ -97,6 +117,7 @@ private:
   std::filesystem::path m_project_dir;
   std::filesystem::path m_build_base_dir;  // resolved from build.directory
   const toml_reader &m_project_config;
+  std::string m_section{"benchmark"};  // see benchmark_config_section()
   benchmark_config m_bench_config;
   std::vector<benchmark_result> m_results;
   std::string m_error;
@@ -0,0 +1,257 @@
+/**
+ * @file build_config.hpp
+ * @brief Build configuration names and per-configuration definitions
+ */
+
+#pragma once
+
+#include "core/types.h"
+
+#include <algorithm>
+#include <cctype>
+#include <map>
+#include <sstream>
+#include <string>
+#include <vector>
+
+namespace cforge {
+
+/**
+ * @brief Spell a build configuration the way CMake does
+ *
+ * Config keys in cforge.toml are lower-case ([build.config.relwithdebinfo])
+ * and `-c` accepts any case, but `if(CMAKE_BUILD_TYPE STREQUAL ...)` and
+ * CMake's own per-config settings expect "Debug", "Release",
+ * "RelWithDebInfo" and "MinSizeRel". Custom configurations are returned
+ * unchanged.
+ *
+ * @param config Configuration name in any case
+ * @return CMake spelling of the configuration
+ */
+inline std::string canonical_build_config(const std::string &config) {
+  std::string lower = config;
+  std::transform(lower.begin(), lower.end(), lower.begin(), [](unsigned char c) {
+    return static_cast<char>(std::tolower(c));
+  });
+  if (lower == "debug") {
+    return "Debug";
+  }
+  if (lower == "release") {
+    return "Release";
+  }
+  if (lower == "relwithdebinfo") {
+    return "RelWithDebInfo";
+  }
+  if (lower == "minsizerel") {
+    return "MinSizeRel";
+  }
+  return config;
+}
+
+/**
+ * @brief Check for one of CMake's four built-in build types
+ *
+ * @param config Configuration name in any case
+ * @return true for Debug, Release, RelWithDebInfo and MinSizeRel
+ */
+inline bool is_standard_cmake_build_type(const std::string &config) {
+  std::string cmake_config = canonical_build_config(config);
+  return cmake_config == "Debug" || cmake_config == "Release" || cmake_config == "RelWithDebInfo"
+         || cmake_config == "MinSizeRel";
+}
+
+/**
+ * @brief Generator expressions matching the configurations built on Debug
+ *
+ * @param base_by_config Configuration key (as in cforge.toml) to built-in type
+ * @return e.g. "$<CONFIG:Debug>,$<CONFIG:checked>"
+ */
+inline std::string
+debug_config_expressions(const std::map<std::string, std::string> &base_by_config) {
+  std::string debug_configs = "$<CONFIG:Debug>";
+  for (const auto &[config, base] : base_by_config) {
+    if (canonical_build_config(base) == "Debug") {
+      debug_configs += ",$<CONFIG:" + canonical_build_config(config) + ">";
+    }
+  }
+  return debug_configs;
+}
+
+/**
+ * @brief Generate the CMake code that layers custom configurations on a built-in type
+ *
+ * A configuration such as [build.config.profiling] with
+ * `cmake_build_type = "RelWithDebInfo"` keeps its own name as the CMake
+ * configuration, so `$<CONFIG:profiling>` still selects its defines and
+ * sources, but starts from the compiler and linker flags of the built-in
+ * type. Imported targets use their configuration of that type, and
+ * multi-config generators get the configuration added to
+ * CMAKE_CONFIGURATION_TYPES.
+ *
+ * @param base_by_config Configuration key (as in cforge.toml) to built-in type
+ * @return CMake code, empty if no configuration declares a type
+ */
+inline std::string
+generate_config_build_type_cmake(const std::map<std::string, std::string> &base_by_config) {
+  std::ostringstream cmake;
+  std::string debug_configs = debug_config_expressions(base_by_config);
+  for (const auto &[config, base] : base_by_config) {
+    std::string cmake_config = canonical_build_config(config);
+    std::string cmake_base   = canonical_build_config(base);
+    std::string config_upper = cmake_config;
+    std::string base_upper   = cmake_base;
+    for (std::string *name : {&config_upper, &base_upper}) {
+      std::transform(name->begin(), name->end(), name->begin(), [](unsigned char c) {
+        return static_cast<char>(std::toupper(c));
+      });
+    }
+
+    cmake << "# Config '" << cmake_config << "' builds on " << cmake_base << "\n";
+    for (const std::string lang : {"C", "CXX"}) {
+      cmake << "set(CMAKE_" << lang << "_FLAGS_" << config_upper << " \"${CMAKE_" << lang
+            << "_FLAGS_" << base_upper << "}\")\n";
+    }
+    for (const std::string kind : {"EXE", "SHARED", "MODULE", "STATIC"}) {
+      cmake << "set(CMAKE_" << kind << "_LINKER_FLAGS_" << config_upper << " \"${CMAKE_" << kind
+            << "_LINKER_FLAGS_" << base_upper << "}\")\n";
+    }
+    cmake << "set(CMAKE_MAP_IMPORTED_CONFIG_" << config_upper << " " << cmake_base;
+    for (const std::string fallback : {"Release", "RelWithDebInfo", "MinSizeRel", "Debug"}) {
+      if (fallback != cmake_base) {
+        cmake << " " << fallback;
+      }
+    }
+    cmake << ")\n";
+    cmake << "if(CMAKE_CONFIGURATION_TYPES AND NOT \"" << cmake_config
+          << "\" IN_LIST CMAKE_CONFIGURATION_TYPES)\n";
+    cmake << "    list(APPEND CMAKE_CONFIGURATION_TYPES \"" << cmake_config << "\")\n";
+    cmake << "endif()\n\n";
+  }
+  // MSVC picks the debug runtime by configuration name, not by flags
+  if (debug_configs != "$<CONFIG:Debug>") {
+    cmake << "if(MSVC AND NOT DEFINED CMAKE_MSVC_RUNTIME_LIBRARY)\n";
+    cmake << "    set(CMAKE_MSVC_RUNTIME_LIBRARY \"MultiThreaded$<$<OR:" << debug_configs
+          << ">:Debug>DLL\")\n";
+    cmake << "endif()\n\n";
+  }
+  return cmake.str();
+}
+
+/**
+ * @brief Generate the CMake code that selects the MSVC runtime library
+ *
+ * `runtime = "static"` picks the static CRT (/MT, /MTd) and `"dynamic"` the
+ * DLL one (/MD, /MDd). Configurations built on Debug use the debug variant.
+ * Other compilers have no equivalent setting, so CMake warns and ignores it
+ * there.
+ *
+ * @param runtime [build] runtime: "static", "dynamic" or empty
+ * @param runtime_by_config Configuration key to its own runtime
+ * @param base_by_config Configuration key to built-in type, see
+ * generate_config_build_type_cmake()
+ * @return CMake code, empty if no runtime is chosen
+ */
+inline std::string
+generate_msvc_runtime_cmake(const std::string &runtime,
+                            const std::map<std::string, std::string> &runtime_by_config,
+                            const std::map<std::string, std::string> &base_by_config) {
+  if (runtime.empty() && runtime_by_config.empty()) {
+    return "";
+  }
+
+  // Configurations whose runtime differs from the default
+  bool default_static = runtime == "static";
+  std::string overrides;
+  for (const auto &[config, config_runtime] : runtime_by_config) {
+    if ((config_runtime == "static") != default_static) {
+      std::string expression = "$<CONFIG:" + canonical_build_config(config) + ">";
+      overrides += (overrides.empty() ? "" : ",") + expression;
+    }
+  }
+  std::string dll;
+  if (overrides.empty()) {
+    dll = default_static ? "" : "DLL";
+  } else if (default_static) {
+    dll = "$<$<OR:" + overrides + ">:DLL>";
+  } else {
+    dll = "$<$<NOT:$<OR:" + overrides + ">>:DLL>";
+  }
+
+  std::string debug_configs = debug_config_expressions(base_by_config);
+  std::string debug         = debug_configs == "$<CONFIG:Debug>"
+                                ? "$<$<CONFIG:Debug>:Debug>"
+                                : "$<$<OR:" + debug_configs + ">:Debug>";
+
+  std::ostringstream cmake;
+  cmake << "# MSVC runtime library ([build] runtime)\n";
+  cmake << "if(MSVC)\n";
+  cmake << "    set(CMAKE_MSVC_RUNTIME_LIBRARY \"MultiThreaded" << debug << dll << "\")\n";
+  cmake << "else()\n";
+  cmake << "    message(WARNING \"[build] runtime only applies to MSVC-style compilers; "
+           "ignored for ${CMAKE_CXX_COMPILER_ID}\")\n";
+  cmake << "endif()\n\n";
+  return cmake.str();
+}
+
+/**
+ * @brief Generate the definitions of [build.config.<cfg>] defines
+ *
+ * Each configuration gets one target_compile_definitions() call whose
+ * entries are wrapped in `$<$<CONFIG:cfg>:...>`. The generator expression
+ * works for single- and multi-config generators alike, and CONFIG
+ * comparisons ignore case, so a definition is applied exactly once, in
+ * exactly the configuration it belongs to.
+ *
+ * @param defines_by_config Configuration key (as in cforge.toml) to defines
+ * @param target_name CMake target
+ * @param scope PRIVATE, or INTERFACE for header-only targets
+ * @return CMake code, empty if there are no defines
+ */
+inline std::string generate_config_defines_cmake(
+    const std::map<std::string, std::vector<std::string>> &defines_by_config,
+    const std::string &target_name,
+    const std::string &scope) {
+  std::ostringstream cmake;
+  for (const auto &[config, defines] : defines_by_config) {
+    if (defines.empty()) {
+      continue;
+    }
+    std::string cmake_config = canonical_build_config(config);
+    cmake << "# Definitions for config '" << cmake_config << "'\n";
+    cmake << "target_compile_definitions(" << target_name << " " << scope << "\n";
+    for (const auto &define : defines) {
+      cmake << "    \"$<$<CONFIG:" << cmake_config << ">:" << define << ">\"\n";
+    }
+    cmake << ")\n\n";
+  }
+  return cmake.str();
+}
+
+/**
+ * @brief Normalize a `cforge build --define` value
+ *
+ * @param define `NAME` or `NAME=VALUE`
+ * @return `NAME=1` for a bare name, otherwise the define unchanged
+ */
+inline std::string normalize_cli_define(const std::string &define) {
+  return define.find('=') == std::string::npos ? define + "=1" : define;
+}
+
+/**
+ * @brief CMake argument carrying `--define` values to the generated CMakeLists.txt
+ *
+ * Always passed, even when empty, so defines from an earlier
+ * `cforge build --define` are cleared from the CMake cache.
+ *
+ * @param defines Normalized defines
+ * @return `-DCFORGE_CLI_DEFINES=<defines as a CMake list>`
+ */
+inline std::string cli_defines_cache_arg(const std::vector<std::string> &defines) {
+  std::string list;
+  for (const auto &define : defines) {
+    list += (list.empty() ? "" : ";") + define;
+  }
+  return "-DCFORGE_CLI_DEFINES=" + list;
+}
+
+}  // namespace cforge
@@ -0,0 +1,133 @@
+/**
+ * @file build_info.hpp
+ * @brief Values and contents of the generated cforge_build_info.h
+ *
+ * With [build.info] enabled, cforge writes a header defining the project name,
+ * version, git commit and build time. The file is only rewritten when one of
+ * the values other than the timestamp changes, so repeated configures do not
+ * trigger rebuilds.
+ */
+
+#pragma once
+
+#include "core/git_utils.hpp"
+
+#include <filesystem>
+#include <fstream>
+#include <functional>
+#include <sstream>
+#include <string>
+#include <system_error>
+#include <vector>
+
+namespace cforge {
+
+/**
+ * @brief Values written to the build info header
+ */
+struct build_info_values {
+  std::string project_name;
+  std::string project_version;
+  std::string git_sha   = "unknown";
+  std::string git_dirty = "unknown";  ///< "0", "1" or "unknown"
+};
+
+/**
+ * @brief Runs git with the given arguments in the project directory
+ */
+using build_info_git_runner = std::function<git_result(const std::vector<std::string> &)>;
+
+/**
+ * @brief Fill in the git values of a build info header
+ *
+ * `run_git` is only called when `project_dir` is a git repository; otherwise,
+ * or when git fails, the values stay "unknown".
+ *
+ * @param values Values to update
+ * @param project_dir Project directory
+ * @param run_git Git runner, or empty if git is not available
+ */
+inline void read_build_info_git(build_info_values &values,
+                                const std::filesystem::path &project_dir,
+                                const build_info_git_runner &run_git) {
+  if (!run_git || !is_git_repository(project_dir)) {
+    return;
+  }
+  git_result sha = run_git({"rev-parse", "--short", "HEAD"});
+  if (sha.success) {
+    std::string commit = sha.output;
+    commit.erase(commit.find_last_not_of(" \n\r\t") + 1);
+    if (!commit.empty()) {
+      values.git_sha = commit;
+    }
+  }
+  git_result status = run_git({"status", "--porcelain"});
+  if (status.success) {
+    values.git_dirty = status.output.find_first_not_of(" \n\r\t") == std::string::npos ? "0" : "1";
+  }
+}
+
+/**
+ * @brief The `#define` lines of the header, without the timestamp
+ */
+inline std::string build_info_defines(const build_info_values &values) {
+  std::ostringstream body;
+  body << "#define CFORGE_PROJECT_NAME \"" << values.project_name << "\"\n";
+  body << "#define CFORGE_PROJECT_VERSION \"" << values.project_version << "\"\n";
+  body << "#define CFORGE_GIT_SHA \"" << values.git_sha << "\"\n";
+  if (values.git_dirty == "unknown") {
+    body << "#define CFORGE_GIT_DIRTY \"unknown\"\n";
+  } else {
+    body << "#define CFORGE_GIT_DIRTY " << values.git_dirty << "\n";
+  }
+  return body.str();
+}
+
+/**
+ * @brief Result of update_build_info_header
+ */
+enum class build_info_update { unchanged, written, failed };
+
+/**
+ * @brief Write the build info header unless only its timestamp would change
+ *
+ * @param header_path Path of cforge_build_info.h
+ * @param values Values to write
+ * @param timestamp Build time, e.g. "2024-01-01T00:00:00Z"
+ * @return Whether the file was left alone, written, or could not be written
+ */
+inline build_info_update update_build_info_header(const std::filesystem::path &header_path,
+                                                  const build_info_values &values,
+                                                  const std::string &timestamp) {
+  const std::string timestamp_prefix = "#define CFORGE_BUILD_TIMESTAMP ";
+  std::string body                   = build_info_defines(values);
+
+  // Compare against the existing header, ignoring the timestamp line
+  if (std::filesystem::exists(header_path)) {
+    std::ifstream in(header_path);
+    std::ostringstream existing;
+    std::string line;
+    while (std::getline(in, line)) {
+      if (line.rfind("#define CFORGE_", 0) == 0 && line.rfind(timestamp_prefix, 0) != 0) {
+        existing << line << "\n";
+      }
+    }
+    if (existing.str() == body) {
+      return build_info_update::unchanged;
+    }
+  }
+
+  std::error_code ec;
+  std::filesystem::create_directories(header_path.parent_path(), ec);
+  std::ofstream out(header_path);
+  if (!out) {
+    return build_info_update::failed;
+  }
+  out << "// Generated by cforge - do not edit\n";
+  out << "#pragma once\n\n";
+  out << body;
+  out << timestamp_prefix << "\"" << timestamp << "\"\n";
+  return out ? build_info_update::written : build_info_update::failed;
+}
+
+}  // namespace cforge
@@ -0,0 +1,200 @@
+/**
+ * @file build_log.hpp
+ * @brief Raw logs of the CMake configure and build steps
+ *
+ * The progress display and the diagnostic formatter show only part of what
+ * the compiler prints. Every configure and build therefore writes its
+ * unfiltered output to `<build>/.cforge/logs/<kind>-<timestamp>.log`, and the
+ * newest `[build] keep_logs` logs of each kind (default 5) are kept.
+ */
+
+#pragma once
+
+#include "core/types.h"
+
+#include <algorithm>
+#include <filesystem>
+#include <string>
+#include <vector>
+
+namespace cforge {
+
+/**
+ * @brief Number of logs of each kind kept when [build] keep_logs is not set
+ */
+constexpr cforge_int_t DEFAULT_KEPT_BUILD_LOGS = 5;
+
+/**
+ * @brief Directory the logs of a build directory are written to
+ */
+inline std::filesystem::path build_log_dir(const std::filesystem::path &build_dir) {
+  return build_dir / ".cforge" / "logs";
+}
+
+/**
+ * @brief Check whether a file name is a log of the given kind
+ *
+ * @param file_name File name in the log directory
+ * @param kind "configure", "build", or empty for either
+ */
+inline bool is_build_log_name(const std::string &file_name, const std::string &kind) {
+  const std::string ext = ".log";
+  if (file_name.size() <= ext.size()
+      || file_name.compare(file_name.size() - ext.size(), ext.size(), ext) != 0) {
+    return false;
+  }
+  if (kind.empty()) {
+    return is_build_log_name(file_name, "configure") || is_build_log_name(file_name, "build");
+  }
+  return file_name.rfind(kind + "-", 0) == 0;
+}
+
+/**
+ * @brief Timestamp part of a log file name
+ *
+ * Timestamps are written as `YYYYMMDD-HHMMSS-mmm`, so they sort in the order
+ * the logs were written.
+ */
+inline std::string build_log_timestamp(const std::string &file_name) {
+  cforge_size_t dash = file_name.find('-');
+  cforge_size_t dot  = file_name.rfind('.');
+  if (dash == std::string::npos || dot == std::string::npos || dot <= dash) {
+    return "";
+  }
+  return file_name.substr(dash + 1, dot - dash - 1);
+}
+
+/**
+ * @brief Logs of one kind to delete so that only the newest remain
+ *
+ * @param file_names File names in the log directory
+ * @param kind "configure" or "build"
+ * @param keep Number of logs to keep
+ * @return The older logs, oldest first
+ */
+inline std::vector<std::string> stale_build_logs(const std::vector<std::string> &file_names,
+                                                 const std::string &kind,
+                                                 cforge_int_t keep) {
+  std::vector<std::string> logs;
+  for (const auto &name : file_names) {
+    if (is_build_log_name(name, kind)) {
+      logs.push_back(name);
+    }
+  }
+  std::sort(logs.begin(), logs.end());
+  cforge_size_t kept = static_cast<cforge_size_t>(std::max<cforge_int_t>(keep, 0));
+  if (logs.size() <= kept) {
+    return {};
+  }
+  logs.resize(logs.size() - kept);
+  return logs;
+}
+
+/**
+ * @brief Newest log among the files of a log directory
+ *
+ * @param file_names File names in the log directory
+ * @param kind "configure", "build", or empty for either
+ * @return File name of the newest log, empty if there is none
+ */
+inline std::string newest_build_log(const std::vector<std::string> &file_names,
+                                    const std::string &kind) {
+  std::string newest;
+  for (const auto &name : file_names) {
+    if (is_build_log_name(name, kind)
+        && (newest.empty() || build_log_timestamp(name) > build_log_timestamp(newest))) {
+      newest = name;
+    }
+  }
+  return newest;
+}
+
+/**
+ * @brief Last lines of a text
+ *
+ * @param text Text, with or without a trailing newline
+ * @param count Number of lines
+ * @return The last @p count lines, without a trailing newline
+ */
+inline std::string last_lines(const std::string &text, cforge_size_t count) {
+  cforge_size_t end = text.size();
+  while (end > 0 && (text[end - 1] == '\n' || text[end - 1] == '\r')) {
+    --end;
+  }
+  if (count == 0) {
+    return "";
+  }
+  cforge_size_t start = end;
+  cforge_size_t lines = 0;
+  while (start > 0) {
+    if (text[start - 1] == '\n' && ++lines == count) {
+      break;
+    }
+    --start;
+  }
+  return text.substr(start, end - start);
+}
+
+/**
+ * @brief Text of a log from the output of a command
+ *
+ * Standard error follows standard output; Ninja and Make already pass the
+ * compiler output on through standard output, in order.
+ */
+inline std::string build_log_text(const std::string &stdout_output,
+                                  const std::string &stderr_output) {
+  std::string text = stdout_output;
+  if (!stderr_output.empty()) {
+    if (!text.empty() && text.back() != '\n') {
+      text += '\n';
+    }
+    text += "--- stderr ---\n" + stderr_output;
+  }
+  return text;
+}
+
+/**
+ * @brief Write the output of a configure or build step to a new log
+ *
+ * Logs of the same kind beyond @p keep are removed afterwards.
+ *
+ * @param build_dir Build directory of the step
+ * @param kind "configure" or "build"
+ * @param command_line Command that produced the output
+ * @param output Combined standard output and error of the command
+ * @param keep Number of logs of this kind to keep; 0 writes none
+ * @return Path of the log, empty if none was written
+ */
+std::filesystem::path write_build_log(const std::filesystem::path &build_dir,
+                                      const std::string &kind,
+                                      const std::string &command_line,
+                                      const std::string &output,
+                                      cforge_int_t keep);
+
+/**
+ * @brief Newest log of a build directory
+ *
+ * @param build_dir Build directory
+ * @param kind "configure", "build", or empty for either
+ * @return Path of the log, empty if there is none
+ */
+std::filesystem::path find_latest_build_log(const std::filesystem::path &build_dir,
+                                            const std::string &kind);
+
+/**
+ * @brief Number of logs to keep, from [build] keep_logs
+ *
+ * @param start_dir Directory to search upwards from for cforge.toml
+ * @return Configured count, or DEFAULT_KEPT_BUILD_LOGS
+ */
+cforge_int_t configured_build_log_limit(const std::filesystem::path &start_dir);
+
+/**
+ * @brief Print the end of a failed step's log and where the full log is
+ *
+ * @param log Log written by write_build_log()
+ * @param output Output written to the log
+ */
+void print_build_log_tail(const std::filesystem::path &log, const std::string &output);
+
+}  // namespace cforge
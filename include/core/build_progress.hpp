@@ -20,6 +20,7 @@ namespace cforge {
  */
 struct file_timing {
   std::string filename;
+  std::string action;  ///< Raw build action, e.g. "Building CXX object app/CMakeFiles/..."
   std::chrono::steady_clock::time_point start_time;
   std::chrono::steady_clock::time_point end_time;
   cforge_double_t duration_seconds = 0.0;
@@ -69,6 +70,29 @@ public:
    */
   bool has_progress() const;
 
+  /**
+   * @brief Set the step total for build output that has no counts of its own
+   *
+   * MSBuild prints file names without "[n/N]" or percentages; this total
+   * (the translation units in the build tree) is used instead. Call after
+   * reset().
+   *
+   * @param steps Expected number of steps, 0 if unknown
+   */
+  void set_expected_steps(cforge_int_t steps);
+
+  /**
+   * @brief Record the timing of the step still in progress
+   *
+   * A step is only timed when the next one starts, so call this once the
+   * build has exited to account for the last step (usually a link).
+   *
+   * @param succeeded Whether the build completed; if so the progress is
+   *        moved to 100%, since an incremental build runs fewer steps than
+   *        the total
+   */
+  void finish(bool succeeded = false);
+
   /**
    * @brief Get all file timings
    */
@@ -80,6 +104,11 @@ public:
    */
   std::vector<file_timing> get_slowest_files(cforge_size_t count = 5) const;
 
+  /**
+   * @brief Get the compiler warning lines seen so far (deduplicated)
+   */
+  const std::vector<std::string> &get_warnings() const;
+
   /**
    * @brief Called when a file starts compiling
    */
@@ -93,13 +122,21 @@ public:
 private:
   mutable std::mutex mutex_;
   std::string current_file_;
+  std::string current_action_;
   cforge_int_t current_step_ = 0;
-  cforge_int_t total_steps_  = 0;
-  bool has_progress_         = false;
+  cforge_int_t total_steps_    = 0;
+  cforge_int_t expected_steps_ = 0;
+  bool has_progress_           = false;
 
   std::vector<file_timing> timings_;
+  std::vector<std::string> warnings_;
   std::chrono::steady_clock::time_point current_file_start_;
 
+  /**
+   * @brief Record a GCC/Clang/MSVC warning line
+   */
+  bool parse_warning(const std::string &line);
+
   /**
    * @brief Parse Ninja-style progress: [1/15] Building CXX object ...
    */
@@ -0,0 +1,260 @@
+/**
+ * @file build_report.hpp
+ * @brief Per-project timing breakdown for the build summary
+ *
+ * Dependency setup and CMake configure are timed around the steps cforge runs
+ * itself. Compile and link time come from the step timings of the build tool
+ * output (see get_last_build_timings()) and are attributed to a project
+ * through the CMake target named in each step: `CMakeFiles/<target>.dir/` for
+ * compile steps and the artifact name for link steps. Steps run in parallel,
+ * so each step is charged the time until the next one started; the columns
+ * add up to wall-clock time rather than CPU time.
+ */
+
+#pragma once
+
+#include "core/build_progress.hpp"
+#include "core/types.h"
+
+#include <fmt/core.h>
+
+#include <regex>
+#include <string>
+#include <vector>
+
+namespace cforge {
+
+/**
+ * @brief Kind of a build tool step
+ */
+enum class build_step_kind {
+  COMPILE,
+  LINK,
+  OTHER,
+};
+
+/**
+ * @brief A build tool step with the CMake target it belongs to
+ */
+struct build_step {
+  build_step_kind kind = build_step_kind::OTHER;
+  std::string target;  ///< CMake target or artifact name; empty if unknown
+};
+
+/**
+ * @brief Outcome of a project in a build
+ */
+enum class project_build_status {
+  BUILT,
+  CACHED,  ///< Nothing was compiled or linked
+  FAILED,
+};
+
+/**
+ * @brief Timing breakdown for one project
+ */
+struct project_build_times {
+  std::string name;
+  project_build_status status       = project_build_status::CACHED;
+  cforge_double_t deps_seconds      = 0.0;
+  cforge_double_t configure_seconds = 0.0;
+  cforge_double_t compile_seconds   = 0.0;
+  cforge_double_t link_seconds      = 0.0;
+  cforge_int_t compiled_files       = 0;
+  bool linked                       = false;
+
+  cforge_double_t total_seconds() const {
+    return deps_seconds + configure_seconds + compile_seconds + link_seconds;
+  }
+};
+
+/**
+ * @brief Timing breakdown for a whole `cforge build`
+ *
+ * In a workspace the projects share one CMake configure, which is reported
+ * in `configure_seconds` instead of per project.
+ */
+struct build_report {
+  std::string config;
+  std::vector<project_build_times> projects;
+  cforge_double_t configure_seconds = 0.0;
+  cforge_double_t total_seconds     = 0.0;
+};
+
+/**
+ * @brief Classify a build action and extract its target
+ *
+ * Understands the Ninja/Make forms (`Building CXX object
+ * app/CMakeFiles/app.dir/src/main.cpp.o`, `Linking CXX executable bin/app`)
+ * and the MSBuild link line (`app.vcxproj -> C:\out\app.exe`). Link targets
+ * are the artifact name without directory and extension.
+ *
+ * @param action Raw action text of a step
+ * @return Step kind and target
+ */
+inline build_step classify_build_step(const std::string &action) {
+  static const std::regex compile_regex(R"(Building\s+\w+\s+object\s+(.*))");
+  static const std::regex target_dir_regex(R"(CMakeFiles[\\/]([^\\/]+)\.dir[\\/])");
+  static const std::regex link_regex(
+      R"(Linking\s+\w+\s+(?:executable|static library|shared library|shared module)\s+(\S+))");
+  static const std::regex msbuild_link_regex(R"(([^\s>\\/]+)\.vcxproj\s*->)");
+  static const std::regex msbuild_compile_regex(R"(^\s*(?:\d+>)?\s*[\w\-]+\.(?:cpp|c|cc|cxx)\s*$)");
+
+  build_step step;
+  std::smatch match;
+  if (std::regex_search(action, match, compile_regex)) {
+    step.kind         = build_step_kind::COMPILE;
+    std::string input = match[1].str();
+    if (std::regex_search(input, match, target_dir_regex)) {
+      step.target = match[1].str();
+    }
+  } else if (std::regex_search(action, match, link_regex)) {
+    step.kind               = build_step_kind::LINK;
+    std::string artifact    = match[1].str();
+    cforge_size_t slash     = artifact.find_last_of("/\\");
+    artifact                = slash == std::string::npos ? artifact : artifact.substr(slash + 1);
+    cforge_size_t extension = artifact.find('.');
+    step.target             = artifact.substr(0, extension);
+  } else if (std::regex_search(action, match, msbuild_link_regex)) {
+    step.kind   = build_step_kind::LINK;
+    step.target = match[1].str();
+  } else if (std::regex_match(action, msbuild_compile_regex)) {
+    step.kind = build_step_kind::COMPILE;
+  }
+  return step;
+}
+
+/**
+ * @brief Find the project a CMake target belongs to
+ *
+ * Matches the project name itself, its `lib`-prefixed artifact, and targets
+ * the project generates as `<name>_<suffix>` (e.g. `app_tests`). The longest
+ * matching project name wins.
+ *
+ * @param report Report holding the projects
+ * @param target Target from classify_build_step()
+ * @return Index into report.projects, or -1 if no project matches
+ */
+inline cforge_int_t find_report_project(const build_report &report, const std::string &target) {
+  if (report.projects.size() == 1) {
+    return 0;
+  }
+  if (target.empty()) {
+    return -1;
+  }
+
+  std::string bare = target.rfind("lib", 0) == 0 ? target.substr(3) : target;
+  cforge_int_t best       = -1;
+  cforge_size_t best_size = 0;
+  for (cforge_size_t i = 0; i < report.projects.size(); ++i) {
+    const std::string &name = report.projects[i].name;
+    for (const auto &candidate : {target, bare}) {
+      bool matches = candidate == name || candidate.rfind(name + "_", 0) == 0;
+      if (matches && name.size() > best_size) {
+        best      = static_cast<cforge_int_t>(i);
+        best_size = name.size();
+      }
+    }
+  }
+  return best;
+}
+
+/**
+ * @brief Charge build step timings to the projects of a report and set
+ * each project's status
+ *
+ * A project with no compile or link step is cached. When the build failed,
+ * projects that did not reach their link step are marked failed; if no step
+ * can be attributed at all, every project that was not built is.
+ *
+ * @param report Report to update
+ * @param timings Step timings of the build
+ * @param succeeded Whether the build tool succeeded
+ */
+inline void apply_build_timings(build_report &report,
+                                const std::vector<file_timing> &timings,
+                                bool succeeded) {
+  for (const auto &timing : timings) {
+    build_step step = classify_build_step(timing.action);
+    if (step.kind == build_step_kind::OTHER) {
+      continue;
+    }
+    cforge_int_t index = find_report_project(report, step.target);
+    if (index < 0) {
+      continue;
+    }
+    project_build_times &project = report.projects[static_cast<cforge_size_t>(index)];
+    if (step.kind == build_step_kind::COMPILE) {
+      project.compile_seconds += timing.duration_seconds;
+      project.compiled_files++;
+    } else {
+      project.link_seconds += timing.duration_seconds;
+      project.linked = true;
+    }
+  }
+
+  bool any_failed = false;
+  for (auto &project : report.projects) {
+    bool did_work  = project.compiled_files > 0 || project.linked;
+    project.status = did_work ? project_build_status::BUILT : project_build_status::CACHED;
+    if (!succeeded && did_work && !project.linked) {
+      project.status = project_build_status::FAILED;
+      any_failed     = true;
+    }
+  }
+  if (!succeeded && !any_failed) {
+    for (auto &project : report.projects) {
+      if (project.status != project_build_status::BUILT) {
+        project.status = project_build_status::FAILED;
+      }
+    }
+  }
+}
+
+/**
+ * @brief Status name used in the summary table and the JSON report
+ */
+inline const char *build_status_name(project_build_status status) {
+  switch (status) {
+    case project_build_status::BUILT:
+      return "built";
+    case project_build_status::CACHED:
+      return "cached";
+    case project_build_status::FAILED:
+      return "failed";
+  }
+  return "built";
+}
+
+/**
+ * @brief One-line project count summary, e.g. "3 built, 1 cached, 0 failed"
+ */
+inline std::string build_report_summary(const build_report &report) {
+  cforge_int_t built = 0, cached = 0, failed = 0;
+  for (const auto &project : report.projects) {
+    switch (project.status) {
+      case project_build_status::BUILT:
+        built++;
+        break;
+      case project_build_status::CACHED:
+        cached++;
+        break;
+      case project_build_status::FAILED:
+        failed++;
+        break;
+    }
+  }
+  return fmt::format("{} built, {} cached, {} failed", built, cached, failed);
+}
+
+/**
+ * @brief Format a duration for the summary table ("-" for nothing)
+ */
+inline std::string format_build_seconds(cforge_double_t seconds) {
+  if (seconds < 0.005) {
+    return "-";
+  }
+  return fmt::format("{:.2f}s", seconds);
+}
+
+}  // namespace cforge
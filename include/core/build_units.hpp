@@ -0,0 +1,101 @@
+/**
+ * @file build_units.hpp
+ * @brief Counting the translation units a configured build tree compiles
+ *
+ * MSBuild prints no step counts, so the build progress bar needs to know up
+ * front how many files a build compiles. The generated build system already
+ * lists every translation unit of the configuration, including generated
+ * sources, so it is read instead of guessing from the source tree.
+ */
+
+#pragma once
+
+#include "core/types.h"
+
+#include <set>
+#include <string>
+
+namespace cforge {
+
+/**
+ * @brief Count compile statements in a Ninja build file
+ *
+ * CMake writes one `build <object>: C_COMPILER__<target>... <source>`
+ * (or `CXX_COMPILER__`) statement per translation unit. With Ninja
+ * Multi-Config they live in `CMakeFiles/impl-<Config>.ninja`, otherwise in
+ * `build.ninja`.
+ *
+ * @param ninja Contents of the Ninja file
+ * @return Number of compile statements
+ */
+inline cforge_int_t count_ninja_compile_units(const std::string &ninja) {
+  cforge_int_t units = 0;
+  cforge_size_t pos  = 0;
+  while (pos < ninja.size()) {
+    cforge_size_t end = ninja.find('\n', pos);
+    if (end == std::string::npos) {
+      end = ninja.size();
+    }
+    if (ninja.compare(pos, 6, "build ") == 0) {
+      std::string line = ninja.substr(pos, end - pos);
+      // Paths escape ':' as "$:", so the first ": " ends the outputs
+      cforge_size_t colon = line.find(": ");
+      if (colon != std::string::npos) {
+        std::string rule = line.substr(colon + 2);
+        if (rule.rfind("C_COMPILER__", 0) == 0 || rule.rfind("CXX_COMPILER__", 0) == 0) {
+          ++units;
+        }
+      }
+    }
+    pos = end + 1;
+  }
+  return units;
+}
+
+/**
+ * @brief Count the distinct source files in a compile_commands.json
+ *
+ * @param json Contents of the compilation database
+ * @return Number of distinct "file" entries
+ */
+inline cforge_int_t count_compile_database_units(const std::string &json) {
+  std::set<std::string> files;
+  const std::string key = "\"file\"";
+  cforge_size_t pos     = json.find(key);
+  while (pos != std::string::npos) {
+    cforge_size_t open = json.find('"', json.find(':', pos + key.size()) + 1);
+    if (open == std::string::npos) {
+      break;
+    }
+    std::string file;
+    cforge_size_t i = open + 1;
+    for (; i < json.size() && json[i] != '"'; ++i) {
+      if (json[i] == '\\' && i + 1 < json.size()) {
+        ++i;
+      }
+      file += json[i];
+    }
+    files.insert(file);
+    pos = json.find(key, i);
+  }
+  return static_cast<cforge_int_t>(files.size());
+}
+
+/**
+ * @brief Count the sources an MSBuild project compiles
+ *
+ * @param vcxproj Contents of a .vcxproj file generated by CMake
+ * @return Number of `<ClCompile Include=...>` items
+ */
+inline cforge_int_t count_msbuild_compile_units(const std::string &vcxproj) {
+  const std::string item = "<ClCompile Include=";
+  cforge_int_t units     = 0;
+  cforge_size_t pos      = vcxproj.find(item);
+  while (pos != std::string::npos) {
+    ++units;
+    pos = vcxproj.find(item, pos + item.size());
+  }
+  return units;
+}
+
+}  // namespace cforge
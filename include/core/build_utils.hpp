@@ -7,14 +7,21 @@
 
 #include "cforge/log.hpp"
 
+#include "core/build_config.hpp"
+#include "core/cforge_tokens.hpp"
+#include "core/compiler_check.hpp"
 #include "core/constants.h"
+#include "core/fetchcontent_deps.hpp"
 #include "core/platform.hpp"
 #include "core/process_utils.hpp"
 #include "core/toml_reader.hpp"
 #include "core/types.h"
 
+#include <algorithm>
+#include <cctype>
 #include <filesystem>
 #include <fstream>
+#include <map>
 #include <string>
 #include <vector>
 
@@ -35,6 +42,111 @@ inline bool is_multi_config_generator(const std::string &generator) {
       || generator.find("Ninja Multi-Config") != std::string::npos;
 }
 
+/**
+ * @brief Check whether a build directory is already configured for a config
+ *
+ * True when CMakeCache.txt exists and either lists CMAKE_CONFIGURATION_TYPES
+ * (multi-config generator) or has a CMAKE_BUILD_TYPE matching `config`
+ * (case-insensitive).
+ *
+ * @param build_dir Build directory
+ * @param config Build configuration
+ * @return true if configure can be skipped
+ */
+inline bool is_build_dir_configured_for(const std::filesystem::path &build_dir,
+                                        const std::string &config) {
+  std::ifstream cache(build_dir / "CMakeCache.txt");
+  if (!cache) {
+    return false;
+  }
+
+  auto lower = [](std::string s) {
+    for (auto &c : s) {
+      c = static_cast<char>(std::tolower(static_cast<unsigned char>(c)));
+    }
+    return s;
+  };
+
+  std::string line;
+  while (std::getline(cache, line)) {
+    if (line.rfind("CMAKE_CONFIGURATION_TYPES:", 0) == 0) {
+      return true;
+    }
+    if (line.rfind("CMAKE_BUILD_TYPE:", 0) == 0) {
+      cforge_size_t eq = line.find('=');
+      return eq != std::string::npos && lower(line.substr(eq + 1)) == lower(config);
+    }
+  }
+  return false;
+}
+
+/**
+ * @brief Read the generator an existing build directory was configured with
+ *
+ * @param build_dir Build directory
+ * @return CMAKE_GENERATOR from CMakeCache.txt, or an empty string if the
+ *         directory has not been configured
+ */
+inline std::string get_cached_cmake_generator(const std::filesystem::path &build_dir) {
+  std::ifstream cache(build_dir / "CMakeCache.txt");
+  std::string line;
+  while (std::getline(cache, line)) {
+    if (line.rfind("CMAKE_GENERATOR:", 0) == 0) {
+      cforge_size_t eq = line.find('=');
+      return eq == std::string::npos ? "" : line.substr(eq + 1);
+    }
+  }
+  return "";
+}
+
+/**
+ * @brief Make sure a build directory can be configured with `generator`
+ *
+ * CMake refuses to reuse a CMakeCache.txt written by a different generator.
+ * When the cached generator differs, the cache is cleared after a warning so
+ * the next configure starts fresh; with `auto_clean` false an error naming
+ * both generators is printed instead.
+ *
+ * @param build_dir Build directory
+ * @param generator Generator about to be passed to cmake -G
+ * @param auto_clean Clear a mismatched cache instead of failing
+ * @return false if the build directory cannot be used
+ */
+bool prepare_build_dir_for_generator(const std::filesystem::path &build_dir,
+                                     const std::string &generator,
+                                     bool auto_clean);
+
+/**
+ * @brief Identify the C++ compiler a project will be configured with
+ *
+ * Follows the order CMake uses: cmake.cxx_compiler (or cmake.toolset with
+ * a Ninja generator), cl for Visual Studio generators, the CXX environment
+ * variable, the compiler cached in CMakeCache.txt, then the first of cl (on
+ * Windows), c++, g++ and clang++ that runs.
+ *
+ * @param project_config TOML reader for project config
+ * @param build_dir Build directory
+ * @param generator CMake generator
+ * @return Identity with an empty family if the compiler could not be identified
+ */
+compiler_identity detect_project_compiler(const toml_reader &project_config,
+                                          const std::filesystem::path &build_dir,
+                                          const std::string &generator);
+
+/**
+ * @brief Record the compiler a build directory is configured with
+ *
+ * When `<build_dir>/.cforge-compiler` names a different compiler or version,
+ * the CMake cache is cleared after a warning, so CMake detects the new
+ * compiler and everything is rebuilt with it.
+ *
+ * @param build_dir Build directory
+ * @param compiler Compiler from detect_project_compiler()
+ * @return false if the cache could not be cleared
+ */
+bool record_build_compiler(const std::filesystem::path &build_dir,
+                           const compiler_identity &compiler);
+
 /**
  * @brief Check if a CMake generator is valid/available
  *
@@ -58,6 +170,279 @@ bool is_generator_valid(const std::string &gen);
  */
 std::string get_cmake_generator();
 
+/**
+ * @brief Normalize an --arch value for the MSVC toolchain
+ *
+ * @param arch Architecture (x64/amd64, x86/win32, arm64)
+ * @return "x64", "x86" or "arm64"; empty if the architecture is unknown
+ */
+inline std::string normalize_msvc_arch(const std::string &arch) {
+  std::string lower;
+  for (char c : arch) {
+    lower += static_cast<char>(std::tolower(static_cast<unsigned char>(c)));
+  }
+  if (lower == "x64" || lower == "amd64" || lower == "x86_64") {
+    return "x64";
+  }
+  if (lower == "x86" || lower == "win32" || lower == "i386") {
+    return "x86";
+  }
+  if (lower == "arm64" || lower == "aarch64") {
+    return "arm64";
+  }
+  return "";
+}
+
+/**
+ * @brief Get the vcvarsall.bat argument for a host/target architecture pair
+ *
+ * vcvarsall.bat takes the target alone when it matches the host and
+ * `<host>_<target>` for a cross toolset (e.g. `x64_arm64`).
+ *
+ * @param host_arch Normalized host architecture
+ * @param target_arch Normalized target architecture
+ * @return vcvarsall.bat argument
+ */
+inline std::string vcvars_arch_argument(const std::string &host_arch,
+                                        const std::string &target_arch) {
+  if (host_arch == target_arch) {
+    return target_arch;
+  }
+  return host_arch + "_" + target_arch;
+}
+
+/**
+ * @brief Get the Visual Studio generator platform (-A) for an architecture
+ *
+ * @param arch Normalized architecture
+ * @return "x64", "Win32" or "ARM64"
+ */
+inline std::string visual_studio_platform(const std::string &arch) {
+  if (arch == "x86") {
+    return "Win32";
+  }
+  if (arch == "arm64") {
+    return "ARM64";
+  }
+  return "x64";
+}
+
+/**
+ * @brief Check whether a build needs the MSVC developer environment
+ *
+ * Visual Studio and Xcode generators locate their compilers themselves. Other
+ * generators (Ninja, NMake) need cl.exe or clang-cl on PATH together with the
+ * INCLUDE/LIB variables that vcvarsall.bat sets, which is the case when no
+ * compiler is configured or the configured one is cl or clang-cl.
+ *
+ * @param generator CMake generator
+ * @param cxx_compiler Configured C++ compiler, empty for the default
+ * @return true on Windows when the MSVC environment applies
+ */
+inline bool uses_msvc_environment(const std::string &generator, const std::string &cxx_compiler) {
+#ifdef _WIN32
+  if (generator.find("Visual Studio") != std::string::npos
+      || generator.find("Xcode") != std::string::npos) {
+    return false;
+  }
+  if (cxx_compiler.empty()) {
+    return true;
+  }
+  std::string name = std::filesystem::path(cxx_compiler).stem().string();
+  for (auto &c : name) {
+    c = static_cast<char>(std::tolower(static_cast<unsigned char>(c)));
+  }
+  return name == "cl" || name == "clang-cl";
+#else
+  (void)generator;
+  (void)cxx_compiler;
+  return false;
+#endif
+}
+
+/**
+ * @brief Parse the output of cmd.exe's `set` into environment variables
+ *
+ * @param output Lines of the form NAME=value
+ * @return Variables by name
+ */
+inline std::map<std::string, std::string> parse_environment_listing(const std::string &output) {
+  std::map<std::string, std::string> env;
+  cforge_size_t start = 0;
+  while (start < output.size()) {
+    cforge_size_t end = output.find('\n', start);
+    if (end == std::string::npos) {
+      end = output.size();
+    }
+    std::string line = output.substr(start, end - start);
+    start            = end + 1;
+
+    if (!line.empty() && line.back() == '\r') {
+      line.pop_back();
+    }
+    // Skip blank lines, stray script output and cmd's hidden "=C:" entries
+    cforge_size_t eq = line.find('=');
+    if (eq == std::string::npos || eq == 0) {
+      continue;
+    }
+    env[line.substr(0, eq)] = line.substr(eq + 1);
+  }
+  return env;
+}
+
+/**
+ * @brief Default vcpkg triplet for a target
+ *
+ * Maps CMake system/processor names (or cforge's --arch values) to vcpkg's
+ * community triplet names, e.g. Windows + aarch64 -> arm64-windows. An empty
+ * system or processor means the host's.
+ *
+ * @param system Target system (Windows, Linux, Darwin, Android, ...)
+ * @param processor Target processor (x86_64, aarch64, arm64, armv7l, x86, ...)
+ * @return Triplet, or empty if the target has no vcpkg triplet
+ */
+inline std::string default_vcpkg_triplet(const std::string &system, const std::string &processor) {
+  auto lower = [](const std::string &value) {
+    std::string out;
+    for (char c : value) {
+      out += static_cast<char>(std::tolower(static_cast<unsigned char>(c)));
+    }
+    return out;
+  };
+
+  std::string os = lower(system);
+  if (os.empty()) {
+#if defined(_WIN32)
+    os = "windows";
+#elif defined(__APPLE__)
+    os = "darwin";
+#elif defined(__FreeBSD__)
+    os = "freebsd";
+#else
+    os = "linux";
+#endif
+  }
+  if (os == "darwin" || os == "macos") {
+    os = "osx";
+  }
+
+  std::string cpu = lower(processor);
+  if (cpu.empty()) {
+#if defined(_M_ARM64) || defined(__aarch64__)
+    cpu = "arm64";
+#elif defined(_M_X64) || defined(__x86_64__)
+    cpu = "x64";
+#elif defined(_M_IX86) || defined(__i386__)
+    cpu = "x86";
+#else
+    cpu = "arm";
+#endif
+  }
+
+  std::string arch = normalize_msvc_arch(cpu);
+  if (arch.empty() && cpu.rfind("arm", 0) == 0) {
+    arch = "arm";
+  } else if (arch.empty() && (cpu == "i686" || cpu == "i586")) {
+    arch = "x86";
+  } else if (arch.empty() && cpu == "wasm32") {
+    arch = "wasm32";
+  }
+
+  static const std::vector<std::string> systems = {
+      "windows", "linux", "osx", "android", "ios", "freebsd", "emscripten"};
+  if (arch.empty() || std::find(systems.begin(), systems.end(), os) == systems.end()) {
+    return "";
+  }
+  return arch + "-" + os;
+}
+
+/**
+ * @brief Format a directory as a `files` source for VCPKG_BINARY_SOURCES
+ *
+ * Commas, semicolons and backticks in the path are escaped with a backtick.
+ */
+inline std::string vcpkg_files_binary_source(const std::string &dir) {
+  std::string source = "files,";
+  for (char c : dir) {
+    if (c == ',' || c == ';' || c == '`') {
+      source += '`';
+    }
+    source += c;
+  }
+  return source + ",readwrite";
+}
+
+/**
+ * @brief Check that a vcpkg triplet name is well formed
+ *
+ * Triplet names, including overlay triplets, are lower-case words separated
+ * by single dashes, e.g. x64-windows-static. This catches typos such as
+ * "x64_windows", "x64-Windows" or a stray ":" before vcpkg sees them.
+ */
+inline bool is_valid_vcpkg_triplet(const std::string &triplet) {
+  if (triplet.empty() || triplet.front() == '-' || triplet.back() == '-'
+      || triplet.find("--") != std::string::npos) {
+    return false;
+  }
+  for (char c : triplet) {
+    if (!(c >= 'a' && c <= 'z') && !(c >= '0' && c <= '9') && c != '-') {
+      return false;
+    }
+  }
+  return true;
+}
+
+/**
+ * @brief Check `vcpkg list` output for a package built for a triplet
+ *
+ * Lines look like `fmt:x64-windows-static  11.0.2  ...`, with features
+ * listed as separate `fmt[feature]:triplet` lines. Only an exact triplet
+ * match counts, so a dynamic x64-windows build does not satisfy
+ * x64-windows-static.
+ *
+ * @param output Output of `vcpkg list`
+ * @param package Package name
+ * @param triplet Triplet the package must be installed for
+ * @return true if the package is installed for the triplet
+ */
+inline bool vcpkg_list_has_package(const std::string &output,
+                                   const std::string &package,
+                                   const std::string &triplet) {
+  std::string spec    = package + ":" + triplet;
+  cforge_size_t start = 0;
+  while (start < output.size()) {
+    cforge_size_t end = output.find('\n', start);
+    if (end == std::string::npos) {
+      end = output.size();
+    }
+    std::string line = output.substr(start, end - start);
+    start            = end + 1;
+
+    cforge_size_t space = line.find_first_of(" \t\r");
+    if (line.substr(0, space) == spec) {
+      return true;
+    }
+  }
+  return false;
+}
+
+/**
+ * @brief Load the MSVC developer environment into this process
+ *
+ * When cl.exe is not on PATH, the newest Visual Studio or Build Tools
+ * install with the C++ toolset is located through vswhere, and its
+ * vcvarsall.bat (or VsDevCmd.bat) is run in a subshell. The resulting
+ * variables are exported to this process, so every configure, build and test
+ * command spawned afterwards inherits them. The captured environment is
+ * cached per architecture and reused until the script changes. Does nothing
+ * outside Windows or once the environment is set up.
+ *
+ * @param arch Normalized target architecture (see normalize_msvc_arch)
+ * @param verbose Verbose output
+ * @return true if cl.exe is usable afterwards (always true outside Windows)
+ */
+bool setup_msvc_environment(const std::string &arch, bool verbose);
+
 /**
  * @brief Get the build directory path for a given configuration
  *
@@ -73,6 +458,285 @@ std::filesystem::path get_build_dir_for_config(const std::string &base_dir,
                                                const std::string &config,
                                                bool create_if_missing = true);
 
+/**
+ * @brief Override the base build directory for this process
+ *
+ * Set from the global --build-dir flag. Takes precedence over the
+ * CFORGE_BUILD_DIR environment variable and build.build_dir in cforge.toml.
+ *
+ * @param dir Build directory (absolute, or relative to the project directory)
+ */
+void set_build_dir_override(const std::string &dir);
+
+/**
+ * @brief Resolve a build directory setting against a project
+ *
+ * A relative directory is resolved against the project directory. An
+ * absolute --build-dir or CFORGE_BUILD_DIR is shared by every project that
+ * is built, so for a workspace member it is nested per project to keep the
+ * members' CMake caches and outputs apart.
+ *
+ * @param project_dir Project directory
+ * @param dir Build directory setting
+ * @param member_name Project name when @p dir is a shared override and the
+ *                    project is a workspace member, otherwise empty
+ * @return Base build directory path
+ */
+inline std::filesystem::path resolve_build_base_dir(const std::filesystem::path &project_dir,
+                                                    const std::string &dir,
+                                                    const std::string &member_name) {
+  std::filesystem::path path(dir);
+  if (path.is_relative()) {
+    return project_dir / path;
+  }
+  return member_name.empty() ? path : path / member_name;
+}
+
+/**
+ * @brief Resolve the base build directory for a project
+ *
+ * Priority order:
+ * 1. --build-dir command line flag (see set_build_dir_override)
+ * 2. CFORGE_BUILD_DIR environment variable
+ * 3. cforge.toml build.build_dir (or legacy build.directory) setting
+ * 4. Default to "build"
+ *
+ * Relative paths are resolved against the project directory; an absolute
+ * override from 1. or 2. is nested per project for workspace members (see
+ * resolve_build_base_dir). The returned path is the base directory;
+ * config-specific handling is still applied by get_build_dir_for_config.
+ *
+ * @param project_dir Project directory
+ * @param project_config TOML reader for project config (can be nullptr)
+ * @return Base build directory path
+ */
+std::filesystem::path get_build_base_dir(const std::filesystem::path &project_dir,
+                                         const toml_reader *project_config);
+
+/**
+ * @brief Resolve the vcpkg triplet for a project
+ *
+ * build.config.<config>.vcpkg_triplet wins, then dependencies.vcpkg.triplet;
+ * otherwise the triplet is derived from the target (see
+ * default_vcpkg_triplet()). The result is not validated.
+ *
+ * @param project_config TOML reader for project config
+ * @param target_system Cross-compilation system, empty for the host
+ * @param target_processor Cross-compilation processor or --arch, empty for the host
+ * @param config Build configuration, empty for none
+ * @return Triplet, or empty if none applies
+ */
+std::string resolve_vcpkg_triplet(const toml_reader &project_config,
+                                  const std::string &target_system    = "",
+                                  const std::string &target_processor = "",
+                                  const std::string &config           = "");
+
+/**
+ * @brief Every vcpkg triplet a project builds with on the host
+ *
+ * The triplet of builds without a configuration override, followed by the
+ * distinct build.config.<config>.vcpkg_triplet values.
+ *
+ * @param project_config TOML reader for project config
+ * @return Triplets, without duplicates
+ */
+std::vector<std::string> get_vcpkg_triplets(const toml_reader &project_config);
+
+/**
+ * @brief Get the CMake arguments for the project's vcpkg integration
+ *
+ * Only applies when cforge.toml has a [dependencies.vcpkg] section. The vcpkg
+ * root comes from dependencies.vcpkg.path, then VCPKG_ROOT, then
+ * `<source_dir>/vcpkg`; the triplet comes from resolve_vcpkg_triplet().
+ * Also applies the binary cache (see apply_vcpkg_binary_cache()).
+ *
+ * @param project_config TOML reader for project config
+ * @param source_dir Directory used for the default vcpkg checkout
+ * @param target_system Cross-compilation system, empty for the host
+ * @param target_processor Cross-compilation processor or --arch, empty for the host
+ * @param config Build configuration, empty for none
+ * @return -DCMAKE_TOOLCHAIN_FILE / -DVCPKG_TARGET_TRIPLET / -DVCPKG_MANIFEST_INSTALL /
+ *         -DVCPKG_MANIFEST_MODE arguments (manifest installs are off in offline
+ *         mode), possibly empty
+ */
+std::vector<std::string> get_vcpkg_cmake_args(const toml_reader &project_config,
+                                              const std::filesystem::path &source_dir,
+                                              const std::string &target_system    = "",
+                                              const std::string &target_processor = "",
+                                              const std::string &config           = "");
+
+/**
+ * @brief Check whether a project uses vcpkg manifest mode
+ *
+ * @param project_config TOML reader for project config
+ * @return dependencies.vcpkg.manifest, false when unset
+ */
+bool is_vcpkg_manifest_mode(const toml_reader &project_config);
+
+/**
+ * @brief Write the project's vcpkg.json for manifest mode
+ *
+ * Lists dependencies.vcpkg.packages, [dependencies.vcpkg.<name>] tables and
+ * unified `vcpkg` dependencies with their features and versions.
+ * `builtin-baseline` is kept from an earlier generated manifest, otherwise
+ * pinned to the vcpkg checkout's HEAD. A vcpkg.json cforge did not write is
+ * left alone.
+ *
+ * @param project_dir Project directory containing cforge.toml
+ * @param project_config TOML reader for project config
+ * @return false if the file could not be written
+ */
+bool write_vcpkg_manifest(const std::filesystem::path &project_dir,
+                          const toml_reader &project_config);
+
+/**
+ * @brief Directory a project's vcpkg binary packages are cached in
+ *
+ * dependencies.vcpkg.binary_cache, relative to the project directory.
+ * Without it, `<user cache dir>/cforge/vcpkg-archives`, shared by all
+ * projects, unless VCPKG_BINARY_SOURCES or VCPKG_DEFAULT_BINARY_CACHE
+ * already tells vcpkg where to cache.
+ *
+ * @param project_config TOML reader for project config
+ * @param project_dir Project directory
+ * @return Cache directory, empty without a [dependencies.vcpkg] section or
+ *         with `binary_cache = false`
+ */
+std::filesystem::path get_vcpkg_binary_cache_dir(const toml_reader &project_config,
+                                                 const std::filesystem::path &project_dir);
+
+/**
+ * @brief Make vcpkg read and write the project's binary cache
+ *
+ * Adds the directory from get_vcpkg_binary_cache_dir() to
+ * VCPKG_BINARY_SOURCES, after any sources the user set, for the vcpkg
+ * processes cforge starts: `vcpkg install` and the toolchain's installs
+ * during configure.
+ *
+ * @param project_config TOML reader for project config
+ * @param project_dir Project directory
+ */
+void apply_vcpkg_binary_cache(const toml_reader &project_config,
+                              const std::filesystem::path &project_dir);
+
+/**
+ * @brief Get the vcpkg root directory for a project
+ *
+ * dependencies.vcpkg.path, then VCPKG_ROOT, then `<source_dir>/vcpkg`.
+ *
+ * @param project_config TOML reader for project config
+ * @param source_dir Directory used for the default vcpkg checkout
+ * @return vcpkg root directory
+ */
+std::string get_vcpkg_root(const toml_reader &project_config,
+                           const std::filesystem::path &source_dir);
+
+/**
+ * @brief List the vcpkg ports a project uses
+ *
+ * Collects dependencies.vcpkg.packages, [dependencies.vcpkg.<name>] tables
+ * and unified `vcpkg` dependencies. Empty without a [dependencies.vcpkg]
+ * section, since the vcpkg toolchain is not used then.
+ *
+ * @param project_dir Project directory containing cforge.toml
+ * @param project_config TOML reader for project config
+ * @return Lower-case port names
+ */
+std::vector<std::string> get_vcpkg_packages(const std::filesystem::path &project_dir,
+                                            const toml_reader &project_config);
+
+/**
+ * @brief Read a project's [dependencies.fetchcontent.<name>] tables
+ *
+ * Entries with neither `git` nor `url` are skipped with a warning.
+ *
+ * @param project_config TOML reader for project config
+ * @return Dependencies, by name
+ */
+std::vector<fetchcontent_dependency> get_fetchcontent_dependencies(
+    const toml_reader &project_config);
+
+/**
+ * @brief Check whether a port is in vcpkg's installed tree for a triplet
+ *
+ * @param vcpkg_root vcpkg root directory
+ * @param port Port name
+ * @param triplet Triplet
+ * @return true if `vcpkg install <port>:<triplet>` has been run
+ */
+bool is_vcpkg_port_installed(const std::string &vcpkg_root,
+                             const std::string &port,
+                             const std::string &triplet);
+
+/**
+ * @brief List a project's conan dependencies
+ *
+ * @param project_dir Project directory containing cforge.toml
+ * @return Conan references in name/version form
+ */
+std::vector<std::string> get_conan_references(const std::filesystem::path &project_dir);
+
+/**
+ * @brief Directory conan installs a project's packages into
+ *
+ * @param project_dir Project directory containing cforge.toml
+ * @return `conan` below the project's base build directory (see
+ *         get_build_base_dir)
+ */
+std::filesystem::path get_conan_dir(const std::filesystem::path &project_dir);
+
+/**
+ * @brief Install a project's conan dependencies into get_conan_dir()
+ *
+ * Writes `conanfile.txt` there with the generators of the installed
+ * Conan version (see conan.hpp) and runs `conan install` on it. Conan 2
+ * needs a default profile; one is detected first if there is none.
+ *
+ * @param project_dir Project directory containing cforge.toml
+ * @param build_type CMake build type to install packages for, e.g. "Release"
+ * @param verbose Show conan's output
+ * @return false if conan is missing or the install failed
+ */
+bool install_conan_packages(const std::filesystem::path &project_dir,
+                            const std::string &build_type,
+                            bool verbose);
+
+/**
+ * @brief Check whether a project's conan dependencies are installed
+ *
+ * @param project_dir Project directory containing cforge.toml
+ * @param build_type CMake build type
+ * @return true if install_conan_packages() ran for this build type with the
+ *         current dependencies
+ */
+bool is_conan_install_current(const std::filesystem::path &project_dir,
+                              const std::string &build_type);
+
+/**
+ * @brief Get the toolchain file conan generated for a project
+ *
+ * @param project_dir Project directory
+ * @return `conan_toolchain.cmake` (Conan 2) or `conan_paths.cmake` (Conan 1)
+ *         in get_conan_dir(), empty if neither exists
+ */
+std::filesystem::path get_conan_toolchain_file(const std::filesystem::path &project_dir);
+
+/**
+ * @brief Check whether cforge.toml gets a package from a package manager
+ *
+ * True when one of the names appears under the legacy [dependencies.vcpkg]
+ * table or as a vcpkg, conan or system dependency, so generated test and
+ * benchmark builds should find_package() it instead of fetching a copy.
+ *
+ * @param project_dir Project directory containing cforge.toml
+ * @param project_config TOML reader for project config
+ * @param names Lower-case package names to look for (e.g. {"gtest", "googletest"})
+ * @return true if any of the names is provided by a package manager
+ */
+bool is_package_manager_dependency(const std::filesystem::path &project_dir,
+                                   const toml_reader &project_config,
+                                   const std::vector<std::string> &names);
+
 /**
  * @brief Get build configuration from various sources
  *
@@ -140,6 +804,191 @@ bool run_cmake_build(const std::filesystem::path &build_dir,
                      cforge_int_t num_jobs     = 0,
                      bool verbose              = false);
 
+/**
+ * @brief Build typed CMake cache arguments from the [cmake.cache] table
+ *
+ * Each key maps either to a plain value (typed as STRING, with booleans
+ * rendered as ON/OFF) or to a table of the form
+ * `{ value = ..., type = "BOOL" }`. Entries are emitted as
+ * `-D<KEY>:<TYPE>=<value>`.
+ *
+ * @param project_config TOML reader for project config
+ * @return List of -D arguments to pass to CMake configure
+ */
+std::vector<std::string> get_cmake_cache_args(const toml_reader &project_config);
+
+/**
+ * @brief Get the CMake arguments cforge.toml sets for a build configuration
+ *
+ * build.defines and build.config.<config>.defines (`-DNAME=ON` for a define
+ * without a value), build.config.<config>.cmake_args, then the [cmake.cache]
+ * entries, in the order `cforge build` passes them.
+ *
+ * @param project_config TOML reader for project config
+ * @param config Build configuration in any case
+ * @return Arguments to pass to CMake configure
+ */
+std::vector<std::string> get_config_cmake_args(const toml_reader &project_config,
+                                               const std::string &config);
+
+/**
+ * @brief Get the compiler and language standard arguments from cforge.toml
+ *
+ * cmake.c_compiler, cmake.cxx_compiler, project.c_standard and
+ * project.cpp_standard. These come after cross-compilation settings so that
+ * an explicit compiler wins.
+ *
+ * @param project_config TOML reader for project config
+ * @return -DCMAKE_<LANG>_COMPILER / -DCMAKE_<LANG>_STANDARD arguments
+ */
+std::vector<std::string> get_compiler_cmake_args(const toml_reader &project_config);
+
+/**
+ * @brief Check whether the generated build info header is enabled
+ *
+ * Enabled when a [build.info] table is present, unless it sets
+ * `enabled = false`.
+ *
+ * @param project_config TOML reader for project config
+ * @return true if cforge_build_info.h should be generated
+ */
+bool is_build_info_enabled(const toml_reader &project_config);
+
+/**
+ * @brief Write cforge_build_info.h into the build directory
+ *
+ * The header is written to `<build_dir>/cforge_generated/` and defines
+ * CFORGE_PROJECT_NAME, CFORGE_PROJECT_VERSION, CFORGE_GIT_SHA,
+ * CFORGE_GIT_DIRTY and CFORGE_BUILD_TIMESTAMP. Git values are "unknown" when
+ * the project is not a git repository. The file is left untouched (keeping
+ * its original timestamp) when none of the other values changed, so that
+ * repeated configures do not trigger rebuilds.
+ *
+ * @param project_dir Project directory
+ * @param build_dir Build directory
+ * @param project_config TOML reader for project config
+ * @return true if the header is up to date
+ */
+bool write_build_info_header(const std::filesystem::path &project_dir,
+                             const std::filesystem::path &build_dir,
+                             const toml_reader &project_config);
+
+/**
+ * @brief Collect the [env] variables for a build configuration
+ *
+ * Merges the top-level [env] table with [build.config.<config>.env] (or
+ * [build.configs.<config>.env]); per-config values win. The tokens from
+ * get_cforge_tokens() are expanded in every value.
+ *
+ * The variables only apply to one project's CMake configure and build and
+ * to the program `cforge run` launches, so callers export them with a
+ * scoped_environment around those calls.
+ *
+ * @param project_config TOML reader for project config
+ * @param config Build configuration
+ * @return Variable name to expanded value
+ */
+std::map<std::string, std::string> get_project_environment(const toml_reader &project_config,
+                                                           const std::string &config);
+
+/**
+ * @brief Get the tokens cforge expands in a project's cforge.toml values
+ *
+ * ${PROJECT}, ${PROJECT_NAME} and ${VERSION} come from [project], and
+ * ${TARGET} is the project's CMake target, which is named after the project.
+ *
+ * @param project_config TOML reader for project config
+ * @param config Build configuration, or a CMake expression for it such as
+ *        "$<CONFIG>"
+ * @return Token name to value
+ */
+std::map<std::string, std::string> get_cforge_tokens(const toml_reader &project_config,
+                                                     const std::string &config);
+
+/**
+ * @brief Get an expanded [output] directory for a build configuration
+ *
+ * @param project_config TOML reader for project config
+ * @param project_dir Project directory; relative directories start here
+ * @param key Directory key, e.g. "bin_dir"
+ * @param config Build configuration
+ * @return Directory, or an empty path if [output] doesn't set it
+ */
+std::filesystem::path get_output_dir(const toml_reader &project_config,
+                                     const std::filesystem::path &project_dir,
+                                     const std::string &key,
+                                     const std::string &config);
+
+/**
+ * @brief Find a project's executable in its [output] bin_dir
+ *
+ * @param project_config TOML reader for project config
+ * @param project_dir Project directory
+ * @param config Build configuration
+ * @param name Executable name without extension
+ * @return Path to the executable, or an empty path if bin_dir is unset or
+ *         has no such file
+ */
+std::filesystem::path find_output_executable(const toml_reader &project_config,
+                                             const std::filesystem::path &project_dir,
+                                             const std::string &config,
+                                             const std::string &name);
+
+/**
+ * @brief Map a project type name to its canonical binary_type
+ *
+ * Canonical values are executable, static_lib, shared_lib and header_only.
+ * Hyphenated and long spellings (static-library, shared-library,
+ * header-only, ...) are accepted too. "library" and "lib" map to static_lib
+ * and set `deprecated_alias`.
+ *
+ * @param type Type name as written in cforge.toml or on the command line
+ * @param deprecated_alias Set to true if a deprecated alias was used (optional)
+ * @return Canonical binary type, or an empty string if unknown
+ */
+std::string normalize_binary_type(const std::string &type, bool *deprecated_alias = nullptr);
+
+/**
+ * @brief Get the canonical project.binary_type, defaulting to executable
+ *
+ * Unknown values are returned unchanged; use validate_binary_type to report
+ * them.
+ *
+ * @param project_config TOML reader for project config
+ * @return Canonical binary type
+ */
+std::string get_binary_type(const toml_reader &project_config);
+
+/**
+ * @brief Validate a binary type key in cforge.toml
+ *
+ * Warns (with the line to change) when a deprecated alias is used and
+ * prints a CONFIG_INVALID_VALUE error pointing at `<file>:<line>` when the
+ * value is unknown.
+ *
+ * @param project_config TOML reader for project config
+ * @param config_file Path of the file, used in messages
+ * @param key Dotted key to check
+ * @return false if the value is invalid
+ */
+bool validate_binary_type(const toml_reader &project_config,
+                          const std::string &config_file,
+                          const std::string &key = "project.binary_type");
+
+/**
+ * @brief Reject keys in [project], [build], [test] and [package] that cforge
+ *        does not read
+ *
+ * Prints a CONFIG_UNKNOWN_KEY error for each one, pointing at `<file>:<line>`
+ * and naming the closest known key, so a typo such as `defins` fails the
+ * build instead of being ignored.
+ *
+ * @param project_config TOML reader for project config
+ * @param config_file Path of the file, used in messages
+ * @return false if any unknown key was found
+ */
+bool validate_manifest_keys(const toml_reader &project_config, const std::string &config_file);
+
 // Smart Rebuild Utilities
 
 /**
@@ -159,9 +1008,60 @@ bool is_file_newer(const std::filesystem::path &source, const std::filesystem::p
  */
 bool needs_cmakelists_regeneration(const std::filesystem::path &project_dir);
 
+/**
+ * @brief Hash the effective project configuration
+ *
+ * cforge.toml is parsed and serialized again before hashing, so comments,
+ * whitespace and key order don't count as changes. Defines given with
+ * `cforge build --define` and the variants given with `--variant` are part of
+ * the configuration too.
+ *
+ * @param project_dir Project directory containing cforge.toml
+ * @param cli_defines Normalized `--define` values
+ * @param variants Selected build variants
+ * @return Hash string, or an empty string if cforge.toml can't be parsed
+ */
+std::string get_project_config_hash(const std::filesystem::path &project_dir,
+                                    const std::vector<std::string> &cli_defines = {},
+                                    const std::vector<std::string> &variants    = {});
+
+/**
+ * @brief Record the configuration a build directory was configured with
+ *
+ * Writes the hash of the project configuration to
+ * `<build_dir>/.cforge-config-hash`. Call after a successful configure.
+ *
+ * @param project_dir Project directory containing cforge.toml
+ * @param build_dir Build directory that was configured
+ * @param cli_defines Normalized `--define` values the configure used
+ * @param variants Build variants the configure used
+ */
+void write_config_hash(const std::filesystem::path &project_dir,
+                       const std::filesystem::path &build_dir,
+                       const std::vector<std::string> &cli_defines = {},
+                       const std::vector<std::string> &variants    = {});
+
+/**
+ * @brief Check whether a build directory was configured with the current configuration
+ *
+ * @param project_dir Project directory containing cforge.toml
+ * @param build_dir Build directory
+ * @param cli_defines Normalized `--define` values of this build
+ * @param variants Build variants of this build
+ * @return true if the recorded hash matches; false if it differs or is missing
+ */
+bool is_config_hash_current(const std::filesystem::path &project_dir,
+                            const std::filesystem::path &build_dir,
+                            const std::vector<std::string> &cli_defines = {},
+                            const std::vector<std::string> &variants    = {});
+
 /**
  * @brief Check if CMake reconfiguration is needed
  *
+ * Compares the project configuration with the hash recorded by the last
+ * configure. Build directories without a recorded hash fall back to
+ * comparing file modification times.
+ *
  * @param project_dir Project directory
  * @param build_dir Build directory
  * @return true if CMake needs to be reconfigured
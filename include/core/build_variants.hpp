@@ -0,0 +1,324 @@
+/**
+ * @file build_variants.hpp
+ * @brief Build variants from [variants], selected with `cforge build --variant`
+ *
+ * A variant is a named set of portable options, defines and flags under
+ * [variants.variants.<name>]. Variants can extend others with
+ * `inherits = ["base"]`, and `--variant a,b` stacks several. The generated
+ * CMakeLists.txt holds every variant, already merged with what it inherits,
+ * and applies the ones listed in CFORGE_VARIANTS on top of the configuration's
+ * own flags.
+ */
+
+#pragma once
+
+#include "core/portable_flags.hpp"
+#include "core/toml_reader.hpp"
+#include "core/types.h"
+
+#include <algorithm>
+#include <functional>
+#include <map>
+#include <sstream>
+#include <string>
+#include <vector>
+
+namespace cforge {
+
+/**
+ * @brief Settings of one [variants.variants.<name>] table
+ */
+struct build_variant {
+  std::string name;
+  std::string description;
+  std::vector<std::string> inherits;  ///< Variants whose settings this one extends
+  portable_options options;           ///< optimize, sanitizers, lto, ...
+  std::vector<std::string> defines;
+  std::vector<std::string> flags;  ///< Universal flag tokens or raw compiler flags
+};
+
+/**
+ * @brief Apply one variant's settings on top of another's
+ *
+ * Works like merge_linker_options(): lists are appended without duplicates,
+ * strings override when set and booleans are only switched on, except
+ * `exceptions` and `rtti`, which are only switched off. A define replaces an
+ * earlier one with the same name. The name, description and inherits list
+ * are left alone.
+ *
+ * @param target Settings to merge into
+ * @param source Settings that take precedence
+ */
+inline void merge_build_variant(build_variant &target, const build_variant &source) {
+  auto append = [](std::vector<std::string> &to, const std::vector<std::string> &from) {
+    for (const auto &item : from) {
+      if (std::find(to.begin(), to.end(), item) == to.end()) {
+        to.push_back(item);
+      }
+    }
+  };
+  auto define_name = [](const std::string &define) { return define.substr(0, define.find('=')); };
+  for (const auto &define : source.defines) {
+    auto same = std::find_if(target.defines.begin(), target.defines.end(),
+                             [&](const std::string &d) {
+                               return define_name(d) == define_name(define);
+                             });
+    if (same != target.defines.end()) {
+      *same = define;
+    } else {
+      target.defines.push_back(define);
+    }
+  }
+  append(target.flags, source.flags);
+  append(target.options.sanitizers, source.options.sanitizers);
+
+  portable_options &opts             = target.options;
+  const portable_options &source_opts = source.options;
+  auto override_string = [](std::string &to, const std::string &from) {
+    if (!from.empty()) {
+      to = from;
+    }
+  };
+  override_string(opts.optimize, source_opts.optimize);
+  override_string(opts.warnings, source_opts.warnings);
+  override_string(opts.stdlib, source_opts.stdlib);
+  override_string(opts.hardening, source_opts.hardening);
+  override_string(opts.visibility, source_opts.visibility);
+  opts.warnings_as_errors = opts.warnings_as_errors || source_opts.warnings_as_errors;
+  opts.debug_info         = opts.debug_info || source_opts.debug_info;
+  opts.lto                = opts.lto || source_opts.lto;
+  opts.exceptions         = opts.exceptions && source_opts.exceptions;
+  opts.rtti               = opts.rtti && source_opts.rtti;
+}
+
+/**
+ * @brief Resolve a variant together with the variants it inherits
+ *
+ * Inherited variants are applied first, in the order listed, so the
+ * variant's own settings take precedence over theirs.
+ *
+ * @param variants Variants by name
+ * @param name Variant to resolve
+ * @param error Set if a variant is not defined, or inheritance forms a cycle
+ * @return The merged settings, named after the variant
+ */
+inline build_variant resolve_build_variant(const std::map<std::string, build_variant> &variants,
+                                           const std::string &name,
+                                           std::string &error) {
+  std::vector<std::string> path;
+  std::function<bool(const std::string &, build_variant &)> visit =
+      [&](const std::string &current, build_variant &resolved) {
+        auto active = std::find(path.begin(), path.end(), current);
+        if (active != path.end()) {
+          error = "variant inheritance cycle detected: ";
+          for (auto it = active; it != path.end(); ++it) {
+            error += *it + " -> ";
+          }
+          error += current;
+          return false;
+        }
+        auto variant = variants.find(current);
+        if (variant == variants.end()) {
+          error = "variant '" + current + "' is not defined";
+          if (!path.empty()) {
+            error += " ('" + path.back() + "' inherits it)";
+          }
+          return false;
+        }
+
+        path.push_back(current);
+        for (const auto &parent : variant->second.inherits) {
+          if (!visit(parent, resolved)) {
+            return false;
+          }
+        }
+        path.pop_back();
+        merge_build_variant(resolved, variant->second);
+        return true;
+      };
+
+  build_variant resolved;
+  if (!visit(name, resolved)) {
+    return build_variant();
+  }
+  const build_variant &own = variants.at(name);
+  resolved.name            = own.name;
+  resolved.description     = own.description;
+  resolved.inherits        = own.inherits;
+  return resolved;
+}
+
+/**
+ * @brief Resolve stacked variants, as given to `--variant a,b`
+ *
+ * Later variants take precedence: their settings are merged last, and their
+ * flags come later on the compiler's command line.
+ *
+ * @param variants Variants by name
+ * @param names Variants in the order given
+ * @param error Set if a variant can't be resolved
+ * @return The merged settings, named after the stack (e.g. "a,b")
+ */
+inline build_variant resolve_build_variants(const std::map<std::string, build_variant> &variants,
+                                            const std::vector<std::string> &names,
+                                            std::string &error) {
+  build_variant stacked;
+  for (const auto &name : names) {
+    build_variant resolved = resolve_build_variant(variants, name, error);
+    if (!error.empty()) {
+      return build_variant();
+    }
+    merge_build_variant(stacked, resolved);
+    stacked.name += (stacked.name.empty() ? "" : ",") + name;
+  }
+  return stacked;
+}
+
+/**
+ * @brief Describe a variant's settings, one `key: value` line per setting
+ *
+ * Used by `cforge list variants`; settings left at their default are
+ * skipped.
+ */
+inline std::vector<std::string> describe_build_variant(const build_variant &variant) {
+  auto join = [](const std::vector<std::string> &items) {
+    std::string joined;
+    for (const auto &item : items) {
+      joined += (joined.empty() ? "" : " ") + item;
+    }
+    return joined;
+  };
+  const portable_options &opts = variant.options;
+  std::vector<std::string> lines;
+  auto add = [&](const std::string &key, const std::string &value) {
+    if (!value.empty()) {
+      lines.push_back(key + ": " + value);
+    }
+  };
+  add("optimize", opts.optimize);
+  add("warnings", opts.warnings);
+  add("sanitizers", join(opts.sanitizers));
+  add("hardening", opts.hardening);
+  add("visibility", opts.visibility);
+  add("stdlib", opts.stdlib);
+  add("lto", opts.lto ? "true" : "");
+  add("debug_info", opts.debug_info ? "true" : "");
+  add("warnings_as_errors", opts.warnings_as_errors ? "true" : "");
+  add("exceptions", opts.exceptions ? "" : "false");
+  add("rtti", opts.rtti ? "" : "false");
+  add("defines", join(variant.defines));
+  add("flags", join(variant.flags));
+  return lines;
+}
+
+/**
+ * @brief Read the variants under [variants.variants]
+ *
+ * @param config Project configuration
+ * @return Variants by name, as written (see resolve_build_variant)
+ */
+inline std::map<std::string, build_variant> read_build_variants(const toml_reader &config) {
+  std::map<std::string, build_variant> variants;
+  for (const auto &name : config.get_table_keys("variants.variants")) {
+    std::string section = "variants.variants." + name;
+    build_variant variant;
+    variant.name        = name;
+    variant.description = config.get_string(section + ".description");
+    variant.inherits    = config.get_string_array(section + ".inherits");
+    variant.options     = parse_portable_options(config, section);
+    variant.defines     = config.get_string_array(section + ".defines");
+    variant.flags       = config.get_string_array(section + ".flags");
+    variants[name]      = std::move(variant);
+  }
+  return variants;
+}
+
+/**
+ * @brief Cache argument selecting the variants to apply
+ *
+ * Passed on every configure, like cli_defines_cache_arg(), so a build
+ * without `--variant` clears the previous selection.
+ *
+ * @return `-DCFORGE_VARIANTS=<variants as a CMake list>`
+ */
+inline std::string variants_cache_arg(const std::vector<std::string> &names) {
+  std::string list;
+  for (const auto &name : names) {
+    list += (list.empty() ? "" : ";") + name;
+  }
+  return "-DCFORGE_VARIANTS=" + list;
+}
+
+/**
+ * @brief Generate the CMake code that applies the selected variants
+ *
+ * Each variant's flags and defines are added to the target, after the
+ * configuration's, so `-c Release --variant asan` keeps Release's
+ * optimization flags. Variants are applied in the order CFORGE_VARIANTS
+ * lists them.
+ *
+ * @param variants Resolved variants (see resolve_build_variant)
+ * @param target_name CMake target name
+ * @return CMake code string, empty if there are no variants
+ */
+inline std::string generate_build_variants_cmake(const std::vector<build_variant> &variants,
+                                                 const std::string &target_name) {
+  if (variants.empty()) {
+    return "";
+  }
+
+  auto emit = [&](std::ostringstream &out, const char *command,
+                  const std::vector<std::string> &flags) {
+    if (flags.empty()) {
+      return;
+    }
+    out << "            " << command << "(" << target_name << " PRIVATE";
+    for (const auto &flag : flags) {
+      out << " \"" << flag << "\"";
+    }
+    out << ")\n";
+  };
+
+  std::ostringstream cmake;
+  cmake << "# Build variants, selected with cforge build --variant\n";
+  cmake << "foreach(CFORGE_VARIANT IN LISTS CFORGE_VARIANTS)\n";
+  for (const auto &variant : variants) {
+    cmake << "    if(CFORGE_VARIANT STREQUAL \"" << variant.name << "\")\n";
+    cmake << generate_portable_flags_cmake(variant.options, target_name, "        ");
+
+    auto msvc_flags = parse_universal_flags(variant.flags, true);
+    auto gcc_flags  = parse_universal_flags(variant.flags, false, false);
+    if (!msvc_flags.empty() || !gcc_flags.empty()) {
+      std::vector<std::string> msvc_link;
+      if (std::find(msvc_flags.begin(), msvc_flags.end(), "/GL") != msvc_flags.end()) {
+        msvc_link.push_back("/LTCG");
+      }
+      std::vector<std::string> gcc_link;
+      for (const auto &flag : gcc_flags) {
+        if (flag == "-flto" || flag.rfind("-fsanitize=", 0) == 0) {
+          gcc_link.push_back(flag);
+        }
+      }
+      cmake << "        if(MSVC AND NOT CMAKE_CXX_COMPILER_ID STREQUAL \"Clang\")\n";
+      emit(cmake, "target_compile_options", msvc_flags);
+      emit(cmake, "target_link_options", msvc_link);
+      cmake << "        else()\n";
+      emit(cmake, "target_compile_options", gcc_flags);
+      emit(cmake, "target_link_options", gcc_link);
+      cmake << "        endif()\n";
+    }
+
+    if (!variant.defines.empty()) {
+      cmake << "        target_compile_definitions(" << target_name << " PRIVATE";
+      for (const auto &define : variant.defines) {
+        cmake << " \"" << define << "\"";
+      }
+      cmake << ")\n";
+    }
+    cmake << "    endif()\n";
+  }
+  cmake << "endforeach()\n\n";
+  return cmake.str();
+}
+
+}  // namespace cforge
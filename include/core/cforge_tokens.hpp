@@ -0,0 +1,113 @@
+/**
+ * @file cforge_tokens.hpp
+ * @brief The `${NAME}` tokens cforge expands in cforge.toml values
+ *
+ * `${PROJECT}` (or `${PROJECT_NAME}`), `${VERSION}`, `${TARGET}`, `${CONFIG}`,
+ * `${OS}` and `${ARCH}` work in [output] directories, package.output_dir,
+ * [env] values, include directories, defines, flags and links, and in
+ * pre_build and post_build hooks.
+ */
+
+#pragma once
+
+#include "core/types.h"
+
+#include <map>
+#include <string>
+#include <vector>
+
+namespace cforge {
+
+/**
+ * @brief Get the host architecture name used for ${ARCH} expansion
+ */
+inline std::string host_arch_name() {
+#if defined(__x86_64__) || defined(_M_X64)
+  return "x64";
+#elif defined(__aarch64__) || defined(_M_ARM64)
+  return "arm64";
+#elif defined(__i386__) || defined(_M_IX86)
+  return "x86";
+#elif defined(__arm__) || defined(_M_ARM)
+  return "arm";
+#else
+  return "unknown";
+#endif
+}
+
+/**
+ * @brief Build the token map for a project
+ *
+ * ${TARGET} is the project's CMake target, which is named after the
+ * project. An empty `config` leaves ${CONFIG} unexpanded, for values used
+ * where no configuration has been chosen.
+ *
+ * @param name project.name
+ * @param version project.version
+ * @param config Build configuration, or a CMake expression for it
+ * @param os Platform name, as in [platform.<os>]
+ * @param arch Architecture name, e.g. from host_arch_name()
+ * @return Token name (without `${}`) to value
+ */
+inline std::map<std::string, std::string> make_cforge_tokens(
+    const std::string &name,
+    const std::string &version,
+    const std::string &config,
+    const std::string &os,
+    const std::string &arch) {
+  std::map<std::string, std::string> tokens = {
+      {"PROJECT",      name   },
+      {"PROJECT_NAME", name   },
+      {"VERSION",      version},
+      {"TARGET",       name   },
+      {"OS",           os     },
+      {"ARCH",         arch   },
+  };
+  if (!config.empty()) {
+    tokens["CONFIG"] = config;
+  }
+  return tokens;
+}
+
+/**
+ * @brief Expand `${NAME}` tokens in a value from cforge.toml
+ *
+ * Each token is replaced once; replacements are not scanned again. Tokens
+ * not in `tokens` are kept as written, so CMake variables such as
+ * ${CMAKE_BINARY_DIR} pass through to the generated CMakeLists.txt.
+ *
+ * @param value Value as written in cforge.toml
+ * @param tokens Token name (without `${}`) to replacement
+ * @return Expanded value
+ */
+inline std::string expand_cforge_tokens(const std::string &value,
+                                        const std::map<std::string, std::string> &tokens) {
+  std::string result;
+  cforge_size_t pos = 0;
+  while (pos < value.size()) {
+    cforge_size_t start = value.find("${", pos);
+    cforge_size_t end   = start == std::string::npos ? start : value.find('}', start + 2);
+    if (end == std::string::npos) {
+      result += value.substr(pos);
+      break;
+    }
+    result += value.substr(pos, start - pos);
+    auto it = tokens.find(value.substr(start + 2, end - start - 2));
+    result += it != tokens.end() ? it->second : value.substr(start, end - start + 1);
+    pos = end + 1;
+  }
+  return result;
+}
+
+/**
+ * @brief Expand `${NAME}` tokens in each of a list of values
+ */
+inline std::vector<std::string> expand_cforge_tokens(
+    std::vector<std::string> values, const std::map<std::string, std::string> &tokens) {
+  for (auto &value : values) {
+    value = expand_cforge_tokens(value, tokens);
+  }
+  return values;
+}
+
+}  // namespace cforge
@@ -0,0 +1,45 @@
+/**
+ * @file cmake_file_api.hpp
+ * @brief Minimal CMake file API client used to locate build artifacts
+ *
+ * cforge writes a stateless `codemodel-v2` query into the build directory
+ * before configuring. CMake then emits a reply describing every target and
+ * the exact paths of the artifacts it produces, per configuration.
+ */
+
+#pragma once
+
+#include <filesystem>
+#include <optional>
+#include <string>
+#include <vector>
+
+namespace cforge {
+
+/// A target described by the CMake file API codemodel.
+struct cmake_target_info {
+  std::string name;                              // CMake target name
+  std::string type;                              // EXECUTABLE, STATIC_LIBRARY, ...
+  std::vector<std::filesystem::path> artifacts;  // Absolute artifact paths
+};
+
+/// Write the `codemodel-v2` query file so the next configure produces a reply.
+/// Returns false if the query directory could not be created.
+bool write_cmake_file_api_query(const std::filesystem::path &build_dir);
+
+/// Read the targets for a configuration from the latest file API reply.
+/// Returns std::nullopt when no usable reply exists (e.g. CMake < 3.14, or the
+/// build directory was configured before the query was written). An empty
+/// config matches the only configuration of a single-config generator.
+std::optional<std::vector<cmake_target_info>>
+read_cmake_file_api_targets(const std::filesystem::path &build_dir, const std::string &config);
+
+/// Locate the executable produced by `target_name` using the file API reply.
+/// Returns std::nullopt when there is no reply, so callers can fall back to
+/// searching the build tree. Returns an empty path (after printing the known
+/// executable targets) when the target is missing or is not an executable.
+std::optional<std::filesystem::path> find_cmake_executable(const std::filesystem::path &build_dir,
+                                                           const std::string &config,
+                                                           const std::string &target_name);
+
+}  // namespace cforge
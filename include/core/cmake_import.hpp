@@ -0,0 +1,286 @@
+/**
+ * @file cmake_import.hpp
+ * @brief Writing a cforge.toml from a parsed CMakeLists.txt
+ *
+ * Used by `cforge init --from-cmake` and `cforge migrate`. The parse is
+ * heuristic, so the manifest is a starting point: whatever can't be mapped
+ * with confidence is written as a `# TODO:` comment next to the closest
+ * setting, and listed in the report so the user knows what to review.
+ */
+
+#pragma once
+
+#include "core/cmake_parser.hpp"
+#include "core/types.h"
+
+#include <algorithm>
+#include <cctype>
+#include <sstream>
+#include <string>
+#include <utility>
+#include <vector>
+
+namespace cforge {
+
+/**
+ * @brief What an import translated, and what it left for the user
+ */
+struct cmake_import_report {
+  std::vector<std::pair<std::string, std::string>> translated;  ///< Setting, value
+  std::vector<std::string> untranslated;  ///< What was skipped or needs review
+};
+
+/**
+ * @brief Quote a string for TOML output
+ */
+inline std::string cmake_import_quote(const std::string &s) {
+  std::string quoted = "\"";
+  for (char c : s) {
+    if (c == '"' || c == '\\') {
+      quoted += '\\';
+    }
+    quoted += c;
+  }
+  return quoted + "\"";
+}
+
+/**
+ * @brief Format a TOML array of strings on one line
+ */
+inline std::string cmake_import_array(const std::vector<std::string> &items) {
+  std::string result = "[";
+  for (cforge_size_t i = 0; i < items.size(); ++i) {
+    result += (i > 0 ? ", " : "") + cmake_import_quote(items[i]);
+  }
+  return result + "]";
+}
+
+/**
+ * @brief The targets linked in CMakeLists.txt that come from a dependency
+ *
+ * Matches the way cmake_parser.cpp told dependency targets apart from other
+ * libraries: the target contains the dependency's name, ignoring case.
+ */
+inline std::vector<std::string> linked_dependency_targets(const cmake_parse_result &r,
+                                                          const std::string &name) {
+  auto lower = [](std::string s) {
+    std::transform(s.begin(), s.end(), s.begin(), [](unsigned char c) {
+      return static_cast<char>(std::tolower(c));
+    });
+    return s;
+  };
+  std::vector<std::string> targets;
+  for (const auto &target : r.dependency_targets) {
+    if (lower(target).find(lower(name)) != std::string::npos) {
+      targets.push_back(target);
+    }
+  }
+  return targets;
+}
+
+/**
+ * @brief Write a cforge.toml for the target parsed from a CMakeLists.txt
+ *
+ * find_package() calls become `[dependencies] system` entries, linking the
+ * targets CMakeLists.txt linked; FetchContent_Declare() calls become
+ * [dependencies.fetchcontent.<name>] tables. cforge builds one target per
+ * project, so targets after the first are only reported.
+ *
+ * @param r Parsed CMakeLists.txt
+ * @param report Receives what was and wasn't translated
+ * @return The manifest's content
+ */
+inline std::string generate_imported_manifest(const cmake_parse_result &r,
+                                              cmake_import_report &report) {
+  std::ostringstream out;
+  out << "# Imported from CMakeLists.txt. Settings cforge could not translate with\n";
+  out << "# confidence are marked TODO; review them before running 'cforge build'.\n\n";
+
+  // [project]
+  std::string name = r.project_name.empty() ? r.target_name : r.project_name;
+  out << "[project]\n";
+  if (name.empty()) {
+    out << "# TODO: no project() or target was found\n";
+    name = "unknown";
+  }
+  out << "name = " << cmake_import_quote(name) << "\n";
+  if (!r.version.empty()) {
+    out << "version = " << cmake_import_quote(r.version) << "\n";
+  }
+  if (!r.cpp_standard.empty()) {
+    out << "cpp_standard = " << cmake_import_quote(r.cpp_standard) << "\n";
+  }
+  if (!r.c_standard.empty()) {
+    out << "c_standard = " << cmake_import_quote(r.c_standard) << "\n";
+  }
+  if (!r.binary_type.empty()) {
+    out << "binary_type = " << cmake_import_quote(r.binary_type) << "\n";
+  }
+  out << "\n";
+  report.translated.emplace_back("project", name + (r.version.empty() ? "" : " " + r.version));
+  if (!r.target_name.empty()) {
+    report.translated.emplace_back("target", r.target_name + " (" + r.binary_type + ")");
+  }
+  for (const auto &target : r.other_targets) {
+    report.untranslated.push_back("target '" + target
+                                  + "' was not imported; a cforge project builds one target");
+  }
+
+  // [build]
+  std::vector<std::string> links, flags;
+  std::vector<std::string> uncertain_links;
+  for (const auto &lib : r.link_libraries) {
+    if (std::find(r.other_targets.begin(), r.other_targets.end(), lib) != r.other_targets.end()) {
+      report.untranslated.push_back("link to target '" + lib + "', which was not imported");
+    } else if (lib.find("::") != std::string::npos || lib.find('$') != std::string::npos) {
+      uncertain_links.push_back(lib);
+    } else {
+      links.push_back(lib);
+    }
+  }
+  for (const auto &option : r.compile_options) {
+    if (option.find('$') != std::string::npos) {
+      report.untranslated.push_back("compile option '" + option + "' uses a CMake expression");
+    } else {
+      flags.push_back(option);
+    }
+  }
+
+  out << "[build]\n";
+  if (!r.source_dirs.empty()) {
+    out << "source_dirs = " << cmake_import_array(r.source_dirs) << "\n";
+    report.translated.emplace_back("source_dirs", cmake_import_array(r.source_dirs));
+  }
+  if (!r.include_dirs.empty()) {
+    out << "include_dirs = " << cmake_import_array(r.include_dirs) << "\n";
+    report.translated.emplace_back("include_dirs", cmake_import_array(r.include_dirs));
+  }
+  if (!r.compile_definitions.empty()) {
+    out << "defines = " << cmake_import_array(r.compile_definitions) << "\n";
+    report.translated.emplace_back("defines", cmake_import_array(r.compile_definitions));
+  }
+  if (!links.empty()) {
+    out << "system_links = " << cmake_import_array(links) << "\n";
+    report.translated.emplace_back("system_links", cmake_import_array(links));
+  }
+  if (!uncertain_links.empty()) {
+    out << "# TODO: linked targets that don't come from a find_package() or\n";
+    out << "# FetchContent dependency; add the dependency that provides them\n";
+    out << "# system_links = " << cmake_import_array(uncertain_links) << "\n";
+    for (const auto &lib : uncertain_links) {
+      report.untranslated.push_back("link to '" + lib + "' has no known dependency");
+    }
+  }
+  out << "\n";
+
+  // Flags are set per compiler, and CMakeLists.txt doesn't say which
+  // compilers these were meant for
+  if (!flags.empty()) {
+    out << "# TODO: target_compile_options() set these flags; copy them to the\n";
+    out << "# [compiler.<name>] tables of the compilers that accept them, e.g.\n";
+    out << "# [compiler.gcc]\n";
+    out << "# flags = " << cmake_import_array(flags) << "\n\n";
+    std::string joined;
+    for (const auto &flag : flags) {
+      joined += (joined.empty() ? "" : " ") + flag;
+    }
+    report.untranslated.push_back("compile options " + joined
+                                  + " are left in a TODO; set them per compiler");
+  }
+
+  // [dependencies]
+  std::vector<const cmake_dependency *> packages, fetched;
+  for (const auto &dep : r.dependencies) {
+    if (dep.is_find_package) {
+      packages.push_back(&dep);
+    } else if (dep.is_fetch_content) {
+      fetched.push_back(&dep);
+    } else if (dep.is_subdirectory) {
+      report.untranslated.push_back("add_subdirectory(" + dep.name + ") was not imported");
+    }
+  }
+
+  if (!packages.empty()) {
+    out << "[dependencies]\n";
+    out << "# TODO: these are found with find_package() as before, so they must be\n";
+    out << "# installed; to have cforge install one, use 'cforge deps add <name> --vcpkg'\n";
+    std::vector<std::string> entries;
+    std::string names;
+    for (const auto *dep : packages) {
+      if (!dep->version.empty()) {
+        out << "# TODO: find_package(" << dep->name << " " << dep->version
+            << ") required a version, which system dependencies don't check\n";
+      }
+      std::vector<std::string> targets = linked_dependency_targets(r, dep->name);
+      bool default_target = targets.empty()
+                            || (targets.size() == 1 && targets[0] == dep->name + "::" + dep->name);
+      std::string entry;
+      if (!dep->components.empty()) {
+        entry = "{ name = " + cmake_import_quote(dep->name)
+                + ", components = " + cmake_import_array(dep->components) + " }";
+      } else if (default_target) {
+        entry = cmake_import_quote(dep->name);
+      } else {
+        std::string joined;
+        for (const auto &target : targets) {
+          joined += (joined.empty() ? "" : " ") + target;
+        }
+        entry = "{ name = " + cmake_import_quote(dep->name)
+                + ", target = " + cmake_import_quote(joined) + " }";
+      }
+      if (targets.empty() && dep->components.empty()) {
+        out << "# TODO: no target of " << dep->name
+            << " is linked in CMakeLists.txt; cforge links " << dep->name << "::" << dep->name
+            << "\n";
+        report.untranslated.push_back("find_package(" + dep->name
+                                      + ") has no linked target; check the one cforge links");
+      }
+      entries.push_back(entry);
+      names += (names.empty() ? "" : ", ") + dep->name;
+    }
+    out << "system = [\n";
+    for (const auto &entry : entries) {
+      out << "    " << entry << ",\n";
+    }
+    out << "]\n\n";
+    report.translated.emplace_back("find_package", names);
+  }
+
+  for (const auto *dep : fetched) {
+    out << "[dependencies.fetchcontent." << dep->name << "]\n";
+    if (dep->git_url.empty()) {
+      out << "# TODO: no GIT_REPOSITORY was found; set git, or url for an archive\n";
+      report.untranslated.push_back("FetchContent dependency '" + dep->name
+                                    + "' has no GIT_REPOSITORY");
+    } else {
+      out << "git = " << cmake_import_quote(dep->git_url) << "\n";
+    }
+    if (!dep->git_tag.empty()) {
+      out << "tag = " << cmake_import_quote(dep->git_tag) << "\n";
+    }
+    std::vector<std::string> targets = linked_dependency_targets(r, dep->name);
+    if (targets.empty()) {
+      out << "# TODO: set target_name to the target " << dep->name << " provides\n";
+      report.untranslated.push_back("FetchContent dependency '" + dep->name
+                                    + "' has no linked target");
+    } else {
+      if (targets.size() > 1) {
+        out << "# TODO: CMakeLists.txt also linked";
+        for (cforge_size_t i = 1; i < targets.size(); ++i) {
+          out << " " << targets[i];
+        }
+        out << "\n";
+      }
+      out << "target_name = " << cmake_import_quote(targets[0]) << "\n";
+    }
+    out << "\n";
+    report.translated.emplace_back("fetchcontent", dep->name);
+  }
+
+  for (const auto &warning : r.warnings) {
+    report.untranslated.push_back(warning);
+  }
+  return out.str();
+}
+
+}  // namespace cforge
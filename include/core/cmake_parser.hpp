@@ -12,13 +12,14 @@ namespace cforge {
 
 /// A single dependency extracted from CMakeLists.txt.
 struct cmake_dependency {
-  std::string name;       // Package/library name
-  std::string version;    // Version string, empty if not found
-  std::string git_url;    // GIT_REPOSITORY if from FetchContent
-  std::string git_tag;    // GIT_TAG if from FetchContent, empty if not found
-  bool is_fetch_content;  // True if from FetchContent_Declare
-  bool is_find_package;   // True if from find_package
-  bool is_subdirectory;   // True if from add_subdirectory
+  std::string name;                     // Package/library name
+  std::string version;                  // Version string, empty if not found
+  std::string git_url;                  // GIT_REPOSITORY if from FetchContent
+  std::string git_tag;                  // GIT_TAG if from FetchContent, empty if not found
+  std::vector<std::string> components;  // COMPONENTS of find_package
+  bool is_fetch_content = false;        // True if from FetchContent_Declare
+  bool is_find_package  = false;        // True if from find_package
+  bool is_subdirectory  = false;        // True if from add_subdirectory
 };
 
 /// Aggregated result of parsing one CMakeLists.txt file.
@@ -28,8 +29,10 @@ struct cmake_parse_result {
   std::string version;       // from project(... VERSION x.y.z ...)
   std::string cpp_standard;  // from set(CMAKE_CXX_STANDARD xx)
   std::string c_standard;    // from set(CMAKE_C_STANDARD xx)
-  std::string binary_type;   // "executable", "static", "shared", "interface"
+  std::string binary_type;   // "executable", "static_lib", "shared_lib", "header_only"
   std::string target_name;   // first target name from add_executable/add_library
+  std::vector<std::string> source_files;   // sources listed in target_name's add_* call
+  std::vector<std::string> other_targets;  // targets after target_name, not imported
 
   // [build]
   std::vector<std::string> source_dirs;   // deduced from file(GLOB...) or target_sources
@@ -42,6 +45,7 @@ struct cmake_parse_result {
   std::vector<std::string> compile_definitions;  // from target_compile_definitions
   std::vector<std::string> compile_options;      // from target_compile_options
   std::vector<std::string> link_libraries;       // from target_link_libraries (non-dep names)
+  std::vector<std::string> dependency_targets;   // from target_link_libraries (dep names)
 
   // Parse diagnostics — warnings to display to the user
   std::vector<std::string> warnings;
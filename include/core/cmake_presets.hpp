@@ -0,0 +1,241 @@
+/**
+ * @file cmake_presets.hpp
+ * @brief CMakePresets.json generation from cforge's configure arguments
+ *
+ * A configure preset is built from the same argument list `cforge build`
+ * passes to `cmake`, so an IDE or `cmake --preset` configures the tree with
+ * the cache values cforge would use. The file is written with sorted keys and
+ * a fixed layout so that it can be committed and diffs stay small.
+ */
+
+#pragma once
+
+#include "cforge/log.hpp"
+
+#include "core/types.h"
+
+#include <algorithm>
+#include <cctype>
+#include <map>
+#include <sstream>
+#include <string>
+#include <vector>
+
+namespace cforge {
+
+/**
+ * @brief A CMake cache entry, with its type if one was given
+ */
+struct cmake_cache_value {
+  std::string type;  ///< BOOL, STRING, ... or empty
+  std::string value;
+
+  bool operator==(const cmake_cache_value &other) const {
+    return type == other.type && value == other.value;
+  }
+};
+
+/**
+ * @brief One configure preset and the build/test presets that use it
+ */
+struct cmake_configure_preset {
+  std::string name;
+  std::string display_name;
+  std::string generator;
+  std::string binary_dir;
+  std::string architecture;   ///< Visual Studio platform (-A), empty otherwise
+  std::string toolset;        ///< Visual Studio toolset (-T), empty otherwise
+  std::string configuration;  ///< Configuration the build and test presets use
+  std::map<std::string, cmake_cache_value> cache_variables;
+};
+
+/**
+ * @brief Preset name for a build configuration
+ *
+ * @param config Configuration in any case ("Debug", "relwithdebinfo")
+ * @return Lower-case name, as used for [build.config.<name>]
+ */
+inline std::string cmake_preset_name(const std::string &config) {
+  std::string name = config;
+  std::transform(name.begin(), name.end(), name.begin(), [](unsigned char c) {
+    return static_cast<char>(std::tolower(c));
+  });
+  return name;
+}
+
+/**
+ * @brief Parse a `-DNAME=VALUE` or `-DNAME:TYPE=VALUE` argument
+ *
+ * @param arg Command line argument
+ * @param name Receives the variable name
+ * @param value Receives the value and type
+ * @return false if @p arg is not a cache definition
+ */
+inline bool parse_cmake_define_arg(const std::string &arg,
+                                   std::string &name,
+                                   cmake_cache_value &value) {
+  if (arg.size() < 3 || arg.compare(0, 2, "-D") != 0) {
+    return false;
+  }
+  cforge_size_t equals = arg.find('=');
+  if (equals == std::string::npos) {
+    return false;
+  }
+  name        = arg.substr(2, equals - 2);
+  value.value = arg.substr(equals + 1);
+  value.type.clear();
+  cforge_size_t colon = name.find(':');
+  if (colon != std::string::npos) {
+    value.type = name.substr(colon + 1);
+    name       = name.substr(0, colon);
+  }
+  return !name.empty();
+}
+
+/**
+ * @brief Build a configure preset from `cmake` configure arguments
+ *
+ * `-G`, `-A` and `-T` become the generator, architecture and toolset; every
+ * `-D` becomes a cache variable, a later definition replacing an earlier one
+ * as it does on the command line. `-S`, `-B` and other options are not part
+ * of a preset and are skipped.
+ *
+ * @param name Preset name
+ * @param configuration Configuration for the build and test presets
+ * @param cmake_args Arguments as passed to `cmake`
+ * @return The preset, without binary_dir and display_name
+ */
+inline cmake_configure_preset cmake_preset_from_args(const std::string &name,
+                                                     const std::string &configuration,
+                                                     const std::vector<std::string> &cmake_args) {
+  cmake_configure_preset preset;
+  preset.name          = name;
+  preset.configuration = configuration;
+  for (cforge_size_t i = 0; i < cmake_args.size(); ++i) {
+    const std::string &arg = cmake_args[i];
+    bool has_value         = i + 1 < cmake_args.size();
+    std::string variable;
+    cmake_cache_value value;
+    if (arg == "-G" && has_value) {
+      preset.generator = cmake_args[++i];
+    } else if (arg == "-A" && has_value) {
+      preset.architecture = cmake_args[++i];
+    } else if (arg == "-T" && has_value) {
+      preset.toolset = cmake_args[++i];
+    } else if ((arg == "-S" || arg == "-B") && has_value) {
+      ++i;
+    } else if (parse_cmake_define_arg(arg, variable, value)) {
+      preset.cache_variables[variable] = value;
+    }
+  }
+  return preset;
+}
+
+/**
+ * @brief The `-D` arguments equivalent to a preset's cache variables
+ *
+ * @param preset Configure preset
+ * @return Arguments sorted by variable name
+ */
+inline std::vector<std::string> cmake_args_from_preset(const cmake_configure_preset &preset) {
+  std::vector<std::string> args;
+  for (const auto &[name, value] : preset.cache_variables) {
+    std::string typed = value.type.empty() ? name : name + ":" + value.type;
+    args.push_back("-D" + typed + "=" + value.value);
+  }
+  return args;
+}
+
+/**
+ * @brief Generate CMakePresets.json
+ *
+ * Writes schema version 3 (CMake 3.21): one configure preset per entry, and
+ * a build and a test preset of the same name for its configuration. Keys are
+ * sorted, presets keep the given order. A `vendor.cforge` entry marks the
+ * file as generated (see is_cforge_generated_presets()).
+ *
+ * @param presets Configure presets
+ * @return File contents
+ */
+inline std::string generate_cmake_presets_json(const std::vector<cmake_configure_preset> &presets) {
+  auto str = logger::json_quote;
+  std::ostringstream out;
+
+  auto write_list = [&](const std::string &kind) {
+    out << "  " << str(kind) << ": [";
+    for (cforge_size_t i = 0; i < presets.size(); ++i) {
+      const cmake_configure_preset &preset = presets[i];
+      out << (i == 0 ? "\n" : ",\n") << "    {\n";
+      if (kind != "configurePresets") {
+        out << "      \"configuration\": " << str(preset.configuration) << ",\n";
+        out << "      \"configurePreset\": " << str(preset.name) << ",\n";
+        out << "      \"name\": " << str(preset.name);
+        if (kind == "testPresets") {
+          out << ",\n      \"output\": {\n        \"outputOnFailure\": true\n      }";
+        }
+        out << "\n    }";
+        continue;
+      }
+      if (!preset.architecture.empty()) {
+        out << "      \"architecture\": {\n";
+        out << "        \"strategy\": \"set\",\n";
+        out << "        \"value\": " << str(preset.architecture) << "\n";
+        out << "      },\n";
+      }
+      out << "      \"binaryDir\": " << str(preset.binary_dir) << ",\n";
+      out << "      \"cacheVariables\": {";
+      bool first = true;
+      for (const auto &[name, value] : preset.cache_variables) {
+        out << (first ? "\n" : ",\n") << "        " << str(name) << ": ";
+        if (value.type.empty()) {
+          out << str(value.value);
+        } else {
+          out << "{\n          \"type\": " << str(value.type) << ",\n";
+          out << "          \"value\": " << str(value.value) << "\n        }";
+        }
+        first = false;
+      }
+      out << (first ? "},\n" : "\n      },\n");
+      out << "      \"displayName\": " << str(preset.display_name) << ",\n";
+      out << "      \"generator\": " << str(preset.generator) << ",\n";
+      out << "      \"name\": " << str(preset.name);
+      if (!preset.toolset.empty()) {
+        out << ",\n      \"toolset\": {\n";
+        out << "        \"strategy\": \"set\",\n";
+        out << "        \"value\": " << str(preset.toolset) << "\n";
+        out << "      }";
+      }
+      out << "\n    }";
+    }
+    out << (presets.empty() ? "]" : "\n  ]");
+  };
+
+  out << "{\n";
+  write_list("buildPresets");
+  out << ",\n  \"cmakeMinimumRequired\": {\n";
+  out << "    \"major\": 3,\n    \"minor\": 21,\n    \"patch\": 0\n  },\n";
+  write_list("configurePresets");
+  out << ",\n";
+  write_list("testPresets");
+  out << ",\n  \"vendor\": {\n    \"cforge\": {\n      \"source\": \"cforge.toml\"\n    }\n  },\n";
+  out << "  \"version\": 3\n}\n";
+  return out.str();
+}
+
+/**
+ * @brief Check whether a CMakePresets.json was written by cforge
+ *
+ * @param json File contents
+ * @return true if the file has the marker generate_cmake_presets_json() writes
+ */
+inline bool is_cforge_generated_presets(const std::string &json) {
+  std::string compact;
+  for (char c : json) {
+    if (!std::isspace(static_cast<unsigned char>(c))) {
+      compact += c;
+    }
+  }
+  return compact.find("\"cforge\":{\"source\":\"cforge.toml\"}") != std::string::npos;
+}
+
+}  // namespace cforge
@@ -41,6 +41,10 @@ typedef struct {
   cforge_string_t *args;      // Additional arguments for the command
   cforge_int_t arg_count;     // Number of additional arguments
   cforge_string_t verbosity;  // Verbosity level (quiet, normal, verbose)
+  cforge_string_t build_dir;  // Optional base build directory override
+  cforge_string_t format;     // Output format (human, json)
+  cforge_string_t color;      // Color mode (auto, always, never)
+  bool offline;               // Never access the network (--offline)
 } cforge_command_args_t;
 
 /**
@@ -92,6 +96,18 @@ bool cforge_is_quiet(void);
  */
 bool cforge_is_verbose(void);
 
+/**
+ * @brief Enable or disable offline mode
+ * @details In offline mode cforge only uses dependencies, package indexes and
+ * tools that are already on disk, and never accesses the network.
+ */
+void cforge_set_offline(bool offline);
+
+/**
+ * @brief Check if offline mode is enabled (--offline or CFORGE_OFFLINE=1)
+ */
+bool cforge_is_offline(void);
+
 #ifdef __cplusplus
 }
 #endif
@@ -80,6 +80,14 @@ cforge_int_t cforge_cmd_run(const cforge_context_t *ctx);
  */
 cforge_int_t cforge_cmd_test(const cforge_context_t *ctx);
 
+/**
+ * @brief Handle the 'coverage' command to report test coverage
+ *
+ * @param ctx Context containing parsed arguments
+ * @return cforge_int_t Exit code (0 for success)
+ */
+cforge_int_t cforge_cmd_coverage(const cforge_context_t *ctx);
+
 /**
  * @brief Handle the 'add' command to add dependencies
  *
@@ -96,6 +104,22 @@ cforge_int_t cforge_cmd_add(const cforge_context_t *ctx);
  */
 cforge_int_t cforge_cmd_remove(const cforge_context_t *ctx);
 
+/**
+ * @brief Handle the 'remove' command to remove a project from a workspace
+ *
+ * @param ctx Context containing parsed arguments
+ * @return cforge_int_t Exit code (0 for success)
+ */
+cforge_int_t cforge_cmd_remove_project(const cforge_context_t *ctx);
+
+/**
+ * @brief Handle the 'set-startup' command to choose a workspace's startup project
+ *
+ * @param ctx Context containing parsed arguments
+ * @return cforge_int_t Exit code (0 for success)
+ */
+cforge_int_t cforge_cmd_set_startup(const cforge_context_t *ctx);
+
 /**
  * @brief Handle the 'vcpkg' command to manage dependencies via vcpkg
  *
@@ -152,6 +176,22 @@ cforge_int_t cforge_cmd_ide(const cforge_context_t *ctx);
  */
 cforge_int_t cforge_cmd_list(const cforge_context_t *ctx);
 
+/**
+ * @brief Handle the 'script' command to run a named script
+ *
+ * @param ctx Context containing parsed arguments
+ * @return cforge_int_t Exit code (0 for success)
+ */
+cforge_int_t cforge_cmd_script(const cforge_context_t *ctx);
+
+/**
+ * @brief Handle the 'fetch' command to download dependencies without building
+ *
+ * @param ctx Context containing parsed arguments
+ * @return cforge_int_t Exit code (0 for success)
+ */
+cforge_int_t cforge_cmd_fetch(const cforge_context_t *ctx);
+
 /**
  * @brief Handle the 'package' command to create distributable packages
  *
@@ -216,6 +256,14 @@ cforge_int_t cforge_cmd_doc(const cforge_context_t *ctx);
  */
 cforge_int_t cforge_cmd_tree(const cforge_context_t *ctx);
 
+/**
+ * @brief Handle the 'graph' command to visualize workspace project dependencies
+ *
+ * @param ctx Context containing parsed arguments
+ * @return cforge_int_t Exit code (0 for success, 1 if the graph is invalid)
+ */
+cforge_int_t cforge_cmd_graph(const cforge_context_t *ctx);
+
 /**
  * @brief Handle the 'new' command to create files from templates
  *
@@ -264,6 +312,14 @@ cforge_int_t cforge_cmd_circular(const cforge_context_t *ctx);
  */
 cforge_int_t cforge_cmd_doctor(const cforge_context_t *ctx);
 
+/**
+ * @brief Handle the 'check' command to validate cforge.toml without building
+ *
+ * @param ctx Context containing parsed arguments
+ * @return cforge_int_t Exit code (0 for success, 1 if a check failed)
+ */
+cforge_int_t cforge_cmd_check(const cforge_context_t *ctx);
+
 /**
  * @brief Handle the 'cache' command to manage binary cache
  *
@@ -288,6 +344,14 @@ cforge_int_t cforge_cmd_flash(const cforge_context_t *ctx);
  */
 cforge_int_t cforge_cmd_migrate(const cforge_context_t *ctx);
 
+/**
+ * @brief Handle the 'export-presets' command to write CMakePresets.json
+ *
+ * @param ctx Context containing parsed arguments
+ * @return cforge_int_t Exit code (0 for success)
+ */
+cforge_int_t cforge_cmd_export_presets(const cforge_context_t *ctx);
+
 /**
  * @brief Handle the 'hot' command for hot reload sessions
  *
@@ -310,4 +374,28 @@ cforge_int_t cforge_cmd_errors(const cforge_context_t *ctx);
  * @brief Handle the 'warnings' / 'warns' command — re-prints the warnings
  *        from the most recent build, using the same formatter.
  */
-cforge_int_t cforge_cmd_warnings(const cforge_context_t *ctx);
\ No newline at end of file
+cforge_int_t cforge_cmd_warnings(const cforge_context_t *ctx);
+
+/**
+ * @brief Handle the 'log' command — prints the raw output of the most recent
+ *        configure or build.
+ */
+cforge_int_t cforge_cmd_log(const cforge_context_t *ctx);
+
+/**
+ * @brief Handle the 'foreach' command — runs a command in every workspace
+ *        project, in dependency order.
+ */
+cforge_int_t cforge_cmd_foreach(const cforge_context_t *ctx);
+
+/**
+ * @brief Handle the 'uninstall' command — removes the files recorded by
+ *        'cforge install --prefix'.
+ */
+cforge_int_t cforge_cmd_uninstall(const cforge_context_t *ctx);
+
+/**
+ * @brief Handle the 'config' command — reads and edits cforge.toml values by
+ *        dotted path.
+ */
+cforge_int_t cforge_cmd_config(const cforge_context_t *ctx);
\ No newline at end of file
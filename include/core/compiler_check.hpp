@@ -0,0 +1,151 @@
+/**
+ * @file compiler_check.hpp
+ * @brief Identifying a C++ compiler and checking it against [project] min_compiler
+ *
+ * GCC and Clang print their version with `--version`; cl prints a banner
+ * with its version when run without arguments. The identity found before
+ * configure is recorded in the build directory, so a build with a different
+ * compiler starts from a fresh CMake cache instead of mixing object files.
+ */
+
+#pragma once
+
+#include "core/types.h"
+
+#include <algorithm>
+#include <cctype>
+#include <map>
+#include <regex>
+#include <sstream>
+#include <string>
+#include <vector>
+
+namespace cforge {
+
+/**
+ * @brief A compiler's family and version
+ */
+struct compiler_identity {
+  std::string family;   ///< "gcc", "clang", "apple_clang" or "msvc"; empty if unknown
+  std::string version;  ///< e.g. "12.3.0" or "19.36.32532"
+  std::string path;     ///< Command the compiler was run as
+
+  /**
+   * @brief Family and version, e.g. "gcc 12.3.0"
+   */
+  std::string label() const {
+    return family + " " + version;
+  }
+};
+
+/**
+ * @brief Identify a compiler from its `--version` output or cl banner
+ *
+ * @param output What the compiler printed, stdout and stderr together
+ * @return Identity without a path; empty family if the output isn't recognized
+ */
+inline compiler_identity parse_compiler_identity(const std::string &output) {
+  static const std::regex msvc(R"(Microsoft \(R\) C/C\+\+.* Version (\d+(\.\d+)+))");
+  static const std::regex clang(R"((Apple )?clang version (\d+(\.\d+)+))");
+  static const std::regex version(R"((\d+\.\d+(\.\d+)?))");
+
+  compiler_identity identity;
+  std::smatch match;
+  if (std::regex_search(output, match, msvc)) {
+    identity.family  = "msvc";
+    identity.version = match[1].str();
+  } else if (std::regex_search(output, match, clang)) {
+    identity.family  = match[1].matched ? "apple_clang" : "clang";
+    identity.version = match[2].str();
+  } else {
+    // GCC's first line ends with its version: "g++ (Ubuntu 12.3.0-1ubuntu1) 12.3.0"
+    std::string first_line = output.substr(0, output.find('\n'));
+    bool gcc               = output.find("Free Software Foundation") != std::string::npos;
+    for (const char *name : {"(GCC)", "g++", "gcc"}) {
+      gcc = gcc || first_line.find(name) != std::string::npos;
+    }
+    std::string last_version;
+    for (auto it = std::sregex_iterator(first_line.begin(), first_line.end(), version);
+         it != std::sregex_iterator();
+         ++it) {
+      last_version = (*it)[1].str();
+    }
+    if (gcc && !last_version.empty()) {
+      identity.family  = "gcc";
+      identity.version = last_version;
+    }
+  }
+  return identity;
+}
+
+/**
+ * @brief Compare dotted version numbers
+ *
+ * Missing components count as 0, so "12" and "12.0.0" are equal.
+ *
+ * @return true if `version` is `minimum` or newer
+ */
+inline bool compiler_version_at_least(const std::string &version, const std::string &minimum) {
+  auto parts = [](const std::string &text) {
+    std::vector<cforge_int_t> numbers;
+    std::stringstream ss(text);
+    std::string part;
+    while (std::getline(ss, part, '.')) {
+      cforge_size_t digits = 0;
+      while (digits < part.size() && std::isdigit(static_cast<unsigned char>(part[digits]))) {
+        ++digits;
+      }
+      numbers.push_back(digits == 0 ? 0 : std::stoi(part.substr(0, digits)));
+    }
+    return numbers;
+  };
+  std::vector<cforge_int_t> found    = parts(version);
+  std::vector<cforge_int_t> required = parts(minimum);
+  cforge_size_t count                = std::max(found.size(), required.size());
+  found.resize(count, 0);
+  required.resize(count, 0);
+  for (cforge_size_t i = 0; i < count; ++i) {
+    if (found[i] != required[i]) {
+      return found[i] > required[i];
+    }
+  }
+  return true;
+}
+
+/**
+ * @brief Minimum version [project] min_compiler sets for a compiler family
+ *
+ * Apple Clang is numbered differently from LLVM Clang, so it only has a
+ * minimum when min_compiler has an `apple_clang` entry.
+ *
+ * @param min_compiler The min_compiler table, family to version
+ * @param family Family from parse_compiler_identity()
+ * @return Minimum version, empty if the family is not constrained
+ */
+inline std::string required_compiler_version(
+    const std::map<std::string, std::string> &min_compiler,
+    const std::string &family) {
+  auto it = min_compiler.find(family);
+  return it == min_compiler.end() ? "" : it->second;
+}
+
+/**
+ * @brief Text recorded in the build directory for a compiler
+ */
+inline std::string compiler_stamp_text(const compiler_identity &identity) {
+  return identity.family + "\n" + identity.version + "\n" + identity.path + "\n";
+}
+
+/**
+ * @brief Read a compiler back from compiler_stamp_text()
+ */
+inline compiler_identity parse_compiler_stamp(const std::string &text) {
+  compiler_identity identity;
+  std::istringstream in(text);
+  std::getline(in, identity.family);
+  std::getline(in, identity.version);
+  std::getline(in, identity.path);
+  return identity;
+}
+
+}  // namespace cforge
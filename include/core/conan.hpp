@@ -0,0 +1,90 @@
+/**
+ * @file conan.hpp
+ * @brief Generated conanfile.txt and `conan install` arguments for Conan 1 and 2
+ *
+ * cforge writes the project's conan dependencies to `conan/conanfile.txt` in
+ * the build directory and installs them into the same folder. Conan 2 generates a
+ * `conan_toolchain.cmake` (CMakeToolchain) and `<pkg>-config.cmake` files
+ * (CMakeDeps); Conan 1 generates `Find<pkg>.cmake` modules
+ * (cmake_find_package) and a `conan_paths.cmake` that points CMake at them.
+ * Either way the build passes that file as CMAKE_TOOLCHAIN_FILE and the
+ * generated CMakeLists.txt finds each package with find_package().
+ */
+
+#pragma once
+
+#include "core/types.h"
+
+#include <filesystem>
+#include <regex>
+#include <string>
+#include <vector>
+
+namespace cforge {
+
+/**
+ * @brief Get the major version from `conan --version` output
+ *
+ * @param output e.g. "Conan version 2.0.17"
+ * @return Major version, or 0 if the output has no version
+ */
+inline cforge_int_t parse_conan_major_version(const std::string &output) {
+  static const std::regex version(R"((\d+)\.\d+)");
+  std::smatch match;
+  if (!std::regex_search(output, match, version)) {
+    return 0;
+  }
+  return static_cast<cforge_int_t>(std::stoi(match[1].str()));
+}
+
+/**
+ * @brief Generate the conanfile.txt for a project's conan dependencies
+ *
+ * @param major Conan major version; 1 uses the Conan 1 generators
+ * @param references Conan references in name/version form
+ */
+inline std::string generate_conanfile_txt(cforge_int_t major,
+                                          const std::vector<std::string> &references) {
+  std::string content = "# Generated by cforge from cforge.toml; do not edit\n";
+  content += "[requires]\n";
+  for (const auto &reference : references) {
+    content += reference + "\n";
+  }
+  content += "\n[generators]\n";
+  content += major == 1 ? "cmake_find_package\ncmake_paths\n" : "CMakeDeps\nCMakeToolchain\n";
+  return content;
+}
+
+/**
+ * @brief Arguments for `conan install` of a generated conanfile.txt
+ *
+ * @param major Conan major version
+ * @param conan_dir Folder with the conanfile.txt, which receives the generated files
+ * @param build_type CMake build type the packages are installed for, e.g. "Debug";
+ *        custom configurations use the profile's build type
+ */
+inline std::vector<std::string> conan_install_args(cforge_int_t major,
+                                                   const std::filesystem::path &conan_dir,
+                                                   const std::string &build_type) {
+  std::string dir               = conan_dir.string();
+  std::vector<std::string> args = {"install", dir};
+  args.push_back((major == 1 ? "--install-folder=" : "--output-folder=") + dir);
+  args.push_back("--build=missing");
+  if (build_type == "Debug" || build_type == "Release" || build_type == "RelWithDebInfo"
+      || build_type == "MinSizeRel") {
+    args.push_back("-s");
+    args.push_back("build_type=" + build_type);
+  }
+  return args;
+}
+
+/**
+ * @brief Name of the file conan generates for CMAKE_TOOLCHAIN_FILE
+ *
+ * @param major Conan major version
+ */
+inline std::string conan_toolchain_file_name(cforge_int_t major) {
+  return major == 1 ? "conan_paths.cmake" : "conan_toolchain.cmake";
+}
+
+}  // namespace cforge
@@ -0,0 +1,230 @@
+/**
+ * @file config_interpolation.hpp
+ * @brief Environment variable and `~` expansion in cforge.toml values
+ *
+ * String values may refer to the environment:
+ *
+ * - `${env:NAME}` must be set; loading the file fails otherwise.
+ * - `${env:NAME:-default}` and `${NAME:-default}` fall back to the default.
+ * - `${NAME}` and `$NAME` are expanded when NAME is set and left as written
+ *   otherwise, so CMake variables such as `${PROJECT_NAME}` and `$ORIGIN`
+ *   pass through to CMake unchanged.
+ * - A leading `~/` is the home directory.
+ *
+ * The tokens cforge expands itself (`${CONFIG}`, `${OS}`, `${ARCH}`,
+ * `${PROJECT}`, `${PROJECT_NAME}`, `${VERSION}`, `${TARGET}`; see
+ * cforge_tokens.hpp) are never taken from the environment, except through
+ * `${env:NAME}`.
+ *
+ * [scripts] and [hooks] hold shell commands, which the shell expands when
+ * they run, so they are left alone. So are `cmake.inject_before_target` and
+ * `cmake.inject_after_target`: they are CMake code, where `$NAME` and
+ * `${NAME}` belong to CMake.
+ */
+
+#pragma once
+
+#include "core/types.h"
+
+#include <toml++/toml.hpp>
+
+#include <algorithm>
+#include <cctype>
+#include <cstdlib>
+#include <functional>
+#include <optional>
+#include <string>
+#include <vector>
+
+namespace cforge {
+
+/**
+ * @brief Looks up an environment variable, nullopt if it is not set
+ */
+using env_lookup = std::function<std::optional<std::string>(const std::string &)>;
+
+/**
+ * @brief env_lookup for the environment of the cforge process
+ */
+inline std::optional<std::string> process_env_lookup(const std::string &name) {
+  cforge_cstring_t value = std::getenv(name.c_str());
+  if (value == nullptr) {
+    return std::nullopt;
+  }
+  return std::string(value);
+}
+
+/**
+ * @brief A `${env:NAME}` reference to an unset variable
+ */
+struct config_interpolation_error {
+  std::string key;       ///< Dotted key of the value, e.g. "build.cmake_args[0]"
+  std::string variable;  ///< The unset variable
+};
+
+/**
+ * @brief Check whether a name can be an environment variable reference
+ */
+inline bool is_env_reference_name(const std::string &name) {
+  if (name.empty() || !(std::isalpha(static_cast<unsigned char>(name[0])) || name[0] == '_')) {
+    return false;
+  }
+  for (char c : name) {
+    if (!std::isalnum(static_cast<unsigned char>(c)) && c != '_') {
+      return false;
+    }
+  }
+  return true;
+}
+
+/**
+ * @brief Check whether a name is a token cforge expands itself
+ */
+inline bool is_cforge_token_name(const std::string &name) {
+  static const std::vector<std::string> tokens = {
+      "CONFIG", "OS", "ARCH", "PROJECT", "PROJECT_NAME", "VERSION", "TARGET"};
+  return std::find(tokens.begin(), tokens.end(), name) != tokens.end();
+}
+
+/**
+ * @brief Expand the environment references and a leading `~` in a value
+ *
+ * @param value Value from cforge.toml
+ * @param env Environment lookup
+ * @param out Receives the expanded value
+ * @param missing Receives the variable of an unset `${env:NAME}`
+ * @return false if a required variable is not set
+ */
+inline bool interpolate_config_string(const std::string &value,
+                                      const env_lookup &env,
+                                      std::string &out,
+                                      std::string &missing) {
+  out.clear();
+  cforge_size_t i = 0;
+  if (value == "~" || value.rfind("~/", 0) == 0 || value.rfind("~\\", 0) == 0) {
+    std::optional<std::string> home = env("HOME");
+    if (!home) {
+      home = env("USERPROFILE");
+    }
+    if (home) {
+      out = *home;
+      i   = 1;
+    }
+  }
+
+  while (i < value.size()) {
+    if (value[i] != '$' || i + 1 == value.size()) {
+      out += value[i++];
+      continue;
+    }
+
+    if (value[i + 1] == '{') {
+      cforge_size_t close = value.find('}', i + 2);
+      if (close == std::string::npos) {
+        out += value.substr(i);
+        break;
+      }
+      std::string reference = value.substr(i, close - i + 1);
+      std::string name      = value.substr(i + 2, close - i - 2);
+      bool required         = name.rfind("env:", 0) == 0;
+      if (required) {
+        name = name.substr(4);
+      }
+      std::optional<std::string> fallback;
+      cforge_size_t separator = name.find(":-");
+      if (separator != std::string::npos) {
+        fallback = name.substr(separator + 2);
+        name     = name.substr(0, separator);
+      }
+      i = close + 1;
+
+      if (!is_env_reference_name(name) || (!required && is_cforge_token_name(name))) {
+        out += reference;
+        continue;
+      }
+      std::optional<std::string> resolved = env(name);
+      if (resolved) {
+        out += *resolved;
+      } else if (fallback) {
+        out += *fallback;
+      } else if (required) {
+        missing = name;
+        return false;
+      } else {
+        out += reference;
+      }
+      continue;
+    }
+
+    cforge_size_t end = i + 1;
+    while (end < value.size()
+           && (std::isalnum(static_cast<unsigned char>(value[end])) || value[end] == '_')) {
+      ++end;
+    }
+    std::string name = value.substr(i + 1, end - i - 1);
+    std::optional<std::string> resolved;
+    if (is_env_reference_name(name) && !is_cforge_token_name(name)) {
+      resolved = env(name);
+    }
+    out += resolved ? *resolved : value.substr(i, std::max<cforge_size_t>(end - i, 1));
+    i = std::max(end, i + 1);
+  }
+  return true;
+}
+
+/**
+ * @brief Expand the string values of a node and everything below it
+ *
+ * @param node Node, updated in place
+ * @param key Dotted key of @p node
+ * @param env Environment lookup
+ * @param errors Receives a reference to an unset variable per value
+ */
+inline void interpolate_config_node(toml::node &node,
+                                    const std::string &key,
+                                    const env_lookup &env,
+                                    std::vector<config_interpolation_error> &errors) {
+  // CMake code copied into CMakeLists.txt as written
+  if (key == "cmake.inject_before_target" || key == "cmake.inject_after_target") {
+    return;
+  }
+  if (auto *text = node.as_string()) {
+    std::string expanded;
+    std::string missing;
+    if (interpolate_config_string(text->get(), env, expanded, missing)) {
+      text->get() = expanded;
+    } else {
+      errors.push_back({key, missing});
+    }
+  } else if (auto *table = node.as_table()) {
+    for (auto &&[name, value] : *table) {
+      interpolate_config_node(value, key + "." + std::string(name.str()), env, errors);
+    }
+  } else if (auto *array = node.as_array()) {
+    for (cforge_size_t i = 0; i < array->size(); ++i) {
+      interpolate_config_node(*array->get(i), key + "[" + std::to_string(i) + "]", env, errors);
+    }
+  }
+}
+
+/**
+ * @brief Expand the string values of a cforge.toml, except [scripts], [hooks]
+ *        and the CMake code injected around targets
+ *
+ * @param config Parsed file, updated in place
+ * @param env Environment lookup
+ * @return One error per value that refers to an unset `${env:NAME}`
+ */
+inline std::vector<config_interpolation_error>
+interpolate_config_table(toml::table &config, const env_lookup &env = process_env_lookup) {
+  std::vector<config_interpolation_error> errors;
+  for (auto &&[name, value] : config) {
+    std::string key(name.str());
+    if (key != "scripts" && key != "hooks") {
+      interpolate_config_node(value, key, env, errors);
+    }
+  }
+  return errors;
+}
+
+}  // namespace cforge
@@ -0,0 +1,127 @@
+/**
+ * @file coverage.hpp
+ * @brief Compiler flags and report tools for `cforge coverage`
+ *
+ * GCC instruments with `--coverage` and is reported with gcovr; Clang uses
+ * source-based coverage (`-fprofile-instr-generate -fcoverage-mapping`),
+ * merged with llvm-profdata and reported with llvm-cov.
+ */
+
+#pragma once
+
+#include "core/types.h"
+
+#include <algorithm>
+#include <cctype>
+#include <string>
+#include <vector>
+
+namespace cforge {
+
+/**
+ * @brief How a compiler family instruments and reports coverage
+ */
+struct coverage_toolchain {
+  std::string flags;   ///< Compile and link flags, empty if unsupported
+  std::string report;  ///< "gcovr" or "llvm-cov"
+};
+
+/**
+ * @brief Compiler family of a compiler command
+ *
+ * @param compiler Compiler path or name, e.g. "/usr/bin/clang++-17", "g++"
+ * @param platform_default Family to assume for a generic `c++`/`cc` or empty name
+ * @return "clang", "gcc", "msvc" or @p platform_default
+ */
+inline std::string coverage_compiler_family(const std::string &compiler,
+                                            const std::string &platform_default) {
+  std::string name = compiler.substr(compiler.find_last_of("/\\") + 1);
+  std::transform(name.begin(), name.end(), name.begin(), [](unsigned char c) {
+    return static_cast<char>(std::tolower(c));
+  });
+  if (name.find("clang-cl") != std::string::npos || name == "cl" || name == "cl.exe") {
+    return "msvc";
+  }
+  if (name.find("clang") != std::string::npos) {
+    return "clang";
+  }
+  if (name.find("g++") != std::string::npos || name.find("gcc") != std::string::npos) {
+    return "gcc";
+  }
+  return platform_default;
+}
+
+/**
+ * @brief Coverage flags and report tool for a compiler family
+ *
+ * @param family "gcc", "clang" or another family
+ * @return Toolchain, with empty flags for families without coverage support
+ */
+inline coverage_toolchain coverage_toolchain_for(const std::string &family) {
+  if (family == "gcc") {
+    return {"--coverage", "gcovr"};
+  }
+  if (family == "clang") {
+    return {"-fprofile-instr-generate -fcoverage-mapping", "llvm-cov"};
+  }
+  return {"", ""};
+}
+
+/**
+ * @brief gcovr arguments for an HTML and a text report
+ *
+ * Run from the project directory, so the relative filters keep the report
+ * to the project's own sources.
+ *
+ * @param build_dir Build directory holding the .gcda files
+ * @param output_dir Report directory
+ * @param source_dirs Project source and include directories, relative
+ * @return Arguments for gcovr
+ */
+inline std::vector<std::string> gcovr_report_args(const std::string &build_dir,
+                                                  const std::string &output_dir,
+                                                  const std::vector<std::string> &source_dirs) {
+  std::vector<std::string> args = {"--root", ".", "--object-directory", build_dir};
+  for (const auto &dir : source_dirs) {
+    args.push_back("--filter");
+    args.push_back(dir + "/");
+  }
+  args.insert(args.end(),
+              {"--html-details",
+               output_dir + "/index.html",
+               "--txt",
+               output_dir + "/coverage.txt",
+               "--print-summary"});
+  return args;
+}
+
+/**
+ * @brief llvm-cov arguments for a report over some test executables
+ *
+ * @param command "show" (HTML) or "report" (text summary)
+ * @param executables Instrumented test executables, at least one
+ * @param profdata Merged profile
+ * @param output_dir Report directory, used by "show"
+ * @param ignore_regex Files to leave out of the report
+ * @return Arguments for llvm-cov
+ */
+inline std::vector<std::string> llvm_cov_report_args(const std::string &command,
+                                                     const std::vector<std::string> &executables,
+                                                     const std::string &profdata,
+                                                     const std::string &output_dir,
+                                                     const std::string &ignore_regex) {
+  std::vector<std::string> args = {command, executables.front()};
+  for (cforge_size_t i = 1; i < executables.size(); ++i) {
+    args.push_back("-object");
+    args.push_back(executables[i]);
+  }
+  args.push_back("-instr-profile=" + profdata);
+  args.push_back("-ignore-filename-regex=" + ignore_regex);
+  if (command == "show") {
+    args.push_back("-format=html");
+    args.push_back("-output-dir=" + output_dir);
+  }
+  return args;
+}
+
+}  // namespace cforge
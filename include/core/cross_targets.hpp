@@ -0,0 +1,180 @@
+/**
+ * @file cross_targets.hpp
+ * @brief Cross targets for `cforge build --profile`, and their toolchain files
+ *
+ * A [cross.targets.<name>] entry describes a toolchain by its compilers and
+ * target system, and cforge writes the CMake toolchain file for it. A few
+ * common targets are predefined, so they work without any configuration;
+ * a [cross.targets.<name>] entry of the same name overrides their fields.
+ * [cross.profile.<name>] and the default [cross] section are written to a
+ * toolchain file the same way, so SDK toolchains and vcpkg (through
+ * VCPKG_CHAINLOAD_TOOLCHAIN_FILE) see one CMAKE_TOOLCHAIN_FILE.
+ */
+
+#pragma once
+
+#include "core/types.h"
+
+#include <algorithm>
+#include <map>
+#include <optional>
+#include <sstream>
+#include <string>
+#include <vector>
+
+namespace cforge {
+
+/**
+ * @brief Fields of a cross target
+ */
+struct cross_target_settings {
+  std::string description;  ///< Shown by `cforge build --list-profiles`
+  std::string system;       ///< CMAKE_SYSTEM_NAME
+  std::string processor;    ///< CMAKE_SYSTEM_PROCESSOR
+  std::string sysroot;
+  std::string find_root;  ///< Defaults to the sysroot
+  std::string c;
+  std::string cxx;
+  std::string rc;         ///< Resource compiler, for Windows targets
+  std::string toolchain;  ///< SDK toolchain file the generated file includes
+  std::map<std::string, std::string> variables;
+};
+
+/**
+ * @brief Names of the predefined cross targets
+ */
+inline const std::vector<std::string> &predefined_cross_target_names() {
+  static const std::vector<std::string> names = {"windows-mingw", "android-arm64", "wasm"};
+  return names;
+}
+
+/**
+ * @brief Settings of a predefined cross target
+ *
+ * windows-mingw builds x86_64 Windows binaries with mingw-w64 (the
+ * gcc-mingw-w64 / mingw-w64-gcc packages of Linux distributions).
+ * android-arm64 uses the NDK's own toolchain file when the NDK location is
+ * known, and CMake's built-in Android support (which looks for the NDK
+ * itself) otherwise. wasm includes Emscripten's toolchain file when
+ * Emscripten is found and falls back to the emcc/em++ on PATH; its
+ * executables are `.js` files with a `.wasm` next to them, or `.html`
+ * pages with CFORGE_WASM_SUFFIX set to ".html".
+ *
+ * @param name Target name
+ * @param android_ndk Android NDK root, usually ANDROID_NDK_HOME; may be empty
+ * @param emscripten_root Directory holding emcc, e.g. $EMSDK/upstream/emscripten;
+ *        may be empty
+ * @return Settings, or nullopt if @p name is not predefined
+ */
+inline std::optional<cross_target_settings>
+get_predefined_cross_target(const std::string &name,
+                            const std::string &android_ndk     = "",
+                            const std::string &emscripten_root = "") {
+  if (name == "windows-mingw") {
+    cross_target_settings target;
+    target.description = "Windows x86_64 (MinGW-w64)";
+    target.system      = "Windows";
+    target.processor   = "x86_64";
+    target.find_root   = "/usr/x86_64-w64-mingw32";
+    target.c           = "x86_64-w64-mingw32-gcc";
+    target.cxx         = "x86_64-w64-mingw32-g++";
+    target.rc          = "x86_64-w64-mingw32-windres";
+    return target;
+  }
+  if (name == "android-arm64") {
+    cross_target_settings target;
+    target.description = "Android arm64-v8a (Android NDK)";
+    target.system      = "Android";
+    target.processor   = "aarch64";
+    if (!android_ndk.empty()) {
+      target.toolchain                     = android_ndk + "/build/cmake/android.toolchain.cmake";
+      target.variables["ANDROID_ABI"]      = "arm64-v8a";
+      target.variables["ANDROID_PLATFORM"] = "android-24";
+    } else {
+      target.variables["CMAKE_ANDROID_ARCH_ABI"] = "arm64-v8a";
+      target.variables["CMAKE_SYSTEM_VERSION"]   = "24";
+    }
+    return target;
+  }
+  if (name == "wasm") {
+    cross_target_settings target;
+    target.description = "WebAssembly (Emscripten)";
+    target.system      = "Emscripten";
+    target.processor   = "wasm32";
+    if (!emscripten_root.empty()) {
+      target.toolchain = emscripten_root + "/cmake/Modules/Platform/Emscripten.cmake";
+    } else {
+      target.c   = "emcc";
+      target.cxx = "em++";
+    }
+    target.variables["CFORGE_WASM_SUFFIX"] = ".js";
+    return target;
+  }
+  return std::nullopt;
+}
+
+/**
+ * @brief Generate the CMake toolchain file of a cross target
+ *
+ * With an SDK toolchain, the variables are set before it is included so the
+ * SDK toolchain can read them (ANDROID_ABI, for example), and the remaining
+ * settings are applied after it.
+ *
+ * @param name Target name, for the header comment
+ * @param target Target settings
+ * @return File contents
+ */
+inline std::string generate_cross_toolchain_file(const std::string &name,
+                                                 const cross_target_settings &target) {
+  // CMake wants forward slashes even on Windows
+  auto cmake_path = [](std::string path) {
+    std::replace(path.begin(), path.end(), '\\', '/');
+    return path;
+  };
+  std::string find_root = target.find_root.empty() ? target.sysroot : target.find_root;
+
+  auto write_variables = [&](std::ostringstream &out) {
+    for (const auto &[var_name, var_value] : target.variables) {
+      out << "set(" << var_name << " \"" << var_value << "\")\n";
+    }
+  };
+
+  std::ostringstream out;
+  out << "# Generated by cforge for cross target " << name << " - do not edit\n\n";
+  if (!target.toolchain.empty()) {
+    write_variables(out);
+    out << "include(\"" << cmake_path(target.toolchain) << "\")\n";
+  }
+  if (!target.system.empty()) {
+    out << "set(CMAKE_SYSTEM_NAME " << target.system << ")\n";
+  }
+  if (!target.processor.empty()) {
+    out << "set(CMAKE_SYSTEM_PROCESSOR " << target.processor << ")\n";
+  }
+  if (!target.sysroot.empty()) {
+    out << "set(CMAKE_SYSROOT \"" << cmake_path(target.sysroot) << "\")\n";
+  }
+  if (!target.c.empty()) {
+    out << "set(CMAKE_C_COMPILER \"" << cmake_path(target.c) << "\")\n";
+  }
+  if (!target.cxx.empty()) {
+    out << "set(CMAKE_CXX_COMPILER \"" << cmake_path(target.cxx) << "\")\n";
+  }
+  if (!target.rc.empty()) {
+    out << "set(CMAKE_RC_COMPILER \"" << cmake_path(target.rc) << "\")\n";
+  }
+  if (!find_root.empty()) {
+    out << "\nset(CMAKE_FIND_ROOT_PATH \"" << cmake_path(find_root) << "\")\n"
+        << "set(CMAKE_FIND_ROOT_PATH_MODE_PROGRAM NEVER)\n"
+        << "set(CMAKE_FIND_ROOT_PATH_MODE_LIBRARY ONLY)\n"
+        << "set(CMAKE_FIND_ROOT_PATH_MODE_INCLUDE ONLY)\n"
+        << "set(CMAKE_FIND_ROOT_PATH_MODE_PACKAGE ONLY)\n";
+  }
+  if (target.toolchain.empty() && !target.variables.empty()) {
+    out << "\n";
+    write_variables(out);
+  }
+  return out.str();
+}
+
+}  // namespace cforge
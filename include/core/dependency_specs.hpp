@@ -0,0 +1,94 @@
+/**
+ * @file dependency_specs.hpp
+ * @brief Checking the dependency specs given to `cforge add`
+ *
+ * `cforge add git <url>`, `cforge add vcpkg <name>[features]:<triplet>` and
+ * their flag forms are validated before cforge.toml is touched, so a typo is
+ * reported instead of being written to the manifest.
+ */
+
+#pragma once
+
+#include "core/types.h"
+
+#include <regex>
+#include <sstream>
+#include <string>
+#include <vector>
+
+namespace cforge {
+
+/**
+ * @brief Check whether a string looks like a git remote URL
+ *
+ * Accepts http(s)/ssh/git/file URLs and scp-like "user@host:path" remotes.
+ */
+inline bool is_valid_git_url(const std::string &url) {
+  static const std::regex scheme_url(R"(^(https?|ssh|git)://[^\s/]+/[^\s]+$)");
+  static const std::regex file_url(R"(^file://[^\s]+$)");
+  static const std::regex scp_url(R"(^[A-Za-z0-9._-]+@[A-Za-z0-9.-]+:[^\s]+$)");
+  return std::regex_match(url, scheme_url) || std::regex_match(url, file_url)
+      || std::regex_match(url, scp_url);
+}
+
+/**
+ * @brief Derive a dependency name from a git URL (last path segment without
+ * the .git suffix)
+ */
+inline std::string git_url_to_name(std::string url) {
+  while (!url.empty() && (url.back() == '/' || url.back() == '\\')) {
+    url.pop_back();
+  }
+  if (url.size() > 4 && url.compare(url.size() - 4, 4, ".git") == 0) {
+    url.resize(url.size() - 4);
+  }
+  cforge_size_t sep = url.find_last_of("/:");
+  return sep == std::string::npos ? url : url.substr(sep + 1);
+}
+
+/**
+ * @brief Split and validate a vcpkg package spec of the form
+ * name[feature,...][:triplet]
+ *
+ * @param spec Package spec from the command line
+ * @param name Receives the package name
+ * @param features Receives the optional feature list
+ * @param triplet Receives the optional triplet
+ * @return Empty string on success, otherwise a description of the problem
+ */
+inline std::string parse_vcpkg_spec(const std::string &spec,
+                                    std::string &name,
+                                    std::vector<std::string> &features,
+                                    std::string &triplet) {
+  static const std::regex spec_re(
+      R"(^([a-z0-9]+(?:-[a-z0-9]+)*)(?:\[([a-z0-9,-]*)\])?(?::(.*))?$)");
+  static const std::regex triplet_re(R"(^[a-z0-9_]+(?:-[a-z0-9_]+)+$)");
+
+  std::smatch match;
+  if (!std::regex_match(spec, match, spec_re)) {
+    return "'" + spec + "' is not a valid vcpkg package name (expected lowercase "
+           "letters, digits and dashes)";
+  }
+
+  name = match[1].str();
+  features.clear();
+  if (match[2].matched) {
+    std::stringstream ss(match[2].str());
+    std::string feat;
+    while (std::getline(ss, feat, ',')) {
+      if (!feat.empty()) {
+        features.push_back(feat);
+      }
+    }
+  }
+
+  triplet = match[3].matched ? match[3].str() : "";
+  if (match[3].matched && !std::regex_match(triplet, triplet_re)) {
+    return "'" + triplet + "' is not a valid vcpkg triplet (expected <arch>-<os>[-<linkage>], "
+           "e.g. x64-linux or x64-windows-static)";
+  }
+
+  return "";
+}
+
+}  // namespace cforge
@@ -0,0 +1,162 @@
+/**
+ * @file doctor_checks.hpp
+ * @brief Check results and cforge.toml validation used by `cforge doctor`
+ */
+
+#pragma once
+
+#include "core/manifest_schema.hpp"
+#include "core/types.h"
+
+#include <toml++/toml.hpp>
+
+#include <algorithm>
+#include <regex>
+#include <sstream>
+#include <string>
+#include <vector>
+
+namespace cforge {
+
+/**
+ * @brief Outcome of a single doctor check
+ */
+enum class check_status {
+  pass,  ///< Nothing to do
+  warn,  ///< Optional tool missing or something worth a look
+  fail,  ///< Builds will not work until this is fixed
+};
+
+/**
+ * @brief Result of a single doctor check
+ */
+struct doctor_check {
+  std::string name;    ///< What was checked, e.g. "CMake"
+  check_status status = check_status::pass;
+  std::string detail;  ///< Version, path or what went wrong
+  std::string hint;    ///< Remediation, shown for warnings and failures
+};
+
+/**
+ * @brief Lower-case name of a check status, as used in --json output
+ */
+inline const char *check_status_name(check_status status) {
+  switch (status) {
+    case check_status::pass:
+      return "pass";
+    case check_status::warn:
+      return "warn";
+    case check_status::fail:
+      return "fail";
+  }
+  return "pass";
+}
+
+/**
+ * @brief Compare dotted numeric versions
+ *
+ * Missing components count as zero and anything after the numeric part of
+ * a component (e.g. "-rc1") is ignored.
+ *
+ * @param version Version to test, e.g. "3.28.1"
+ * @param minimum Required version, e.g. "3.15"
+ * @return true if version >= minimum
+ */
+inline bool version_at_least(const std::string &version, const std::string &minimum) {
+  auto parts = [](const std::string &v) {
+    std::vector<cforge_int_t> result;
+    std::stringstream ss(v);
+    std::string part;
+    while (std::getline(ss, part, '.')) {
+      cforge_int_t value = 0;
+      for (char c : part) {
+        if (c < '0' || c > '9') {
+          break;
+        }
+        value = value * 10 + (c - '0');
+      }
+      result.push_back(value);
+    }
+    return result;
+  };
+
+  std::vector<cforge_int_t> have = parts(version);
+  std::vector<cforge_int_t> need = parts(minimum);
+  cforge_size_t count            = std::max(have.size(), need.size());
+  have.resize(count, 0);
+  need.resize(count, 0);
+  return have >= need;
+}
+
+/**
+ * @brief Extract the version from `cmake --version` output
+ *
+ * @param output e.g. "cmake version 3.28.1\n\nCMake suite maintained ..."
+ * @return "3.28.1", or empty if there is no "cmake version" line
+ */
+inline std::string parse_cmake_version(const std::string &output) {
+  static const std::regex version_regex(R"(cmake version (\d+(?:\.\d+)*))");
+  std::smatch match;
+  if (std::regex_search(output, match, version_regex)) {
+    return match[1].str();
+  }
+  return "";
+}
+
+/**
+ * @brief CMake version a project needs
+ *
+ * @param cforge_minimum The oldest CMake generated projects work with
+ * @param project_minimum cmake.version from cforge.toml, may be empty
+ * @return The higher of the two
+ */
+inline std::string required_cmake_version(const std::string &cforge_minimum,
+                                          const std::string &project_minimum) {
+  if (!project_minimum.empty() && version_at_least(project_minimum, cforge_minimum)) {
+    return project_minimum;
+  }
+  return cforge_minimum;
+}
+
+/**
+ * @brief Top-level tables cforge reads from a project's cforge.toml
+ */
+inline const std::vector<std::string> &known_manifest_tables() {
+  static const std::vector<std::string> tables = {
+      "bench", "benchmark", "benchmarks", "build", "cache", "cmake", "compiler", "cross",
+      "defaults", "dependencies", "env", "features", "hooks", "hot_reload", "linker", "lint",
+      "output", "package", "pch", "platform", "project", "scripts", "target", "test", "tests",
+      "variants", "vcpkg", "workspace",
+  };
+  return tables;
+}
+
+/**
+ * @brief Find top-level keys of cforge.toml that cforge does not read
+ *
+ * Each entry is the unknown key, followed by " (did you mean 'x'?)" when a
+ * known table is at most two edits away (fewer for short keys), so typos
+ * such as [dependancies] point at the intended table.
+ *
+ * @param config Parsed cforge.toml
+ * @return One message per unknown key, sorted by key
+ */
+inline std::vector<std::string> find_unknown_manifest_keys(const toml::table &config) {
+  std::vector<std::string> unknown;
+  const auto &known = known_manifest_tables();
+  for (const auto &[key, value] : config) {
+    std::string name(key.str());
+    if (std::find(known.begin(), known.end(), name) != known.end()) {
+      continue;
+    }
+    std::string message    = name;
+    std::string suggestion = closest_manifest_key(name, known);
+    if (!suggestion.empty()) {
+      message += " (did you mean '" + suggestion + "'?)";
+    }
+    unknown.push_back(message);
+  }
+  return unknown;
+}
+
+}  // namespace cforge
@@ -120,6 +120,16 @@ void print_diagnostic(const diagnostic &diagnostic);
  */
 std::string format_diagnostic_to_string(const diagnostic &diagnostic);
 
+/**
+ * @brief Format a diagnostic as a single-line JSON "diagnostic" event
+ *
+ * Used when the global `--format json` output mode is active.
+ *
+ * @param diagnostic The diagnostic to format
+ * @return std::string JSON object (no trailing newline)
+ */
+std::string format_diagnostic_to_json(const diagnostic &diagnostic);
+
 /**
  * @brief Extract diagnostics from compiler error output
  *
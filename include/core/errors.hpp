@@ -51,6 +51,7 @@ enum class error_code {
   CONFIG_INVALID_VALUE = 102,
   CONFIG_MISSING_FIELD = 103,
   CONFIG_INVALID_TOML  = 104,
+  CONFIG_UNKNOWN_KEY   = 105,
   WORKSPACE_NOT_FOUND  = 110,
   WORKSPACE_INVALID    = 111,
 
@@ -62,6 +63,7 @@ enum class error_code {
   DEP_BUILD_FAILED       = 204,
   DEP_INVALID_SPEC       = 205,
   DEP_CIRCULAR           = 206,
+  DEP_INTEGRITY_MISMATCH = 207,
   REGISTRY_UPDATE_FAILED = 210,
   REGISTRY_OFFLINE       = 211,
 
@@ -74,6 +76,7 @@ enum class error_code {
   BUILD_GENERATOR_NOT_FOUND = 305,
   BUILD_TARGET_NOT_FOUND    = 306,
   BUILD_BINARY_NOT_FOUND    = 307,
+  BUILD_COMPILER_TOO_OLD    = 308,
 
   // Cache errors (400-499)
   CACHE_NOT_FOUND            = 400,
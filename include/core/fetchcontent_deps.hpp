@@ -0,0 +1,108 @@
+/**
+ * @file fetchcontent_deps.hpp
+ * @brief CMake for FetchContent dependencies ([dependencies.fetchcontent.<name>])
+ *
+ * Unlike git dependencies, which are cloned into deps/, these are left to
+ * FetchContent entirely: they are downloaded into `<build>/_deps` during
+ * configure, or by `cforge fetch` ahead of an offline build. Their `options`
+ * become cache variables set before FetchContent_MakeAvailable(), so a
+ * library's tests or optional features can be turned off before its
+ * CMakeLists.txt runs.
+ */
+
+#pragma once
+
+#include "core/types.h"
+
+#include <cctype>
+#include <map>
+#include <string>
+
+namespace cforge {
+
+/**
+ * @brief A [dependencies.fetchcontent.<name>] entry
+ */
+struct fetchcontent_dependency {
+  std::string name;
+  std::string git;          ///< Repository URL; empty for an archive download
+  std::string tag;          ///< Tag, branch or commit of `git`
+  std::string url;          ///< Archive URL, used when `git` is empty
+  std::string sha256;       ///< Expected hash of the archive
+  std::string target_name;  ///< Target to link, empty to link nothing
+  std::map<std::string, std::string> options;  ///< Cache variables for the dependency
+};
+
+/**
+ * @brief Check whether a git ref is a full commit SHA
+ *
+ * A commit can't be cloned shallowly by name, so it needs full history.
+ */
+inline bool is_git_commit_sha(const std::string &ref) {
+  if (ref.size() != 40) {
+    return false;
+  }
+  for (char c : ref) {
+    if (!std::isxdigit(static_cast<unsigned char>(c))) {
+      return false;
+    }
+  }
+  return true;
+}
+
+/**
+ * @brief `set(... CACHE ... FORCE)` for a dependency option
+ *
+ * ON/OFF style values are cache BOOLs; anything else is a quoted STRING.
+ */
+inline std::string fetchcontent_option_cmake(const std::string &key, const std::string &value) {
+  std::string upper;
+  for (char c : value) {
+    upper += static_cast<char>(std::toupper(static_cast<unsigned char>(c)));
+  }
+  if (upper == "ON" || upper == "OFF" || upper == "TRUE" || upper == "FALSE" || upper == "YES"
+      || upper == "NO") {
+    return "set(" + key + " " + upper + " CACHE BOOL \"\" FORCE)\n";
+  }
+  std::string quoted;
+  for (char c : value) {
+    if (c == '"' || c == '\\') {
+      quoted += '\\';
+    }
+    quoted += c;
+  }
+  return "set(" + key + " \"" + quoted + "\" CACHE STRING \"\" FORCE)\n";
+}
+
+/**
+ * @brief Declare a FetchContent dependency, set its options and add it
+ *
+ * @param dep The dependency; `git` or `url` must be set
+ * @return CMake code, ending with FetchContent_MakeAvailable()
+ */
+inline std::string generate_fetchcontent_dependency_cmake(const fetchcontent_dependency &dep) {
+  std::string cmake = "# " + dep.name + " dependency\n";
+  cmake += "FetchContent_Declare(" + dep.name + "\n";
+  if (!dep.git.empty()) {
+    cmake += "    GIT_REPOSITORY " + dep.git + "\n";
+    if (!dep.tag.empty()) {
+      cmake += "    GIT_TAG " + dep.tag + "\n";
+    }
+    if (!is_git_commit_sha(dep.tag)) {
+      cmake += "    GIT_SHALLOW 1\n";
+    }
+  } else {
+    cmake += "    URL \"" + dep.url + "\"\n";
+    if (!dep.sha256.empty()) {
+      cmake += "    URL_HASH SHA256=" + dep.sha256 + "\n";
+    }
+  }
+  cmake += ")\n";
+  for (const auto &[key, value] : dep.options) {
+    cmake += fetchcontent_option_cmake(key, value);
+  }
+  cmake += "FetchContent_MakeAvailable(" + dep.name + ")\n\n";
+  return cmake;
+}
+
+}  // namespace cforge
@@ -56,6 +56,29 @@ inline bool is_git_repository(const std::filesystem::path &dir) {
   return std::filesystem::exists(dir / ".git");
 }
 
+/**
+ * @brief Check whether git failed because it needed credentials it could not
+ *        ask for
+ *
+ * Under an interrupt_guard git runs without a terminal prompt, so a private
+ * repository fails with one of these messages instead of waiting for input.
+ *
+ * @param output git's stderr
+ */
+inline bool is_git_authentication_error(const std::string &output) {
+  for (const char *message : {"terminal prompts disabled",
+                              "could not read Username",
+                              "could not read Password",
+                              "Authentication failed",
+                              "Permission denied (publickey",
+                              "Host key verification failed"}) {
+    if (output.find(message) != std::string::npos) {
+      return true;
+    }
+  }
+  return false;
+}
+
 /**
  * @brief Execute a Git command
  *
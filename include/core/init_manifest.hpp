@@ -0,0 +1,337 @@
+/**
+ * @file init_manifest.hpp
+ * @brief The cforge.toml written by `cforge init`
+ *
+ * Kept apart from command_init.cpp so the generated manifest can be checked
+ * against the keys cforge reads.
+ */
+
+#pragma once
+
+#include <ostream>
+#include <string>
+
+namespace cforge {
+
+/**
+ * @brief Write the cforge.toml for a new project
+ *
+ * @param config Stream to write to
+ * @param project_name Project name (normalized with underscores)
+ * @param cpp_version C++ standard version (e.g., "17")
+ * @param template_name Init template, e.g. "executable" or "embedded"
+ * @param with_tests Whether [test] is enabled
+ */
+inline void write_init_manifest(std::ostream &config,
+                                const std::string &project_name,
+                                const std::string &cpp_version,
+                                const std::string &template_name,
+                                bool with_tests) {
+  config << "# Project configuration for " << project_name << "\n\n";
+
+  // Embedded template generates a completely different cforge.toml
+  if (template_name == "embedded" || template_name == "bare-metal"
+      || template_name == "bare_metal") {
+    config << "[project]\n";
+    config << "name = \"" << project_name << "\"\n";
+    config << "version = \"0.1.0\"\n";
+    config << "description = \"An embedded project created with cforge\"\n";
+    config << "binary_type = \"executable\"\n";
+    config << "c_standard = \"99\"\n";
+    config << "c_extensions = true         # Enable GNU extensions (e.g., "
+              "gnu99)\n";
+    config << "languages = [\"C\", \"ASM\"]   # Enable C and assembly support\n";
+    config << "authors = [\"Your Name <your.email@example.com>\"]\n";
+    config << "license = \"MIT\"\n\n";
+
+    config << "[build]\n";
+    config << "build_type = \"Release\"\n";
+    config << "directory = \"build\"\n";
+    config << "source_dirs = [\"src\"]\n";
+    config << "include_dirs = [\"include\"]\n";
+    config << "export_compile_commands = true\n";
+    config << "# Add hardware-specific defines here\n";
+    config << "# defines = [\"F_CPU=16000000UL\", \"MY_MCU\"]\n\n";
+
+    config << "[build.config.release]\n";
+    config << "optimize = \"size\"          # Optimize for code size\n";
+    config << "warnings = \"all\"\n";
+    config << "defines = [\"NDEBUG\"]\n\n";
+
+    config << "[build.config.debug]\n";
+    config << "optimize = \"debug\"\n";
+    config << "debug_info = true\n";
+    config << "warnings = \"all\"\n";
+    config << "defines = [\"DEBUG=1\"]\n\n";
+
+    config << "# Compiler flags (adjust for your target MCU)\n";
+    config << "[compiler.gcc]\n";
+    config << "# flags = [\"-mmcu=YOUR_MCU\", \"-funsigned-char\", "
+              "\"-ffunction-sections\", \"-fdata-sections\"]\n\n";
+
+    config << "# Linker configuration\n";
+    config << "[linker]\n";
+    config << "# scripts = [\"link/linker.ld\"]   # Custom linker script\n";
+    config << "# flags = [\"-mmcu=YOUR_MCU\"]     # MCU-specific linker flags\n";
+    config << "dead_code_strip = true              # Remove unused code "
+              "sections\n";
+    config << "map_file = true                     # Generate memory map file\n\n";
+
+    config << "# Cross-compilation profile (configure for your target)\n";
+    config << "[cross.profile.target]\n";
+    config << "system = \"Generic\"                # Bare-metal target\n";
+    config << "# processor = \"avr\"              # Target processor "
+              "architecture\n";
+    config << "# compilers = { c = \"avr-gcc\", cxx = \"avr-g++\" }\n";
+    config << "# variables = { CMAKE_ASM_COMPILER = \"avr-gcc\" }\n";
+    config << "nostdlib = true                     # No standard library\n";
+    config << "nostartfiles = true                 # No default startup code\n";
+    config << "nodefaultlibs = true                # No default libraries\n";
+    config << "\n";
+    config << "# Post-build commands (e.g., generate .hex from .elf)\n";
+    config << "# post_build = [\n";
+    config << "#     \"avr-objcopy -R .eeprom -O ihex "
+              "$<TARGET_FILE:${PROJECT_NAME}> "
+              "$<TARGET_FILE_DIR:${PROJECT_NAME}>/${PROJECT_NAME}.hex\",\n";
+    config << "#     \"avr-size --mcu=YOUR_MCU -C "
+              "$<TARGET_FILE:${PROJECT_NAME}>\"\n";
+    config << "# ]\n";
+    config << "\n";
+    config << "# Flash/upload command\n";
+    config << "# flash = \"avrdude -c arduino -p YOUR_MCU -P /dev/ttyUSB0 -b "
+              "115200 -D -U "
+              "flash:w:$<TARGET_FILE_DIR:${PROJECT_NAME}>/${PROJECT_NAME}.hex\"\n";
+
+    return;
+  }
+
+  // Standard (non-embedded) project header
+  config << "[project]\n";
+  config << "name = \"" << project_name << "\"\n";
+  config << "version = \"0.1.0\"\n";
+  config << "description = \"A C++ project created with cforge\"\n";
+  config << "cpp_standard = \"" << cpp_version << "\"\n";
+  config << "c_standard = \"11\"\n";
+
+  if (template_name == "executable" || template_name == "app"
+      || template_name == "application") {
+    config << "binary_type = \"executable\"  # executable, shared_lib, "
+              "static_lib, or header_only\n";
+  } else if (template_name == "shared-library" || template_name == "shared_library") {
+    config << "binary_type = \"shared_lib\"  # executable, shared_lib, "
+              "static_lib, or header_only\n";
+  } else if (template_name == "header-only" || template_name == "header_only") {
+    config << "binary_type = \"header_only\"  # executable, shared_lib, "
+              "static_lib, or header_only\n";
+  } else {
+    config << "binary_type = \"static_lib\"  # executable, shared_lib, "
+              "static_lib, or header_only\n";
+  }
+
+  config << "authors = [\"Your Name <your.email@example.com>\"]\n";
+  config << "homepage = \"https://github.com/yourusername/" << project_name << "\"\n";
+  config << "repository = \"https://github.com/yourusername/" << project_name << ".git\"\n";
+  config << "license = \"MIT\"\n\n";
+
+  config << "[build]\n";
+  config << "build_type = \"Debug\"  # Debug, Release, RelWithDebInfo, "
+            "MinSizeRel\n";
+  config << "directory = \"build\"\n";
+  config << "source_dirs = [\"src\"]\n";
+  config << "include_dirs = [\"include\"]\n";
+  config << "export_compile_commands = true  # Generate compile_commands.json "
+            "for IDEs\n";
+  config << "# position_independent_code = true  # For shared libraries\n";
+  if (template_name == "shared-library" || template_name == "shared_library") {
+    config << "export_all_symbols = false  # Export only symbols marked with the "
+              "export macro\n";
+  }
+  config << "# Uncomment to specify custom source patterns\n";
+  config << "# source_patterns = [\"src/*.cpp\", \"src/**/*.cpp\"]\n";
+  config << "# Uncomment to specify individual source files\n";
+  config << "# source_files = [\"src/main.cpp\", \"src/example.cpp\"]\n\n";
+
+  // Add build configuration for different build types
+  // Using portable options that work across MSVC, GCC, and Clang
+  config << "[build.config.debug]\n";
+  config << "optimize = \"debug\"      # Portable: maps to /Od (MSVC) or -Og "
+            "(GCC/Clang)\n";
+  config << "debug_info = true        # Portable: maps to /Zi (MSVC) or -g "
+            "(GCC/Clang)\n";
+  config << "warnings = \"all\"        # Portable: maps to /W4 (MSVC) or -Wall "
+            "-Wextra (GCC/Clang)\n";
+  config << "defines = [\"DEBUG=1\"]\n";
+  config << "# sanitizers = [\"address\"]  # Enable AddressSanitizer for debug "
+            "builds\n\n";
+
+  config << "[build.config.release]\n";
+  config << "optimize = \"speed\"      # Portable: maps to /O2 (MSVC) or -O2 "
+            "(GCC/Clang)\n";
+  config << "warnings = \"all\"        # Portable: maps to /W4 (MSVC) or -Wall "
+            "-Wextra (GCC/Clang)\n";
+  config << "lto = true              # Link-time optimization: /GL+/LTCG "
+            "(MSVC) or -flto (GCC/Clang)\n";
+  config << "defines = [\"NDEBUG\"]\n\n";
+
+  config << "[build.config.relwithdebinfo]\n";
+  config << "optimize = \"speed\"\n";
+  config << "debug_info = true\n";
+  config << "warnings = \"all\"\n";
+  config << "defines = [\"NDEBUG\"]\n\n";
+
+  config << "[build.config.minsizerel]\n";
+  config << "optimize = \"size\"       # Portable: maps to /O1 /Os (MSVC) or "
+            "-Os (GCC/Clang)\n";
+  config << "defines = [\"NDEBUG\"]\n\n";
+
+  config << "[test]\n";
+  config << "enabled = " << (with_tests ? "true" : "false") << "\n";
+
+  config << "[package]\n";
+  config << "enabled = true\n";
+  config << "generators = []  # Package generators\n";
+  config << "# Windows generators: ZIP, NSIS\n";
+  config << "# Linux generators: TGZ, DEB, RPM\n";
+  config << "# macOS generators: TGZ\n";
+  config << "vendor = \"Your Organization\"\n";
+  config << "contact = \"Your Name <your.email@example.com>\"\n\n";
+
+  // Dependencies section
+  config << "# Dependencies section\n";
+  config << "# [dependencies]\n\n";
+
+  config << "# Git dependencies\n";
+  config << "# [dependencies.git.json]\n";
+  config << "# url = \"https://github.com/nlohmann/json.git\"\n";
+  config << "# tag = \"v3.11.2\"\n";
+  config << "# link = true\n";
+  config << "# include = true\n\n";
+
+  config << "# vcpkg dependencies\n";
+  config << "# [dependencies.vcpkg]\n";
+  config << "# path = \"C:/vcpkg\"  # Optional: path to vcpkg\n";
+  config << "# triplet = \"x64-windows\"  # Optional: vcpkg triplet\n";
+  config << "#\n";
+  config << "# [dependencies.vcpkg.fmt]\n";
+  config << "# version = \"9.1.0\"\n";
+  config << "#\n";
+  config << "# [dependencies.vcpkg.curl]\n";
+  config << "# version = \"7.80.0\"\n";
+  config << "# features = [\"ssl\", \"http2\"]\n";
+  config << "# target_name = \"CURL::libcurl\"\n\n";
+
+  config << "# System dependencies (find_package, pkg_config, or manual)\n";
+  config << "# [dependencies.system.OpenGL]\n";
+  config << "# method = \"find_package\"  # find_package, pkg_config, or manual\n";
+  config << "# required = true\n";
+  config << "# components = [\"GL\", \"GLU\"]\n";
+  config << "#\n";
+  config << "# [dependencies.system.custom_lib]\n";
+  config << "# method = \"manual\"\n";
+  config << "# include_dirs = [\"/usr/local/include/custom\"]\n";
+  config << "# library_dirs = [\"/usr/local/lib\"]\n";
+  config << "# libraries = [\"custom\"]\n";
+  config << "# platforms = [\"linux\", \"macos\"]  # Optional: limit to "
+            "platforms\n\n";
+
+  config << "# Subdirectory dependencies (for existing CMake projects)\n";
+  config << "# [dependencies.subdirectory.spdlog]\n";
+  config << "# path = \"extern/spdlog\"\n";
+  config << "# target = \"spdlog::spdlog\"\n";
+  config << "# options = { SPDLOG_BUILD_TESTS = \"OFF\" }\n\n";
+
+  // Platform-specific configuration
+  config << "# Platform-specific configuration\n";
+  config << "# Portable options: optimize, warnings, debug_info, sanitizers, "
+            "lto,\n";
+  config << "#                   exceptions, rtti, hardening, visibility\n";
+  config << "# [platform.windows]\n";
+  config << "# hardening = \"full\"      # Portable: /GS /sdl /GUARD:CF (MSVC)\n";
+  config << "# defines = [\"WIN32\", \"_WINDOWS\"]\n";
+  config << "# links = [\"kernel32\", \"user32\"]\n";
+  config << "#\n";
+  config << "# [platform.linux]\n";
+  config << "# hardening = \"basic\"     # Portable: -fstack-protector-strong "
+            "-D_FORTIFY_SOURCE=2\n";
+  config << "# defines = [\"LINUX\"]\n";
+  config << "# links = [\"pthread\", \"dl\"]\n";
+  config << "#\n";
+  config << "# [platform.macos]\n";
+  config << "# stdlib = \"libc++\"       # Portable: use libc++ on macOS\n";
+  config << "# defines = [\"MACOS\"]\n";
+  config << "# frameworks = [\"Cocoa\", \"IOKit\"]\n\n";
+
+  // Compiler-specific configuration
+  config << "# Compiler-specific configuration\n";
+  config << "# Portable options work here too - they translate to the right "
+            "flags\n";
+  config << "# [compiler.msvc]\n";
+  config << "# warnings = \"strict\"     # Portable: /W4 /WX\n";
+  config << "# defines = [\"_CRT_SECURE_NO_WARNINGS\"]\n";
+  config << "#\n";
+  config << "# [compiler.gcc]\n";
+  config << "# warnings = \"pedantic\"   # Portable: -Wall -Wextra -Wpedantic "
+            "-Werror\n";
+  config << "#\n";
+  config << "# [compiler.clang]\n";
+  config << "# warnings = \"pedantic\"   # Portable: -Wall -Wextra -Wpedantic "
+            "-Werror\n";
+  config << "#\n";
+  config << "# [compiler.mingw]\n";
+  config << "# warnings = \"all\"        # Portable: -Wall -Wextra\n";
+  config << "# defines = [\"MINGW\"]\n\n";
+
+  // Platform + Compiler combination
+  config << "# Platform + Compiler combination\n";
+  config << "# [platform.windows.compiler.msvc]\n";
+  config << "# flags = [\"/W4\"]\n";
+  config << "# defines = [\"_CRT_SECURE_NO_WARNINGS\"]\n\n";
+
+  // Cross-compilation
+  config << "# Cross-compilation (optional)\n";
+  config << "# [cross]\n";
+  config << "# enabled = true\n";
+  config << "#\n";
+  config << "# [cross.target]\n";
+  config << "# system = \"Linux\"           # CMAKE_SYSTEM_NAME\n";
+  config << "# processor = \"aarch64\"      # CMAKE_SYSTEM_PROCESSOR\n";
+  config << "# toolchain = \"path/to/toolchain.cmake\"  # Optional\n";
+  config << "#\n";
+  config << "# [cross.compilers]\n";
+  config << "# c = \"/usr/bin/aarch64-linux-gnu-gcc\"\n";
+  config << "# cxx = \"/usr/bin/aarch64-linux-gnu-g++\"\n";
+  config << "#\n";
+  config << "# [cross.paths]\n";
+  config << "# sysroot = \"/path/to/sysroot\"\n";
+  config << "# find_root = \"/path/to/find/root\"\n";
+  config << "#\n";
+  config << "# [cross.variables]  # Custom CMake variables\n";
+  config << "# ANDROID_ABI = \"arm64-v8a\"\n";
+  config << "#\n";
+  config << "# Cross-compilation profiles (use with: cforge build --profile "
+            "<name>)\n";
+  config << "# [cross.profile.android-arm64]\n";
+  config << "# system = \"Android\"\n";
+  config << "# processor = \"aarch64\"\n";
+  config << "# toolchain = "
+            "\"${ANDROID_NDK}/build/cmake/android.toolchain.cmake\"\n";
+  config << "# variables = { ANDROID_ABI = \"arm64-v8a\", ANDROID_PLATFORM = "
+            "\"android-24\" }\n";
+  config << "#\n";
+  config << "# [cross.profile.raspberry-pi]\n";
+  config << "# system = \"Linux\"\n";
+  config << "# processor = \"armv7l\"\n";
+  config << "# compilers = { c = \"arm-linux-gnueabihf-gcc\", cxx = "
+            "\"arm-linux-gnueabihf-g++\" }\n";
+  config << "# sysroot = \"/path/to/rpi-sysroot\"\n\n";
+
+  // CMake integration
+  config << "# CMake integration\n";
+  config << "# [cmake]\n";
+  config << "# generator = \"Ninja\"  # Optional: override CMake generator\n";
+  config << "# includes = [\"cmake/custom.cmake\"]  # Custom CMake files to "
+            "include\n";
+  config << "# module_paths = [\"cmake/modules\"]  # Custom module paths\n";
+}
+
+}  // namespace cforge
@@ -0,0 +1,207 @@
+/**
+ * @file install_manifest.hpp
+ * @brief Records of `cforge install --prefix`, used by `cforge uninstall`
+ *
+ * After a CMake install, cforge keeps the install_manifest.txt that CMake
+ * wrote into the build directory as a record under
+ * `~/.local/share/cforge/installs` (`%LOCALAPPDATA%\cforge\installs` on
+ * Windows). A record starts with `key = value` metadata lines, followed by
+ * a blank line and one installed file per line.
+ */
+
+#pragma once
+
+#include "core/types.h"
+
+#include <algorithm>
+#include <cctype>
+#include <cstdint>
+#include <filesystem>
+#include <iomanip>
+#include <iterator>
+#include <optional>
+#include <set>
+#include <sstream>
+#include <string>
+#include <vector>
+
+namespace cforge {
+
+/**
+ * @brief One install of a project to a prefix
+ */
+struct install_record {
+  std::string project;
+  std::string version;
+  std::string config;  ///< Build configuration that was installed
+  std::string prefix;  ///< Absolute install prefix
+  std::string date;    ///< UTC, e.g. "2026-10-16T12:00:00Z"
+  std::vector<std::string> files;
+};
+
+/**
+ * @brief Read the file list of a CMake install_manifest.txt
+ */
+inline std::vector<std::string> parse_cmake_install_manifest(const std::string &text) {
+  std::vector<std::string> files;
+  std::istringstream in(text);
+  std::string line;
+  while (std::getline(in, line)) {
+    if (!line.empty() && line.back() == '\r') {
+      line.pop_back();
+    }
+    if (!line.empty()) {
+      files.push_back(line);
+    }
+  }
+  return files;
+}
+
+/**
+ * @brief Write an install record
+ */
+inline std::string format_install_record(const install_record &record) {
+  std::ostringstream out;
+  out << "project = " << record.project << "\n"
+      << "version = " << record.version << "\n"
+      << "config = " << record.config << "\n"
+      << "prefix = " << record.prefix << "\n"
+      << "date = " << record.date << "\n\n";
+  for (const auto &file : record.files) {
+    out << file << "\n";
+  }
+  return out.str();
+}
+
+/**
+ * @brief Read an install record
+ *
+ * @param text Contents written by format_install_record
+ * @return The record, or nullopt if it has no project or prefix
+ */
+inline std::optional<install_record> parse_install_record(const std::string &text) {
+  install_record record;
+  std::istringstream in(text);
+  std::string line;
+  bool in_files = false;
+  while (std::getline(in, line)) {
+    if (!line.empty() && line.back() == '\r') {
+      line.pop_back();
+    }
+    if (in_files) {
+      if (!line.empty()) {
+        record.files.push_back(line);
+      }
+      continue;
+    }
+    if (line.empty()) {
+      in_files = true;
+      continue;
+    }
+    cforge_size_t eq = line.find(" = ");
+    if (eq == std::string::npos) {
+      continue;
+    }
+    std::string key   = line.substr(0, eq);
+    std::string value = line.substr(eq + 3);
+    if (key == "project") {
+      record.project = value;
+    } else if (key == "version") {
+      record.version = value;
+    } else if (key == "config") {
+      record.config = value;
+    } else if (key == "prefix") {
+      record.prefix = value;
+    } else if (key == "date") {
+      record.date = value;
+    }
+  }
+  if (record.project.empty() || record.prefix.empty()) {
+    return std::nullopt;
+  }
+  return record;
+}
+
+/**
+ * @brief File name of the record of a project install
+ *
+ * Characters that are not safe in file names become '_'. The name ends in a
+ * hash of the prefix, so installing to a second prefix keeps the first
+ * prefix's record.
+ */
+inline std::string install_record_file_name(const std::string &project,
+                                            const std::string &version,
+                                            const std::string &prefix) {
+  std::string name = version.empty() ? project : project + "-" + version;
+  for (char &c : name) {
+    if (!std::isalnum(static_cast<unsigned char>(c)) && c != '-' && c != '_' && c != '.') {
+      c = '_';
+    }
+  }
+
+  // FNV-1a, so the name is the same on every run and platform
+  std::uint32_t hash = 2166136261u;
+  for (char c : prefix) {
+    hash = (hash ^ static_cast<unsigned char>(c)) * 16777619u;
+  }
+  std::ostringstream suffix;
+  suffix << std::hex << std::setw(8) << std::setfill('0') << hash;
+  return name + "-" + suffix.str() + ".manifest";
+}
+
+/**
+ * @brief Check whether a path is strictly inside a directory
+ *
+ * Both paths are compared lexically, after resolving `.` and `..`.
+ */
+inline bool path_is_within(const std::filesystem::path &path, const std::filesystem::path &root) {
+  std::filesystem::path relative =
+      path.lexically_normal().lexically_relative(root.lexically_normal());
+  if (relative.empty() || relative == ".") {
+    return false;
+  }
+  return *relative.begin() != "..";
+}
+
+/**
+ * @brief Installed files of a record that are not inside its prefix
+ */
+inline std::vector<std::string> files_outside_prefix(const install_record &record) {
+  std::vector<std::string> outside;
+  for (const auto &file : record.files) {
+    if (!path_is_within(file, record.prefix)) {
+      outside.push_back(file);
+    }
+  }
+  return outside;
+}
+
+/**
+ * @brief Directories that may be left empty by removing the files of a record
+ *
+ * Only directories inside the prefix are listed, deepest first, so each one
+ * can be removed once its subdirectories are gone. The prefix itself is kept.
+ */
+inline std::vector<std::filesystem::path> install_record_directories(const install_record &record) {
+  std::filesystem::path prefix = std::filesystem::path(record.prefix).lexically_normal();
+  std::set<std::filesystem::path> directories;
+  for (const auto &file : record.files) {
+    std::filesystem::path dir = std::filesystem::path(file).lexically_normal().parent_path();
+    while (path_is_within(dir, prefix) && directories.insert(dir).second) {
+      dir = dir.parent_path();
+    }
+  }
+
+  std::vector<std::filesystem::path> ordered(directories.begin(), directories.end());
+  auto depth = [](const std::filesystem::path &path) {
+    return std::distance(path.begin(), path.end());
+  };
+  std::stable_sort(ordered.begin(),
+                   ordered.end(),
+                   [&](const std::filesystem::path &a, const std::filesystem::path &b) {
+                     return depth(a) > depth(b);
+                   });
+  return ordered;
+}
+
+}  // namespace cforge
@@ -17,6 +17,19 @@
 
 namespace cforge {
 
+/**
+ * @brief Options for installing through the generated CMake install rules
+ */
+struct cmake_install_options {
+  std::string prefix;     ///< Install prefix; empty keeps the configured CMAKE_INSTALL_PREFIX
+  std::string destdir;    ///< Staging root placed in front of the prefix
+  std::string component;  ///< Only install this component (Runtime, Headers, Resources)
+  bool strip   = false;   ///< Strip binaries while installing
+  bool dry_run = false;   ///< List the files that would be installed instead
+  std::string project;    ///< Project name, for the install record
+  std::string version;    ///< Project version, for the install record
+};
+
 /**
  * @brief Class for handling cforge installation and updates
  */
@@ -68,6 +81,33 @@ public:
                        const std::string &env_var               = "",
                        bool skip_build                          = false);
 
+  /**
+   * @brief Install a configured build directory with `cmake --install`
+   *
+   * On Unix the staging root is passed to CMake as the DESTDIR environment
+   * variable. Windows prefixes carry a drive letter, so there the staging root
+   * is joined with the prefix instead. A dry run installs into a temporary
+   * staging root and lists the files that would have been installed.
+   *
+   * A real install without a staging root keeps CMake's install manifest
+   * as a record for `cforge uninstall`, replacing any earlier record of the
+   * same project and prefix.
+   *
+   * @param build_dir Configured CMake build directory
+   * @param build_config Build configuration to install
+   * @param options Prefix, staging root, component and strip settings
+   * @return True if installation (or the dry run) was successful
+   */
+  bool install_with_cmake(const std::filesystem::path &build_dir,
+                          const std::string &build_config,
+                          const cmake_install_options &options) const;
+
+  /**
+   * @brief Get the directory holding the install records
+   * @return `<data dir>/cforge/installs`
+   */
+  static std::filesystem::path get_install_records_dir();
+
   /**
    * @brief Get the default installation path
    * @return Default installation path
@@ -0,0 +1,452 @@
+/**
+ * @file linker_diagnostics.hpp
+ * @brief Grouping undefined-symbol errors from GNU ld, lld, ld64 and MSVC
+ *        LINK, and guessing why each symbol is missing
+ *
+ * A linker reports an undefined symbol once per reference, so a single
+ * missing function can produce dozens of lines. The lines are grouped by
+ * symbol here, with a count and the first few places that reference it, and
+ * each symbol gets hints for the usual causes: a library missing from
+ * build.system_links, a source file left out of the build, or a function
+ * compiled as C but declared as C++ (or the other way round).
+ */
+
+#pragma once
+
+#include "core/types.h"
+
+#include <algorithm>
+#include <cctype>
+#include <regex>
+#include <sstream>
+#include <string>
+#include <vector>
+
+namespace cforge {
+
+/**
+ * @brief A symbol the linker could not find, with every reference to it
+ */
+struct undefined_symbol {
+  std::string symbol;                        ///< As the linker printed it, e.g. "foo::bar(int)"
+  std::string decorated;                     ///< MSVC decorated name, e.g. "?bar@foo@@YAHH@Z"
+  cforge_int_t count = 0;                    ///< References to the symbol in the output
+  std::vector<std::string> referenced_from;  ///< First few functions or objects using it
+};
+
+/**
+ * @brief Recover the qualified name from an MSVC decorated name
+ *
+ * Only the name is recovered, not the parameter list: `?bar@foo@@YAHH@Z`
+ * becomes `foo::bar`, `??0Widget@ui@@QEAA@XZ` becomes `ui::Widget::Widget`.
+ *
+ * @return The name, or empty if `decorated` is not a decorated name or uses
+ *         templates or operators that are not handled
+ */
+inline std::string demangle_msvc_name(const std::string &decorated) {
+  std::string rest = decorated;
+  if (rest.rfind("__imp_", 0) == 0) {
+    rest = rest.substr(6);
+  }
+  if (rest.size() < 2 || rest[0] != '?') {
+    return "";
+  }
+  rest = rest.substr(1);
+
+  // Special names: constructors, destructors, vftables and a few operators
+  std::string special;
+  if (rest[0] == '?') {
+    if (rest.rfind("?0", 0) == 0) {
+      special = "ctor";
+    } else if (rest.rfind("?1", 0) == 0) {
+      special = "dtor";
+    } else if (rest.rfind("?_7", 0) == 0) {
+      special = "`vftable'";
+    } else if (rest.rfind("?4", 0) == 0) {
+      special = "operator=";
+    } else if (rest.rfind("?8", 0) == 0) {
+      special = "operator==";
+    } else if (rest.rfind("?9", 0) == 0) {
+      special = "operator!=";
+    } else if (rest.rfind("?A", 0) == 0) {
+      special = "operator[]";
+    } else if (rest.rfind("?R", 0) == 0) {
+      special = "operator()";
+    } else {
+      return "";
+    }
+    rest = rest.substr(special == "`vftable'" ? 3 : 2);
+  }
+
+  // Name fragments run innermost first up to "@@"; a digit refers back to an
+  // earlier fragment
+  std::vector<std::string> names;
+  cforge_size_t pos = 0;
+  while (pos < rest.size() && rest[pos] != '@') {
+    if (std::isdigit(static_cast<unsigned char>(rest[pos]))) {
+      cforge_size_t index = static_cast<cforge_size_t>(rest[pos] - '0');
+      if (index >= names.size()) {
+        return "";
+      }
+      names.push_back(names[index]);
+      ++pos;
+      continue;
+    }
+    if (rest[pos] == '?') {
+      if (rest.compare(pos, 3, "?A0") != 0) {
+        return "";  // Templates and nested special names
+      }
+      names.push_back("`anonymous namespace'");
+    } else {
+      names.push_back("");
+    }
+    cforge_size_t end = rest.find('@', pos);
+    if (end == std::string::npos) {
+      return "";
+    }
+    if (rest[pos] != '?') {
+      names.back() = rest.substr(pos, end - pos);
+    }
+    pos = end + 1;
+  }
+  if (names.empty() || pos >= rest.size()) {
+    return "";
+  }
+
+  if (special == "ctor") {
+    names.insert(names.begin(), names.front());
+  } else if (special == "dtor") {
+    names.insert(names.begin(), "~" + names.front());
+  } else if (!special.empty()) {
+    names.insert(names.begin(), special);
+  }
+  std::string name;
+  for (auto it = names.rbegin(); it != names.rend(); ++it) {
+    name += (name.empty() ? "" : "::") + *it;
+  }
+  return name;
+}
+
+/**
+ * @brief Check whether a line of linker output is part of an undefined-symbol
+ *        error that find_undefined_symbols() reports
+ *
+ * Includes the lines around the error itself, such as GNU ld's
+ * "in function" and lld's "referenced by" lines.
+ */
+inline bool is_undefined_symbol_line(const std::string &line) {
+  static const std::regex pattern(
+      R"(undefined reference to |error: undefined symbol: |error LNK20(?:01|19): unresolved )"
+      R"(|: in function [`'\xE2]|>>> referenced by |^>>> |Undefined symbols for architecture )"
+      R"(|", referenced from:|symbol\(s\) not found for architecture)");
+  return std::regex_search(line, pattern);
+}
+
+/**
+ * @brief Find the undefined symbols in linker output, one entry per symbol
+ *
+ * Understands GNU ld ("undefined reference to `foo'"), lld
+ * ("error: undefined symbol: foo" with ">>> referenced by" lines), Apple's
+ * ld ("Undefined symbols for architecture ...") and MSVC LINK
+ * (LNK2019/LNK2001 "unresolved external symbol").
+ *
+ * @param output Raw build output
+ * @return Symbols in the order they were first reported
+ */
+inline std::vector<undefined_symbol> find_undefined_symbols(const std::string &output) {
+  static const std::regex gnu_function(
+      R"(in function [`'\xE2](?:\x80\x98)?(.+?)(?:'|\xE2\x80\x99):)");
+  static const std::regex gnu_reference(R"(^(?:(.*?):(?:\(|\d).*?: )?undefined reference to )"
+                                        R"([`'\xE2](?:\x80\x98)?(.+)(?:'|\xE2\x80\x99))");
+  static const std::regex lld_symbol(R"(error: undefined symbol: (.+?)\s*$)");
+  static const std::regex lld_reference(R"(^>>> referenced by (.+?)(?: \(.*\))?\s*$)");
+  static const std::regex lld_more(R"(^>>> referenced (\d+) more times)");
+  static const std::regex ld64_symbol(R"xx(^\s+"(.+)", referenced from:\s*$)xx");
+  static const std::regex ld64_reference(R"(^\s+(.+) in (\S+?)\s*$)");
+  static const std::regex msvc_symbol(
+      R"xx(error LNK20(?:01|19): unresolved external symbol (?:"(.+?)" \((\S+)\)|(\S+)))xx"
+      R"xx((?: referenced in function (?:"(.+?)" \((\S+)\)|(\S+)))?)xx");
+
+  // MSVC symbols are keyed by their decorated name, which includes the
+  // parameter types; the others by the name the linker printed
+  std::vector<undefined_symbol> symbols;
+  auto key_of = [](const undefined_symbol &symbol) {
+    return symbol.decorated.empty() ? symbol.symbol : symbol.decorated;
+  };
+  auto find_symbol = [&](const std::string &key) -> undefined_symbol * {
+    for (auto &existing : symbols) {
+      if (key_of(existing) == key) {
+        return &existing;
+      }
+    }
+    return nullptr;
+  };
+  auto add_symbol = [&](const std::string &symbol, const std::string &decorated) {
+    undefined_symbol *existing = find_symbol(decorated.empty() ? symbol : decorated);
+    if (existing) {
+      return existing;
+    }
+    undefined_symbol added;
+    added.symbol    = symbol;
+    added.decorated = decorated;
+    symbols.push_back(added);
+    return &symbols.back();
+  };
+  auto add_reference = [](undefined_symbol &symbol, const std::string &from) {
+    symbol.count++;
+    if (from.empty() || symbol.referenced_from.size() >= 3
+        || std::find(symbol.referenced_from.begin(), symbol.referenced_from.end(), from)
+               != symbol.referenced_from.end()) {
+      return;
+    }
+    symbol.referenced_from.push_back(from);
+  };
+  // Apple's ld prints C names with the leading underscore they have in the
+  // object file
+  auto strip_c_underscore = [](const std::string &name) {
+    return name.size() > 1 && name[0] == '_' && name.find('(') == std::string::npos
+             ? name.substr(1)
+             : name;
+  };
+
+  std::string line;
+  std::string gnu_function_name;  // GNU ld's most recent "in function" line
+  std::string last_symbol;        // Key of the lld or ld64 symbol whose references follow
+  bool in_ld64_block = false;
+  std::istringstream stream(output);
+  while (std::getline(stream, line)) {
+    if (!line.empty() && line.back() == '\r') {
+      line.pop_back();
+    }
+    std::smatch match;
+
+    if (std::regex_search(line, match, msvc_symbol)) {
+      std::string decorated = match[2].matched ? match[2].str() : match[3].str();
+      std::string symbol    = match[1].matched ? match[1].str() : demangle_msvc_name(decorated);
+      if (symbol.empty()) {
+        symbol = decorated.rfind("__imp_", 0) == 0 ? decorated.substr(6) : decorated;
+      }
+      add_reference(*add_symbol(symbol, decorated),
+                    match[4].matched ? match[4].str() : match[6].str());
+      continue;
+    }
+    if (std::regex_search(line, match, lld_symbol)) {
+      // lld-link prints decorated names when /demangle:no is set
+      std::string symbol = match[1].str();
+      std::string decorated;
+      std::string demangled = demangle_msvc_name(symbol);
+      if (!demangled.empty()) {
+        decorated = symbol;
+        symbol    = demangled;
+      }
+      last_symbol = key_of(*add_symbol(symbol, decorated));
+      continue;
+    }
+    if (line.find("Undefined symbols for architecture ") != std::string::npos) {
+      in_ld64_block = true;
+      continue;
+    }
+    if (in_ld64_block && std::regex_search(line, match, ld64_symbol)) {
+      last_symbol = key_of(*add_symbol(strip_c_underscore(match[1].str()), ""));
+      continue;
+    }
+    if (in_ld64_block && std::regex_search(line, match, ld64_reference)) {
+      if (undefined_symbol *entry = find_symbol(last_symbol)) {
+        add_reference(*entry, strip_c_underscore(match[1].str()));
+      }
+      continue;
+    }
+    in_ld64_block = false;
+
+    // lld lists each reference as "referenced by <source>", then the object
+    if (line.rfind(">>> ", 0) == 0) {
+      undefined_symbol *entry = find_symbol(last_symbol);
+      if (entry && std::regex_search(line, match, lld_more)) {
+        entry->count += std::stoi(match[1].str());
+      } else if (entry && std::regex_search(line, match, lld_reference)) {
+        add_reference(*entry, match[1].str());
+      }
+      continue;
+    }
+    last_symbol.clear();
+
+    if (std::regex_search(line, match, gnu_function)) {
+      gnu_function_name = match[1].str();
+    }
+    if (std::regex_search(line, match, gnu_reference)) {
+      // "ld: main.o:(.data+0x0): undefined reference" stands alone; other
+      // references follow the "in function" line of the function using them
+      std::string from = match[1].str();
+      if (from.find(": ") != std::string::npos) {
+        from              = from.substr(from.rfind(": ") + 2);
+        gnu_function_name = "";
+      }
+      add_reference(*add_symbol(match[2].str(), ""),
+                    gnu_function_name.empty() ? from : gnu_function_name);
+    }
+  }
+
+  // A symbol lld reported without any "referenced by" line
+  for (auto &symbol : symbols) {
+    symbol.count = std::max<cforge_int_t>(symbol.count, 1);
+  }
+  return symbols;
+}
+
+/**
+ * @brief A library a symbol prefix belongs to
+ */
+struct symbol_library {
+  const char *prefix;   ///< Namespace ("fmt::") or C function prefix ("curl_")
+  const char *package;  ///< vcpkg package providing it
+  const char *target;   ///< CMake target to put in build.system_links, empty if it varies
+};
+
+/**
+ * @brief Find the vcpkg package a symbol likely comes from
+ *
+ * The prefix must start the symbol or follow a character that can't be part
+ * of an identifier, so "myfmt::x" does not match "fmt::".
+ *
+ * @return The library, or nullptr if the symbol matches none
+ */
+inline const symbol_library *find_symbol_library(const std::string &symbol) {
+  static const symbol_library libraries[] = {
+      {"fmt::",              "fmt",        "fmt::fmt"                    },
+      {"spdlog::",           "spdlog",     "spdlog::spdlog"              },
+      {"YAML::",             "yaml-cpp",   "yaml-cpp::yaml-cpp"          },
+      {"testing::",          "gtest",      "GTest::gtest"                },
+      {"benchmark::",        "benchmark",  "benchmark::benchmark"        },
+      {"Catch::",            "catch2",     "Catch2::Catch2"              },
+      {"google::protobuf::", "protobuf",   "protobuf::libprotobuf"       },
+      {"absl::",             "abseil",     ""                            },
+      {"boost::",            "boost",      ""                            },
+      {"cv::",               "opencv",     ""                            },
+      {"tinyxml2::",         "tinyxml2",   "tinyxml2::tinyxml2"          },
+      {"pugi::",             "pugixml",    "pugixml::pugixml"            },
+      {"curl_",              "curl",       "CURL::libcurl"               },
+      {"SSL_",               "openssl",    "OpenSSL::SSL"                },
+      {"EVP_",               "openssl",    "OpenSSL::Crypto"             },
+      {"sqlite3_",           "sqlite3",    "unofficial::sqlite3::sqlite3"},
+      {"glfw",               "glfw3",      "glfw"                        },
+      {"SDL_",               "sdl2",       "SDL2::SDL2"                  },
+      {"png_",               "libpng",     "PNG::PNG"                    },
+      {"deflate",            "zlib",       "ZLIB::ZLIB"                  },
+      {"inflate",            "zlib",       "ZLIB::ZLIB"                  },
+      {"ZSTD_",              "zstd",       "zstd::libzstd"               },
+      {"FT_",                "freetype",   "Freetype::Freetype"          },
+      {"lua_",               "lua",        ""                            },
+      {"xmlParse",           "libxml2",    "LibXml2::LibXml2"            },
+      {"archive_",           "libarchive", "LibArchive::LibArchive"      },
+  };
+  for (const auto &library : libraries) {
+    std::string prefix = library.prefix;
+    for (cforge_size_t pos = symbol.find(prefix); pos != std::string::npos;
+         pos               = symbol.find(prefix, pos + 1)) {
+      char before = pos == 0 ? ' ' : symbol[pos - 1];
+      if (!std::isalnum(static_cast<unsigned char>(before)) && before != '_') {
+        return &library;
+      }
+    }
+  }
+  return nullptr;
+}
+
+/**
+ * @brief The name of an undefined symbol without parameters or return type,
+ *        e.g. "foo::bar" for "int __cdecl foo::bar(int)"
+ */
+inline std::string undefined_symbol_name(const undefined_symbol &symbol) {
+  if (!symbol.decorated.empty()) {
+    std::string demangled = demangle_msvc_name(symbol.decorated);
+    if (!demangled.empty()) {
+      return demangled;
+    }
+  }
+  return symbol.symbol.substr(0, symbol.symbol.find('('));
+}
+
+/**
+ * @brief Notes and help for an undefined symbol
+ */
+struct undefined_symbol_advice {
+  std::vector<std::string> notes;
+  std::string help;
+};
+
+/**
+ * @brief Suggest the likely causes of an undefined symbol
+ *
+ * A symbol from a known library suggests linking it. A missing vtable or
+ * typeinfo means a virtual function was declared but never defined.
+ * Anything else is either a source file the build leaves out or a function
+ * whose C/C++ linkage differs between its declaration and its definition.
+ *
+ * @param symbol The undefined symbol
+ * @param system_library System library providing it, e.g. "ws2_32.lib", if
+ *        known (see suggest_library_for_symbol())
+ */
+inline undefined_symbol_advice advise_undefined_symbol(const undefined_symbol &symbol,
+                                                       const std::string &system_library = "") {
+  undefined_symbol_advice advice;
+  std::string name = undefined_symbol_name(symbol);
+
+  std::string from;
+  for (const auto &reference : symbol.referenced_from) {
+    from += (from.empty() ? "" : ", ") + reference;
+  }
+  if (!from.empty()) {
+    advice.notes.push_back("referenced from: " + from);
+  }
+
+  if (const symbol_library *library = find_symbol_library(symbol.symbol)) {
+    std::string target = library->target;
+    advice.notes.push_back("try linking: "
+                           + (target.empty() ? std::string(library->package) : target)
+                           + " (vcpkg package '" + library->package + "')");
+    advice.help = (target.empty() ? "add its library" : "add '" + target + "'")
+                + " to build.system_links, or install it with 'cforge deps add " + library->package
+                + " --vcpkg'";
+    return advice;
+  }
+  if (!system_library.empty()) {
+    advice.notes.push_back("try linking: " + system_library);
+    advice.help = "add it to build.system_links, or to [platform.<os>] links if only one "
+                  "platform needs it";
+    return advice;
+  }
+
+  for (const char *prefix : {"vtable for ", "typeinfo for ", "`vftable'"}) {
+    if (symbol.symbol.rfind(prefix, 0) == 0 || name.find(prefix) != std::string::npos) {
+      std::string type = symbol.symbol.rfind(prefix, 0) == 0
+                           ? symbol.symbol.substr(std::string(prefix).size())
+                           : name.substr(0, name.rfind("::"));
+      advice.help = "define every virtual function of '" + type
+                  + "' that is not pure; the vtable is emitted with the first one that is not "
+                    "inline";
+      return advice;
+    }
+  }
+
+  // Qualified names can't have been compiled as C, and a name without a
+  // parameter list or decoration was referenced as C
+  bool is_cpp_decorated = symbol.decorated.find('?') != std::string::npos;
+  bool is_cpp_function  = name.find("::") == std::string::npos
+                      && (symbol.symbol.find('(') != std::string::npos || is_cpp_decorated);
+  bool is_c_name = !is_cpp_decorated && !symbol.symbol.empty()
+                && std::all_of(symbol.symbol.begin(), symbol.symbol.end(), [](unsigned char c) {
+                     return std::isalnum(c) || c == '_';
+                   });
+  if (is_cpp_function) {
+    advice.notes.push_back("if '" + name + "' is defined in a .c file, declare it inside "
+                           "extern \"C\" { } in the header C++ code includes");
+  } else if (is_c_name) {
+    advice.notes.push_back("if '" + name + "' is a function defined in a .cpp file and called "
+                           "from C, declare it extern \"C\" there too");
+  }
+  advice.help = "if '" + name + "' is defined in this project, check that its source file is in "
+                "build.source_dirs and not matched by build.exclude_sources";
+  return advice;
+}
+
+}  // namespace cforge
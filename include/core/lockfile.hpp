@@ -16,6 +16,7 @@
 #include "core/toml_reader.hpp"
 
 #include <chrono>
+#include <cstdlib>
 #include <filesystem>
 #include <fstream>
 #include <iomanip>
@@ -36,7 +37,7 @@ constexpr cforge_cstring_t LOCK_FILE = "cforge.lock";
  */
 struct locked_dependency {
   std::string name;
-  std::string source_type;  // "git", "vcpkg", "system"
+  std::string source_type;  // "git", "vcpkg", "conan", "index"
   std::string url;          // For git deps
   std::string version;      // Requested version/tag/branch
   std::string resolved;     // Actual resolved version (commit hash for git)
@@ -110,7 +111,7 @@ public:
             value = value.substr(1, value.length() - 2);
           }
 
-          if (key == "source") {
+          if (key == "source" || key == "source_type") {
             current.source_type = value;
           } else if (key == "url") {
             current.url = value;
@@ -254,6 +255,21 @@ public:
     dependencies_[name] = dep;
   }
 
+  /**
+   * @brief Lock a conan dependency
+   *
+   * @param name Package name
+   * @param version Requested version; the resolved reference is name/version
+   */
+  void lock_conan_dependency(const std::string &name, const std::string &version) {
+    locked_dependency dep;
+    dep.name            = name;
+    dep.source_type     = "conan";
+    dep.version         = version;
+    dep.resolved        = name + "/" + version;
+    dependencies_[name] = dep;
+  }
+
   /**
    * @brief Lock an index dependency (from cforge-index registry)
    *
@@ -329,6 +345,138 @@ private:
   }
 };
 
+/**
+ * @brief Get the commit a git dependency is locked at
+ *
+ * The lock only applies while the dependency still points at the same URL and
+ * ref it was resolved from; after cforge.toml changes either, the dependency
+ * is resolved again.
+ *
+ * @param lock Loaded lock file
+ * @param name Dependency name
+ * @param url Configured repository URL
+ * @param ref Configured tag, branch or commit
+ * @return Full commit hash, or an empty string if the dependency is not locked
+ */
+inline std::string locked_git_commit(const lockfile &lock,
+                                     const std::string &name,
+                                     const std::string &url,
+                                     const std::string &ref) {
+  auto locked = lock.get_dependency(name);
+  if (!locked || locked->source_type != "git" || locked->version != ref) {
+    return "";
+  }
+  if (!locked->url.empty() && locked->url != url) {
+    return "";
+  }
+  if (locked->resolved.size() != 40
+      || locked->resolved.find_first_not_of("0123456789abcdefABCDEF") != std::string::npos) {
+    return "";
+  }
+  return locked->resolved;
+}
+
+/**
+ * @brief Parse the output of `vcpkg list` into installed package versions
+ *
+ * Lines look like `fmt:x64-linux  10.2.1#1  Formatting library`; feature
+ * lines (`curl[ssl]:x64-linux`) are folded into their package.
+ *
+ * @param output Output of `vcpkg list`
+ * @return Map of package name to installed version
+ */
+inline std::map<std::string, std::string> parse_vcpkg_list(const std::string &output) {
+  std::map<std::string, std::string> versions;
+  std::istringstream lines(output);
+  std::string line;
+
+  while (std::getline(lines, line)) {
+    std::istringstream fields(line);
+    std::string spec;
+    std::string version;
+    if (!(fields >> spec >> version) || spec.find(':') == std::string::npos) {
+      continue;
+    }
+    std::string name = spec.substr(0, spec.find_first_of(":["));
+    versions.emplace(name, version);
+  }
+
+  return versions;
+}
+
+/**
+ * @brief Lock vcpkg and conan dependencies declared in [dependencies]
+ *
+ * vcpkg packages are locked at the version reported by `vcpkg list`, falling
+ * back to the requested version when vcpkg is not available. conan packages
+ * are locked at their `name/version` reference.
+ *
+ * @param lock Lock file to add the dependencies to
+ * @param project_dir Project directory
+ * @param config Project configuration
+ * @param verbose Verbose output
+ */
+inline void lock_package_manager_dependencies(lockfile &lock,
+                                              const std::filesystem::path &project_dir,
+                                              const toml_reader &config,
+                                              bool verbose = false) {
+  std::vector<dependency_spec> vcpkg_deps;
+  for (const auto &spec : parse_dependencies(project_dir / CFORGE_FILE)) {
+    if (spec.source == dependency_source::VCPKG) {
+      vcpkg_deps.push_back(spec);
+    } else if (spec.source == dependency_source::CONAN) {
+      lock.lock_conan_dependency(spec.name, spec.version);
+      if (verbose) {
+        logger::print_verbose("Locked conan package: " + spec.name + "/" + spec.version);
+      }
+    }
+  }
+  if (vcpkg_deps.empty()) {
+    return;
+  }
+
+  // Same lookup order as the vcpkg toolchain: explicit path, VCPKG_ROOT, ./vcpkg
+  std::filesystem::path vcpkg_root = project_dir / "vcpkg";
+  if (config.has_key("dependencies.vcpkg.path")) {
+    vcpkg_root = config.get_string("dependencies.vcpkg.path", "");
+  } else if (cforge_cstring_t env = std::getenv("VCPKG_ROOT")) {
+    vcpkg_root = env;
+  }
+#ifdef _WIN32
+  std::filesystem::path vcpkg_exe = vcpkg_root / "vcpkg.exe";
+#else
+  std::filesystem::path vcpkg_exe = vcpkg_root / "vcpkg";
+#endif
+
+  std::map<std::string, std::string> installed;
+  std::string vcpkg_cmd = std::filesystem::exists(vcpkg_exe) ? vcpkg_exe.string() : "vcpkg";
+  if (vcpkg_cmd != "vcpkg" || is_command_available("vcpkg", 10)) {
+    process_result result =
+        execute_process(vcpkg_cmd, {"list"}, project_dir.string(), nullptr, nullptr, 60);
+    if (result.success && result.exit_code == 0) {
+      installed = parse_vcpkg_list(result.stdout_output);
+    }
+  }
+
+  for (const auto &spec : vcpkg_deps) {
+    std::string package = spec.vcpkg_name.empty() ? spec.name : spec.vcpkg_name;
+    auto it             = installed.find(package);
+    if (it != installed.end()) {
+      lock.lock_vcpkg_dependency(spec.name, it->second);
+    } else {
+      lock.lock_vcpkg_dependency(spec.name, spec.version);
+      if (verbose) {
+        logger::print_warning("vcpkg package " + package
+                              + " is not installed, locking the requested version");
+      }
+    }
+    if (verbose) {
+      logger::print_verbose("Locked vcpkg package: " + package + " "
+                            + lock.get_dependency(spec.name)->resolved);
+    }
+  }
+}
+
 /**
  * @brief Update lock file from current project configuration
  *
@@ -363,15 +511,19 @@ inline bool update_lockfile(const std::filesystem::path &project_dir,
       std::string branch = config.get_string("dependencies.git." + dep + ".branch", "");
       std::string commit = config.get_string("dependencies.git." + dep + ".commit", "");
 
-      std::string version = commit;
+      // Same precedence as the clone: tag, then branch, then commit
+      std::string version = tag;
       if (version.empty()) {
-        version = tag;
+        version = branch;
       }
       if (version.empty()) {
-        version = branch;
+        version = commit;
       }
 
-      std::filesystem::path repo_dir = deps_dir / dep;
+      std::string custom_dir = config.get_string("dependencies.git." + dep + ".directory", "");
+
+      std::filesystem::path repo_dir = custom_dir.empty() ? deps_dir / dep
+                                                          : project_dir / custom_dir / dep;
 
       if (std::filesystem::exists(repo_dir)) {
         lock.lock_git_dependency(dep, url, version, repo_dir);
@@ -407,6 +559,8 @@ inline bool update_lockfile(const std::filesystem::path &project_dir,
     }
   }
 
+  lock_package_manager_dependencies(lock, project_dir, config, verbose);
+
   // Lock index dependencies (simple name = "version" format)
   // Skip if using FetchContent mode (CMake handles downloading, packages not in
   // deps_dir)
@@ -536,13 +690,18 @@ inline bool generate_lockfile_from_config(const std::filesystem::path &project_d
   lock_file << "# Generated by cforge - DO NOT EDIT MANUALLY\n";
   lock_file << "# Mode: FetchContent\n\n";
 
+  // vcpkg and conan packages are locked below, not resolved from the index
+  lockfile managed;
+  lock_package_manager_dependencies(managed, project_dir, config, verbose);
+
   // Get index dependencies from cforge.toml
   auto dep_keys = config.get_table_keys("dependencies");
   bool has_deps = false;
 
   for (const auto &key : dep_keys) {
     // Skip config keys
-    if (key == "fetch_content" || key == "directory" || key == "git" || key == "vcpkg") {
+    if (key == "fetch_content" || key == "directory" || key == "git" || key == "vcpkg"
+        || key == "archive" || key == "fetchcontent" || managed.has_dependency(key)) {
       continue;
     }
 
@@ -595,31 +754,50 @@ inline bool generate_lockfile_from_config(const std::filesystem::path &project_d
     }
   }
 
-  // Also handle git dependencies
+  // Also handle git dependencies; they are cloned before CMake runs, so the
+  // checkout gives the resolved commit
+  std::string deps_dir = config.get_string("dependencies.directory", "deps");
   for (const auto &key : config.get_table_keys("dependencies.git")) {
-    std::string url    = config.get_string("dependencies.git." + key + ".url", "");
-    std::string tag    = config.get_string("dependencies.git." + key + ".tag", "");
-    std::string branch = config.get_string("dependencies.git." + key + ".branch", "");
-    std::string commit = config.get_string("dependencies.git." + key + ".commit", "");
+    std::string url        = config.get_string("dependencies.git." + key + ".url", "");
+    std::string tag        = config.get_string("dependencies.git." + key + ".tag", "");
+    std::string branch     = config.get_string("dependencies.git." + key + ".branch", "");
+    std::string commit     = config.get_string("dependencies.git." + key + ".commit", "");
+    std::string custom_dir = config.get_string("dependencies.git." + key + ".directory", "");
+
+    std::string ref = !tag.empty() ? tag : !branch.empty() ? branch : commit;
+    std::filesystem::path repo_dir = project_dir / (custom_dir.empty() ? deps_dir : custom_dir)
+                                   / key;
+    std::string resolved = commit;
+    if (std::filesystem::exists(repo_dir)) {
+      std::string head = git_get_head_commit(repo_dir, false);
+      if (!head.empty()) {
+        resolved = head;
+      }
+    }
 
     lock_file << "[dependency." << key << "]\n";
     lock_file << "source_type = \"git\"\n";
     if (!url.empty()) {
       lock_file << "url = \"" << url << "\"\n";
     }
-    if (!tag.empty()) {
-      lock_file << "version = \"" << tag << "\"\n";
-    }
-    if (!branch.empty()) {
-      lock_file << "branch = \"" << branch << "\"\n";
+    if (!ref.empty()) {
+      lock_file << "version = \"" << ref << "\"\n";
     }
-    if (!commit.empty()) {
-      lock_file << "resolved = \"" << commit << "\"\n";
+    if (!resolved.empty()) {
+      lock_file << "resolved = \"" << resolved << "\"\n";
     }
     lock_file << "\n";
     has_deps = true;
   }
 
+  for (const auto &[name, dep] : managed.get_all()) {
+    lock_file << "[dependency." << name << "]\n";
+    lock_file << "source_type = \"" << dep.source_type << "\"\n";
+    lock_file << "version = \"" << dep.version << "\"\n";
+    lock_file << "resolved = \"" << dep.resolved << "\"\n\n";
+    has_deps = true;
+  }
+
   lock_file.close();
 
   if (!has_deps) {
@@ -0,0 +1,504 @@
+/**
+ * @file manifest_edit.hpp
+ * @brief Setting and removing cforge.toml values by dotted path, for `cforge config`,
+ *        `cforge add` and `cforge remove`
+ *
+ * Like the edits in workspace_edit.hpp, these work on the text of the file:
+ * only the lines of the value being changed are rewritten, so comments,
+ * ordering and formatting elsewhere are kept. Every command re-parses the
+ * result before writing it (see write_manifest_edit()) to check that the
+ * edit produced the intended document.
+ */
+
+#pragma once
+
+#include "cforge/log.hpp"
+
+#include "core/types.h"
+#include "core/workspace_edit.hpp"
+
+#include <toml++/toml.hpp>
+
+#include <filesystem>
+#include <fstream>
+#include <functional>
+#include <regex>
+#include <sstream>
+#include <string>
+#include <vector>
+
+namespace cforge {
+
+/**
+ * @brief A table header or key/value pair in manifest text
+ */
+struct manifest_line {
+  bool is_header   = false;
+  bool array_table = false;  ///< `[[table]]` header, or a key inside one
+  std::string table;         ///< Dotted name of the table the line is in
+  std::string key;           ///< Dotted key as written, empty for headers
+  cforge_size_t first = 0;   ///< First line of the entry
+  cforge_size_t last  = 0;   ///< Last line, after a multi-line value
+  std::string comment;       ///< Trailing comment of a one-line value, with its indent
+
+  /**
+   * @brief Dotted path of the key, e.g. "build.source_dirs"
+   */
+  std::string path() const {
+    return table.empty() ? key : table + "." + key;
+  }
+};
+
+/**
+ * @brief Remove spaces around the dots of a dotted TOML key
+ */
+inline std::string normalize_manifest_key(const std::string &key) {
+  std::string normalized;
+  for (char c : trim_manifest_line(key)) {
+    if (c == ' ' || c == '\t') {
+      continue;
+    }
+    normalized += c;
+  }
+  return normalized;
+}
+
+/**
+ * @brief Line ending of manifest text: "\r\n" if it uses CRLF, otherwise "\n"
+ */
+inline std::string manifest_line_ending(const std::string &content) {
+  return content.find("\r\n") != std::string::npos ? "\r\n" : "\n";
+}
+
+/**
+ * @brief Split manifest text into lines, without line endings
+ */
+inline std::vector<std::string> split_manifest_lines(const std::string &content) {
+  std::vector<std::string> lines;
+  std::istringstream in(content);
+  std::string line;
+  while (std::getline(in, line)) {
+    if (!line.empty() && line.back() == '\r') {
+      line.pop_back();
+    }
+    lines.push_back(line);
+  }
+  return lines;
+}
+
+/**
+ * @brief Join manifest lines, ending each with @p eol
+ */
+inline std::string join_manifest_lines(const std::vector<std::string> &lines,
+                                       const std::string &eol) {
+  std::string result;
+  for (const auto &line : lines) {
+    result += line + eol;
+  }
+  return result;
+}
+
+/**
+ * @brief Find where a value ends
+ *
+ * Follows brackets, braces and strings (including multi-line ones), so an
+ * array spread over several lines is one value.
+ *
+ * @param lines Manifest lines
+ * @param first Line the value starts on
+ * @param offset Position of the value in that line
+ * @param comment Receives the position of a `#` comment on the last line,
+ *        or npos
+ * @return Last line of the value
+ */
+inline cforge_size_t find_manifest_value_end(const std::vector<std::string> &lines,
+                                             cforge_size_t first,
+                                             cforge_size_t offset,
+                                             cforge_size_t &comment) {
+  cforge_int_t depth = 0;
+  std::string multiline;
+  for (cforge_size_t i = first; i < lines.size(); ++i) {
+    const std::string &line = lines[i];
+    comment                 = std::string::npos;
+    for (cforge_size_t pos = i == first ? offset : 0; pos < line.size(); ++pos) {
+      char c = line[pos];
+      if (!multiline.empty()) {
+        if (line.compare(pos, 3, multiline) == 0) {
+          multiline.clear();
+          pos += 2;
+        } else if (c == '\\' && multiline[0] == '"') {
+          ++pos;
+        }
+      } else if (c == '#') {
+        comment = pos;
+        break;
+      } else if ((c == '"' || c == '\'') && line.compare(pos, 3, std::string(3, c)) == 0) {
+        multiline = std::string(3, c);
+        pos += 2;
+      } else if (c == '"' || c == '\'') {
+        for (++pos; pos < line.size() && line[pos] != c; ++pos) {
+          if (c == '"' && line[pos] == '\\') {
+            ++pos;
+          }
+        }
+      } else if (c == '[' || c == '{') {
+        ++depth;
+      } else if (c == ']' || c == '}') {
+        --depth;
+      }
+    }
+    if (depth <= 0 && multiline.empty()) {
+      return i;
+    }
+  }
+  return lines.empty() ? 0 : lines.size() - 1;
+}
+
+/**
+ * @brief List the table headers and key/value pairs of manifest text
+ */
+inline std::vector<manifest_line> scan_manifest(const std::vector<std::string> &lines) {
+  std::vector<manifest_line> entries;
+  std::string table;
+  bool array_table = false;
+  for (cforge_size_t i = 0; i < lines.size(); ++i) {
+    std::string trimmed = trim_manifest_line(lines[i]);
+    if (trimmed.empty() || trimmed[0] == '#') {
+      continue;
+    }
+    if (trimmed[0] == '[') {
+      array_table       = trimmed.rfind("[[", 0) == 0;
+      cforge_size_t len = array_table ? 2 : 1;
+      cforge_size_t end = trimmed.find(array_table ? "]]" : "]");
+      end               = end == std::string::npos ? len : end;
+      table             = normalize_manifest_key(trimmed.substr(len, end - len));
+      manifest_line header;
+      header.is_header   = true;
+      header.array_table = array_table;
+      header.table       = table;
+      header.first       = i;
+      header.last        = i;
+      entries.push_back(header);
+      continue;
+    }
+    cforge_size_t eq = lines[i].find('=');
+    if (eq == std::string::npos) {
+      continue;
+    }
+    manifest_line entry;
+    entry.array_table     = array_table;
+    entry.table           = table;
+    entry.key             = normalize_manifest_key(lines[i].substr(0, eq));
+    entry.first           = i;
+    cforge_size_t comment = std::string::npos;
+    entry.last            = find_manifest_value_end(lines, i, eq + 1, comment);
+    if (entry.last == i && comment != std::string::npos) {
+      entry.comment = lines[i].substr(lines[i].find_last_not_of(" \t", comment - 1) + 1);
+    }
+    entries.push_back(entry);
+    i = entry.last;
+  }
+  return entries;
+}
+
+/**
+ * @brief Check whether a dotted path names a key that `cforge config` can edit
+ *
+ * Bare keys separated by dots, e.g. "build.source_dirs". Array indices and
+ * quoted keys are only supported by `cforge config get`.
+ */
+inline bool is_editable_manifest_path(const std::string &path) {
+  static const std::regex pattern(R"([A-Za-z0-9_-]+(\.[A-Za-z0-9_-]+)*)");
+  return std::regex_match(path, pattern);
+}
+
+/**
+ * @brief Turn a value given on the command line into a TOML value
+ *
+ * Booleans, numbers, arrays, inline tables and quoted strings are used as
+ * written; anything else becomes a string, so `Release` and `"Release"`
+ * mean the same.
+ */
+inline std::string manifest_value_literal(const std::string &text) {
+  static const std::regex number(R"([+-]?(\d[\d_]*)(\.\d[\d_]*)?([eE][+-]?\d+)?)");
+  std::string value = trim_manifest_line(text);
+  if (value == "true" || value == "false" || std::regex_match(value, number)) {
+    return value;
+  }
+  if (!value.empty() && std::string("[{\"'").find(value[0]) != std::string::npos) {
+    return value;
+  }
+  std::string quoted = "\"";
+  for (char c : text) {
+    if (c == '"' || c == '\\') {
+      quoted += '\\';
+    }
+    quoted += c;
+  }
+  return quoted + "\"";
+}
+
+/**
+ * @brief Text of a value as written in the manifest
+ *
+ * @param content Manifest text
+ * @param path Dotted path, e.g. "build.source_dirs"
+ * @return The value, or empty if no line sets the key
+ */
+inline std::string manifest_value_text(const std::string &content, const std::string &path) {
+  std::vector<std::string> lines = split_manifest_lines(content);
+  for (const auto &entry : scan_manifest(lines)) {
+    if (entry.is_header || entry.array_table || entry.path() != path) {
+      continue;
+    }
+    std::string text = lines[entry.first].substr(lines[entry.first].find('=') + 1);
+    for (cforge_size_t i = entry.first + 1; i <= entry.last; ++i) {
+      text += "\n" + lines[i];
+    }
+    if (!entry.comment.empty()) {
+      text = text.substr(0, text.rfind(entry.comment));
+    }
+    return trim_manifest_line(text);
+  }
+  return "";
+}
+
+/**
+ * @brief Set a value in manifest text
+ *
+ * An existing key keeps its position, indentation and trailing comment. A
+ * new key goes after the last key of its table; a table that doesn't exist
+ * yet is added at the end of the file. Edited and new lines use the line
+ * ending the text already has.
+ *
+ * @param content Manifest text, updated in place
+ * @param path Dotted path, e.g. "build.build_type"
+ * @param value TOML value, e.g. `"Release"` or `["include", "third_party"]`
+ * @return false if the path is not editable
+ */
+inline bool set_manifest_value(std::string &content,
+                               const std::string &path,
+                               const std::string &value) {
+  if (!is_editable_manifest_path(path)) {
+    return false;
+  }
+  std::string eol                    = manifest_line_ending(content);
+  std::vector<std::string> lines     = split_manifest_lines(content);
+  std::vector<manifest_line> entries = scan_manifest(lines);
+  auto join = [&]() { content = join_manifest_lines(lines, eol); };
+
+  for (const auto &entry : entries) {
+    if (entry.is_header || entry.array_table || entry.path() != path) {
+      continue;
+    }
+    std::string line = lines[entry.first];
+    std::string head = line.substr(0, line.find('='));
+    head             = head.substr(0, head.find_last_not_of(" \t") + 1);
+    std::string text = head + " = " + value + entry.comment;
+    lines.erase(lines.begin() + entry.first, lines.begin() + entry.last + 1);
+    lines.insert(lines.begin() + entry.first, text);
+    join();
+    return true;
+  }
+
+  // A new key goes at the end of its table, which may have to be created
+  cforge_size_t dot       = path.rfind('.');
+  std::string parent      = dot == std::string::npos ? "" : path.substr(0, dot);
+  std::string key         = dot == std::string::npos ? path : path.substr(dot + 1);
+  bool has_parent         = parent.empty();
+  cforge_size_t insert_at = 0;
+  for (const auto &entry : entries) {
+    if (entry.array_table || entry.table != parent) {
+      continue;
+    }
+    has_parent = true;
+    insert_at  = entry.last + 1;
+  }
+
+  if (!has_parent) {
+    while (!lines.empty() && trim_manifest_line(lines.back()).empty()) {
+      lines.pop_back();
+    }
+    if (!lines.empty()) {
+      lines.push_back("");
+    }
+    lines.push_back("[" + parent + "]");
+    lines.push_back(key + " = " + value);
+  } else {
+    lines.insert(lines.begin() + insert_at, key + " = " + value);
+  }
+  join();
+  return true;
+}
+
+/**
+ * @brief Remove a key or a table from manifest text
+ *
+ * Removing a table also removes its sub-tables and any dotted keys that
+ * define values inside it.
+ *
+ * @param content Manifest text, updated in place
+ * @param path Dotted path of a key or table, e.g. "pch"
+ * @return true if anything was removed
+ */
+inline bool unset_manifest_value(std::string &content, const std::string &path) {
+  if (!is_editable_manifest_path(path)) {
+    return false;
+  }
+  std::string eol                    = manifest_line_ending(content);
+  std::vector<std::string> lines     = split_manifest_lines(content);
+  std::vector<manifest_line> entries = scan_manifest(lines);
+  auto inside                        = [&](const std::string &name) {
+    return name == path || name.rfind(path + ".", 0) == 0;
+  };
+
+  std::vector<bool> remove(lines.size(), false);
+  bool removed = false;
+  for (cforge_size_t e = 0; e < entries.size(); ++e) {
+    const manifest_line &entry = entries[e];
+    cforge_size_t last         = entry.last;
+    if (entry.is_header && inside(entry.table)) {
+      // The table runs to its last key and the blank lines after it; a
+      // comment before the next header belongs to that header
+      for (cforge_size_t n = e + 1; n < entries.size() && !entries[n].is_header; ++n) {
+        last = entries[n].last;
+      }
+      while (last + 1 < lines.size() && trim_manifest_line(lines[last + 1]).empty()) {
+        ++last;
+      }
+    } else if (entry.is_header || entry.array_table || !inside(entry.path())) {
+      continue;
+    }
+    for (cforge_size_t i = entry.first; i <= last; ++i) {
+      remove[i] = true;
+    }
+    removed = true;
+  }
+  if (!removed) {
+    return false;
+  }
+
+  std::vector<std::string> kept;
+  for (cforge_size_t i = 0; i < lines.size(); ++i) {
+    if (!remove[i]) {
+      kept.push_back(lines[i]);
+    }
+  }
+  while (!kept.empty() && trim_manifest_line(kept.back()).empty()) {
+    kept.pop_back();
+  }
+  content = join_manifest_lines(kept, eol);
+  return true;
+}
+
+/**
+ * @brief Apply a text edit to a manifest file
+ *
+ * The file is read and written as bytes, so the lines the edit doesn't touch
+ * are kept exactly, line endings included; set_manifest_value() and
+ * unset_manifest_value() write CRLF into a CRLF file.
+ *
+ * @param file Manifest to edit
+ * @param edit Edits the content in place; returns false if there was nothing
+ *        to change
+ * @return false if the file can't be read or written, or `edit` returned false
+ */
+inline bool edit_manifest_file(const std::filesystem::path &file,
+                               const std::function<bool(std::string &)> &edit) {
+  std::string content;
+  {
+    std::ifstream in(file, std::ios::binary);
+    if (!in) {
+      return false;
+    }
+    std::stringstream buffer;
+    buffer << in.rdbuf();
+    content = buffer.str();
+  }
+  if (!edit(content)) {
+    return false;
+  }
+  std::ofstream out(file, std::ios::binary);
+  if (!out) {
+    return false;
+  }
+  out << content;
+  return static_cast<bool>(out);
+}
+
+/**
+ * @brief Check edited manifest text before it is written
+ *
+ * The text must parse as TOML. When @p path is given, the parsed document
+ * must also have the key set (or, with @p expect_set false, no longer set),
+ * which catches an edit that landed inside an inline table or array.
+ *
+ * @param content Edited manifest text
+ * @param file Manifest the text belongs to, for parse errors
+ * @param path Dotted path of the edited key, or empty to only check the syntax
+ * @param expect_set Whether @p path must be set after the edit
+ * @param parsed Receives the parsed document, if not null
+ * @return Empty if the text can be written, otherwise what is wrong with it
+ */
+inline std::string check_manifest_edit(const std::string &content,
+                                       const std::filesystem::path &file,
+                                       const std::string &path = "",
+                                       bool expect_set         = true,
+                                       toml::table *parsed     = nullptr) {
+  toml::table table;
+  try {
+    table = toml::parse(content, file.string());
+  } catch (const toml::parse_error &e) {
+    return std::string(e.description()) + " at line " + std::to_string(e.source().begin.line);
+  }
+  if (!path.empty() && static_cast<bool>(table.at_path(path)) != expect_set) {
+    return "'" + path + "' would still " + (expect_set ? "not be set" : "be set");
+  }
+  if (parsed != nullptr) {
+    *parsed = std::move(table);
+  }
+  return "";
+}
+
+/**
+ * @brief Apply a text edit to a manifest file if the result checks out
+ *
+ * Like edit_manifest_file(), but the edited text has to pass
+ * check_manifest_edit() first. Problems are printed, with a hint to edit the
+ * file by hand when the edit itself was rejected; the file is left alone.
+ *
+ * @param file Manifest to edit
+ * @param edit Edits the content in place; returns false if there was nothing
+ *        to change, which is not reported
+ * @param path Dotted path of the edited key, or empty to only check the syntax
+ * @param expect_set Whether @p path must be set after the edit
+ * @return true if the edit was written
+ */
+inline bool write_manifest_edit(const std::filesystem::path &file,
+                                const std::function<bool(std::string &)> &edit,
+                                const std::string &path = "",
+                                bool expect_set         = true) {
+  if (!std::filesystem::exists(file)) {
+    logger::print_error("Failed to read " + file.string());
+    return false;
+  }
+  bool changed = false;
+  std::string problem;
+  auto checked = [&](std::string &content) {
+    changed = edit(content);
+    problem = changed ? check_manifest_edit(content, file, path, expect_set) : "";
+    return changed && problem.empty();
+  };
+  if (edit_manifest_file(file, checked)) {
+    return true;
+  }
+  if (!problem.empty()) {
+    logger::print_error("Could not edit " + file.filename().string() + ": " + problem);
+    logger::print_hint("edit " + file.string() + " by hand");
+  } else if (changed) {
+    logger::print_error("Failed to write " + file.string());
+  }
+  return false;
+}
+
+}  // namespace cforge
@@ -0,0 +1,230 @@
+/**
+ * @file manifest_schema.hpp
+ * @brief Keys cforge reads from cforge.toml, their types, and finding
+ *        misspelled ones
+ *
+ * toml++ keeps every key it parses, so a misspelled key such as
+ * `defins = [...]` is never an error by itself; it is simply never read. The
+ * tables checked here have a fixed set of keys, so anything else in them is
+ * reported with its line and the closest key cforge knows. Tables whose keys
+ * are names the user picks, such as [dependencies] or [scripts], are not
+ * checked.
+ */
+
+#pragma once
+
+#include "core/types.h"
+
+#include <toml++/toml.hpp>
+
+#include <algorithm>
+#include <map>
+#include <string>
+#include <vector>
+
+namespace cforge {
+
+/**
+ * @brief Levenshtein distance between two keys
+ */
+inline cforge_size_t manifest_key_distance(const std::string &a, const std::string &b) {
+  std::vector<cforge_size_t> row(b.size() + 1);
+  for (cforge_size_t j = 0; j <= b.size(); ++j) {
+    row[j] = j;
+  }
+  for (cforge_size_t i = 1; i <= a.size(); ++i) {
+    cforge_size_t diagonal = row[0];
+    row[0]                 = i;
+    for (cforge_size_t j = 1; j <= b.size(); ++j) {
+      cforge_size_t above = row[j];
+      row[j]   = std::min({row[j] + 1, row[j - 1] + 1, diagonal + (a[i - 1] == b[j - 1] ? 0 : 1)});
+      diagonal = above;
+    }
+  }
+  return row[b.size()];
+}
+
+/**
+ * @brief The known key closest to `name`, if it is close enough to be a typo
+ *
+ * A key matches when it is at most two edits away, and at most a third of
+ * `name`'s length, so short unrelated keys don't match each other.
+ *
+ * @return The closest key, or empty if none is close
+ */
+inline std::string closest_manifest_key(const std::string &name,
+                                        const std::vector<std::string> &known) {
+  cforge_size_t best = std::min<cforge_size_t>(2, name.size() / 3) + 1;
+  std::string closest;
+  for (const auto &candidate : known) {
+    cforge_size_t distance = manifest_key_distance(name, candidate);
+    if (distance < best) {
+      closest = candidate;
+      best    = distance;
+    }
+  }
+  return closest;
+}
+
+/**
+ * @brief Keys of the cforge.toml tables that have a fixed set of keys
+ *
+ * Includes keys that are only documented, such as project.license, so
+ * manifests written from the docs are not reported.
+ */
+inline const std::map<std::string, std::vector<std::string>> &known_manifest_keys() {
+  static const std::map<std::string, std::vector<std::string>> keys = {
+      {"project",
+       {"additional_includes", "additional_sources", "authors", "binary_type", "c_extensions",
+        "c_standard", "cpp_extensions", "cpp_standard", "default_run_target", "description",
+        "homepage", "languages", "license", "min_compiler", "name", "namespace", "repository",
+        "type", "version"}},
+      {"build",
+       {"build_dir", "build_type", "cmake_args", "cmake_variables", "compiler_cache", "config",
+        "configs", "default_config", "defines", "directory", "exclude_sources",
+        "export_all_symbols", "export_compile_commands", "fail_on_circular", "flags", "generator",
+        "include_dirs", "info", "interprocedural_optimization", "keep_logs", "libraries", "links",
+        "ninja", "pkg_config", "position_independent_code", "precompiled_headers", "runtime",
+        "source_dirs", "system_links", "visibility_hidden", "warn_circular"}},
+      {"test",
+       {"auto_link_project", "boost", "catch", "defines", "directory", "discovery", "doctest",
+        "enabled", "framework", "gtest", "jobs", "output_style", "sources", "targets",
+        "timeout"}},
+      {"package",
+       {"auto_versions", "categories", "cmake_config", "contact", "deb", "description",
+        "documentation", "enabled", "features", "formats", "generators", "homepage",
+        "include_debug", "include_files", "keywords", "license", "max_versions", "name", "nsis",
+        "output_dir", "repository", "rpm", "tag_exclude", "tag_pattern", "vendor", "verified"}},
+  };
+  return keys;
+}
+
+/**
+ * @brief Types of the keys cforge reads as a single type
+ *
+ * Keys missing here either accept more than one form, such as build.links, or
+ * are not read through a fixed path.
+ */
+inline const std::map<std::string, toml::node_type> &manifest_key_types() {
+  using type = toml::node_type;
+  static const std::map<std::string, type> types = {
+      {"project.additional_sources", type::array},
+      {"project.authors", type::array},
+      {"project.binary_type", type::string},
+      {"project.c_extensions", type::boolean},
+      {"project.c_standard", type::string},
+      {"project.cpp_extensions", type::boolean},
+      {"project.cpp_standard", type::string},
+      {"project.default_run_target", type::string},
+      {"project.description", type::string},
+      {"project.languages", type::array},
+      {"project.name", type::string},
+      {"project.namespace", type::string},
+      {"project.type", type::string},
+      {"project.version", type::string},
+      {"build.build_dir", type::string},
+      {"build.build_type", type::string},
+      {"build.compiler_cache", type::string},
+      {"build.default_config", type::string},
+      {"build.defines", type::array},
+      {"build.directory", type::string},
+      {"build.exclude_sources", type::array},
+      {"build.export_all_symbols", type::boolean},
+      {"build.export_compile_commands", type::boolean},
+      {"build.fail_on_circular", type::boolean},
+      {"build.generator", type::string},
+      {"build.include_dirs", type::array},
+      {"build.interprocedural_optimization", type::boolean},
+      {"build.keep_logs", type::integer},
+      {"build.pkg_config", type::array},
+      {"build.position_independent_code", type::boolean},
+      {"build.precompiled_headers", type::array},
+      {"build.source_dirs", type::array},
+      {"build.visibility_hidden", type::boolean},
+      {"build.warn_circular", type::boolean},
+      {"test.auto_link_project", type::boolean},
+      {"test.defines", type::array},
+      {"test.directory", type::string},
+      {"test.discovery", type::string},
+      {"test.framework", type::string},
+      {"test.jobs", type::integer},
+      {"test.output_style", type::string},
+      {"test.sources", type::array},
+      {"test.timeout", type::integer},
+      {"package.auto_versions", type::boolean},
+      {"package.categories", type::array},
+      {"package.cmake_config", type::boolean},
+      {"package.description", type::string},
+      {"package.documentation", type::string},
+      {"package.enabled", type::boolean},
+      {"package.formats", type::array},
+      {"package.generators", type::array},
+      {"package.homepage", type::string},
+      {"package.include_debug", type::boolean},
+      {"package.include_files", type::array},
+      {"package.keywords", type::array},
+      {"package.license", type::string},
+      {"package.max_versions", type::integer},
+      {"package.name", type::string},
+      {"package.output_dir", type::string},
+      {"package.repository", type::string},
+      {"package.tag_exclude", type::array},
+      {"package.tag_pattern", type::string},
+      {"package.vendor", type::string},
+      {"package.verified", type::boolean},
+  };
+  return types;
+}
+
+/**
+ * @brief The type cforge reads a key as
+ *
+ * @param path Dotted path, e.g. "build.source_dirs"
+ * @return The key's type, or toml::node_type::none if it is not fixed
+ */
+inline toml::node_type expected_manifest_type(const std::string &path) {
+  auto it = manifest_key_types().find(path);
+  return it == manifest_key_types().end() ? toml::node_type::none : it->second;
+}
+
+/**
+ * @brief A key in a checked table that cforge does not read
+ */
+struct unknown_manifest_key {
+  std::string path;        ///< Dotted path, e.g. "build.defins"
+  std::string file;        ///< File the key was parsed from, e.g. a workspace's [defaults]
+  cforge_size_t line = 0;  ///< Line in `file`, 0 if unknown
+  std::string suggestion;  ///< Closest known key in the same table, may be empty
+};
+
+/**
+ * @brief Find the keys in [project], [build], [test] and [package] that
+ *        cforge does not read
+ *
+ * @param config Parsed cforge.toml
+ * @return Unknown keys in table order, then key order
+ */
+inline std::vector<unknown_manifest_key> find_unknown_table_keys(const toml::table &config) {
+  std::vector<unknown_manifest_key> unknown;
+  for (const auto &[table_name, known] : known_manifest_keys()) {
+    const toml::table *table = config[table_name].as_table();
+    if (!table) {
+      continue;
+    }
+    for (const auto &[key, value] : *table) {
+      std::string name(key.str());
+      if (std::find(known.begin(), known.end(), name) != known.end()) {
+        continue;
+      }
+      unknown_manifest_key entry;
+      entry.path       = table_name + "." + name;
+      entry.file       = value.source().path ? *value.source().path : "";
+      entry.line       = static_cast<cforge_size_t>(value.source().begin.line);
+      entry.suggestion = closest_manifest_key(name, known);
+      unknown.push_back(entry);
+    }
+  }
+  return unknown;
+}
+
+}  // namespace cforge
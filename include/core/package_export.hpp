@@ -0,0 +1,185 @@
+/**
+ * @file package_export.hpp
+ * @brief CMake package export generation for library projects
+ *
+ * A library target is always reachable as `<name>::<name>`: inside the build
+ * through an ALIAS target, and after installation through the exported
+ * `<name>Targets.cmake`. Both spellings come from the same package name so
+ * workspace members and find_package() consumers link the same target.
+ */
+
+#pragma once
+
+#include <algorithm>
+#include <cctype>
+#include <filesystem>
+#include <sstream>
+#include <string>
+
+namespace cforge {
+
+/**
+ * @brief Generate the namespaced ALIAS and export name for a library target
+ *
+ * EXPORT_NAME pins the name written to `<name>Targets.cmake` to the package
+ * name, so the installed target matches the in-tree alias even if the
+ * underlying CMake target is named differently.
+ *
+ * @param target CMake target expression (e.g. `${PROJECT_NAME}`)
+ * @param package_name Package name, used as both namespace and export name
+ * @return CMake code
+ */
+inline std::string generate_library_alias_cmake(const std::string &target,
+                                                const std::string &package_name) {
+  std::ostringstream out;
+  out << "add_library(" << package_name << "::" << package_name << " ALIAS " << target << ")\n";
+  out << "set_target_properties(" << target << " PROPERTIES EXPORT_NAME " << package_name
+      << ")\n\n";
+  return out.str();
+}
+
+/**
+ * @brief Identifier used for a shared library's export header and macros
+ *
+ * Same rule as the names `cforge init` uses in code: anything that is not
+ * alphanumeric becomes `_`, and a leading digit gets a `_` prefix.
+ *
+ * @param project_name Project name from cforge.toml
+ * @return Base name; the header is `<base>/export.h`, the macro `<BASE>_EXPORT`
+ */
+inline std::string export_header_base_name(const std::string &project_name) {
+  std::string base = project_name;
+  for (char &c : base) {
+    if (!std::isalnum(static_cast<unsigned char>(c)) && c != '_') {
+      c = '_';
+    }
+  }
+  if (!base.empty() && std::isdigit(static_cast<unsigned char>(base[0]))) {
+    base = "_" + base;
+  }
+  return base;
+}
+
+/**
+ * @brief Generate the export header and symbol visibility for a shared library
+ *
+ * generate_export_header() writes `<base>/export.h` into the build tree with
+ * `<BASE>_EXPORT`, which expands to dllexport/dllimport on Windows and to
+ * default visibility elsewhere. `<BASE>_EXPORTS` is defined only while the
+ * library itself is compiled. Unless @p export_all_symbols is set, every
+ * other symbol is hidden, so GCC/Clang builds export exactly what a Windows
+ * DLL does.
+ *
+ * @param target CMake target expression (e.g. `${PROJECT_NAME}`)
+ * @param base_name Result of export_header_base_name()
+ * @param export_all_symbols Keep default visibility and WINDOWS_EXPORT_ALL_SYMBOLS
+ * @return CMake code
+ */
+inline std::string generate_export_header_cmake(const std::string &target,
+                                                const std::string &base_name,
+                                                bool export_all_symbols) {
+  std::string upper = base_name;
+  std::transform(upper.begin(), upper.end(), upper.begin(), [](unsigned char c) {
+    return static_cast<char>(std::toupper(c));
+  });
+
+  std::ostringstream out;
+  out << "# Export macros (" << upper << "_EXPORT) in " << base_name << "/export.h\n";
+  out << "include(GenerateExportHeader)\n";
+  out << "set_target_properties(" << target << " PROPERTIES DEFINE_SYMBOL " << upper
+      << "_EXPORTS)\n";
+  out << "generate_export_header(" << target << "\n";
+  out << "    BASE_NAME " << base_name << "\n";
+  out << "    EXPORT_FILE_NAME \"${CMAKE_CURRENT_BINARY_DIR}/export/" << base_name
+      << "/export.h\"\n";
+  out << ")\n";
+  out << "target_include_directories(" << target
+      << " PUBLIC \"$<BUILD_INTERFACE:${CMAKE_CURRENT_BINARY_DIR}/export>\")\n";
+  if (export_all_symbols) {
+    out << "set_target_properties(" << target << " PROPERTIES WINDOWS_EXPORT_ALL_SYMBOLS ON)\n\n";
+  } else {
+    out << "set_target_properties(" << target << " PROPERTIES\n";
+    out << "    C_VISIBILITY_PRESET hidden\n";
+    out << "    CXX_VISIBILITY_PRESET hidden\n";
+    out << "    VISIBILITY_INLINES_HIDDEN ON\n";
+    out << ")\n\n";
+  }
+  return out.str();
+}
+
+/**
+ * @brief Generate the install rules for a relocatable CMake package
+ *
+ * Installs `<name>Targets.cmake` from the target's export set (CMake writes
+ * one file per configuration with the real artifact locations) together with
+ * `<name>Config.cmake` and `<name>ConfigVersion.cmake`, so an installed
+ * library can be consumed with `find_package(<name>)` and linked as
+ * `<name>::<name>`. A `cmake/<name>Config.cmake.in` in the project replaces
+ * the default config template.
+ *
+ * @param project_dir Project directory
+ * @param target CMake target expression (e.g. `${PROJECT_NAME}`)
+ * @param package_name Package name (also the export set and namespace)
+ * @param binary_type Project binary type (shared_lib, static_lib, header_only)
+ * @return CMake code
+ */
+inline std::string generate_package_config_cmake(const std::filesystem::path &project_dir,
+                                                 const std::string &target,
+                                                 const std::string &package_name,
+                                                 const std::string &binary_type) {
+  const std::string &pkg = package_name;
+  std::ostringstream out;
+
+  out << "# CMake package configuration (find_package support)\n";
+  if (binary_type == "header_only") {
+    out << "include(GNUInstallDirs)\n";
+    out << "install(TARGETS " << target << "\n";
+    out << "    EXPORT " << pkg << "Targets\n";
+    out << "    INCLUDES DESTINATION ${CMAKE_INSTALL_INCLUDEDIR}\n";
+    out << ")\n";
+  }
+  out << "include(CMakePackageConfigHelpers)\n";
+  out << "set(CFORGE_PACKAGE_CMAKE_DIR \"${CMAKE_INSTALL_LIBDIR}/cmake/" << pkg << "\")\n";
+  out << "install(EXPORT " << pkg << "Targets\n";
+  out << "    FILE " << pkg << "Targets.cmake\n";
+  out << "    NAMESPACE " << pkg << "::\n";
+  out << "    DESTINATION ${CFORGE_PACKAGE_CMAKE_DIR}\n";
+  out << "    COMPONENT Headers\n";
+  out << ")\n";
+
+  std::string config_template = "cmake/" + pkg + "Config.cmake.in";
+  if (std::filesystem::exists(project_dir / config_template)) {
+    out << "set(CFORGE_PACKAGE_CONFIG_TEMPLATE \"${SOURCE_DIR}/" << config_template << "\")\n";
+  } else {
+    out << "set(CFORGE_PACKAGE_CONFIG_TEMPLATE \"${CMAKE_CURRENT_BINARY_DIR}/" << pkg
+        << "Config.cmake.in\")\n";
+    out << "file(WRITE \"${CFORGE_PACKAGE_CONFIG_TEMPLATE}\"\n";
+    out << "    \"@PACKAGE_INIT@\\n\\n\"\n";
+    out << "    \"include(\\\"\\${CMAKE_CURRENT_LIST_DIR}/" << pkg << "Targets.cmake\\\")\\n\"\n";
+    out << "    \"check_required_components(" << pkg << ")\\n\"\n";
+    out << ")\n";
+  }
+  out << "configure_package_config_file(\"${CFORGE_PACKAGE_CONFIG_TEMPLATE}\"\n";
+  out << "    \"${CMAKE_CURRENT_BINARY_DIR}/" << pkg << "Config.cmake\"\n";
+  out << "    INSTALL_DESTINATION ${CFORGE_PACKAGE_CMAKE_DIR}\n";
+  out << ")\n";
+  out << "write_basic_package_version_file(\n";
+  out << "    \"${CMAKE_CURRENT_BINARY_DIR}/" << pkg << "ConfigVersion.cmake\"\n";
+  out << "    VERSION ${PROJECT_VERSION}\n";
+  out << "    COMPATIBILITY SameMajorVersion\n";
+  if (binary_type == "header_only") {
+    // Header-only packages work for any architecture (CMake 3.14+)
+    out << "    ARCH_INDEPENDENT\n";
+  }
+  out << ")\n";
+  out << "install(FILES\n";
+  out << "    \"${CMAKE_CURRENT_BINARY_DIR}/" << pkg << "Config.cmake\"\n";
+  out << "    \"${CMAKE_CURRENT_BINARY_DIR}/" << pkg << "ConfigVersion.cmake\"\n";
+  out << "    DESTINATION ${CFORGE_PACKAGE_CMAKE_DIR}\n";
+  out << "    COMPONENT Headers\n";
+  out << ")\n\n";
+
+  return out.str();
+}
+
+}  // namespace cforge
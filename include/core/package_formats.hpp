@@ -0,0 +1,191 @@
+/**
+ * @file package_formats.hpp
+ * @brief Package formats from [package], and the CPack settings behind them
+ *
+ * `formats = ["zip", "deb", "nsis"]` picks the CPack generators that
+ * `cforge package` runs, one at a time. Each format can have its own table,
+ * such as [package.deb], whose keys become CPack variables.
+ */
+
+#pragma once
+
+#include "core/types.h"
+
+#include <algorithm>
+#include <cctype>
+#include <cstdint>
+#include <filesystem>
+#include <iomanip>
+#include <map>
+#include <sstream>
+#include <string>
+#include <vector>
+
+namespace cforge {
+
+/**
+ * @brief Map a package format to its CPack generator
+ *
+ * Formats are matched case-insensitively, so CPack generator names written
+ * in package.generators (`ZIP`, `DragNDrop`) keep working.
+ *
+ * @param format Format name, e.g. "zip", "deb" or "nsis"
+ * @return CPack generator name, or empty for an unknown format
+ */
+inline std::string package_generator_for_format(const std::string &format) {
+  static const std::map<std::string, std::string> generators = {
+      {"zip",       "ZIP"      },
+      {"tgz",       "TGZ"      },
+      {"txz",       "TXZ"      },
+      {"tbz2",      "TBZ2"     },
+      {"7z",        "7Z"       },
+      {"deb",       "DEB"      },
+      {"rpm",       "RPM"      },
+      {"nsis",      "NSIS"     },
+      {"nsis64",    "NSIS64"   },
+      {"wix",       "WIX"      },
+      {"dmg",       "DragNDrop"},
+      {"dragndrop", "DragNDrop"},
+  };
+  std::string lower = format;
+  std::transform(lower.begin(), lower.end(), lower.begin(), ::tolower);
+  auto it = generators.find(lower);
+  return it != generators.end() ? it->second : "";
+}
+
+/**
+ * @brief Extension of the file a CPack generator writes
+ *
+ * @return Extension including the dot, e.g. ".tar.gz", or empty if unknown
+ */
+inline std::string package_file_extension(const std::string &generator) {
+  static const std::map<std::string, std::string> extensions = {
+      {"ZIP",       ".zip"    },
+      {"TGZ",       ".tar.gz" },
+      {"TXZ",       ".tar.xz" },
+      {"TBZ2",      ".tar.bz2"},
+      {"7Z",        ".7z"     },
+      {"DEB",       ".deb"    },
+      {"RPM",       ".rpm"    },
+      {"NSIS",      ".exe"    },
+      {"NSIS64",    ".exe"    },
+      {"WIX",       ".msi"    },
+      {"DragNDrop", ".dmg"    },
+  };
+  auto it = extensions.find(generator);
+  return it != extensions.end() ? it->second : "";
+}
+
+/**
+ * @brief System name used in package file names
+ */
+inline std::string package_system_name() {
+#ifdef _WIN32
+  return "win64";
+#elif defined(__APPLE__)
+  return "macos";
+#else
+  return "linux";
+#endif
+}
+
+/**
+ * @brief Package file name without extension, e.g. "app-1.0.0-linux-release"
+ */
+inline std::string package_file_stem(const std::string &name,
+                                     const std::string &version,
+                                     const std::string &config) {
+  std::string config_lower = config;
+  std::transform(config_lower.begin(), config_lower.end(), config_lower.begin(), ::tolower);
+  return name + "-" + version + "-" + package_system_name() + "-" + config_lower;
+}
+
+/**
+ * @brief Whether `filename` is a package CPack wrote for `stem` and `generator`
+ *
+ * Matches "<stem><anything><extension>", so per-component packages such as
+ * "app-1.0.0-linux-release-Runtime.deb" count too.
+ */
+inline bool is_package_file(const std::string &filename,
+                            const std::string &stem,
+                            const std::string &generator) {
+  std::string extension = package_file_extension(generator);
+  return !extension.empty() && filename.size() >= stem.size() + extension.size()
+         && filename.compare(0, stem.size(), stem) == 0
+         && filename.compare(filename.size() - extension.size(), extension.size(), extension) == 0;
+}
+
+/**
+ * @brief CPack variables for a format's table, such as [package.deb]
+ *
+ * | Table          | Key          | CPack variable                   |
+ * |----------------|--------------|----------------------------------|
+ * | [package.deb]  | `maintainer` | CPACK_DEBIAN_PACKAGE_MAINTAINER  |
+ * | [package.deb]  | `depends`    | CPACK_DEBIAN_PACKAGE_DEPENDS     |
+ * | [package.deb]  | `section`    | CPACK_DEBIAN_PACKAGE_SECTION     |
+ * | [package.rpm]  | `release`    | CPACK_RPM_PACKAGE_RELEASE        |
+ * | [package.rpm]  | `requires`   | CPACK_RPM_PACKAGE_REQUIRES       |
+ * | [package.nsis] | `icon`       | CPACK_NSIS_MUI_ICON              |
+ * | [package.nsis] | `license`    | CPACK_RESOURCE_FILE_LICENSE      |
+ *
+ * NSIS paths are relative to the project directory.
+ *
+ * @param generator CPack generator
+ * @param settings Keys of the format's table; lists joined with ", "
+ * @param project_dir Project directory
+ * @return `NAME=value` strings for `cpack -D`
+ */
+inline std::vector<std::string> package_format_variables(
+    const std::string &generator,
+    const std::map<std::string, std::string> &settings,
+    const std::filesystem::path &project_dir) {
+  struct variable {
+    const char *key;
+    const char *name;
+    bool path;
+  };
+  std::vector<variable> variables;
+  if (generator == "DEB") {
+    variables = {{"maintainer", "CPACK_DEBIAN_PACKAGE_MAINTAINER", false},
+                 {"depends", "CPACK_DEBIAN_PACKAGE_DEPENDS", false},
+                 {"section", "CPACK_DEBIAN_PACKAGE_SECTION", false}};
+  } else if (generator == "RPM") {
+    variables = {{"release", "CPACK_RPM_PACKAGE_RELEASE", false},
+                 {"requires", "CPACK_RPM_PACKAGE_REQUIRES", false}};
+  } else if (generator == "NSIS" || generator == "NSIS64") {
+    variables = {{"icon", "CPACK_NSIS_MUI_ICON", true},
+                 {"license", "CPACK_RESOURCE_FILE_LICENSE", true}};
+  }
+
+  std::vector<std::string> result;
+  for (const auto &var : variables) {
+    auto it = settings.find(var.key);
+    if (it == settings.end() || it->second.empty()) {
+      continue;
+    }
+    std::string value = it->second;
+    if (var.path) {
+      value = (project_dir / value).lexically_normal().generic_string();
+    }
+    result.push_back(std::string(var.name) + "=" + value);
+  }
+  return result;
+}
+
+/**
+ * @brief Format a package size for display, e.g. "1.4 MB"
+ */
+inline std::string format_package_size(std::uintmax_t bytes) {
+  cforge_cstring_t units[] = {"B", "KB", "MB", "GB"};
+  cforge_int_t unit        = 0;
+  cforge_double_t size     = static_cast<double>(bytes);
+  while (size >= 1024 && unit < 3) {
+    size /= 1024;
+    unit++;
+  }
+  std::ostringstream out;
+  out << std::fixed << std::setprecision(unit > 0 ? 1 : 0) << size << " " << units[unit];
+  return out.str();
+}
+
+}  // namespace cforge
@@ -0,0 +1,163 @@
+/**
+ * @file pch.hpp
+ * @brief Precompiled header configuration and CMake generation
+ *
+ * Reads the [pch] section of cforge.toml and emits
+ * target_precompile_headers() calls for the generated targets:
+ *
+ *   [pch]
+ *   enabled = true                    # default when [pch] is present
+ *   header = "include/pch.h"          # or headers = [...]
+ *   source = "src/pch.cpp"            # optional, added to MSVC targets
+ *   exclude_sources = ["src/c_api.c"] # compiled without the PCH
+ *   exclude_targets = ["tests"]       # per-target opt-out
+ *   reuse = "core"                    # REUSE_FROM a workspace dependency
+ */
+
+#pragma once
+
+#include "core/toml_reader.hpp"
+
+#include <algorithm>
+#include <filesystem>
+#include <sstream>
+#include <string>
+#include <vector>
+
+namespace cforge {
+
+/**
+ * @brief Precompiled header settings for a project
+ */
+struct pch_config {
+  bool enabled = false;                      ///< True if any header is configured
+  std::vector<std::string> headers;          ///< Headers, project-relative or <system>
+  std::string source;                        ///< Optional MSVC PCH source file
+  std::vector<std::string> exclude_sources;  ///< Sources compiled without the PCH
+  std::vector<std::string> exclude_targets;  ///< Targets that opt out
+  std::string reuse;                         ///< Target to REUSE_FROM, if present
+};
+
+/**
+ * @brief Read precompiled header settings from cforge.toml
+ *
+ * The legacy `build.precompiled_headers` array is merged into the header
+ * list so existing projects keep working.
+ *
+ * @param project_config TOML reader for project config
+ * @return Parsed settings; `enabled` is false if there is nothing to do
+ */
+inline pch_config read_pch_config(const toml_reader &project_config) {
+  pch_config cfg;
+
+  if (project_config.has_key("pch") && project_config.get_bool("pch.enabled", true)) {
+    std::string header = project_config.get_string("pch.header", "");
+    if (!header.empty()) {
+      cfg.headers.push_back(header);
+    }
+    for (const auto &h : project_config.get_string_array("pch.headers")) {
+      cfg.headers.push_back(h);
+    }
+    cfg.source          = project_config.get_string("pch.source", "");
+    cfg.exclude_sources = project_config.get_string_array("pch.exclude_sources");
+    cfg.exclude_targets = project_config.get_string_array("pch.exclude_targets");
+    cfg.reuse           = project_config.get_string("pch.reuse", "");
+  }
+
+  for (const auto &h : project_config.get_string_array("build.precompiled_headers")) {
+    if (std::find(cfg.headers.begin(), cfg.headers.end(), h) == cfg.headers.end()) {
+      cfg.headers.push_back(h);
+    }
+  }
+
+  cfg.enabled = !cfg.headers.empty() || !cfg.reuse.empty();
+  return cfg;
+}
+
+/**
+ * @brief Check whether a target opted out of the precompiled header
+ *
+ * @param cfg Precompiled header settings
+ * @param target_name Target name (e.g. the project name, or "tests")
+ * @return true if the PCH should be applied to the target
+ */
+inline bool pch_applies_to(const pch_config &cfg, const std::string &target_name) {
+  return cfg.enabled
+      && std::find(cfg.exclude_targets.begin(), cfg.exclude_targets.end(), target_name)
+             == cfg.exclude_targets.end();
+}
+
+/**
+ * @brief Generate the CMake commands that attach the PCH to a target
+ *
+ * Project-relative paths are prefixed with `source_dir` (a CMake expression
+ * such as `${CMAKE_CURRENT_SOURCE_DIR}`); `<vector>`-style entries are passed
+ * through unchanged. With `reuse`, the dependency's PCH is reused when that
+ * target exists in the same build and the own headers are used otherwise.
+ *
+ * @param cfg Precompiled header settings
+ * @param target CMake target expression (e.g. `${PROJECT_NAME}`)
+ * @param source_dir CMake expression for the project directory
+ * @return CMake code, or an empty string if there is nothing to emit
+ */
+inline std::string generate_pch_cmake(const pch_config &cfg,
+                                      const std::string &target,
+                                      const std::string &source_dir) {
+  if (!cfg.enabled) {
+    return "";
+  }
+
+  auto to_cmake_entry = [&](const std::string &path) {
+    if (!path.empty() && path.front() == '<') {
+      return "\"" + path + "\"";
+    }
+    std::filesystem::path p(path);
+    if (p.is_absolute()) {
+      return "\"" + p.generic_string() + "\"";
+    }
+    return "\"" + source_dir + "/" + p.generic_string() + "\"";
+  };
+
+  std::ostringstream own;
+  if (!cfg.headers.empty()) {
+    own << "target_precompile_headers(" << target << " PRIVATE";
+    for (const auto &header : cfg.headers) {
+      own << " " << to_cmake_entry(header);
+    }
+    own << ")\n";
+  }
+
+  std::ostringstream out;
+  out << "# Precompiled headers\n";
+  out << "if(CMAKE_VERSION VERSION_GREATER_EQUAL 3.16)\n";
+  if (!cfg.reuse.empty()) {
+    out << "  if(TARGET " << cfg.reuse << ")\n";
+    out << "    target_precompile_headers(" << target << " REUSE_FROM " << cfg.reuse << ")\n";
+    if (!cfg.headers.empty()) {
+      out << "  else()\n";
+      out << "    " << own.str();
+    }
+    out << "  endif()\n";
+  } else {
+    out << "  " << own.str();
+  }
+  if (!cfg.source.empty()) {
+    out << "  if(MSVC)\n";
+    out << "    target_sources(" << target << " PRIVATE " << to_cmake_entry(cfg.source) << ")\n";
+    out << "  endif()\n";
+  }
+  if (!cfg.exclude_sources.empty()) {
+    out << "  set_source_files_properties(";
+    for (const auto &src : cfg.exclude_sources) {
+      out << to_cmake_entry(src) << " ";
+    }
+    out << "PROPERTIES SKIP_PRECOMPILE_HEADERS ON)\n";
+  }
+  out << "else()\n";
+  out << "  message(WARNING \"Precompiled headers require CMake 3.16 or newer\")\n";
+  out << "endif()\n\n";
+
+  return out.str();
+}
+
+}  // namespace cforge
@@ -0,0 +1,61 @@
+/**
+ * @file pkg_config.hpp
+ * @brief Helpers for the pkg-config modules listed in [build] pkg_config
+ */
+
+#pragma once
+
+#include "core/types.h"
+
+#include <cctype>
+#include <string>
+
+namespace cforge {
+
+/**
+ * @brief Normalize a pkg-config module spec for pkg_check_modules()
+ *
+ * FindPkgConfig only recognizes a version constraint written without
+ * spaces, so `"libcurl >= 7.68"` becomes `libcurl>=7.68`.
+ *
+ * @param spec Module spec as written in cforge.toml
+ * @return Spec with all whitespace removed
+ */
+inline std::string pkg_config_spec(const std::string &spec) {
+  std::string result;
+  for (char c : spec) {
+    if (!std::isspace(static_cast<unsigned char>(c))) {
+      result += c;
+    }
+  }
+  return result;
+}
+
+/**
+ * @brief Module name of a pkg-config spec, without its version constraint
+ */
+inline std::string pkg_config_module(const std::string &spec) {
+  std::string normalized = pkg_config_spec(spec);
+  return normalized.substr(0, normalized.find_first_of("<>="));
+}
+
+/**
+ * @brief CMake variable prefix for a pkg-config module
+ *
+ * The module name is upper-cased and every character CMake doesn't allow in
+ * a target name is replaced, so `gtk+-3.0` links as `PkgConfig::GTK__3_0`.
+ *
+ * @param spec Module spec as written in cforge.toml
+ * @return Prefix passed to pkg_check_modules()
+ */
+inline std::string pkg_config_variable(const std::string &spec) {
+  std::string variable = pkg_config_module(spec);
+  for (char &c : variable) {
+    c = std::isalnum(static_cast<unsigned char>(c))
+            ? static_cast<char>(std::toupper(static_cast<unsigned char>(c)))
+            : '_';
+  }
+  return variable;
+}
+
+}  // namespace cforge
@@ -0,0 +1,105 @@
+/**
+ * @file platform_settings.hpp
+ * @brief Per-target, per-OS defines and flags for test and benchmark targets
+ *
+ * A [[test.targets]] or [[benchmark.targets]] entry can list defines and
+ * flags that only apply on one OS:
+ *
+ *     [test.targets.platform_defines]
+ *     windows = ["UNICODE", "_UNICODE"]
+ *
+ *     [test.targets.platform_flags]
+ *     linux = ["-mavx2"]
+ *
+ * They are emitted behind `$<PLATFORM_ID:...>` generator expressions, next
+ * to the target's own defines.
+ */
+
+#pragma once
+
+#include "core/toml_reader.hpp"
+#include "core/types.h"
+
+#include <map>
+#include <sstream>
+#include <string>
+#include <vector>
+
+namespace cforge {
+
+/**
+ * @brief Map a cforge platform name to CMake's PLATFORM_ID
+ *
+ * @param os Platform name, as in [platform.<os>]
+ * @return "Windows", "Linux" or "Darwin", or empty for an unknown platform
+ */
+inline std::string cmake_platform_id(const std::string &os) {
+  if (os == "windows") {
+    return "Windows";
+  }
+  if (os == "linux") {
+    return "Linux";
+  }
+  if (os == "macos") {
+    return "Darwin";
+  }
+  return "";
+}
+
+/**
+ * @brief Read a table of per-OS lists, such as `platform_defines`
+ *
+ * @param table Target table
+ * @param key Key of the table inside the target
+ * @param unknown Receives platform names that are not windows, linux or macos
+ * @return Lists by platform name, unknown platforms left out
+ */
+inline std::map<std::string, std::vector<std::string>> read_platform_lists(
+    const toml_reader &table, const std::string &key, std::vector<std::string> &unknown) {
+  std::map<std::string, std::vector<std::string>> lists;
+  for (const auto &os : table.get_table_keys(key)) {
+    if (cmake_platform_id(os).empty()) {
+      unknown.push_back(os);
+      continue;
+    }
+    lists[os] = table.get_string_array(key + "." + os);
+  }
+  return lists;
+}
+
+/**
+ * @brief Generate the CMake code for a target's per-OS defines and flags
+ *
+ * @param platform_defines Defines by platform name
+ * @param platform_flags Compiler flags by platform name
+ * @param target_name CMake target name
+ * @return CMake code string, empty if there is nothing to add
+ */
+inline std::string generate_platform_settings_cmake(
+    const std::map<std::string, std::vector<std::string>> &platform_defines,
+    const std::map<std::string, std::vector<std::string>> &platform_flags,
+    const std::string &target_name) {
+  auto emit = [&](std::ostringstream &out, const char *command,
+                  const std::map<std::string, std::vector<std::string>> &lists) {
+    std::ostringstream items;
+    for (const auto &[os, values] : lists) {
+      std::string platform_id = cmake_platform_id(os);
+      if (platform_id.empty()) {
+        continue;
+      }
+      for (const auto &value : values) {
+        items << "  \"$<$<PLATFORM_ID:" << platform_id << ">:" << value << ">\"\n";
+      }
+    }
+    if (!items.str().empty()) {
+      out << command << "(" << target_name << " PRIVATE\n" << items.str() << ")\n\n";
+    }
+  };
+
+  std::ostringstream cmake;
+  emit(cmake, "target_compile_definitions", platform_defines);
+  emit(cmake, "target_compile_options", platform_flags);
+  return cmake.str();
+}
+
+}  // namespace cforge
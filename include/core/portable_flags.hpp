@@ -13,6 +13,8 @@
 #include <string>
 #include <vector>
 
+#include "cforge/log.hpp"
+
 #include "core/types.h"
 
 namespace cforge {
@@ -32,7 +34,8 @@ struct portable_options {
   // Optimization level: "none", "debug", "size", "speed", "aggressive"
   std::string optimize;
 
-  // Warning level: "none", "default", "all", "strict", "pedantic"
+  // Warning level: "none", "default", "all", "extra", "strict", "pedantic"
+  // (read from `warnings`, or its alias `warning_level`)
   std::string warnings;
 
   // Treat warnings as errors
@@ -221,6 +224,85 @@ linker_options parse_linker_options(const toml_reader &config,
  */
 void merge_linker_options(linker_options &target, const linker_options &source);
 
+/**
+ * @brief A universal flag token usable in `flags = [...]` arrays
+ *
+ * An empty translation means the compiler family has no equivalent and the
+ * token is dropped for that family.
+ */
+struct universal_flag {
+  const char *token;        // Token as written in cforge.toml
+  const char *msvc;         // MSVC-style flag
+  const char *gcc;          // GCC/Clang-style flag
+  const char *description;  // Shown in documentation
+};
+
+/**
+ * @brief Get the table of supported universal flag tokens
+ */
+inline const std::vector<universal_flag> &get_universal_flags() {
+  static const std::vector<universal_flag> flags = {
+      {"OPTIMIZE", "/O2", "-O2", "Optimize for speed"},
+      {"OPTIMIZE_MAX", "/Ox", "-O3", "Maximum optimization"},
+      {"OPTIMIZE_SIZE", "/O1", "-Os", "Optimize for size"},
+      {"NO_OPT", "/Od", "-O0", "Disable optimization"},
+      {"DEBUG_INFO", "/Zi", "-g", "Emit debug information"},
+      {"LTO", "/GL", "-flto", "Link-time optimization"},
+      {"SANITIZE_ADDRESS", "/fsanitize=address", "-fsanitize=address", "AddressSanitizer"},
+      {"SANITIZE_UNDEFINED", "", "-fsanitize=undefined", "UndefinedBehaviorSanitizer"},
+      {"NATIVE_ARCH", "", "-march=native", "Tune for the build machine's CPU"},
+      {"RTTI_OFF", "/GR-", "-fno-rtti", "Disable runtime type information"},
+  };
+  return flags;
+}
+
+/**
+ * @brief Translate universal flag tokens to compiler flags
+ *
+ * Known tokens (see get_universal_flags) are mapped to their MSVC or
+ * GCC/Clang spelling. Raw flags starting with '-' or '/' pass through as-is;
+ * any other unknown token also passes through verbatim, with a warning.
+ *
+ * @param tokens Tokens from a `flags` array
+ * @param is_msvc true for MSVC-style compilers, false for GCC/Clang
+ * @param warn_unknown Warn about unknown tokens
+ * @return Vector of compiler flags
+ */
+inline std::vector<std::string> parse_universal_flags(const std::vector<std::string> &tokens,
+                                                      bool is_msvc,
+                                                      bool warn_unknown = true) {
+  std::vector<std::string> result;
+  for (const auto &token : tokens) {
+    if (token.empty()) {
+      continue;
+    }
+    if (token[0] == '-' || token[0] == '/') {
+      result.push_back(token);
+      continue;
+    }
+
+    bool known = false;
+    for (const auto &flag : get_universal_flags()) {
+      if (token == flag.token) {
+        std::string mapped = is_msvc ? flag.msvc : flag.gcc;
+        if (!mapped.empty()) {
+          result.push_back(mapped);
+        }
+        known = true;
+        break;
+      }
+    }
+
+    if (!known) {
+      if (warn_unknown) {
+        logger::print_warning("Unknown flag '" + token + "', passing it to the compiler as-is");
+      }
+      result.push_back(token);
+    }
+  }
+  return result;
+}
+
 /**
  * @brief Translate portable options to MSVC flags
  *
@@ -333,6 +415,23 @@ generate_config_portable_flags_cmake(const std::string &config_name,
                                      const portable_options &opts,
                                      const std::string &target_name);
 
+/**
+ * @brief Generate CMake code for a configuration's universal `flags` tokens
+ *
+ * Translates the tokens for the MSVC frontend (cl, clang-cl) and for
+ * GCC/Clang, each wrapped in a `$<$<CONFIG:...>:...>` generator expression so
+ * single- and multi-config generators both apply them. LTO and sanitizer
+ * flags are also passed to the linker.
+ *
+ * @param config_name Configuration name (e.g., "Debug", "Release")
+ * @param tokens Universal flag tokens for this configuration
+ * @param target_name CMake target name
+ * @return CMake code string
+ */
+std::string generate_config_universal_flags_cmake(const std::string &config_name,
+                                                  const std::vector<std::string> &tokens,
+                                                  const std::string &target_name);
+
 /**
  * @brief Join a vector of flags into a space-separated string
  *
@@ -7,6 +7,7 @@
 
 #include "cforge/log.hpp"
 
+#include "core/build_progress.hpp"
 #include "core/error_format.hpp"
 
 #include <cstdio>
@@ -59,6 +60,39 @@ process_result execute_process(const std::string &command,
                                std::function<void(const std::string &)> stderr_callback = nullptr,
                                cforge_int_t timeout_seconds                             = 5);
 
+/**
+ * @brief Turns Ctrl+C into a clean stop of running child processes
+ *
+ * While a guard is alive, SIGINT and SIGTERM (Ctrl+C and Ctrl+Break on
+ * Windows) no longer terminate cforge on the spot. Children started by
+ * execute_process() run in their own process group (a job object on Windows);
+ * when the signal arrives the whole group is stopped and execute_process()
+ * returns exit code 130. On POSIX the group gets SIGTERM and two seconds to
+ * exit before SIGKILL. Further calls return 130 without starting anything, so
+ * the caller can unwind, clean up and check was_interrupted(). On POSIX the
+ * group can't read the terminal, so git is told not to prompt for credentials
+ * and fails with an authentication error instead.
+ *
+ * Guards nest. The previous handlers are restored when the outermost guard is
+ * destroyed, so programs run afterwards (e.g. by `cforge run`) keep the
+ * terminal's default Ctrl+C behavior.
+ */
+class interrupt_guard {
+public:
+  interrupt_guard();
+  ~interrupt_guard();
+
+  interrupt_guard(const interrupt_guard &)            = delete;
+  interrupt_guard &operator=(const interrupt_guard &) = delete;
+};
+
+/**
+ * @brief Check whether Ctrl+C was pressed under the current interrupt_guard
+ *
+ * @return true if an interrupt arrived since the outermost guard was created
+ */
+bool was_interrupted();
+
 /**
  * @brief Convert a string to lowercase
  *
@@ -111,7 +145,81 @@ std::vector<std::string> uppercase_generators(const std::vector<std::string> &ge
  */
 std::string join_strings(const std::vector<std::string> &strings, const std::string &delimiter);
 
+/**
+ * @brief Quote one argument for a Windows command line
+ *
+ * Windows passes a single command-line string to the child, which splits it
+ * back into argv with the CRT rules: whitespace separates arguments, quotes
+ * group them, and backslashes are only special before a quote. An argument
+ * that needs quoting is wrapped in quotes with embedded quotes and the
+ * backslashes preceding them (or the closing quote) doubled, so paths such as
+ * `C:\My Projects\` round-trip unchanged.
+ *
+ * @param arg Argument as the child should see it
+ * @return Quoted argument
+ */
+inline std::string quote_windows_argument(const std::string &arg) {
+  if (!arg.empty() && arg.find_first_of(" \t\n\v\"") == std::string::npos) {
+    return arg;
+  }
+
+  std::string quoted        = "\"";
+  cforge_size_t backslashes = 0;
+  for (char c : arg) {
+    if (c == '\\') {
+      ++backslashes;
+      continue;
+    }
+    if (c == '"') {
+      quoted.append(backslashes * 2 + 1, '\\');
+    } else {
+      quoted.append(backslashes, '\\');
+    }
+    quoted.push_back(c);
+    backslashes = 0;
+  }
+  quoted.append(backslashes * 2, '\\');
+  quoted.push_back('"');
+  return quoted;
+}
+
+/**
+ * @brief Build a Windows command line from a program and its arguments
+ *
+ * @param command Program to run
+ * @param args Arguments, one per argv entry
+ * @return Command line for CreateProcess
+ */
+inline std::string build_windows_command_line(const std::string &command,
+                                              const std::vector<std::string> &args) {
+  std::string cmd_line = quote_windows_argument(command);
+  for (const auto &arg : args) {
+    cmd_line += " " + quote_windows_argument(arg);
+  }
+  return cmd_line;
+}
+
 // Global flag to suppress build warnings
 extern bool g_suppress_warnings;
 
+/**
+ * @brief Get the number of compiler warnings seen by the last build tool run
+ *
+ * Updated by execute_tool() for build tools; 0 if no build has run.
+ *
+ * @return cforge_int_t Number of unique warning lines
+ */
+cforge_int_t get_last_build_warning_count();
+
+/**
+ * @brief Get the step timings of the last build invocation
+ *
+ * One entry per compile or link step reported by Ninja, Make or MSBuild, in
+ * the order the steps started. Updated by execute_tool() for build steps;
+ * empty if the build had nothing to do.
+ *
+ * @return Step timings
+ */
+const std::vector<file_timing> &get_last_build_timings();
+
 }  // namespace cforge
\ No newline at end of file
@@ -0,0 +1,472 @@
+/**
+ * @file project_templates.hpp
+ * @brief Embedded project templates used by `cforge new` and `cforge init`
+ *
+ * A template is plain data: a list of files whose paths and contents may
+ * contain `{{name}}` (project name), `{{namespace}}` (the name as a C++
+ * identifier) and `{{cpp_standard}}` placeholders. Adding a template means
+ * adding an entry to project_templates(); nothing else needs to change.
+ */
+
+#pragma once
+
+#include "core/types.h"
+
+#include <filesystem>
+#include <map>
+#include <string>
+#include <vector>
+
+namespace cforge {
+
+/**
+ * @brief A file written by a project template
+ */
+struct template_file {
+  std::string path;     ///< Path relative to the project directory
+  std::string content;  ///< File content with placeholders
+};
+
+/**
+ * @brief A named project template
+ */
+struct project_template {
+  std::string name;
+  std::string description;
+  std::string binary_type;  ///< binary_type written to cforge.toml
+  std::vector<template_file> files;
+};
+
+/**
+ * @brief Placeholder values for rendering a template
+ */
+using template_variables = std::map<std::string, std::string>;
+
+/**
+ * @brief Replace `{{key}}` placeholders in a template string
+ *
+ * Unknown placeholders are left as they are.
+ *
+ * @param text Template text
+ * @param variables Placeholder values
+ * @return Rendered text
+ */
+inline std::string render_template(const std::string &text, const template_variables &variables) {
+  std::string out;
+  out.reserve(text.size());
+  cforge_size_t pos = 0;
+  while (pos < text.size()) {
+    cforge_size_t open = text.find("{{", pos);
+    if (open == std::string::npos) {
+      break;
+    }
+    cforge_size_t close = text.find("}}", open + 2);
+    if (close == std::string::npos) {
+      break;
+    }
+    out.append(text, pos, open - pos);
+    auto it = variables.find(text.substr(open + 2, close - open - 2));
+    if (it != variables.end()) {
+      out += it->second;
+    } else {
+      out.append(text, open, close + 2 - open);
+    }
+    pos = close + 2;
+  }
+  out.append(text, pos, std::string::npos);
+  return out;
+}
+
+/**
+ * @brief Turn a project name into a C++ identifier (`my-app` -> `my_app`)
+ */
+inline std::string template_namespace(const std::string &name) {
+  std::string id;
+  for (char c : name) {
+    bool word = (c >= 'a' && c <= 'z') || (c >= 'A' && c <= 'Z') || (c >= '0' && c <= '9');
+    id += word ? c : '_';
+  }
+  if (id.empty() || (id[0] >= '0' && id[0] <= '9')) {
+    id = "_" + id;
+  }
+  return id;
+}
+
+/**
+ * @brief Placeholder values for a project
+ */
+inline template_variables make_template_variables(const std::string &name,
+                                                  const std::string &cpp_standard) {
+  return {
+      {"name",         name                    },
+      {"namespace",    template_namespace(name)},
+      {"cpp_standard", cpp_standard            },
+  };
+}
+
+/**
+ * @brief .gitignore shared by every template and `cforge init --with-git`
+ */
+inline const template_file &gitignore_template() {
+  static const template_file file = {".gitignore", R"(# Build output
+build/
+build-*/
+out/
+
+# Fetched dependencies
+deps/
+
+# IDEs
+.vs/
+.vscode/
+.idea/
+*.swp
+*.swo
+)"};
+  return file;
+}
+
+/**
+ * @brief Standalone sample test shared with `cforge init --with-tests`
+ *
+ * Uses cforge's builtin framework, so no main() and no fetched framework
+ * are needed.
+ */
+inline const template_file &sample_test_template() {
+  static const template_file file = {"tests/test_example.cpp", R"(/**
+ * @file test_example.cpp
+ * @brief Example tests for {{namespace}}
+ *
+ * Run with: `cforge test`. cforge provides the test framework -
+ * no main() needed, no extra config, no external dependencies.
+ */
+
+#include "test_framework.h"
+
+TEST(Example, BasicAssertions) {
+    cf_assert(1 + 1 == 2);
+    cf_assert_eq(2 * 3, 6);
+    return 0;
+}
+
+TEST(Example, StringCompare) {
+    const char *a = "hello";
+    cf_assert(a != nullptr);
+    return 0;
+}
+)"};
+  return file;
+}
+
+/**
+ * @brief cforge.toml for a template
+ *
+ * @param binary_type Project binary type
+ * @param extra Additional TOML appended after the [test] section
+ */
+inline std::string template_manifest(const std::string &binary_type,
+                                     const std::string &extra = "") {
+  std::string sources = binary_type == "header_only" ? "" : "source_dirs = [\"src\"]\n";
+  return "[project]\n"
+         "name = \"{{name}}\"\n"
+         "version = \"0.1.0\"\n"
+         "description = \"A C++ project created with cforge\"\n"
+         "cpp_standard = \"{{cpp_standard}}\"\n"
+         "binary_type = \""
+         + binary_type
+         + "\"\n\n"
+           "[build]\n"
+           "build_type = \"Debug\"\n"
+         + sources
+         + "include_dirs = [\"include\"]\n\n"
+           "[test]\n"
+           "enabled = true\n"
+           "framework = \"builtin\"\n"
+           "directory = \"tests\"\n"
+         + extra;
+}
+
+/**
+ * @brief README.md for a template
+ */
+inline std::string template_readme(const std::string &summary) {
+  return "# {{name}}\n\n" + summary
+         + "\n\n"
+           "```bash\n"
+           "cforge build   # build the project\n"
+           "cforge test    # run the tests in tests/\n"
+           "```\n";
+}
+
+/**
+ * @brief All embedded project templates, in the order they are listed
+ */
+inline const std::vector<project_template> &project_templates() {
+  static const std::vector<project_template> templates = {
+      {"app",
+       "Executable with a small header library and a test",
+       "executable",
+       {
+           {"cforge.toml", template_manifest("executable")},
+           {"README.md", template_readme("A command-line application.")},
+           {"include/{{namespace}}/{{namespace}}.hpp", R"(#pragma once
+
+#include <string>
+
+namespace {{namespace}} {
+
+inline std::string greeting(const std::string &who) {
+  return "Hello, " + who + "!";
+}
+
+}  // namespace {{namespace}}
+)"},
+           {"src/main.cpp", R"(#include "{{namespace}}/{{namespace}}.hpp"
+
+#include <iostream>
+
+int main() {
+  std::cout << {{namespace}}::greeting("{{name}}") << "\n";
+  return 0;
+}
+)"},
+           {"tests/test_{{namespace}}.cpp", R"(#include "test_framework.h"
+#include "{{namespace}}/{{namespace}}.hpp"
+
+TEST({{namespace}}, Greeting) {
+    cf_assert({{namespace}}::greeting("world") == "Hello, world!");
+    return 0;
+}
+)"},
+       }},
+      {"lib",
+       "Static library with a public header and a linked test",
+       "static_lib",
+       {
+           {"cforge.toml", template_manifest("static_lib")},
+           {"README.md", template_readme("A static library.")},
+           {"include/{{namespace}}/{{namespace}}.hpp", R"(#pragma once
+
+namespace {{namespace}} {
+
+int add(int a, int b);
+
+}  // namespace {{namespace}}
+)"},
+           {"src/{{namespace}}.cpp", R"(#include "{{namespace}}/{{namespace}}.hpp"
+
+namespace {{namespace}} {
+
+int add(int a, int b) {
+  return a + b;
+}
+
+}  // namespace {{namespace}}
+)"},
+           {"tests/test_{{namespace}}.cpp", R"(#include "test_framework.h"
+#include "{{namespace}}/{{namespace}}.hpp"
+
+TEST({{namespace}}, Add) {
+    cf_assert_eq(5, {{namespace}}::add(2, 3));
+    return 0;
+}
+)"},
+       }},
+      {"header-only",
+       "Header-only library",
+       "header_only",
+       {
+           {"cforge.toml", template_manifest("header_only")},
+           {"README.md", template_readme("A header-only library.")},
+           {"include/{{namespace}}/{{namespace}}.hpp", R"(#pragma once
+
+namespace {{namespace}} {
+
+constexpr int add(int a, int b) {
+  return a + b;
+}
+
+}  // namespace {{namespace}}
+)"},
+           {"tests/test_{{namespace}}.cpp", R"(#include "test_framework.h"
+#include "{{namespace}}/{{namespace}}.hpp"
+
+TEST({{namespace}}, Add) {
+    cf_assert_eq(5, {{namespace}}::add(2, 3));
+    return 0;
+}
+)"},
+       }},
+      {"gui",
+       "Windowed application using GLFW",
+       "executable",
+       {
+           {"cforge.toml",
+            template_manifest("executable", R"(
+[dependencies.git.glfw]
+url = "https://github.com/glfw/glfw.git"
+tag = "3.4"
+)")},
+           {"README.md", template_readme("A windowed application using GLFW.")},
+           {"include/{{namespace}}/{{namespace}}.hpp", R"cf(#pragma once
+
+#include <string>
+
+namespace {{namespace}} {
+
+inline std::string window_title(const std::string &name, int width, int height) {
+  return name + " (" + std::to_string(width) + "x" + std::to_string(height) + ")";
+}
+
+}  // namespace {{namespace}}
+)cf"},
+           {"src/main.cpp", R"(#include "{{namespace}}/{{namespace}}.hpp"
+
+#include <GLFW/glfw3.h>
+
+int main() {
+  if (!glfwInit()) {
+    return 1;
+  }
+
+  const int width  = 800;
+  const int height = 600;
+  std::string title = {{namespace}}::window_title("{{name}}", width, height);
+  GLFWwindow *window = glfwCreateWindow(width, height, title.c_str(), nullptr, nullptr);
+  if (!window) {
+    glfwTerminate();
+    return 1;
+  }
+
+  glfwMakeContextCurrent(window);
+  while (!glfwWindowShouldClose(window)) {
+    glfwSwapBuffers(window);
+    glfwPollEvents();
+  }
+
+  glfwDestroyWindow(window);
+  glfwTerminate();
+  return 0;
+}
+)"},
+           {"tests/test_{{namespace}}.cpp", R"cf(#include "test_framework.h"
+#include "{{namespace}}/{{namespace}}.hpp"
+
+TEST({{namespace}}, WindowTitle) {
+    cf_assert({{namespace}}::window_title("app", 800, 600) == "app (800x600)");
+    return 0;
+}
+)cf"},
+       }},
+      {"test-driven",
+       "Static library set up for writing tests first",
+       "static_lib",
+       {
+           {"cforge.toml", template_manifest("static_lib", "timeout = 10\n")},
+           {"README.md",
+            template_readme("A static library developed test-first: add a failing test in\n"
+                            "tests/, make it pass in src/, then refactor.")},
+           {"include/{{namespace}}/{{namespace}}.hpp", R"(#pragma once
+
+namespace {{namespace}} {
+
+int clamp(int value, int low, int high);
+
+}  // namespace {{namespace}}
+)"},
+           {"src/{{namespace}}.cpp", R"(#include "{{namespace}}/{{namespace}}.hpp"
+
+namespace {{namespace}} {
+
+int clamp(int value, int low, int high) {
+  if (value < low) {
+    return low;
+  }
+  if (value > high) {
+    return high;
+  }
+  return value;
+}
+
+}  // namespace {{namespace}}
+)"},
+           {"tests/test_{{namespace}}.cpp", R"(#include "test_framework.h"
+#include "{{namespace}}/{{namespace}}.hpp"
+
+TEST(Clamp, InsideRange) {
+    cf_assert_eq(5, {{namespace}}::clamp(5, 0, 10));
+    return 0;
+}
+
+TEST(Clamp, BelowRange) {
+    cf_assert_eq(0, {{namespace}}::clamp(-3, 0, 10));
+    return 0;
+}
+
+TEST(Clamp, AboveRange) {
+    cf_assert_eq(10, {{namespace}}::clamp(42, 0, 10));
+    return 0;
+}
+)"},
+       }},
+  };
+  return templates;
+}
+
+/**
+ * @brief Look up a project template by name
+ *
+ * @param name Template name
+ * @return Template, or nullptr if there is none with that name
+ */
+inline const project_template *find_project_template(const std::string &name) {
+  for (const auto &tmpl : project_templates()) {
+    if (tmpl.name == name) {
+      return &tmpl;
+    }
+  }
+  return nullptr;
+}
+
+/**
+ * @brief Write one template file into a project
+ *
+ * Existing files are kept unless `overwrite` is set.
+ *
+ * @param project_dir Project directory
+ * @param file Template file
+ * @param variables Placeholder values
+ * @param overwrite Replace an existing file
+ * @return true if the file was written or intentionally kept
+ */
+bool write_template_file(const std::filesystem::path &project_dir,
+                         const template_file &file,
+                         const template_variables &variables,
+                         bool overwrite);
+
+/**
+ * @brief Write every file of a template into a project directory
+ *
+ * @param project_dir Project directory (created if missing)
+ * @param tmpl Template to write
+ * @param variables Placeholder values
+ * @param overwrite Replace existing files
+ * @return true if all files were written
+ */
+bool write_project_template(const std::filesystem::path &project_dir,
+                            const project_template &tmpl,
+                            const template_variables &variables,
+                            bool overwrite);
+
+/**
+ * @brief Run `git init` in a new project
+ *
+ * A missing git or a failing `git init` is reported as a warning; it never
+ * fails project creation.
+ *
+ * @param project_dir Project directory
+ * @param verbose Show git output
+ */
+void init_project_git_repository(const std::filesystem::path &project_dir, bool verbose);
+
+}  // namespace cforge
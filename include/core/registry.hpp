@@ -24,7 +24,8 @@ enum class dependency_source {
   GIT,     // Direct git repository
   VCPKG,   // vcpkg package
   SYSTEM,  // System library
-  PROJECT  // Local project/path
+  PROJECT, // Local project/path
+  CONAN    // conan package
 };
 
 /**
@@ -0,0 +1,57 @@
+/**
+ * @file scoped_environment.hpp
+ * @brief Environment variables set for one scope of the cforge process
+ */
+
+#pragma once
+
+#include <cstdlib>
+#include <map>
+#include <optional>
+#include <string>
+#include <utility>
+#include <vector>
+
+namespace cforge {
+
+/**
+ * @brief Sets environment variables for the lifetime of the object
+ *
+ * Previous values are restored (or the variables unset) on destruction.
+ */
+class scoped_environment {
+public:
+  explicit scoped_environment(const std::map<std::string, std::string> &env) {
+    for (const auto &[name, value] : env) {
+      const char *old = std::getenv(name.c_str());
+      saved_.emplace_back(name, old ? std::optional<std::string>(old) : std::nullopt);
+      set(name, value.c_str());
+    }
+  }
+
+  ~scoped_environment() {
+    for (const auto &[name, value] : saved_) {
+      set(name, value ? value->c_str() : nullptr);
+    }
+  }
+
+  scoped_environment(const scoped_environment &)            = delete;
+  scoped_environment &operator=(const scoped_environment &) = delete;
+
+private:
+  std::vector<std::pair<std::string, std::optional<std::string>>> saved_;
+
+  static void set(const std::string &name, const char *value) {
+#ifdef _WIN32
+    _putenv_s(name.c_str(), value ? value : "");
+#else
+    if (value) {
+      setenv(name.c_str(), value, 1);
+    } else {
+      unsetenv(name.c_str());
+    }
+#endif
+  }
+};
+
+}  // namespace cforge
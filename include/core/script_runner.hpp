@@ -7,13 +7,24 @@
 
 #include "cforge/log.hpp"
 
+#include "core/cforge_tokens.hpp"
 #include "core/constants.h"
+#include "core/errors.hpp"
+#include "core/platform.hpp"
 #include "core/process_utils.hpp"
+#include "core/scoped_environment.hpp"
 #include "core/toml_reader.hpp"
 
 #include <toml++/toml.hpp>
 
+#include <algorithm>
+#include <cstdio>
+#include <cstdlib>
 #include <filesystem>
+#include <functional>
+#include <map>
+#include <optional>
+#include <set>
 #include <string>
 #include <vector>
 
@@ -95,6 +106,83 @@ inline std::string phase_to_name(script_phase phase) {
   }
 }
 
+/**
+ * @brief Convert script phase to its [hooks] TOML key
+ */
+inline std::string phase_to_hook_key(script_phase phase) {
+  std::string key = phase_to_key(phase);
+  return key.empty() ? key : "hooks." + key.substr(key.find('.') + 1);
+}
+
+/**
+ * @brief A single hook entry
+ *
+ * Entries are either plain strings or tables of the form
+ * `{ command = "...", cwd = "tools/", env = { FOO = "bar" },
+ *    allow_failure = true, platforms = ["windows"] }`.
+ * Plain strings in [scripts] are script files; plain strings in [hooks] are
+ * shell commands.
+ */
+struct hook_spec {
+  std::string command;                     ///< Shell command (empty if `script` is set)
+  std::string script;                      ///< Script file run via execute_script
+  std::string cwd;                         ///< Working directory, relative to the project
+  std::map<std::string, std::string> env;  ///< Extra environment variables
+  bool allow_failure = false;              ///< Failures only warn
+  std::vector<std::string> platforms;      ///< windows, macos, linux (empty = all)
+};
+
+/**
+ * @brief Parse the hook entries stored under a dotted key
+ *
+ * @param config Parsed config file
+ * @param key Dotted key, e.g. "hooks.pre_build"
+ * @param strings_are_scripts Treat plain string entries as script files
+ * @return Parsed hooks (invalid entries are skipped with a warning)
+ */
+inline std::vector<hook_spec> read_hooks(const toml::table &config,
+                                         const std::string &key,
+                                         bool strings_are_scripts) {
+  std::vector<hook_spec> hooks;
+  const toml::array *entries = config.at_path(key).as_array();
+  if (!entries) {
+    return hooks;
+  }
+
+  for (const auto &entry : *entries) {
+    hook_spec hook;
+    if (auto str = entry.value<std::string>()) {
+      (strings_are_scripts ? hook.script : hook.command) = *str;
+    } else if (const toml::table *tbl = entry.as_table()) {
+      hook.command       = (*tbl)["command"].value_or(std::string());
+      hook.script        = (*tbl)["script"].value_or(std::string());
+      hook.cwd           = (*tbl)["cwd"].value_or(std::string());
+      hook.allow_failure = (*tbl)["allow_failure"].value_or(false);
+      if (const toml::table *env = (*tbl)["env"].as_table()) {
+        for (const auto &[name, value] : *env) {
+          if (auto v = value.value<std::string>()) {
+            hook.env[std::string(name.str())] = *v;
+          }
+        }
+      }
+      if (const toml::array *platforms = (*tbl)["platforms"].as_array()) {
+        for (const auto &platform_name : *platforms) {
+          if (auto v = platform_name.value<std::string>()) {
+            hook.platforms.push_back(string_to_lower(*v));
+          }
+        }
+      }
+    }
+
+    if (hook.command.empty() && hook.script.empty()) {
+      logger::print_warning("Ignoring " + key + " entry without a command");
+      continue;
+    }
+    hooks.push_back(std::move(hook));
+  }
+  return hooks;
+}
+
 /**
  * @brief Determine script interpreter based on file extension
  *
@@ -201,6 +289,103 @@ inline bool execute_script(const std::filesystem::path &script_path,
   return success;
 }
 
+/**
+ * @brief Run a shell command hook
+ *
+ * Output streams live in verbose mode; otherwise it is captured and shown
+ * only if the command fails.
+ *
+ * @param command Shell command line
+ * @param working_dir Working directory for execution
+ * @param phase Script phase (for logging)
+ * @param verbose Verbose output
+ * @param timeout Timeout in seconds
+ * @return true if the command exited successfully
+ */
+inline bool execute_hook_command(const std::string &command,
+                                 const std::filesystem::path &working_dir,
+                                 script_phase phase,
+                                 bool verbose         = false,
+                                 cforge_int_t timeout = 300) {
+  std::string phase_name = phase_to_name(phase);
+  logger::print_status("Running " + phase_name + " hook: " + command);
+
+#ifdef _WIN32
+  std::string shell             = "cmd";
+  std::vector<std::string> args = {"/c", command};
+#else
+  std::string shell             = "sh";
+  std::vector<std::string> args = {"-c", command};
+#endif
+
+  std::function<void(const std::string &)> stream = nullptr;
+  if (verbose) {
+    stream = [](const std::string &chunk) { logger::print_plain(chunk); };
+  }
+
+  process_result result =
+      execute_process(shell, args, working_dir.string(), stream, stream, timeout);
+  if (!result.success) {
+    if (!verbose) {
+      if (!result.stdout_output.empty()) {
+        logger::print_plain(result.stdout_output);
+      }
+      if (!result.stderr_output.empty()) {
+        logger::print_plain(result.stderr_output);
+      }
+    }
+    logger::print_error(phase_name + " hook failed (exit code "
+                        + std::to_string(result.exit_code) + "): " + command);
+  }
+  return result.success;
+}
+
+/**
+ * @brief Run one hook, honoring its platform filter, cwd, env and failure policy
+ *
+ * @param hook Hook to run
+ * @param project_dir Project directory (base for relative cwd)
+ * @param phase Script phase
+ * @param verbose Verbose output
+ * @return false only if the hook failed and does not allow failure
+ */
+inline bool run_hook(const hook_spec &hook,
+                     const std::filesystem::path &project_dir,
+                     script_phase phase,
+                     bool verbose = false) {
+  if (!hook.platforms.empty()
+      && std::find(hook.platforms.begin(), hook.platforms.end(), platform::get_platform_name())
+             == hook.platforms.end()) {
+    logger::print_verbose("Skipping " + phase_to_name(phase) + " hook (not for "
+                          + platform::get_platform_name() + "): "
+                          + (hook.command.empty() ? hook.script : hook.command));
+    return true;
+  }
+
+  std::filesystem::path working_dir = project_dir;
+  if (!hook.cwd.empty()) {
+    working_dir = std::filesystem::path(hook.cwd).is_absolute() ? std::filesystem::path(hook.cwd)
+                                                                : project_dir / hook.cwd;
+    if (!std::filesystem::is_directory(working_dir)) {
+      logger::print_error("Hook working directory does not exist: " + working_dir.string());
+      return hook.allow_failure;
+    }
+  }
+
+  bool success;
+  {
+    scoped_environment env(hook.env);
+    success = hook.command.empty() ? execute_script(hook.script, working_dir, phase, verbose)
+                                   : execute_hook_command(hook.command, working_dir, phase, verbose);
+  }
+
+  if (!success && hook.allow_failure) {
+    logger::print_warning("Continuing because the hook sets allow_failure");
+    return true;
+  }
+  return success;
+}
+
 /**
  * @brief Run all scripts for a given phase from a config file
  *
@@ -209,31 +394,52 @@ inline bool execute_script(const std::filesystem::path &script_path,
  * @param working_dir Working directory for script execution
  * @param phase Script phase to run
  * @param verbose Verbose output
+ * @param build_config Build configuration for ${CONFIG} (empty leaves it as written)
  * @return true if all scripts succeeded (or no scripts defined)
  */
 inline bool run_phase_scripts(const std::filesystem::path &config_path,
                               const std::filesystem::path &working_dir,
                               script_phase phase,
-                              bool verbose = false) {
-  toml_reader config;
-  if (!config.load(config_path.string())) {
-    // Config file not found or invalid - not an error, just no scripts
+                              bool verbose                    = false,
+                              const std::string &build_config = "") {
+  if (!std::filesystem::exists(config_path)) {
+    // Config file not found - not an error, just no scripts
     return true;
   }
 
-  std::string key = phase_to_key(phase);
-  if (!config.has_key(key)) {
-    // No scripts defined for this phase
+  toml::table config;
+  try {
+    config = toml::parse_file(config_path.string());
+  } catch (...) {
+    // Invalid config is reported by whoever loads it for the build
     return true;
   }
 
-  std::vector<std::string> scripts = config.get_string_array(key);
-  if (scripts.empty()) {
-    return true;
+  // [scripts] lists script files; [hooks] lists shell commands. Either may
+  // also hold structured entries.
+  std::vector<hook_spec> hooks       = read_hooks(config, phase_to_key(phase), true);
+  std::vector<hook_spec> shell_hooks = read_hooks(config, phase_to_hook_key(phase), false);
+  hooks.insert(hooks.end(), shell_hooks.begin(), shell_hooks.end());
+
+  // ${CONFIG}, ${OS} and the other cforge tokens work in every hook value
+  std::string name = config.at_path("project.name")
+                         .value_or(config.at_path("workspace.name").value_or(std::string()));
+  std::string version =
+      config.at_path("project.version")
+          .value_or(config.at_path("workspace.version").value_or(std::string("0.1.0")));
+  std::map<std::string, std::string> tokens = make_cforge_tokens(
+      name, version, build_config, platform::get_platform_name(), host_arch_name());
+  for (auto &hook : hooks) {
+    hook.command = expand_cforge_tokens(hook.command, tokens);
+    hook.script  = expand_cforge_tokens(hook.script, tokens);
+    hook.cwd     = expand_cforge_tokens(hook.cwd, tokens);
+    for (auto &[env_name, value] : hook.env) {
+      value = expand_cforge_tokens(value, tokens);
+    }
   }
 
-  for (const auto &script : scripts) {
-    if (!execute_script(script, working_dir, phase, verbose)) {
+  for (const auto &hook : hooks) {
+    if (!run_hook(hook, working_dir, phase, verbose)) {
       return false;
     }
   }
@@ -241,6 +447,198 @@ inline bool run_phase_scripts(const std::filesystem::path &config_path,
   return true;
 }
 
+/**
+ * @brief A script run by name with `cforge script <name>`
+ *
+ * Entries under [scripts.scripts] are either plain shell commands or tables
+ * of the form `{ command = "...", needs = ["build"], cwd = "tools/",
+ * env = { FOO = "bar" } }`.
+ */
+struct named_script {
+  std::string name;
+  std::string command;                     ///< Shell command (may be empty for pure chains)
+  std::vector<std::string> needs;          ///< Scripts that run first
+  std::string cwd;                         ///< Working directory, relative to the project
+  std::map<std::string, std::string> env;  ///< Extra environment variables
+};
+
+/**
+ * @brief Parse the named scripts under [scripts.scripts]
+ *
+ * @param config Parsed config file
+ * @return Scripts by name (invalid entries are skipped with a warning)
+ */
+inline std::map<std::string, named_script> read_named_scripts(const toml::table &config) {
+  std::map<std::string, named_script> scripts;
+  const toml::table *entries = config.at_path("scripts.scripts").as_table();
+  if (!entries) {
+    return scripts;
+  }
+
+  for (const auto &[key, entry] : *entries) {
+    named_script script;
+    script.name = std::string(key.str());
+    if (auto str = entry.value<std::string>()) {
+      script.command = *str;
+    } else if (const toml::table *tbl = entry.as_table()) {
+      script.command = (*tbl)["command"].value_or(std::string());
+      script.cwd     = (*tbl)["cwd"].value_or(std::string());
+      if (const toml::array *needs = (*tbl)["needs"].as_array()) {
+        for (const auto &need : *needs) {
+          if (auto v = need.value<std::string>()) {
+            script.needs.push_back(*v);
+          }
+        }
+      }
+      if (const toml::table *env = (*tbl)["env"].as_table()) {
+        for (const auto &[name, value] : *env) {
+          if (auto v = value.value<std::string>()) {
+            script.env[std::string(name.str())] = *v;
+          }
+        }
+      }
+    }
+
+    if (script.command.empty() && script.needs.empty()) {
+      logger::print_warning("Ignoring script '" + script.name + "' without a command");
+      continue;
+    }
+    scripts[script.name] = std::move(script);
+  }
+  return scripts;
+}
+
+/**
+ * @brief Order a script after everything it needs
+ *
+ * Each prerequisite appears once, before every script that needs it, and the
+ * requested script comes last.
+ *
+ * @param scripts Scripts by name
+ * @param name Script to run
+ * @return Script names in run order, or an error for unknown scripts and cycles
+ */
+inline result<std::vector<std::string>>
+resolve_script_order(const std::map<std::string, named_script> &scripts, const std::string &name) {
+  std::vector<std::string> order;
+  std::set<std::string> done;
+  std::vector<std::string> path;
+
+  std::function<result<void>(const std::string &)> visit =
+      [&](const std::string &current) -> result<void> {
+    if (done.count(current)) {
+      return {};
+    }
+    auto active = std::find(path.begin(), path.end(), current);
+    if (active != path.end()) {
+      std::string chain;
+      for (auto it = active; it != path.end(); ++it) {
+        chain += *it + " -> ";
+      }
+      return cforge_error::make(error_code::CONFIG_INVALID_VALUE,
+                                "script dependency cycle detected: " + chain + current)
+          .with_help("remove one of these `needs` entries to break the cycle");
+    }
+    auto script = scripts.find(current);
+    if (script == scripts.end()) {
+      auto err = cforge_error::make(error_code::CONFIG_MISSING_FIELD,
+                                    "script '" + current + "' is not defined");
+      if (!path.empty()) {
+        err.with_help("'" + path.back() + "' needs it; add it to [scripts.scripts]");
+      }
+      return err;
+    }
+
+    path.push_back(current);
+    for (const auto &need : script->second.needs) {
+      auto visited = visit(need);
+      if (visited.is_error()) {
+        return visited;
+      }
+    }
+    path.pop_back();
+    done.insert(current);
+    order.push_back(current);
+    return {};
+  };
+
+  auto visited = visit(name);
+  if (visited.is_error()) {
+    return visited.error();
+  }
+  return order;
+}
+
+/**
+ * @brief Run a named script, streaming its output
+ *
+ * Extra arguments are forwarded to the command. On POSIX they are the
+ * positional parameters (`$@`) and are appended as `"$@"` unless the command
+ * already uses `$@` or `$*`; on Windows they are appended quoted.
+ *
+ * @param script Script to run
+ * @param project_dir Project directory (base for relative cwd)
+ * @param args Arguments to forward
+ * @return true if the command exited successfully
+ */
+inline bool run_named_script(const named_script &script,
+                             const std::filesystem::path &project_dir,
+                             const std::vector<std::string> &args = {}) {
+  if (script.command.empty()) {
+    return true;
+  }
+
+  std::filesystem::path working_dir = project_dir;
+  if (!script.cwd.empty()) {
+    working_dir = std::filesystem::path(script.cwd).is_absolute()
+                    ? std::filesystem::path(script.cwd)
+                    : project_dir / script.cwd;
+    if (!std::filesystem::is_directory(working_dir)) {
+      logger::print_error("Script working directory does not exist: " + working_dir.string());
+      return false;
+    }
+  }
+
+#ifdef _WIN32
+  std::string command = script.command;
+  for (const auto &arg : args) {
+    command += " \"" + arg + "\"";
+  }
+  std::string shell                   = "cmd";
+  std::vector<std::string> shell_args = {"/c", command};
+#else
+  std::string command = script.command;
+  if (!args.empty() && command.find("$@") == std::string::npos
+      && command.find("$*") == std::string::npos) {
+    command += " \"$@\"";
+  }
+  std::string shell                   = "sh";
+  std::vector<std::string> shell_args = {"-c", command, script.name};
+  shell_args.insert(shell_args.end(), args.begin(), args.end());
+#endif
+
+  logger::print_action("Running", script.name + ": " + script.command);
+  // Written as-is: chunks keep their own newlines
+  auto stream_to = [](FILE *out) {
+    return [out](const std::string &chunk) {
+      std::fwrite(chunk.data(), 1, chunk.size(), out);
+      std::fflush(out);
+    };
+  };
+
+  process_result result;
+  {
+    scoped_environment env(script.env);
+    result = execute_process(
+        shell, shell_args, working_dir.string(), stream_to(stdout), stream_to(stderr), 0);
+  }
+  if (!result.success) {
+    logger::print_error("Script '" + script.name + "' failed (exit code "
+                        + std::to_string(result.exit_code) + ")");
+  }
+  return result.success;
+}
+
 /**
  * @brief Get the correct config path for a project or workspace
  * Checks for unified format (cforge.toml with [workspace]) first for workspaces
@@ -274,13 +672,16 @@ inline std::filesystem::path get_script_config_path(const std::filesystem::path
  * @param project_dir Project or workspace directory
  * @param is_workspace True if this is a workspace
  * @param verbose Verbose output
+ * @param build_config Configuration being built, for ${CONFIG}
  * @return true if all scripts succeeded
  */
 inline bool run_pre_build_scripts(const std::filesystem::path &project_dir,
                                   bool is_workspace,
-                                  bool verbose = false) {
+                                  bool verbose                    = false,
+                                  const std::string &build_config = "") {
   std::filesystem::path config_path = get_script_config_path(project_dir, is_workspace);
-  return run_phase_scripts(config_path, project_dir, script_phase::PRE_BUILD, verbose);
+  return run_phase_scripts(
+      config_path, project_dir, script_phase::PRE_BUILD, verbose, build_config);
 }
 
 /**
@@ -289,13 +690,16 @@ inline bool run_pre_build_scripts(const std::filesystem::path &project_dir,
  * @param project_dir Project or workspace directory
  * @param is_workspace True if this is a workspace
  * @param verbose Verbose output
+ * @param build_config Configuration being built, for ${CONFIG}
  * @return true if all scripts succeeded
  */
 inline bool run_post_build_scripts(const std::filesystem::path &project_dir,
                                    bool is_workspace,
-                                   bool verbose = false) {
+                                   bool verbose                    = false,
+                                   const std::string &build_config = "") {
   std::filesystem::path config_path = get_script_config_path(project_dir, is_workspace);
-  return run_phase_scripts(config_path, project_dir, script_phase::POST_BUILD, verbose);
+  return run_phase_scripts(
+      config_path, project_dir, script_phase::POST_BUILD, verbose, build_config);
 }
 
 /**
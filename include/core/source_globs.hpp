@@ -0,0 +1,196 @@
+/**
+ * @file source_globs.hpp
+ * @brief Source exclude patterns ([build] exclude_sources) and their regexes
+ *
+ * Exclude patterns follow .gitignore rules, relative to the project
+ * directory:
+ *
+ * - `*` and `?` stop at `/`; `**` matches any number of directories,
+ *   including none.
+ * - A pattern with a `/` (other than a trailing one) is anchored to the
+ *   project directory; `*_disabled.cpp` matches at any depth.
+ * - A trailing `/` matches a directory and everything below it.
+ * - `\` is a path separator, so patterns written on Windows work everywhere.
+ *
+ * project.additional_sources globs use CMake's rules instead, and a leading
+ * `!` turns one into an exclusion (see count_project_source_files()).
+ */
+
+#pragma once
+
+#include "core/types.h"
+
+#include <algorithm>
+#include <filesystem>
+#include <regex>
+#include <string>
+#include <vector>
+
+namespace cforge {
+
+/**
+ * @brief Convert a glob to a regular expression over `/`-separated paths
+ *
+ * The result uses only syntax that std::regex and CMake's regex engine
+ * share, so it can be written into `list(FILTER ... REGEX ...)`.
+ *
+ * @param pattern Glob, e.g. "src/generated/"
+ * @param star_crosses_dirs Follow CMake's GLOB_RECURSE instead: `*` matches `/`
+ *        too and every pattern is anchored to the project directory
+ * @return Regex matching a whole relative path, without ^ and $ anchors
+ */
+inline std::string glob_to_regex(const std::string &pattern, bool star_crosses_dirs = false) {
+  std::string glob = pattern;
+  for (char &c : glob) {
+    if (c == '\\') {
+      c = '/';
+    }
+  }
+  cforge_size_t slash = glob.find('/');
+  bool anchored = star_crosses_dirs || (slash != std::string::npos && slash + 1 != glob.size());
+  if (!glob.empty() && glob.front() == '/') {
+    glob.erase(0, 1);
+  }
+  if (!glob.empty() && glob.back() == '/') {
+    glob += "**";
+  }
+
+  std::string regex = anchored ? "" : "(.*/)?";
+  for (cforge_size_t i = 0; i < glob.size(); ++i) {
+    char c = glob[i];
+    if (c == '*' && i + 1 < glob.size() && glob[i + 1] == '*') {
+      if (i + 2 < glob.size() && glob[i + 2] == '/') {
+        regex += "(.*/)?";
+        i += 2;
+      } else {
+        regex += ".*";
+        ++i;
+      }
+    } else if (c == '*') {
+      regex += star_crosses_dirs ? ".*" : "[^/]*";
+    } else if (c == '?') {
+      regex += star_crosses_dirs ? "." : "[^/]";
+    } else if (std::string("^$.|()[]{}+\\").find(c) != std::string::npos) {
+      regex += '\\';
+      regex += c;
+    } else {
+      regex += c;
+    }
+  }
+  return regex;
+}
+
+/**
+ * @brief Patterns excluded from every project's sources
+ *
+ * Dependency checkouts, build directories and .git are never sources, even
+ * when a source directory is the project directory itself.
+ *
+ * @param deps_dir dependencies.directory, usually "deps"
+ * @param build_dir build.directory, usually "build"
+ */
+inline std::vector<std::string> default_source_excludes(const std::string &deps_dir,
+                                                        const std::string &build_dir) {
+  return {"/" + deps_dir + "/", "/" + build_dir + "/", "/" + build_dir + "-*/", ".git/"};
+}
+
+/**
+ * @brief Check whether a project-relative path matches an exclude pattern
+ *
+ * @param relative_path Path relative to the project directory
+ * @param patterns Exclude patterns
+ */
+inline bool is_source_excluded(const std::filesystem::path &relative_path,
+                               const std::vector<std::string> &patterns) {
+  std::string path = relative_path.generic_string();
+  for (const auto &pattern : patterns) {
+    try {
+      if (std::regex_match(path, std::regex(glob_to_regex(pattern)))) {
+        return true;
+      }
+    } catch (const std::regex_error &) {
+      // An invalid pattern excludes nothing
+    }
+  }
+  return false;
+}
+
+/**
+ * @brief Check whether a source glob removes files (`!src/main.cpp`)
+ */
+inline bool is_negated_source_pattern(const std::string &pattern) {
+  return !pattern.empty() && pattern.front() == '!';
+}
+
+/**
+ * @brief Count the C and C++ sources under a project's source directories
+ *
+ * `patterns` are globs like project.additional_sources: they follow CMake's
+ * GLOB_RECURSE, relative to the project directory, and add the files they
+ * match. A pattern starting with `!` removes the files it matches instead,
+ * from the directories and the other patterns alike, wherever it is listed.
+ *
+ * @param project_dir Project directory
+ * @param source_dirs build.source_dirs
+ * @param excludes Exclude patterns, including the defaults
+ * @param patterns project.additional_sources globs, e.g. "!src/main.cpp"
+ * @return Number of .c, .cc, .cpp and .cxx files that are not excluded
+ */
+inline cforge_size_t count_project_source_files(const std::filesystem::path &project_dir,
+                                                const std::vector<std::string> &source_dirs,
+                                                const std::vector<std::string> &excludes,
+                                                const std::vector<std::string> &patterns = {}) {
+  std::vector<std::regex> added;
+  std::vector<std::regex> removed;
+  for (const auto &pattern : patterns) {
+    bool negated = is_negated_source_pattern(pattern);
+    try {
+      (negated ? removed : added)
+          .emplace_back(glob_to_regex(negated ? pattern.substr(1) : pattern, true));
+    } catch (const std::regex_error &) {
+      // An invalid pattern matches nothing
+    }
+  }
+  auto matches_any = [](const std::string &path, const std::vector<std::regex> &regexes) {
+    return std::any_of(regexes.begin(), regexes.end(), [&](const std::regex &regex) {
+      return std::regex_match(path, regex);
+    });
+  };
+
+  std::vector<std::filesystem::path> seen;
+  auto collect = [&](const std::filesystem::path &root, bool only_added) {
+    std::error_code ec;
+    for (auto it = std::filesystem::recursive_directory_iterator(root, ec);
+         !ec && it != std::filesystem::recursive_directory_iterator();
+         it.increment(ec)) {
+      std::filesystem::path relative = it->path().lexically_relative(project_dir);
+      if (it->is_directory(ec)) {
+        // Skip directories whose contents are all excluded, like deps/
+        if (is_source_excluded(relative / "_", excludes)) {
+          it.disable_recursion_pending();
+        }
+        continue;
+      }
+      std::string ext = it->path().extension().string();
+      if (ext != ".c" && ext != ".cc" && ext != ".cpp" && ext != ".cxx") {
+        continue;
+      }
+      std::filesystem::path file = relative.lexically_normal();
+      if ((only_added && !matches_any(file.generic_string(), added))
+          || is_source_excluded(file, excludes) || matches_any(file.generic_string(), removed)
+          || std::find(seen.begin(), seen.end(), file) != seen.end()) {
+        continue;
+      }
+      seen.push_back(file);
+    }
+  };
+  for (const auto &dir : source_dirs) {
+    collect(project_dir / dir, false);
+  }
+  if (!added.empty()) {
+    collect(project_dir, true);
+  }
+  return seen.size();
+}
+
+}  // namespace cforge
@@ -11,6 +11,7 @@
 #include <map>
 #include <memory>
 #include <optional>
+#include <regex>
 #include <string>
 #include <vector>
 
@@ -79,7 +80,10 @@ struct test_target {
   int timeout_seconds      = 300;
   std::vector<std::string> dependencies;  // Link dependencies
   std::vector<std::string> defines;
+  std::map<std::string, std::vector<std::string>> platform_defines;  // By OS name
+  std::map<std::string, std::vector<std::string>> platform_flags;    // By OS name
   std::vector<std::string> includes;
+  std::vector<std::string> labels;  // Selected with `cforge test --label`
   bool enabled = true;
 
   // Computed paths
@@ -98,6 +102,8 @@ struct test_config {
   bool auto_link_project           = true;
   bool cargo_style_output          = true;
   std::string discovery_mode{"both"};  // "auto", "explicit", "both"
+  std::vector<std::string> sources;    // Globs replacing the scan of `directory`
+  std::vector<std::string> defines;    // Added to every test target
 
   // Framework-specific settings
   struct FrameworkConfig {
@@ -127,6 +133,53 @@ struct test_summary {
   std::vector<std::string> failed_tests;
 };
 
+/**
+ * @brief Check whether a test target passes --label/--exclude selection
+ *
+ * Both are regular expressions matched anywhere in the string, like ctest's
+ * -L and -E. Throws std::regex_error for an invalid expression.
+ *
+ * @param target Test target
+ * @param label A label of the target must match this (empty: no check)
+ * @param exclude Targets whose name matches this are skipped (empty: no check)
+ */
+inline bool test_target_selected(const test_target &target,
+                                 const std::string &label,
+                                 const std::string &exclude) {
+  if (!exclude.empty() && std::regex_search(target.name, std::regex(exclude))) {
+    return false;
+  }
+  if (label.empty()) {
+    return true;
+  }
+  std::regex label_re(label);
+  for (const auto &l : target.labels) {
+    if (std::regex_search(l, label_re)) {
+      return true;
+    }
+  }
+  return false;
+}
+
+/**
+ * @brief Describe the failed tests of a run, e.g. "2 tests failed: A.B, A.C"
+ *
+ * @param failed_tests Names of the failed tests
+ * @param limit Names listed before the rest are counted as "and N more"
+ */
+inline std::string describe_failed_tests(const std::vector<std::string> &failed_tests,
+                                         cforge_size_t limit = 5) {
+  cforge_size_t count = failed_tests.size();
+  std::string text    = std::to_string(count) + (count == 1 ? " test" : " tests") + " failed";
+  for (cforge_size_t i = 0; i < count && i < limit; ++i) {
+    text += (i == 0 ? ": " : ", ") + failed_tests[i];
+  }
+  if (count > limit) {
+    text += ", and " + std::to_string(count - limit) + " more";
+  }
+  return text;
+}
+
 /**
  * @brief Convert TestFramework enum to string
  */
@@ -25,8 +25,10 @@ struct test_run_options {
   bool no_build                 = false;
   bool list_only                = false;
   bool verbose                  = false;
-  cforge_int_t jobs             = 0;
+  cforge_int_t jobs             = 0;  ///< Targets run in parallel (0 = test.jobs or CPUs)
   cforge_int_t timeout_override = 0;
+  std::string repeat_mode;            ///< until-pass, until-fail or after-timeout
+  cforge_int_t repeat_count = 1;      ///< Maximum runs per target for repeat_mode
 };
 
 /**
@@ -60,6 +62,15 @@ public:
    */
   std::vector<test_target> discover_targets();
 
+  /**
+   * @brief Disable discovered targets that don't pass --label/--exclude
+   *
+   * @param label Regex one of a target's labels must match (empty: any)
+   * @param exclude Regex for target names to skip (empty: none)
+   * @return Number of targets still enabled
+   */
+  cforge_size_t select_targets(const std::string &label, const std::string &exclude);
+
   /**
    * @brief Detect framework from source file content
    * @param source_file Path to the source file
@@ -107,12 +118,14 @@ private:
   std::string m_error;
 
   /**
-   * @brief Get the build directory for a test target
+   * @brief Get the generated CMake directory and build directory for a test target
    * @param target_name Name of the test target
-   * @return Path to the test target's build directory
+   * @param build_config Build configuration (build directory only)
+   * @return Path to the test target's CMake or build directory
    */
   std::filesystem::path get_test_gen_dir(const std::string &target_name) const;
-  std::filesystem::path get_test_build_dir(const std::string &target_name) const;
+  std::filesystem::path get_test_build_dir(const std::string &target_name,
+                                           const std::string &build_config) const;
 
   // Framework adapters (lazily created)
   std::map<test_framework, std::unique_ptr<i_test_framework_adapter>> m_adapters;
@@ -165,6 +178,15 @@ private:
    */
   std::vector<test_result> run_target(const test_target &target, const test_run_options &options);
 
+  /**
+   * @brief Run a test target, repeating it according to options.repeat_mode
+   * @param target The test target
+   * @param options Execution options
+   * @return Results of the last run
+   */
+  std::vector<test_result> run_target_repeated(const test_target &target,
+                                               const test_run_options &options);
+
   /**
    * @brief Auto-discover tests from source files
    * @return Vector of discovered targets
@@ -106,6 +106,13 @@ public:
    */
   bool has_key(const std::string &key) const;
 
+  /**
+   * @brief Get the source line a key's value was parsed from
+   * @param key The key to look up (can be dotted for tables)
+   * @return 1-based line number, or 0 if the key does not exist
+   */
+  cforge_size_t get_line(const std::string &key) const;
+
   /**
    * @brief Get all keys in a table
    * @param table The table name (empty for root table)
@@ -173,6 +180,17 @@ private:
   std::unique_ptr<toml::table> toml_data;  // Smart pointer to the toml::table
 };
 
+/**
+ * @brief Expand environment variables and `~` in the values of a cforge.toml
+ *
+ * Prints an error for every `${env:NAME}` whose variable is not set.
+ *
+ * @param config Parsed file, updated in place
+ * @param filepath Path of the file, for error messages
+ * @return False if a required variable is not set
+ */
+bool interpolate_config_values(toml::table &config, const std::string &filepath);
+
 }  // namespace cforge
 
 #endif  // CFORGE_TOML_READER_H
\ No newline at end of file
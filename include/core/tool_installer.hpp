@@ -35,6 +35,7 @@ enum class install_result {
   unknown_tool,     // Tool name not in the registry.
   failed,           // Package manager ran but exited non-zero.
   non_interactive,  // stdin is not a TTY — no prompt shown.
+  offline,          // Offline mode (--offline) — nothing is installed.
 };
 
 /**
@@ -57,6 +58,7 @@ struct install_outcome {
  * @brief Look up a tool, ask the user, and run the platform's install command.
  *
  * The function:
+ *   0. Returns `offline` without doing anything in offline mode.
  *   1. Returns `unknown_tool` if `tool` isn't in the registry.
  *   2. Picks the first available package manager for the current platform
  *      (winget→choco→scoop on Windows, brew on macOS, apt/dnf on Linux).
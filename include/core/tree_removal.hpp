@@ -0,0 +1,153 @@
+/**
+ * @file tree_removal.hpp
+ * @brief Removing a directory tree when some of its files are locked
+ *
+ * On Windows a file can't be deleted while a process has it open: the
+ * program that was just run, or a compiler helper such as mspdbsrv.exe or
+ * vctip.exe that outlives the build. Those locks are usually released within
+ * moments, so removal is retried with increasing delays; files that stay
+ * locked are skipped and reported instead of failing the whole clean.
+ */
+
+#pragma once
+
+#include "core/types.h"
+
+#include <algorithm>
+#include <cctype>
+#include <chrono>
+#include <filesystem>
+#include <string>
+#include <system_error>
+#include <thread>
+#include <vector>
+
+namespace cforge {
+
+/**
+ * @brief What remove_tree_with_retries() removed and left behind
+ */
+struct tree_removal_result {
+  cforge_size_t removed = 0;                   ///< Files removed
+  std::vector<std::filesystem::path> skipped;  ///< Files that were still locked
+};
+
+/**
+ * @brief Delay before retry `attempt` (0-based): 50ms, doubling each time
+ */
+inline std::chrono::milliseconds removal_retry_delay(cforge_int_t attempt) {
+  return std::chrono::milliseconds(50) * (1 << std::min<cforge_int_t>(attempt, 10));
+}
+
+/**
+ * @brief Remove every file in `files` that isn't locked
+ *
+ * Read-only files, such as git's pack files, are made writable and tried
+ * again, since Windows refuses to delete them otherwise.
+ *
+ * @param files Files to remove
+ * @param removed Incremented for each file removed
+ * @return Files that could not be removed
+ */
+inline std::vector<std::filesystem::path> remove_unlocked_files(
+    const std::vector<std::filesystem::path> &files,
+    cforge_size_t &removed) {
+  std::vector<std::filesystem::path> failed;
+  for (const auto &file : files) {
+    std::error_code ec;
+    if (std::filesystem::remove(file, ec)) {
+      ++removed;
+      continue;
+    }
+    if (!ec) {
+      continue;  // Already gone
+    }
+    std::filesystem::permissions(
+        file, std::filesystem::perms::owner_write, std::filesystem::perm_options::add, ec);
+    if (std::filesystem::remove(file, ec)) {
+      ++removed;
+      continue;
+    }
+    failed.push_back(file);
+  }
+  return failed;
+}
+
+/**
+ * @brief Remove a directory tree, retrying files that are locked
+ *
+ * Every file is tried; the ones that fail are tried again after
+ * removal_retry_delay(), up to `retries` more times. Directories are removed
+ * afterwards, deepest first, so only the directories holding a skipped file
+ * are left.
+ *
+ * @param dir Directory to remove
+ * @param retries Number of retries after the first pass
+ * @return Files removed and skipped; if nothing was skipped but `dir` still
+ *         exists (a directory itself is in use), `dir` is the skipped entry
+ */
+inline tree_removal_result remove_tree_with_retries(const std::filesystem::path &dir,
+                                                    cforge_int_t retries = 4) {
+  tree_removal_result result;
+  std::error_code ec;
+  if (!std::filesystem::exists(dir, ec)) {
+    return result;
+  }
+
+  // Symlinks are removed as files, never followed
+  std::vector<std::filesystem::path> files;
+  std::vector<std::filesystem::path> dirs;
+  for (auto it = std::filesystem::recursive_directory_iterator(dir, ec);
+       !ec && it != std::filesystem::recursive_directory_iterator();
+       it.increment(ec)) {
+    if (std::filesystem::is_directory(it->symlink_status(ec))) {
+      dirs.push_back(it->path());
+    } else {
+      files.push_back(it->path());
+    }
+  }
+
+  for (cforge_int_t attempt = 0;; ++attempt) {
+    files = remove_unlocked_files(files, result.removed);
+    if (files.empty() || attempt >= retries) {
+      break;
+    }
+    std::this_thread::sleep_for(removal_retry_delay(attempt));
+  }
+  result.skipped = files;
+
+  // A child's path sorts after its parent's, so reverse order is deepest first
+  std::sort(dirs.begin(), dirs.end());
+  for (auto it = dirs.rbegin(); it != dirs.rend(); ++it) {
+    std::filesystem::remove(*it, ec);
+  }
+  std::filesystem::remove(dir, ec);
+  if (result.skipped.empty() && std::filesystem::exists(dir, ec)) {
+    result.skipped.push_back(dir);
+  }
+  return result;
+}
+
+/**
+ * @brief Check whether a locked file is a program the project builds
+ *
+ * That is the usual cause of a failed clean on Windows: the program is still
+ * running, and closing it releases the file.
+ *
+ * @param file A file that could not be removed
+ * @param executables Names of the project's executables, without extension
+ */
+inline bool is_project_executable(const std::filesystem::path &file,
+                                  const std::vector<std::string> &executables) {
+  std::string extension = file.extension().string();
+  std::transform(extension.begin(), extension.end(), extension.begin(), [](unsigned char c) {
+    return static_cast<char>(std::tolower(c));
+  });
+  if (extension != ".exe" && !extension.empty()) {
+    return false;
+  }
+  return std::find(executables.begin(), executables.end(), file.stem().string())
+         != executables.end();
+}
+
+}  // namespace cforge
@@ -0,0 +1,226 @@
+/**
+ * @file vcpkg_manifest.hpp
+ * @brief vcpkg.json generation for [dependencies.vcpkg] manifest = true
+ *
+ * In manifest mode the vcpkg toolchain installs a project's ports into its
+ * build directory at configure time, so projects no longer share (and fight
+ * over) the versions in vcpkg's global installed tree. cforge writes the
+ * manifest from cforge.toml and pins `builtin-baseline` to the vcpkg
+ * checkout it was generated with.
+ */
+
+#pragma once
+
+#include "core/types.h"
+
+#include <algorithm>
+#include <cctype>
+#include <sstream>
+#include <string>
+#include <vector>
+
+namespace cforge {
+
+/**
+ * @brief One entry of a vcpkg.json "dependencies" array
+ */
+struct vcpkg_manifest_dependency {
+  std::string name;
+  std::vector<std::string> features;
+  std::string version_min;  ///< Written as "version>=", empty for none
+  bool default_features = true;
+};
+
+/**
+ * @brief Check whether a key of [dependencies.vcpkg] is a setting, not a package
+ *
+ * @param key Key directly under [dependencies.vcpkg]
+ * @return true for enabled, path, triplet, packages, manifest and binary_cache
+ */
+inline bool is_vcpkg_setting_key(const std::string &key) {
+  return key == "enabled" || key == "path" || key == "triplet" || key == "packages"
+         || key == "manifest" || key == "binary_cache";
+}
+
+/**
+ * @brief Parse a dependencies.vcpkg.packages entry
+ *
+ * Accepts `name`, `name[feature,...]` and `name@1.2.3`, in any combination.
+ * A `:triplet` or `:version` suffix is ignored, the triplet being a build
+ * setting in manifest mode.
+ *
+ * @param spec Package entry, e.g. "fmt[header-only]@10.1.0"
+ * @return Dependency with a lower-case name, empty name if @p spec has none
+ */
+inline vcpkg_manifest_dependency parse_vcpkg_manifest_spec(const std::string &spec) {
+  vcpkg_manifest_dependency dep;
+  std::string rest = spec.substr(0, spec.find(':'));
+
+  cforge_size_t at = rest.find('@');
+  if (at != std::string::npos) {
+    dep.version_min = rest.substr(at + 1);
+    rest            = rest.substr(0, at);
+  }
+
+  cforge_size_t open = rest.find('[');
+  if (open != std::string::npos) {
+    cforge_size_t close = rest.find(']', open);
+    cforge_size_t count = close == std::string::npos ? close : close - open - 1;
+    std::stringstream list(rest.substr(open + 1, count));
+    std::string feature;
+    while (std::getline(list, feature, ',')) {
+      feature.erase(0, feature.find_first_not_of(" \t"));
+      feature.erase(feature.find_last_not_of(" \t") + 1);
+      if (!feature.empty()) {
+        dep.features.push_back(feature);
+      }
+    }
+    rest = rest.substr(0, open);
+  }
+
+  std::transform(rest.begin(), rest.end(), rest.begin(), [](unsigned char c) {
+    return static_cast<char>(std::tolower(c));
+  });
+  dep.name = rest;
+  return dep;
+}
+
+/**
+ * @brief Minimum version for a cforge version requirement
+ *
+ * vcpkg only has `version>=`, so only an exact version ("1.2.3") becomes a
+ * constraint; wildcards and ranges leave the version to the baseline.
+ *
+ * @param version Version from cforge.toml
+ * @return The version, or empty if it is not an exact version
+ */
+inline std::string vcpkg_minimum_version(const std::string &version) {
+  if (version.empty() || version.find_first_of("*^~<>=, ") != std::string::npos) {
+    return "";
+  }
+  return version;
+}
+
+/**
+ * @brief Add a dependency to a manifest's list, merging duplicates
+ *
+ * A port listed twice keeps the union of its features and the later
+ * version constraint.
+ *
+ * @param deps Dependencies so far
+ * @param dep Dependency to add
+ */
+inline void add_vcpkg_manifest_dependency(std::vector<vcpkg_manifest_dependency> &deps,
+                                          const vcpkg_manifest_dependency &dep) {
+  if (dep.name.empty()) {
+    return;
+  }
+  auto existing = std::find_if(deps.begin(), deps.end(), [&](const vcpkg_manifest_dependency &d) {
+    return d.name == dep.name;
+  });
+  if (existing == deps.end()) {
+    deps.push_back(dep);
+    return;
+  }
+  for (const auto &feature : dep.features) {
+    if (std::find(existing->features.begin(), existing->features.end(), feature)
+        == existing->features.end()) {
+      existing->features.push_back(feature);
+    }
+  }
+  if (!dep.version_min.empty()) {
+    existing->version_min = dep.version_min;
+  }
+  existing->default_features = existing->default_features && dep.default_features;
+}
+
+/**
+ * @brief Marker written into generated manifests
+ */
+inline const std::string &vcpkg_manifest_marker() {
+  static const std::string marker = "Generated by cforge from cforge.toml";
+  return marker;
+}
+
+/**
+ * @brief Generate vcpkg.json
+ *
+ * Dependencies are sorted by name; a port without features, version or
+ * default-features is written as a plain string.
+ *
+ * @param deps Dependencies
+ * @param baseline vcpkg commit for "builtin-baseline", empty to leave it out
+ * @return File contents
+ */
+inline std::string generate_vcpkg_manifest_json(std::vector<vcpkg_manifest_dependency> deps,
+                                                const std::string &baseline) {
+  std::sort(deps.begin(), deps.end(), [](const auto &a, const auto &b) { return a.name < b.name; });
+  auto str = [](const std::string &text) { return "\"" + text + "\""; };
+
+  std::ostringstream out;
+  out << "{\n";
+  out << "  \"$comment\": " << str(vcpkg_manifest_marker()) << ",\n";
+  if (!baseline.empty()) {
+    out << "  \"builtin-baseline\": " << str(baseline) << ",\n";
+  }
+  out << "  \"dependencies\": [";
+  for (cforge_size_t i = 0; i < deps.size(); ++i) {
+    const vcpkg_manifest_dependency &dep = deps[i];
+    out << (i == 0 ? "\n" : ",\n");
+    if (dep.features.empty() && dep.version_min.empty() && dep.default_features) {
+      out << "    " << str(dep.name);
+      continue;
+    }
+    out << "    {\n";
+    if (!dep.default_features) {
+      out << "      \"default-features\": false,\n";
+    }
+    if (!dep.features.empty()) {
+      out << "      \"features\": [";
+      for (cforge_size_t f = 0; f < dep.features.size(); ++f) {
+        out << (f == 0 ? "" : ", ") << str(dep.features[f]);
+      }
+      out << "],\n";
+    }
+    out << "      \"name\": " << str(dep.name);
+    if (!dep.version_min.empty()) {
+      out << ",\n      \"version>=\": " << str(dep.version_min);
+    }
+    out << "\n    }";
+  }
+  out << (deps.empty() ? "]\n" : "\n  ]\n");
+  out << "}\n";
+  return out.str();
+}
+
+/**
+ * @brief Check whether a vcpkg.json was written by cforge
+ *
+ * @param json File contents
+ * @return true if the file has the marker generate_vcpkg_manifest_json() writes
+ */
+inline bool is_cforge_generated_vcpkg_manifest(const std::string &json) {
+  return json.find(vcpkg_manifest_marker()) != std::string::npos;
+}
+
+/**
+ * @brief Read "builtin-baseline" from a vcpkg.json
+ *
+ * @param json File contents
+ * @return The baseline commit, or empty if there is none
+ */
+inline std::string vcpkg_manifest_baseline(const std::string &json) {
+  const std::string key = "\"builtin-baseline\"";
+  cforge_size_t pos     = json.find(key);
+  if (pos == std::string::npos) {
+    return "";
+  }
+  cforge_size_t open = json.find('"', json.find(':', pos + key.size()));
+  if (open == std::string::npos) {
+    return "";
+  }
+  cforge_size_t close = json.find('"', open + 1);
+  return close == std::string::npos ? "" : json.substr(open + 1, close - open - 1);
+}
+
+}  // namespace cforge
@@ -394,6 +394,71 @@ inline std::optional<std::string> find_best_version(const std::vector<std::strin
   return std::nullopt;
 }
 
+/**
+ * @brief Bump one part of a version, as `cforge version bump` does
+ *
+ * The parts below the bumped one are reset and build metadata is dropped.
+ * A prerelease is released instead of bumped when it already leads to the
+ * requested version, so "2.0.0-rc1" bumped by "major" becomes "2.0.0".
+ *
+ * @param current Current version, e.g. "1.4.2"
+ * @param part "major", "minor" or "patch"
+ * @return The new version, or nullopt if `current` or `part` is not valid
+ */
+inline std::optional<std::string> bump_version(const std::string &current,
+                                               const std::string &part) {
+  auto parsed = semver::parse(current);
+  if (!parsed || parsed->major < 0 || parsed->minor < 0 || parsed->patch < 0) {
+    return std::nullopt;
+  }
+  semver v            = *parsed;
+  bool has_prerelease = !v.prerelease.empty();
+  v.build.clear();
+  v.prerelease.clear();
+  if (part == "major") {
+    if (!has_prerelease || v.minor != 0 || v.patch != 0) {
+      v = semver{v.major + 1, 0, 0, "", ""};
+    }
+  } else if (part == "minor") {
+    if (!has_prerelease || v.patch != 0) {
+      v = semver{v.major, v.minor + 1, 0, "", ""};
+    }
+  } else if (part == "patch") {
+    if (!has_prerelease) {
+      ++v.patch;
+    }
+  } else {
+    return std::nullopt;
+  }
+  return v.to_string();
+}
+
+/**
+ * @brief Point a version constraint at a new version, keeping its operator
+ *
+ * "^1.2.0" retargeted to "1.3.0" becomes "^1.3.0". Ranges and wildcards
+ * can't be retargeted meaningfully and are left to the user.
+ *
+ * @param constraint Constraint with at most one operator, e.g. ">=1.2.0"
+ * @param version The version it should now require
+ * @return The new constraint, or nullopt for a range or wildcard
+ */
+inline std::optional<std::string> retarget_version_constraint(const std::string &constraint,
+                                                              const std::string &version) {
+  if (constraint.find_first_of(", ") != std::string::npos) {
+    return std::nullopt;
+  }
+  cforge_size_t start = constraint.find_first_not_of("^~=<>!");
+  if (start == std::string::npos) {
+    return std::nullopt;
+  }
+  auto target = semver::parse(constraint.substr(start));
+  if (!target || target->major < 0 || target->minor < 0 || target->patch < 0) {
+    return std::nullopt;
+  }
+  return constraint.substr(0, start) + version;
+}
+
 /**
  * @brief Get available Git tags for a repository
  *
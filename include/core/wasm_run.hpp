@@ -0,0 +1,71 @@
+/**
+ * @file wasm_run.hpp
+ * @brief Recognizing and starting WebAssembly builds in `cforge run`
+ *
+ * Emscripten links an executable into a `.js` loader (or an `.html` page and
+ * its loader) with the module in a `.wasm` file of the same name. The loader
+ * runs under node; the page is served and opened in a browser by emrun.
+ */
+
+#pragma once
+
+#include "core/types.h"
+
+#include <filesystem>
+#include <string>
+#include <vector>
+
+namespace cforge {
+
+/**
+ * @brief Check whether a build output is an Emscripten executable
+ *
+ * A `.js` or `.html` file counts when the `.wasm` module of the same name is
+ * next to it, so unrelated scripts in the build tree are not picked up.
+ */
+inline bool is_wasm_executable(const std::filesystem::path &path) {
+  std::string ext = path.extension().string();
+  if (ext != ".js" && ext != ".html") {
+    return false;
+  }
+  std::error_code ec;
+  return std::filesystem::is_regular_file(path, ec)
+      && std::filesystem::exists(std::filesystem::path(path).replace_extension(".wasm"), ec);
+}
+
+/**
+ * @brief File to start for an Emscripten executable
+ *
+ * @param artifact The `.js`, `.html` or `.wasm` output
+ * @param browser Whether the build is opened in a browser rather than node
+ * @return The `.html` page for a browser or the `.js` loader for node,
+ *         empty if the build did not produce it
+ */
+inline std::filesystem::path wasm_entry_point(const std::filesystem::path &artifact,
+                                              bool browser) {
+  std::filesystem::path entry = artifact;
+  entry.replace_extension(browser ? ".html" : ".js");
+  std::error_code ec;
+  if (!std::filesystem::is_regular_file(entry, ec)) {
+    return {};
+  }
+  return entry;
+}
+
+/**
+ * @brief Command line that starts an Emscripten executable
+ *
+ * @param entry File from wasm_entry_point
+ * @param browser Open the page with emrun instead of running the loader in node
+ * @param program_args Arguments for the program
+ * @return Program followed by its arguments
+ */
+inline std::vector<std::string> wasm_run_command(const std::filesystem::path &entry,
+                                                 bool browser,
+                                                 const std::vector<std::string> &program_args) {
+  std::vector<std::string> command = {browser ? "emrun" : "node", entry.string()};
+  command.insert(command.end(), program_args.begin(), program_args.end());
+  return command;
+}
+
+}  // namespace cforge
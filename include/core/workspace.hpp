@@ -5,6 +5,7 @@
 
 #pragma once
 
+#include "core/errors.hpp"
 #include "core/toml_reader.hpp"
 
 #include <algorithm>
@@ -52,6 +53,8 @@ struct workspace_project {
   std::string name;
   std::filesystem::path path;
   std::vector<std::string> dependencies;
+  std::vector<std::string> order_only_dependencies;  ///< Dependencies declared with link = false
+  std::vector<std::string> missing_dependencies;     ///< Referenced projects that are not members
   bool is_startup         = false;  ///< Runtime flag: true if this is the active startup project
   bool is_startup_project = false;  ///< Config flag: true if marked as startup in workspace config
 };
@@ -192,9 +195,40 @@ public:
    */
   std::vector<std::string> get_build_order() const;
 
+  /**
+   * @brief Check the inter-project dependency graph
+   *
+   * Reports self-dependencies, dependencies on projects that are not
+   * workspace members, and dependency cycles with the full cycle path.
+   * @return Success, or the first problem found
+   */
+  result<void> check_dependency_graph() const;
+
   workspace_project get_startup_project() const;
   bool set_startup_project(const std::string &project_name);
 
+  /**
+   * @brief Get the projects `cforge run` may start when none is named
+   *
+   * `default_startup_project` (or the older `main_project`) names a single
+   * project. Otherwise the `startup_projects` array lists the choices, and
+   * failing that, the projects marked `startup = true`.
+   * @return Project names, empty if nothing is configured
+   */
+  std::vector<std::string> get_startup_candidates() const;
+
+  /**
+   * @brief Get the build configuration for a project
+   *
+   * [workspace.project_configs] maps project names to a configuration, e.g.
+   * `codegen = "Release"`; projects it doesn't list use `fallback`.
+   * @param project_name Project name
+   * @param fallback Configuration requested for the workspace
+   * @return Configuration to build the project with
+   */
+  std::string get_project_config(const std::string &project_name,
+                                 const std::string &fallback) const;
+
   bool build_all(const std::string &config, cforge_int_t num_jobs, bool verbose) const;
   bool build_project(const std::string &project_name,
                      const std::string &config,
@@ -269,6 +303,17 @@ bool generate_workspace_cmakelists(const std::filesystem::path &workspace_dir,
  */
 std::pair<bool, std::filesystem::path> is_in_workspace(const std::filesystem::path &path);
 
+/**
+ * @brief Find the workspace that lists a directory as one of its projects
+ *
+ * Unlike is_in_workspace(), a directory that merely sits below a workspace,
+ * such as a dependency cloned into a member's deps/, is not a member.
+ *
+ * @param project_dir Project directory
+ * @return Workspace directory, or empty if no enclosing workspace lists it
+ */
+std::filesystem::path find_member_workspace(const std::filesystem::path &project_dir);
+
 /**
  * @brief Add Git dependencies configuration to CMakeLists.txt
  *
@@ -277,7 +322,8 @@ std::pair<bool, std::filesystem::path> is_in_workspace(const std::filesystem::pa
  * @param deps_dir Dependencies directory
  * @param cmakelists CMakeLists.txt output stream
  */
-void configure_git_dependencies_in_cmake(const toml_reader &project_config,
+void configure_git_dependencies_in_cmake(const std::filesystem::path &project_dir,
+                                         const toml_reader &project_config,
                                          const std::string &deps_dir,
                                          std::ofstream &cmakelists);
 
@@ -305,6 +351,34 @@ void configure_index_dependencies_fetchcontent_phase2(const std::filesystem::pat
                                                       const toml_reader &project_config,
                                                       std::ofstream &cmakelists);
 
+/**
+ * @brief Index dependency (from cforge-index) with its requested version
+ */
+struct index_dep_info {
+  std::string name;
+  std::string version;
+};
+
+struct package_info;
+
+/**
+ * @brief Get the index dependencies of a project with their versions
+ *
+ * @param project_config Project configuration from cforge.toml
+ * @return std::vector<index_dep_info> Index dependencies in declaration order
+ */
+std::vector<index_dep_info> get_index_dependencies_with_versions(
+    const toml_reader &project_config);
+
+/**
+ * @brief Git tag FetchContent checks out for a resolved package version
+ *
+ * @param pkg Package from the registry
+ * @param resolved_version Version resolved from the requirement
+ * @return std::string Tag from the version entry, or one built from the tag pattern
+ */
+std::string fetch_content_git_tag(const package_info &pkg, const std::string &resolved_version);
+
 /**
  * @brief Get the workspace configuration file path
  * Checks for unified format (cforge.toml with [workspace] section) first,
@@ -315,4 +389,19 @@ void configure_index_dependencies_fetchcontent_phase2(const std::filesystem::pat
  */
 std::filesystem::path get_workspace_config_path(const std::filesystem::path &workspace_path);
 
+/**
+ * @brief Merge the enclosing workspace's [defaults] into a project configuration
+ *
+ * Only applies to the cforge.toml of a project the workspace lists (see
+ * find_member_workspace()) when the workspace configuration has a [defaults]
+ * table; other cforge.toml files below the workspace are left alone. See
+ * merge_workspace_defaults() for the merge rules.
+ *
+ * @param project Parsed project configuration, updated in place
+ * @param project_file Path the configuration was read from
+ * @return Dotted keys whose value came from the workspace defaults
+ */
+std::vector<std::string> apply_workspace_defaults(toml::table &project,
+                                                  const std::filesystem::path &project_file);
+
 }  // namespace cforge
\ No newline at end of file
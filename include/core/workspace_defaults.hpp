@@ -0,0 +1,105 @@
+/**
+ * @file workspace_defaults.hpp
+ * @brief Merging a workspace's [defaults] into its projects' cforge.toml
+ */
+
+#pragma once
+
+#include <toml++/toml.hpp>
+
+#include <string>
+#include <vector>
+
+namespace cforge {
+
+/**
+ * @brief Read a list annotated with a merge mode
+ *
+ * In [defaults], a list can be written as `{ merge = "append", value = [...] }`
+ * so a project's own list is added to it instead of replacing it.
+ *
+ * @param node Value from [defaults]
+ * @param mode Receives the merge mode
+ * @return The list, or nullptr if the value is not an annotated list
+ */
+inline const toml::array *annotated_default_list(const toml::node &node, std::string &mode) {
+  const toml::table *table = node.as_table();
+  if (table == nullptr || table->size() != 2) {
+    return nullptr;
+  }
+  auto merge                = (*table)["merge"].value<std::string>();
+  const toml::array *values = (*table)["value"].as_array();
+  if (!merge || values == nullptr) {
+    return nullptr;
+  }
+  mode = *merge;
+  return values;
+}
+
+/**
+ * @brief Merge workspace defaults into a project's configuration
+ *
+ * Works key by key: a value the project sets itself always wins, and tables
+ * such as [build.config.debug] are merged recursively, so a project can
+ * override one setting of a configuration and inherit the rest. Lists are
+ * replaced by the project's list unless the default is annotated with
+ * `merge = "append"` (see annotated_default_list()), in which case the
+ * project's entries are appended to the defaults, skipping duplicates.
+ * `project.name` is never inherited.
+ *
+ * @param project Project configuration, updated in place
+ * @param defaults The workspace's [defaults] table
+ * @param prefix Dotted path of @p project within the configuration
+ * @return Dotted keys whose value came from the workspace; appended lists
+ *         are marked with " (appended)"
+ */
+inline std::vector<std::string> merge_workspace_defaults(toml::table &project,
+                                                         const toml::table &defaults,
+                                                         const std::string &prefix = "") {
+  auto same_string = [](const toml::node &a, const toml::node &b) {
+    auto lhs = a.value<std::string>();
+    auto rhs = b.value<std::string>();
+    return lhs && rhs && *lhs == *rhs;
+  };
+
+  std::vector<std::string> inherited;
+  for (const auto &[key, value] : defaults) {
+    std::string name(key.str());
+    std::string path = prefix.empty() ? name : prefix + "." + name;
+    if (path == "project.name" || path == "workspace") {
+      continue;
+    }
+
+    std::string mode;
+    const toml::array *list = annotated_default_list(value, mode);
+    toml::node *own         = project.get(name);
+
+    if (own == nullptr) {
+      if (list != nullptr) {
+        project.insert_or_assign(name, *list);
+      } else {
+        value.visit([&](const auto &v) { project.insert_or_assign(name, v); });
+      }
+      inherited.push_back(path);
+    } else if (list != nullptr && mode == "append" && own->is_array()) {
+      toml::array merged = *list;
+      for (const auto &item : *own->as_array()) {
+        bool duplicate = false;
+        for (const auto &existing : merged) {
+          duplicate = duplicate || same_string(existing, item);
+        }
+        if (!duplicate) {
+          item.visit([&](const auto &v) { merged.push_back(v); });
+        }
+      }
+      project.insert_or_assign(name, std::move(merged));
+      inherited.push_back(path + " (appended)");
+    } else if (list == nullptr && value.is_table() && own->is_table()) {
+      auto nested = merge_workspace_defaults(*own->as_table(), *value.as_table(), path);
+      inherited.insert(inherited.end(), nested.begin(), nested.end());
+    }
+  }
+  return inherited;
+}
+
+}  // namespace cforge
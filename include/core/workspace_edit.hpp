@@ -0,0 +1,317 @@
+/**
+ * @file workspace_edit.hpp
+ * @brief In-place edits of a workspace's cforge.toml
+ *
+ * Edits work on the text of the file, like `cforge deps remove` does for a
+ * project manifest, so comments and settings cforge doesn't model survive.
+ */
+
+#pragma once
+
+#include "core/types.h"
+
+#include <algorithm>
+#include <sstream>
+#include <string>
+#include <vector>
+
+namespace cforge {
+
+/**
+ * @brief Trim spaces and tabs from both ends of a manifest line
+ */
+inline std::string trim_manifest_line(const std::string &line) {
+  cforge_size_t first = line.find_first_not_of(" \t\r");
+  if (first == std::string::npos) {
+    return "";
+  }
+  cforge_size_t last = line.find_last_not_of(" \t\r");
+  return line.substr(first, last - first + 1);
+}
+
+/**
+ * @brief Split a `key = value` manifest line
+ *
+ * @param line Manifest line
+ * @param key Receives the trimmed key
+ * @param value Receives the trimmed value
+ * @return true if the line is a key/value pair
+ */
+inline bool split_manifest_key(const std::string &line, std::string &key, std::string &value) {
+  std::string trimmed = trim_manifest_line(line);
+  if (trimmed.empty() || trimmed[0] == '#' || trimmed[0] == '[') {
+    return false;
+  }
+  cforge_size_t eq = trimmed.find('=');
+  if (eq == std::string::npos) {
+    return false;
+  }
+  key   = trim_manifest_line(trimmed.substr(0, eq));
+  value = trim_manifest_line(trimmed.substr(eq + 1));
+  return !key.empty();
+}
+
+/**
+ * @brief Collect the double-quoted strings of a TOML value
+ *
+ * @param value Value text, e.g. `["core", "gui"]` or `"core"`
+ * @return Strings in order of appearance
+ */
+inline std::vector<std::string> manifest_strings(const std::string &value) {
+  std::vector<std::string> strings;
+  cforge_size_t pos = 0;
+  while ((pos = value.find('"', pos)) != std::string::npos) {
+    cforge_size_t end = value.find('"', pos + 1);
+    if (end == std::string::npos) {
+      break;
+    }
+    strings.push_back(value.substr(pos + 1, end - pos - 1));
+    pos = end + 1;
+  }
+  return strings;
+}
+
+/**
+ * @brief Normalize a workspace member path for comparison
+ */
+inline std::string normalize_member_path(std::string path) {
+  std::replace(path.begin(), path.end(), '\\', '/');
+  while (path.rfind("./", 0) == 0) {
+    path.erase(0, 2);
+  }
+  while (path.size() > 1 && path.back() == '/') {
+    path.pop_back();
+  }
+  return path;
+}
+
+/**
+ * @brief Remove every reference to a project from workspace manifest text
+ *
+ * Handles each way a workspace can list its projects: `members` and
+ * `projects` string arrays (including the legacy `"name:path:startup"`
+ * entries), `[[workspace.projects]]` and `[[workspace.project]]` tables, and
+ * the `startup_projects`, `main_project` and `default_startup_project` keys.
+ *
+ * @param content Manifest text, updated in place
+ * @param name Project name
+ * @param path Project directory relative to the workspace
+ * @return true if anything was removed
+ */
+inline bool remove_workspace_project_entries(std::string &content,
+                                             const std::string &name,
+                                             const std::string &path) {
+  std::vector<std::string> lines;
+  {
+    std::istringstream in(content);
+    std::string line;
+    while (std::getline(in, line)) {
+      lines.push_back(line);
+    }
+  }
+
+  std::string member_path = normalize_member_path(path);
+  auto matches            = [&](const std::string &entry, bool legacy) {
+    if (entry == name || normalize_member_path(entry) == member_path) {
+      return true;
+    }
+    // Legacy "name:path:startup" entries; a drive letter is not a name
+    cforge_size_t colon = entry.find(':');
+    return legacy && colon != std::string::npos && colon > 1 && entry.substr(0, colon) == name;
+  };
+
+  std::vector<std::string> out;
+  std::string section;
+  bool removed = false;
+
+  for (cforge_size_t i = 0; i < lines.size(); ++i) {
+    std::string trimmed = trim_manifest_line(lines[i]);
+
+    if (trimmed == "[[workspace.projects]]" || trimmed == "[[workspace.project]]") {
+      section           = "";
+      cforge_size_t end = i + 1;
+      bool is_target    = false;
+      for (; end < lines.size(); ++end) {
+        std::string key, value;
+        if (trim_manifest_line(lines[end]).rfind('[', 0) == 0) {
+          break;
+        }
+        if (split_manifest_key(lines[end], key, value) && key == "name") {
+          auto strings = manifest_strings(value);
+          is_target    = !strings.empty() && strings[0] == name;
+        }
+      }
+      if (is_target) {
+        // Comments right before the next table belong to that table
+        cforge_size_t keep_from = end;
+        for (cforge_size_t k = end; k > i + 1; --k) {
+          std::string previous = trim_manifest_line(lines[k - 1]);
+          if (!previous.empty() && previous[0] != '#') {
+            break;
+          }
+          if (!previous.empty()) {
+            keep_from = k - 1;
+          }
+        }
+        out.insert(out.end(), lines.begin() + keep_from, lines.begin() + end);
+        removed = true;
+      } else {
+        out.insert(out.end(), lines.begin() + i, lines.begin() + end);
+      }
+      i = end - 1;
+      continue;
+    }
+
+    if (!trimmed.empty() && trimmed[0] == '[') {
+      section = trimmed;
+      out.push_back(lines[i]);
+      continue;
+    }
+
+    std::string key, value;
+    if (section != "[workspace]" || !split_manifest_key(lines[i], key, value)) {
+      out.push_back(lines[i]);
+      continue;
+    }
+
+    if (key == "main_project" || key == "default_startup_project") {
+      auto strings = manifest_strings(value);
+      if (!strings.empty() && strings[0] == name) {
+        removed = true;
+        continue;
+      }
+      out.push_back(lines[i]);
+      continue;
+    }
+
+    if ((key != "members" && key != "projects" && key != "startup_projects")
+        || value.rfind('[', 0) != 0) {
+      out.push_back(lines[i]);
+      continue;
+    }
+
+    // Gather a multi-line array up to its closing bracket
+    cforge_size_t end = i;
+    std::string array = value;
+    while (array.find(']') == std::string::npos && end + 1 < lines.size()) {
+      array += "\n" + lines[++end];
+    }
+
+    std::vector<std::string> kept;
+    bool changed = false;
+    for (const auto &entry : manifest_strings(array)) {
+      if (matches(entry, key == "projects")) {
+        changed = true;
+      } else {
+        kept.push_back(entry);
+      }
+    }
+    if (!changed) {
+      out.insert(out.end(), lines.begin() + i, lines.begin() + end + 1);
+      i = end;
+      continue;
+    }
+
+    removed            = true;
+    std::string prefix = lines[i].substr(0, lines[i].find('=') + 1) + " ";
+    if (end == i) {
+      std::string rebuilt = prefix + "[";
+      for (cforge_size_t k = 0; k < kept.size(); ++k) {
+        rebuilt += (k ? ", \"" : "\"") + kept[k] + "\"";
+      }
+      out.push_back(rebuilt + "]");
+    } else {
+      std::string indent = "    ";
+      if (i + 1 < lines.size()) {
+        indent = lines[i + 1].substr(0, lines[i + 1].find_first_not_of(" \t"));
+      }
+      out.push_back(prefix + "[");
+      for (const auto &entry : kept) {
+        out.push_back(indent + "\"" + entry + "\",");
+      }
+      out.push_back("]");
+    }
+    i = end;
+  }
+
+  if (removed) {
+    std::string result;
+    for (const auto &line : out) {
+      result += line + "\n";
+    }
+    content = result;
+  }
+  return removed;
+}
+
+/**
+ * @brief Set `default_startup_project` in workspace manifest text
+ *
+ * Replaces the value if the [workspace] table already has the key, and
+ * otherwise adds it after the table's last key.
+ *
+ * @param content Manifest text, updated in place
+ * @param name Project name
+ * @return true if the manifest has a [workspace] table
+ */
+inline bool set_default_startup_project(std::string &content, const std::string &name) {
+  std::vector<std::string> lines;
+  {
+    std::istringstream in(content);
+    std::string line;
+    while (std::getline(in, line)) {
+      lines.push_back(line);
+    }
+  }
+
+  std::string entry       = "default_startup_project = \"" + name + "\"";
+  bool in_workspace       = false;
+  bool has_workspace      = false;
+  bool replaced           = false;
+  cforge_size_t insert_at = 0;
+
+  for (cforge_size_t i = 0; i < lines.size() && !replaced; ++i) {
+    std::string trimmed = trim_manifest_line(lines[i]);
+    if (!trimmed.empty() && trimmed[0] == '[') {
+      in_workspace = trimmed == "[workspace]";
+      if (in_workspace) {
+        has_workspace = true;
+        insert_at     = i + 1;
+      }
+      continue;
+    }
+
+    std::string key, value;
+    if (!in_workspace || !split_manifest_key(lines[i], key, value)) {
+      continue;
+    }
+    if (key == "default_startup_project") {
+      lines[i] = lines[i].substr(0, lines[i].find_first_not_of(" \t")) + entry;
+      replaced = true;
+      break;
+    }
+
+    // A multi-line array ends at its closing bracket
+    while (value.rfind('[', 0) == 0 && value.find(']') == std::string::npos
+           && i + 1 < lines.size()) {
+      value += lines[++i];
+    }
+    insert_at = i + 1;
+  }
+
+  if (!has_workspace) {
+    return false;
+  }
+  if (!replaced) {
+    lines.insert(lines.begin() + insert_at, entry);
+  }
+
+  std::string result;
+  for (const auto &line : lines) {
+    result += line + "\n";
+  }
+  content = result;
+  return true;
+}
+
+}  // namespace cforge
@@ -0,0 +1,111 @@
+/**
+ * @file workspace_members.hpp
+ * @brief Which directories a workspace lists as its projects
+ *
+ * A cforge.toml below a workspace is not necessarily one of its projects: it
+ * can be an unrelated project, or a cforge-based dependency cloned into a
+ * member's deps/ directory. Only directories the workspace lists count.
+ */
+
+#pragma once
+
+#include <toml++/toml.hpp>
+
+#include <filesystem>
+#include <string>
+#include <system_error>
+#include <vector>
+
+namespace cforge {
+
+/**
+ * @brief Project paths listed by a workspace configuration
+ *
+ * Reads `workspace.members`, `[[workspace.projects]]`, the legacy
+ * `[[workspace.project]]` tables and the legacy `"name:path:startup"`
+ * strings in `workspace.projects`. A table without a path uses its name.
+ *
+ * @param workspace_config Parsed workspace configuration
+ * @return Paths relative to the workspace directory
+ */
+inline std::vector<std::string> workspace_member_paths(const toml::table &workspace_config) {
+  std::vector<std::string> paths;
+  const toml::table *workspace = workspace_config["workspace"].as_table();
+  if (workspace == nullptr) {
+    return paths;
+  }
+
+  if (const toml::array *members = (*workspace)["members"].as_array()) {
+    for (const auto &member : *members) {
+      if (auto path = member.value<std::string>(); path && !path->empty()) {
+        paths.push_back(*path);
+      }
+    }
+  }
+
+  for (const char *key : {"projects", "project"}) {
+    const toml::array *projects = (*workspace)[key].as_array();
+    if (projects == nullptr) {
+      continue;
+    }
+    for (const auto &entry : *projects) {
+      if (const toml::table *table = entry.as_table()) {
+        std::string name = (*table)["name"].value_or(std::string());
+        std::string path = (*table)["path"].value_or(name);
+        if (!path.empty()) {
+          paths.push_back(path);
+        }
+        continue;
+      }
+      auto text = entry.value<std::string>();
+      if (!text || text->empty()) {
+        continue;
+      }
+      // A plain path, or "name:path:startup"; a drive letter's colon is part of the path
+      auto is_drive = [](const std::string &s) {
+        return s.size() > 2 && s[1] == ':' && (s[2] == '\\' || s[2] == '/');
+      };
+      std::size_t colon = text->find(':');
+      if (colon == std::string::npos || is_drive(*text)) {
+        paths.push_back(*text);
+        continue;
+      }
+      std::string rest = text->substr(colon + 1);
+      std::string path = rest.substr(0, rest.find(':', is_drive(rest) ? 2 : 0));
+      paths.push_back(path.empty() ? text->substr(0, colon) : path);
+    }
+  }
+  return paths;
+}
+
+/**
+ * @brief Whether a directory is one of the workspace's listed projects
+ *
+ * @param workspace_dir Workspace directory
+ * @param member_paths Paths from workspace_member_paths()
+ * @param project_dir Directory to check
+ */
+inline bool is_workspace_member_dir(const std::filesystem::path &workspace_dir,
+                                    const std::vector<std::string> &member_paths,
+                                    const std::filesystem::path &project_dir) {
+  std::error_code ec;
+  std::filesystem::path target = std::filesystem::weakly_canonical(project_dir, ec);
+  if (ec) {
+    target = project_dir.lexically_normal();
+  }
+  for (const auto &member : member_paths) {
+    std::filesystem::path path = std::filesystem::path(member).is_absolute()
+                                   ? std::filesystem::path(member)
+                                   : workspace_dir / member;
+    std::filesystem::path resolved = std::filesystem::weakly_canonical(path, ec);
+    if (ec) {
+      resolved = path.lexically_normal();
+    }
+    if (resolved == target) {
+      return true;
+    }
+  }
+  return false;
+}
+
+}  // namespace cforge
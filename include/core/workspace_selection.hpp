@@ -0,0 +1,173 @@
+/**
+ * @file workspace_selection.hpp
+ * @brief Choosing workspace projects by name, for `cforge foreach --filter`
+ *        and `cforge build --only/--exclude`
+ */
+
+#pragma once
+
+#include "core/types.h"
+
+#include <algorithm>
+#include <map>
+#include <set>
+#include <string>
+#include <vector>
+
+namespace cforge {
+
+/**
+ * @brief Match a project name against a glob pattern
+ *
+ * `*` matches any run of characters and `?` a single character; everything
+ * else matches itself.
+ */
+inline bool project_pattern_matches(const std::string &pattern, const std::string &name) {
+  cforge_size_t p    = 0;
+  cforge_size_t n    = 0;
+  cforge_size_t star = std::string::npos;
+  cforge_size_t mark = 0;
+  while (n < name.size()) {
+    if (p < pattern.size() && (pattern[p] == '?' || pattern[p] == name[n])) {
+      ++p;
+      ++n;
+    } else if (p < pattern.size() && pattern[p] == '*') {
+      star = p++;
+      mark = n;
+    } else if (star != std::string::npos) {
+      p = star + 1;
+      n = ++mark;
+    } else {
+      return false;
+    }
+  }
+  while (p < pattern.size() && pattern[p] == '*') {
+    ++p;
+  }
+  return p == pattern.size();
+}
+
+/**
+ * @brief Check whether a project name matches any of the patterns
+ */
+inline bool matches_any_project_pattern(const std::vector<std::string> &patterns,
+                                        const std::string &name) {
+  return std::any_of(patterns.begin(), patterns.end(), [&](const std::string &pattern) {
+    return project_pattern_matches(pattern, name);
+  });
+}
+
+/**
+ * @brief An excluded project that a selected project needs
+ */
+struct required_excluded_project {
+  std::string project;      ///< The excluded project
+  std::string required_by;  ///< First selected project that depends on it
+};
+
+/**
+ * @brief Projects picked by --only/--exclude
+ */
+struct workspace_selection {
+  std::vector<std::string> projects;   ///< In build order
+  std::vector<std::string> unmatched;  ///< --only patterns that match no project
+  std::vector<required_excluded_project> required_excluded;
+};
+
+/**
+ * @brief Select workspace projects by name
+ *
+ * @param build_order Every project, dependencies first
+ * @param dependencies Workspace dependencies of each project
+ * @param only Patterns of the projects to select; empty selects all
+ * @param exclude Patterns of the projects to leave out
+ * @param with_dependencies Also select the projects the selection depends
+ *        on, even if they are excluded
+ * @return Selected projects
+ */
+inline workspace_selection
+select_workspace_projects(const std::vector<std::string> &build_order,
+                          const std::map<std::string, std::vector<std::string>> &dependencies,
+                          const std::vector<std::string> &only,
+                          const std::vector<std::string> &exclude,
+                          bool with_dependencies) {
+  workspace_selection selection;
+  for (const auto &pattern : only) {
+    if (std::none_of(build_order.begin(), build_order.end(), [&](const std::string &name) {
+          return project_pattern_matches(pattern, name);
+        })) {
+      selection.unmatched.push_back(pattern);
+    }
+  }
+
+  std::set<std::string> selected;
+  std::vector<std::string> pending;
+  for (const auto &name : build_order) {
+    if ((only.empty() || matches_any_project_pattern(only, name))
+        && !matches_any_project_pattern(exclude, name)) {
+      selected.insert(name);
+      pending.push_back(name);
+    }
+  }
+
+  while (with_dependencies && !pending.empty()) {
+    std::string name = pending.back();
+    pending.pop_back();
+    auto it = dependencies.find(name);
+    if (it == dependencies.end()) {
+      continue;
+    }
+    for (const auto &dep : it->second) {
+      if (selected.insert(dep).second) {
+        pending.push_back(dep);
+        if (matches_any_project_pattern(exclude, dep)) {
+          selection.required_excluded.push_back({dep, name});
+        }
+      }
+    }
+  }
+
+  for (const auto &name : build_order) {
+    if (selected.count(name)) {
+      selection.projects.push_back(name);
+    }
+  }
+  return selection;
+}
+
+/**
+ * @brief Group projects into waves that can run at the same time
+ *
+ * Each project comes after every wave holding one of its dependencies.
+ * Dependencies outside @p projects are ignored.
+ *
+ * @param projects Projects in build order
+ * @param dependencies Workspace dependencies of each project
+ * @return Waves, each in build order
+ */
+inline std::vector<std::vector<std::string>>
+workspace_project_waves(const std::vector<std::string> &projects,
+                        const std::map<std::string, std::vector<std::string>> &dependencies) {
+  std::map<std::string, cforge_size_t> wave_of;
+  std::vector<std::vector<std::string>> waves;
+  for (const auto &name : projects) {
+    cforge_size_t wave = 0;
+    auto it            = dependencies.find(name);
+    if (it != dependencies.end()) {
+      for (const auto &dep : it->second) {
+        auto dep_wave = wave_of.find(dep);
+        if (dep_wave != wave_of.end()) {
+          wave = std::max(wave, dep_wave->second + 1);
+        }
+      }
+    }
+    wave_of[name] = wave;
+    if (waves.size() <= wave) {
+      waves.resize(wave + 1);
+    }
+    waves[wave].push_back(name);
+  }
+  return waves;
+}
+
+}  // namespace cforge
@@ -19,13 +19,49 @@
 #include "core/types.h"
 
 #include <cmath>
+#include <cstdlib>
+#include <cstring>
 #include <iostream>
+#include <mutex>
 #include <vector>
 
+#ifdef _WIN32
+#include <io.h>  // _isatty, _fileno
+#else
+#include <unistd.h>  // isatty, fileno
+#endif
+
 #ifdef __cplusplus
 namespace cforge {
 
 log_verbosity logger::s_verbosity = log_verbosity::VERBOSITY_NORMAL;
+output_format logger::s_format     = output_format::HUMAN;
+color_mode logger::s_color_mode    = color_mode::AUTO;
+
+namespace {
+
+bool is_terminal(FILE *stream) {
+#ifdef _WIN32
+  return _isatty(_fileno(stream)) != 0;
+#else
+  return isatty(fileno(stream)) != 0;
+#endif
+}
+
+// Command shown by the current running_timer, so non-interactive output can
+// print it once when the timer starts and again when it stops
+std::string s_timer_command;
+
+std::string format_elapsed(cforge_double_t elapsed_secs) {
+  if (elapsed_secs < 60.0) {
+    return fmt::format("{:.1f}s", elapsed_secs);
+  }
+  cforge_int_t mins    = static_cast<int>(elapsed_secs) / 60;
+  cforge_double_t secs = elapsed_secs - (mins * 60);
+  return fmt::format("{}m {:.0f}s", mins, secs);
+}
+
+}  // namespace
 
 void logger::set_verbosity(log_verbosity level) {
   s_verbosity = level;
@@ -35,6 +71,65 @@ log_verbosity logger::get_verbosity() {
   return s_verbosity;
 }
 
+void logger::set_output_format(output_format format) {
+  s_format = format;
+}
+
+output_format logger::get_output_format() {
+  return s_format;
+}
+
+void logger::set_color_mode(color_mode mode) {
+  s_color_mode = mode;
+}
+
+color_mode logger::get_color_mode() {
+  return s_color_mode;
+}
+
+bool logger::use_color(FILE *stream) {
+  switch (s_color_mode) {
+    case color_mode::ALWAYS:
+      return true;
+    case color_mode::NEVER:
+      return false;
+    case color_mode::AUTO:
+      break;
+  }
+  // https://no-color.org: any non-empty value disables color
+  const char *no_color = std::getenv("NO_COLOR");
+  if (no_color && no_color[0] != '\0') {
+    return false;
+  }
+  return is_terminal(stream);
+}
+
+bool logger::is_interactive() {
+  if (s_format == output_format::JSON || !is_terminal(stderr)) {
+    return false;
+  }
+  const char *term = std::getenv("TERM");
+  return !(term && std::strcmp(term, "dumb") == 0);
+}
+
+fmt::text_style logger::style(fmt::text_style style, FILE *stream) {
+  return use_color(stream) ? style : fmt::text_style();
+}
+
+// Machine-readable output
+
+void logger::print_json_event(const std::string &event,
+                              const std::vector<std::pair<std::string, std::string>> &fields) {
+  std::string line = "{\"event\":" + json_quote(event);
+  for (const auto &[key, value] : fields) {
+    line += "," + json_quote(key) + ":" + value;
+  }
+  line += "}";
+  // Always stdout, one event per line, so the stream is valid NDJSON
+  fmt::print("{}\n", line);
+  std::fflush(stdout);
+}
+
 // Core formatting helper
 
 void logger::print_status_line(const std::string &status,
@@ -42,11 +137,30 @@ void logger::print_status_line(const std::string &status,
                                fmt::color status_color,
                                bool is_bold,
                                FILE *stream) {
+  if (s_format == output_format::JSON) {
+    if (status == "error" || status == "warning") {
+      print_json_event(status, {{"message", json_quote(message)}});
+    } else {
+      print_json_event("status",
+                       {{"status", json_quote(status)}, {"message", json_quote(message)}});
+    }
+    return;
+  }
+
+  // Status lines may come from worker threads (parallel dependency fetches);
+  // keep the status word and message on one line
+  static std::mutex s_status_line_mutex;
+  std::lock_guard<std::mutex> lock(s_status_line_mutex);
+
   // Right-align status word to STATUS_WIDTH characters
   if (is_bold) {
-    fmt::print(stream, fg(status_color) | fmt::emphasis::bold, "{:>{}}", status, STATUS_WIDTH);
+    fmt::print(stream,
+               style(fg(status_color) | fmt::emphasis::bold, stream),
+               "{:>{}}",
+               status,
+               STATUS_WIDTH);
   } else {
-    fmt::print(stream, fg(status_color), "{:>{}}", status, STATUS_WIDTH);
+    fmt::print(stream, style(fg(status_color), stream), "{:>{}}", status, STATUS_WIDTH);
   }
   fmt::print(stream, " {}\n", message);
 }
@@ -144,7 +258,8 @@ void logger::running(const std::string &command) {
 }
 
 void logger::running_timer(const std::string &command, cforge_double_t elapsed_secs) {
-  if (s_verbosity == log_verbosity::VERBOSITY_QUIET) {
+  // In-place timers are purely decorative
+  if (s_verbosity == log_verbosity::VERBOSITY_QUIET || s_format == output_format::JSON) {
     return;
   }
 
@@ -154,22 +269,56 @@ void logger::running_timer(const std::string &command, cforge_double_t elapsed_s
     display_cmd = display_cmd.substr(0, 27) + "...";
   }
 
-  // Format elapsed time
-  std::string time_str;
-  if (elapsed_secs < 60.0) {
-    time_str = fmt::format("{:.1f}s", elapsed_secs);
-  } else {
-    cforge_int_t mins    = static_cast<int>(elapsed_secs) / 60;
-    cforge_double_t secs = elapsed_secs - (mins * 60);
-    time_str    = fmt::format("{}m {:.0f}s", mins, secs);
+  // Logs and pipes get one permanent line instead of a redrawn timer
+  if (!is_interactive()) {
+    if (s_timer_command.empty()) {
+      s_timer_command = display_cmd;
+      fmt::print(stderr,
+                 style(fg(fmt::color::green) | fmt::emphasis::bold, stderr),
+                 "{:>{}}",
+                 "Running",
+                 STATUS_WIDTH);
+      fmt::print(stderr, " {}...\n", display_cmd);
+      std::fflush(stderr);
+    }
+    return;
   }
+  s_timer_command = display_cmd;
 
   // Clear line and print with timer (updates in place)
   fmt::print(stderr, "\r\033[K");
-  fmt::print(
-      stderr, fg(fmt::color::green) | fmt::emphasis::bold, "{:>{}}", "Running", STATUS_WIDTH);
+  fmt::print(stderr,
+             style(fg(fmt::color::green) | fmt::emphasis::bold, stderr),
+             "{:>{}}",
+             "Running",
+             STATUS_WIDTH);
   fmt::print(stderr, " {}", display_cmd);
-  fmt::print(stderr, fg(fmt::color::dim_gray), " ({})", time_str);
+  fmt::print(
+      stderr, style(fg(fmt::color::dim_gray), stderr), " ({})", format_elapsed(elapsed_secs));
+  std::fflush(stderr);
+}
+
+void logger::stop_timer(cforge_double_t elapsed_secs, bool success) {
+  if (s_timer_command.empty()) {
+    return;
+  }
+  std::string display_cmd = s_timer_command;
+  s_timer_command.clear();
+
+  if (is_interactive()) {
+    fmt::print(stderr, "\r\033[K\n");
+    std::fflush(stderr);
+    return;
+  }
+
+  fmt::print(stderr,
+             style(fg(fmt::color::green) | fmt::emphasis::bold, stderr),
+             "{:>{}}",
+             "Running",
+             STATUS_WIDTH);
+  fmt::print(stderr, " {}... {}", display_cmd, success ? "done" : "failed");
+  fmt::print(
+      stderr, style(fg(fmt::color::dim_gray), stderr), " ({})\n", format_elapsed(elapsed_secs));
   std::fflush(stderr);
 }
 
@@ -289,17 +438,30 @@ void logger::compiling_file(const std::string &file,
     display_file = file.substr(7);
   }
 
+  if (s_format == output_format::JSON) {
+    print_json_event("build_step",
+                     {{"name", json_quote(action)},
+                      {"target", json_quote(display_file)},
+                      {"current", std::to_string(current)},
+                      {"total", std::to_string(total)}});
+    return;
+  }
+
   // The progress bar (if any) is currently sitting on this line. Clear it
   // first, then print the new permanent file line on top. The caller is
   // responsible for redrawing the bar on the next line below.
-  if (s_progress_initialized) {
+  if (s_progress_initialized && is_interactive()) {
     fmt::print(stderr, "\r\033[K");
   }
 
-  fmt::print(stderr, fg(fmt::color::green) | fmt::emphasis::bold, "{:>{}}", action, STATUS_WIDTH);
+  fmt::print(stderr,
+             style(fg(fmt::color::green) | fmt::emphasis::bold, stderr),
+             "{:>{}}",
+             action,
+             STATUS_WIDTH);
   fmt::print(stderr, " {}", display_file);
   if (current > 0 && total > 0) {
-    fmt::print(stderr, fg(fmt::color::dim_gray), " [{}/{}]", current, total);
+    fmt::print(stderr, style(fg(fmt::color::dim_gray), stderr), " [{}/{}]", current, total);
   }
   fmt::print(stderr, "\n");
   s_progress_initialized = true;
@@ -318,15 +480,28 @@ void logger::progress_step(const std::string &action,
     return;
   }
 
+  if (s_format == output_format::JSON) {
+    print_json_event("build_step",
+                     {{"name", json_quote(action)},
+                      {"target", json_quote(target)},
+                      {"current", std::to_string(current)},
+                      {"total", std::to_string(total)}});
+    return;
+  }
+
   // Mirrors `compiling_file`'s contract: clear any in-place bar first so the
   // new permanent line lands cleanly above where the bar will be re-drawn.
-  if (s_progress_initialized) {
+  if (s_progress_initialized && is_interactive()) {
     fmt::print(stderr, "\r\033[K");
   }
-  fmt::print(stderr, fg(fmt::color::green) | fmt::emphasis::bold, "{:>{}}", action, STATUS_WIDTH);
+  fmt::print(stderr,
+             style(fg(fmt::color::green) | fmt::emphasis::bold, stderr),
+             "{:>{}}",
+             action,
+             STATUS_WIDTH);
   fmt::print(stderr, " {}", target);
   if (current > 0 && total > 0) {
-    fmt::print(stderr, fg(fmt::color::dim_gray), " [{}/{}]", current, total);
+    fmt::print(stderr, style(fg(fmt::color::dim_gray), stderr), " [{}/{}]", current, total);
   }
   fmt::print(stderr, "\n");
   s_progress_initialized = true;
@@ -338,7 +513,8 @@ void logger::progress_bar(cforge_int_t current,
                           bool in_place,
                           cforge_double_t elapsed_secs,
                           const std::string &action) {
-  if (s_verbosity == log_verbosity::VERBOSITY_QUIET) {
+  // Progress bars are purely decorative; build_step events carry the counts
+  if (s_verbosity == log_verbosity::VERBOSITY_QUIET || s_format == output_format::JSON) {
     return;
   }
 
@@ -355,13 +531,23 @@ void logger::progress_bar(cforge_int_t current,
   }
 
   if (in_place) {
+    // Redrawing in place only garbles logs; the per-file lines already
+    // record progress when stderr is not a terminal
+    if (!is_interactive()) {
+      return;
+    }
+
     // Cargo-style bar: right-aligned status word in bold green, then a
     // bracketed bar with green '=' fill, green '>' cursor at the leading
     // edge, and plain spaces for unfilled space. Counts + elapsed appear
     // after. The action label defaults to "Building" but callers like
     // `cforge fmt` pass "Formatting" / "Linting" etc.
     fmt::print(stderr, "\r\033[K");
-    fmt::print(stderr, fg(fmt::color::green) | fmt::emphasis::bold, "{:>{}}", action, STATUS_WIDTH);
+    fmt::print(stderr,
+               style(fg(fmt::color::green) | fmt::emphasis::bold, stderr),
+               "{:>{}}",
+               action,
+               STATUS_WIDTH);
 
     const cforge_int_t bar_width = 30;
     if (total > 0) {
@@ -374,10 +560,10 @@ void logger::progress_bar(cforge_int_t current,
 
       fmt::print(stderr, " [");
       for (cforge_int_t i = 0; i < filled; ++i) {
-        fmt::print(stderr, fg(fmt::color::green), "=");
+        fmt::print(stderr, style(fg(fmt::color::green), stderr), "=");
       }
       if (filled < bar_width && current < total) {
-        fmt::print(stderr, fg(fmt::color::green), ">");
+        fmt::print(stderr, style(fg(fmt::color::green), stderr), ">");
         for (cforge_int_t i = filled + 1; i < bar_width; ++i) {
           fmt::print(stderr, " ");
         }
@@ -410,9 +596,9 @@ void logger::progress_bar(cforge_int_t current,
       for (cforge_int_t i = 0; i < bar_width; ++i) {
         if (i >= hl_start && i < hl_start + hl_width) {
           if (i == hl_start + hl_width - 1) {
-            fmt::print(stderr, fg(fmt::color::green), ">");
+            fmt::print(stderr, style(fg(fmt::color::green), stderr), ">");
           } else {
-            fmt::print(stderr, fg(fmt::color::green), "=");
+            fmt::print(stderr, style(fg(fmt::color::green), stderr), "=");
           }
         } else {
           fmt::print(stderr, " ");
@@ -422,7 +608,7 @@ void logger::progress_bar(cforge_int_t current,
     }
 
     if (!time_str.empty()) {
-      fmt::print(stderr, fg(fmt::color::dim_gray), " ({})", time_str);
+      fmt::print(stderr, style(fg(fmt::color::dim_gray), stderr), " ({})", time_str);
     }
     std::fflush(stderr);
   } else {
@@ -438,10 +624,10 @@ void logger::progress_bar(cforge_int_t current,
     }
     fmt::print(stderr, "   [");
     for (cforge_int_t i = 0; i < filled; ++i) {
-      fmt::print(stderr, fg(fmt::color::green), "=");
+      fmt::print(stderr, style(fg(fmt::color::green), stderr), "=");
     }
     if (filled < bar_width && current < total) {
-      fmt::print(stderr, fg(fmt::color::green), ">");
+      fmt::print(stderr, style(fg(fmt::color::green), stderr), ">");
       for (cforge_int_t i = filled + 1; i < bar_width; ++i) {
         fmt::print(stderr, " ");
       }
@@ -459,6 +645,9 @@ void logger::progress_bar(cforge_int_t current,
 }
 
 void logger::clear_line() {
+  if (!is_interactive()) {
+    return;
+  }
   // Move to beginning of line and clear it
   fmt::print(stderr, "\r\033[K");
   std::fflush(stderr);
@@ -471,16 +660,32 @@ void logger::print_timing_summary(
     return;
   }
 
+  if (s_format == output_format::JSON) {
+    std::string slowest = "[";
+    for (const auto &[file, duration] : slowest_files) {
+      if (slowest.size() > 1) {
+        slowest += ",";
+      }
+      slowest += "{\"file\":" + json_quote(file) + ",\"duration\":"
+               + fmt::format("{:.3f}", duration) + "}";
+    }
+    slowest += "]";
+    print_json_event("timing",
+                     {{"duration", fmt::format("{:.3f}", total_duration)}, {"slowest", slowest}});
+    return;
+  }
+
   fmt::print("\n");
   print_status_line("Finished", fmt::format("in {:.2f}s", total_duration), fmt::color::green);
 
   if (!slowest_files.empty()) {
-    fmt::print(fg(fmt::color::cyan) | fmt::emphasis::bold, "{:>{}}", "Slowest", STATUS_WIDTH);
+    fmt::print(
+        style(fg(fmt::color::cyan) | fmt::emphasis::bold), "{:>{}}", "Slowest", STATUS_WIDTH);
     fmt::print(" files:\n");
     for (const auto &[file, duration] : slowest_files) {
       fmt::print("{:>{}}", "", STATUS_WIDTH);
       if (duration >= 10.0) {
-        fmt::print(fg(fmt::color::yellow), " {:>6.1f}s  {}\n", duration, file);
+        fmt::print(style(fg(fmt::color::yellow)), " {:>6.1f}s  {}\n", duration, file);
       } else {
         fmt::print(" {:>6.1f}s  {}\n", duration, file);
       }
@@ -494,8 +699,12 @@ void logger::print_header(const std::string &message) {
   if (s_verbosity == log_verbosity::VERBOSITY_QUIET) {
     return;
   }
+  if (s_format == output_format::JSON) {
+    print_json_event("message", {{"message", json_quote(message)}});
+    return;
+  }
   // Simple header without box drawing
-  fmt::print(fg(fmt::color::cyan) | fmt::emphasis::bold, "{}\n", message);
+  fmt::print(style(fg(fmt::color::cyan) | fmt::emphasis::bold), "{}\n", message);
 }
 
 void logger::print_step(const std::string &action, const std::string &target) {
@@ -506,6 +715,28 @@ void logger::print_step(const std::string &action, const std::string &target) {
 }
 
 void logger::print_plain(const std::string &message) {
+  if (s_format == output_format::JSON) {
+    // Commands that already produce JSON (e.g. `--json`, or diagnostics in
+    // JSON mode) pass through untouched; anything else becomes message events
+    auto first = message.find_first_not_of(" \t\r\n");
+    if (first != std::string::npos && (message[first] == '{' || message[first] == '[')) {
+      fmt::print("{}\n", message);
+      return;
+    }
+    std::string::size_type start = 0;
+    while (start <= message.size()) {
+      auto end         = message.find('\n', start);
+      std::string line = message.substr(start, end == std::string::npos ? end : end - start);
+      if (!line.empty()) {
+        print_json_event("message", {{"message", json_quote(line)}});
+      }
+      if (end == std::string::npos) {
+        break;
+      }
+      start = end + 1;
+    }
+    return;
+  }
   fmt::print("{}\n", message);
 }
 
@@ -521,13 +752,21 @@ void logger::print_section(const std::string &title) {
   if (s_verbosity == log_verbosity::VERBOSITY_QUIET) {
     return;
   }
-  fmt::print(fg(fmt::color::cyan) | fmt::emphasis::bold, "{}\n", title);
+  if (s_format == output_format::JSON) {
+    print_json_event("message", {{"message", json_quote(title)}});
+    return;
+  }
+  fmt::print(style(fg(fmt::color::cyan) | fmt::emphasis::bold), "{}\n", title);
 }
 
 void logger::print_kv(const std::string &key, const std::string &value, cforge_int_t key_width, int indent) {
   if (s_verbosity == log_verbosity::VERBOSITY_QUIET) {
     return;
   }
+  if (s_format == output_format::JSON) {
+    print_json_event("message", {{"key", json_quote(key)}, {"value", json_quote(value)}});
+    return;
+  }
   std::string key_fmt = key.empty() ? "" : key + ":";
   fmt::print("{:{}}{:<{}} {}\n", "", indent, key_fmt, key_width, value);
 }
@@ -540,15 +779,23 @@ void logger::print_kv_colored(const std::string &key,
   if (s_verbosity == log_verbosity::VERBOSITY_QUIET) {
     return;
   }
+  if (s_format == output_format::JSON) {
+    print_kv(key, value, key_width, indent);
+    return;
+  }
   std::string key_fmt = key.empty() ? "" : key + ":";
   fmt::print("{:{}}{:<{}}", "", indent, key_fmt, key_width);
-  fmt::print(fg(value_color), "{}\n", value);
+  fmt::print(style(fg(value_color)), "{}\n", value);
 }
 
 void logger::print_list_item(const std::string &text, const std::string &bullet, cforge_int_t indent) {
   if (s_verbosity == log_verbosity::VERBOSITY_QUIET) {
     return;
   }
+  if (s_format == output_format::JSON) {
+    print_json_event("message", {{"message", json_quote(text)}});
+    return;
+  }
   fmt::print("{:{}}{} {}\n", "", indent, bullet, text);
 }
 
@@ -556,28 +803,41 @@ void logger::print_dim(const std::string &message, cforge_int_t indent) {
   if (s_verbosity == log_verbosity::VERBOSITY_QUIET) {
     return;
   }
-  fmt::print(fg(fmt::color::gray), "{:{}}{}\n", "", indent, message);
+  if (s_format == output_format::JSON) {
+    print_json_event("message", {{"message", json_quote(message)}});
+    return;
+  }
+  fmt::print(style(fg(fmt::color::gray)), "{:{}}{}\n", "", indent, message);
 }
 
 void logger::print_rule(cforge_int_t width, char ch) {
-  if (s_verbosity == log_verbosity::VERBOSITY_QUIET) {
+  if (s_verbosity == log_verbosity::VERBOSITY_QUIET || s_format == output_format::JSON) {
     return;
   }
-  fmt::print(fg(fmt::color::gray), "{}\n", std::string(width, ch));
+  fmt::print(style(fg(fmt::color::gray)), "{}\n", std::string(width, ch));
 }
 
 void logger::print_emphasis(const std::string &message) {
   if (s_verbosity == log_verbosity::VERBOSITY_QUIET) {
     return;
   }
-  fmt::print(fmt::emphasis::bold, "{}\n", message);
+  if (s_format == output_format::JSON) {
+    print_json_event("message", {{"message", json_quote(message)}});
+    return;
+  }
+  fmt::print(style(fmt::emphasis::bold), "{}\n", message);
 }
 
 void logger::print_note(const std::string &message) {
   if (s_verbosity == log_verbosity::VERBOSITY_QUIET) {
     return;
   }
-  fmt::print(fg(fmt::color::steel_blue) | fmt::emphasis::bold, "{:>{}}", "note", STATUS_WIDTH);
+  if (s_format == output_format::JSON) {
+    print_json_event("note", {{"message", json_quote(message)}});
+    return;
+  }
+  fmt::print(
+      style(fg(fmt::color::steel_blue) | fmt::emphasis::bold), "{:>{}}", "note", STATUS_WIDTH);
   fmt::print(" {}\n", message);
 }
 
@@ -585,8 +845,14 @@ void logger::print_hint(const std::string &message) {
   if (s_verbosity == log_verbosity::VERBOSITY_QUIET) {
     return;
   }
-  fmt::print(
-      fg(fmt::color::medium_sea_green) | fmt::emphasis::bold, "{:>{}}", "hint", STATUS_WIDTH);
+  if (s_format == output_format::JSON) {
+    print_json_event("hint", {{"message", json_quote(message)}});
+    return;
+  }
+  fmt::print(style(fg(fmt::color::medium_sea_green) | fmt::emphasis::bold),
+             "{:>{}}",
+             "hint",
+             STATUS_WIDTH);
   fmt::print(" {}\n", message);
 }
 
@@ -624,16 +890,19 @@ void logger::print_table_header(const std::vector<std::string> &columns,
   cforge_int_t total_width = 0;
   for (size_t i = 0; i < columns.size(); ++i) {
     cforge_int_t width = (i < widths.size()) ? widths[i] : 12;
-    fmt::print(fmt::emphasis::bold, "{:<{}} ", columns[i], width);
+    fmt::print(style(fmt::emphasis::bold), "{:<{}} ", columns[i], width);
     total_width += width + 1;
   }
   fmt::print("\n");
   // Print separator
   fmt::print("{:{}}", "", indent);
-  fmt::print(fg(fmt::color::gray), "{}\n", std::string(total_width, '-'));
+  fmt::print(style(fg(fmt::color::gray)), "{}\n", std::string(total_width, '-'));
 }
 
 void logger::print_blank() {
+  if (s_format == output_format::JSON) {
+    return;
+  }
   fmt::print("\n");
 }
 
@@ -645,8 +914,8 @@ void logger::print_cmd_header(const std::string &cmd, const std::string &descrip
   }
   // Clean header without separators, similar to cargo/git
   fmt::print("\n");
-  fmt::print(fg(fmt::color::lime_green) | fmt::emphasis::bold, "cforge {}", cmd);
-  fmt::print(fg(fmt::color::white), " - ");
+  fmt::print(style(fg(fmt::color::lime_green) | fmt::emphasis::bold), "cforge {}", cmd);
+  fmt::print(style(fg(fmt::color::white)), " - ");
   fmt::print("{}\n\n", description);
 }
 
@@ -654,8 +923,8 @@ void logger::print_usage(const std::string &usage) {
   if (s_verbosity == log_verbosity::VERBOSITY_QUIET) {
     return;
   }
-  fmt::print(fg(fmt::color::cyan) | fmt::emphasis::bold, "USAGE:\n");
-  fmt::print(fg(fmt::color::white), "    {}\n\n", usage);
+  fmt::print(style(fg(fmt::color::cyan) | fmt::emphasis::bold), "USAGE:\n");
+  fmt::print(style(fg(fmt::color::white)), "    {}\n\n", usage);
 }
 
 void logger::print_option(const std::string &flags,
@@ -665,8 +934,8 @@ void logger::print_option(const std::string &flags,
     return;
   }
   fmt::print("    ");
-  fmt::print(fg(fmt::color::lime_green), "{:<{}}", flags, flag_width);
-  fmt::print(fg(fmt::color::light_gray), "{}\n", description);
+  fmt::print(style(fg(fmt::color::lime_green)), "{:<{}}", flags, flag_width);
+  fmt::print(style(fg(fmt::color::light_gray)), "{}\n", description);
 }
 
 void logger::print_arg(const std::string &name, const std::string &description, cforge_int_t name_width) {
@@ -674,8 +943,8 @@ void logger::print_arg(const std::string &name, const std::string &description,
     return;
   }
   fmt::print("    ");
-  fmt::print(fg(fmt::color::lime_green) | fmt::emphasis::bold, "{:<{}}", name, name_width);
-  fmt::print(fg(fmt::color::light_gray), "{}\n", description);
+  fmt::print(style(fg(fmt::color::lime_green) | fmt::emphasis::bold), "{:<{}}", name, name_width);
+  fmt::print(style(fg(fmt::color::light_gray)), "{}\n", description);
 }
 
 void logger::print_example(const std::string &example, const std::string &description) {
@@ -683,10 +952,10 @@ void logger::print_example(const std::string &example, const std::string &descri
     return;
   }
   fmt::print("    ");
-  fmt::print(fg(fmt::color::steel_blue), "$ ");
-  fmt::print(fg(fmt::color::white), "{}", example);
+  fmt::print(style(fg(fmt::color::steel_blue)), "$ ");
+  fmt::print(style(fg(fmt::color::white)), "{}", example);
   if (!description.empty()) {
-    fmt::print(fg(fmt::color::gray), "  # {}", description);
+    fmt::print(style(fg(fmt::color::gray)), "  # {}", description);
   }
   fmt::print("\n");
 }
@@ -698,26 +967,26 @@ void logger::print_subcommand(const std::string &name,
     return;
   }
   fmt::print("    ");
-  fmt::print(fg(fmt::color::lime_green) | fmt::emphasis::bold, "{:<{}}", name, name_width);
-  fmt::print(fg(fmt::color::light_gray), "{}\n", description);
+  fmt::print(style(fg(fmt::color::lime_green) | fmt::emphasis::bold), "{:<{}}", name, name_width);
+  fmt::print(style(fg(fmt::color::light_gray)), "{}\n", description);
 }
 
 void logger::print_help_section(const std::string &title) {
   if (s_verbosity == log_verbosity::VERBOSITY_QUIET) {
     return;
   }
-  fmt::print(fg(fmt::color::cyan) | fmt::emphasis::bold, "{}\n", title);
+  fmt::print(style(fg(fmt::color::cyan) | fmt::emphasis::bold), "{}\n", title);
 }
 
 void logger::print_config_block(const std::vector<std::string> &lines) {
   if (s_verbosity == log_verbosity::VERBOSITY_QUIET) {
     return;
   }
-  fmt::print(fg(fmt::color::steel_blue), "    {}\n", std::string(40, '-'));
+  fmt::print(style(fg(fmt::color::steel_blue)), "    {}\n", std::string(40, '-'));
   for (const auto &line : lines) {
-    fmt::print(fg(fmt::color::light_slate_gray), "    {}\n", line);
+    fmt::print(style(fg(fmt::color::light_slate_gray)), "    {}\n", line);
   }
-  fmt::print(fg(fmt::color::steel_blue), "    {}\n", std::string(40, '-'));
+  fmt::print(style(fg(fmt::color::steel_blue)), "    {}\n", std::string(40, '-'));
 }
 
 void logger::print_help_footer(const std::string &message) {
@@ -725,25 +994,25 @@ void logger::print_help_footer(const std::string &message) {
     return;
   }
   fmt::print("\n");
-  fmt::print(fg(fmt::color::gray), "{}\n", message);
+  fmt::print(style(fg(fmt::color::gray)), "{}\n", message);
 }
 
 // Error/Diagnostic formatting utilities
 
 void logger::print_error_header(const std::string &code, const std::string &message) {
-  fmt::print(stderr, fg(fmt::color::red) | fmt::emphasis::bold, "error");
+  fmt::print(stderr, style(fg(fmt::color::red) | fmt::emphasis::bold, stderr), "error");
   if (!code.empty()) {
-    fmt::print(stderr, fg(fmt::color::red) | fmt::emphasis::bold, "[{}]", code);
+    fmt::print(stderr, style(fg(fmt::color::red) | fmt::emphasis::bold, stderr), "[{}]", code);
   }
-  fmt::print(stderr, fg(fmt::color::white) | fmt::emphasis::bold, ": {}\n", message);
+  fmt::print(stderr, style(fg(fmt::color::white) | fmt::emphasis::bold, stderr), ": {}\n", message);
 }
 
 void logger::print_warning_header(const std::string &code, const std::string &message) {
-  fmt::print(stderr, fg(fmt::color::yellow) | fmt::emphasis::bold, "warning");
+  fmt::print(stderr, style(fg(fmt::color::yellow) | fmt::emphasis::bold, stderr), "warning");
   if (!code.empty()) {
-    fmt::print(stderr, fg(fmt::color::yellow) | fmt::emphasis::bold, "[{}]", code);
+    fmt::print(stderr, style(fg(fmt::color::yellow) | fmt::emphasis::bold, stderr), "[{}]", code);
   }
-  fmt::print(stderr, fg(fmt::color::white) | fmt::emphasis::bold, ": {}\n", message);
+  fmt::print(stderr, style(fg(fmt::color::white) | fmt::emphasis::bold, stderr), ": {}\n", message);
 }
 
 void logger::print_location(const std::string &file_path, cforge_int_t line, int column) {
@@ -754,7 +1023,7 @@ void logger::print_location(const std::string &file_path, cforge_int_t line, int
                  + display_path.substr(display_path.length() - 32);
   }
 
-  fmt::print(stderr, fg(fmt::color::steel_blue), "   --> ");
+  fmt::print(stderr, style(fg(fmt::color::steel_blue), stderr), "   --> ");
   fmt::print(stderr, "{}", display_path);
   if (line > 0) {
     fmt::print(stderr, ":{}", line);
@@ -767,20 +1036,21 @@ void logger::print_location(const std::string &file_path, cforge_int_t line, int
 
 void logger::print_code_line(cforge_int_t line_number, const std::string &content, int gutter_width) {
   if (line_number > 0) {
-    fmt::print(stderr, fg(fmt::color::steel_blue), "{:>{}} | ", line_number, gutter_width);
+    fmt::print(
+        stderr, style(fg(fmt::color::steel_blue), stderr), "{:>{}} | ", line_number, gutter_width);
   } else {
-    fmt::print(stderr, fg(fmt::color::steel_blue), "{:>{}} | ", "", gutter_width);
+    fmt::print(stderr, style(fg(fmt::color::steel_blue), stderr), "{:>{}} | ", "", gutter_width);
   }
   fmt::print(stderr, "{}\n", content);
 }
 
 void logger::print_error_pointer(cforge_int_t column_start, int length, int gutter_width) {
-  fmt::print(stderr, fg(fmt::color::steel_blue), "{:>{}} | ", "", gutter_width);
+  fmt::print(stderr, style(fg(fmt::color::steel_blue), stderr), "{:>{}} | ", "", gutter_width);
   if (column_start > 0) {
     fmt::print(stderr, "{:>{}}", "", column_start - 1);
   }
   fmt::print(stderr,
-             fg(fmt::color::red) | fmt::emphasis::bold,
+             style(fg(fmt::color::red) | fmt::emphasis::bold, stderr),
              "{}\n",
              std::string(length > 0 ? length : 1, '^'));
 }
@@ -788,38 +1058,38 @@ void logger::print_error_pointer(cforge_int_t column_start, int length, int gutt
 void logger::print_diag_note(const std::string &message) {
   // Cargo aligns auxiliary lines under the gutter so they visually connect to
   // the `   |` of the code snippet:  "   = note: <msg>"
-  fmt::print(stderr, fg(fmt::color::steel_blue), "   = ");
-  fmt::print(stderr, fg(fmt::color::cyan) | fmt::emphasis::bold, "note");
-  fmt::print(stderr, fg(fmt::color::light_gray), ": {}\n", message);
+  fmt::print(stderr, style(fg(fmt::color::steel_blue), stderr), "   = ");
+  fmt::print(stderr, style(fg(fmt::color::cyan) | fmt::emphasis::bold, stderr), "note");
+  fmt::print(stderr, style(fg(fmt::color::light_gray), stderr), ": {}\n", message);
 }
 
 void logger::print_diag_help(const std::string &message) {
-  fmt::print(stderr, fg(fmt::color::steel_blue), "   = ");
-  fmt::print(stderr, fg(fmt::color::medium_sea_green) | fmt::emphasis::bold, "help");
-  fmt::print(stderr, fg(fmt::color::light_gray), ": {}\n", message);
+  fmt::print(stderr, style(fg(fmt::color::steel_blue), stderr), "   = ");
+  fmt::print(stderr, style(fg(fmt::color::medium_sea_green) | fmt::emphasis::bold, stderr), "help");
+  fmt::print(stderr, style(fg(fmt::color::light_gray), stderr), ": {}\n", message);
 }
 
 void logger::print_diag_fix(const std::string &description, const std::string &replacement) {
-  fmt::print(stderr, fg(fmt::color::steel_blue), "   = ");
-  fmt::print(stderr, fg(fmt::color::magenta) | fmt::emphasis::bold, "fix");
-  fmt::print(stderr, fg(fmt::color::light_gray), ": {}", description);
+  fmt::print(stderr, style(fg(fmt::color::steel_blue), stderr), "   = ");
+  fmt::print(stderr, style(fg(fmt::color::magenta) | fmt::emphasis::bold, stderr), "fix");
+  fmt::print(stderr, style(fg(fmt::color::light_gray), stderr), ": {}", description);
   // Be generous with the inline-replacement length cap — 40 chars cuts off a
   // lot of useful suggestions like `[[maybe_unused]] explicit_destructor`.
   if (!replacement.empty() && replacement.length() < 80) {
-    fmt::print(stderr, fg(fmt::color::gray), " -> ");
-    fmt::print(stderr, fg(fmt::color::lime_green), "`{}`", replacement);
+    fmt::print(stderr, style(fg(fmt::color::gray), stderr), " -> ");
+    fmt::print(stderr, style(fg(fmt::color::lime_green), stderr), "`{}`", replacement);
   }
   fmt::print(stderr, "\n");
 }
 
 void logger::print_error_count(cforge_int_t count, const std::string &type, bool is_error) {
-  fmt::print(stderr, fg(fmt::color::steel_blue), "   |  ");
+  fmt::print(stderr, style(fg(fmt::color::steel_blue), stderr), "   |  ");
   fmt::color color = is_error ? fmt::color::red : fmt::color::yellow;
-  fmt::print(stderr, fg(color), "{} {}{}\n", count, type, count == 1 ? "" : "s");
+  fmt::print(stderr, style(fg(color), stderr), "{} {}{}\n", count, type, count == 1 ? "" : "s");
 }
 
 void logger::print_gutter_line() {
-  fmt::print(stderr, fg(fmt::color::steel_blue), "   |\n");
+  fmt::print(stderr, style(fg(fmt::color::steel_blue), stderr), "   |\n");
 }
 
 }  // namespace cforge
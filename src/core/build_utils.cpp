@@ -5,8 +5,31 @@
 
 #include "core/build_utils.hpp"
 
+#include "core/build_info.hpp"
+#include "core/build_variants.hpp"
+#include "core/cmake_file_api.hpp"
+#include "core/command.h"
+#include "core/conan.hpp"
+#include "core/config_interpolation.hpp"
 #include "core/constants.h"
+#include "core/dependency_hash.hpp"
+#include "core/errors.hpp"
+#include "core/git_utils.hpp"
+#include "core/manifest_schema.hpp"
+#include "core/registry.hpp"
 #include "core/types.h"
+#include "core/vcpkg_manifest.hpp"
+#include "core/wasm_run.hpp"
+#include "core/workspace.hpp"
+
+#include <algorithm>
+#include <chrono>
+#include <cstdlib>
+#include <ctime>
+#include <functional>
+#include <iomanip>
+#include <iterator>
+#include <sstream>
 
 namespace cforge {
 
@@ -76,6 +99,286 @@ std::string get_cmake_generator() {
   return g_cached_generator;
 }
 
+#ifdef _WIN32
+/**
+ * @brief Find the vcvarsall.bat/VsDevCmd.bat of the newest C++-capable install
+ *
+ * @param host_arch Normalized host architecture
+ * @param arch Normalized target architecture
+ * @param args Receives the script arguments
+ * @return Script path, empty if no install was found
+ */
+static std::filesystem::path find_msvc_environment_script(const std::string &host_arch,
+                                                          const std::string &arch,
+                                                          std::vector<std::string> &args) {
+  cforge_cstring_t program_files = std::getenv("ProgramFiles(x86)");
+  std::filesystem::path vswhere =
+      std::filesystem::path(program_files ? program_files : "C:\\Program Files (x86)")
+      / "Microsoft Visual Studio" / "Installer" / "vswhere.exe";
+  if (!std::filesystem::exists(vswhere)) {
+    logger::print_verbose("vswhere.exe not found at " + vswhere.string());
+    return {};
+  }
+
+  process_result pr = execute_process(vswhere.string(),
+                                      {"-latest",
+                                       "-products",
+                                       "*",
+                                       "-requires",
+                                       "Microsoft.VisualStudio.Component.VC.Tools.x86.x64",
+                                       "-property",
+                                       "installationPath"},
+                                      "",
+                                      nullptr,
+                                      nullptr,
+                                      30);
+  std::string install = pr.stdout_output;
+  install.erase(install.find_last_not_of(" \r\n\t") + 1);
+  if (!pr.success || install.empty()) {
+    logger::print_verbose("vswhere found no Visual Studio install with the C++ toolset");
+    return {};
+  }
+
+  std::filesystem::path vcvarsall =
+      std::filesystem::path(install) / "VC" / "Auxiliary" / "Build" / "vcvarsall.bat";
+  if (std::filesystem::exists(vcvarsall)) {
+    args = {vcvars_arch_argument(host_arch, arch)};
+    return vcvarsall;
+  }
+  std::filesystem::path vsdevcmd =
+      std::filesystem::path(install) / "Common7" / "Tools" / "VsDevCmd.bat";
+  if (std::filesystem::exists(vsdevcmd)) {
+    args = {"-arch=" + arch, "-host_arch=" + host_arch, "-no_logo"};
+    return vsdevcmd;
+  }
+  return {};
+}
+#endif
+
+bool setup_msvc_environment(const std::string &arch, bool verbose) {
+#ifdef _WIN32
+  static bool s_msvc_environment_ready = false;
+  if (s_msvc_environment_ready) {
+    return true;
+  }
+  if (is_command_available("cl", 5)) {
+    s_msvc_environment_ready = true;
+    return true;
+  }
+
+  cforge_cstring_t host_env = std::getenv("PROCESSOR_ARCHITECTURE");
+  std::string host_arch     = normalize_msvc_arch(host_env ? host_env : "");
+  if (host_arch.empty()) {
+    host_arch = "x64";
+  }
+  std::string target_arch = arch.empty() ? "x64" : arch;
+
+  std::vector<std::string> script_args;
+  std::filesystem::path script = find_msvc_environment_script(host_arch, target_arch, script_args);
+  if (script.empty()) {
+    return false;
+  }
+
+  // Reuse the captured environment while the script and PATH are unchanged
+  std::error_code ec;
+  auto script_time      = std::filesystem::last_write_time(script, ec).time_since_epoch().count();
+  cforge_cstring_t path = std::getenv("PATH");
+  std::string cache_key = script.string() + "|" + script_args.front() + "|"
+                        + std::to_string(script_time) + "|"
+                        + std::to_string(std::hash<std::string>{}(path ? path : ""));
+  cforge_cstring_t local_app_data = std::getenv("LOCALAPPDATA");
+  std::filesystem::path cache_file =
+      std::filesystem::path(local_app_data ? local_app_data : ".") / "cforge"
+      / ("msvc-env-" + target_arch + ".txt");
+
+  std::map<std::string, std::string> env;
+  std::ifstream cached(cache_file);
+  std::string first_line;
+  if (cached && std::getline(cached, first_line) && first_line == "# " + cache_key) {
+    std::string rest((std::istreambuf_iterator<char>(cached)), std::istreambuf_iterator<char>());
+    env = parse_environment_listing(rest);
+    logger::print_verbose("Using cached MSVC environment from " + cache_file.string());
+  }
+  cached.close();
+
+  if (env.empty()) {
+    logger::print_action("Loading", "MSVC environment (" + script.filename().string() + " "
+                                        + script_args.front() + ")");
+    std::vector<std::string> args = {"/d", "/c", "call", script.string()};
+    args.insert(args.end(), script_args.begin(), script_args.end());
+    args.insert(args.end(), {">nul", "&&", "set"});
+    process_result pr = execute_process("cmd", args, "", nullptr, nullptr, 120);
+    if (!pr.success) {
+      logger::print_warning("Failed to run " + script.string());
+      if (verbose && !pr.stderr_output.empty()) {
+        logger::print_verbose(pr.stderr_output);
+      }
+      return false;
+    }
+    env = parse_environment_listing(pr.stdout_output);
+
+    std::filesystem::create_directories(cache_file.parent_path(), ec);
+    std::ofstream out(cache_file);
+    if (out) {
+      out << "# " << cache_key << "\n";
+      for (const auto &[name, value] : env) {
+        out << name << "=" << value << "\n";
+      }
+    }
+  }
+
+  cforge_int_t changed = 0;
+  for (const auto &[name, value] : env) {
+    cforge_cstring_t current = std::getenv(name.c_str());
+    if (!current || value != current) {
+      _putenv_s(name.c_str(), value.c_str());
+      changed++;
+    }
+  }
+  logger::print_verbose("Exported " + std::to_string(changed) + " MSVC environment variables");
+
+  s_msvc_environment_ready = is_command_available("cl", 5);
+  if (!s_msvc_environment_ready) {
+    logger::print_warning("cl.exe is still not on PATH after running " + script.string());
+  }
+  return s_msvc_environment_ready;
+#else
+  (void)arch;
+  (void)verbose;
+  return true;
+#endif
+}
+
+bool prepare_build_dir_for_generator(const std::filesystem::path &build_dir,
+                                     const std::string &generator,
+                                     bool auto_clean) {
+  std::string cached = get_cached_cmake_generator(build_dir);
+  if (cached.empty() || cached == generator) {
+    return true;
+  }
+
+  if (!auto_clean) {
+    cforge_error::make(error_code::BUILD_CMAKE_CONFIG_FAILED,
+                       "build directory was configured with generator '" + cached
+                           + "', but '" + generator + "' was requested",
+                       "--> " + (build_dir / "CMakeCache.txt").string())
+        .with_help("run 'cforge clean' to remove the old build directory")
+        .with_help("or set cmake.generator back to \"" + cached + "\" in cforge.toml")
+        .print();
+    return false;
+  }
+
+  // CMakeCache.txt and CMakeFiles/ are what tie the directory to a generator;
+  // outputs written by cforge itself (build info header, timestamps) survive
+  logger::print_warning("generator changed from '" + cached + "' to '" + generator
+                        + "'; clearing the CMake cache in " + build_dir.string());
+  std::error_code ec;
+  std::filesystem::remove(build_dir / "CMakeCache.txt", ec);
+  if (!ec) {
+    std::filesystem::remove_all(build_dir / "CMakeFiles", ec);
+  }
+  if (ec) {
+    logger::print_error("failed to clear the CMake cache: " + ec.message());
+    return false;
+  }
+  return true;
+}
+
+/**
+ * @brief Run a compiler and identify it from its output
+ */
+static compiler_identity run_compiler_identity(const std::string &command) {
+  // cl prints its banner, with the version, when run without arguments
+  std::string name = string_to_lower(std::filesystem::path(command).stem().string());
+  std::vector<std::string> args;
+  if (name != "cl") {
+    args.push_back("--version");
+  }
+  process_result pr = execute_process(command, args, "", nullptr, nullptr, 30);
+  compiler_identity identity = parse_compiler_identity(pr.stdout_output + pr.stderr_output);
+  identity.path              = command;
+  return identity;
+}
+
+compiler_identity detect_project_compiler(const toml_reader &project_config,
+                                          const std::filesystem::path &build_dir,
+                                          const std::string &generator) {
+  std::string command = project_config.get_string("cmake.cxx_compiler", "");
+  if (command.empty() && generator.find("Ninja") != std::string::npos) {
+    command = project_config.get_string("cmake.toolset", "");
+  }
+  if (command.empty() && generator.rfind("Visual Studio", 0) == 0) {
+    bool clang_cl = project_config.get_string("cmake.toolset", "") == "ClangCL";
+    command       = clang_cl ? "clang-cl" : "cl";
+  }
+  if (command.empty()) {
+    cforge_cstring_t cxx = std::getenv("CXX");
+    command              = cxx != nullptr ? cxx : "";
+  }
+  if (command.empty()) {
+    std::ifstream cache(build_dir / "CMakeCache.txt");
+    std::string line;
+    while (std::getline(cache, line)) {
+      if (line.rfind("CMAKE_CXX_COMPILER:", 0) == 0) {
+        command = line.substr(line.find('=') + 1);
+        break;
+      }
+    }
+  }
+  if (!command.empty()) {
+    return run_compiler_identity(command);
+  }
+
+#ifdef _WIN32
+  std::vector<std::string> candidates = {"cl", "c++", "g++", "clang++"};
+#else
+  std::vector<std::string> candidates = {"c++", "g++", "clang++"};
+#endif
+  for (const auto &candidate : candidates) {
+    compiler_identity identity = run_compiler_identity(candidate);
+    if (!identity.family.empty()) {
+      return identity;
+    }
+  }
+  return {};
+}
+
+bool record_build_compiler(const std::filesystem::path &build_dir,
+                           const compiler_identity &compiler) {
+  std::filesystem::path stamp = build_dir / ".cforge-compiler";
+  std::string recorded;
+  {
+    std::ifstream in(stamp, std::ios::binary);
+    std::stringstream contents;
+    contents << in.rdbuf();
+    recorded = contents.str();
+  }
+  // The path alone may change from a bare name to the full path CMake caches
+  compiler_identity previous = parse_compiler_stamp(recorded);
+  if (previous.family == compiler.family && previous.version == compiler.version) {
+    return true;
+  }
+
+  std::error_code ec;
+  if (!recorded.empty() && std::filesystem::exists(build_dir / "CMakeCache.txt", ec)) {
+    logger::print_warning("compiler changed from " + previous.label() + " to " + compiler.label()
+                          + "; clearing the CMake cache in " + build_dir.string());
+    std::filesystem::remove(build_dir / "CMakeCache.txt", ec);
+    if (!ec) {
+      std::filesystem::remove_all(build_dir / "CMakeFiles", ec);
+    }
+    if (ec) {
+      logger::print_error("failed to clear the CMake cache: " + ec.message());
+      return false;
+    }
+  }
+
+  std::filesystem::create_directories(build_dir, ec);
+  std::ofstream out(stamp, std::ios::binary);
+  out << compiler_stamp_text(compiler);
+  return true;
+}
+
 std::filesystem::path get_build_dir_for_config(const std::string &base_dir,
                                                const std::string &config,
                                                bool create_if_missing) {
@@ -99,6 +402,543 @@ std::filesystem::path get_build_dir_for_config(const std::string &base_dir,
   return build_path;
 }
 
+// Process-wide build directory override from --build-dir
+static std::string g_build_dir_override;
+
+void set_build_dir_override(const std::string &dir) {
+  g_build_dir_override = dir;
+}
+
+std::string resolve_vcpkg_triplet(const toml_reader &project_config,
+                                  const std::string &target_system,
+                                  const std::string &target_processor,
+                                  const std::string &config) {
+  std::string triplet;
+  if (!config.empty()) {
+    std::string config_key = "build.config." + string_to_lower(config);
+    triplet                = project_config.get_string(config_key + ".vcpkg_triplet", "");
+  }
+  if (triplet.empty()) {
+    triplet = project_config.get_string("dependencies.vcpkg.triplet", "");
+  }
+  if (!triplet.empty()) {
+    return triplet;
+  }
+  return default_vcpkg_triplet(target_system, target_processor);
+}
+
+std::vector<std::string> get_vcpkg_triplets(const toml_reader &project_config) {
+  std::vector<std::string> triplets;
+  std::string triplet = resolve_vcpkg_triplet(project_config);
+  if (!triplet.empty()) {
+    triplets.push_back(triplet);
+  }
+  for (const auto &config : project_config.get_table_keys("build.config")) {
+    triplet = project_config.get_string("build.config." + config + ".vcpkg_triplet", "");
+    if (!triplet.empty()
+        && std::find(triplets.begin(), triplets.end(), triplet) == triplets.end()) {
+      triplets.push_back(triplet);
+    }
+  }
+  return triplets;
+}
+
+std::vector<std::string> get_vcpkg_cmake_args(const toml_reader &project_config,
+                                              const std::filesystem::path &source_dir,
+                                              const std::string &target_system,
+                                              const std::string &target_processor,
+                                              const std::string &config) {
+  std::vector<std::string> args;
+  if (!project_config.has_key("dependencies.vcpkg")) {
+    return args;
+  }
+
+  std::string vcpkg_root = get_vcpkg_root(project_config, source_dir);
+
+  // Compute toolchain file path
+  std::string toolchain_path = vcpkg_root + "/scripts/buildsystems/vcpkg.cmake";
+  std::replace(toolchain_path.begin(), toolchain_path.end(), '\\', '/');
+  if (std::filesystem::exists(toolchain_path)) {
+    args.push_back("-DCMAKE_TOOLCHAIN_FILE=" + toolchain_path);
+    logger::print_verbose("Using vcpkg toolchain: " + toolchain_path);
+  } else {
+    logger::print_warning("vcpkg toolchain file not found: " + toolchain_path);
+  }
+
+  // Ports built during configure are cached for later clean builds
+  apply_vcpkg_binary_cache(project_config, source_dir);
+
+  // Install and find packages for the configured or target-derived triplet
+  std::string triplet =
+      resolve_vcpkg_triplet(project_config, target_system, target_processor, config);
+  if (!triplet.empty()) {
+    args.push_back("-DVCPKG_TARGET_TRIPLET=" + triplet);
+    logger::print_verbose("Using vcpkg triplet: " + triplet);
+  }
+
+  // With a vcpkg.json manifest the toolchain installs packages at configure
+  // time; the value is cached, so online builds turn it back on
+  args.push_back(std::string("-DVCPKG_MANIFEST_INSTALL=") + (cforge_is_offline() ? "OFF" : "ON"));
+
+  // A vcpkg.json left behind by manifest = true must not switch a project
+  // that went back to classic mode into manifest mode
+  if (is_vcpkg_manifest_mode(project_config)) {
+    args.push_back("-DVCPKG_MANIFEST_MODE=ON");
+  } else if (std::filesystem::exists(source_dir / "vcpkg.json")) {
+    std::ifstream file(source_dir / "vcpkg.json", std::ios::binary);
+    std::stringstream contents;
+    contents << file.rdbuf();
+    args.push_back(std::string("-DVCPKG_MANIFEST_MODE=")
+                   + (is_cforge_generated_vcpkg_manifest(contents.str()) ? "OFF" : "ON"));
+  }
+  return args;
+}
+
+bool is_vcpkg_manifest_mode(const toml_reader &project_config) {
+  return project_config.get_bool("dependencies.vcpkg.manifest", false);
+}
+
+bool write_vcpkg_manifest(const std::filesystem::path &project_dir,
+                          const toml_reader &project_config) {
+  std::vector<vcpkg_manifest_dependency> deps;
+  for (const auto &pkg : project_config.get_string_array("dependencies.vcpkg.packages")) {
+    add_vcpkg_manifest_dependency(deps, parse_vcpkg_manifest_spec(pkg));
+  }
+  for (const auto &key : project_config.get_table_keys("dependencies.vcpkg")) {
+    if (is_vcpkg_setting_key(key)) {
+      continue;
+    }
+    std::string table = "dependencies.vcpkg." + key;
+    vcpkg_manifest_dependency dep;
+    dep.name             = string_to_lower(key);
+    dep.features         = project_config.get_string_array(table + ".features");
+    dep.version_min      = vcpkg_minimum_version(project_config.get_string(table + ".version", ""));
+    dep.default_features = project_config.get_bool(table + ".default_features", true);
+    add_vcpkg_manifest_dependency(deps, dep);
+  }
+  for (const auto &spec : parse_dependencies(project_dir / CFORGE_FILE)) {
+    if (spec.source != dependency_source::VCPKG) {
+      continue;
+    }
+    vcpkg_manifest_dependency dep =
+        parse_vcpkg_manifest_spec(spec.vcpkg_name.empty() ? spec.name : spec.vcpkg_name);
+    dep.features.insert(dep.features.end(), spec.features.begin(), spec.features.end());
+    dep.version_min      = vcpkg_minimum_version(spec.version);
+    dep.default_features = spec.default_features;
+    add_vcpkg_manifest_dependency(deps, dep);
+  }
+
+  // Keep the baseline recorded in an earlier manifest, so the port versions
+  // only change when vcpkg.json does
+  std::filesystem::path manifest_path = project_dir / "vcpkg.json";
+  std::string existing;
+  if (std::filesystem::exists(manifest_path)) {
+    std::ifstream file(manifest_path, std::ios::binary);
+    std::stringstream contents;
+    contents << file.rdbuf();
+    existing = contents.str();
+    if (!is_cforge_generated_vcpkg_manifest(existing)) {
+      logger::print_warning("vcpkg.json was not generated by cforge; using it as is");
+      return true;
+    }
+  }
+  std::string baseline = vcpkg_manifest_baseline(existing);
+  if (baseline.empty()) {
+    baseline = git_get_head_commit(get_vcpkg_root(project_config, project_dir));
+    if (baseline.empty()) {
+      logger::print_warning("could not read the vcpkg checkout's commit; vcpkg.json has no "
+                            "builtin-baseline");
+    }
+  }
+
+  std::string contents = generate_vcpkg_manifest_json(deps, baseline);
+  if (contents == existing) {
+    return true;
+  }
+  std::ofstream out(manifest_path, std::ios::binary);
+  if (!out || !(out << contents)) {
+    logger::print_error("Failed to write " + manifest_path.string());
+    return false;
+  }
+  logger::print_action("Generated", manifest_path.string());
+  return true;
+}
+
+/**
+ * @brief VCPKG_BINARY_SOURCES as the user set it, before cforge added to it
+ */
+static const std::string &user_vcpkg_binary_sources() {
+  static const std::string sources = [] {
+    cforge_cstring_t value = std::getenv("VCPKG_BINARY_SOURCES");
+    return value ? std::string(value) : std::string();
+  }();
+  return sources;
+}
+
+std::filesystem::path get_vcpkg_binary_cache_dir(const toml_reader &project_config,
+                                                 const std::filesystem::path &project_dir) {
+  if (!project_config.has_key("dependencies.vcpkg")
+      || !project_config.get_bool("dependencies.vcpkg.binary_cache", true)) {
+    return {};
+  }
+  std::string configured = project_config.get_string("dependencies.vcpkg.binary_cache", "");
+  if (!configured.empty()) {
+    std::filesystem::path dir(configured);
+    return dir.is_absolute() ? dir : project_dir / dir;
+  }
+  if (!user_vcpkg_binary_sources().empty() || std::getenv("VCPKG_DEFAULT_BINARY_CACHE")) {
+    return {};
+  }
+
+#ifdef _WIN32
+  cforge_cstring_t cache_home = std::getenv("LOCALAPPDATA");
+  if (!cache_home) {
+    cforge_cstring_t userprofile = std::getenv("USERPROFILE");
+    return userprofile ? std::filesystem::path(userprofile) / ".cforge" / "vcpkg-archives"
+                       : std::filesystem::path();
+  }
+  return std::filesystem::path(cache_home) / "cforge" / "vcpkg-archives";
+#else
+  cforge_cstring_t cache_home = std::getenv("XDG_CACHE_HOME");
+  if (cache_home && *cache_home) {
+    return std::filesystem::path(cache_home) / "cforge" / "vcpkg-archives";
+  }
+  cforge_cstring_t home = std::getenv("HOME");
+  return home ? std::filesystem::path(home) / ".cache" / "cforge" / "vcpkg-archives"
+              : std::filesystem::path();
+#endif
+}
+
+void apply_vcpkg_binary_cache(const toml_reader &project_config,
+                              const std::filesystem::path &project_dir) {
+  // Each project starts from the user's sources, so a workspace project
+  // without a cache doesn't use the one set for the project before it
+  std::string sources       = user_vcpkg_binary_sources();
+  std::filesystem::path dir = get_vcpkg_binary_cache_dir(project_config, project_dir);
+  if (!dir.empty()) {
+    std::error_code ec;
+    std::filesystem::create_directories(dir, ec);
+    if (ec) {
+      logger::print_warning("could not create the vcpkg binary cache " + dir.string() + ": "
+                            + ec.message());
+    } else {
+      sources += (sources.empty() ? "" : ";") + vcpkg_files_binary_source(dir.string());
+      logger::print_verbose("vcpkg binary cache: " + dir.string());
+    }
+  }
+
+#ifdef _WIN32
+  _putenv_s("VCPKG_BINARY_SOURCES", sources.c_str());
+#else
+  if (sources.empty()) {
+    unsetenv("VCPKG_BINARY_SOURCES");
+  } else {
+    setenv("VCPKG_BINARY_SOURCES", sources.c_str(), 1);
+  }
+#endif
+}
+
+std::string get_vcpkg_root(const toml_reader &project_config,
+                           const std::filesystem::path &source_dir) {
+  if (project_config.has_key("dependencies.vcpkg.path")) {
+    return project_config.get_string("dependencies.vcpkg.path", "");
+  }
+  if (cforge_cstring_t env = std::getenv("VCPKG_ROOT")) {
+    return env;
+  }
+  return (source_dir / "vcpkg").string();
+}
+
+std::vector<std::string> get_vcpkg_packages(const std::filesystem::path &project_dir,
+                                            const toml_reader &project_config) {
+  std::vector<std::string> packages;
+  if (!project_config.has_key("dependencies.vcpkg")) {
+    return packages;
+  }
+
+  // Port names only: drop ":version"/":triplet" suffixes and [features]
+  auto add = [&](std::string name) {
+    name = string_to_lower(name.substr(0, name.find_first_of(":[@")));
+    if (!name.empty() && std::find(packages.begin(), packages.end(), name) == packages.end()) {
+      packages.push_back(name);
+    }
+  };
+
+  for (const auto &pkg : project_config.get_string_array("dependencies.vcpkg.packages")) {
+    add(pkg);
+  }
+  for (const auto &key : project_config.get_table_keys("dependencies.vcpkg")) {
+    if (!is_vcpkg_setting_key(key)) {
+      add(key);
+    }
+  }
+  for (const auto &spec : parse_dependencies(project_dir / CFORGE_FILE)) {
+    if (spec.source == dependency_source::VCPKG) {
+      add(spec.vcpkg_name.empty() ? spec.name : spec.vcpkg_name);
+    }
+  }
+  return packages;
+}
+
+std::vector<fetchcontent_dependency> get_fetchcontent_dependencies(
+    const toml_reader &project_config) {
+  std::vector<fetchcontent_dependency> deps;
+  for (const auto &name : project_config.get_table_keys("dependencies.fetchcontent")) {
+    std::string prefix = "dependencies.fetchcontent." + name;
+    fetchcontent_dependency dep;
+    dep.name        = name;
+    dep.git         = project_config.get_string(prefix + ".git", "");
+    dep.tag         = project_config.get_string(prefix + ".tag", "");
+    dep.url         = project_config.get_string(prefix + ".url", "");
+    dep.sha256      = string_to_lower(project_config.get_string(prefix + ".sha256", ""));
+    dep.target_name = project_config.get_string(prefix + ".target_name", "");
+    dep.options     = project_config.get_string_map(prefix + ".options");
+    if (dep.git.empty() && dep.url.empty()) {
+      logger::print_warning("FetchContent dependency '" + name + "' has no git or url; skipping");
+      continue;
+    }
+    deps.push_back(dep);
+  }
+  return deps;
+}
+
+bool is_vcpkg_port_installed(const std::string &vcpkg_root,
+                             const std::string &port,
+                             const std::string &triplet) {
+  // vcpkg records each installed port as
+  // installed/vcpkg/info/<port>_<version>_<triplet>.list
+  std::filesystem::path info_dir =
+      std::filesystem::path(vcpkg_root) / "installed" / "vcpkg" / "info";
+  std::error_code ec;
+  if (!std::filesystem::is_directory(info_dir, ec)) {
+    return false;
+  }
+  std::string prefix = port + "_";
+  std::string suffix = "_" + triplet + ".list";
+  for (const auto &entry : std::filesystem::directory_iterator(info_dir, ec)) {
+    std::string file = entry.path().filename().string();
+    if (file.size() > prefix.size() + suffix.size() && file.rfind(prefix, 0) == 0
+        && file.compare(file.size() - suffix.size(), suffix.size(), suffix) == 0) {
+      return true;
+    }
+  }
+  return false;
+}
+
+/**
+ * @brief Ask the installed conan for its major version
+ *
+ * @return Major version, or 0 if `conan --version` failed
+ */
+static cforge_int_t detect_conan_major_version() {
+  process_result pr = execute_process("conan", {"--version"}, "", nullptr, nullptr, 60);
+  if (!pr.success) {
+    return 0;
+  }
+  return parse_conan_major_version(pr.stdout_output + pr.stderr_output);
+}
+
+std::filesystem::path get_conan_dir(const std::filesystem::path &project_dir) {
+  toml_reader project_config;
+  bool has_config = project_config.load((project_dir / CFORGE_FILE).string());
+  return get_build_base_dir(project_dir, has_config ? &project_config : nullptr) / "conan";
+}
+
+/**
+ * @brief File recording what the last conan install for a build type installed
+ */
+static std::filesystem::path conan_install_stamp(const std::filesystem::path &project_dir,
+                                                 const std::string &build_type) {
+  std::string name = "cforge-" + string_to_lower(build_type.empty() ? "default" : build_type);
+  return get_conan_dir(project_dir) / (name + ".stamp");
+}
+
+/**
+ * @brief Contents of the install stamp for the current dependencies
+ */
+static std::string conan_install_stamp_contents(const std::filesystem::path &project_dir) {
+  std::string contents;
+  for (const auto &reference : get_conan_references(project_dir)) {
+    contents += reference + "\n";
+  }
+  return contents;
+}
+
+std::vector<std::string> get_conan_references(const std::filesystem::path &project_dir) {
+  std::vector<std::string> references;
+  for (const auto &spec : parse_dependencies(project_dir / CFORGE_FILE)) {
+    if (spec.source == dependency_source::CONAN) {
+      references.push_back(spec.name + "/" + spec.version);
+    }
+  }
+  return references;
+}
+
+bool install_conan_packages(const std::filesystem::path &project_dir,
+                            const std::string &build_type,
+                            bool verbose) {
+  std::vector<std::string> references = get_conan_references(project_dir);
+  if (references.empty()) {
+    return true;
+  }
+  if (!is_command_available("conan", 10)) {
+    logger::print_error("conan not found in PATH");
+    logger::print_hint("install conan with 'pip install conan'");
+    return false;
+  }
+
+  cforge_int_t major = detect_conan_major_version();
+  if (major == 0) {
+    logger::print_error("could not determine the conan version from 'conan --version'");
+    return false;
+  }
+  logger::print_verbose("Using conan " + std::to_string(major) + ".x generators");
+
+  std::filesystem::path conan_dir = get_conan_dir(project_dir);
+  std::error_code ec;
+  std::filesystem::create_directories(conan_dir, ec);
+  {
+    std::ofstream conanfile(conan_dir / "conanfile.txt", std::ios::binary);
+    if (!conanfile) {
+      logger::print_error("Failed to write " + (conan_dir / "conanfile.txt").string());
+      return false;
+    }
+    conanfile << generate_conanfile_txt(major, references);
+  }
+  // Files from the other major version's generators would be picked up instead
+  std::filesystem::remove(conan_dir / conan_toolchain_file_name(major == 1 ? 2 : 1), ec);
+
+  // Conan 2 refuses to install without a default profile
+  if (major >= 2) {
+    process_result profile =
+        execute_process("conan", {"profile", "path", "default"}, "", nullptr, nullptr, 60);
+    if (!profile.success
+        && !execute_tool(
+            "conan", {"profile", "detect"}, "", "conan profile detect", verbose, 60)) {
+      logger::print_error("Failed to create a default conan profile");
+      return false;
+    }
+  }
+
+  for (const auto &reference : references) {
+    logger::installing(reference);
+  }
+  if (!execute_tool("conan",
+                    conan_install_args(major, conan_dir, build_type),
+                    project_dir.string(),
+                    "conan install",
+                    verbose,
+                    3600)) {
+    logger::print_error("Failed to install conan packages");
+    return false;
+  }
+
+  std::ofstream stamp(conan_install_stamp(project_dir, build_type), std::ios::binary);
+  stamp << conan_install_stamp_contents(project_dir);
+  return true;
+}
+
+bool is_conan_install_current(const std::filesystem::path &project_dir,
+                              const std::string &build_type) {
+  if (get_conan_toolchain_file(project_dir).empty()) {
+    return false;
+  }
+  std::ifstream stamp(conan_install_stamp(project_dir, build_type), std::ios::binary);
+  if (!stamp) {
+    return false;
+  }
+  std::stringstream contents;
+  contents << stamp.rdbuf();
+  return contents.str() == conan_install_stamp_contents(project_dir);
+}
+
+std::filesystem::path get_conan_toolchain_file(const std::filesystem::path &project_dir) {
+  std::filesystem::path conan_dir = get_conan_dir(project_dir);
+  for (cforge_int_t major : {2, 1}) {
+    std::filesystem::path toolchain = conan_dir / conan_toolchain_file_name(major);
+    if (std::filesystem::exists(toolchain)) {
+      return toolchain;
+    }
+  }
+  return {};
+}
+
+bool is_package_manager_dependency(const std::filesystem::path &project_dir,
+                                   const toml_reader &project_config,
+                                   const std::vector<std::string> &names) {
+  auto matches = [&](std::string name) {
+    name = string_to_lower(name);
+    return std::find(names.begin(), names.end(), name) != names.end();
+  };
+
+  // Legacy [dependencies.vcpkg.<package>] tables
+  for (const auto &pkg : project_config.get_table_keys("dependencies.vcpkg")) {
+    if (!is_vcpkg_setting_key(pkg) && matches(pkg)) {
+      return true;
+    }
+  }
+
+  for (const auto &spec : parse_dependencies(project_dir / CFORGE_FILE)) {
+    bool managed = spec.source == dependency_source::VCPKG
+                || spec.source == dependency_source::CONAN
+                || spec.source == dependency_source::SYSTEM;
+    if (managed && (matches(spec.name) || (!spec.vcpkg_name.empty() && matches(spec.vcpkg_name)))) {
+      return true;
+    }
+  }
+  return false;
+}
+
+std::filesystem::path get_build_base_dir(const std::filesystem::path &project_dir,
+                                         const toml_reader *project_config) {
+  std::string dir;
+  bool shared_override = false;
+
+  // Priority 1: --build-dir flag
+  if (!g_build_dir_override.empty()) {
+    dir             = g_build_dir_override;
+    shared_override = true;
+    logger::print_verbose("Using build directory from command line: " + dir);
+  }
+
+  // Priority 2: CFORGE_BUILD_DIR environment variable
+  if (dir.empty()) {
+    cforge_cstring_t env_dir = std::getenv("CFORGE_BUILD_DIR");
+    if (env_dir && *env_dir) {
+      dir             = env_dir;
+      shared_override = true;
+      logger::print_verbose("Using build directory from CFORGE_BUILD_DIR: " + dir);
+    }
+  }
+
+  // Priority 3: Configuration from cforge.toml
+  if (dir.empty() && project_config != nullptr) {
+    dir = project_config->get_string("build.build_dir", "");
+    if (dir.empty()) {
+      dir = project_config->get_string("build.directory", "");
+    }
+  }
+
+  // Priority 4: Default
+  if (dir.empty()) {
+    dir = DEFAULT_BUILD_DIR;
+  }
+
+  // An absolute override is shared by every workspace member; nest it per project
+  std::string member_name;
+  if (shared_override && std::filesystem::path(dir).is_absolute()
+      && !find_member_workspace(project_dir).empty()) {
+    toml_reader member_config;
+    if (project_config == nullptr && member_config.load((project_dir / CFORGE_FILE).string())) {
+      project_config = &member_config;
+    }
+    std::error_code ec;
+    std::string fallback = std::filesystem::weakly_canonical(project_dir, ec).filename().string();
+    member_name = project_config ? project_config->get_string("project.name", fallback) : fallback;
+  }
+  return resolve_build_base_dir(project_dir, dir, member_name);
+}
+
 std::string get_build_config(cforge_cstring_t explicit_config,
                              cforge_int_t arg_count,
                              cforge_string_t const *args,
@@ -177,17 +1017,25 @@ bool ensure_cmake_configured(const std::filesystem::path &project_dir,
   std::string generator               = get_cmake_generator();
   std::vector<std::string> cmake_args = {
       "-B", build_dir.string(), "-S", project_dir.string(), "-G", generator};
+  if (uses_msvc_environment(generator, "")) {
+    setup_msvc_environment("", verbose);
+  }
 
   // Add config for single-config generators
   if (!is_multi_config_generator(generator)) {
-    cmake_args.push_back("-DCMAKE_BUILD_TYPE=" + config);
+    cmake_args.push_back("-DCMAKE_BUILD_TYPE=" + canonical_build_config(config));
   }
 
+  // Drop defines and variants left in the cache by an earlier `cforge build`
+  cmake_args.push_back(cli_defines_cache_arg({}));
+  cmake_args.push_back(variants_cache_arg({}));
+
   // Add any extra arguments
   for (const auto &arg : extra_args) {
     cmake_args.push_back(arg);
   }
 
+  write_cmake_file_api_query(build_dir);
   return execute_tool("cmake", cmake_args, project_dir.string(), "CMake", verbose, 120);
 }
 
@@ -211,6 +1059,296 @@ bool run_cmake_build(const std::filesystem::path &build_dir,
   return execute_tool("cmake", build_args, "", "CMake Build", verbose, 600);
 }
 
+std::vector<std::string> get_cmake_cache_args(const toml_reader &project_config) {
+  static const std::vector<std::string> valid_types = {
+      "BOOL", "FILEPATH", "PATH", "STRING", "INTERNAL"};
+
+  std::vector<std::string> args;
+  // Scalar entries (KEY = "value" / KEY = true / KEY = 3)
+  auto scalars = project_config.get_string_map("cmake.cache");
+
+  for (const auto &key : project_config.get_table_keys("cmake.cache")) {
+    std::string value;
+    std::string type = "STRING";
+
+    auto scalar_it = scalars.find(key);
+    if (scalar_it != scalars.end()) {
+      value = scalar_it->second;
+    } else {
+      // Table entries (KEY = { value = ..., type = "..." })
+      auto entry    = project_config.get_string_map("cmake.cache." + key);
+      auto value_it = entry.find("value");
+      if (value_it == entry.end()) {
+        logger::print_warning("Ignoring [cmake.cache] entry '" + key + "': missing 'value'");
+        continue;
+      }
+      value = value_it->second;
+
+      auto type_it = entry.find("type");
+      if (type_it != entry.end() && !type_it->second.empty()) {
+        type = type_it->second;
+        std::transform(type.begin(), type.end(), type.begin(), ::toupper);
+      }
+    }
+
+    if (std::find(valid_types.begin(), valid_types.end(), type) == valid_types.end()) {
+      logger::print_warning("Unknown CMake cache type '" + type + "' for '" + key
+                            + "', using STRING");
+      type = "STRING";
+    }
+
+    args.push_back("-D" + key + ":" + type + "=" + value);
+  }
+
+  return args;
+}
+
+std::vector<std::string> get_config_cmake_args(const toml_reader &project_config,
+                                               const std::string &config) {
+  std::vector<std::string> args;
+  std::string config_key = "build.config." + string_to_lower(config);
+
+  // build.defines, then build.config.<config>.defines; '=ON' if no value
+  for (const auto &key : {std::string("build.defines"), config_key + ".defines"}) {
+    if (!project_config.has_key(key)) {
+      continue;
+    }
+    for (std::string def : project_config.get_string_array(key)) {
+      if (def.find('=') == std::string::npos) {
+        def += "=ON";
+      }
+      args.push_back("-D" + def);
+    }
+  }
+
+  if (project_config.has_key(config_key + ".cmake_args")) {
+    for (const auto &arg : project_config.get_string_array(config_key + ".cmake_args")) {
+      args.push_back(arg);
+    }
+  }
+
+  // Typed cache entries from [cmake.cache]
+  auto cache_args = get_cmake_cache_args(project_config);
+  args.insert(args.end(), cache_args.begin(), cache_args.end());
+  return args;
+}
+
+std::vector<std::string> get_compiler_cmake_args(const toml_reader &project_config) {
+  std::vector<std::string> args;
+  std::string cc = project_config.get_string("cmake.c_compiler", "");
+  if (!cc.empty()) {
+    args.push_back("-DCMAKE_C_COMPILER=" + cc);
+    logger::print_verbose("Using C compiler: " + cc);
+  }
+  std::string cxx = project_config.get_string("cmake.cxx_compiler", "");
+  if (!cxx.empty()) {
+    args.push_back("-DCMAKE_CXX_COMPILER=" + cxx);
+    logger::print_verbose("Using C++ compiler: " + cxx);
+  }
+
+  // Project-level C and C++ standard overrides
+  std::string cstd = project_config.get_string("project.c_standard", "");
+  if (!cstd.empty()) {
+    args.push_back("-DCMAKE_C_STANDARD=" + cstd);
+    logger::print_verbose("Using C standard: " + cstd);
+  }
+  std::string cppstd = project_config.get_string("project.cpp_standard", "");
+  if (!cppstd.empty()) {
+    args.push_back("-DCMAKE_CXX_STANDARD=" + cppstd);
+    logger::print_verbose("Using C++ standard: " + cppstd);
+  }
+  return args;
+}
+
+std::string normalize_binary_type(const std::string &type, bool *deprecated_alias) {
+  std::string t = string_to_lower(type);
+  std::replace(t.begin(), t.end(), '-', '_');
+
+  if (deprecated_alias) {
+    *deprecated_alias = false;
+  }
+
+  if (t == "executable" || t == "exe") {
+    return "executable";
+  }
+  if (t == "static_lib" || t == "static_library" || t == "static") {
+    return "static_lib";
+  }
+  if (t == "shared_lib" || t == "shared_library" || t == "shared") {
+    return "shared_lib";
+  }
+  if (t == "header_only" || t == "header_only_library" || t == "interface") {
+    return "header_only";
+  }
+  if (t == "library" || t == "lib") {
+    if (deprecated_alias) {
+      *deprecated_alias = true;
+    }
+    return "static_lib";
+  }
+  return "";
+}
+
+std::string get_binary_type(const toml_reader &project_config) {
+  std::string type       = project_config.get_string("project.binary_type", "executable");
+  std::string normalized = normalize_binary_type(type);
+  return normalized.empty() ? type : normalized;
+}
+
+bool validate_binary_type(const toml_reader &project_config,
+                          const std::string &config_file,
+                          const std::string &key) {
+  if (!project_config.has_key(key)) {
+    return true;
+  }
+
+  std::string type     = project_config.get_string(key, "");
+  cforge_size_t line   = project_config.get_line(key);
+  std::string location = line > 0 ? config_file + ":" + std::to_string(line) : config_file;
+
+  bool deprecated        = false;
+  std::string normalized = normalize_binary_type(type, &deprecated);
+  if (normalized.empty()) {
+    cforge_error::make(error_code::CONFIG_INVALID_VALUE,
+                       "invalid " + key + " '" + type + "'",
+                       "--> " + location)
+        .with_help("expected one of: executable, static_lib, shared_lib, header_only")
+        .print();
+    return false;
+  }
+
+  if (deprecated) {
+    logger::print_warning(key + " = \"" + type + "\" is deprecated and builds a static library");
+    logger::print_hint("set " + key + " = \"static_lib\" (or \"shared_lib\") at " + location);
+  }
+  return true;
+}
+
+bool validate_manifest_keys(const toml_reader &project_config, const std::string &config_file) {
+  const toml::table *table = project_config.get_raw_table();
+  if (!table) {
+    return true;
+  }
+
+  std::vector<unknown_manifest_key> unknown = find_unknown_table_keys(*table);
+  for (const auto &key : unknown) {
+    // Keys inherited from a workspace's [defaults] point at the workspace file
+    std::string file     = key.file.empty() ? config_file : key.file;
+    std::string location = key.line > 0 ? file + ":" + std::to_string(key.line) : file;
+    std::string table_name = key.path.substr(0, key.path.find('.'));
+    cforge_error::make(error_code::CONFIG_UNKNOWN_KEY,
+                       "unknown key '" + key.path + "'",
+                       "--> " + location)
+        .with_help(key.suggestion.empty()
+                       ? "cforge does not read this key; remove it or check the spelling"
+                       : "did you mean '" + table_name + "." + key.suggestion + "'?")
+        .print();
+  }
+  return unknown.empty();
+}
+
+std::map<std::string, std::string> get_project_environment(const toml_reader &project_config,
+                                                           const std::string &config) {
+  std::map<std::string, std::string> env = project_config.get_string_map("env");
+
+  std::string config_lower = string_to_lower(config);
+  auto config_env = project_config.get_string_map("build.config." + config_lower + ".env");
+  if (config_env.empty()) {
+    config_env = project_config.get_string_map("build.configs." + config_lower + ".env");
+  }
+  for (const auto &[name, value] : config_env) {
+    env[name] = value;
+  }
+
+  std::map<std::string, std::string> tokens = get_cforge_tokens(project_config, config);
+  for (auto &[name, value] : env) {
+    value = expand_cforge_tokens(value, tokens);
+    logger::print_verbose("Environment: " + name + "=" + value);
+  }
+
+  return env;
+}
+
+std::map<std::string, std::string> get_cforge_tokens(const toml_reader &project_config,
+                                                     const std::string &config) {
+  return make_cforge_tokens(project_config.get_string("project.name", "cpp-project"),
+                            project_config.get_string("project.version", "0.1.0"),
+                            config,
+                            platform::get_platform_name(),
+                            host_arch_name());
+}
+
+std::filesystem::path get_output_dir(const toml_reader &project_config,
+                                     const std::filesystem::path &project_dir,
+                                     const std::string &key,
+                                     const std::string &config) {
+  std::string dir = project_config.get_string("output." + key, "");
+  if (dir.empty()) {
+    return {};
+  }
+  std::filesystem::path expanded =
+      expand_cforge_tokens(dir, get_cforge_tokens(project_config, config));
+  return expanded.is_absolute() ? expanded : project_dir / expanded;
+}
+
+std::filesystem::path find_output_executable(const toml_reader &project_config,
+                                             const std::filesystem::path &project_dir,
+                                             const std::string &config,
+                                             const std::string &name) {
+  std::filesystem::path bin_dir = get_output_dir(project_config, project_dir, "bin_dir", config);
+  if (bin_dir.empty()) {
+    return {};
+  }
+  std::filesystem::path executable = bin_dir / (name + platform::executable_extension());
+  if (std::filesystem::is_regular_file(executable)) {
+    return executable;
+  }
+  // Emscripten builds
+  for (cforge_cstring_t ext : {".js", ".html"}) {
+    if (is_wasm_executable(bin_dir / (name + ext))) {
+      return bin_dir / (name + ext);
+    }
+  }
+  logger::print_verbose("No executable at " + executable.string() + ", searching the build tree");
+  return {};
+}
+
+bool is_build_info_enabled(const toml_reader &project_config) {
+  return project_config.has_key("build.info")
+      && project_config.get_bool("build.info.enabled", true);
+}
+
+bool write_build_info_header(const std::filesystem::path &project_dir,
+                             const std::filesystem::path &build_dir,
+                             const toml_reader &project_config) {
+  build_info_values values;
+  values.project_name    = project_config.get_string("project.name", "cpp-project");
+  values.project_version = project_config.get_string("project.version", "0.1.0");
+  if (is_git_available()) {
+    read_build_info_git(values, project_dir, [&](const std::vector<std::string> &args) {
+      return git_execute(args, project_dir.string());
+    });
+  }
+
+  std::time_t now = std::chrono::system_clock::to_time_t(std::chrono::system_clock::now());
+  std::ostringstream timestamp;
+  timestamp << std::put_time(std::gmtime(&now), "%Y-%m-%dT%H:%M:%SZ");
+
+  std::filesystem::path header_path = build_dir / "cforge_generated" / "cforge_build_info.h";
+  switch (update_build_info_header(header_path, values, timestamp.str())) {
+    case build_info_update::unchanged:
+      logger::print_verbose("Build info header is up to date");
+      return true;
+    case build_info_update::written:
+      logger::print_verbose("Generated build info header: " + header_path.string());
+      return true;
+    case build_info_update::failed:
+      break;
+  }
+  logger::print_warning("Failed to write build info header: " + header_path.string());
+  return false;
+}
+
 bool is_file_newer(const std::filesystem::path &source, const std::filesystem::path &target) {
   if (!std::filesystem::exists(target)) {
     return true;  // Target doesn't exist, so source is "newer"
@@ -253,6 +1391,69 @@ bool needs_cmakelists_regeneration(const std::filesystem::path &project_dir) {
   return false;
 }
 
+std::string get_project_config_hash(const std::filesystem::path &project_dir,
+                                    const std::vector<std::string> &cli_defines,
+                                    const std::vector<std::string> &variants) {
+  try {
+    toml::table config = toml::parse_file((project_dir / CFORGE_FILE).string());
+    apply_workspace_defaults(config, project_dir / CFORGE_FILE);
+    // Hash the expanded values, so a changed environment variable regenerates
+    interpolate_config_table(config);
+    std::ostringstream serialized;
+    serialized << config;
+    if (!cli_defines.empty()) {
+      serialized << "\n" << cli_defines_cache_arg(cli_defines);
+    }
+    if (!variants.empty()) {
+      serialized << "\n" << variants_cache_arg(variants);
+    }
+    return dependency_hash().calculate_file_content_hash(serialized.str());
+  } catch (const std::exception &e) {
+    logger::print_verbose("Cannot hash " + std::string(CFORGE_FILE) + ": " + e.what());
+    return "";
+  }
+}
+
+void write_config_hash(const std::filesystem::path &project_dir,
+                       const std::filesystem::path &build_dir,
+                       const std::vector<std::string> &cli_defines,
+                       const std::vector<std::string> &variants) {
+  std::string hash = get_project_config_hash(project_dir, cli_defines, variants);
+  if (hash.empty()) {
+    return;
+  }
+  std::ofstream out(build_dir / ".cforge-config-hash");
+  if (out) {
+    out << hash << "\n";
+  }
+}
+
+bool is_config_hash_current(const std::filesystem::path &project_dir,
+                            const std::filesystem::path &build_dir,
+                            const std::vector<std::string> &cli_defines,
+                            const std::vector<std::string> &variants) {
+  std::ifstream hash_file(build_dir / ".cforge-config-hash");
+  std::string recorded_hash;
+  if (!(hash_file >> recorded_hash)) {
+    return false;
+  }
+  return recorded_hash == get_project_config_hash(project_dir, cli_defines, variants);
+}
+
+/**
+ * @brief Check whether cforge.toml changed since a hash was recorded
+ *
+ * @param has_hash Set to true if the build directory has a recorded hash
+ */
+static bool config_hash_changed(const std::filesystem::path &project_dir,
+                                const std::filesystem::path &build_dir,
+                                bool &has_hash) {
+  std::ifstream hash_file(build_dir / ".cforge-config-hash");
+  std::string recorded_hash;
+  has_hash = static_cast<bool>(hash_file >> recorded_hash);
+  return has_hash && recorded_hash != get_project_config_hash(project_dir);
+}
+
 bool needs_cmake_reconfigure(const std::filesystem::path &project_dir,
                              const std::filesystem::path &build_dir) {
   std::filesystem::path cmake_cache = build_dir / "CMakeCache.txt";
@@ -270,9 +1471,18 @@ bool needs_cmake_reconfigure(const std::filesystem::path &project_dir,
     return true;
   }
 
-  // Also check cforge.toml
+  // Also check cforge.toml: by content when the last configure recorded a
+  // hash, since a checkout or copy can leave an edited file with an old mtime
   std::filesystem::path toml_path = project_dir / CFORGE_FILE;
-  if (std::filesystem::exists(toml_path) && is_file_newer(toml_path, cmake_cache)) {
+  if (!std::filesystem::exists(toml_path)) {
+    return false;
+  }
+  bool has_hash = false;
+  if (config_hash_changed(project_dir, build_dir, has_hash)) {
+    logger::print_verbose("cforge.toml changed since the last configure, reconfiguration needed");
+    return true;
+  }
+  if (!has_hash && is_file_newer(toml_path, cmake_cache)) {
     logger::print_verbose("cforge.toml is newer than CMakeCache.txt, reconfiguration needed");
     return true;
   }
@@ -291,8 +1501,11 @@ build_preparation_result prepare_project_for_build(const std::filesystem::path &
 
   std::filesystem::path toml_path = project_dir / CFORGE_FILE;
 
-  // Step 1: Check if CMakeLists.txt needs regeneration
-  bool need_regen = force_regenerate || needs_cmakelists_regeneration(project_dir);
+  // Step 1: Check if CMakeLists.txt needs regeneration; a changed config hash
+  // catches edits whose mtime is older than the generated file
+  bool has_hash   = false;
+  bool need_regen = force_regenerate || needs_cmakelists_regeneration(project_dir)
+                 || config_hash_changed(project_dir, build_dir, has_hash);
 
   if (need_regen && std::filesystem::exists(toml_path)) {
     logger::print_action("Regenerating", "CMakeLists.txt from cforge.toml");
@@ -300,6 +1513,12 @@ build_preparation_result prepare_project_for_build(const std::filesystem::path &
     // Load the project config
     try {
       toml::table config_table = toml::parse_file(toml_path.string());
+      apply_workspace_defaults(config_table, toml_path);
+      if (!interpolate_config_values(config_table, toml_path.string())) {
+        result.success       = false;
+        result.error_message = "cforge.toml refers to unset environment variables";
+        return result;
+      }
       toml_reader project_config(config_table);
 
       // Generate CMakeLists.txt
@@ -341,6 +1560,7 @@ build_preparation_result prepare_project_for_build(const std::filesystem::path &
       result.error_message = "CMake configuration failed";
       return result;
     }
+    write_config_hash(project_dir, build_dir);
     result.cmake_reconfigured = true;
   }
 
@@ -0,0 +1,446 @@
+/**
+ * @file cmake_file_api.cpp
+ * @brief CMake file API query writer and codemodel reply reader
+ *
+ * The reply files are small, well-formed JSON written by CMake itself, so a
+ * compact recursive-descent parser is enough here; anything malformed is
+ * treated as "no reply" and callers fall back to their old heuristics.
+ */
+
+#include "core/cmake_file_api.hpp"
+
+#include "cforge/log.hpp"
+
+#include "core/types.h"
+
+#include <algorithm>
+#include <cctype>
+#include <fstream>
+#include <sstream>
+
+namespace cforge {
+
+// ============================================================================
+// Minimal JSON reader
+// ============================================================================
+
+namespace {
+
+struct json_value {
+  enum class kind { NUL, BOOL, NUMBER, STRING, ARRAY, OBJECT };
+
+  kind type = kind::NUL;
+  std::string text;               // STRING contents, or NUMBER/BOOL spelling
+  std::vector<json_value> items;  // ARRAY elements, or OBJECT values
+  std::vector<std::string> keys;  // OBJECT keys (parallel to items)
+
+  const json_value *get(const std::string &key) const {
+    if (type != kind::OBJECT) {
+      return nullptr;
+    }
+    for (cforge_size_t i = 0; i < keys.size(); ++i) {
+      if (keys[i] == key) {
+        return &items[i];
+      }
+    }
+    return nullptr;
+  }
+
+  std::string get_string(const std::string &key) const {
+    const json_value *v = get(key);
+    return (v && v->type == kind::STRING) ? v->text : std::string();
+  }
+};
+
+class json_parser {
+public:
+  explicit json_parser(const std::string &input) : s_(input) {}
+
+  bool parse(json_value &out) {
+    if (!parse_value(out)) {
+      return false;
+    }
+    skip_ws();
+    return pos_ == s_.size();
+  }
+
+private:
+  const std::string &s_;
+  cforge_size_t pos_ = 0;
+
+  void skip_ws() {
+    while (pos_ < s_.size() && std::isspace(static_cast<unsigned char>(s_[pos_]))) {
+      ++pos_;
+    }
+  }
+
+  bool consume(char c) {
+    skip_ws();
+    if (pos_ < s_.size() && s_[pos_] == c) {
+      ++pos_;
+      return true;
+    }
+    return false;
+  }
+
+  bool parse_literal(const char *word, json_value::kind type, json_value &out) {
+    std::string w(word);
+    if (s_.compare(pos_, w.size(), w) != 0) {
+      return false;
+    }
+    pos_ += w.size();
+    out.type = type;
+    out.text = w;
+    return true;
+  }
+
+  bool parse_value(json_value &out) {
+    skip_ws();
+    if (pos_ >= s_.size()) {
+      return false;
+    }
+    char c = s_[pos_];
+    if (c == '{') {
+      return parse_object(out);
+    }
+    if (c == '[') {
+      return parse_array(out);
+    }
+    if (c == '"') {
+      out.type = json_value::kind::STRING;
+      return parse_string(out.text);
+    }
+    if (c == 't') {
+      return parse_literal("true", json_value::kind::BOOL, out);
+    }
+    if (c == 'f') {
+      return parse_literal("false", json_value::kind::BOOL, out);
+    }
+    if (c == 'n') {
+      return parse_literal("null", json_value::kind::NUL, out);
+    }
+    return parse_number(out);
+  }
+
+  bool parse_number(json_value &out) {
+    cforge_size_t start = pos_;
+    while (pos_ < s_.size()
+           && (std::isdigit(static_cast<unsigned char>(s_[pos_])) || s_[pos_] == '-'
+               || s_[pos_] == '+' || s_[pos_] == '.' || s_[pos_] == 'e' || s_[pos_] == 'E')) {
+      ++pos_;
+    }
+    if (pos_ == start) {
+      return false;
+    }
+    out.type = json_value::kind::NUMBER;
+    out.text = s_.substr(start, pos_ - start);
+    return true;
+  }
+
+  bool parse_string(std::string &out) {
+    if (s_[pos_] != '"') {
+      return false;
+    }
+    ++pos_;
+    out.clear();
+    while (pos_ < s_.size()) {
+      char c = s_[pos_++];
+      if (c == '"') {
+        return true;
+      }
+      if (c != '\\') {
+        out += c;
+        continue;
+      }
+      if (pos_ >= s_.size()) {
+        return false;
+      }
+      char esc = s_[pos_++];
+      switch (esc) {
+        case 'n':
+          out += '\n';
+          break;
+        case 't':
+          out += '\t';
+          break;
+        case 'r':
+          out += '\r';
+          break;
+        case 'b':
+          out += '\b';
+          break;
+        case 'f':
+          out += '\f';
+          break;
+        case 'u': {
+          // Paths in replies are ASCII in practice; keep BMP code points as UTF-8
+          if (pos_ + 4 > s_.size()) {
+            return false;
+          }
+          unsigned long cp = std::stoul(s_.substr(pos_, 4), nullptr, 16);
+          pos_ += 4;
+          if (cp < 0x80) {
+            out += static_cast<char>(cp);
+          } else if (cp < 0x800) {
+            out += static_cast<char>(0xC0 | (cp >> 6));
+            out += static_cast<char>(0x80 | (cp & 0x3F));
+          } else {
+            out += static_cast<char>(0xE0 | (cp >> 12));
+            out += static_cast<char>(0x80 | ((cp >> 6) & 0x3F));
+            out += static_cast<char>(0x80 | (cp & 0x3F));
+          }
+          break;
+        }
+        default:
+          out += esc;  // \" \\ \/
+          break;
+      }
+    }
+    return false;
+  }
+
+  bool parse_array(json_value &out) {
+    out.type = json_value::kind::ARRAY;
+    ++pos_;  // '['
+    if (consume(']')) {
+      return true;
+    }
+    do {
+      json_value item;
+      if (!parse_value(item)) {
+        return false;
+      }
+      out.items.push_back(std::move(item));
+    } while (consume(','));
+    return consume(']');
+  }
+
+  bool parse_object(json_value &out) {
+    out.type = json_value::kind::OBJECT;
+    ++pos_;  // '{'
+    if (consume('}')) {
+      return true;
+    }
+    do {
+      skip_ws();
+      std::string key;
+      if (pos_ >= s_.size() || !parse_string(key) || !consume(':')) {
+        return false;
+      }
+      json_value item;
+      if (!parse_value(item)) {
+        return false;
+      }
+      out.keys.push_back(std::move(key));
+      out.items.push_back(std::move(item));
+    } while (consume(','));
+    return consume('}');
+  }
+};
+
+bool load_json_file(const std::filesystem::path &path, json_value &out) {
+  std::ifstream file(path, std::ios::binary);
+  if (!file) {
+    return false;
+  }
+  std::stringstream buffer;
+  buffer << file.rdbuf();
+  std::string content = buffer.str();
+
+  try {
+    json_parser parser(content);
+    return parser.parse(out);
+  } catch (const std::exception &) {
+    return false;
+  }
+}
+
+std::string to_lower(std::string s) {
+  std::transform(s.begin(), s.end(), s.begin(), [](unsigned char c) {
+    return static_cast<char>(std::tolower(c));
+  });
+  return s;
+}
+
+}  // namespace
+
+// ============================================================================
+// Public API
+// ============================================================================
+
+bool write_cmake_file_api_query(const std::filesystem::path &build_dir) {
+  std::filesystem::path query_dir = build_dir / ".cmake" / "api" / "v1" / "query";
+  std::filesystem::path query     = query_dir / "codemodel-v2";
+  if (std::filesystem::exists(query)) {
+    return true;
+  }
+
+  std::error_code ec;
+  std::filesystem::create_directories(query_dir, ec);
+  if (ec) {
+    logger::print_verbose("Could not create CMake file API query directory: " + ec.message());
+    return false;
+  }
+
+  // A stateless query is just an empty file whose name is the object kind
+  std::ofstream(query).close();
+  return std::filesystem::exists(query);
+}
+
+std::optional<std::vector<cmake_target_info>>
+read_cmake_file_api_targets(const std::filesystem::path &build_dir, const std::string &config) {
+  std::filesystem::path reply_dir = build_dir / ".cmake" / "api" / "v1" / "reply";
+  if (!std::filesystem::is_directory(reply_dir)) {
+    return std::nullopt;
+  }
+
+  // CMake may leave several index files behind; the newest sorts last
+  std::filesystem::path index_path;
+  for (const auto &entry : std::filesystem::directory_iterator(reply_dir)) {
+    std::string filename = entry.path().filename().string();
+    if (filename.rfind("index-", 0) == 0 && entry.path().extension() == ".json"
+        && (index_path.empty() || filename > index_path.filename().string())) {
+      index_path = entry.path();
+    }
+  }
+  if (index_path.empty()) {
+    return std::nullopt;
+  }
+
+  json_value index;
+  if (!load_json_file(index_path, index)) {
+    logger::print_verbose("Failed to parse CMake file API index: " + index_path.string());
+    return std::nullopt;
+  }
+
+  std::string codemodel_file;
+  if (const json_value *objects = index.get("objects")) {
+    for (const auto &object : objects->items) {
+      const json_value *version = object.get("version");
+      const json_value *major   = version ? version->get("major") : nullptr;
+      if (object.get_string("kind") == "codemodel" && major && major->text == "2") {
+        codemodel_file = object.get_string("jsonFile");
+        break;
+      }
+    }
+  }
+  if (codemodel_file.empty()) {
+    return std::nullopt;
+  }
+
+  json_value codemodel;
+  if (!load_json_file(reply_dir / codemodel_file, codemodel)) {
+    logger::print_verbose("Failed to parse CMake file API codemodel: " + codemodel_file);
+    return std::nullopt;
+  }
+
+  std::filesystem::path top_build_dir = build_dir;
+  if (const json_value *paths = codemodel.get("paths")) {
+    std::string build_path = paths->get_string("build");
+    if (!build_path.empty()) {
+      top_build_dir = build_path;
+    }
+  }
+
+  const json_value *configurations = codemodel.get("configurations");
+  if (!configurations || configurations->items.empty()) {
+    return std::nullopt;
+  }
+
+  // Multi-config generators list every configuration; single-config ones list
+  // only CMAKE_BUILD_TYPE, which is what this build directory was made for
+  const json_value *selected = nullptr;
+  for (const auto &cfg : configurations->items) {
+    if (to_lower(cfg.get_string("name")) == to_lower(config)) {
+      selected = &cfg;
+      break;
+    }
+  }
+  if (!selected && configurations->items.size() == 1) {
+    selected = &configurations->items.front();
+  }
+  if (!selected) {
+    return std::nullopt;
+  }
+
+  std::vector<cmake_target_info> targets;
+  if (const json_value *target_list = selected->get("targets")) {
+    for (const auto &target_ref : target_list->items) {
+      json_value target;
+      if (!load_json_file(reply_dir / target_ref.get_string("jsonFile"), target)) {
+        continue;
+      }
+
+      cmake_target_info info;
+      info.name = target.get_string("name");
+      info.type = target.get_string("type");
+      if (const json_value *artifacts = target.get("artifacts")) {
+        for (const auto &artifact : artifacts->items) {
+          std::filesystem::path artifact_path = artifact.get_string("path");
+          if (artifact_path.empty()) {
+            continue;
+          }
+          if (artifact_path.is_relative()) {
+            artifact_path = top_build_dir / artifact_path;
+          }
+          info.artifacts.push_back(artifact_path.lexically_normal());
+        }
+      }
+      targets.push_back(std::move(info));
+    }
+  }
+
+  return targets;
+}
+
+std::optional<std::filesystem::path> find_cmake_executable(const std::filesystem::path &build_dir,
+                                                           const std::string &config,
+                                                           const std::string &target_name) {
+  auto targets = read_cmake_file_api_targets(build_dir, config);
+  if (!targets) {
+    logger::print_verbose("No CMake file API reply in " + build_dir.string());
+    return std::nullopt;
+  }
+
+  std::vector<std::string> executables;
+  for (const auto &target : *targets) {
+    if (target.type != "EXECUTABLE") {
+      continue;
+    }
+    executables.push_back(target.name);
+    if (target.name != target_name) {
+      continue;
+    }
+    // MSVC also lists the .pdb next to the .exe
+    for (const auto &artifact : target.artifacts) {
+      if (artifact.extension() != ".pdb") {
+        logger::print_verbose("CMake file API artifact for " + target_name + ": "
+                              + artifact.string());
+        return artifact;
+      }
+    }
+  }
+
+  auto it = std::find_if(targets->begin(), targets->end(), [&](const cmake_target_info &t) {
+    return t.name == target_name;
+  });
+  if (it != targets->end()) {
+    logger::print_error("target '" + target_name + "' is not an executable (type "
+                        + to_lower(it->type) + ")");
+  } else {
+    logger::print_error("no CMake target named '" + target_name + "'");
+  }
+
+  if (executables.empty()) {
+    logger::print_hint("the build has no executable targets");
+  } else {
+    std::string list;
+    for (const auto &name : executables) {
+      list += (list.empty() ? "" : ", ") + name;
+    }
+    logger::print_hint("executable targets: " + list);
+  }
+  return std::filesystem::path();
+}
+
+}  // namespace cforge
@@ -111,7 +111,9 @@ static std::string join_lines(const std::vector<std::string> &lines) {
 }
 
 /// Split a token list (whitespace-separated, respecting quotes) and filter
-/// out ${...} variable references and $<...> generator expressions.
+/// out ${...} variable references and $<...> generator expressions. Paths
+/// under the project directory, such as ${CMAKE_CURRENT_SOURCE_DIR}/include,
+/// are made relative instead.
 static std::vector<std::string> tokenize_cmake_args(const std::string &s) {
   std::vector<std::string> tokens;
   std::string tok;
@@ -139,6 +141,15 @@ static std::vector<std::string> tokenize_cmake_args(const std::string &s) {
   // Filter out ${VAR} and $<GENEX> references
   std::vector<std::string> filtered;
   for (auto &token : tokens) {
+    for (const char *dir : {"${CMAKE_CURRENT_SOURCE_DIR}", "${PROJECT_SOURCE_DIR}",
+                            "${CMAKE_SOURCE_DIR}"}) {
+      std::string prefix = dir;
+      if (token == prefix) {
+        token = ".";
+      } else if (token.rfind(prefix + "/", 0) == 0) {
+        token = token.substr(prefix.size() + 1);
+      }
+    }
     if (token.find("${") == std::string::npos && token.find("$<") == std::string::npos) {
       filtered.push_back(token);
     }
@@ -233,7 +244,8 @@ static void extract_c_standard(const std::string &content, cmake_parse_result &r
   }
 }
 
-/// Extract binary type (executable, static, shared, interface) and target name.
+/// Extract binary type (executable, static_lib, shared_lib, header_only) and
+/// target name.
 static void extract_binary_type(const std::string &content, cmake_parse_result &result) {
   try {
     bool found_executable = false;
@@ -264,16 +276,16 @@ static void extract_binary_type(const std::string &content, cmake_parse_result &
         std::transform(lib_type.begin(), lib_type.end(), lib_type.begin(), ::toupper);
 
         if (lib_type == "SHARED") {
-          result.binary_type = "shared";
+          result.binary_type = "shared_lib";
         } else if (lib_type == "INTERFACE") {
-          result.binary_type = "interface";
+          result.binary_type = "header_only";
         } else if (lib_type == "MODULE") {
-          result.binary_type = "shared";
+          result.binary_type = "shared_lib";
           result.warnings.push_back("MODULE library mapped to binary_type = "
-                                    "\"shared\" (closest match)");
+                                    "\"shared_lib\" (closest match)");
         } else {
           // STATIC or empty defaults to static
-          result.binary_type = "static";
+          result.binary_type = "static_lib";
         }
       } else {
         result.warnings.push_back("Both add_executable and add_library found; "
@@ -290,6 +302,44 @@ static void extract_binary_type(const std::string &content, cmake_parse_result &
   }
 }
 
+/// Extract the sources listed for the imported target, and the names of the
+/// targets that are not imported.
+static void extract_targets(const std::string &content, cmake_parse_result &result) {
+  try {
+    std::regex re_target(R"(add_(?:executable|library)\s*\(\s*([\w.:-]+)([^)]*)\))",
+                         std::regex::icase);
+    std::regex re_source(R"(\.(?:c|cc|cpp|cxx|c\+\+|h|hh|hpp|hxx|ipp|m|mm)$)",
+                         std::regex::icase);
+
+    auto it     = std::sregex_iterator(content.begin(), content.end(), re_target);
+    auto end_it = std::sregex_iterator();
+    for (; it != end_it; ++it) {
+      std::string name = (*it)[1].str();
+      auto tokens      = tokenize_cmake_args((*it)[2].str());
+      // ALIAS and IMPORTED targets name something defined elsewhere
+      if (std::find(tokens.begin(), tokens.end(), "ALIAS") != tokens.end()
+          || std::find(tokens.begin(), tokens.end(), "IMPORTED") != tokens.end()) {
+        continue;
+      }
+      if (name != result.target_name) {
+        result.other_targets.push_back(name);
+        continue;
+      }
+      for (const auto &tok : tokens) {
+        if (std::regex_search(tok, re_source)) {
+          result.source_files.push_back(tok);
+        }
+      }
+    }
+
+    dedup(result.source_files);
+    dedup(result.other_targets);
+
+  } catch (const std::exception &) {
+    result.warnings.push_back("Failed to parse target sources");
+  }
+}
+
 /// Extract source directories from file(GLOB...) or target_sources().
 static void extract_source_dirs(const std::string &content,
                                 cmake_parse_result &result,
@@ -381,7 +431,28 @@ static void extract_source_dirs(const std::string &content,
       }
     }
 
-    // Strategy 3: default to src/ if it exists
+    // Strategy 3: the directories of the sources listed in add_executable()
+    // or add_library()
+    if (!found_any) {
+      std::vector<std::string> dirs;
+      for (const auto &file : result.source_files) {
+        cforge_size_t last_sep = file.find_last_of("/\\");
+        dirs.push_back(last_sep == std::string::npos ? "." : file.substr(0, last_sep));
+      }
+      dedup(dirs);
+      // Source directories are searched recursively, so nested ones are dropped
+      for (const auto &dir : dirs) {
+        bool nested = std::any_of(dirs.begin(), dirs.end(), [&](const std::string &other) {
+          return dir.rfind(other + "/", 0) == 0;
+        });
+        if (!nested) {
+          result.source_dirs.push_back(dir);
+          found_any = true;
+        }
+      }
+    }
+
+    // Strategy 4: default to src/ if it exists
     if (!found_any) {
       std::filesystem::path src_dir = std::filesystem::path(cmake_dir) / "src";
       if (std::filesystem::exists(src_dir) && std::filesystem::is_directory(src_dir)) {
@@ -427,7 +498,7 @@ static void extract_include_dirs(const std::string &content, cmake_parse_result
     }
 
     // Fallback: include_directories(dirs...)
-    std::regex re_id(R"(include_directories\s*\(\s*([^)]+)\))", std::regex::icase);
+    std::regex re_id(R"(\binclude_directories\s*\(\s*([^)]+)\))", std::regex::icase);
 
     auto it2            = std::sregex_iterator(content.begin(), content.end(), re_id);
     bool found_fallback = false;
@@ -468,13 +539,20 @@ static void extract_dependencies(const std::string &content, cmake_parse_result
     std::regex re_fp(
         R"(find_package\s*\(\s*(\w+)(?:\s+([\d.]+))?(?:\s+REQUIRED)?(?:\s+COMPONENTS\s+([^)]+))?)",
         std::regex::icase);
+    std::regex re_component_end(R"(\b(?:OPTIONAL_COMPONENTS|CONFIG|MODULE|QUIET|REQUIRED)\b)");
 
     auto end_it = std::sregex_iterator();
     auto it     = std::sregex_iterator(content.begin(), content.end(), re_fp);
     for (; it != end_it; ++it) {
       cmake_dependency dep;
-      dep.name             = (*it)[1].str();
-      dep.version          = (*it)[2].str();
+      dep.name               = (*it)[1].str();
+      dep.version            = (*it)[2].str();
+      std::string components = (*it)[3].str();
+      std::smatch m_end;
+      if (std::regex_search(components, m_end, re_component_end)) {
+        components = components.substr(0, m_end.position(0));
+      }
+      dep.components       = tokenize_cmake_args(components);
       dep.is_find_package  = true;
       dep.is_fetch_content = false;
       dep.is_subdirectory  = false;
@@ -672,11 +750,14 @@ static void extract_link_libraries(const std::string &content, cmake_parse_resul
 
         if (!is_dep) {
           result.link_libraries.push_back(tok);
+        } else {
+          result.dependency_targets.push_back(tok);
         }
       }
     }
 
     dedup(result.link_libraries);
+    dedup(result.dependency_targets);
 
   } catch (const std::exception &) {
     result.warnings.push_back("Failed to parse link libraries");
@@ -754,6 +835,7 @@ cmake_parse_result parse_cmake_file(const std::string &cmake_path) {
   extract_cpp_standard(content, result);
   extract_c_standard(content, result);
   extract_binary_type(content, result);
+  extract_targets(content, result);
   extract_source_dirs(content, result, cmake_dir);
   extract_include_dirs(content, result);
   extract_dependencies(content, result);
@@ -42,17 +42,24 @@ bool cforge_is_workspace_dir(void) {
 void cforge_parse_args(cforge_int_t argc, cforge_string_t argv[], cforge_command_args_t *args) {
   memset(args, 0, sizeof(cforge_command_args_t));
 
+  // --offline may also come before the command
+  cforge_int_t first = 1;
+  while (first < argc && strcmp(argv[first], "--offline") == 0) {
+    args->offline = true;
+    first++;
+  }
+
   // Need at least one argument (the command)
-  if (argc < 2) {
+  if (argc <= first) {
     return;
   }
 
   // Use strdup to safely copy strings - these will be freed in cforge_free_args
-  args->command = strdup(argv[1]);
+  args->command = strdup(argv[first]);
 
   // Allocate memory for additional arguments
   // We allocate for all possible arguments to simplify management
-  args->args      = (cforge_string_t *)malloc((argc - 1) * sizeof(cforge_string_t));
+  args->args      = (cforge_string_t *)malloc((argc - first) * sizeof(cforge_string_t));
   args->arg_count = 0;
 
   if (!args->args) {
@@ -62,7 +69,44 @@ void cforge_parse_args(cforge_int_t argc, cforge_string_t argv[], cforge_command
   }
 
   // Parse the rest of the arguments
-  for (cforge_int_t i = 2; i < argc; i++) {
+  bool after_separator = false;
+  for (cforge_int_t i = first + 1; i < argc; i++) {
+    // --offline, --build-dir and --color are global and not passed on to the
+    // command, so their values can't be taken for positional arguments;
+    // anything after "--" belongs to the program being run
+    if (strcmp(argv[i], "--") == 0) {
+      after_separator = true;
+    } else if (!after_separator && strcmp(argv[i], "--offline") == 0) {
+      args->offline = true;
+      continue;
+    } else if (!after_separator && strcmp(argv[i], "--build-dir") == 0 && i + 1 < argc) {
+      i++;
+      if (args->build_dir) {
+        free(args->build_dir);
+      }
+      args->build_dir = strdup(argv[i]);
+      continue;
+    } else if (!after_separator && strncmp(argv[i], "--build-dir=", 12) == 0) {
+      if (args->build_dir) {
+        free(args->build_dir);
+      }
+      args->build_dir = strdup(argv[i] + 12);
+      continue;
+    } else if (!after_separator && strcmp(argv[i], "--color") == 0 && i + 1 < argc) {
+      i++;
+      if (args->color) {
+        free(args->color);
+      }
+      args->color = strdup(argv[i]);
+      continue;
+    } else if (!after_separator && strncmp(argv[i], "--color=", 8) == 0) {
+      if (args->color) {
+        free(args->color);
+      }
+      args->color = strdup(argv[i] + 8);
+      continue;
+    }
+
     // Store all arguments in args->args for better access in command handlers
     // Use strdup for safety
     args->args[args->arg_count++] = strdup(argv[i]);
@@ -100,6 +144,14 @@ void cforge_parse_args(cforge_int_t argc, cforge_string_t argv[], cforge_command
       args->verbosity = strdup(argv[i]);
       // Also add this to args array
       args->args[args->arg_count++] = strdup(argv[i]);
+    } else if (strcmp(argv[i], "--format") == 0 && i + 1 < argc) {
+      i++;
+      if (args->format) {
+        free(args->format);
+      }
+      args->format = strdup(argv[i]);
+      // Also add this to args array
+      args->args[args->arg_count++] = strdup(argv[i]);
     } else if (args->project == NULL && argv[i][0] != '-') {
       // The first non-option argument is the project
       args->project = strdup(argv[i]);
@@ -133,6 +185,13 @@ void cforge_parse_args(cforge_int_t argc, cforge_string_t argv[], cforge_command
       }
       args->verbosity = strdup(argv[i] + 12);
     }
+    // Handle --format=value format
+    else if (strncmp(argv[i], "--format=", 9) == 0) {
+      if (args->format) {
+        free(args->format);
+      }
+      args->format = strdup(argv[i] + 9);
+    }
   }
 
   // Null-terminate the args array to avoid crashes when no arguments are
@@ -180,6 +239,24 @@ void cforge_free_args(cforge_command_args_t *args) {
     args->verbosity = NULL;
   }
 
+  // Free build directory string
+  if (args->build_dir) {
+    free(args->build_dir);
+    args->build_dir = NULL;
+  }
+
+  // Free format string
+  if (args->format) {
+    free(args->format);
+    args->format = NULL;
+  }
+
+  // Free color string
+  if (args->color) {
+    free(args->color);
+    args->color = NULL;
+  }
+
   // Free all argument strings in the args array
   if (args->args) {
     for (cforge_int_t i = 0; i < args->arg_count; i++) {
@@ -217,4 +294,15 @@ bool cforge_is_quiet(void) {
 
 bool cforge_is_verbose(void) {
   return cforge_get_verbosity() == CFORGE_VERBOSITY_VERBOSE;
+}
+
+// Offline mode
+static bool g_offline = false;
+
+void cforge_set_offline(bool offline) {
+  g_offline = offline;
+}
+
+bool cforge_is_offline(void) {
+  return g_offline;
 }
\ No newline at end of file
@@ -16,10 +16,14 @@ namespace cforge {
 
 // Global flags available to all commands
 const std::vector<flag_def> global_flags = {
-    {"-c", "--config",  "Build configuration (Debug, Release, etc.)", "CONFIG", "", false},
-    {"-v", "--verbose", "Enable verbose output",                      "",       "", false},
-    {"-q", "--quiet",   "Suppress non-essential output",              "",       "", false},
-    {"-h", "--help",    "Show help for this command",                 "",       "", false},
+    {"-c", "--config",    "Build configuration (Debug, Release, etc.)", "CONFIG", "", false},
+    {"",   "--build-dir", "Override the base build directory",          "DIR",    "", false},
+    {"",   "--format",    "Output format (human, json)",                "FORMAT", "", false},
+    {"",   "--color",     "When to use colors (auto, always, never)",   "WHEN",   "", false},
+    {"",   "--offline",   "Never use the network (see 'cforge fetch')", "",       "", false},
+    {"-v", "--verbose",   "Enable verbose output",                      "",       "", false},
+    {"-q", "--quiet",     "Suppress non-essential output",              "",       "", false},
+    {"-h", "--help",      "Show help for this command",                 "",       "", false},
 };
 
 command_registry &command_registry::instance() {
@@ -236,13 +240,15 @@ void command_registry::print_general_help() const {
   };
 
   std::vector<category> categories = {
-      {"Project",      {"init", "migrate", "build", "run", "clean", "test", "bench", "flash"}},
-      {"Dependencies", {"deps", "vcpkg"}                                                     },
+      {"Project",
+       {"init", "migrate", "remove", "set-startup", "config", "check", "list", "build", "run",
+        "script", "foreach", "clean", "test", "coverage", "bench", "flash"}},
+      {"Dependencies", {"deps", "fetch", "vcpkg", "graph"}                                   },
       {"Code Quality", {"fmt", "lint", "circular"}                                           },
-      {"IDE & Tools",  {"ide", "watch", "hot", "doc", "new"}                                 },
-      {"Package",      {"package", "install"}                                                },
+      {"IDE & Tools",  {"ide", "export-presets", "watch", "hot", "doc", "new"}               },
+      {"Package",      {"package", "install", "uninstall"}                                   },
       {"Cache",        {"cache"}                                                             },
-      {"Other",        {"version", "upgrade", "doctor", "completions", "help"}               },
+      {"Other",        {"version", "upgrade", "doctor", "log", "completions", "help"}        },
   };
 
   for (const auto &cat : categories) {
@@ -331,11 +337,34 @@ void register_builtin_commands() {
       "for your platform and handles configuration changes.",
       "build [options] [target]",
       {
-        {"", "--target", "Build specific target", "TARGET", "", false},
+        {"", "--target", "Build specific target, or cross-compile for a cross target", "TARGET",
+         "", false},
         {"", "--jobs", "Number of parallel jobs", "N", "", false},
         {"", "--force", "Force full rebuild", "", "", false},
+        {"-P", "--profile", "Cross-compile with a profile or custom target", "NAME", "", false},
+        {"", "--list-profiles", "List cross profiles and custom targets", "", "", false},
+        {"", "--list-targets", "Same as --list-profiles", "", "", false},
+        {"", "--no-auto-clean", "Fail instead of clearing a cache from another generator", "",
+         "", false},
+        {"", "--arch", "Target architecture for MSVC (x64, x86, arm64)", "ARCH", "x64", false},
+        {"", "--report-json", "Write the per-project timing report as JSON", "FILE", "", false},
+        {"-D", "--define", "Add a compile definition for this build (repeatable)", "NAME[=VALUE]",
+         "", false},
+        {"", "--variant", "Apply build variants; later ones take precedence", "NAMES", "", false},
+        {"", "--use-presets", "Configure with 'cmake --preset' from CMakePresets.json", "", "",
+         false},
+        {"", "--only", "Workspace: build only these projects and their dependencies",
+         "NAMES", "", false},
+        {"", "--exclude", "Workspace: leave these projects out", "NAMES", "", false},
         },
-      {"cforge build", "cforge build --config Release", "cforge build --target mylib"},
+      {"cforge build",
+        "cforge build --config Release",
+        "cforge build --target mylib",
+        "cforge build --profile myboard",
+        "cforge build --report-json build-report.json",
+        "cforge build -D TRACE -D LEVEL=2",
+        "cforge build --config Release --variant asan,profiling",
+        "cforge build --only app,core --exclude experimental"},
       {"run", "clean", "test"},
       false,
       cforge_cmd_build,
@@ -354,9 +383,11 @@ void register_builtin_commands() {
         {"", "--exe", "Create an executable project (default)", "", "", false},
         {"", "--cpp", "C++ standard to use", "STANDARD", "17", false},
         {"", "--template", "Project template (default, embedded)", "NAME", "default", false},
+        {"", "--from-cmake", "Import the CMakeLists.txt in this directory", "", "", false},
         },
       {"cforge init myproject",
-        "cforge init mylib --lib --cpp 20", "cforge init blink --template embedded"},
+        "cforge init mylib --lib --cpp 20", "cforge init blink --template embedded",
+        "cforge init --from-cmake"},
       {"build"},
       false,
       cforge_cmd_init,
@@ -368,13 +399,25 @@ void register_builtin_commands() {
       "run",
       {},
       "Build and run the project",
-      "Compile the project and execute the resulting binary.",
-      "run [options] [-- args]",
+      "Compile the project and execute the resulting binary.\n"
+      "In a workspace, runs the named project, else the startup project set with\n"
+      "'cforge set-startup'. With several startup projects and no default, asks which to run.\n"
+      "--target runs another executable target of the project; without it, cforge runs\n"
+      "[project] default_run_target, else the project's own executable.\n"
+      "WebAssembly builds run in node, or in a browser with --browser.",
+      "run [project] [options] [-- args]",
       {
         {"", "--release", "Build in release mode", "", "", false},
+        {"-p", "--project", "Workspace project to run", "NAME", "", false},
+        {"-t", "--target", "Executable target to run", "TARGET", "", false},
+        {"", "--browser", "Open a WebAssembly build in a browser (emrun)", "", "", false},
         },
-      {"cforge run", "cforge run --config Release -- --arg1 value1"},
-      {"build"},
+      {"cforge run",
+        "cforge run --config Release -- --arg1 value1",
+        "cforge run gui",
+        "cforge run --target cli",
+        "cforge run --no-build --browser"},
+      {"build", "set-startup"},
       false,
       cforge_cmd_run,
       nullptr,
@@ -389,6 +432,8 @@ void register_builtin_commands() {
       "clean [options]",
       {
         {"", "--all", "Also clean cached dependencies", "", "", false},
+        {"", "--kill-holders", "Stop processes that keep build files locked (Windows)", "", "",
+         false},
         },
       {"cforge clean", "cforge clean --all"},
       {"build"},
@@ -407,14 +452,50 @@ void register_builtin_commands() {
       {
         {"", "--filter", "Run only tests matching pattern", "PATTERN", "", false},
         {"", "--verbose", "Show test output", "", "", false},
+        {"", "--init", "Scaffold a sample test file in the test directory", "", "", false},
+        {"", "--framework", "Framework used by --init", "NAME", "builtin", false},
+        {"-j", "--jobs", "Test targets to run in parallel (default: CPU count)", "N", "", false},
+        {"", "--parallel", "Same as --jobs", "N", "", false},
+        {"-L", "--label", "Run only targets with a label matching REGEX", "REGEX", "", false},
+        {"-E", "--exclude", "Skip targets whose name matches REGEX", "REGEX", "", false},
+        {"", "--timeout", "Per-target timeout, overriding cforge.toml", "SECONDS", "", false},
+        {"",
+         "--repeat",
+         "Re-run targets: N (same as until-fail:N), until-pass:N, until-fail:N, after-timeout:N",
+         "MODE:N",
+         "",
+         false},
         },
-      {"cforge test", "cforge test --filter '*unit*'"},
+      {"cforge test",
+       "cforge test --filter '*unit*'",
+       "cforge test -j 4 --repeat until-pass:3",
+       "cforge test --label integration --exclude slow --repeat 3",
+       "cforge test --init --framework catch2"},
       {"build", "bench"},
       false,
       cforge_cmd_test,
       nullptr,
   });
 
+  reg.register_command({
+      "coverage",
+      {},
+      "Report test coverage",
+      "Build the tests with coverage instrumentation in <build>/coverage, run them and write an "
+      "HTML and a text report. GCC builds are reported with gcovr, Clang builds with llvm-cov. "
+      "Other options are passed on to 'cforge test'.",
+      "coverage [options] [filter]",
+      {
+        {"-o", "--output", "Report directory", "DIR", "coverage", false},
+        {"-c", "--config", "Build configuration", "CONFIG", "Debug", false},
+        },
+      {"cforge coverage", "cforge coverage --filter 'math::*'", "cforge coverage -o out/cov"},
+      {"test"},
+      false,
+      cforge_cmd_coverage,
+      nullptr,
+  });
+
   // Errors — replay the most recent build's errors.
   reg.register_command({
       "errors",
@@ -448,15 +529,49 @@ void register_builtin_commands() {
       nullptr,
   });
 
+  // Log — raw output of the most recent configure or build
+  reg.register_command({
+      "log",
+      {},
+      "Print the output of the last build",
+      "Print the unfiltered output of the most recent CMake configure or build.\n"
+      "Each step writes <build>/.cforge/logs/<configure|build>-<timestamp>.log;\n"
+      "the newest [build] keep_logs logs of each kind are kept (default 5).",
+      "log [options]",
+      {
+        {"", "--configure", "Show the last configure log", "", "", false},
+        {"", "--build", "Show the last build log", "", "", false},
+        {"-n", "--lines", "Show only the last N lines", "N", "", false},
+        {"", "--path", "Print the log's path instead of its contents", "", "", false},
+        },
+      {"cforge log", "cforge log --configure", "cforge log -n 50"},
+      {"build", "errors"},
+      false,
+      cforge_cmd_log,
+      nullptr,
+  });
+
   // Deps command
   reg.register_command({
       "deps",
       {},
       "Manage dependencies",
-      "Add, remove, and manage project dependencies from the registry.",
+      "Add, remove, and manage project dependencies from the registry.\n"
+      "Dependencies can also come from git, vcpkg, or conan:\n"
+      "  deps add git <url> [--tag <tag>] [--name <name>]\n"
+      "  deps add vcpkg <pkg>[:triplet]\n"
+      "  deps add conan <pkg>/<version>\n"
+      "Pass --no-install to only edit cforge.toml.\n"
+      "Builds record resolved versions in cforge.lock; 'deps update' drops it\n"
+      "so the next build resolves git refs again.",
       "deps <subcommand> [options]",
       {},
-      {"cforge deps add fmt", "cforge deps remove spdlog", "cforge deps search json"},
+      {"cforge deps add fmt",
+        "cforge deps add git https://github.com/gabime/spdlog --tag v1.14.1",
+        "cforge deps add vcpkg openssl:x64-linux --no-install",
+        "cforge deps add conan zlib/1.3.1",
+        "cforge deps remove spdlog",
+        "cforge deps search json"},
       {},
       false,
       cforge_cmd_deps,
@@ -503,8 +618,11 @@ void register_builtin_commands() {
       "fmt [options] [files]",
       {
         {"", "--check", "Check formatting without making changes", "", "", false},
+        {"", "--dry-run", "Show which files would change", "", "", false},
+        {"", "--init", "Create a .clang-format from --style", "", "", false},
+        {"", "--style", "clang-format style (file, llvm, google, ...)", "STYLE", "file", false},
         },
-      {"cforge fmt", "cforge fmt --check"},
+      {"cforge fmt", "cforge fmt --check", "cforge fmt --init --style google"},
       {"lint"},
       false,
       cforge_cmd_fmt,
@@ -514,14 +632,18 @@ void register_builtin_commands() {
   // Lint command
   reg.register_command({
       "lint",
-      {"check"},
+      {},
       "Run static analysis",
-      "Run clang-tidy static analysis on project source files.",
+      "Run clang-tidy static analysis on project source files.\n"
+      "Files are analyzed in parallel; defaults come from the [lint] section of cforge.toml.",
       "lint [options] [files]",
       {
-        {"", "--fix", "Automatically apply fixes", "", "", false},
+        {"", "--fix", "Automatically apply fixes (runs serially)", "", "", false},
+        {"", "--checks", "clang-tidy checks to enable", "CHECKS", "", false},
+        {"-j", "--jobs", "Number of files to analyze in parallel", "N", "", false},
+        {"-c", "--config", "Build configuration to analyze", "CONFIG", "", false},
         },
-      {"cforge lint", "cforge lint --fix"},
+      {"cforge lint", "cforge lint --fix", "cforge lint --checks 'bugprone-*' src/"},
       {"fmt"},
       false,
       cforge_cmd_lint,
@@ -535,17 +657,22 @@ void register_builtin_commands() {
       "Run benchmarks",
       "Build and run benchmarks using the detected benchmark framework.\n"
       "Supports Google Benchmark, nanobench, and Catch2 BENCHMARK.\n"
-      "Benchmarks run in Release mode by default for accurate timing.",
-      "bench [options] [benchmark-name]",
+      "Benchmarks run in Release mode by default for accurate timing.\n"
+      "Arguments after -- are passed to the benchmark binaries unchanged.",
+      "bench [options] [benchmark-name] [-- args...]",
       {
+        {"-c", "--config", "Build configuration", "CONFIG", "Release", false},
         {"", "--no-build", "Skip building before running", "", "", false},
         {"", "--filter", "Run only benchmarks matching pattern", "PATTERN", "", false},
         {"", "--json", "Output in JSON format", "", "", false},
         {"", "--csv", "Output in CSV format", "", "", false},
+        {"", "--out", "Write results to a JSON file", "FILE", "", false},
+        {"", "--allow-debug", "Allow running benchmarks in a Debug build", "", "", false},
         },
       {"cforge bench",
         "cforge bench --filter 'BM_Sort'", "cforge bench --no-build",
-        "cforge bench --json > results.json"},
+        "cforge bench --out results.json",
+        "cforge bench -- --benchmark_min_time=0.5s"},
       {"test"},
       false,
       cforge_cmd_bench,
@@ -560,9 +687,10 @@ void register_builtin_commands() {
       "Generate installers and archives for distribution.",
       "package [options]",
       {
-        {"", "--generator", "CPack generator to use", "GEN", "", false},
+        {"", "--format", "Package formats, e.g. deb,zip (replaces package.formats)", "FORMATS", "",
+         false},
         },
-      {"cforge package", "cforge package --generator ZIP"},
+      {"cforge package", "cforge package --format deb", "cforge package --format zip,nsis"},
       {"build", "install"},
       false,
       cforge_cmd_package,
@@ -578,23 +706,60 @@ void register_builtin_commands() {
       "install [options]",
       {
         {"", "--prefix", "Installation prefix", "PATH", "", false},
+        {"", "--destdir", "Stage the install under this root (DESTDIR)", "DIR", "", false},
+        {"", "--component", "Install one component (Runtime, Headers, Resources)", "NAME", "",
+         false},
+        {"", "--strip", "Strip binaries while installing", "", "", false},
+        {"", "--dry-run", "List the files that would be installed", "", "", false},
         },
-      {"cforge install", "cforge install --prefix /usr/local"},
-      {"build", "package"},
+      {"cforge install",
+       "cforge install --prefix /usr/local",
+       "cforge install --prefix /usr --destdir /tmp/stage --strip",
+       "cforge install --component Headers --dry-run"},
+      {"build", "package", "uninstall"},
       false,
       cforge_cmd_install,
       nullptr,
   });
 
+  // Uninstall command
+  reg.register_command({
+      "uninstall",
+      {},
+      "Remove an installed project",
+      "Remove the files recorded by 'cforge install --prefix', then the directories it\n"
+      "left empty. Without a project name, the project in the current directory is\n"
+      "uninstalled.",
+      "uninstall [project] [options]",
+      {
+        {"", "--prefix", "Uninstall the install in this prefix", "PATH", "", false},
+        {"", "--force", "Also remove listed files outside the prefix", "", "", false},
+        {"", "--dry-run", "List the files that would be removed", "", "", false},
+        {"", "--list", "List the recorded installs", "", "", false},
+        },
+      {"cforge uninstall",
+       "cforge uninstall mylib --prefix ~/.local",
+       "cforge uninstall --dry-run",
+       "cforge uninstall --list"},
+      {"install"},
+      false,
+      cforge_cmd_uninstall,
+      nullptr,
+  });
+
   // IDE command
   reg.register_command({
       "ide",
       {},
       "Generate IDE configurations",
-      "Generate project files for various IDEs.",
-      "ide <vscode|clion|vs|xcode>",
-      {},
-      {"cforge ide vscode", "cforge ide vs"},
+      "Generate project files for various IDEs.\n"
+      "Existing VS Code and CLion files are merged: missing entries are added\n"
+      "and entries you already have are kept unless --force is given.",
+      "ide <vscode|clion|vs|xcode|cb> [options]",
+      {
+        {"-f", "--force", "Overwrite existing IDE configuration files", "", "", false},
+        },
+      {"cforge ide vscode", "cforge ide clion", "cforge ide vscode --force", "cforge ide vs"},
       {},
       false,
       cforge_cmd_ide,
@@ -668,17 +833,191 @@ void register_builtin_commands() {
   reg.register_command({
       "new",
       {},
-      "Create files from templates",
-      "Generate source files from built-in templates.",
-      "new <class|header|interface|test|struct> <name>",
-      {},
-      {"cforge new class MyClass", "cforge new test MyClass"},
+      "Create projects and files from templates",
+      "Create a new project directory from a project template, or generate source files in "
+      "the current project from built-in file templates.",
+      "new <name> [--template <name>] | new <class|header|interface|test|struct> <name>",
+      {
+        {"-t", "--template", "Project template (app, lib, header-only, gui, test-driven)", "NAME",
+         "app", false},
+        {"", "--list-templates", "List the project templates", "", "", false},
+        {"", "--std", "C++ standard for a new project", "VERSION", "17", false},
+        {"", "--git", "Run git init in the new project", "", "", false},
+        {"-n", "--namespace", "Wrap generated files in a namespace", "NAME", "", false},
+        {"-o", "--output", "Output directory for generated files", "DIR", "", false},
+        {"-f", "--force", "Overwrite existing files", "", "", false},
+      },
+      {"cforge new my_app", "cforge new mylib --template lib --git", "cforge new --list-templates",
+       "cforge new class MyClass", "cforge new test MyClass"},
       {},
       false,
       cforge_cmd_new,
       nullptr,
   });
 
+  // List command
+  reg.register_command({
+      "list",
+      {},
+      "List project and workspace information",
+      "List build configurations, generators, targets, commands and settings. In a workspace,\n"
+      "'list projects' shows each project's type, build status for the selected configuration,\n"
+      "workspace dependencies and the startup project.",
+      "list [projects|order|dependencies|configs|generators|targets|commands|settings|variants]",
+      {
+        {"-c", "--config", "Configuration to report build status for", "CONFIG", "", false},
+        },
+      {"cforge list projects",
+        "cforge list projects --config Release",
+        "cforge list order",
+        "cforge list variants"},
+      {"build", "deps"},
+      false,
+      cforge_cmd_list,
+      nullptr,
+  });
+
+  // Script command
+  reg.register_command({
+      "script",
+      {},
+      "Run a named script",
+      "Run a script from [scripts.scripts] in cforge.toml. Scripts listed in its `needs` run\n"
+      "first, each once, in dependency order. Arguments after '--' are forwarded to the\n"
+      "requested script. Without a name, lists the available scripts.",
+      "script [name] [-- args...]",
+      {},
+      {"cforge script format", "cforge script deploy -- --env staging", "cforge script"},
+      {"list", "build"},
+      false,
+      cforge_cmd_script,
+      nullptr,
+  });
+
+  // Fetch command
+  reg.register_command({
+      "fetch",
+      {},
+      "Download dependencies without building",
+      "Do all of a build's network work up front: clone Git and index dependencies, download\n"
+      "archive dependencies, install vcpkg ports and conan packages. Nothing is configured\n"
+      "or built, so a later 'cforge build --offline' can run without network access. In a\n"
+      "workspace, fetches the dependencies of every project.",
+      "fetch [--refresh]",
+      {
+        {"", "--refresh", "Download archive dependencies again", "", "", false},
+        },
+      {"cforge fetch", "cforge fetch && cforge build --offline", "cforge fetch --refresh"},
+      {"build", "deps"},
+      false,
+      cforge_cmd_fetch,
+      nullptr,
+  });
+
+  // Remove command
+  reg.register_command({
+      "remove",
+      {},
+      "Remove a workspace project or a dependency",
+      "Remove a project from the workspace configuration, including its startup entries.\n"
+      "The project directory is kept unless --delete is given. Outside a workspace, or when\n"
+      "the name is not a workspace project, removes a dependency like 'cforge deps remove'.",
+      "remove <project|package> [--delete]",
+      {
+        {"", "--delete", "Also delete the project directory (asks first)", "", "", false},
+        {"-y", "--yes", "Delete without asking", "", "", false},
+        },
+      {"cforge remove tools", "cforge remove tools --delete", "cforge remove fmt"},
+      {"deps", "graph"},
+      false,
+      cforge_cmd_remove_project,
+      nullptr,
+  });
+
+  // Set-startup command
+  reg.register_command({
+      "set-startup",
+      {},
+      "Set the workspace's startup project",
+      "Set the project 'cforge run' starts in a workspace when no project is named.\n"
+      "Writes default_startup_project to the workspace configuration.",
+      "set-startup <project>",
+      {},
+      {"cforge set-startup gui"},
+      {"run", "list"},
+      false,
+      cforge_cmd_set_startup,
+      nullptr,
+  });
+
+  // Config command
+  reg.register_command({
+      "config",
+      {},
+      "Get or set cforge.toml values",
+      "Read, set or remove a cforge.toml value by dotted path. Edits keep the\n"
+      "file's comments and formatting, and are refused if the result is not valid\n"
+      "TOML or would change the type of an existing value. Values that are not\n"
+      "booleans, numbers, arrays or inline tables are written as strings.",
+      "config <get|set|unset> <key> [value] [options]",
+      {
+        {"", "--workspace", "Edit the workspace configuration instead", "", "", false},
+        },
+      {"cforge config get build.build_type",
+       "cforge config set build.build_type Release",
+       "cforge config set build.include_dirs '[\"include\", \"third_party\"]'",
+       "cforge config unset pch",
+       "cforge config get workspace.projects --workspace"},
+      {"init"},
+      false,
+      cforge_cmd_config,
+      nullptr,
+  });
+
+  // Graph command
+  reg.register_command({
+      "graph",
+      {},
+      "Show workspace project dependencies",
+      "Print the dependency graph between workspace projects and check it for\n"
+      "cycles, self-dependencies and references to non-member projects.",
+      "graph [options]",
+      {
+        {"", "--dot", "Also write the graph in Graphviz DOT format", "FILE", "", false},
+        },
+      {"cforge graph", "cforge graph --dot deps.dot"},
+      {"deps", "build"},
+      false,
+      cforge_cmd_graph,
+      nullptr,
+  });
+
+  // Foreach command
+  reg.register_command({
+      "foreach",
+      {},
+      "Run a command in every workspace project",
+      "Run a command in each workspace project's directory, dependencies first.\n"
+      "A single argument after '--' runs through the shell; several arguments\n"
+      "are a program and its arguments. Ends with each project's exit code.",
+      "foreach [options] -- <command...>",
+      {
+        {"", "--parallel", "Run projects that don't depend on each other at once", "", "",
+         false},
+        {"-j", "--jobs", "Projects to run at once (implies --parallel)", "N", "", false},
+        {"-k", "--continue-on-error", "Keep going after a project fails", "", "", false},
+        {"-f", "--filter", "Only projects matching a name or glob (repeatable)", "PATTERN", "",
+         false},
+        },
+      {"cforge foreach -- git status",
+        "cforge foreach --parallel -- cforge clean",
+        "cforge foreach --filter 'lib*' -- \"cforge test && cforge bench\""},
+      {"build", "graph", "script"},
+      false,
+      cforge_cmd_foreach,
+      nullptr,
+  });
+
   // Circular command
   reg.register_command({
       "circular",
@@ -742,31 +1081,91 @@ void register_builtin_commands() {
       nullptr,
   });
 
+  // Export presets command
+  reg.register_command({
+      "export-presets",
+      {},
+      "Write CMakePresets.json from cforge.toml",
+      "Translate the build settings in cforge.toml into a CMakePresets.json with a\n"
+      "configure, build and test preset per build configuration, for IDEs and raw\n"
+      "CMake. The file is written with sorted keys so it can be committed.",
+      "export-presets [options]",
+      {
+        {"-o", "--output", "Output file path", "FILE", "CMakePresets.json", false},
+        {"-f", "--force", "Overwrite a CMakePresets.json not generated by cforge", "", "", false},
+        },
+      {"cforge export-presets", "cforge export-presets --output presets/CMakePresets.json"},
+      {"build", "ide"},
+      false,
+      cforge_cmd_export_presets,
+      nullptr,
+  });
+
   // Doctor command
   reg.register_command({
       "doctor",
       {},
       "Diagnose environment",
-      "Check system for required tools and report any issues.",
-      "doctor",
-      {},
-      {"cforge doctor"},
+      "Check system for required tools and report any issues.\n\n"
+      "Checks CMake (and its minimum version), a C++ compiler, the build tool of\n"
+      "the CMake generator, optional tools, free disk space and compilers that\n"
+      "conflict on PATH. Inside a project it also validates cforge.toml and the\n"
+      "vcpkg or conan setup the project uses. Exits non-zero if a check fails.",
+      "doctor [options]",
+      {
+        {"", "--json", "Output the checks as JSON", "", "", false},
+      },
+      {"cforge doctor", "cforge doctor --json"},
       {},
       false,
       cforge_cmd_doctor,
       nullptr,
   });
 
-  // Version command
+  // Check command
   reg.register_command({
-      "version",
+      "check",
       {},
-      "Show version information",
-      "Display cforge version and build information.",
+      "Validate cforge.toml without building",
+      "Check cforge.toml the way a build would, without configuring or building:\n"
+      "TOML syntax, unknown keys, the binary type, listed include directories,\n"
+      "source directories and globs that match nothing, and whether each\n"
+      "dependency can be found. Dependencies are checked without downloading\n"
+      "them: index packages are looked up in the package index, git repositories\n"
+      "are asked for their tag or branch and archive URLs get a HEAD request.\n"
+      "In a workspace every project is checked. Exits non-zero on errors.",
+      "check [options]",
+      {
+        {"", "--no-fetch", "Skip the network checks of dependency sources", "", "", false},
+        },
+      {"cforge check", "cforge check --no-fetch"},
+      {"doctor", "fetch"},
+      false,
+      cforge_cmd_check,
+      nullptr,
+  });
+
+  // Version command
+  reg.register_command({
       "version",
       {},
-      {"cforge version"},
-      {},
+      "Show or bump the project version",
+      "Display the cforge version and the project's version. 'bump' rewrites\n"
+      "project.version in cforge.toml, keeping its formatting, and regenerates\n"
+      "CMakeLists.txt and cforge_build_info.h from it. With --workspace every\n"
+      "project is set to the same version and [dependencies.project.<name>]\n"
+      "version constraints are updated to match.",
+      "version [bump <major|minor|patch>] [options]",
+      {
+        {"", "--set", "Set this version instead of bumping", "VERSION", "", false},
+        {"", "--workspace", "Bump every workspace project in lockstep", "", "", false},
+        {"", "--tag", "Commit the bump and tag it v<version>; needs a clean tree", "", "", false},
+        },
+      {"cforge version",
+       "cforge version bump patch",
+       "cforge version bump --set 2.1.0",
+       "cforge version bump minor --workspace --tag"},
+      {"package"},
       false,
       cforge_cmd_version,
       nullptr,
@@ -826,15 +1225,12 @@ void register_builtin_commands() {
 
   reg.register_deprecated({"add", "deps add", "Use 'cforge deps add <package>' instead."});
 
-  reg.register_deprecated({"remove", "deps remove", "Use 'cforge deps remove <package>' instead."});
-
   reg.register_deprecated({"update", "deps update", "Use 'cforge deps update' instead."});
 
   reg.register_deprecated({"search", "deps search", "Use 'cforge deps search <query>' instead."});
 
   reg.register_deprecated({"info", "deps info", "Use 'cforge deps info <package>' instead."});
 
-  reg.register_deprecated({"list", "deps list", "Use 'cforge deps list' instead."});
 
   reg.register_deprecated({"tree", "deps tree", "Use 'cforge deps tree' instead."});
 
@@ -5,9 +5,13 @@
 
 #include "cforge/log.hpp"
 
+#include "core/build_config.hpp"
+#include "core/build_utils.hpp"
 #include "core/commands.hpp"
 #include "core/constants.h"
+#include "core/dependency_specs.hpp"
 #include "core/file_system.h"
+#include "core/manifest_edit.hpp"
 #include "core/process_utils.hpp"
 #include "core/registry.hpp"
 #include "core/toml_reader.hpp"
@@ -15,6 +19,7 @@
 #include "core/workspace.hpp"
 #include "core/workspace_utils.hpp"
 
+#include <algorithm>
 #include <atomic>
 #include <chrono>
 #include <filesystem>
@@ -82,18 +87,34 @@
   return true;
 }
 
+/**
+ * @brief Check whether vcpkg has a package installed for a triplet
+ *
+ * @param vcpkg_exe vcpkg executable
+ * @param package_name Package name
+ * @param triplet Triplet the package must be built for
+ * @return true if `vcpkg list` shows the package for exactly that triplet
+ */
+static bool is_vcpkg_package_installed(const std::string &vcpkg_exe,
+                                       const std::string &package_name,
+                                       const std::string &triplet) {
+  auto result = cforge::execute_process(vcpkg_exe, {"list", package_name}, "", nullptr, nullptr);
+  return result.success
+      && cforge::vcpkg_list_has_package(result.stdout_output, package_name, triplet);
+}
+
 /**
  * @brief Run vcpkg to install the package
  *
  * @param project_dir Directory containing the project
  * @param package_name Name of the package to install
- * @param package_version Version of the package (optional)
+ * @param triplet vcpkg triplet to install for (optional)
  * @param verbose Show verbose output
  * @return true if successful, false otherwise
  */
 static bool install_package_with_vcpkg(const std::filesystem::path &project_dir,
                                        const std::string &package_name,
-                                       const std::string &package_version,
+                                       const std::string &triplet,
                                        bool verbose) {
   // Determine vcpkg executable: try project-local then global
   std::filesystem::path project_vcpkg_exe;
@@ -128,14 +149,39 @@ static bool install_package_with_vcpkg(const std::filesystem::path &project_dir,
     }
   }
 
+  // An explicit pkg:triplet wins over [dependencies.vcpkg] triplet and the
+  // triplet derived from the host
+  std::string resolved_triplet = triplet;
+  cforge::toml_reader project_config;
+  bool has_project_config = project_config.load((project_dir / CFORGE_FILE).string());
+  if (resolved_triplet.empty()) {
+    resolved_triplet = has_project_config ? cforge::resolve_vcpkg_triplet(project_config)
+                                          : cforge::default_vcpkg_triplet("", "");
+  }
+  if (has_project_config) {
+    cforge::apply_vcpkg_binary_cache(project_config, project_dir);
+  }
+
+  if (!resolved_triplet.empty() && !cforge::is_valid_vcpkg_triplet(resolved_triplet)) {
+    cforge::logger::print_error("invalid vcpkg triplet '" + resolved_triplet + "'");
+    cforge::logger::print_hint("triplets are lower-case words separated by dashes, "
+                               "e.g. x64-windows-static");
+    return false;
+  }
+
   // Prepare the package spec
   std::string package_spec = package_name;
-  if (!package_version.empty()) {
-    package_spec += ":" + package_version;
+  if (!resolved_triplet.empty()) {
+    package_spec += ":" + resolved_triplet;
+  }
+
+  std::string command = vcpkg_exe.string();
+  if (!resolved_triplet.empty()
+      && is_vcpkg_package_installed(command, package_name, resolved_triplet)) {
+    cforge::logger::print_action("Skipping", package_spec + " is already installed");
+    return true;
   }
 
-  // Build the command
-  std::string command           = vcpkg_exe.string();
   std::vector<std::string> args = {"install", package_spec};
 
   // Run the command
@@ -314,85 +360,30 @@ static bool clone_git_repo(const std::string &url,
   return true;
 }
 
-// Helpers to add dependencies to a specific TOML section
+// Helpers to add dependencies to a specific TOML section. The file is edited
+// as text, so the user's comments and layout are kept, and only written when
+// the result parses, so a dependency entry can't leave cforge.toml unreadable
 static bool add_dependency_to_section(const std::filesystem::path &config_file,
                                       const std::string &section,
                                       const std::string &entry,
                                       bool verbose) {
-  // Read existing config file
-  std::ifstream file(config_file);
-  if (!file) {
-    cforge::logger::print_error("Failed to read configuration file: " + config_file.string());
+  std::string key;
+  std::string value;
+  cforge::split_manifest_key(entry, key, value);
+  std::string path = section + "." + key;
+  if (!cforge::is_editable_manifest_path(path)) {
+    cforge::logger::print_error("'" + key + "' can't be written as a TOML key");
+    cforge::logger::print_hint("add `" + entry + "` to [" + section + "] by hand");
     return false;
   }
 
-  std::vector<std::string> lines;
-  std::string line;
-  bool in_section    = false;
-  bool section_found = false;
-  cforge_int_t section_end    = -1;
-
-  // Read all lines and find the section
-  std::string section_header = "[" + section + "]";
-  while (std::getline(file, line)) {
-    lines.push_back(line);
-
-    // Trim whitespace for comparison
-    std::string trimmed = line;
-    cforge_size_t start        = trimmed.find_first_not_of(" \t");
-    if (start != std::string::npos) {
-      trimmed = trimmed.substr(start);
-    }
-
-    // Check if this is exactly our section (not a subsection like
-    // [dependencies.git])
-    if (trimmed == section_header) {
-      in_section    = true;
-      section_found = true;
-      continue;
-    }
-
-    // Check if we're leaving the section (new section starts)
-    if (in_section && !trimmed.empty() && trimmed[0] == '[') {
-      section_end = lines.size() - 1;
-      in_section  = false;
-    }
-  }
-  file.close();
-
-  // If section not found, create it
-  if (!section_found) {
-    if (verbose) {
-      cforge::logger::print_verbose("Creating new section: [" + section + "]");
-    }
-    // Add blank line before new section if file doesn't end with blank line
-    if (!lines.empty() && !lines.back().empty()) {
-      lines.push_back("");
-    }
-    lines.push_back(section_header);
-    section_end = lines.size();
-  }
-
-  // If we never found the end of the section, it's at the end of the file
-  if (section_end == -1) {
-    section_end = lines.size();
-  }
-
-  // Insert the new entry at the end of the section
-  lines.insert(lines.begin() + section_end, entry);
-
-  // Write back to file
-  std::ofstream outfile(config_file);
-  if (!outfile) {
-    cforge::logger::print_error("Failed to write configuration file: " + config_file.string());
+  auto add_entry = [&](std::string &content) {
+    return cforge::set_manifest_value(content, path, value);
+  };
+  if (!cforge::write_manifest_edit(config_file, add_entry, path)) {
     return false;
   }
 
-  for (const auto &l : lines) {
-    outfile << l << "\n";
-  }
-  outfile.close();
-
   if (verbose) {
     cforge::logger::print_verbose("Added dependency to section [" + section + "]: " + entry);
   }
@@ -404,14 +395,28 @@ static bool add_vcpkg_dependency_to_config(const std::filesystem::path & /*proje
                                            const std::filesystem::path &config_file,
                                            const std::string &package_name,
                                            const std::string &package_version,
+                                           const std::string &triplet,
+                                           const std::vector<std::string> &features,
                                            bool verbose) {
   // New consolidated format under [dependencies]
-  std::string entry;
-  if (package_version.empty()) {
-    entry = package_name + " = { source = \"vcpkg\" }";
-  } else {
-    entry = package_name + " = { source = \"vcpkg\", version = \"" + package_version + "\" }";
+  std::string entry = package_name + " = { source = \"vcpkg\"";
+  if (!package_version.empty()) {
+    entry += ", version = \"" + package_version + "\"";
+  }
+  if (!triplet.empty()) {
+    entry += ", triplet = \"" + triplet + "\"";
   }
+  if (!features.empty()) {
+    entry += ", features = [";
+    for (cforge_size_t i = 0; i < features.size(); ++i) {
+      if (i > 0) {
+        entry += ", ";
+      }
+      entry += "\"" + features[i] + "\"";
+    }
+    entry += "]";
+  }
+  entry += " }";
   return add_dependency_to_section(config_file, "dependencies", entry, verbose);
 }
 
@@ -427,12 +432,38 @@ static bool add_git_dependency_to_config(const std::filesystem::path & /*project
     return false;
   }
 
-  // New consolidated format under [dependencies]
-  std::string entry = package_name + " = { git = \"" + package_url + "\"";
-  if (!tag.empty()) {
-    entry += ", tag = \"" + tag + "\"";
+  // Git dependencies live in their own [dependencies.git.<name>] table, which
+  // is what the build and CMake generation read
+  std::string table = "dependencies.git." + package_name;
+  if (!cforge::is_editable_manifest_path(table)) {
+    cforge::logger::print_error("'" + package_name + "' can't be written as a TOML key");
+    return false;
   }
-  entry += " }";
+  auto add_table = [&](std::string &content) {
+    cforge::set_manifest_value(content, table + ".url", "\"" + package_url + "\"");
+    if (!tag.empty()) {
+      cforge::set_manifest_value(content, table + ".tag", "\"" + tag + "\"");
+    }
+    return true;
+  };
+  if (!cforge::write_manifest_edit(config_file, add_table, table + ".url")) {
+    return false;
+  }
+
+  if (verbose) {
+    cforge::logger::print_verbose("Added git dependency table [dependencies.git." + package_name
+                                  + "]");
+  }
+
+  return true;
+}
+
+static bool add_conan_dependency_to_config(const std::filesystem::path &config_file,
+                                           const std::string &package_name,
+                                           const std::string &package_version,
+                                           bool verbose) {
+  std::string entry = package_name + " = { source = \"conan\", version = \"" + package_version
+                    + "\" }";
   return add_dependency_to_section(config_file, "dependencies", entry, verbose);
 }
 
@@ -473,6 +504,61 @@ static bool add_index_dependency_to_config(const std::filesystem::path & /*proje
   return add_dependency_to_section(config_file, "dependencies", entry, verbose);
 }
 
+/**
+ * @brief Check whether a dependency with the given name is already declared
+ * in any dependency section of the configuration
+ */
+static bool dependency_already_declared(const std::filesystem::path &config_file,
+                                        const std::string &name) {
+  cforge::toml_reader config;
+  if (!config.load(config_file.string())) {
+    return false;
+  }
+
+  for (const char *section : {"dependencies.",
+                              "dependencies.git.",
+                              "dependencies.vcpkg.",
+                              "dependencies.system.",
+                              "dependencies.subdirectory."}) {
+    if (config.has_key(section + name)) {
+      return true;
+    }
+  }
+
+  auto vcpkg_packages = config.get_string_array("dependencies.vcpkg.packages");
+  return std::find(vcpkg_packages.begin(), vcpkg_packages.end(), name) != vcpkg_packages.end();
+}
+
+/**
+ * @brief Flip `enabled = false` to `enabled = true` in a package manager
+ * block such as [dependencies.vcpkg] so a newly added dependency is used
+ */
+static bool enable_package_manager_block(const std::filesystem::path &config_file,
+                                         const std::string &manager,
+                                         bool verbose) {
+  cforge::toml_reader config;
+  if (!config.load(config_file.string())) {
+    return false;
+  }
+
+  std::string key = "dependencies." + manager + ".enabled";
+  if (!config.has_key(key) || config.get_bool(key, true)) {
+    return true;
+  }
+
+  auto enable = [&](std::string &content) {
+    return cforge::set_manifest_value(content, key, "true");
+  };
+  if (!cforge::write_manifest_edit(config_file, enable, key)) {
+    return false;
+  }
+
+  if (verbose) {
+    cforge::logger::print_verbose("Enabled [dependencies." + manager + "]");
+  }
+  return true;
+}
+
 /**
  * @brief Handle the 'add' command
  *
@@ -493,24 +579,55 @@ cforge_int_t cforge_cmd_add(const cforge_context_t *ctx) {
   }
 
   // Parse flags and arguments
-  bool mode_git = false, mode_vcpkg = false, mode_index = false;
+  bool mode_git = false, mode_vcpkg = false, mode_index = false, mode_conan = false;
   bool header_only = false;
+  bool no_install  = false;
+  bool git_subcommand = false;
   std::string tag_value;
+  std::string name_value;
   std::vector<std::string> features;
   std::vector<std::string> args;
   for (cforge_int_t i = 0; i < ctx->args.arg_count; ++i) {
     args.push_back(ctx->args.args[i]);
   }
+
+  // Subcommand form: 'add vcpkg <pkg>', 'add git <url>', 'add conan <ref>'
+  if (!args.empty()) {
+    if (args[0] == "vcpkg") {
+      mode_vcpkg = true;
+      args.erase(args.begin());
+    } else if (args[0] == "git") {
+      mode_git       = true;
+      git_subcommand = true;
+      args.erase(args.begin());
+    } else if (args[0] == "conan") {
+      mode_conan = true;
+      args.erase(args.begin());
+    }
+  }
+
   std::vector<std::string> filtered;
   for (cforge_size_t i = 0; i < args.size(); ++i) {
     if (args[i] == "--git") {
       mode_git = true;
     } else if (args[i] == "--vcpkg") {
       mode_vcpkg = true;
+    } else if (args[i] == "--conan") {
+      mode_conan = true;
     } else if (args[i] == "--index") {
       mode_index = true;
     } else if (args[i] == "--header-only") {
       header_only = true;
+    } else if (args[i] == "--no-install") {
+      no_install = true;
+    } else if (args[i] == "--name") {
+      if (i + 1 < args.size()) {
+        name_value = args[i + 1];
+        ++i;
+      } else {
+        cforge::logger::print_error("--name flag requires a value");
+        return 1;
+      }
     } else if (args[i] == "--tag") {
       if (i + 1 < args.size()) {
         tag_value = args[i + 1];
@@ -545,9 +662,11 @@ cforge_int_t cforge_cmd_add(const cforge_context_t *ctx) {
   args.swap(filtered);
 
   // Count modes
-  cforge_int_t mode_count = (mode_git ? 1 : 0) + (mode_vcpkg ? 1 : 0) + (mode_index ? 1 : 0);
+  cforge_int_t mode_count = (mode_git ? 1 : 0) + (mode_vcpkg ? 1 : 0) + (mode_index ? 1 : 0)
+                          + (mode_conan ? 1 : 0);
   if (mode_count > 1) {
-    cforge::logger::print_error("Cannot use multiple source flags (--git, --vcpkg, --index)");
+    cforge::logger::print_error(
+        "Cannot use multiple source flags (--git, --vcpkg, --conan, --index)");
     return 1;
   }
 
@@ -565,7 +684,10 @@ cforge_int_t cforge_cmd_add(const cforge_context_t *ctx) {
                                  "cforge add spdlog --features async,fmt_external");
     cforge::logger::print_action("Git dependency",
                                  "cforge add --git mylib https://github.com/user/lib --tag v1.0");
-    cforge::logger::print_action("vcpkg package", "cforge add openssl --vcpkg");
+    cforge::logger::print_action("Git (by URL)",
+                                 "cforge add git https://github.com/user/lib --tag v1.0");
+    cforge::logger::print_action("vcpkg package", "cforge add vcpkg openssl:x64-linux");
+    cforge::logger::print_action("conan package", "cforge add conan zlib/1.3.1");
     return 1;
   }
 
@@ -573,14 +695,47 @@ cforge_int_t cforge_cmd_add(const cforge_context_t *ctx) {
   std::string package_name = args[0];
   std::string package_version;
   std::string package_url;
+  std::string vcpkg_triplet;
 
-  if (mode_git) {
+  if (git_subcommand) {
+    // 'add git <url> [--name foo]' - name defaults to the repository name
+    package_url  = args[0];
+    package_name = name_value.empty() ? cforge::git_url_to_name(package_url) : name_value;
+  } else if (mode_git) {
     if (args.size() < 2) {
       cforge::logger::print_error("URL for git dependency not specified");
       cforge::logger::print_action("Usage", "cforge add --git <name> <url> [--tag <version>]");
       return 1;
     }
     package_url = args[1];
+  } else if (mode_conan) {
+    // Conan references are name/version
+    cforge_size_t slash_pos = package_name.find('/');
+    if (slash_pos == std::string::npos || slash_pos == 0
+        || slash_pos == package_name.size() - 1) {
+      cforge::logger::print_error("'" + package_name
+                                  + "' is not a valid conan reference (expected name/version)");
+      cforge::logger::print_action("Usage", "cforge add conan <name>/<version>");
+      return 1;
+    }
+    package_version = package_name.substr(slash_pos + 1);
+    package_name    = package_name.substr(0, slash_pos);
+  } else if (mode_vcpkg) {
+    // vcpkg specs are name[features]:triplet, with an optional @version
+    std::string spec     = package_name;
+    cforge_size_t at_pos = spec.find('@');
+    if (at_pos != std::string::npos) {
+      package_version = spec.substr(at_pos + 1);
+      spec            = spec.substr(0, at_pos);
+    }
+    std::vector<std::string> vcpkg_features;
+    std::string error =
+        cforge::parse_vcpkg_spec(spec, package_name, vcpkg_features, vcpkg_triplet);
+    if (!error.empty()) {
+      cforge::logger::print_error(error);
+      return 1;
+    }
+    features.insert(features.end(), vcpkg_features.begin(), vcpkg_features.end());
   } else {
     // Parse name@version format
     cforge_size_t at_pos = package_name.find('@');
@@ -590,6 +745,22 @@ cforge_int_t cforge_cmd_add(const cforge_context_t *ctx) {
     }
   }
 
+  if (!name_value.empty()) {
+    package_name = name_value;
+  }
+
+  // Validate before touching any configuration file
+  if (mode_git && !cforge::is_valid_git_url(package_url)) {
+    cforge::logger::print_error("'" + package_url + "' is not a valid git URL");
+    cforge::logger::print_action("Hint",
+                                 "Use an https://, ssh://, git:// or user@host:path remote");
+    return 1;
+  }
+  if (package_name.empty()) {
+    cforge::logger::print_error("could not determine a dependency name, pass --name <name>");
+    return 1;
+  }
+
   // Check for verbosity
   bool verbose = cforge::logger::get_verbosity() == cforge::log_verbosity::VERBOSITY_VERBOSE;
 
@@ -634,12 +805,39 @@ cforge_int_t cforge_cmd_add(const cforge_context_t *ctx) {
     cforge::logger::print_action("Found", package_name + " " + package_version);
   }
 
+  // What the summary reports, e.g. "zlib/1.3.1 (conan)"
+  std::string added = package_name;
+  if (mode_git) {
+    added += " from " + package_url + (tag_value.empty() ? "" : " at " + tag_value);
+  } else if (mode_conan) {
+    added += "/" + package_version + " (conan)";
+  } else if (mode_vcpkg) {
+    std::string spec = package_name;
+    if (!features.empty()) {
+      spec += "[" + cforge::join_strings(features, ",") + "]";
+    }
+    if (!vcpkg_triplet.empty()) {
+      spec += ":" + vcpkg_triplet;
+    }
+    added += (package_version.empty() ? "" : " " + package_version) + " (vcpkg: " + spec + ")";
+  } else if (!package_version.empty()) {
+    added += " " + package_version;
+  }
+
   // Helper lambda to add dependency to a single project
   auto add_to_project = [&](const std::filesystem::path &proj_dir,
                             const std::filesystem::path &proj_config) -> bool {
     bool cfg_ok  = false;
     bool inst_ok = true;
 
+    if (dependency_already_declared(proj_config, package_name)) {
+      cforge::logger::print_error("Dependency '" + package_name + "' is already declared in "
+                                  + proj_config.string());
+      cforge::logger::print_action("Hint", "Run 'cforge deps remove " + package_name
+                                               + "' first to replace it");
+      return false;
+    }
+
     if (mode_git) {
       // Get the configured dependency directory from cforge.toml
       cforge::toml_reader project_config;
@@ -653,12 +851,36 @@ cforge_int_t cforge_cmd_add(const cforge_context_t *ctx) {
 
       cfg_ok = add_git_dependency_to_config(
           proj_dir, proj_config, package_name, package_url, tag_value, verbose);
-      inst_ok = clone_git_repo(package_url, deps_path.string(), tag_value, verbose);
+      if (cfg_ok && !no_install) {
+        inst_ok = clone_git_repo(package_url, deps_path.string(), tag_value, verbose);
+      }
 
     } else if (mode_vcpkg) {
       cfg_ok = add_vcpkg_dependency_to_config(
-          proj_dir, proj_config, package_name, package_version, verbose);
-      inst_ok = install_package_with_vcpkg(proj_dir, package_name, package_version, verbose);
+          proj_dir, proj_config, package_name, package_version, vcpkg_triplet, features, verbose);
+      cfg_ok = cfg_ok && enable_package_manager_block(proj_config, "vcpkg", verbose);
+      cforge::toml_reader project_config;
+      bool manifest_mode = cfg_ok && project_config.load(proj_config.string())
+                        && cforge::is_vcpkg_manifest_mode(project_config);
+      if (manifest_mode) {
+        // The vcpkg toolchain installs it from vcpkg.json at the next build
+        inst_ok = cforge::write_vcpkg_manifest(proj_dir, project_config);
+      } else if (cfg_ok && !no_install) {
+        inst_ok = install_package_with_vcpkg(proj_dir, package_name, vcpkg_triplet, verbose);
+      }
+
+    } else if (mode_conan) {
+      cfg_ok = add_conan_dependency_to_config(proj_config, package_name, package_version, verbose);
+      cfg_ok = cfg_ok && enable_package_manager_block(proj_config, "conan", verbose);
+      if (cfg_ok && !no_install) {
+        cforge::toml_reader project_config;
+        project_config.load(proj_config.string());
+        inst_ok = cforge::install_conan_packages(
+            proj_dir,
+            cforge::canonical_build_config(
+                cforge::get_build_config(nullptr, 0, nullptr, &project_config)),
+            verbose);
+      }
 
     } else if (mode_index) {
       // Add from registry - just update config (dependency will be fetched on
@@ -683,6 +905,9 @@ cforge_int_t cforge_cmd_add(const cforge_context_t *ctx) {
       } else if (mode_git) {
         cforge::logger::print_warning("Dependency '" + package_name
                                       + "' added to config, but clone failed");
+      } else if (mode_conan) {
+        cforge::logger::print_warning("Dependency '" + package_name
+                                      + "' added to config, but conan install failed");
       }
     }
 
@@ -711,7 +936,7 @@ cforge_int_t cforge_cmd_add(const cforge_context_t *ctx) {
       return 1;
     }
 
-    cforge::logger::print_action("Added", package_name + " to workspace projects");
+    cforge::logger::print_action("Added", added + " to workspace projects");
     return 0;
   }
 
@@ -720,6 +945,6 @@ cforge_int_t cforge_cmd_add(const cforge_context_t *ctx) {
     return 1;
   }
 
-  cforge::logger::print_action("Added", package_name + " " + package_version);
+  cforge::logger::print_action("Added", added + " to " + std::string(CFORGE_FILE));
   return 0;
 }
\ No newline at end of file
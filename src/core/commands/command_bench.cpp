@@ -86,17 +86,19 @@ void print_benchmark_summary(const cforge::benchmark_summary &summary) {
   for (const auto &result : summary.results) {
     fmt::print("benchmark {:<{}} ... ", result.name, name_width);
     if (result.success) {
-      fmt::print(fmt::fg(fmt::color::green) | fmt::emphasis::bold,
+      fmt::print(cforge::logger::style(fmt::fg(fmt::color::green) | fmt::emphasis::bold),
                  "{:>{}}",
                  cforge::format_bench_time(result.time_ns),
                  time_width);
       fmt::print("/iter");
       if (result.iterations > 0) {
-        fmt::print(fmt::fg(fmt::color::dim_gray), " (n={})", with_commas(result.iterations));
+        fmt::print(cforge::logger::style(fmt::fg(fmt::color::dim_gray)),
+                   " (n={})",
+                   with_commas(result.iterations));
       }
       fmt::print("\n");
     } else {
-      fmt::print(fmt::emphasis::bold | fmt::fg(fmt::color::red),
+      fmt::print(cforge::logger::style(fmt::emphasis::bold | fmt::fg(fmt::color::red)),
                  "FAILED: {}\n",
                  result.error_message);
     }
@@ -104,9 +106,9 @@ void print_benchmark_summary(const cforge::benchmark_summary &summary) {
 
   fmt::print("\nbenchmark result: ");
   if (summary.failed == 0) {
-    fmt::print(fmt::fg(fmt::color::green) | fmt::emphasis::bold, "ok");
+    fmt::print(cforge::logger::style(fmt::fg(fmt::color::green) | fmt::emphasis::bold), "ok");
   } else {
-    fmt::print(fmt::fg(fmt::color::red) | fmt::emphasis::bold, "FAILED");
+    fmt::print(cforge::logger::style(fmt::fg(fmt::color::red) | fmt::emphasis::bold), "FAILED");
   }
   fmt::print(". {} failed; {} measured; finished in {:.2f}s\n",
              summary.failed,
@@ -114,6 +116,43 @@ void print_benchmark_summary(const cforge::benchmark_summary &summary) {
              summary.total_duration.count() / 1000.0);
 }
 
+/**
+ * @brief Write benchmark results as JSON so runs can be diffed
+ *
+ * Times are per iteration in nanoseconds.
+ */
+bool write_results_json(const cforge::benchmark_summary &summary,
+                        const std::string &build_config,
+                        const fs::path &out_path) {
+  std::ofstream out(out_path);
+  if (!out) {
+    return false;
+  }
+
+  out << "{\n";
+  out << "  \"config\": " << cforge::logger::json_quote(build_config) << ",\n";
+  out << "  \"duration_ms\": " << summary.total_duration.count() << ",\n";
+  out << "  \"benchmarks\": [";
+  for (cforge_size_t i = 0; i < summary.results.size(); ++i) {
+    const auto &r = summary.results[i];
+    out << (i == 0 ? "\n" : ",\n");
+    out << "    {\"name\": " << cforge::logger::json_quote(r.name)
+        << ", \"success\": " << (r.success ? "true" : "false");
+    if (r.success) {
+      out << fmt::format(", \"time_ns\": {:.3f}, \"cpu_time_ns\": {:.3f}, \"iterations\": {}",
+                         r.time_ns,
+                         r.cpu_time_ns,
+                         r.iterations);
+    } else {
+      out << ", \"error\": " << cforge::logger::json_quote(r.error_message);
+    }
+    out << "}";
+  }
+  out << (summary.results.empty() ? "]\n" : "\n  ]\n");
+  out << "}\n";
+  return static_cast<bool>(out);
+}
+
 }  // anonymous namespace
 
 /**
@@ -124,20 +163,36 @@ cforge_int_t cforge_cmd_bench(const cforge_context_t *ctx) {
 
   // Parse arguments
   cforge::benchmark_run_options options;
-  options.build_config = "Release";  // Benchmarks should run in Release by default
+  std::string build_config;  // Empty: [benchmark] build_type, which defaults to Release
   std::string specific_bench;
+  std::string out_path;
+  bool allow_debug = false;
 
   for (cforge_int_t i = 0; i < ctx->args.arg_count; i++) {
     std::string arg = ctx->args.args[i];
 
-    if (arg == "-h" || arg == "--help") {
+    if (arg == "--") {
+      // Everything after -- goes to the benchmark binaries unchanged
+      for (++i; i < ctx->args.arg_count; ++i) {
+        options.extra_args.push_back(ctx->args.args[i]);
+      }
+      break;
+    } else if (arg == "-h" || arg == "--help") {
       // Use command registry for consistent help output
       cforge::command_registry::instance().print_command_help("bench");
       return 0;
     } else if (arg == "-c" || arg == "--config") {
       if (i + 1 < ctx->args.arg_count) {
-        options.build_config = ctx->args.args[++i];
+        build_config = ctx->args.args[++i];
       }
+    } else if (arg.rfind("--config=", 0) == 0) {
+      build_config = arg.substr(9);
+    } else if (arg == "--out" && i + 1 < ctx->args.arg_count) {
+      out_path = ctx->args.args[++i];
+    } else if (arg.rfind("--out=", 0) == 0) {
+      out_path = arg.substr(6);
+    } else if (arg == "--allow-debug") {
+      allow_debug = true;
     } else if (arg == "-v" || arg == "--verbose") {
       options.verbose = true;
     } else if (arg == "--no-build") {
@@ -173,7 +228,8 @@ cforge_int_t cforge_cmd_bench(const cforge_context_t *ctx) {
   }
 
   // Check for benchmark directory
-  std::string bench_dir_str = reader.get_string("benchmark.directory", "bench");
+  std::string bench_dir_str =
+      reader.get_string(cforge::benchmark_config_section(reader) + ".directory", "bench");
   fs::path bench_dir        = project_dir / bench_dir_str;
 
   if (!fs::exists(bench_dir)) {
@@ -219,6 +275,17 @@ cforge_int_t cforge_cmd_bench(const cforge_context_t *ctx) {
     return 1;
   }
 
+  // Release unless overridden on the command line or by [benchmark] build_type;
+  // the project's default_config deliberately doesn't apply here
+  options.build_config = build_config.empty() ? runner.get_config().default_build_type
+                                              : build_config;
+  if (cforge::string_to_lower(options.build_config) == "debug" && !allow_debug) {
+    cforge::logger::print_error("refusing to run benchmarks in a Debug build");
+    cforge::logger::print_hint("Debug timings are not meaningful; pass --allow-debug to run "
+                               "them anyway");
+    return 1;
+  }
+
   // Discover benchmark targets
   auto targets = runner.discover_targets();
 
@@ -244,6 +311,14 @@ cforge_int_t cforge_cmd_bench(const cforge_context_t *ctx) {
   // Print summary
   print_benchmark_summary(summary);
 
+  if (!out_path.empty()) {
+    if (!write_results_json(summary, options.build_config, out_path)) {
+      cforge::logger::print_error("Failed to write " + out_path);
+      return 1;
+    }
+    cforge::logger::print_action("Wrote", out_path);
+  }
+
   // Return appropriate exit code
   return summary.failed > 0 ? 1 : 0;
 }
@@ -5,11 +5,19 @@
 
 #include "cforge/log.hpp"
 
+#include "core/build_log.hpp"
+#include "core/build_report.hpp"
 #include "core/build_utils.hpp"
+#include "core/build_variants.hpp"
+#include "core/cmake_file_api.hpp"
+#include "core/cmake_presets.hpp"
+#include "core/command.h"
 #include "core/command_registry.hpp"
 #include "core/commands.hpp"
 #include "core/constants.h"
+#include "core/cross_targets.hpp"
 #include "core/dependency_hash.hpp"
+#include "core/doctor_checks.hpp"
 #include "core/error_format.hpp"
 #include "core/file_system.h"
 #include "core/git_utils.hpp"
@@ -19,14 +27,17 @@
 #include "core/registry.hpp"
 #include "core/script_runner.hpp"
 #include "core/toml_reader.hpp"
+#include "core/tool_installer.hpp"
 #include "core/types.h"
 #include "core/workspace.hpp"
+#include "core/workspace_selection.hpp"
 
 #include <fmt/color.h>
 #include <fmt/core.h>
 #include <toml++/toml.hpp>
 
 #include <algorithm>
+#include <atomic>
 #include <cctype>
 #include <chrono>
 #include <cstdlib>
@@ -42,6 +53,21 @@
 #include <thread>
 #include <vector>
 
+// Clear a CMake cache written by a different generator (off with --no-auto-clean)
+static bool s_auto_clean = true;
+
+// Target architecture from --arch (x64, x86, arm64); empty if not given
+static std::string s_msvc_arch;
+
+// Preprocessor defines from --define/-D, normalized to NAME=VALUE
+static std::vector<std::string> s_cli_defines;
+
+// Variants from --variant (or [variants] default), in order of precedence
+static std::vector<std::string> s_variants;
+
+// Configure with `cmake --preset` when the project has a CMakePresets.json
+static bool s_use_presets = false;
+
 /**
  * @brief Check if Visual Studio is available
  *
@@ -105,9 +131,513 @@ static bool is_cmake_available() {
   return available;
 }
 
+/**
+ * @brief Check that the installed CMake is new enough before configuring
+ *
+ * The minimum is CMAKE_MIN_VERSION, raised by `cmake.version` in the
+ * project's cforge.toml (which is also what the generated
+ * cmake_minimum_required() asks for). Failing here replaces the error CMake
+ * itself would give halfway through configuring.
+ *
+ * @param project_dir Directory the build runs in
+ * @return false if CMake is too old; true if it is new enough or its version
+ *         could not be determined
+ */
+static bool check_cmake_version(const std::filesystem::path &project_dir) {
+  std::string project_minimum;
+  cforge::toml_reader project_config;
+  if (std::filesystem::exists(project_dir / CFORGE_FILE)
+      && project_config.load((project_dir / CFORGE_FILE).string())) {
+    project_minimum = project_config.get_string("cmake.version", "");
+  }
+  std::string required = cforge::required_cmake_version(CMAKE_MIN_VERSION, project_minimum);
+
+  cforge::process_result result =
+      cforge::execute_process("cmake", {"--version"}, "", nullptr, nullptr, 10);
+  std::string found = cforge::parse_cmake_version(result.stdout_output);
+  if (found.empty()) {
+    cforge::logger::print_verbose("Could not determine the CMake version");
+    return true;
+  }
+  if (cforge::version_at_least(found, required)) {
+    return true;
+  }
+
+  cforge::logger::print_error("cforge requires CMake >= " + required + ", found " + found);
+  if (required != CMAKE_MIN_VERSION) {
+    cforge::logger::print_hint("cmake.version in cforge.toml asks for " + required
+                               + "; upgrade CMake from https://cmake.org/download/");
+  } else {
+    cforge::logger::print_hint("upgrade CMake from https://cmake.org/download/");
+  }
+  return false;
+}
+
+/**
+ * @brief Check a git dependency's HEAD against its expected_sha
+ *
+ * Unlike `commit`, which only selects what to check out, this validates the
+ * final state, so a force-pushed tag or branch fails the build. An
+ * abbreviated expected SHA (at least 7 characters) matches as a prefix.
+ */
+static bool verify_git_dependency_sha(const std::string &dep,
+                                      const std::filesystem::path &dep_path,
+                                      const std::string &expected_sha) {
+  if (expected_sha.empty()) {
+    return true;
+  }
+
+  std::string expected = cforge::string_to_lower(expected_sha);
+  std::string actual   = cforge::git_get_head_commit(dep_path);
+  if (expected.size() >= 7 && !actual.empty() && actual.rfind(expected, 0) == 0) {
+    cforge::logger::print_verbose("Verified '" + dep + "' is at " + actual);
+    return true;
+  }
+
+  cforge::cforge_error::make(cforge::error_code::DEP_INTEGRITY_MISMATCH,
+                             "git dependency '" + dep + "' is not at the expected commit",
+                             "expected " + expected_sha + ", found "
+                                 + (actual.empty() ? "no commit" : actual))
+      .with_help("if the upstream change is intended, update expected_sha in cforge.toml")
+      .with_help("to build the expected commit anyway, set commit = \"" + expected_sha + "\"")
+      .print();
+  return false;
+}
+
+/**
+ * @brief Initialize and update a dependency's submodules recursively
+ */
+static bool update_git_submodules(const std::string &dep,
+                                  const std::filesystem::path &dep_path,
+                                  bool verbose) {
+  std::vector<std::string> args = {"submodule", "update", "--init", "--recursive"};
+  if (!verbose) {
+    args.push_back("--quiet");
+  }
+  if (!cforge::execute_tool(
+          "git", args, dep_path.string(), "Git Submodules for " + dep, verbose, 600)) {
+    cforge::logger::print_error("Failed to update submodules for dependency '" + dep + "'");
+    return false;
+  }
+  return true;
+}
+
+/**
+ * @brief Check out the commit a git dependency is locked at in cforge.lock
+ *
+ * When `ref_resolved` is set, HEAD is what the configured ref resolves to
+ * right now, and a different commit means upstream moved since the lock was
+ * written. Shallow clones only have the tip, so the locked commit is fetched
+ * if it is missing.
+ */
+static bool pin_git_dependency_to_lock(const std::string &dep,
+                                       const std::filesystem::path &dep_path,
+                                       const std::string &ref,
+                                       const std::string &locked_sha,
+                                       bool ref_resolved,
+                                       bool shallow,
+                                       bool submodules,
+                                       bool verbose) {
+  std::string head = cforge::git_get_head_commit(dep_path, false);
+  if (locked_sha.empty() || head == locked_sha) {
+    return true;
+  }
+
+  if (ref_resolved && !head.empty()) {
+    cforge::logger::print_warning("'" + dep + "': " + ref + " now resolves to "
+                                  + head.substr(0, 7) + ", but " + cforge::LOCK_FILE
+                                  + " pins " + locked_sha.substr(0, 7)
+                                  + "; using the locked commit");
+    cforge::logger::print_hint("run 'cforge deps update' to re-resolve locked dependencies");
+  }
+
+  if (!cforge::git_execute({"cat-file", "-e", locked_sha + "^{commit}"}, dep_path.string())
+           .success) {
+    std::vector<std::string> fetch_args = {"fetch", "origin", locked_sha};
+    if (shallow) {
+      fetch_args.push_back("--depth=1");
+    }
+    if (!verbose) {
+      fetch_args.push_back("--quiet");
+    }
+    if (!cforge::execute_tool(
+            "git", fetch_args, dep_path.string(), "Git Fetch for " + dep, verbose, 120)) {
+      cforge::logger::print_error("Failed to fetch locked commit " + locked_sha + " for '" + dep
+                                  + "'");
+      return false;
+    }
+  }
+
+  cforge::logger::print_action("Checking out",
+                               "locked commit " + locked_sha.substr(0, 7) + " for '" + dep + "'");
+  std::vector<std::string> checkout_args = {"checkout", locked_sha, "--quiet"};
+  if (verbose) {
+    checkout_args.pop_back();  // Remove --quiet for verbose output
+  }
+  if (!cforge::execute_tool(
+          "git", checkout_args, dep_path.string(), "Git Checkout for " + dep, verbose, 30)) {
+    cforge::logger::print_error("Failed to check out locked commit " + locked_sha + " for '"
+                                + dep + "'");
+    return false;
+  }
+
+  return !submodules || update_git_submodules(dep, dep_path, verbose);
+}
+
+/**
+ * @brief Remove what a failed or interrupted clone left behind
+ *
+ * Otherwise the next build would see the directory and treat the partial
+ * checkout as an already fetched dependency.
+ *
+ * @param dep_path Directory the clone was writing to
+ */
+static void remove_partial_clone(const std::filesystem::path &dep_path) {
+  std::error_code ec;
+  std::filesystem::remove_all(dep_path, ec);
+  if (ec) {
+    cforge::logger::print_warning("Failed to remove partial clone " + dep_path.string() + ": "
+                                  + ec.message());
+  }
+}
+
+/**
+ * @brief Report a dependency an offline build can't fetch
+ *
+ * @param dep Dependency name, with its version where there is one
+ * @param detail What is missing or out of date
+ */
+static void print_offline_dependency_error(const std::string &dep, const std::string &detail) {
+  cforge::cforge_error::make(cforge::error_code::DEP_NOT_FOUND,
+                             "dependency '" + dep + "' is not available in offline mode",
+                             detail)
+      .with_help("run 'cforge fetch' with network access, then build with --offline again")
+      .print();
+}
+
+/**
+ * @brief Check a Git dependency's existing checkout without using the network
+ *
+ * Offline builds use what is already on disk. A checkout at another ref, or
+ * a locked commit the clone doesn't have, would need a fetch and fails.
+ */
+static bool use_offline_git_dependency(const std::string &dep,
+                                       const std::filesystem::path &dep_path,
+                                       const std::string &ref,
+                                       bool version_changed,
+                                       const std::string &locked_sha,
+                                       const std::string &expected_sha,
+                                       bool verbose) {
+  if (!std::filesystem::exists(dep_path)) {
+    print_offline_dependency_error(dep, dep_path.string() + " does not exist");
+    return false;
+  }
+  if (version_changed) {
+    print_offline_dependency_error(dep, dep_path.string() + " is not checked out at " + ref);
+    return false;
+  }
+  if (!locked_sha.empty() && cforge::git_get_head_commit(dep_path, false) != locked_sha
+      && !cforge::git_execute({"cat-file", "-e", locked_sha + "^{commit}"}, dep_path.string())
+              .success) {
+    print_offline_dependency_error(dep, "locked commit " + locked_sha + " is not in "
+                                            + dep_path.string());
+    return false;
+  }
+
+  cforge::logger::print_verbose("Offline: using existing checkout of '" + dep + "'");
+  return pin_git_dependency_to_lock(dep, dep_path, ref, locked_sha, false, false, false, verbose)
+      && verify_git_dependency_sha(dep, dep_path, expected_sha);
+}
+
+/**
+ * @brief Outcome of setting up one Git dependency
+ *
+ * Workers only read the shared dependency hashes; the new hash and version
+ * are applied by the caller once every worker is done.
+ */
+struct git_dependency_result {
+  bool success = true;
+  std::string hash;     ///< New directory hash; empty to keep the stored one
+  std::string version;  ///< Ref the dependency was set up at
+};
+
+/**
+ * @brief Clone or update a single Git dependency
+ *
+ * Safe to run for several dependencies at once: each one works in its own
+ * directory and does not write shared state.
+ *
+ * @param dep Dependency name
+ * @param project_dir Project directory
+ * @param deps_path Default dependencies directory
+ * @param project_config Project configuration from cforge.toml
+ * @param dep_hashes Stored dependency hashes (read only)
+ * @param toml_changed Whether cforge.toml changed since the last setup
+ * @param lock Lockfile, if has_lock is set
+ * @param has_lock Whether a lockfile was loaded
+ * @param verbose Verbose output flag
+ * @return Setup result
+ */
+static git_dependency_result setup_git_dependency(const std::string &dep,
+                                                  const std::filesystem::path &project_dir,
+                                                  const std::filesystem::path &deps_path,
+                                                  const cforge::toml_reader &project_config,
+                                                  const cforge::dependency_hash &dep_hashes,
+                                                  bool toml_changed,
+                                                  const cforge::lockfile &lock,
+                                                  bool has_lock,
+                                                  bool verbose) {
+  git_dependency_result result;
+
+  // Get dependency configuration
+  std::string url = project_config.get_string("dependencies.git." + dep + ".url", "");
+  if (url.empty()) {
+    cforge::logger::print_warning("Git dependency '" + dep + "' is missing a URL, skipping");
+    return result;
+  }
+
+  // Get reference (tag, branch, or commit)
+  std::string tag    = project_config.get_string("dependencies.git." + dep + ".tag", "");
+  std::string branch = project_config.get_string("dependencies.git." + dep + ".branch", "");
+  std::string commit = project_config.get_string("dependencies.git." + dep + ".commit", "");
+  std::string ref    = tag;
+  if (ref.empty()) {
+    ref = branch;
+  }
+  if (ref.empty()) {
+    ref = commit;
+  }
+
+  // Get custom directory if specified
+  std::string custom_dir = project_config.get_string("dependencies.git." + dep + ".directory", "");
+  std::filesystem::path dep_path = custom_dir.empty() ? deps_path / dep
+                                                      : project_dir / custom_dir / dep;
+
+  // A shallow clone can't check out an arbitrary commit, so pinning a
+  // commit always clones the full history
+  std::string dep_key      = "dependencies.git." + dep;
+  bool shallow             = project_config.get_bool(dep_key + ".shallow", true);
+  bool submodules          = project_config.get_bool(dep_key + ".submodules", false);
+  std::string expected_sha = project_config.get_string(dep_key + ".expected_sha", "");
+  if (shallow && !commit.empty() && tag.empty() && branch.empty()) {
+    if (project_config.get_bool(dep_key + ".shallow", false)) {
+      cforge::logger::print_warning("'" + dep + "' pins a commit; ignoring shallow = true");
+    }
+    shallow = false;
+  }
+
+  std::string locked_sha = has_lock ? cforge::locked_git_commit(lock, dep, url, ref) : "";
+
+  // Check if version has changed
+  std::string stored_version = dep_hashes.get_version(dep);
+  bool version_changed       = !ref.empty() && ref != stored_version;
+
+  if (cforge_is_offline()) {
+    result.success = use_offline_git_dependency(
+        dep, dep_path, ref, version_changed, locked_sha, expected_sha, verbose);
+    return result;
+  }
+
+  if (std::filesystem::exists(dep_path)) {
+    // If version changed, remove the directory and reclone
+    if (version_changed) {
+      cforge::logger::print_action(
+          "Updating", "version changed for '" + dep + "', removing existing directory");
+      try {
+        std::filesystem::remove_all(dep_path);
+      } catch (const std::exception &e) {
+        cforge::logger::print_error("Failed to remove directory for '" + dep + "': " + e.what());
+        result.success = false;
+        return result;
+      }
+    } else {
+      // Check if update is needed based on directory hash
+      std::string current_hash = cforge::dependency_hash::calculate_directory_hash(dep_path);
+      std::string stored_hash  = dep_hashes.get_hash(dep);
+
+      // A dependency missing from the lock (e.g. after 'cforge deps update')
+      // is fetched again so its ref is re-resolved
+      bool needs_update = current_hash != stored_hash || toml_changed || !lock.has_dependency(dep);
+
+      if (!needs_update) {
+        // Inform that dependency is up to date and no update is needed
+        cforge::logger::print_verbose("Dependency '" + dep + "' is up to date, skipping update");
+        bool repin = !locked_sha.empty()
+                  && cforge::git_get_head_commit(dep_path, false) != locked_sha;
+        if (!pin_git_dependency_to_lock(
+                dep, dep_path, ref, locked_sha, false, shallow, submodules, verbose)
+            || !verify_git_dependency_sha(dep, dep_path, expected_sha)) {
+          result.success = false;
+        } else if (repin) {
+          result.hash = cforge::dependency_hash::calculate_directory_hash(dep_path);
+        }
+        return result;
+      }
+
+      cforge::logger::print_verbose(
+          "Dependency '" + dep + "' directory exists but needs update at: " + dep_path.string());
+
+      // Update the repository
+      cforge::logger::print_action("Updating", "dependency '" + dep + "' from remote");
+
+      // Run git fetch to update
+      std::vector<std::string> fetch_args = {"fetch"};
+      if (shallow) {
+        fetch_args.push_back("--depth=1");
+      }
+      if (!verbose) {
+        fetch_args.push_back("--quiet");
+      }
+
+      // Set a shorter timeout for fetch operations
+      bool fetch_result = cforge::execute_tool(
+          "git", fetch_args, dep_path.string(), "Git Fetch for " + dep, verbose, 30);
+
+      if (!fetch_result) {
+        cforge::logger::print_warning("Failed to fetch updates for '" + dep
+                                      + "', continuing with existing version");
+        result.success = false;
+        return result;
+      }
+
+      // Checkout specific ref if provided; a branch is checked out from the
+      // remote so the fetch above actually moves it
+      if (!ref.empty()) {
+        cforge::logger::print_action("Checking out", ref + " for dependency '" + dep + "'");
+
+        std::string checkout_ref = tag.empty() && !branch.empty() ? "origin/" + branch : ref;
+        std::vector<std::string> checkout_args = {"checkout", checkout_ref, "--quiet"};
+        if (verbose) {
+          checkout_args.pop_back();  // Remove --quiet for verbose output
+        }
+
+        bool checkout_result = cforge::execute_tool(
+            "git", checkout_args, dep_path.string(), "Git Checkout for " + dep, verbose, 30);
+
+        if (!checkout_result) {
+          cforge::logger::print_warning("Failed to checkout " + ref + " for '" + dep
+                                        + "', continuing with current version");
+          result.success = false;
+          return result;
+        }
+      }
+
+      if (!pin_git_dependency_to_lock(
+              dep, dep_path, ref, locked_sha, !ref.empty(), shallow, false, verbose)) {
+        result.success = false;
+        return result;
+      }
+
+      if (submodules && !update_git_submodules(dep, dep_path, verbose)) {
+        result.success = false;
+        return result;
+      }
+
+      if (!verify_git_dependency_sha(dep, dep_path, expected_sha)) {
+        result.success = false;
+        return result;
+      }
+
+      // Update hash after successful update
+      result.hash    = cforge::dependency_hash::calculate_directory_hash(dep_path);
+      result.version = ref;
+      return result;
+    }
+  }
+
+  // Create parent directory if it doesn't exist
+  std::filesystem::create_directories(dep_path.parent_path());
+
+  // Clone the repository
+  cforge::logger::fetching(dep + " from " + url);
+
+  std::vector<std::string> clone_args = {"clone", url, dep_path.string()};
+  if (shallow) {
+    clone_args.push_back("--depth=1");
+  }
+  if (submodules) {
+    clone_args.push_back("--recurse-submodules");
+    if (shallow) {
+      clone_args.push_back("--shallow-submodules");
+    }
+  }
+
+  // --branch takes tags and branches; commits are checked out below
+  if (!tag.empty() || !branch.empty()) {
+    clone_args.push_back("--branch");
+    clone_args.push_back(ref);
+  }
+
+  if (!verbose) {
+    clone_args.push_back("--quiet");
+  }
+
+  bool clone_result =
+      cforge::execute_tool("git", clone_args, "", "Git Clone for " + dep, verbose, 600);
+
+  if (!clone_result) {
+    remove_partial_clone(dep_path);
+    if (!cforge::was_interrupted()) {
+      cforge::logger::print_error("Failed to clone dependency '" + dep + "' from " + url);
+    }
+    result.success = false;
+    return result;
+  }
+
+  // Checkout specific commit if provided (since --branch doesn't work with
+  // commit hashes)
+  if (!commit.empty()) {
+    cforge::logger::print_action("Checking out",
+                                 "commit " + commit + " for dependency '" + dep + "'");
+
+    std::vector<std::string> checkout_args = {"checkout", commit, "--quiet"};
+    if (verbose) {
+      checkout_args.pop_back();  // Remove --quiet for verbose output
+    }
+
+    bool checkout_result = cforge::execute_tool(
+        "git", checkout_args, dep_path.string(), "Git Checkout for " + dep, verbose, 30);
+
+    if (!checkout_result) {
+      cforge::logger::print_error("Failed to checkout commit " + commit + " for dependency '"
+                                  + dep + "'");
+      result.success = false;
+      return result;
+    }
+
+    // The clone initialized submodules for the default branch, not this commit
+    if (submodules && !update_git_submodules(dep, dep_path, verbose)) {
+      result.success = false;
+      return result;
+    }
+  }
+
+  if (!pin_git_dependency_to_lock(
+          dep, dep_path, ref, locked_sha, !ref.empty(), shallow, submodules, verbose)) {
+    result.success = false;
+    return result;
+  }
+
+  if (!verify_git_dependency_sha(dep, dep_path, expected_sha)) {
+    result.success = false;
+    return result;
+  }
+
+  // Store hash and version for newly cloned dependency
+  result.hash    = cforge::dependency_hash::calculate_directory_hash(dep_path);
+  result.version = ref;
+
+  cforge::logger::print_action("Downloaded", dep);
+  return result;
+}
+
 /**
  * @brief Clone and update Git dependencies for a project
  *
+ * Dependencies are fetched in parallel, up to `dependencies.jobs` at a time
+ * (default 4). Verbose builds fetch one at a time so raw git output stays
+ * readable.
+ *
  * @param project_dir Project directory
  * @param project_config Project configuration from cforge.toml
  * @param verbose Verbose output flag
@@ -166,177 +696,56 @@ bool clone_git_dependencies(const std::filesystem::path &project_dir,
   }
   std::string stored_toml_hash = dep_hashes.get_hash("cforge.toml");
 
+  // Resolved commits from a previous build; see pin_git_dependency_to_lock
+  cforge::lockfile lock;
+  bool has_lock = lock.load(project_dir);
+
   // Get all Git dependencies
   auto git_deps = project_config.get_table_keys("dependencies.git");
   cforge::logger::print_action("Fetching", std::to_string(git_deps.size()) + " Git dependencies");
 
-  bool all_success = true;
-
-  for (const auto &dep : git_deps) {
-    // Get dependency configuration
-    std::string url = project_config.get_string("dependencies.git." + dep + ".url", "");
-    if (url.empty()) {
-      cforge::logger::print_warning("Git dependency '" + dep + "' is missing a URL, skipping");
-      continue;
-    }
-
-    // Get reference (tag, branch, or commit)
-    std::string tag    = project_config.get_string("dependencies.git." + dep + ".tag", "");
-    std::string branch = project_config.get_string("dependencies.git." + dep + ".branch", "");
-    std::string commit = project_config.get_string("dependencies.git." + dep + ".commit", "");
-    std::string ref    = tag;
-    if (ref.empty()) {
-      ref = branch;
-    }
-    if (ref.empty()) {
-      ref = commit;
-    }
-
-    // Get custom directory if specified
-    std::string custom_dir = project_config.get_string("dependencies.git." + dep + ".directory",
-                                                       "");
-    std::filesystem::path dep_path = custom_dir.empty() ? deps_path / dep
-                                                        : project_dir / custom_dir / dep;
+  // Fetch in parallel; results are applied in declaration order afterwards
+  cforge_int_t jobs = verbose ? 1 : project_config.get_int("dependencies.jobs", 4);
+  cforge_size_t workers = std::min(git_deps.size(),
+                                   static_cast<cforge_size_t>(std::max<cforge_int_t>(1, jobs)));
+  bool toml_changed     = stored_toml_hash != toml_hash;
 
-    // Check if version has changed
-    std::string stored_version = dep_hashes.get_version(dep);
-    bool version_changed       = !ref.empty() && ref != stored_version;
-
-    if (std::filesystem::exists(dep_path)) {
-      // If version changed, remove the directory and reclone
-      if (version_changed) {
-        cforge::logger::print_action(
-            "Updating", "version changed for '" + dep + "', removing existing directory");
-        try {
-          std::filesystem::remove_all(dep_path);
-        } catch (const std::exception &e) {
-          cforge::logger::print_error("Failed to remove directory for '" + dep + "': " + e.what());
-          all_success = false;
-          continue;
-        }
-      } else {
-        // Check if update is needed based on directory hash
-        std::string current_hash = cforge::dependency_hash::calculate_directory_hash(dep_path);
-        std::string stored_hash  = dep_hashes.get_hash(dep);
-
-        bool needs_update = current_hash != stored_hash || stored_toml_hash != toml_hash;
-
-        if (!needs_update) {
-          // Inform that dependency is up to date and no update is needed
-          cforge::logger::print_verbose("Dependency '" + dep + "' is up to date, skipping update");
-          continue;
-        }
-
-        cforge::logger::print_verbose(
-            "Dependency '" + dep + "' directory exists but needs update at: " + dep_path.string());
-
-        // Update the repository
-        cforge::logger::print_action("Updating", "dependency '" + dep + "' from remote");
-
-        // Run git fetch to update
-        std::vector<std::string> fetch_args = {"fetch", "--quiet", "--depth=1"};
-        if (verbose) {
-          fetch_args.pop_back();  // Remove --quiet for verbose output
-        }
-
-        // Set a shorter timeout for fetch operations
-        bool fetch_result = cforge::execute_tool(
-            "git", fetch_args, dep_path.string(), "Git Fetch for " + dep, verbose, 30);
-
-        if (!fetch_result) {
-          cforge::logger::print_warning("Failed to fetch updates for '" + dep
-                                        + "', continuing with existing version");
-          all_success = false;
-          continue;
-        }
-
-        // Checkout specific ref if provided
-        if (!ref.empty()) {
-          cforge::logger::print_action("Checking out", ref + " for dependency '" + dep + "'");
-
-          std::vector<std::string> checkout_args = {"checkout", ref, "--quiet"};
-          if (verbose) {
-            checkout_args.pop_back();  // Remove --quiet for verbose output
-          }
-
-          bool checkout_result = cforge::execute_tool(
-              "git", checkout_args, dep_path.string(), "Git Checkout for " + dep, verbose, 30);
-
-          if (!checkout_result) {
-            cforge::logger::print_warning("Failed to checkout " + ref + " for '" + dep
-                                          + "', continuing with current version");
-            all_success = false;
-            continue;
-          }
-        }
-
-        // Update hash after successful update
-        current_hash = cforge::dependency_hash::calculate_directory_hash(dep_path);
-        dep_hashes.set_hash(dep, current_hash);
-        if (!ref.empty()) {
-          dep_hashes.set_version(dep, ref);
-        }
-        continue;
+  std::vector<git_dependency_result> results(git_deps.size());
+  std::atomic<cforge_size_t> next{0};
+  auto worker = [&]() {
+    for (cforge_size_t i = next++; i < git_deps.size(); i = next++) {
+      try {
+        results[i] = setup_git_dependency(git_deps[i], project_dir, deps_path, project_config,
+                                          dep_hashes, toml_changed, lock, has_lock, verbose);
+      } catch (const std::exception &e) {
+        cforge::logger::print_error("Failed to set up '" + git_deps[i] + "': " + e.what());
+        results[i].success = false;
       }
     }
+  };
 
-    // Create parent directory if it doesn't exist
-    std::filesystem::create_directories(dep_path.parent_path());
-
-    // Clone the repository
-    cforge::logger::fetching(dep + " from " + url);
-
-    std::vector<std::string> clone_args = {"clone", "--depth=1", url, dep_path.string()};
-
-    // Add specific ref if provided
-    if (!ref.empty()) {
-      clone_args.push_back("--branch");
-      clone_args.push_back(ref);
-    }
-
-    if (!verbose) {
-      clone_args.push_back("--quiet");
+  if (workers <= 1) {
+    worker();
+  } else {
+    std::vector<std::thread> threads;
+    for (cforge_size_t i = 0; i < workers; ++i) {
+      threads.emplace_back(worker);
     }
-
-    bool clone_result =
-        cforge::execute_tool("git", clone_args, "", "Git Clone for " + dep, verbose, 600);
-
-    if (!clone_result) {
-      cforge::logger::print_error("Failed to clone dependency '" + dep + "' from " + url);
-      all_success = false;
-      continue;
+    for (auto &thread : threads) {
+      thread.join();
     }
+  }
 
-    // Checkout specific commit if provided (since --branch doesn't work with
-    // commit hashes)
-    if (!commit.empty()) {
-      cforge::logger::print_action("Checking out",
-                                   "commit " + commit + " for dependency '" + dep + "'");
-
-      std::vector<std::string> checkout_args = {"checkout", commit, "--quiet"};
-      if (verbose) {
-        checkout_args.pop_back();  // Remove --quiet for verbose output
-      }
-
-      bool checkout_result = cforge::execute_tool(
-          "git", checkout_args, dep_path.string(), "Git Checkout for " + dep, verbose, 30);
-
-      if (!checkout_result) {
-        cforge::logger::print_error("Failed to checkout commit " + commit + " for dependency '"
-                                    + dep + "'");
-        all_success = false;
-        continue;
-      }
+  bool all_success = true;
+  for (cforge_size_t i = 0; i < git_deps.size(); ++i) {
+    const git_dependency_result &result = results[i];
+    all_success                         = all_success && result.success;
+    if (!result.hash.empty()) {
+      dep_hashes.set_hash(git_deps[i], result.hash);
     }
-
-    // Store hash and version for newly cloned dependency
-    std::string current_hash = cforge::dependency_hash::calculate_directory_hash(dep_path);
-    dep_hashes.set_hash(dep, current_hash);
-    if (!ref.empty()) {
-      dep_hashes.set_version(dep, ref);
+    if (!result.version.empty()) {
+      dep_hashes.set_version(git_deps[i], result.version);
     }
-
-    cforge::logger::print_action("Downloaded", dep);
   }
 
   // Save updated dependency hashes (but NOT cforge.toml hash - that's handled
@@ -602,13 +1011,17 @@ bool resolve_index_dependencies(const std::filesystem::path &project_dir,
     std::string version_key = dep_key + ".version";
     version                 = project_config.get_string(version_key, "");
 
-    // Skip if it has explicit source indicators
-    bool has_git     = !project_config.get_string(dep_key + ".git", "").empty();
-    bool has_vcpkg   = project_config.get_bool(dep_key + ".vcpkg", false);
-    bool has_system  = project_config.get_bool(dep_key + ".system", false);
-    bool has_project = project_config.get_bool(dep_key + ".project", false);
-
-    if (!has_git && !has_vcpkg && !has_system && !has_project && !version.empty()) {
+    // Skip if it has explicit source indicators; `source = "conan"` or
+    // `source = "vcpkg"` entries have a version too but aren't in the index
+    std::string source = project_config.get_string(dep_key + ".source", "");
+    bool has_source    = !source.empty() && source != "index";
+    bool has_git       = !project_config.get_string(dep_key + ".git", "").empty();
+    bool has_vcpkg     = project_config.get_bool(dep_key + ".vcpkg", false);
+    bool has_system    = project_config.get_bool(dep_key + ".system", false);
+    bool has_project   = project_config.get_bool(dep_key + ".project", false);
+
+    if (!has_source && !has_git && !has_vcpkg && !has_system && !has_project
+        && !version.empty()) {
       // This is an index dependency
       cforge::logger::print_verbose("    Found index dep (table): " + dep + " = " + version);
 
@@ -643,7 +1056,7 @@ bool resolve_index_dependencies(const std::filesystem::path &project_dir,
   cforge::registry reg;
 
   // Check if registry needs update
-  if (reg.needs_update()) {
+  if (!cforge_is_offline() && reg.needs_update()) {
     cforge::logger::print_action("Updating", "package index");
     if (!reg.update()) {
       cforge::logger::print_warning("Failed to update package index, using cached version");
@@ -712,6 +1125,15 @@ bool resolve_index_dependencies(const std::filesystem::path &project_dir,
     // unnecessary updates)
     bool version_changed = !stored_version.empty() && resolved_version != stored_version;
 
+    if (cforge_is_offline() && (version_changed || !std::filesystem::exists(dep_path))) {
+      print_offline_dependency_error(name + "@" + resolved_version,
+                                     version_changed ? dep_path.string() + " has version "
+                                                           + stored_version
+                                                     : dep_path.string() + " does not exist");
+      all_success = false;
+      continue;
+    }
+
     if (std::filesystem::exists(dep_path)) {
       if (version_changed) {
         cforge::logger::print_action("Updating",
@@ -753,6 +1175,10 @@ bool resolve_index_dependencies(const std::filesystem::path &project_dir,
         cforge::execute_tool("git", clone_args, "", "Git Clone for " + name, verbose, 600);
 
     if (!clone_result) {
+      remove_partial_clone(dep_path);
+      if (cforge::was_interrupted()) {
+        return false;
+      }
       cforge::logger::print_error("Failed to clone package '" + name + "' from " + pkg.repository);
       all_success = false;
       continue;
@@ -801,6 +1227,187 @@ bool resolve_index_dependencies(const std::filesystem::path &project_dir,
   return all_success;
 }
 
+/**
+ * @brief Check that an offline build has what CMake would otherwise download
+ *
+ * Index, archive and [dependencies.fetchcontent] dependencies are downloaded
+ * into `<build_dir>/_deps` during configure, and the vcpkg toolchain installs
+ * ports there too (into `<build_dir>/vcpkg_installed` in manifest mode);
+ * offline, all of them must already be present.
+ *
+ * @param project_dir Project directory
+ * @param project_config Project configuration from cforge.toml
+ * @param build_dir CMake binary directory
+ * @param vcpkg_root vcpkg root directory
+ * @param vcpkg_triplet Triplet the build uses, empty to skip vcpkg ports
+ * @return bool true if nothing is missing
+ */
+static bool check_offline_dependencies(const std::filesystem::path &project_dir,
+                                       const cforge::toml_reader &project_config,
+                                       const std::filesystem::path &build_dir,
+                                       const std::string &vcpkg_root,
+                                       const std::string &vcpkg_triplet) {
+  bool all_present = true;
+  auto require_fetched = [&](const std::string &dep, const std::string &label) {
+    std::filesystem::path source = build_dir / "_deps" / (cforge::string_to_lower(dep) + "-src");
+    if (!std::filesystem::exists(source)) {
+      print_offline_dependency_error(label, source.string() + " does not exist");
+      all_present = false;
+    }
+  };
+
+  if (project_config.get_bool("dependencies.fetch_content", true)) {
+    for (const auto &dep : cforge::get_index_dependencies_with_versions(project_config)) {
+      require_fetched(dep.name, dep.name + "@" + dep.version);
+    }
+  }
+  for (const auto &dep : project_config.get_table_keys("dependencies.archive")) {
+    require_fetched(dep, dep);
+  }
+  for (const auto &dep : project_config.get_table_keys("dependencies.fetchcontent")) {
+    require_fetched(dep, dep);
+  }
+
+  // In manifest mode the toolchain installs ports into the build directory
+  bool manifest_mode = cforge::is_vcpkg_manifest_mode(project_config);
+  if (manifest_mode && !cforge::get_vcpkg_packages(project_dir, project_config).empty()
+      && !std::filesystem::exists(build_dir / "vcpkg_installed")) {
+    cforge::cforge_error::make(cforge::error_code::DEP_NOT_FOUND,
+                               "vcpkg.json packages are not available in offline mode",
+                               (build_dir / "vcpkg_installed").string() + " does not exist")
+        .with_help("build once with network access; the vcpkg toolchain installs them")
+        .print();
+    all_present = false;
+  }
+  if (!vcpkg_triplet.empty() && !manifest_mode) {
+    for (const auto &port : cforge::get_vcpkg_packages(project_dir, project_config)) {
+      if (!cforge::is_vcpkg_port_installed(vcpkg_root, port, vcpkg_triplet)) {
+        print_offline_dependency_error(port + ":" + vcpkg_triplet,
+                                       "it is not installed in " + vcpkg_root);
+        all_present = false;
+      }
+    }
+  }
+  return all_present;
+}
+
+/**
+ * @brief Check the compiler against [project] min_compiler and record it
+ *
+ * A compiler older than the project requires stops the build before
+ * configure. The compiler is recorded in the build directory, and a
+ * different one than last time clears the CMake cache (see
+ * cforge::record_build_compiler()).
+ *
+ * @param project_config Project configuration from cforge.toml
+ * @param build_dir CMake binary directory
+ * @param generator CMake generator
+ * @return bool true if the build can go ahead
+ */
+static bool verify_toolchain(const cforge::toml_reader &project_config,
+                             const std::filesystem::path &build_dir,
+                             const std::string &generator) {
+  auto min_compiler = project_config.get_string_map("project.min_compiler");
+  cforge::compiler_identity compiler =
+      cforge::detect_project_compiler(project_config, build_dir, generator);
+  if (compiler.family.empty()) {
+    if (!min_compiler.empty()) {
+      cforge::logger::print_warning("could not determine the C++ compiler's version; "
+                                    "[project] min_compiler is not checked");
+    }
+    return true;
+  }
+  cforge::logger::print_verbose("Using C++ compiler: " + compiler.label() + " ("
+                                + compiler.path + ")");
+
+  std::string required = cforge::required_compiler_version(min_compiler, compiler.family);
+  if (!required.empty() && !cforge::compiler_version_at_least(compiler.version, required)) {
+    cforge::cforge_error::make(cforge::error_code::BUILD_COMPILER_TOO_OLD,
+                               "found " + compiler.label() + ", but this project requires "
+                                   + compiler.family + " " + required + " or newer",
+                               "--> " + compiler.path)
+        .with_help("set cmake.cxx_compiler in cforge.toml, or the CXX environment variable, "
+                   "to a newer compiler")
+        .with_help("or install " + compiler.family + " " + required + " or newer")
+        .print();
+    return false;
+  }
+  return cforge::record_build_compiler(build_dir, compiler);
+}
+
+/**
+ * @brief Make sure ninja is available when a Ninja generator is selected
+ *
+ * Offers to install it when it is missing, instead of letting CMake fail
+ * with "CMAKE_MAKE_PROGRAM is not set".
+ *
+ * @param generator CMake generator that will be used
+ * @param cmake_args Receives CMAKE_MAKE_PROGRAM when a fresh install is not on PATH yet
+ * @return bool true if the build can go ahead
+ */
+static bool ensure_ninja_available(const std::string &generator,
+                                   std::vector<std::string> &cmake_args) {
+  if (generator.find("Ninja") == std::string::npos || cforge::is_command_available("ninja", 15)) {
+    return true;
+  }
+
+  cforge::logger::print_error("ninja not found in PATH, but the " + generator
+                              + " generator needs it");
+  auto r = cforge::offer_install_tool("ninja");
+  if (r.status == cforge::install_result::installed && !r.path.empty()) {
+    cmake_args.push_back("-DCMAKE_MAKE_PROGRAM=" + r.path);
+    return true;
+  }
+  if (cforge::is_command_available("ninja", 15)) {
+    return true;
+  }
+  if (r.status == cforge::install_result::installed) {
+    cforge::logger::print_error("ninja was installed but is still not on PATH");
+    cforge::logger::print_hint("open a new terminal so the updated PATH is picked up");
+  } else if (r.status != cforge::install_result::declined) {
+    cforge::logger::print_plain("  Windows: winget install Ninja-build.Ninja");
+    cforge::logger::print_plain("  macOS:   brew install ninja");
+    cforge::logger::print_plain("  Linux:   sudo apt install ninja-build");
+  }
+  cforge::logger::print_hint("or set cmake.generator in cforge.toml to a generator you have");
+  return false;
+}
+
+/**
+ * @brief Make sure pkg-config is available when [build] pkg_config lists modules
+ *
+ * Offers to install it when it is missing. The generated CMakeLists.txt also
+ * stops with an error, but failing here lets cforge suggest the install.
+ *
+ * @param project_config Project configuration from cforge.toml
+ * @param cmake_args Receives PKG_CONFIG_EXECUTABLE when a fresh install is not on PATH yet
+ * @return bool true if the build can go ahead
+ */
+static bool ensure_pkg_config_available(const cforge::toml_reader &project_config,
+                                        std::vector<std::string> &cmake_args) {
+  if (project_config.get_string_array("build.pkg_config").empty()
+      || cforge::is_command_available("pkg-config", 5)) {
+    return true;
+  }
+
+  cforge::logger::print_error("pkg-config not found in PATH, but [build] pkg_config lists "
+                              "modules");
+  auto r = cforge::offer_install_tool("pkg-config");
+  if (r.status == cforge::install_result::installed && !r.path.empty()) {
+    cmake_args.push_back("-DPKG_CONFIG_EXECUTABLE=" + r.path);
+    return true;
+  }
+  if (cforge::is_command_available("pkg-config", 5)) {
+    return true;
+  }
+  if (r.status != cforge::install_result::declined) {
+    cforge::logger::print_plain("  Windows: choco install pkgconfiglite");
+    cforge::logger::print_plain("  macOS:   brew install pkg-config");
+    cforge::logger::print_plain("  Linux:   sudo apt install pkg-config");
+  }
+  return false;
+}
+
 /**
  * @brief Run CMake configure step
  *
@@ -809,6 +1416,46 @@ bool resolve_index_dependencies(const std::filesystem::path &project_dir,
  * @param verbose Verbose output
  * @return bool Success flag
  */
+/**
+ * @brief Replace the configure arguments with `cmake --preset` under --use-presets
+ *
+ * The preset for the configuration (see `cforge export-presets`) supplies the
+ * generator and cache variables. Only the build directory and the settings
+ * of this invocation (--define, offline mode) are still passed.
+ *
+ * @param cmake_args Classic configure arguments
+ * @param source_dir Directory holding CMakePresets.json
+ * @param build_dir Build directory
+ * @param config Build configuration
+ * @param workspace_build Whether the project is configured through its workspace
+ * @return Arguments for `cmake`
+ */
+static std::vector<std::string> configure_with_presets(const std::vector<std::string> &cmake_args,
+                                                       const std::filesystem::path &source_dir,
+                                                       const std::filesystem::path &build_dir,
+                                                       const std::string &config,
+                                                       bool workspace_build) {
+  if (!s_use_presets) {
+    return cmake_args;
+  }
+  if (workspace_build || !std::filesystem::exists(source_dir / "CMakePresets.json")) {
+    cforge::logger::print_warning("No CMakePresets.json for this project, configuring without "
+                                  "presets");
+    cforge::logger::print_hint("run 'cforge export-presets' to generate one");
+    return cmake_args;
+  }
+
+  std::string preset = cforge::cmake_preset_name(config);
+  cforge::logger::print_verbose("Configuring with CMake preset: " + preset);
+  return {"--preset",
+          preset,
+          "-B",
+          build_dir.string(),
+          cforge::cli_defines_cache_arg(s_cli_defines),
+          cforge::variants_cache_arg(s_variants),
+          std::string("-DFETCHCONTENT_FULLY_DISCONNECTED=") + (cforge_is_offline() ? "ON" : "OFF")};
+}
+
 static bool run_cmake_configure(const std::vector<std::string> &cmake_args,
                                 const std::string &build_dir,
                                 const std::string &project_dir,
@@ -834,18 +1481,25 @@ static bool run_cmake_configure(const std::vector<std::string> &cmake_args,
     }
     cforge::logger::print_verbose("Command: " + cmd);
   }
-  // Check if the -DCMAKE_BUILD_TYPE argument is present
+  // Check if the -DCMAKE_BUILD_TYPE argument is present; multi-config
+  // generators don't take one
   bool has_build_type = false;
-  for (const auto &arg : cmake_args) {
-    if (arg.find("-DCMAKE_BUILD_TYPE=") != std::string::npos) {
+  bool multi_config   = false;
+  bool uses_preset    = false;
+  for (cforge_size_t i = 0; i < cmake_args.size(); ++i) {
+    const std::string &arg = cmake_args[i];
+    if (arg == "-G" && i + 1 < cmake_args.size()) {
+      multi_config = cforge::is_multi_config_generator(cmake_args[i + 1]);
+    } else if (arg == "--preset") {
+      uses_preset = true;
+    } else if (arg.find("-DCMAKE_BUILD_TYPE=") != std::string::npos) {
       has_build_type = true;
       cforge::logger::print_verbose("Using build type: " + arg);
-      break;
     }
   }
 
   // Ensure build type is being passed - just in case
-  if (!has_build_type) {
+  if (!has_build_type && !multi_config && !uses_preset) {
     cforge::logger::print_warning(
         "No build type specified in CMake arguments - this should not happen");
   }
@@ -859,15 +1513,35 @@ static bool run_cmake_configure(const std::vector<std::string> &cmake_args,
     cforge::logger::print_verbose("Full CMake command: " + cmd);
   }
 
+  // Ask CMake to describe its targets so `cforge run` can find artifacts
+  cforge::write_cmake_file_api_query(build_dir);
+
   // Execute CMake and capture output
   cforge::process_result pr =
       cforge::execute_process("cmake", cmake_args, project_dir, nullptr, nullptr, timeout);
   bool result = pr.success;
 
+  // A configure cut short by Ctrl+C can leave a half-written cache that the
+  // next run would trust; drop it so that run configures from scratch
+  if (cforge::was_interrupted()) {
+    std::error_code ec;
+    std::filesystem::remove(std::filesystem::path(build_dir) / "CMakeCache.txt", ec);
+    return false;
+  }
+
   if (result) {
     cforge::logger::print_action("Finished", "CMake configuration");
   }
 
+  // Keep the raw output; only the formatted errors are shown below
+  std::string log_output = cforge::build_log_text(pr.stdout_output, pr.stderr_output);
+  std::filesystem::path log =
+      cforge::write_build_log(build_dir,
+                              "configure",
+                              "cmake " + cforge::join_strings(cmake_args, " "),
+                              log_output,
+                              cforge::configured_build_log_limit(project_dir));
+
   // Verify that the configuration was successful by checking for CMakeCache.txt
   std::filesystem::path build_path(build_dir);
   bool cmake_success = result && std::filesystem::exists(build_path / "CMakeCache.txt");
@@ -923,76 +1597,402 @@ static bool run_cmake_configure(const std::vector<std::string> &cmake_args,
         }
       }
     }
+    if (!log.empty()) {
+      cforge::print_build_log_tail(log, log_output);
+    }
+    return false;
+  }
+
+  return true;
+}
+
+/**
+ * @brief Check for circular include dependencies and warn/fail if found
+ *
+ * @param project_dir Project directory
+ * @param project_config Project configuration
+ * @param verbose Verbose output
+ * @return bool True if build should continue, false if it should fail
+ */
+static bool check_circular_dependencies(const std::filesystem::path &project_dir,
+                                        const cforge::toml_reader &project_config,
+                                        bool verbose) {
+  // Check if circular dependency check is enabled (default: true)
+  bool warn_circular    = project_config.get_bool("build.warn_circular", true);
+  bool fail_on_circular = project_config.get_bool("build.fail_on_circular", false);
+
+  if (!warn_circular && !fail_on_circular) {
+    return true;  // Checks disabled
+  }
+
+  if (verbose) {
+    cforge::logger::print_verbose("Checking for circular include dependencies...");
+  }
+
+  cforge::include_analyzer analyzer(project_dir);
+  cforge::include_analysis_result result = analyzer.analyze(false);  // Don't include deps
+
+  if (!result.has_cycles) {
+    if (verbose) {
+      cforge::logger::print_verbose("No circular dependencies found");
+    }
+    return true;
+  }
+
+  // Circular dependencies found - emit warnings
+  for (const auto &chain : result.chains) {
+    // Build the chain string
+    std::string chain_str;
+    for (cforge_size_t i = 0; i < chain.files.size(); ++i) {
+      chain_str += chain.files[i];
+      if (i < chain.files.size() - 1) {
+        chain_str += " -> ";
+      }
+    }
+
+    // Format warning in Rust style
+    cforge::logger::print_warning("circular include detected");
+    cforge::logger::print_plain("  --> " + chain.root);
+    cforge::logger::print_plain("   |");
+    cforge::logger::print_plain("   = " + chain_str);
+    cforge::logger::print_hint("consider forward declarations or restructuring");
+    cforge::logger::print_blank();
+  }
+
+  // Summary
+  std::string chains_msg = std::to_string(result.chains.size()) + " circular dependency chain"
+                         + (result.chains.size() == 1 ? "" : "s") + " detected";
+  cforge::logger::print_warning(chains_msg);
+
+  if (fail_on_circular) {
+    cforge::logger::print_error("Build failed due to circular dependencies "
+                                "(build.fail_on_circular = true)");
     return false;
   }
 
-  return true;
+  return true;
+}
+
+/**
+ * @brief Android NDK root from ANDROID_NDK_HOME or ANDROID_NDK_ROOT
+ *
+ * @return std::string NDK root, empty if neither is set
+ */
+static std::string android_ndk_root() {
+  for (cforge_cstring_t name : {"ANDROID_NDK_HOME", "ANDROID_NDK_ROOT"}) {
+    cforge_cstring_t value = std::getenv(name);
+    if (value != nullptr && *value != '\0') {
+      return value;
+    }
+  }
+  return "";
+}
+
+/**
+ * @brief Emscripten directory (the one holding emcc)
+ *
+ * Uses $EMSDK/upstream/emscripten, then the directory of the emcc found on
+ * PATH, resolving symlinks such as Homebrew's.
+ *
+ * @return std::string Emscripten directory, empty if Emscripten is not found
+ */
+static std::string emscripten_root() {
+  auto has_toolchain = [](const std::filesystem::path &dir) {
+    return std::filesystem::exists(dir / "cmake" / "Modules" / "Platform" / "Emscripten.cmake");
+  };
+
+  cforge_cstring_t emsdk = std::getenv("EMSDK");
+  if (emsdk != nullptr && *emsdk != '\0') {
+    std::filesystem::path dir = std::filesystem::path(emsdk) / "upstream" / "emscripten";
+    if (has_toolchain(dir)) {
+      return dir.generic_string();
+    }
+  }
+
+  cforge_cstring_t path_env = std::getenv("PATH");
+  if (path_env == nullptr) {
+    return "";
+  }
+#ifdef _WIN32
+  const char separator   = ';';
+  const std::string emcc = "emcc.bat";
+#else
+  const char separator   = ':';
+  const std::string emcc = "emcc";
+#endif
+  std::stringstream paths(path_env);
+  std::string entry;
+  while (std::getline(paths, entry, separator)) {
+    if (entry.empty()) {
+      continue;
+    }
+    std::error_code ec;
+    std::filesystem::path candidate = std::filesystem::path(entry) / emcc;
+    if (!std::filesystem::exists(candidate, ec)) {
+      continue;
+    }
+    std::filesystem::path dir = std::filesystem::weakly_canonical(candidate, ec).parent_path();
+    if (has_toolchain(dir)) {
+      return dir.generic_string();
+    }
+  }
+  return "";
+}
+
+/**
+ * @brief Read the settings of a cross target
+ *
+ * Reads a [cross.targets.<name>] entry (system, processor, sysroot,
+ * find_root, toolchain, c, cxx, rc or a compilers = { c, cxx, rc } table,
+ * and a variables table of extra CMake variables) on top of the predefined
+ * target of that name, if there is one.
+ *
+ * @param target_name Cross target name
+ * @param project_config Project configuration
+ * @return cforge::cross_target_settings Target settings
+ */
+static cforge::cross_target_settings
+read_cross_target(const std::string &target_name, const cforge::toml_reader &project_config) {
+  std::string key = "cross.targets." + target_name;
+  cforge::cross_target_settings target =
+      cforge::get_predefined_cross_target(target_name, android_ndk_root(), emscripten_root())
+          .value_or(cforge::cross_target_settings{});
+
+  auto read = [&](const std::string &field, std::string &value) {
+    value = project_config.get_string(key + "." + field, value);
+  };
+  read("system", target.system);
+  read("processor", target.processor);
+  read("sysroot", target.sysroot);
+  read("find_root", target.find_root);
+  read("toolchain", target.toolchain);
+  read("c", target.c);
+  read("compilers.c", target.c);
+  read("cxx", target.cxx);
+  read("compilers.cxx", target.cxx);
+  read("rc", target.rc);
+  read("compilers.rc", target.rc);
+  for (const auto &[var_name, var_value] : project_config.get_string_map(key + ".variables")) {
+    target.variables[var_name] = var_value;
+  }
+  return target;
+}
+
+/**
+ * @brief Write the CMake toolchain file for cross-compilation
+ *
+ * The file is written to <build_dir>/toolchains/<name>.cmake.
+ *
+ * @param build_dir Build directory
+ * @param name Target or profile name
+ * @param target Target settings
+ * @return std::filesystem::path Path to the toolchain file, or empty on failure
+ */
+static std::filesystem::path write_cross_toolchain(const std::filesystem::path &build_dir,
+                                                   const std::string &name,
+                                                   const cforge::cross_target_settings &target) {
+  std::filesystem::path toolchain_dir  = build_dir / "toolchains";
+  std::filesystem::path toolchain_path = toolchain_dir / (name + ".cmake");
+  try {
+    std::filesystem::create_directories(toolchain_dir);
+  } catch (const std::exception &e) {
+    cforge::logger::print_error("Failed to create toolchain directory: " + std::string(e.what()));
+    return {};
+  }
+
+  std::ofstream out(toolchain_path);
+  if (!out) {
+    cforge::logger::print_error("Failed to write toolchain file: " + toolchain_path.string());
+    return {};
+  }
+  out << cforge::generate_cross_toolchain_file(name, target);
+
+  cforge::logger::print_verbose("Generated toolchain file: " + toolchain_path.string());
+  return toolchain_path;
 }
 
 /**
- * @brief Check for circular include dependencies and warn/fail if found
+ * @brief Export the env table of a [cross.targets.<name>] entry
  *
- * @param project_dir Project directory
+ * The variables are set on the cforge process so CMake configure and build
+ * inherit them.
+ *
+ * @param target_name Cross target name
  * @param project_config Project configuration
- * @param verbose Verbose output
- * @return bool True if build should continue, false if it should fail
  */
-static bool check_circular_dependencies(const std::filesystem::path &project_dir,
-                                        const cforge::toml_reader &project_config,
-                                        bool verbose) {
-  // Check if circular dependency check is enabled (default: true)
-  bool warn_circular    = project_config.get_bool("build.warn_circular", true);
-  bool fail_on_circular = project_config.get_bool("build.fail_on_circular", false);
+static void apply_cross_target_environment(const std::string &target_name,
+                                           const cforge::toml_reader &project_config) {
+  auto env = project_config.get_string_map("cross.targets." + target_name + ".env");
+  for (const auto &[name, value] : env) {
+#ifdef _WIN32
+    _putenv_s(name.c_str(), value.c_str());
+#else
+    setenv(name.c_str(), value.c_str(), 1);
+#endif
+    cforge::logger::print_verbose("Environment: " + name + "=" + value);
+  }
+}
 
-  if (!warn_circular && !fail_on_circular) {
-    return true;  // Checks disabled
+/**
+ * @brief List cross-compilation profiles and custom targets from cforge.toml
+ *
+ * @param project_config Project configuration
+ */
+static void list_cross_targets(const cforge::toml_reader &project_config) {
+  auto profiles = project_config.get_table_keys("cross.profile");
+  auto targets  = project_config.get_table_keys("cross.targets");
+
+  if (profiles.empty() && targets.empty()) {
+    cforge::logger::print_status("No cross-compilation profiles or targets defined");
+    cforge::logger::print_hint("add [cross.targets.<name>] or [cross.profile.<name>] to "
+                               CFORGE_FILE);
+  }
+
+  if (!profiles.empty()) {
+    cforge::logger::print_section("Cross profiles:");
+    for (const auto &name : profiles) {
+      std::string system = project_config.get_string("cross.profile." + name + ".system", "");
+      cforge::logger::print_kv(name, system.empty() ? "(toolchain file)" : system, 20, 2);
+    }
+  }
+  if (!targets.empty()) {
+    cforge::logger::print_section("Custom cross targets:");
+    for (const auto &name : targets) {
+      std::string key       = "cross.targets." + name;
+      std::string system    = project_config.get_string(key + ".system", "");
+      std::string processor = project_config.get_string(key + ".processor", "");
+      std::string desc      = system;
+      if (!processor.empty()) {
+        desc += desc.empty() ? processor : " / " + processor;
+      }
+      cforge::logger::print_kv(name, desc.empty() ? "(unspecified)" : desc, 20, 2);
+    }
   }
+  cforge::logger::print_section("Predefined cross targets:");
+  for (const auto &name : cforge::predefined_cross_target_names()) {
+    cforge::logger::print_kv(name, cforge::get_predefined_cross_target(name)->description, 20, 2);
+  }
+  cforge::logger::print_dim(
+      "Use 'cforge build --profile <name>' or 'cforge build --target <name>' to cross-compile.", 2);
+}
 
-  if (verbose) {
-    cforge::logger::print_verbose("Checking for circular include dependencies...");
+/**
+ * @brief Report how many compiler warnings the last build produced
+ *
+ * Warnings are already printed in full unless --no-warnings was given, in
+ * which case the user is pointed at 'cforge warnings' instead.
+ */
+static void print_build_warning_summary() {
+  cforge_int_t warning_count = cforge::get_last_build_warning_count();
+  if (warning_count == 0) {
+    return;
   }
 
-  cforge::include_analyzer analyzer(project_dir);
-  cforge::include_analysis_result result = analyzer.analyze(false);  // Don't include deps
+  cforge::logger::print_warning(fmt::format(
+      "Build succeeded with {} warning{}", warning_count, warning_count == 1 ? "" : "s"));
+  if (cforge::g_suppress_warnings) {
+    cforge::logger::print_hint("run 'cforge warnings' to see them");
+  }
+}
 
-  if (!result.has_cycles) {
-    if (verbose) {
-      cforge::logger::print_verbose("No circular dependencies found");
-    }
-    return true;
+/**
+ * @brief Print the per-project timing table and the built/cached/failed count
+ */
+static void print_build_report(const cforge::build_report &report) {
+  if (report.projects.empty()) {
+    return;
   }
 
-  // Circular dependencies found - emit warnings
-  for (const auto &chain : result.chains) {
-    // Build the chain string
-    std::string chain_str;
-    for (cforge_size_t i = 0; i < chain.files.size(); ++i) {
-      chain_str += chain.files[i];
-      if (i < chain.files.size() - 1) {
-        chain_str += " -> ";
-      }
-    }
+  std::vector<int> widths = {20, 9, 10, 9, 9, 9, 7};
+  for (const auto &project : report.projects) {
+    widths[0] = std::max(widths[0], static_cast<int>(project.name.size()));
+  }
+
+  cforge::logger::print_blank();
+  cforge::logger::print_table_header(
+      {"Project", "Deps", "Configure", "Compile", "Link", "Total", "Status"}, widths, 2);
+  for (const auto &project : report.projects) {
+    cforge::logger::print_table_row({project.name,
+                                     cforge::format_build_seconds(project.deps_seconds),
+                                     cforge::format_build_seconds(project.configure_seconds),
+                                     cforge::format_build_seconds(project.compile_seconds),
+                                     cforge::format_build_seconds(project.link_seconds),
+                                     cforge::format_build_seconds(project.total_seconds()),
+                                     cforge::build_status_name(project.status)},
+                                    widths,
+                                    2);
+  }
+  if (report.configure_seconds > 0.0) {
+    cforge::logger::print_table_row(
+        {"(workspace)", "", cforge::format_build_seconds(report.configure_seconds), "", "", "", ""},
+        widths,
+        2);
+  }
+  cforge::logger::print_blank();
+  cforge::logger::print_dim(fmt::format("{} in {:.2f}s",
+                                        cforge::build_report_summary(report),
+                                        report.total_seconds),
+                            2);
+}
 
-    // Format warning in Rust style
-    cforge::logger::print_warning("circular include detected");
-    cforge::logger::print_plain("  --> " + chain.root);
-    cforge::logger::print_plain("   |");
-    cforge::logger::print_plain("   = " + chain_str);
-    cforge::logger::print_hint("consider forward declarations or restructuring");
-    cforge::logger::print_blank();
+/**
+ * @brief Write the build report as JSON for CI dashboards
+ *
+ * @param report Build report
+ * @param path Output file
+ * @return true if the file was written
+ */
+static bool write_build_report_json(const cforge::build_report &report,
+                                    const std::filesystem::path &path) {
+  std::ofstream out(path);
+  if (!out) {
+    return false;
   }
 
-  // Summary
-  std::string chains_msg = std::to_string(result.chains.size()) + " circular dependency chain"
-                         + (result.chains.size() == 1 ? "" : "s") + " detected";
-  cforge::logger::print_warning(chains_msg);
+  using cforge::logger;
+  out << "{\n";
+  out << "  \"config\": " << logger::json_quote(report.config) << ",\n";
+  out << "  \"summary\": " << logger::json_quote(cforge::build_report_summary(report)) << ",\n";
+  out << fmt::format("  \"configure_seconds\": {:.3f},\n", report.configure_seconds);
+  out << fmt::format("  \"total_seconds\": {:.3f},\n", report.total_seconds);
+  out << "  \"projects\": [";
+  for (cforge_size_t i = 0; i < report.projects.size(); ++i) {
+    const auto &project = report.projects[i];
+    out << (i == 0 ? "\n" : ",\n");
+    out << "    {\"name\": " << logger::json_quote(project.name)
+        << ", \"status\": " << logger::json_quote(cforge::build_status_name(project.status))
+        << fmt::format(", \"deps_seconds\": {:.3f}", project.deps_seconds)
+        << fmt::format(", \"configure_seconds\": {:.3f}", project.configure_seconds)
+        << fmt::format(", \"compile_seconds\": {:.3f}", project.compile_seconds)
+        << fmt::format(", \"link_seconds\": {:.3f}", project.link_seconds)
+        << fmt::format(", \"total_seconds\": {:.3f}", project.total_seconds())
+        << fmt::format(", \"compiled_files\": {}}}", project.compiled_files);
+  }
+  out << (report.projects.empty() ? "]\n" : "\n  ]\n");
+  out << "}\n";
+  return static_cast<bool>(out);
+}
 
-  if (fail_on_circular) {
-    cforge::logger::print_error("Build failed due to circular dependencies "
-                                "(build.fail_on_circular = true)");
+/**
+ * @brief Print the build report and write it to --report-json if requested
+ *
+ * @return false if the JSON report could not be written
+ */
+static bool finish_build_report(cforge::build_report &report,
+                                std::chrono::steady_clock::time_point build_start,
+                                const std::string &report_json) {
+  report.total_seconds =
+      std::chrono::duration<double>(std::chrono::steady_clock::now() - build_start).count();
+  print_build_report(report);
+
+  if (report_json.empty()) {
+    return true;
+  }
+  if (!write_build_report_json(report, report_json)) {
+    cforge::logger::print_error("Failed to write build report to " + report_json);
     return false;
   }
-
+  cforge::logger::print_action("Wrote", "build report to " + report_json);
   return true;
 }
 
@@ -1006,6 +2006,8 @@ static bool check_circular_dependencies(const std::filesystem::path &project_dir
  * @param target Optional target to build
  * @param built_projects Set of already built projects to avoid rebuilding
  * @param skip_deps Skip dependencies flag
+ * @param cross_profile Cross-compilation profile name
+ * @param report Optional build report to record this project's timings in
  * @return bool Success flag
  */
 static bool build_project(const std::filesystem::path &project_dir,
@@ -1015,7 +2017,8 @@ static bool build_project(const std::filesystem::path &project_dir,
                           const std::string &target             = "",
                           std::set<std::string> *built_projects = nullptr,
                           bool skip_deps                        = false,
-                          const std::string &cross_profile      = "") {
+                          const std::string &cross_profile      = "",
+                          cforge::build_report *report          = nullptr) {
   // Start project build timer
   auto project_build_start = std::chrono::steady_clock::now();
 
@@ -1049,6 +2052,14 @@ static bool build_project(const std::filesystem::path &project_dir,
 
   cforge::logger::building(project_name + " [" + build_config + "]");
 
+  // Stays failed unless the build tool runs; apply_build_timings() sets the
+  // final status
+  cforge::project_build_times *report_entry = nullptr;
+  if (report) {
+    report->projects.push_back({project_name, cforge::project_build_status::FAILED});
+    report_entry = &report->projects.back();
+  }
+
   // Pre-build check for circular include dependencies
   if (has_project_config && !check_circular_dependencies(project_dir, project_config, verbose)) {
     return false;
@@ -1063,9 +2074,12 @@ static bool build_project(const std::filesystem::path &project_dir,
     use_workspace_build = true;
   }
   // Determine build and source directories
-  std::filesystem::path build_base_dir = use_workspace_build ? workspace_dir / DEFAULT_BUILD_DIR
-                                                             : project_dir / DEFAULT_BUILD_DIR;
-  std::filesystem::path source_dir     = use_workspace_build ? workspace_dir : project_dir;
+  std::filesystem::path build_base_dir =
+      use_workspace_build ? cforge::get_build_base_dir(workspace_dir, nullptr)
+                          : cforge::get_build_base_dir(project_dir,
+                                                       has_project_config ? &project_config
+                                                                          : nullptr);
+  std::filesystem::path source_dir = use_workspace_build ? workspace_dir : project_dir;
 
   // Get the config-specific build directory
   std::filesystem::path build_dir = cforge::get_build_dir_for_config(build_base_dir.string(),
@@ -1086,6 +2100,8 @@ static bool build_project(const std::filesystem::path &project_dir,
   // Handle project-level dependencies and CMakeLists generation (skip in
   // workspace build)
   if (!use_workspace_build && has_project_config) {
+    auto deps_start = std::chrono::steady_clock::now();
+
     // Resolve index/registrydependencies first (they get cloned to deps/)
     // Skip if using FetchContent mode (CMake will handle downloading)
     bool use_fetch_content = project_config.get_bool("dependencies.fetch_content", true);
@@ -1093,6 +2109,9 @@ static bool build_project(const std::filesystem::path &project_dir,
       try {
         std::filesystem::current_path(project_dir);
         if (!resolve_index_dependencies(project_dir, project_config, verbose, skip_deps)) {
+          if (cforge_is_offline()) {
+            return false;
+          }
           cforge::logger::print_warning("Some index dependencies could not be resolved");
         }
       } catch (const std::exception &ex) {
@@ -1138,6 +2157,10 @@ static bool build_project(const std::filesystem::path &project_dir,
         cforge::update_lockfile(project_dir, deps_path, verbose);
       }
     }
+    if (report_entry) {
+      report_entry->deps_seconds =
+          std::chrono::duration<double>(std::chrono::steady_clock::now() - deps_start).count();
+    }
 
     // Generate CMakeLists.txt in the build directory
     std::filesystem::path timestamp_file = build_dir / ".cforge_cmakefile_timestamp";
@@ -1156,9 +2179,17 @@ static bool build_project(const std::filesystem::path &project_dir,
     }
   }
 
+  // Refresh cforge_build_info.h (only rewritten when its contents change)
+  if (has_project_config && cforge::is_build_info_enabled(project_config)) {
+    cforge::write_build_info_header(project_dir, build_dir, project_config);
+  }
+
   // Prepare CMake arguments
   std::vector<std::string> cmake_args = {
-      "-S", source_dir.string(), "-B", build_dir.string(), "-DCMAKE_BUILD_TYPE=" + build_config};
+      "-S",
+      source_dir.string(),
+      "-B",
+      build_dir.string()};
 
   // ccache/sccache integration
   // Configuration: build.compiler_cache = "auto" (default), "ccache",
@@ -1197,57 +2228,18 @@ static bool build_project(const std::filesystem::path &project_dir,
     }
   }
 
-  // Inject top-level build.defines into CMake args
-  if (has_project_config && project_config.has_key("build.defines")) {
-    auto global_defs = project_config.get_string_array("build.defines");
-    for (const auto &d : global_defs) {
-      std::string def = d;
-      // Append '=ON' if no value provided
-      if (def.find('=') == std::string::npos) {
-        def += "=ON";
-      }
-      cmake_args.push_back(std::string("-D") + def);
-    }
-  }
-  // Inject config-specific defines: build.config.<config>.defines
-  {
-    std::string defs_key = "build.config." + cforge::string_to_lower(build_config) + ".defines";
-    if (has_project_config && project_config.has_key(defs_key)) {
-      auto cfg_defs = project_config.get_string_array(defs_key);
-      for (const auto &d : cfg_defs) {
-        std::string def = d;
-        // Append '=ON' if no value provided
-        if (def.find('=') == std::string::npos) {
-          def += "=ON";
-        }
-        cmake_args.push_back(std::string("-D") + def);
-      }
-    }
-  }
-
-  // Add any custom CMake arguments
+  // build.defines, build.config.<config>.defines/cmake_args and [cmake.cache]
   if (has_project_config) {
-    std::string config_key = "build.config." + cforge::string_to_lower(build_config)
-                           + ".cmake_args";
-    if (project_config.has_key(config_key)) {
-      auto custom_args = project_config.get_string_array(config_key);
-      for (const auto &arg : custom_args) {
-        cmake_args.push_back(arg);
-      }
-    }
+    auto config_args = cforge::get_config_cmake_args(project_config, build_config);
+    cmake_args.insert(cmake_args.end(), config_args.begin(), config_args.end());
   }
 
-  // Cross-compilation settings
-  // Supports both [cross] section and [cross.profile.<name>] profiles
+  // Cross-compilation settings, from [cross.profile.<name>], a custom or
+  // predefined cross target, or the default [cross] section. They are
+  // written to one toolchain file rather than passed as separate -D options.
   bool cross_enabled = false;
-  std::string cross_system;
-  std::string cross_processor;
-  std::string cross_toolchain;
-  std::string cross_c_compiler;
-  std::string cross_cxx_compiler;
-  std::string cross_sysroot;
-  std::string cross_find_root;
-  std::map<std::string, std::string> cross_variables;
+  std::string cross_name;
+  cforge::cross_target_settings cross_target;
 
   if (has_project_config) {
     // Check if a profile is specified via command line
@@ -1256,161 +2248,114 @@ static bool build_project(const std::filesystem::path &project_dir,
       if (project_config.has_key(profile_key + ".system")
           || project_config.has_key(profile_key + ".toolchain")) {
         cross_enabled = true;
+        cross_name    = cross_profile;
         cforge::logger::print_action("Cross-compiling", "using profile '" + cross_profile + "'");
 
         // Read profile settings
-        cross_system    = project_config.get_string(profile_key + ".system", "");
-        cross_processor = project_config.get_string(profile_key + ".processor", "");
-        cross_toolchain = project_config.get_string(profile_key + ".toolchain", "");
-        cross_sysroot   = project_config.get_string(profile_key + ".sysroot", "");
+        cross_target.system    = project_config.get_string(profile_key + ".system", "");
+        cross_target.processor = project_config.get_string(profile_key + ".processor", "");
+        cross_target.toolchain = project_config.get_string(profile_key + ".toolchain", "");
+        cross_target.sysroot   = project_config.get_string(profile_key + ".sysroot", "");
+        cross_target.find_root = project_config.get_string(profile_key + ".find_root", "");
 
         // Compilers can be specified as inline table or separate keys
-        cross_c_compiler   = project_config.get_string(profile_key + ".compilers.c", "");
-        cross_cxx_compiler = project_config.get_string(profile_key + ".compilers.cxx", "");
-        if (cross_c_compiler.empty()) {
-          cross_c_compiler = project_config.get_string(profile_key + ".c", "");
+        cross_target.c   = project_config.get_string(profile_key + ".compilers.c", "");
+        cross_target.cxx = project_config.get_string(profile_key + ".compilers.cxx", "");
+        if (cross_target.c.empty()) {
+          cross_target.c = project_config.get_string(profile_key + ".c", "");
         }
-        if (cross_cxx_compiler.empty()) {
-          cross_cxx_compiler = project_config.get_string(profile_key + ".cxx", "");
+        if (cross_target.cxx.empty()) {
+          cross_target.cxx = project_config.get_string(profile_key + ".cxx", "");
         }
 
         // Read variables as inline table
-        cross_variables = project_config.get_string_map(profile_key + ".variables");
+        cross_target.variables = project_config.get_string_map(profile_key + ".variables");
 
         // Read embedded/bare-metal options from profile
         if (project_config.get_bool(profile_key + ".nostdlib", false)) {
-          cross_variables["CFORGE_NOSTDLIB"] = "ON";
+          cross_target.variables["CFORGE_NOSTDLIB"] = "ON";
         }
         if (project_config.get_bool(profile_key + ".nostartfiles", false)) {
-          cross_variables["CFORGE_NOSTARTFILES"] = "ON";
+          cross_target.variables["CFORGE_NOSTARTFILES"] = "ON";
         }
         if (project_config.get_bool(profile_key + ".nodefaultlibs", false)) {
-          cross_variables["CFORGE_NODEFAULTLIBS"] = "ON";
+          cross_target.variables["CFORGE_NODEFAULTLIBS"] = "ON";
         }
         // Pass active profile name so CMake knows which post_build/flash
         // targets to use
-        cross_variables["CFORGE_CROSS_PROFILE"] = cross_profile;
+        cross_target.variables["CFORGE_CROSS_PROFILE"] = cross_profile;
+      } else if (project_config.has_key("cross.targets." + cross_profile)
+                 || cforge::get_predefined_cross_target(cross_profile)) {
+        // Custom or predefined target
+        cross_enabled = true;
+        cross_name    = cross_profile;
+        cross_target  = read_cross_target(cross_profile, project_config);
+        cforge::logger::print_action("Cross-compiling", "for target '" + cross_profile + "'");
+        apply_cross_target_environment(cross_profile, project_config);
+        if (cross_profile == "wasm" && cross_target.toolchain.empty()
+            && emscripten_root().empty()) {
+          cforge::logger::print_warning("Emscripten not found; trying emcc and em++ from PATH");
+          cforge::logger::print_hint(
+              "install the Emscripten SDK and run 'emsdk_env', or set EMSDK");
+        }
       } else {
         cforge::logger::print_error("Cross-compilation profile '" + cross_profile + "' not found");
+        cforge::logger::print_hint("run 'cforge build --list-profiles' to see available profiles");
         return false;
       }
     }
     // Check default [cross] section if no profile specified
     else if (project_config.get_bool("cross.enabled", false)) {
       cross_enabled = true;
+      cross_name    = "default";
       cforge::logger::print_action("Cross-compiling", "using default cross configuration");
 
       // Read [cross.target] settings
-      cross_system    = project_config.get_string("cross.target.system", "");
-      cross_processor = project_config.get_string("cross.target.processor", "");
-      cross_toolchain = project_config.get_string("cross.target.toolchain", "");
+      cross_target.system    = project_config.get_string("cross.target.system", "");
+      cross_target.processor = project_config.get_string("cross.target.processor", "");
+      cross_target.toolchain = project_config.get_string("cross.target.toolchain", "");
 
       // Read [cross.compilers] settings
-      cross_c_compiler   = project_config.get_string("cross.compilers.c", "");
-      cross_cxx_compiler = project_config.get_string("cross.compilers.cxx", "");
+      cross_target.c   = project_config.get_string("cross.compilers.c", "");
+      cross_target.cxx = project_config.get_string("cross.compilers.cxx", "");
 
       // Read [cross.paths] settings
-      cross_sysroot   = project_config.get_string("cross.paths.sysroot", "");
-      cross_find_root = project_config.get_string("cross.paths.find_root", "");
+      cross_target.sysroot   = project_config.get_string("cross.paths.sysroot", "");
+      cross_target.find_root = project_config.get_string("cross.paths.find_root", "");
 
       // Read [cross.variables] as inline table
-      cross_variables = project_config.get_string_map("cross.variables");
+      cross_target.variables = project_config.get_string_map("cross.variables");
 
       // Read embedded/bare-metal options from default cross section
       if (project_config.get_bool("cross.nostdlib", false)) {
-        cross_variables["CFORGE_NOSTDLIB"] = "ON";
+        cross_target.variables["CFORGE_NOSTDLIB"] = "ON";
       }
       if (project_config.get_bool("cross.nostartfiles", false)) {
-        cross_variables["CFORGE_NOSTARTFILES"] = "ON";
+        cross_target.variables["CFORGE_NOSTARTFILES"] = "ON";
       }
       if (project_config.get_bool("cross.nodefaultlibs", false)) {
-        cross_variables["CFORGE_NODEFAULTLIBS"] = "ON";
-      }
-    }
-
-    // Apply cross-compilation settings to CMake args
-    if (cross_enabled) {
-      if (!cross_toolchain.empty()) {
-        // Expand environment variables in toolchain path
-        std::string expanded_toolchain = cross_toolchain;
-        cforge_size_t pos              = 0;
-        while ((pos = expanded_toolchain.find("${", pos)) != std::string::npos) {
-          cforge_size_t end = expanded_toolchain.find("}", pos);
-          if (end != std::string::npos) {
-            std::string var_name  = expanded_toolchain.substr(pos + 2, end - pos - 2);
-            cforge_cstring_t var_value = std::getenv(var_name.c_str());
-            if (var_value) {
-              expanded_toolchain.replace(pos, end - pos + 1, var_value);
-            } else {
-              pos = end + 1;
-            }
-          } else {
-            break;
-          }
-        }
-        cmake_args.push_back("-DCMAKE_TOOLCHAIN_FILE=" + expanded_toolchain);
-        cforge::logger::print_verbose("Using toolchain file: " + expanded_toolchain);
-      }
-      if (!cross_system.empty()) {
-        cmake_args.push_back("-DCMAKE_SYSTEM_NAME=" + cross_system);
-        cforge::logger::print_verbose("Target system: " + cross_system);
-      }
-      if (!cross_processor.empty()) {
-        cmake_args.push_back("-DCMAKE_SYSTEM_PROCESSOR=" + cross_processor);
-        cforge::logger::print_verbose("Target processor: " + cross_processor);
-      }
-      if (!cross_c_compiler.empty()) {
-        cmake_args.push_back("-DCMAKE_C_COMPILER=" + cross_c_compiler);
-        cforge::logger::print_verbose("C compiler: " + cross_c_compiler);
-      }
-      if (!cross_cxx_compiler.empty()) {
-        cmake_args.push_back("-DCMAKE_CXX_COMPILER=" + cross_cxx_compiler);
-        cforge::logger::print_verbose("C++ compiler: " + cross_cxx_compiler);
-      }
-      if (!cross_sysroot.empty()) {
-        cmake_args.push_back("-DCMAKE_SYSROOT=" + cross_sysroot);
-        cforge::logger::print_verbose("Sysroot: " + cross_sysroot);
-      }
-      if (!cross_find_root.empty()) {
-        cmake_args.push_back("-DCMAKE_FIND_ROOT_PATH=" + cross_find_root);
-        cforge::logger::print_verbose("Find root path: " + cross_find_root);
-      }
-      // Apply custom variables
-      for (const auto &[var_name, var_value] : cross_variables) {
-        cmake_args.push_back("-D" + var_name + "=" + var_value);
-        cforge::logger::print_verbose("Variable: " + var_name + "=" + var_value);
+        cross_target.variables["CFORGE_NODEFAULTLIBS"] = "ON";
       }
     }
   }
 
-  // Custom compiler specification: separate C and C++ compilers
-  if (has_project_config && project_config.has_key("cmake.c_compiler")) {
-    std::string cc = project_config.get_string("cmake.c_compiler", "");
-    if (!cc.empty()) {
-      cmake_args.push_back("-DCMAKE_C_COMPILER=" + cc);
-      cforge::logger::print_verbose("Using C compiler: " + cc);
+  std::filesystem::path cross_toolchain;
+  if (cross_enabled) {
+    cross_toolchain = write_cross_toolchain(build_dir, cross_name, cross_target);
+    if (cross_toolchain.empty()) {
+      return false;
     }
-  }
-  if (has_project_config && project_config.has_key("cmake.cxx_compiler")) {
-    std::string cxx = project_config.get_string("cmake.cxx_compiler", "");
-    if (!cxx.empty()) {
-      cmake_args.push_back("-DCMAKE_CXX_COMPILER=" + cxx);
-      cforge::logger::print_verbose("Using C++ compiler: " + cxx);
+    if (!cross_target.toolchain.empty()) {
+      cforge::logger::print_verbose("Including toolchain file: " + cross_target.toolchain);
     }
   }
+  const std::string &cross_system    = cross_target.system;
+  const std::string &cross_processor = cross_target.processor;
 
-  // Project-level C and C++ standard overrides
+  // Custom C/C++ compilers and language standards
   if (has_project_config) {
-    std::string cstd = project_config.get_string("project.c_standard", "");
-    if (!cstd.empty()) {
-      cmake_args.push_back("-DCMAKE_C_STANDARD=" + cstd);
-      cforge::logger::print_verbose("Using C standard: " + cstd);
-    }
-    std::string cppstd = project_config.get_string("project.cpp_standard", "");
-    if (!cppstd.empty()) {
-      cmake_args.push_back("-DCMAKE_CXX_STANDARD=" + cppstd);
-      cforge::logger::print_verbose("Using C++ standard: " + cppstd);
-    }
+    auto compiler_args = cforge::get_compiler_cmake_args(project_config);
+    cmake_args.insert(cmake_args.end(), compiler_args.begin(), compiler_args.end());
   }
 
   // Determine CMake generator: use override in cforge.toml if present,
@@ -1430,35 +2375,99 @@ static bool build_project(const std::filesystem::path &project_dir,
   }
 
   // vcpkg integration: support path and triplet
-  if (has_project_config && project_config.has_key("dependencies.vcpkg")) {
-    // Determine vcpkg root directory
-    std::string vcpkg_root;
-    if (project_config.has_key("dependencies.vcpkg.path")) {
-      vcpkg_root = project_config.get_string("dependencies.vcpkg.path", "");
-    } else if (cforge_cstring_t env = std::getenv("VCPKG_ROOT")) {
-      vcpkg_root = env;
-    } else {
-      vcpkg_root = (source_dir / "vcpkg").string();
-    }
-    // Compute toolchain file path
-    std::string toolchain_path = vcpkg_root + "/scripts/buildsystems/vcpkg.cmake";
-    std::replace(toolchain_path.begin(), toolchain_path.end(), '\\', '/');
-    if (std::filesystem::exists(toolchain_path)) {
-      cmake_args.push_back("-DCMAKE_TOOLCHAIN_FILE=" + toolchain_path);
-      cforge::logger::print_verbose("Using vcpkg toolchain: " + toolchain_path);
-    } else {
-      cforge::logger::print_warning("vcpkg toolchain file not found: " + toolchain_path);
+  if (has_project_config) {
+    if (cforge::is_vcpkg_manifest_mode(project_config)
+        && !cforge::write_vcpkg_manifest(project_dir, project_config)) {
+      return false;
+    }
+    std::string vcpkg_processor = cross_processor.empty() ? s_msvc_arch : cross_processor;
+    std::string vcpkg_triplet =
+        cforge::resolve_vcpkg_triplet(project_config, cross_system, vcpkg_processor, build_config);
+    if (project_config.has_key("dependencies.vcpkg") && !vcpkg_triplet.empty()
+        && !cforge::is_valid_vcpkg_triplet(vcpkg_triplet)) {
+      cforge::logger::print_error("invalid vcpkg triplet '" + vcpkg_triplet + "'");
+      cforge::logger::print_hint("triplets are lower-case words separated by dashes, "
+                                 "e.g. x64-windows-static");
+      return false;
+    }
+    for (const auto &arg : cforge::get_vcpkg_cmake_args(
+             project_config, source_dir, cross_system, vcpkg_processor, build_config)) {
+      // vcpkg's toolchain loads the cross toolchain itself
+      if (!cross_toolchain.empty() && arg.rfind("-DCMAKE_TOOLCHAIN_FILE=", 0) == 0) {
+        cmake_args.push_back("-DVCPKG_CHAINLOAD_TOOLCHAIN_FILE="
+                             + cross_toolchain.generic_string());
+        cforge::logger::print_verbose("Chain-loading toolchain file from vcpkg");
+        cross_toolchain.clear();
+      }
+      cmake_args.push_back(arg);
     }
-    // Add triplet if specified
-    if (project_config.has_key("dependencies.vcpkg.triplet")) {
-      std::string triplet = project_config.get_string("dependencies.vcpkg.triplet", "");
-      if (!triplet.empty()) {
-        cmake_args.push_back("-DVCPKG_TARGET_TRIPLET=" + triplet);
-        cforge::logger::print_verbose("Using vcpkg triplet: " + triplet);
+
+    if (cforge_is_offline()
+        && !check_offline_dependencies(
+            project_dir,
+            project_config,
+            build_dir,
+            cforge::get_vcpkg_root(project_config, source_dir),
+            vcpkg_triplet)) {
+      return false;
+    }
+  }
+
+  if (!cross_toolchain.empty()) {
+    cmake_args.push_back("-DCMAKE_TOOLCHAIN_FILE=" + cross_toolchain.generic_string());
+  }
+
+  // conan installs into <build dir>/conan and generates the files
+  // find_package() needs there, along with a toolchain file that points CMake
+  // at them
+  if (has_project_config && !cforge::get_conan_references(project_dir).empty()) {
+    std::string conan_build_type = cforge::canonical_build_config(build_config);
+    if (!cforge::is_conan_install_current(project_dir, conan_build_type)) {
+      if (cforge_is_offline()) {
+        print_offline_dependency_error("conan packages for " + conan_build_type,
+                                       "they are not installed in "
+                                           + cforge::get_conan_dir(project_dir).string());
+        return false;
       }
+      if (!cforge::install_conan_packages(project_dir, conan_build_type, verbose)) {
+        return false;
+      }
+    }
+    std::filesystem::path conan_toolchain = cforge::get_conan_toolchain_file(project_dir);
+    if (conan_toolchain.empty()) {
+      cforge::logger::print_error("conan install did not generate a CMake toolchain file");
+      return false;
     }
+    bool has_toolchain = std::any_of(cmake_args.begin(), cmake_args.end(), [](const auto &arg) {
+      return arg.rfind("-DCMAKE_TOOLCHAIN_FILE=", 0) == 0;
+    });
+    if (!has_toolchain) {
+      cmake_args.push_back("-DCMAKE_TOOLCHAIN_FILE=" + conan_toolchain.generic_string());
+      cforge::logger::print_verbose("Using conan toolchain: " + conan_toolchain.string());
+    } else {
+      // Only one toolchain file can be used; with vcpkg's or a cross
+      // toolchain, find the generated package files through the search paths
+      std::string conan_dir = conan_toolchain.parent_path().generic_string();
+      cmake_args.push_back("-DCMAKE_PREFIX_PATH=" + conan_dir);
+      cmake_args.push_back("-DCMAKE_MODULE_PATH=" + conan_dir);
+      cforge::logger::print_verbose("Finding conan packages in " + conan_dir);
+    }
+  }
+
+  if (has_project_config && !ensure_pkg_config_available(project_config, cmake_args)) {
+    return false;
   }
 
+  // --define values, applied on top of the defines from cforge.toml, and
+  // the --variant selection
+  cmake_args.push_back(cforge::cli_defines_cache_arg(s_cli_defines));
+  cmake_args.push_back(cforge::variants_cache_arg(s_variants));
+
+  // FetchContent uses what is in _deps instead of downloading; the setting is
+  // cached, so online builds turn it back off
+  cmake_args.push_back(std::string("-DFETCHCONTENT_FULLY_DISCONNECTED=")
+                       + (cforge_is_offline() ? "ON" : "OFF"));
+
   // If using Ninja and a toolset is specified, force C/C++ compilers
   if (generator.find("Ninja") != std::string::npos && has_project_config
       && project_config.has_key("cmake.toolset")) {
@@ -1477,15 +2486,60 @@ static bool build_project(const std::filesystem::path &project_dir,
     generator = cforge::get_cmake_generator();
     cforge::logger::print_verbose("Using fallback CMake generator: " + generator);
   }
+  if (!cforge::prepare_build_dir_for_generator(build_dir, generator, s_auto_clean)) {
+    return false;
+  }
+  if (!ensure_ninja_available(generator, cmake_args)) {
+    return false;
+  }
   // Inject generator flag
   cmake_args.push_back("-G");
   cmake_args.push_back(generator);
 
+  // Multi-config generators keep every configuration in one tree and pick
+  // it at build time with --config
+  bool multi_config = cforge::is_multi_config_generator(generator);
+  if (!multi_config) {
+    cmake_args.push_back("-DCMAKE_BUILD_TYPE=" + cforge::canonical_build_config(build_config));
+  }
+
+  // --arch wins over cmake.platform, which also selects the vcvars target
+  std::string msvc_arch = s_msvc_arch;
+  if (msvc_arch.empty() && has_project_config) {
+    msvc_arch = cforge::normalize_msvc_arch(project_config.get_string("cmake.platform", ""));
+  }
+
+  // Ninja/NMake builds with cl or clang-cl need the MSVC developer environment
+  std::string msvc_compiler =
+      has_project_config ? project_config.get_string("cmake.cxx_compiler",
+                                                     project_config.get_string("cmake.toolset", ""))
+                         : "";
+  if (cross_profile.empty() && cforge::uses_msvc_environment(generator, msvc_compiler)
+      && !cforge::setup_msvc_environment(msvc_arch, verbose)) {
+    if (msvc_compiler.empty()) {
+      cforge::logger::print_verbose("MSVC developer environment not found; "
+                                    "CMake will pick a compiler from PATH");
+    } else {
+      cforge::logger::print_warning("cl.exe is not on PATH and no Visual Studio install with "
+                                    "the C++ toolset was found");
+      cforge::logger::print_hint("install the Visual Studio Build Tools 'Desktop development "
+                                 "with C++' workload, or build from a Developer Command Prompt");
+    }
+  }
+
+  // The cross toolchain picks its own compilers
+  if (has_project_config && !cross_enabled
+      && !verify_toolchain(project_config, build_dir, generator)) {
+    return false;
+  }
+
   // If Visual Studio generator, specify platform and optional toolset
   if (generator.rfind("Visual Studio", 0) == 0) {
-    // Read platform from config or default to x64
+    // Read platform from --arch, then config, or default to x64
     std::string platform = "x64";
-    if (has_project_config && project_config.has_key("cmake.platform")) {
+    if (!s_msvc_arch.empty()) {
+      platform = cforge::visual_studio_platform(s_msvc_arch);
+    } else if (has_project_config && project_config.has_key("cmake.platform")) {
       platform = project_config.get_string("cmake.platform", platform);
     }
     cmake_args.push_back("-A");
@@ -1523,16 +2577,41 @@ static bool build_project(const std::filesystem::path &project_dir,
     return false;
   }
 
+  // Export [env] for this project's CMake configure and build only
+  cforge::scoped_environment project_env(
+      has_project_config ? cforge::get_project_environment(project_config, build_config)
+                         : std::map<std::string, std::string>{});
+
+  // Switching configurations in a multi-config tree needs no configure, as
+  // long as cforge.toml, the --define values and the variants are unchanged
+  bool reuse_configure =
+      multi_config && cforge::is_build_dir_configured_for(build_dir, build_config)
+      && cforge::is_config_hash_current(project_dir, build_dir, s_cli_defines, s_variants);
+
   // Run CMake configuration
-  cforge::logger::configuring("project with CMake");
-  bool configure_result =
-      run_cmake_configure(cmake_args, build_dir.string(), project_dir.string(), verbose);
+  auto configure_start  = std::chrono::steady_clock::now();
+  bool configure_result = true;
+  if (reuse_configure) {
+    cforge::logger::print_verbose("Reusing the configured " + generator + " tree for "
+                                  + build_config);
+  } else {
+    cforge::logger::configuring("project with CMake");
+    std::vector<std::string> configure_args = configure_with_presets(
+        cmake_args, source_dir, build_dir, build_config, use_workspace_build);
+    configure_result =
+        run_cmake_configure(configure_args, build_dir.string(), project_dir.string(), verbose);
+  }
+  if (report_entry) {
+    report_entry->configure_seconds =
+        std::chrono::duration<double>(std::chrono::steady_clock::now() - configure_start).count();
+  }
 
   if (!configure_result) {
     cforge::logger::print_error("CMake configuration failed for project: " + project_name);
     std::filesystem::current_path(original_dir);
     return false;
   }
+  cforge::write_config_hash(project_dir, build_dir, s_cli_defines, s_variants);
 
   // Run CMake build
   cforge::logger::compiling(project_name);
@@ -1587,6 +2666,9 @@ static bool build_project(const std::filesystem::path &project_dir,
   cforge_int_t build_timeout = 600;  // 10 minutes
   bool build_result =
       cforge::execute_tool("cmake", build_args, "", "CMake Build", verbose, build_timeout);
+  if (report) {
+    cforge::apply_build_timings(*report, cforge::get_last_build_timings(), build_result);
+  }
 
   // Clean up empty config directories under the build root
   for (const auto &cfg : {"Debug", "Release", "RelWithDebInfo"}) {
@@ -1615,6 +2697,7 @@ static bool build_project(const std::filesystem::path &project_dir,
                            .count();
     std::string duration_str = fmt::format("{:.2f}s", duration_ms / 1000.0);
     cforge::logger::finished(build_config, duration_str);
+    print_build_warning_summary();
 
     // If we're tracking built projects, add this one
     if (built_projects) {
@@ -1697,10 +2780,11 @@ static bool build_project(const std::filesystem::path &project_dir,
   cforge::toml_reader config_data(config_table);
 
   // Determine build directory
-  std::string base_build_dir = config_data.get_string("build.build_dir", "build");
+  std::filesystem::path base_build_dir = cforge::get_build_base_dir(project.path, &config_data);
 
   // Get the config-specific build directory
-  std::filesystem::path build_dir = cforge::get_build_dir_for_config(base_build_dir, build_config);
+  std::filesystem::path build_dir = cforge::get_build_dir_for_config(base_build_dir.string(),
+                                                                     build_config);
 
   // Build the project
   bool success = build_project(
@@ -1715,12 +2799,12 @@ static bool build_project(const std::filesystem::path &project_dir,
 }
 
 /**
- * @brief Handle the 'build' command
+ * @brief Run the 'build' command
  *
  * @param ctx Context containing parsed arguments
  * @return cforge_int_t Exit code (0 for success)
  */
-cforge_int_t cforge_cmd_build(const cforge_context_t *ctx) {
+static cforge_int_t run_build_command(const cforge_context_t *ctx) {
   // Check for help flag first
   for (cforge_int_t i = 0; i < ctx->args.arg_count; i++) {
     std::string arg = ctx->args.args[i];
@@ -1736,6 +2820,9 @@ cforge_int_t cforge_cmd_build(const cforge_context_t *ctx) {
                                 "https://cmake.org/download/ and ensure it is in your PATH.");
     return 1;
   }
+  if (!check_cmake_version(ctx->working_dir)) {
+    return 1;
+  }
 
   // Start build timer
   auto build_start_time = std::chrono::steady_clock::now();
@@ -1744,6 +2831,19 @@ cforge_int_t cforge_cmd_build(const cforge_context_t *ctx) {
   std::filesystem::path current_dir  = std::filesystem::path(ctx->working_dir);
   auto [is_workspace, workspace_dir] = cforge::is_in_workspace(current_dir);
 
+  // List cross profiles/targets and exit
+  for (cforge_int_t i = 0; i < ctx->args.arg_count; i++) {
+    std::string arg = ctx->args.args[i];
+    if (arg == "--list-profiles" || arg == "--list-targets") {
+      cforge::toml_reader list_cfg;
+      if (!list_cfg.load((current_dir / CFORGE_FILE).string())) {
+        return 1;
+      }
+      list_cross_targets(list_cfg);
+      return 0;
+    }
+  }
+
   // Parse command line arguments
   std::string config_name;
   cforge_int_t num_jobs = 0;
@@ -1754,6 +2854,12 @@ cforge_int_t cforge_cmd_build(const cforge_context_t *ctx) {
   [[maybe_unused]] bool generate_workspace_cmake = false;
   [[maybe_unused]] bool force_regenerate         = false;
   bool skip_deps                                 = false;
+  std::string report_json;  // --report-json output file
+  std::vector<std::string> only_projects;      // --only, workspace builds
+  std::vector<std::string> excluded_projects;  // --exclude, workspace builds
+  s_cli_defines.clear();
+  s_variants.clear();
+  s_use_presets = false;
 
   // Extract command line arguments
   for (cforge_int_t i = 0; i < ctx->args.arg_count; i++) {
@@ -1761,6 +2867,10 @@ cforge_int_t cforge_cmd_build(const cforge_context_t *ctx) {
 
     if (arg == "--skip-deps" || arg == "--no-deps") {
       skip_deps = true;
+    } else if (arg == "--no-auto-clean") {
+      s_auto_clean = false;
+    } else if (arg == "--use-presets") {
+      s_use_presets = true;
     } else if (arg == "--no-warnings") {
       cforge::g_suppress_warnings = true;
       cforge::logger::print_verbose("Suppressing build warnings (--no-warnings flag)");
@@ -1790,11 +2900,27 @@ cforge_int_t cforge_cmd_build(const cforge_context_t *ctx) {
         target = ctx->args.args[i + 1];
         i++;  // Skip the next argument
       }
+    } else if (arg.substr(0, 9) == "--target=") {
+      target = arg.substr(9);
     } else if (arg == "-p" || arg == "--project") {
       if (i + 1 < ctx->args.arg_count) {
         project_name = ctx->args.args[i + 1];
         i++;  // Skip the next argument
       }
+    } else if (arg == "--only" || arg == "--exclude") {
+      if (i + 1 >= ctx->args.arg_count) {
+        cforge::logger::print_error(arg + " requires project names (comma-separated)");
+        return 1;
+      }
+      auto names = cforge::split_project_list(ctx->args.args[++i]);
+      auto &list = arg == "--only" ? only_projects : excluded_projects;
+      list.insert(list.end(), names.begin(), names.end());
+    } else if (arg.rfind("--only=", 0) == 0) {
+      auto names = cforge::split_project_list(arg.substr(7));
+      only_projects.insert(only_projects.end(), names.begin(), names.end());
+    } else if (arg.rfind("--exclude=", 0) == 0) {
+      auto names = cforge::split_project_list(arg.substr(10));
+      excluded_projects.insert(excluded_projects.end(), names.begin(), names.end());
     } else if (arg == "--gen-workspace-cmake") {
       generate_workspace_cmake = true;
     } else if (arg == "--force-regenerate") {
@@ -1808,6 +2934,56 @@ cforge_int_t cforge_cmd_build(const cforge_context_t *ctx) {
     } else if (arg.substr(0, 10) == "--profile=") {
       cross_profile = arg.substr(10);
       cforge::logger::print_verbose("Using cross-compilation profile: " + cross_profile);
+    } else if (arg == "--arch" || arg.rfind("--arch=", 0) == 0) {
+      std::string value;
+      if (arg == "--arch") {
+        if (i + 1 >= ctx->args.arg_count) {
+          cforge::logger::print_error("--arch requires an architecture (x64, x86 or arm64)");
+          return 1;
+        }
+        value = ctx->args.args[++i];
+      } else {
+        value = arg.substr(7);
+      }
+      s_msvc_arch = cforge::normalize_msvc_arch(value);
+      if (s_msvc_arch.empty()) {
+        cforge::logger::print_error("Unknown architecture '" + value
+                                    + "' (expected x64, x86 or arm64)");
+        return 1;
+      }
+    } else if (arg == "--report-json") {
+      if (i + 1 >= ctx->args.arg_count) {
+        cforge::logger::print_error("--report-json requires an output file");
+        return 1;
+      }
+      report_json = ctx->args.args[++i];
+    } else if (arg.rfind("--report-json=", 0) == 0) {
+      report_json = arg.substr(14);
+    } else if (arg == "-D" || arg == "--define") {
+      if (i + 1 >= ctx->args.arg_count) {
+        cforge::logger::print_error(arg + " requires a define (NAME or NAME=VALUE)");
+        return 1;
+      }
+      s_cli_defines.push_back(cforge::normalize_cli_define(ctx->args.args[++i]));
+    } else if (arg.rfind("--define=", 0) == 0) {
+      s_cli_defines.push_back(cforge::normalize_cli_define(arg.substr(9)));
+    } else if (arg.rfind("-D", 0) == 0) {
+      s_cli_defines.push_back(cforge::normalize_cli_define(arg.substr(2)));
+    }
+  }
+
+  // --target names a CMake target, unless it names a cross target: a
+  // [cross.targets.<name>] entry or a predefined one. Then it works like
+  // --profile.
+  if (!target.empty() && cross_profile.empty()) {
+    cforge::toml_reader target_cfg;
+    std::filesystem::path config_path = current_dir / CFORGE_FILE;
+    bool custom = std::filesystem::exists(config_path) && target_cfg.load(config_path.string())
+               && target_cfg.has_key("cross.targets." + target);
+    if (custom || cforge::get_predefined_cross_target(target)) {
+      cforge::logger::print_verbose("Using cross target from --target: " + target);
+      cross_profile = target;
+      target.clear();
     }
   }
 
@@ -1841,26 +3017,45 @@ cforge_int_t cforge_cmd_build(const cforge_context_t *ctx) {
     config_name = "Debug";
     cforge::logger::print_verbose("No configuration specified, using default: " + config_name);
   } else {
-    // Convert to lowercase for case-insensitive comparison
-    std::string config_lower = cforge::string_to_lower(config_name);
-
-    // Capitalize first letter for standard configs
-    if (config_lower == "debug" || config_lower == "release" || config_lower == "relwithdebinfo"
-        || config_lower == "minsizerel") {
-      config_name    = config_lower;
-      config_name[0] = std::toupper(config_name[0]);
-    }
+    // Standard configs are spelled the way CMake does, e.g. RelWithDebInfo
+    config_name = cforge::canonical_build_config(config_name);
   }
 
   cforge::logger::print_verbose("Using build configuration: " + config_name);
 
+  // --variant a,b stacks variants; later ones take precedence
+  if (ctx->args.variant != nullptr && strlen(ctx->args.variant) > 0) {
+    s_variants = cforge::split_project_list(ctx->args.variant);
+  }
+  if (!is_workspace && std::filesystem::exists(current_dir / CFORGE_FILE)) {
+    cforge::toml_reader variant_config;
+    if (variant_config.load((current_dir / CFORGE_FILE).string())) {
+      if (s_variants.empty()) {
+        s_variants = cforge::split_project_list(variant_config.get_string("variants.default"));
+      }
+      std::string error;
+      cforge::build_variant stacked = cforge::resolve_build_variants(
+          cforge::read_build_variants(variant_config), s_variants, error);
+      if (!error.empty()) {
+        cforge::logger::print_error(error);
+        cforge::logger::print_hint("run 'cforge list variants' to see the defined variants");
+        return 1;
+      }
+      if (!s_variants.empty()) {
+        cforge::logger::print_verbose("Using build variant: " + stacked.name);
+      }
+    }
+  }
+
   // Pre-build script support using shared script_runner
   if (!cforge::run_pre_build_scripts(
-          is_workspace ? workspace_dir : current_dir, is_workspace, verbose)) {
+          is_workspace ? workspace_dir : current_dir, is_workspace, verbose, config_name)) {
     return 1;
   }
 
   cforge_int_t result = 0;
+  cforge::build_report report;
+  report.config = config_name;
 
   if (is_workspace) {
     cforge::logger::print_verbose("Building in workspace context: " + workspace_dir.string());
@@ -1876,6 +3071,58 @@ cforge_int_t cforge_cmd_build(const cforge_context_t *ctx) {
       return 1;
     }
 
+    // Fail on cycles or unknown members before resolving or building anything
+    auto graph = ws.check_dependency_graph();
+    if (graph.is_error()) {
+      graph.error().print();
+      std::filesystem::current_path(original_cwd);
+      return 1;
+    }
+
+    // --only/--exclude pick the projects to build; the projects they depend
+    // on are built too, even when excluded
+    std::vector<std::string> selected_projects;
+    if (!only_projects.empty() || !excluded_projects.empty()) {
+      if (!target.empty() || !project_name.empty()) {
+        cforge::logger::print_error("--only and --exclude cannot be combined with --target or "
+                                    "--project");
+        std::filesystem::current_path(original_cwd);
+        return 1;
+      }
+      std::map<std::string, std::vector<std::string>> project_deps;
+      for (const auto &proj : ws.get_projects()) {
+        auto &deps = project_deps[proj.name];
+        deps       = proj.dependencies;
+        deps.insert(
+            deps.end(), proj.order_only_dependencies.begin(), proj.order_only_dependencies.end());
+      }
+      auto selection = cforge::select_workspace_projects(
+          ws.get_build_order(), project_deps, only_projects, excluded_projects, true);
+      for (const auto &pattern : selection.unmatched) {
+        cforge::logger::print_error("No workspace project matches '" + pattern + "'");
+      }
+      if (!selection.unmatched.empty()) {
+        cforge::logger::print_hint("run 'cforge list projects' to see the workspace members");
+        std::filesystem::current_path(original_cwd);
+        return 1;
+      }
+      for (const auto &required : selection.required_excluded) {
+        cforge::logger::print_warning(required.project + " is excluded, but " + required.required_by
+                                      + " depends on it; building it anyway");
+      }
+      if (selection.projects.empty()) {
+        cforge::logger::print_error("--only/--exclude leave no projects to build");
+        std::filesystem::current_path(original_cwd);
+        return 1;
+      }
+      selected_projects = selection.projects;
+    }
+    auto is_selected = [&](const std::string &name) {
+      return selected_projects.empty()
+          || std::find(selected_projects.begin(), selected_projects.end(), name)
+                 != selected_projects.end();
+    };
+
     // STEP 1: Resolve all dependencies FIRST (before CMakeLists generation)
     // This ensures dependencies are available when CMakeLists.txt references
     // them
@@ -1884,6 +3131,7 @@ cforge_int_t cforge_cmd_build(const cforge_context_t *ctx) {
       for (const auto &proj : ws.get_projects()) {
         auto proj_toml = proj.path / CFORGE_FILE;
         if (std::filesystem::exists(proj_toml)) {
+          auto deps_start = std::chrono::steady_clock::now();
           cforge::toml_reader pcfg(toml::parse_file(proj_toml.string()));
 
           // Resolve index/registrydependencies first (skip if using
@@ -1893,6 +3141,10 @@ cforge_int_t cforge_cmd_build(const cforge_context_t *ctx) {
             try {
               std::filesystem::current_path(proj.path);
               if (!resolve_index_dependencies(proj.path, pcfg, verbose, skip_deps)) {
+                if (cforge_is_offline()) {
+                  std::filesystem::current_path(original_cwd);
+                  return 1;
+                }
                 cforge::logger::print_warning("Some index dependencies could "
                                               "not be resolved for project: "
                                               + proj.name);
@@ -1923,11 +3175,31 @@ cforge_int_t cforge_cmd_build(const cforge_context_t *ctx) {
               return 1;
             }
           }
+
+          cforge::project_build_times times;
+          times.name = proj.name;
+          times.deps_seconds =
+              std::chrono::duration<double>(std::chrono::steady_clock::now() - deps_start).count();
+          report.projects.push_back(times);
         }
       }
       std::filesystem::current_path(workspace_dir);
     }
 
+    // Projects without dependency setup (--skip-deps) still get a row
+    for (const auto &proj : ws.get_projects()) {
+      bool listed = std::any_of(report.projects.begin(),
+                                report.projects.end(),
+                                [&](const cforge::project_build_times &times) {
+                                  return times.name == proj.name;
+                                });
+      if (!listed) {
+        cforge::project_build_times times;
+        times.name = proj.name;
+        report.projects.push_back(times);
+      }
+    }
+
     // STEP 2: Generate workspace and project CMakeLists.txt AFTER dependencies
     // are resolved
     auto ws_config_path = cforge::get_workspace_config_path(workspace_dir);
@@ -1956,7 +3228,7 @@ cforge_int_t cforge_cmd_build(const cforge_context_t *ctx) {
     }
 
     // STEP 3: Determine workspace build directory and configure CMake
-    std::filesystem::path build_dir = workspace_dir / DEFAULT_BUILD_DIR;
+    std::filesystem::path build_dir = cforge::get_build_base_dir(workspace_dir, nullptr);
     // Ensure build directory exists
     if (!std::filesystem::exists(build_dir)) {
       try {
@@ -1973,6 +3245,10 @@ cforge_int_t cforge_cmd_build(const cforge_context_t *ctx) {
       generator = cforge::get_cmake_generator();
     }
     cforge::logger::print_verbose("Using CMake generator: " + generator);
+    if (!cforge::prepare_build_dir_for_generator(build_dir, generator, s_auto_clean)) {
+      std::filesystem::current_path(original_cwd);
+      return 1;
+    }
 
     // Check if multi-config generator
     bool is_multi_config = generator.find("Multi-Config") != std::string::npos
@@ -1982,10 +3258,25 @@ cforge_int_t cforge_cmd_build(const cforge_context_t *ctx) {
     // Configure workspace CMake
     std::vector<std::string> cmake_args = {
         "-S", workspace_dir.string(), "-B", build_dir.string(), "-G", generator};
+    if (!ensure_ninja_available(generator, cmake_args)) {
+      std::filesystem::current_path(original_cwd);
+      return 1;
+    }
+
+    std::string msvc_compiler = ws_cfg.get_string("cmake.cxx_compiler", "");
+    if (cforge::uses_msvc_environment(generator, msvc_compiler)
+        && !cforge::setup_msvc_environment(s_msvc_arch, verbose) && !msvc_compiler.empty()) {
+      cforge::logger::print_warning("cl.exe is not on PATH and no Visual Studio install with "
+                                    "the C++ toolset was found");
+    }
+    if (generator.rfind("Visual Studio", 0) == 0 && !s_msvc_arch.empty()) {
+      cmake_args.push_back("-A");
+      cmake_args.push_back(cforge::visual_studio_platform(s_msvc_arch));
+    }
 
     // Add build type for non-multi-config generators
     if (!is_multi_config) {
-      cmake_args.push_back("-DCMAKE_BUILD_TYPE=" + config_name);
+      cmake_args.push_back("-DCMAKE_BUILD_TYPE=" + cforge::canonical_build_config(config_name));
     }
 
     // Add export_compile_commands if enabled
@@ -1993,10 +3284,36 @@ cforge_int_t cforge_cmd_build(const cforge_context_t *ctx) {
       cmake_args.push_back("-DCMAKE_EXPORT_COMPILE_COMMANDS=ON");
     }
 
+    // Every project's FetchContent dependencies share the workspace _deps
+    if (cforge_is_offline()) {
+      bool all_present = true;
+      for (const auto &proj : ws.get_projects()) {
+        auto proj_toml = proj.path / CFORGE_FILE;
+        if (std::filesystem::exists(proj_toml)) {
+          cforge::toml_reader pcfg(toml::parse_file(proj_toml.string()));
+          all_present = check_offline_dependencies(proj.path, pcfg, build_dir, "", "")
+                     && all_present;
+        }
+      }
+      if (!all_present) {
+        std::filesystem::current_path(original_cwd);
+        return 1;
+      }
+    }
+    cmake_args.push_back(std::string("-DFETCHCONTENT_FULLY_DISCONNECTED=")
+                         + (cforge_is_offline() ? "ON" : "OFF"));
+    cmake_args.push_back(cforge::cli_defines_cache_arg(s_cli_defines));
+    cmake_args.push_back(cforge::variants_cache_arg(s_variants));
+
     if (verbose) {
       cmake_args.push_back("--debug-output");
     }
-    if (!run_cmake_configure(cmake_args, build_dir.string(), workspace_dir.string(), verbose)) {
+    auto configure_start = std::chrono::steady_clock::now();
+    bool configured =
+        run_cmake_configure(cmake_args, build_dir.string(), workspace_dir.string(), verbose);
+    report.configure_seconds =
+        std::chrono::duration<double>(std::chrono::steady_clock::now() - configure_start).count();
+    if (!configured) {
       cforge::logger::print_error("Workspace CMake configuration failed");
       // Restore original directory before exit
       std::filesystem::current_path(original_cwd);
@@ -2018,23 +3335,67 @@ cforge_int_t cforge_cmd_build(const cforge_context_t *ctx) {
     if (verbose) {
       build_args.push_back("--verbose");
     }
-    if (!project_name.empty()) {
-      // Build only the specified cforge::workspacetarget
+    // --target builds one CMake target, -p a project's main target and
+    // --only/--exclude the main targets of the selected projects
+    std::string build_target = target.empty() ? project_name : target;
+    if (!build_target.empty()) {
+      build_args.push_back("--target");
+      build_args.push_back(build_target);
+      cforge::logger::building(build_target + " in workspace");
+    } else if (!selected_projects.empty()) {
       build_args.push_back("--target");
-      build_args.push_back(project_name);
-      cforge::logger::building(project_name + " in workspace");
+      build_args.insert(build_args.end(), selected_projects.begin(), selected_projects.end());
+      cforge::logger::building(cforge::join_strings(selected_projects, ", ") + " in workspace");
     } else {
       cforge::logger::building("entire workspace");
     }
 
+    // Projects pinned in [workspace.project_configs]; single-config builds
+    // get them from the generated CMakeLists.txt
+    std::vector<std::pair<std::string, std::string>> pinned_projects;
+    for (const auto &proj : ws.get_projects()) {
+      std::string proj_config = ws.get_project_config(proj.name, config_name);
+      if (proj_config != config_name && (project_name.empty() || proj.name == project_name)
+          && is_selected(proj.name)) {
+        pinned_projects.push_back({proj.name, proj_config});
+        cforge::logger::print_status(proj.name + " builds " + proj_config
+                                     + " ([workspace.project_configs])");
+      }
+    }
+
     // Use longer timeout for workspace builds in CI environments
     cforge_int_t build_timeout = 600;  // 10 minutes
     bool result =
         cforge::execute_tool("cmake", build_args, "", "CMake Build", verbose, build_timeout);
+
+    // Multi-config generators build one configuration per invocation, so
+    // pinned projects are built again in theirs
+    auto build_timings = cforge::get_last_build_timings();
+    if (result && is_multi_config) {
+      for (const auto &[pinned_name, pinned_config] : pinned_projects) {
+        cforge::logger::building(pinned_name + " [" + pinned_config + "]");
+        result = cforge::run_cmake_build(build_dir, pinned_config, pinned_name, num_jobs, verbose)
+              && result;
+      }
+    }
     // Restore original directory
     std::filesystem::current_path(original_cwd);
+
+    // A --project or --only/--exclude build only reports the projects it built
+    if (!project_name.empty() || !selected_projects.empty()) {
+      report.projects.erase(std::remove_if(report.projects.begin(),
+                                           report.projects.end(),
+                                           [&](const cforge::project_build_times &times) {
+                                             return project_name.empty()
+                                                      ? !is_selected(times.name)
+                                                      : times.name != project_name;
+                                           }),
+                            report.projects.end());
+    }
+    cforge::apply_build_timings(report, build_timings, result);
     if (!result) {
       cforge::logger::print_error("Build failed");
+      finish_build_report(report, build_start_time, report_json);
       return 1;
     }
     // Calculate workspace build duration
@@ -2044,9 +3405,13 @@ cforge_int_t cforge_cmd_build(const cforge_context_t *ctx) {
             .count();
     std::string duration_str = fmt::format("{:.2f}s", duration_ms / 1000.0);
     cforge::logger::finished(config_name, duration_str);
+    print_build_warning_summary();
+    if (!finish_build_report(report, build_start_time, report_json)) {
+      return 1;
+    }
     // Clean up empty config directories under workspace build root
     {
-      std::filesystem::path build_root = workspace_dir / DEFAULT_BUILD_DIR;
+      std::filesystem::path build_root = cforge::get_build_base_dir(workspace_dir, nullptr);
       for (const auto &cfg : {"Debug", "Release", "RelWithDebInfo"}) {
         std::filesystem::path cfg_dir = build_root / cfg;
         if (std::filesystem::exists(cfg_dir) && std::filesystem::is_directory(cfg_dir)
@@ -2059,12 +3424,16 @@ cforge_int_t cforge_cmd_build(const cforge_context_t *ctx) {
     }
 
     // Post-build script support (workspace)
-    if (!cforge::run_post_build_scripts(workspace_dir, true, verbose)) {
+    if (!cforge::run_post_build_scripts(workspace_dir, true, verbose, config_name)) {
       return 1;
     }
 
     return 0;
   } else {
+    if (!only_projects.empty() || !excluded_projects.empty()) {
+      cforge::logger::print_warning("--only and --exclude apply to workspace builds; "
+                                    "ignoring them");
+    }
     // Single project build outside workspace. build_project() runs the
     // CMakeLists regeneration check itself (build_project → line ~1153),
     // so doing it here would just produce a duplicate
@@ -2072,14 +3441,16 @@ cforge_int_t cforge_cmd_build(const cforge_context_t *ctx) {
     //   "  Generating CMakeLists.txt from cforge.toml"
     //   "    Finished CMakeLists.txt target(s)"
     // block before the real one.
-    if (!build_project(current_dir,
-                       config_name,
-                       num_jobs,
-                       verbose,
-                       target,
-                       nullptr,
-                       skip_deps,
-                       cross_profile)) {
+    bool built = build_project(current_dir,
+                               config_name,
+                               num_jobs,
+                               verbose,
+                               target,
+                               nullptr,
+                               skip_deps,
+                               cross_profile,
+                               &report);
+    if (!finish_build_report(report, build_start_time, report_json) || !built) {
       return 1;
     }
 
@@ -2094,7 +3465,7 @@ cforge_int_t cforge_cmd_build(const cforge_context_t *ctx) {
         if (!post_build_cmds.empty()) {
           cforge::logger::print_action("Running",
                                        "post-build commands for profile '" + cross_profile + "'");
-          std::string build_dir_str     = (current_dir / DEFAULT_BUILD_DIR).string();
+          std::string build_dir_str     = cforge::get_build_base_dir(current_dir, &pb_cfg).string();
           std::string post_build_target = "post_build_" + cross_profile;
           if (!cforge::run_cmake_build(
                   build_dir_str, config_name, post_build_target, num_jobs, verbose)) {
@@ -2106,13 +3477,32 @@ cforge_int_t cforge_cmd_build(const cforge_context_t *ctx) {
     }
 
     // Post-build script support (single project)
-    if (!cforge::run_post_build_scripts(current_dir, false, verbose)) {
+    if (!cforge::run_post_build_scripts(current_dir, false, verbose, config_name)) {
       return 1;
     }
   }
   return result;
 }
 
+/**
+ * @brief Handle the 'build' command
+ *
+ * Ctrl+C stops the running tools instead of killing cforge outright, so
+ * nothing is left running and the next build starts from a clean state.
+ *
+ * @param ctx Context containing parsed arguments
+ * @return cforge_int_t Exit code (0 for success, 130 if interrupted)
+ */
+cforge_int_t cforge_cmd_build(const cforge_context_t *ctx) {
+  cforge::interrupt_guard interrupt;
+  cforge_int_t result = run_build_command(ctx);
+  if (cforge::was_interrupted()) {
+    cforge::logger::print_error("Build interrupted");
+    return 130;
+  }
+  return result;
+}
+
 /**
  * @brief Configure project dependencies in CMakeLists.txt
  *
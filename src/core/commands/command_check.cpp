@@ -0,0 +1,258 @@
+/**
+ * @file command_check.cpp
+ * @brief Implementation of the 'check' command to validate cforge.toml
+ *        without building
+ */
+
+#include "cforge/log.hpp"
+
+#include "core/build_utils.hpp"
+#include "core/command.h"
+#include "core/command_registry.hpp"
+#include "core/commands.hpp"
+#include "core/constants.h"
+#include "core/doctor_checks.hpp"
+#include "core/fetchcontent_deps.hpp"
+#include "core/git_utils.hpp"
+#include "core/http_client.hpp"
+#include "core/registry.hpp"
+#include "core/toml_reader.hpp"
+#include "core/types.h"
+#include "core/workspace.hpp"
+
+#include <fmt/color.h>
+
+#include <filesystem>
+#include <string>
+#include <vector>
+
+namespace cforge {
+// Defined in command_doctor.cpp
+void check_project(const std::filesystem::path &project_dir, std::vector<doctor_check> &checks);
+}  // namespace cforge
+
+namespace {
+
+/**
+ * @brief Check that a git repository, and the ref if given, exist
+ *
+ * A commit SHA is not advertised by the remote, so only the repository is
+ * checked for one.
+ *
+ * @return An empty string if reachable, otherwise what went wrong
+ */
+std::string check_git_source(const std::string &url, const std::string &ref) {
+  std::vector<std::string> args = {"ls-remote", "--exit-code", url};
+  if (!ref.empty() && !cforge::is_git_commit_sha(ref)) {
+    args.push_back(ref);
+  }
+  cforge::git_result result = cforge::git_execute(args, "", 30);
+  if (result.success) {
+    return "";
+  }
+  // --exit-code exits with 2 when the repository has no matching ref
+  if (result.exit_code == 2) {
+    return "'" + ref + "' not found in " + url;
+  }
+  return url + " is not reachable";
+}
+
+/**
+ * @brief Check that an archive URL answers a HEAD request
+ *
+ * @return An empty string if reachable, otherwise what went wrong
+ */
+std::string check_url_source(cforge::http_client &client, const std::string &url) {
+  cforge::http_request_options options;
+  options.timeout_seconds = 30;
+  auto response           = client.head(url, options);
+  if (!response) {
+    return url + " is not reachable: " + client.last_error();
+  }
+  if (response->status_code >= 400) {
+    return url + " returned HTTP " + std::to_string(response->status_code);
+  }
+  return "";
+}
+
+/**
+ * @brief Check that every dependency's source can be found, without
+ *        downloading it
+ *
+ * Index packages are looked up in the package index and their version
+ * requirement resolved; git repositories are asked for the requested ref;
+ * archive URLs are sent a HEAD request. vcpkg and conan are checked by
+ * check_project().
+ *
+ * @param project_config Project configuration
+ * @param reg Package index
+ * @param checks Receives one entry per dependency that can't be found
+ */
+void check_dependency_sources(const cforge::toml_reader &project_config,
+                              cforge::registry &reg,
+                              std::vector<cforge::doctor_check> &checks) {
+  auto report = [&](const std::string &dep, const std::string &detail, const std::string &hint) {
+    cforge::doctor_check check;
+    check.name   = dep;
+    check.status = cforge::check_status::fail;
+    check.detail = detail;
+    check.hint   = hint;
+    checks.push_back(check);
+  };
+
+  for (const auto &dep : cforge::get_index_dependencies_with_versions(project_config)) {
+    if (!reg.get_package(dep.name)) {
+      report(dep.name,
+             "not found in the package index",
+             "run 'cforge deps search " + dep.name + "' to find the package's name");
+    } else if (reg.resolve_version(dep.name, dep.version).empty()) {
+      report(dep.name,
+             "no version matches '" + dep.version + "'",
+             "run 'cforge deps info " + dep.name + " --versions' to list its versions");
+    }
+  }
+
+  for (const auto &dep : project_config.get_table_keys("dependencies.git")) {
+    std::string key = "dependencies.git." + dep;
+    std::string url = project_config.get_string(key + ".url", "");
+    if (url.empty()) {
+      report(dep, "has no url", "set " + key + ".url to the repository");
+      continue;
+    }
+    std::string tag     = project_config.get_string(key + ".tag", "");
+    std::string problem = check_git_source(
+        url, tag.empty() ? project_config.get_string(key + ".branch", "") : tag);
+    if (!problem.empty()) {
+      report(dep, problem, "check the url and tag or branch in [" + key + "]");
+    }
+  }
+
+  cforge::http_client client;
+  for (const auto &dep : project_config.get_table_keys("dependencies.archive")) {
+    std::string url     = project_config.get_string("dependencies.archive." + dep + ".url", "");
+    std::string problem = url.empty() ? "has no url" : check_url_source(client, url);
+    if (!problem.empty()) {
+      report(dep, problem, "check the url in [dependencies.archive." + dep + "]");
+    }
+  }
+
+  for (const auto &dep : cforge::get_fetchcontent_dependencies(project_config)) {
+    std::string problem;
+    if (!dep.git.empty()) {
+      problem = check_git_source(dep.git, dep.tag);
+    } else if (!dep.url.empty()) {
+      problem = check_url_source(client, dep.url);
+    } else {
+      problem = "has neither git nor url";
+    }
+    if (!problem.empty()) {
+      report(dep.name, problem, "check [dependencies.fetchcontent." + dep.name + "]");
+    }
+  }
+}
+
+/**
+ * @brief Print the problems found in one project
+ */
+void print_problems(const std::vector<cforge::doctor_check> &checks) {
+  for (const auto &check : checks) {
+    if (check.status == cforge::check_status::pass) {
+      continue;
+    }
+    fmt::color color = check.status == cforge::check_status::warn ? fmt::color::yellow
+                                                                  : fmt::color::red;
+    cforge::logger::print_kv_colored(check.name, check.detail, color, 20, 2);
+    if (!check.hint.empty()) {
+      cforge::logger::print_hint(check.hint);
+    }
+  }
+}
+
+}  // namespace
+
+/**
+ * @brief Handle the 'check' command
+ *
+ * @param ctx Context containing parsed arguments
+ * @return cforge_int_t Exit code (0 if no check failed)
+ */
+cforge_int_t cforge_cmd_check(const cforge_context_t *ctx) {
+  bool check_sources = true;
+  for (cforge_int_t i = 0; i < ctx->args.arg_count; ++i) {
+    std::string arg = ctx->args.args[i];
+    if (arg == "-h" || arg == "--help") {
+      cforge::command_registry::instance().print_command_help("check");
+      return 0;
+    } else if (arg == "--no-fetch") {
+      check_sources = false;
+    }
+  }
+  // Sources are checked over the network, which an offline run can't do
+  check_sources = check_sources && !cforge_is_offline();
+
+  std::filesystem::path working_dir(ctx->working_dir);
+  std::vector<std::pair<std::string, std::filesystem::path>> projects;
+  auto [is_workspace, workspace_dir] = cforge::is_in_workspace(working_dir);
+  if (std::filesystem::exists(working_dir / CFORGE_FILE)
+      && !cforge::workspace::is_workspace_dir(working_dir)) {
+    cforge::toml_reader project_config;
+    project_config.load((working_dir / CFORGE_FILE).string());
+    projects.emplace_back(
+        project_config.get_string("project.name", working_dir.filename().string()), working_dir);
+  } else if (is_workspace) {
+    cforge::workspace ws;
+    if (!ws.load(workspace_dir)) {
+      cforge::logger::print_error("Failed to load workspace configuration");
+      return 1;
+    }
+    for (const auto &project : ws.get_projects()) {
+      projects.emplace_back(project.name, project.path);
+    }
+  } else {
+    cforge::logger::print_error("No " + std::string(CFORGE_FILE) + " found in "
+                                + working_dir.string());
+    return 1;
+  }
+
+  cforge::registry reg;
+  if (check_sources && reg.needs_update() && !reg.update()) {
+    cforge::logger::print_warning("Failed to update package index, using cached version");
+  }
+
+  cforge_size_t warnings = 0, errors = 0;
+  for (const auto &[name, dir] : projects) {
+    std::vector<cforge::doctor_check> checks;
+    cforge::check_project(dir, checks);
+    cforge::toml_reader project_config;
+    if (check_sources && project_config.load((dir / CFORGE_FILE).string())) {
+      check_dependency_sources(project_config, reg, checks);
+    }
+
+    cforge_size_t project_warnings = 0, project_errors = 0;
+    for (const auto &check : checks) {
+      project_warnings += check.status == cforge::check_status::warn ? 1 : 0;
+      project_errors += check.status == cforge::check_status::fail ? 1 : 0;
+    }
+    warnings += project_warnings;
+    errors += project_errors;
+
+    if (project_warnings == 0 && project_errors == 0) {
+      cforge::logger::print_action("Checked", name + ": OK");
+      continue;
+    }
+    cforge::logger::print_action("Checked", name);
+    print_problems(checks);
+  }
+
+  if (!check_sources) {
+    cforge::logger::print_dim("Dependency sources were not checked");
+  }
+  if (warnings == 0 && errors == 0) {
+    cforge::logger::print_action("Summary", "OK");
+    return 0;
+  }
+  cforge::logger::print_action("Summary",
+                               std::to_string(warnings) + " warnings, " + std::to_string(errors)
+                                   + " errors");
+  return errors > 0 ? 1 : 0;
+}
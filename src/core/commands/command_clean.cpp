@@ -13,6 +13,7 @@
 #include "core/process_utils.hpp"
 #include "core/script_runner.hpp"
 #include "core/toml_reader.hpp"
+#include "core/tree_removal.hpp"
 #include "core/types.h"
 #include "core/workspace.hpp"
 
@@ -23,88 +24,108 @@
 
 #ifdef _WIN32
 #include <windows.h>
+
+#include <restartmanager.h>
+#pragma comment(lib, "rstrtmgr.lib")
 #endif
 
+#ifdef _WIN32
 /**
- * @brief Make all files in a directory writable (removes read-only attribute)
- *        This is needed on Windows to delete .git directories that have
- * read-only pack files
- *
- * @param dir Directory to process
+ * @brief A process that has a file open
  */
-static void make_directory_writable(const std::filesystem::path &dir) {
-  try {
-    for (const auto &entry : std::filesystem::recursive_directory_iterator(dir)) {
-      try {
-        std::filesystem::permissions(entry.path(),
-                                     std::filesystem::perms::owner_write,
-                                     std::filesystem::perm_options::add);
-      } catch (...) {
-        // Ignore permission errors on individual files
-      }
-    }
-  } catch (...) {
-    // Ignore errors during iteration
+struct lock_holder {
+  DWORD pid;
+  std::string name;
+};
+
+/**
+ * @brief Convert a UTF-16 string from the Win32 API to UTF-8
+ */
+static std::string wide_to_utf8(const WCHAR *text) {
+  cforge_int_t size = WideCharToMultiByte(CP_UTF8, 0, text, -1, NULL, 0, NULL, NULL);
+  if (size <= 1) {
+    return "";
   }
+  std::string result(static_cast<cforge_size_t>(size), '\0');
+  WideCharToMultiByte(CP_UTF8, 0, text, -1, &result[0], size, NULL, NULL);
+  result.resize(static_cast<cforge_size_t>(size - 1));
+  return result;
 }
 
 /**
- * @brief Forcefully remove a directory, handling .git directories on Windows
+ * @brief Find the processes that have any of `files` open
  *
- * @param dir Directory to remove
- * @return bool Success flag
+ * Uses the Restart Manager, which knows about every process's open handles
+ * without needing handle.exe or administrator rights.
+ *
+ * @param files Files that could not be removed
+ * @return The processes, empty if none were found or the lookup failed
  */
-static bool force_remove_directory(const std::filesystem::path &dir) {
-  if (!std::filesystem::exists(dir)) {
-    return true;
+static std::vector<lock_holder> find_lock_holders(
+    const std::vector<std::filesystem::path> &files) {
+  std::vector<lock_holder> holders;
+  DWORD session                             = 0;
+  WCHAR session_key[CCH_RM_SESSION_KEY + 1] = {};
+  if (RmStartSession(&session, 0, session_key) != ERROR_SUCCESS) {
+    return holders;
   }
 
-  // First attempt: standard remove_all
-  try {
-    std::filesystem::remove_all(dir);
-    return true;
-  } catch (...) {
-    // Continue to fallback methods
+  std::vector<std::wstring> names;
+  for (const auto &file : files) {
+    names.push_back(file.wstring());
   }
-
-  // Second attempt: make all files writable, then remove
-  make_directory_writable(dir);
-  try {
-    std::filesystem::remove_all(dir);
-    return true;
-  } catch (...) {
-    // Continue to fallback methods
+  std::vector<LPCWSTR> name_ptrs;
+  for (const auto &name : names) {
+    name_ptrs.push_back(name.c_str());
   }
-
-#ifdef _WIN32
-  // Third attempt on Windows: use system command
-  std::string cmd     = "cmd /c \"rmdir /s /q \"" + dir.string() + "\"\" 2>nul";
-  cforge_int_t result = std::system(cmd.c_str());
-  if (result == 0 || !std::filesystem::exists(dir)) {
-    return true;
+  if (RmRegisterResources(session,
+                          static_cast<UINT>(name_ptrs.size()),
+                          name_ptrs.data(),
+                          0,
+                          NULL,
+                          0,
+                          NULL)
+      == ERROR_SUCCESS) {
+    // The list can grow between calls, so ask again until it fits
+    std::vector<RM_PROCESS_INFO> info;
+    UINT needed   = 0;
+    UINT count    = 0;
+    DWORD reasons = 0;
+    DWORD status  = ERROR_MORE_DATA;
+    while (status == ERROR_MORE_DATA) {
+      info.resize(needed);
+      count  = needed;
+      status = RmGetList(session, &needed, &count, info.empty() ? NULL : info.data(), &reasons);
+    }
+    if (status == ERROR_SUCCESS) {
+      for (UINT i = 0; i < count; ++i) {
+        holders.push_back({info[i].Process.dwProcessId, wide_to_utf8(info[i].strAppName)});
+      }
+    }
   }
+  RmEndSession(session);
+  return holders;
+}
 
-  // Fourth attempt: use robocopy trick (create empty dir, mirror to target)
-  // This can handle files that are locked or have special characters
-  std::filesystem::path temp_empty = std::filesystem::temp_directory_path() / "cforge_empty_dir";
-  try {
-    std::filesystem::create_directories(temp_empty);
-    cmd = "robocopy \"" + temp_empty.string() + "\" \"" + dir.string()
-        + "\" /mir /r:1 /w:1 >nul 2>&1";
-    std::system(cmd.c_str());
-    std::filesystem::remove_all(temp_empty);
-    std::filesystem::remove_all(dir);
-    return !std::filesystem::exists(dir);
-  } catch (...) {
-    // Clean up temp directory
-    try {
-      std::filesystem::remove_all(temp_empty);
-    } catch (...) {}
+/**
+ * @brief Terminate a process and wait briefly for its handles to close
+ */
+static bool terminate_lock_holder(const lock_holder &holder) {
+  if (holder.pid == GetCurrentProcessId()) {
+    return false;
   }
-#endif
-
-  return !std::filesystem::exists(dir);
+  HANDLE process = OpenProcess(PROCESS_TERMINATE | SYNCHRONIZE, FALSE, holder.pid);
+  if (process == NULL) {
+    return false;
+  }
+  bool terminated = TerminateProcess(process, 1) != 0;
+  if (terminated) {
+    WaitForSingleObject(process, 5000);
+  }
+  CloseHandle(process);
+  return terminated;
 }
+#endif
 
 // Note: cforge::get_build_dir_for_config() is now in build_utils.hpp
 
@@ -133,13 +154,99 @@ static std::vector<std::filesystem::path> find_all_build_dirs(const std::string
 }
 
 /**
- * @brief Clean the build directory
+ * @brief Remove one configuration's outputs from a multi-config build tree
+ *
+ * Ninja Multi-Config, Visual Studio and Xcode share one build directory
+ * between configurations, so deleting it would also throw away the others.
  *
  * @param build_dir Path to the build directory
+ * @param config Configuration to clean
  * @param verbose Verbose output flag
  * @return bool Success flag
  */
-static bool clean_build_directory(const std::filesystem::path &build_dir, bool /*verbose*/) {
+static bool clean_build_config(const std::filesystem::path &build_dir,
+                               const std::string &config,
+                               bool verbose) {
+  std::string cmake_config = cforge::canonical_build_config(config);
+  cforge::logger::removing(cmake_config + " outputs in " + build_dir.string());
+
+  std::vector<std::string> args = {
+      "--build", build_dir.string(), "--config", cmake_config, "--target", "clean"};
+  if (!cforge::execute_tool("cmake", args, "", "CMake Clean", verbose, 300)) {
+    cforge::logger::print_error("Failed to clean " + cmake_config + " in " + build_dir.string());
+    return false;
+  }
+
+  cforge::logger::print_action("Removed", cmake_config + " outputs");
+  return true;
+}
+
+/**
+ * @brief Report files a clean had to leave behind, and what is holding them
+ *
+ * @param skipped Files that could not be removed
+ * @param executables Names of the project's executables
+ */
+static void report_locked_files(const std::vector<std::filesystem::path> &skipped,
+                                const std::vector<std::string> &executables) {
+  constexpr cforge_size_t max_listed = 10;
+  for (cforge_size_t i = 0; i < skipped.size() && i < max_listed; ++i) {
+    cforge::logger::print_plain("  " + skipped[i].string());
+  }
+  if (skipped.size() > max_listed) {
+    cforge::logger::print_plain("  ... and " + std::to_string(skipped.size() - max_listed)
+                                + " more");
+  }
+
+  for (const auto &file : skipped) {
+    if (cforge::is_project_executable(file, executables)) {
+      cforge::logger::print_hint(file.filename().string()
+                                 + " is still running; close it and run 'cforge clean' again");
+      return;
+    }
+  }
+#ifdef _WIN32
+  cforge::logger::print_hint("run 'cforge clean --kill-holders' to stop the processes holding "
+                             "them");
+#else
+  cforge::logger::print_hint("close any program using these files and run 'cforge clean' again");
+#endif
+}
+
+/**
+ * @brief Files removed and skipped, e.g. " (1204 files removed, 2 skipped)"
+ *
+ * @return The summary, or empty if no directory was removed
+ */
+static std::string removal_summary(const cforge::tree_removal_result &totals) {
+  if (totals.removed == 0 && totals.skipped.empty()) {
+    return "";
+  }
+  std::string summary = " (" + std::to_string(totals.removed) + " files removed";
+  if (!totals.skipped.empty()) {
+    summary += ", " + std::to_string(totals.skipped.size()) + " skipped";
+  }
+  return summary + ")";
+}
+
+/**
+ * @brief Remove a build (or dependencies) directory
+ *
+ * Files that stay locked are skipped and reported rather than failing the
+ * clean; see cforge::remove_tree_with_retries().
+ *
+ * @param build_dir Path to the directory
+ * @param executables Names of the project's executables, to recognize one
+ *        that is still running
+ * @param kill_holders Terminate the processes holding skipped files and try
+ *        again (Windows only)
+ * @param totals Receives the files removed and skipped
+ * @return bool true if nothing had to be skipped
+ */
+static bool clean_build_directory(const std::filesystem::path &build_dir,
+                                  const std::vector<std::string> &executables,
+                                  bool kill_holders,
+                                  cforge::tree_removal_result &totals) {
   if (!std::filesystem::exists(build_dir)) {
     cforge::logger::print_status("Build directory does not exist, nothing to clean: "
                                  + build_dir.string());
@@ -147,14 +254,43 @@ static bool clean_build_directory(const std::filesystem::path &build_dir, bool /
   }
 
   cforge::logger::removing(build_dir.string());
+  cforge::tree_removal_result result = cforge::remove_tree_with_retries(build_dir);
+
+#ifdef _WIN32
+  if (!result.skipped.empty()) {
+    std::vector<lock_holder> holders = find_lock_holders(result.skipped);
+    for (const auto &holder : holders) {
+      std::string label = holder.name + " (pid " + std::to_string(holder.pid) + ")";
+      if (!kill_holders) {
+        cforge::logger::print_warning(label + " has files open in " + build_dir.string());
+      } else if (terminate_lock_holder(holder)) {
+        cforge::logger::print_action("Stopped", label);
+      } else {
+        cforge::logger::print_warning("could not stop " + label);
+      }
+    }
+    if (kill_holders && !holders.empty()) {
+      cforge::tree_removal_result retry = cforge::remove_tree_with_retries(build_dir);
+      result.removed += retry.removed;
+      result.skipped = retry.skipped;
+    }
+  }
+#else
+  (void)kill_holders;
+#endif
 
-  if (force_remove_directory(build_dir)) {
+  totals.removed += result.removed;
+  totals.skipped.insert(totals.skipped.end(), result.skipped.begin(), result.skipped.end());
+  if (result.skipped.empty()) {
     cforge::logger::print_action("Removed", build_dir.string());
     return true;
-  } else {
-    cforge::logger::print_error("Failed to remove build directory: " + build_dir.string());
-    return false;
   }
+
+  cforge::logger::print_warning("removed " + std::to_string(result.removed) + " files from "
+                                + build_dir.string() + ", skipped "
+                                + std::to_string(result.skipped.size()) + " that are in use:");
+  report_locked_files(result.skipped, executables);
+  return false;
 }
 
 /**
@@ -289,7 +425,7 @@ static bool regenerate_cmake_files(const std::filesystem::path &project_dir,
   // Add build type for single-config generators
 #ifndef _WIN32
   if (!config.empty()) {
-    cmake_args.push_back("-DCMAKE_BUILD_TYPE=" + config);
+    cmake_args.push_back("-DCMAKE_BUILD_TYPE=" + cforge::canonical_build_config(config));
   }
 #endif
 
@@ -344,9 +480,10 @@ cforge_int_t cforge_cmd_clean(const cforge_context_t *ctx) {
     // Workspace cleaning: only clean root cforge::workspacebuild outputs
     cforge::logger::cleaning("cforge::workspacebuild outputs");
     // Parse clean arguments
-    bool clean_all   = false;
-    bool clean_cmake = true;
-    bool regenerate  = false;
+    bool clean_all    = false;
+    bool clean_cmake  = true;
+    bool regenerate   = false;
+    bool kill_holders = false;
     // bool deep = false; // Reserved for future deep clean functionality
     std::string config_name;
     bool verbose = cforge::logger::get_verbosity() == cforge::log_verbosity::VERBOSITY_VERBOSE;
@@ -358,6 +495,8 @@ cforge_int_t cforge_cmd_clean(const cforge_context_t *ctx) {
         clean_cmake = false;
       } else if (arg == "--regenerate") {
         regenerate = true;
+      } else if (arg == "--kill-holders") {
+        kill_holders = true;
       }
       // --deep flag reserved for future use
       else if ((arg == "--config" || arg == "-c") && i + 1 < ctx->args.arg_count) {
@@ -365,7 +504,7 @@ cforge_int_t cforge_cmd_clean(const cforge_context_t *ctx) {
       }
     }
     // Determine cforge::workspacebuild directory(s)
-    std::filesystem::path base_build = current_dir / DEFAULT_BUILD_DIR;
+    std::filesystem::path base_build = cforge::get_build_base_dir(current_dir, nullptr);
     std::vector<std::filesystem::path> build_dirs;
     if (clean_all) {
       // Clean all config-specific build dirs as before
@@ -410,15 +549,24 @@ cforge_int_t cforge_cmd_clean(const cforge_context_t *ctx) {
         }
       }
     }
+    // Executables of every member project, to recognize one still running
+    std::vector<std::string> executables;
+    cforge::workspace members;
+    if (members.load(current_dir)) {
+      for (const auto &proj : members.get_projects()) {
+        executables.push_back(proj.name);
+      }
+    }
     // Remove build directories
+    cforge::tree_removal_result totals;
     for (auto &bd : build_dirs) {
-      clean_build_directory(bd, verbose);
+      clean_build_directory(bd, executables, kill_holders, totals);
       if (regenerate) {
         regenerate_cmake_files(current_dir, bd, config_name, verbose);
       }
     }
-    cforge::logger::print_action("Finished", "cforge::workspaceclean");
-    return 0;
+    cforge::logger::print_action("Finished", "cforge::workspaceclean" + removal_summary(totals));
+    return totals.skipped.empty() ? 0 : 1;
   }
   // Check if cforge.toml exists
   if (!std::filesystem::exists(CFORGE_FILE)) {
@@ -437,15 +585,16 @@ cforge_int_t cforge_cmd_clean(const cforge_context_t *ctx) {
   // Get project directory
   std::filesystem::path project_dir = ctx->working_dir;
 
-  // Get base build directory from config
-  std::string base_build_dir = config.get_string("build.build_dir", "build");
+  // Get base build directory (--build-dir, CFORGE_BUILD_DIR, or config)
+  std::string base_build_dir = cforge::get_build_base_dir(project_dir, &config).string();
 
   // Check arguments
-  std::string config_name;   // Specific configuration to clean
-  bool clean_all   = false;  // Clean all configurations
-  bool clean_cmake = true;   // Clean CMake files by default since we regenerate CMakeLists.txt
-  bool regenerate  = false;  // Regenerate CMake files after cleaning
-  bool deep        = false;  // Deep clean: remove dependencies directory
+  std::string config_name;    // Specific configuration to clean
+  bool clean_all    = false;  // Clean all configurations
+  bool clean_cmake  = true;   // Clean CMake files by default since we regenerate CMakeLists.txt
+  bool regenerate   = false;  // Regenerate CMake files after cleaning
+  bool deep         = false;  // Deep clean: remove dependencies directory
+  bool kill_holders = false;  // Stop processes holding files in the build directory (Windows)
 
   bool verbose = cforge::logger::get_verbosity() == cforge::log_verbosity::VERBOSITY_VERBOSE;
 
@@ -461,6 +610,8 @@ cforge_int_t cforge_cmd_clean(const cforge_context_t *ctx) {
       regenerate = true;
     } else if (arg == "--deep") {
       deep = true;
+    } else if (arg == "--kill-holders") {
+      kill_holders = true;
     } else if ((arg == "--config" || arg == "-c") && (i + 1) < ctx->args.arg_count) {
       config_name = ctx->args.args[++i];
     }
@@ -488,10 +639,18 @@ cforge_int_t cforge_cmd_clean(const cforge_context_t *ctx) {
     clean_cmake_files(verbose);
   }
 
-  // Clean each build directory
+  // Clean each build directory; a single configuration of a multi-config
+  // tree is cleaned in place
+  std::vector<std::string> executables = {config.get_string("project.name", "")};
+  cforge::tree_removal_result totals;
   bool all_cleaned = true;
   for (const auto &build_dir : build_dirs) {
-    if (!clean_build_directory(build_dir, verbose)) {
+    std::string generator = cforge::get_cached_cmake_generator(build_dir);
+    bool in_place         = !clean_all && cforge::is_multi_config_generator(generator);
+    bool cleaned =
+        in_place ? clean_build_config(build_dir, config_name, verbose)
+                 : clean_build_directory(build_dir, executables, kill_holders, totals);
+    if (!cleaned) {
       all_cleaned = false;
     }
   }
@@ -501,15 +660,8 @@ cforge_int_t cforge_cmd_clean(const cforge_context_t *ctx) {
     std::string deps_dir            = config.get_string("dependencies.directory", "deps");
     std::filesystem::path deps_path = project_dir / deps_dir;
     if (std::filesystem::exists(deps_path)) {
-      cforge::logger::removing(deps_path.string());
-      try {
-        std::filesystem::remove_all(deps_path);
-        cforge::logger::print_action("Removed", deps_path.string());
-      } catch (const std::exception &e) {
-        cforge::logger::print_error("Failed to remove dependencies directory: "
-                                    + std::string(e.what()));
-        all_cleaned = false;
-      }
+      all_cleaned = clean_build_directory(deps_path, executables, kill_holders, totals)
+                    && all_cleaned;
     } else {
       cforge::logger::print_status("Dependencies directory does not exist, nothing to clean: "
                                    + deps_path.string());
@@ -526,10 +678,10 @@ cforge_int_t cforge_cmd_clean(const cforge_context_t *ctx) {
   }
 
   if (all_cleaned) {
-    cforge::logger::print_action("Finished", "clean");
+    cforge::logger::print_action("Finished", "clean" + removal_summary(totals));
     return 0;
   } else {
-    cforge::logger::print_error("Some directories could not be cleaned");
+    cforge::logger::print_error("Some directories could not be cleaned" + removal_summary(totals));
     return 1;
   }
 }
\ No newline at end of file
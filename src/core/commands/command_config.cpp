@@ -0,0 +1,236 @@
+/**
+ * @file command_config.cpp
+ * @brief Implementation of the 'config' command to read and edit cforge.toml
+ *        values by dotted path
+ */
+
+#include "cforge/log.hpp"
+
+#include "core/command_registry.hpp"
+#include "core/commands.hpp"
+#include "core/constants.h"
+#include "core/manifest_edit.hpp"
+#include "core/manifest_schema.hpp"
+#include "core/types.h"
+#include "core/workspace.hpp"
+
+#include <toml++/toml.hpp>
+
+#include <algorithm>
+#include <filesystem>
+#include <fstream>
+#include <sstream>
+#include <string>
+#include <vector>
+
+namespace {
+
+/**
+ * @brief Name of a TOML value's type, for error messages
+ */
+std::string toml_type_name(toml::node_type type) {
+  switch (type) {
+    case toml::node_type::table:
+      return "a table";
+    case toml::node_type::array:
+      return "an array";
+    case toml::node_type::string:
+      return "a string";
+    case toml::node_type::integer:
+      return "an integer";
+    case toml::node_type::floating_point:
+      return "a number";
+    case toml::node_type::boolean:
+      return "a boolean";
+    default:
+      return "a date or time";
+  }
+}
+
+/**
+ * @brief Format a value for printing; strings are printed without quotes
+ */
+std::string format_toml_value(toml::node_view<toml::node> value) {
+  if (!value) {
+    return "(not set)";
+  }
+  if (const auto *str = value.as_string()) {
+    return str->get();
+  }
+  std::ostringstream out;
+  out << value;
+  return out.str();
+}
+
+}  // namespace
+
+/**
+ * @brief Handle the 'config' command
+ *
+ * @param ctx Context containing parsed arguments
+ * @return cforge_int_t Exit code (0 for success)
+ */
+cforge_int_t cforge_cmd_config(const cforge_context_t *ctx) {
+  std::vector<std::string> positional;
+  bool use_workspace = false;
+
+  for (cforge_int_t i = 0; i < ctx->args.arg_count; ++i) {
+    std::string arg = ctx->args.args[i];
+    if (arg == "-h" || arg == "--help") {
+      cforge::command_registry::instance().print_command_help("config");
+      return 0;
+    } else if (arg == "--workspace") {
+      use_workspace = true;
+    } else if (arg.rfind("--", 0) == 0) {
+      cforge::logger::print_error("Unknown option: " + arg);
+      return 1;
+    } else {
+      // Values may start with '-', e.g. `config set cmake.args -Wall`
+      positional.push_back(arg);
+    }
+  }
+
+  std::string action = positional.empty() ? "" : positional[0];
+  if (action != "get" && action != "set" && action != "unset") {
+    cforge::logger::print_error(action.empty() ? "No action given"
+                                               : "Unknown action '" + action + "'");
+    cforge::logger::print_hint("expected get, set or unset");
+    return 1;
+  }
+  if (positional.size() != (action == "set" ? 3u : 2u)) {
+    cforge::logger::print_error("Usage: cforge config "
+                                + (action == "set" ? "set <key> <value>" : action + " <key>"));
+    return 1;
+  }
+  std::string path = positional[1];
+
+  std::filesystem::path file = ctx->working_dir / std::filesystem::path(CFORGE_FILE);
+  if (use_workspace) {
+    auto [is_workspace, workspace_dir] = cforge::is_in_workspace(ctx->working_dir);
+    if (!is_workspace) {
+      cforge::logger::print_error("Not in a workspace");
+      return 1;
+    }
+    file = cforge::get_workspace_config_path(workspace_dir);
+  }
+
+  std::string content;
+  {
+    std::ifstream in(file, std::ios::binary);
+    if (!in) {
+      cforge::logger::print_error("No " + file.filename().string() + " in "
+                                  + file.parent_path().string());
+      return 1;
+    }
+    std::stringstream buffer;
+    buffer << in.rdbuf();
+    content = buffer.str();
+  }
+
+  toml::table table;
+  std::string problem = cforge::check_manifest_edit(content, file, "", true, &table);
+  if (!problem.empty()) {
+    cforge::logger::print_error(problem);
+    cforge::logger::print_hint("fix the TOML syntax in " + file.string() + " first");
+    return 1;
+  }
+  toml::node_view<toml::node> old_value = table.at_path(path);
+
+  if (action == "get") {
+    if (!old_value) {
+      cforge::logger::print_error("'" + path + "' is not set in " + file.filename().string());
+      return 1;
+    }
+    cforge::logger::print_plain(format_toml_value(old_value));
+    return 0;
+  }
+
+  if (!cforge::is_editable_manifest_path(path)) {
+    cforge::logger::print_error("'" + path + "' can't be edited");
+    cforge::logger::print_hint("use a dotted path of plain keys, e.g. build.source_dirs; "
+                               "set a whole array rather than one element");
+    return 1;
+  }
+
+  // Keys of the tables with a fixed set of keys must be ones cforge reads
+  std::string table_name = path.substr(0, path.find('.'));
+  auto known_keys        = cforge::known_manifest_keys().find(table_name);
+  if (action == "set" && table_name != path && known_keys != cforge::known_manifest_keys().end()) {
+    std::string key = path.substr(table_name.size() + 1);
+    key             = key.substr(0, key.find('.'));
+    if (std::find(known_keys->second.begin(), known_keys->second.end(), key)
+        == known_keys->second.end()) {
+      cforge::logger::print_error("'" + table_name + "." + key + "' is not a key cforge reads");
+      std::string suggestion = cforge::closest_manifest_key(key, known_keys->second);
+      if (!suggestion.empty()) {
+        cforge::logger::print_hint("did you mean '" + table_name + "." + suggestion + "'?");
+      }
+      return 1;
+    }
+  }
+
+  // The value must have the type cforge reads the key as, or else keep the
+  // type of the existing value
+  toml::node_type expected = cforge::expected_manifest_type(path);
+  bool fixed_type          = expected != toml::node_type::none;
+  if (!fixed_type && old_value) {
+    expected = old_value.type();
+  }
+
+  std::string edited = content;
+  if (action == "unset") {
+    if (!old_value) {
+      cforge::logger::print_status("'" + path + "' is not set");
+      return 0;
+    }
+    if (!cforge::unset_manifest_value(edited, path)) {
+      cforge::logger::print_error("'" + path + "' is set inside an inline table or array");
+      cforge::logger::print_hint("edit " + file.string() + " by hand");
+      return 1;
+    }
+  } else {
+    std::string new_text = cforge::manifest_value_literal(positional[2]);
+    // A number for a key that holds a string, such as project.cpp_standard,
+    // is meant as the string
+    if (expected == toml::node_type::string
+        && std::string("\"'[{").find(new_text.front()) == std::string::npos) {
+      new_text = "\"" + new_text + "\"";
+    }
+    cforge::set_manifest_value(edited, path, new_text);
+  }
+
+  // The edit must produce valid TOML with the value where it was meant to go
+  toml::table result;
+  problem = cforge::check_manifest_edit(edited, file, path, action == "set", &result);
+  if (!problem.empty()) {
+    cforge::logger::print_error("Could not " + action + " '" + path + "' in "
+                                + file.filename().string() + ": " + problem);
+    cforge::logger::print_hint("'" + path + "' may be defined in an inline table; "
+                               "edit " + file.string() + " by hand");
+    return 1;
+  }
+  toml::node_view<toml::node> new_value = result.at_path(path);
+  if (action == "set" && expected != toml::node_type::none && new_value.type() != expected
+      && !(!fixed_type && old_value.is_number() && new_value.is_number())) {
+    cforge::logger::print_error("'" + path + "' must be " + toml_type_name(expected) + ", not "
+                                + toml_type_name(new_value.type()));
+    if (expected == toml::node_type::array) {
+      cforge::logger::print_hint("write the value as an array, e.g. '[\"include\", \"src\"]'");
+    }
+    return 1;
+  }
+
+  std::ofstream out(file, std::ios::binary);
+  if (!out) {
+    cforge::logger::print_error("Failed to write " + file.string());
+    return 1;
+  }
+  out << edited;
+  out.close();
+
+  cforge::logger::print_action(action == "set" ? "Set" : "Unset",
+                               path + " in " + file.filename().string());
+  cforge::logger::print_kv("old", format_toml_value(old_value));
+  cforge::logger::print_kv("new", format_toml_value(new_value));
+  return 0;
+}
@@ -0,0 +1,335 @@
+/**
+ * @file command_coverage.cpp
+ * @brief Implementation of the 'coverage' command to measure test coverage
+ */
+
+#include "cforge/log.hpp"
+
+#include "core/build_utils.hpp"
+#include "core/command_registry.hpp"
+#include "core/commands.hpp"
+#include "core/constants.h"
+#include "core/coverage.hpp"
+#include "core/process_utils.hpp"
+#include "core/toml_reader.hpp"
+#include "core/types.h"
+
+#include <cstdlib>
+#include <filesystem>
+#include <fstream>
+#include <string>
+#include <vector>
+
+namespace fs = std::filesystem;
+
+namespace {
+
+/**
+ * @brief Set an environment variable for the builds and tests cforge runs
+ */
+void set_env(const std::string &name, const std::string &value) {
+#ifdef _WIN32
+  _putenv_s(name.c_str(), value.c_str());
+#else
+  setenv(name.c_str(), value.c_str(), 1);
+#endif
+  cforge::logger::print_verbose("Environment: " + name + "=" + value);
+}
+
+/**
+ * @brief Add flags to CFLAGS/CXXFLAGS/LDFLAGS
+ *
+ * CMake reads them when it first configures a build directory, which is why
+ * coverage builds get a directory of their own.
+ */
+void append_flags_env(const std::string &name, const std::string &flags) {
+  cforge_cstring_t existing = std::getenv(name.c_str());
+  set_env(name, existing && *existing ? std::string(existing) + " " + flags : flags);
+}
+
+/**
+ * @brief Find an LLVM tool, through xcrun on macOS if it isn't on PATH
+ *
+ * @param tool Tool name
+ * @param command Receives the command to run
+ * @param prefix Receives arguments that go before the tool's own
+ * @return false if the tool is not available
+ */
+bool find_llvm_tool(const std::string &tool,
+                    std::string &command,
+                    std::vector<std::string> &prefix) {
+  prefix.clear();
+  if (cforge::is_command_available(tool, 10)) {
+    command = tool;
+    return true;
+  }
+#ifdef __APPLE__
+  if (cforge::execute_process("xcrun", {"--find", tool}, "", nullptr, nullptr, 10).success) {
+    command = "xcrun";
+    prefix  = {tool};
+    return true;
+  }
+#endif
+  return false;
+}
+
+/**
+ * @brief Run a report tool
+ *
+ * @param ok Receives whether the tool succeeded
+ * @return The tool's standard output
+ */
+std::string run_report_tool(const std::string &command,
+                            const std::vector<std::string> &args,
+                            const fs::path &working_dir,
+                            bool &ok) {
+  auto result = cforge::execute_process(
+      command,
+      args,
+      working_dir.string(),
+      nullptr,
+      [](const std::string &line) { cforge::logger::print_verbose(line); },
+      600);
+  ok = result.success;
+  if (!ok) {
+    cforge::logger::print_error(command + " failed with exit code "
+                                + std::to_string(result.exit_code));
+    if (!result.stderr_output.empty()) {
+      cforge::logger::print_plain(result.stderr_output);
+    }
+  }
+  return result.stdout_output;
+}
+
+/**
+ * @brief Remove files with an extension below a directory
+ */
+void remove_files_with_extension(const fs::path &dir, const std::string &extension) {
+  std::error_code ec;
+  if (!fs::is_directory(dir, ec)) {
+    return;
+  }
+  std::vector<fs::path> stale;
+  for (const auto &entry : fs::recursive_directory_iterator(dir, ec)) {
+    if (entry.is_regular_file(ec) && entry.path().extension() == extension) {
+      stale.push_back(entry.path());
+    }
+  }
+  for (const auto &path : stale) {
+    fs::remove(path, ec);
+  }
+}
+
+/**
+ * @brief Produce the gcovr report from the .gcda files of a test run
+ */
+bool report_gcovr(const fs::path &project_dir,
+                  const cforge::toml_reader &project_config,
+                  const fs::path &build_dir,
+                  const fs::path &output_dir) {
+  std::vector<std::string> source_dirs = project_config.get_string_array("build.source_dirs");
+  if (source_dirs.empty()) {
+    source_dirs = {"src"};
+  }
+  auto include_dirs = project_config.get_string_array("build.include_dirs");
+  source_dirs.insert(source_dirs.end(), include_dirs.begin(), include_dirs.end());
+  if (include_dirs.empty()) {
+    source_dirs.push_back("include");
+  }
+
+  bool ok             = false;
+  std::string summary = run_report_tool(
+      "gcovr",
+      cforge::gcovr_report_args(
+          build_dir.generic_string(), output_dir.generic_string(), source_dirs),
+      project_dir,
+      ok);
+  if (ok) {
+    cforge::logger::print_plain(summary);
+  }
+  return ok;
+}
+
+/**
+ * @brief Merge the raw profiles of a test run and produce the llvm-cov report
+ */
+bool report_llvm_cov(const fs::path &project_dir,
+                     const cforge::toml_reader &project_config,
+                     const fs::path &build_dir,
+                     const fs::path &output_dir) {
+  std::string profdata_cmd;
+  std::string cov_cmd;
+  std::vector<std::string> profdata_args;
+  std::vector<std::string> cov_prefix;
+  find_llvm_tool("llvm-profdata", profdata_cmd, profdata_args);
+  find_llvm_tool("llvm-cov", cov_cmd, cov_prefix);
+
+  std::error_code ec;
+  fs::path profdata = build_dir / "coverage.profdata";
+  profdata_args.insert(profdata_args.end(), {"merge", "-sparse", "-o", profdata.string()});
+  bool have_profiles = false;
+  for (const auto &entry : fs::directory_iterator(build_dir / "profraw", ec)) {
+    profdata_args.push_back(entry.path().string());
+    have_profiles = true;
+  }
+  if (!have_profiles) {
+    cforge::logger::print_error("The tests wrote no coverage data");
+    cforge::logger::print_hint("check that test executables were built and ran");
+    return false;
+  }
+
+  // Test executables are named <target>_test
+  std::vector<std::string> executables;
+  for (const auto &entry : fs::recursive_directory_iterator(build_dir / "tests", ec)) {
+    std::string stem = entry.path().stem().string();
+    if (entry.is_regular_file(ec) && stem.size() > 5
+        && stem.compare(stem.size() - 5, 5, "_test") == 0
+        && (entry.path().extension().empty() || entry.path().extension() == ".exe")) {
+      executables.push_back(entry.path().string());
+    }
+  }
+  if (executables.empty()) {
+    cforge::logger::print_error("No test executables found in " + (build_dir / "tests").string());
+    return false;
+  }
+
+  bool ok = false;
+  run_report_tool(profdata_cmd, profdata_args, project_dir, ok);
+  if (!ok) {
+    return false;
+  }
+
+  // Leave dependencies, generated code and the tests themselves out
+  std::string test_dir     = project_config.get_string("test.directory", "tests");
+  std::string ignore_regex = "(^|[/\\\\])(_deps|" + test_dir + "|"
+                           + build_dir.parent_path().filename().string() + ")[/\\\\]";
+
+  for (const std::string command : {"show", "report"}) {
+    std::vector<std::string> args = cov_prefix;
+    auto report_args              = cforge::llvm_cov_report_args(
+        command, executables, profdata.string(), output_dir.string(), ignore_regex);
+    args.insert(args.end(), report_args.begin(), report_args.end());
+    std::string output = run_report_tool(cov_cmd, args, project_dir, ok);
+    if (!ok) {
+      return false;
+    }
+    if (command == "report") {
+      std::ofstream(output_dir / "coverage.txt", std::ios::binary) << output;
+      cforge::logger::print_plain(output);
+    }
+  }
+  return true;
+}
+
+}  // namespace
+
+/**
+ * @brief Handle the 'coverage' command
+ *
+ * Builds the tests with coverage instrumentation in `<build>/coverage`, runs
+ * them the way `cforge test` does and writes an HTML and a text report.
+ *
+ * @param ctx Context containing parsed arguments
+ * @return cforge_int_t Exit code (0 for success)
+ */
+cforge_int_t cforge_cmd_coverage(const cforge_context_t *ctx) {
+  std::string output = "coverage";
+  std::vector<cforge_string_t> test_args;
+  for (cforge_int_t i = 0; i < ctx->args.arg_count; ++i) {
+    std::string arg = ctx->args.args[i];
+    if (arg == "-h" || arg == "--help") {
+      cforge::command_registry::instance().print_command_help("coverage");
+      return 0;
+    } else if ((arg == "-o" || arg == "--output") && i + 1 < ctx->args.arg_count) {
+      output = ctx->args.args[++i];
+    } else {
+      test_args.push_back(ctx->args.args[i]);
+    }
+  }
+
+  fs::path project_dir = fs::absolute(ctx->working_dir);
+  cforge::toml_reader project_config;
+  if (!project_config.load((project_dir / CFORGE_FILE).string())
+      || !project_config.has_key("project.name")) {
+    cforge::logger::print_error("coverage needs a project; run it in a project directory");
+    return 1;
+  }
+
+  // Tests are configured with CMake's default compiler, so CXX decides
+  cforge_cstring_t cxx_env = std::getenv("CXX");
+  std::string cxx          = cxx_env ? cxx_env : "";
+  if (cxx.empty()) {
+    cxx = project_config.get_string("cmake.cxx_compiler", "");
+    if (!cxx.empty()) {
+      set_env("CXX", cxx);
+    }
+  }
+#if defined(_WIN32)
+  std::string family = cforge::coverage_compiler_family(cxx, "msvc");
+#elif defined(__APPLE__)
+  std::string family = cforge::coverage_compiler_family(cxx, "clang");
+#else
+  std::string family = cforge::coverage_compiler_family(cxx, "gcc");
+#endif
+  cforge::coverage_toolchain toolchain = cforge::coverage_toolchain_for(family);
+  if (toolchain.flags.empty()) {
+    cforge::logger::print_error("coverage needs GCC or Clang, but the compiler is "
+                                + (cxx.empty() ? family : cxx));
+    cforge::logger::print_hint("set CXX or cmake.cxx_compiler to g++ or clang++");
+    return 1;
+  }
+
+  // Check for the report tool before spending time on a build
+  std::string command;
+  std::vector<std::string> prefix;
+  if (toolchain.report == "gcovr" && !cforge::is_command_available("gcovr", 10)) {
+    cforge::logger::print_error("gcovr not found in PATH, but GCC coverage reports need it");
+    cforge::logger::print_hint("install it with 'pip install gcovr'");
+    return 1;
+  }
+  if (toolchain.report == "llvm-cov"
+      && (!find_llvm_tool("llvm-cov", command, prefix)
+          || !find_llvm_tool("llvm-profdata", command, prefix))) {
+    cforge::logger::print_error("llvm-cov and llvm-profdata are needed for Clang coverage reports");
+    cforge::logger::print_hint("install LLVM and make sure its bin directory is in PATH");
+    return 1;
+  }
+
+  fs::path build_dir = cforge::get_build_base_dir(project_dir, &project_config) / "coverage";
+  cforge::set_build_dir_override(build_dir.string());
+  append_flags_env("CFLAGS", toolchain.flags);
+  append_flags_env("CXXFLAGS", toolchain.flags);
+  append_flags_env("LDFLAGS", toolchain.flags);
+
+  // Counters from an earlier run would add up with this one
+  std::error_code ec;
+  if (toolchain.report == "gcovr") {
+    remove_files_with_extension(build_dir, ".gcda");
+  } else {
+    fs::remove_all(build_dir / "profraw", ec);
+    set_env("LLVM_PROFILE_FILE", (build_dir / "profraw" / "%p-%m.profraw").string());
+  }
+
+  cforge::logger::print_action("Coverage", family + " (" + toolchain.report + ")");
+  cforge_context_t test_ctx = *ctx;
+  test_ctx.args.arg_count   = static_cast<cforge_int_t>(test_args.size());
+  test_ctx.args.args        = test_args.empty() ? nullptr : test_args.data();
+  cforge_int_t test_result  = cforge_cmd_test(&test_ctx);
+
+  fs::path output_dir = output;
+  if (output_dir.is_relative()) {
+    output_dir = project_dir / output_dir;
+  }
+  fs::create_directories(output_dir, ec);
+
+  bool reported = toolchain.report == "gcovr"
+                    ? report_gcovr(project_dir, project_config, build_dir, output_dir)
+                    : report_llvm_cov(project_dir, project_config, build_dir, output_dir);
+  if (reported) {
+    cforge::logger::print_action("Generated", (output_dir / "index.html").string());
+  }
+  if (test_result != 0) {
+    return test_result;
+  }
+  return reported ? 0 : 1;
+}
@@ -5,7 +5,7 @@
  * Consolidates all dependency-related commands under 'cforge deps':
  *   deps add      - Add a dependency
  *   deps remove   - Remove a dependency
- *   deps update   - Update the package registry
+ *   deps update   - Update the package registry and re-resolve cforge.lock
  *   deps search   - Search for packages
  *   deps info     - Show package information
  *   deps tree     - Visualize dependency tree
@@ -19,6 +19,7 @@
 #include "core/command_registry.hpp"
 #include "core/commands.hpp"
 #include "core/constants.h"
+#include "core/lockfile.hpp"
 #include "core/registry.hpp"
 #include "core/toml_reader.hpp"
 #include "core/types.h"
@@ -466,6 +467,14 @@ cforge_int_t cforge_cmd_deps(const cforge_context_t *ctx) {
     }
     free(new_args);
 
+    // Drop the lock so the next build resolves every ref again and rewrites it
+    if (result == 0 && cforge::lockfile::exists(ctx->working_dir)) {
+      std::filesystem::remove(std::filesystem::path(ctx->working_dir) / cforge::LOCK_FILE);
+      cforge::logger::removing(std::string(cforge::LOCK_FILE));
+      cforge::logger::print_hint("the next build re-resolves dependencies and writes a new "
+                                 + std::string(cforge::LOCK_FILE));
+    }
+
     return result;
   } else if (subcommand == "search") {
     cforge_context_t sub_ctx = create_subcommand_context(ctx, "search", 1);
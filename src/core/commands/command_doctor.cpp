@@ -5,17 +5,33 @@
 
 #include "cforge/log.hpp"
 
+#include "core/build_utils.hpp"
 #include "core/command_registry.hpp"
 #include "core/commands.hpp"
+#include "core/constants.h"
+#include "core/doctor_checks.hpp"
 #include "core/process_utils.hpp"
+#include "core/source_globs.hpp"
+#include "core/toml_reader.hpp"
 #include "core/tool_installer.hpp"
 #include "core/types.h"
+#include "core/workspace.hpp"
 
 #include <fmt/color.h>
 #include <fmt/core.h>
+#include <toml++/toml.hpp>
 
+#include <algorithm>
+#include <chrono>
+#include <cstdlib>
 #include <filesystem>
+#include <iterator>
+#include <map>
 #include <regex>
+#include <set>
+#include <sstream>
+#include <string>
+#include <vector>
 
 namespace cforge {
 
@@ -39,203 +55,593 @@ static std::string get_tool_version(const std::string &command,
   return "";
 }
 
-static void print_check_result(const std::string &name,
-                               bool success,
-                               const std::string &version = "",
-                               const std::string &help    = "") {
-  if (success) {
-    // Green checkmark with name and version
-    std::string value = version.empty() ? "found" : version;
-    logger::print_kv_colored(name, value, fmt::color::green, 20, 2);
+/**
+ * @brief Check that a tool is on PATH and report its version
+ *
+ * @param name Name shown in the report
+ * @param command Command looked up on PATH
+ * @param required Fail instead of warn when the tool is missing
+ * @param hint Remediation shown when the tool is missing
+ */
+static doctor_check check_tool(const std::string &name,
+                               const std::string &command,
+                               bool required,
+                               const std::string &hint) {
+  doctor_check check;
+  check.name = name;
+  if (!is_command_available(command, 5)) {
+    check.status = required ? check_status::fail : check_status::warn;
+    check.detail = "not found";
+    check.hint   = hint;
+    return check;
+  }
+  check.detail = get_tool_version(command, {"--version"});
+  if (check.detail.empty()) {
+    check.detail = "found";
+  }
+  return check;
+}
+
+/**
+ * @brief Check that CMake is installed and new enough for generated projects
+ */
+static doctor_check check_cmake() {
+  doctor_check check =
+      check_tool("CMake", "cmake", true, "install from https://cmake.org/download/");
+  if (check.status == check_status::pass && check.detail != "found"
+      && !version_at_least(check.detail, CMAKE_MIN_VERSION)) {
+    check.status = check_status::fail;
+    check.detail += " (older than " + std::string(CMAKE_MIN_VERSION) + ")";
+    check.hint = "generated projects need CMake " + std::string(CMAKE_MIN_VERSION)
+               + " or newer; upgrade from https://cmake.org/download/";
+  }
+  return check;
+}
+
+/**
+ * @brief Check that at least one supported C++ compiler can be found
+ *
+ * On Windows MSVC counts when cl.exe is on PATH or when a Visual Studio
+ * install with the C++ toolset can be loaded through vcvarsall.
+ */
+static doctor_check check_compiler() {
+  doctor_check check;
+  check.name = "C++ Compiler";
+
+#ifdef _WIN32
+  // Check for MSVC or MinGW
+  if (is_command_available("cl", 5)) {
+    check.name = "MSVC";
+  } else if (setup_msvc_environment("", false)) {
+    // Installed, but only on PATH inside a Developer Command Prompt
+    check.name   = "MSVC";
+    check.detail = "loaded via vcvarsall";
+  } else if (is_command_available("g++", 5)) {
+    check.name   = "g++ (MinGW)";
+    check.detail = get_tool_version("g++", {"--version"});
+  } else if (is_command_available("clang++", 5)) {
+    check.name   = "clang++";
+    check.detail = get_tool_version("clang++", {"--version"});
+  } else {
+    check.status = check_status::fail;
+  }
+#else
+  if (is_command_available("g++", 5)) {
+    check.name   = "g++";
+    check.detail = get_tool_version("g++", {"--version"});
+  } else if (is_command_available("clang++", 5)) {
+    check.name   = "clang++";
+    check.detail = get_tool_version("clang++", {"--version"});
   } else {
-    // Red X with name
-    logger::print_kv_colored(name, "not found", fmt::color::red, 20, 2);
-    if (!help.empty()) {
-      logger::print_hint(help);
+    check.status = check_status::fail;
+  }
+#endif
+
+  if (check.status == check_status::fail) {
+    check.detail = "not found";
+    check.hint   = "install a C++ compiler (g++, clang++, or MSVC)";
+  } else if (check.detail.empty()) {
+    check.detail = "found";
+  }
+  return check;
+}
+
+/**
+ * @brief Check the build tool that the configured CMake generator runs
+ *
+ * @param project_config Project configuration, or nullptr outside a project
+ * @param missing_tools Receives tools the installer can offer
+ */
+static doctor_check check_generator_tool(const toml_reader *project_config,
+                                         std::vector<std::string> &missing_tools) {
+  std::string generator = project_config ? project_config->get_string("cmake.generator", "") : "";
+  if (generator.empty()) {
+    generator = get_cmake_generator();
+  }
+
+  std::string tool;
+  std::string hint;
+  if (generator.find("Ninja") != std::string::npos) {
+    tool = "ninja";
+    hint = "install with 'choco install ninja' or 'apt install ninja-build'";
+  } else if (generator == "Unix Makefiles") {
+    tool = "make";
+    hint = "install make (e.g. 'apt install build-essential' or 'xcode-select --install')";
+  } else if (generator == "MinGW Makefiles") {
+    tool = "mingw32-make";
+    hint = "install MinGW-w64 and add its bin directory to PATH";
+  } else if (generator.rfind("NMake", 0) == 0) {
+    tool = "nmake";
+    hint = "run from a Developer Command Prompt or install the Visual Studio Build Tools";
+  }
+
+  if (tool.empty()) {
+    // Visual Studio and Xcode generators drive their own build tools
+    doctor_check check;
+    check.name   = "Generator";
+    check.detail = generator;
+    return check;
+  }
+
+  doctor_check check = check_tool("Generator", tool, true, hint);
+  if (check.status == check_status::pass) {
+    check.detail = generator + " (" + tool + " " + check.detail + ")";
+  } else {
+    check.detail = generator + " (" + tool + " not found)";
+    if (tool == "ninja") {
+      missing_tools.push_back("ninja");
+    }
+  }
+  return check;
+}
+
+/**
+ * @brief Check the free space where build output goes
+ *
+ * @param dir Build directory; its nearest existing parent is measured
+ */
+static doctor_check check_disk_space(std::filesystem::path dir) {
+  doctor_check check;
+  check.name = "Disk space";
+
+  std::error_code ec;
+  dir = std::filesystem::absolute(dir, ec);
+  while (!dir.empty() && !std::filesystem::exists(dir, ec) && dir != dir.parent_path()) {
+    dir = dir.parent_path();
+  }
+  std::filesystem::space_info space = std::filesystem::space(dir, ec);
+  if (ec) {
+    check.status = check_status::warn;
+    check.detail = "could not be determined for " + dir.string();
+    return check;
+  }
+
+  constexpr std::uintmax_t mib = 1024 * 1024;
+  check.detail = fmt::format("{:.1f} GiB free in {}",
+                             static_cast<double>(space.available) / (1024.0 * mib),
+                             dir.string());
+  if (space.available < 256 * mib) {
+    check.status = check_status::fail;
+    check.hint   = "free up space; builds with dependencies can need several GiB";
+  } else if (space.available < 1024 * mib) {
+    check.status = check_status::warn;
+    check.hint   = "less than 1 GiB free; builds with dependencies can need several GiB";
+  }
+  return check;
+}
+
+/**
+ * @brief Look for compilers that resolve to different binaries on PATH
+ *
+ * The first match on PATH wins, so a second toolchain earlier on PATH
+ * silently changes which compiler CMake picks up.
+ */
+static doctor_check check_path_conflicts() {
+  doctor_check check;
+  check.name   = "PATH";
+  check.detail = "no conflicting compilers";
+
+  cforge_cstring_t path_env = std::getenv("PATH");
+  if (!path_env) {
+    check.status = check_status::warn;
+    check.detail = "PATH is not set";
+    return check;
+  }
+
+#ifdef _WIN32
+  const char separator        = ';';
+  const std::string extension = ".exe";
+#else
+  const char separator        = ':';
+  const std::string extension = "";
+#endif
+
+  std::vector<std::string> conflicts;
+  for (const std::string compiler : {"g++", "clang++", "cl", "c++"}) {
+    std::vector<std::string> found;
+    std::set<std::filesystem::path> binaries;
+    std::stringstream ss(path_env);
+    std::string entry;
+    while (std::getline(ss, entry, separator)) {
+      if (entry.empty()) {
+        continue;
+      }
+      std::error_code ec;
+      std::filesystem::path candidate = std::filesystem::path(entry) / (compiler + extension);
+      if (!std::filesystem::is_regular_file(candidate, ec)) {
+        continue;
+      }
+      // Symlinked directories (/bin -> /usr/bin) and alternatives are the same binary
+      std::filesystem::path binary = std::filesystem::canonical(candidate, ec);
+      if (!ec && binaries.insert(binary).second) {
+        found.push_back(candidate.string());
+      }
+    }
+    if (found.size() > 1) {
+      std::string list;
+      for (const auto &f : found) {
+        list += (list.empty() ? "" : ", ") + f;
+      }
+      conflicts.push_back(compiler + ": " + list);
+    }
+  }
+
+  if (!conflicts.empty()) {
+    check.status = check_status::warn;
+    check.detail = "";
+    for (const auto &c : conflicts) {
+      check.detail += (check.detail.empty() ? "" : "; ") + c;
+    }
+    check.hint = "the first one on PATH is used; reorder PATH or set cmake.cxx_compiler "
+                 "in cforge.toml";
+  }
+  return check;
+}
+
+/**
+ * @brief Check that a vcpkg checkout is usable and not badly out of date
+ */
+static doctor_check check_vcpkg(const std::string &vcpkg_root) {
+  doctor_check check;
+  check.name = "vcpkg";
+
+  std::filesystem::path vcpkg_exe = std::filesystem::path(vcpkg_root) / "vcpkg";
+#ifdef _WIN32
+  vcpkg_exe += ".exe";
+#endif
+  if (vcpkg_root.empty() || !std::filesystem::exists(vcpkg_exe)) {
+    check.status = check_status::fail;
+    check.detail = vcpkg_root.empty() ? "not found" : "not bootstrapped in " + vcpkg_root;
+    check.hint   = "run 'cforge vcpkg setup', or set dependencies.vcpkg.path or VCPKG_ROOT";
+    return check;
+  }
+  check.detail = vcpkg_root;
+
+  // A checkout that hasn't been pulled for months has an old port baseline
+  process_result last_commit =
+      execute_process("git", {"-C", vcpkg_root, "log", "-1", "--format=%ct"}, "", nullptr,
+                      nullptr, 10);
+  if (last_commit.success) {
+    try {
+      auto committed = std::chrono::system_clock::time_point(
+          std::chrono::seconds(std::stoll(last_commit.stdout_output)));
+      auto age = std::chrono::duration_cast<std::chrono::hours>(std::chrono::system_clock::now()
+                                                                - committed);
+      if (age.count() > 24 * 180) {
+        check.status = check_status::warn;
+        check.detail += " (last updated " + std::to_string(age.count() / 24) + " days ago)";
+        check.hint = "run 'cforge vcpkg update' to get newer ports";
+      }
+    } catch (const std::exception &) {
+      // Not a git checkout we can read; the executable is enough
+    }
+  }
+  return check;
+}
+
+/**
+ * @brief Check whether a project-relative glob matches at least one file
+ *
+ * Follows CMake's GLOB_RECURSE, where `*` also matches across directories.
+ */
+static bool glob_matches_any(const std::filesystem::path &project_dir, const std::string &pattern) {
+  std::string regex_pattern = glob_to_regex(pattern, true);
+
+  std::regex file_regex;
+  try {
+    file_regex = std::regex(regex_pattern);
+  } catch (const std::regex_error &) {
+    return true;
+  }
+
+  std::error_code ec;
+  for (auto it = std::filesystem::recursive_directory_iterator(project_dir, ec);
+       !ec && it != std::filesystem::recursive_directory_iterator();
+       it.increment(ec)) {
+    std::string name = it->path().filename().string();
+    if (it->is_directory() && (name == ".git" || name == DEFAULT_BUILD_DIR)) {
+      it.disable_recursion_pending();
+      continue;
+    }
+    std::string relative = std::filesystem::relative(it->path(), project_dir).generic_string();
+    if (std::regex_match(relative, file_regex)) {
+      return true;
+    }
+  }
+  return false;
+}
+
+/**
+ * @brief Check cforge.toml semantics and the package managers the project uses
+ *
+ * Also used by `cforge check`.
+ *
+ * @param project_dir Project directory containing cforge.toml
+ * @param checks Receives one entry per problem found, or a single pass
+ */
+void check_project(const std::filesystem::path &project_dir, std::vector<doctor_check> &checks) {
+  std::filesystem::path toml_path = project_dir / CFORGE_FILE;
+  doctor_check manifest;
+  manifest.name   = CFORGE_FILE;
+  manifest.detail = "valid";
+
+  toml::table table;
+  try {
+    table = toml::parse_file(toml_path.string());
+  } catch (const toml::parse_error &e) {
+    manifest.status = check_status::fail;
+    manifest.detail = std::string(e.description()) + " at line "
+                    + std::to_string(e.source().begin.line);
+    manifest.hint   = "fix the TOML syntax in " + toml_path.string();
+    checks.push_back(manifest);
+    return;
+  }
+  toml_reader project_config(table);
+
+  cforge_size_t problems = 0;
+  auto report            = [&](check_status status, std::string detail, std::string hint) {
+    doctor_check check;
+    check.name   = CFORGE_FILE;
+    check.status = status;
+    check.detail = std::move(detail);
+    check.hint   = std::move(hint);
+    checks.push_back(check);
+    ++problems;
+  };
+
+  for (const auto &key : find_unknown_manifest_keys(table)) {
+    report(check_status::warn,
+           "unknown table [" + key + "]",
+           "cforge ignores this table; check the spelling");
+  }
+  for (const auto &key : find_unknown_table_keys(table)) {
+    std::string table_name = key.path.substr(0, key.path.find('.'));
+    report(check_status::fail,
+           "unknown key '" + key.path + "' at line " + std::to_string(key.line),
+           key.suggestion.empty() ? "remove it or check the spelling"
+                                  : "did you mean '" + table_name + "." + key.suggestion + "'?");
+  }
+
+  std::string binary_type = project_config.get_string("project.binary_type", "executable");
+  if (normalize_binary_type(binary_type).empty()) {
+    report(check_status::fail,
+           "invalid project.binary_type '" + binary_type + "'",
+           "expected one of: executable, static_lib, shared_lib, header_only");
+  }
+
+  // Include directories are only checked when listed; the default include/
+  // is optional
+  for (const auto &key : {"build.include_dirs", "project.additional_includes"}) {
+    for (const auto &dir : project_config.get_string_array(key)) {
+      if (!std::filesystem::is_directory(project_dir / dir)) {
+        report(check_status::fail,
+               std::string(key) + " '" + dir + "' does not exist",
+               "create it or remove it from " + std::string(key));
+      }
+    }
+  }
+
+  // Source directories and globs that match nothing usually mean a typo
+  auto source_dirs = project_config.get_string_array("build.source_dirs");
+  if (source_dirs.empty() && normalize_binary_type(binary_type) != "header_only") {
+    source_dirs.push_back("src");
+  }
+  std::vector<std::string> excludes = default_source_excludes(
+      project_config.get_string("dependencies.directory", "deps"), DEFAULT_BUILD_DIR);
+  auto exclude_sources = project_config.get_string_array("build.exclude_sources");
+  excludes.insert(excludes.end(), exclude_sources.begin(), exclude_sources.end());
+  auto additional_sources = project_config.get_string_array("project.additional_sources");
+  std::vector<std::string> removed;
+  std::copy_if(additional_sources.begin(), additional_sources.end(), std::back_inserter(removed),
+               is_negated_source_pattern);
+  for (const auto &dir : source_dirs) {
+    if (count_project_source_files(project_dir, {dir}, excludes, removed) > 0) {
+      continue;
+    }
+    if (count_project_source_files(project_dir, {dir}, {}) > 0) {
+      report(check_status::warn,
+             "every source in '" + dir + "' is excluded",
+             "check build.exclude_sources and the ! patterns in project.additional_sources");
+    } else {
+      report(check_status::warn,
+             "source directory '" + dir + "' has no .c or .cpp files",
+             "check build.source_dirs, or add sources to " + dir);
     }
   }
+  for (const auto &pattern : additional_sources) {
+    bool negated = is_negated_source_pattern(pattern);
+    if (!glob_matches_any(project_dir, negated ? pattern.substr(1) : pattern)) {
+      report(check_status::warn,
+             "additional_sources '" + pattern + "' matches no files",
+             "check the path; globs are relative to the project directory");
+    }
+  }
+
+  if (problems == 0) {
+    checks.push_back(manifest);
+  }
+
+  if (!get_vcpkg_packages(project_dir, project_config).empty()) {
+    checks.push_back(check_vcpkg(get_vcpkg_root(project_config, project_dir)));
+  }
+
+  bool uses_conan = false;
+  for (const auto &dep : project_config.get_table_keys("dependencies")) {
+    uses_conan = uses_conan || project_config.get_string("dependencies." + dep + ".source", "")
+                                   == "conan";
+  }
+  if (uses_conan) {
+    checks.push_back(check_tool("conan", "conan", true, "install conan with 'pip install conan'"));
+  }
+}
+
+/**
+ * @brief Print one check as a colored key/value line
+ */
+static void print_check(const doctor_check &check) {
+  fmt::color color = check.status == check_status::pass   ? fmt::color::green
+                     : check.status == check_status::warn ? fmt::color::yellow
+                                                          : fmt::color::red;
+  logger::print_kv_colored(check.name, check.detail, color, 20, 2);
+  if (check.status != check_status::pass && !check.hint.empty()) {
+    logger::print_hint(check.hint);
+  }
+}
+
+/**
+ * @brief Print all checks and their totals as one JSON object
+ */
+static void print_checks_json(const std::vector<doctor_check> &checks) {
+  std::map<check_status, cforge_size_t> totals;
+  logger::print_plain("{");
+  logger::print_plain("  \"checks\": [");
+  for (cforge_size_t i = 0; i < checks.size(); ++i) {
+    const auto &check = checks[i];
+    totals[check.status]++;
+    std::string line = "    { \"name\": " + logger::json_quote(check.name)
+                     + ", \"status\": \"" + check_status_name(check.status)
+                     + "\", \"detail\": " + logger::json_quote(check.detail)
+                     + ", \"hint\": " + logger::json_quote(check.hint) + " }";
+    if (i + 1 < checks.size()) {
+      line += ",";
+    }
+    logger::print_plain(line);
+  }
+  logger::print_plain("  ],");
+  logger::print_plain("  \"passed\": " + std::to_string(totals[check_status::pass]) + ",");
+  logger::print_plain("  \"warnings\": " + std::to_string(totals[check_status::warn]) + ",");
+  logger::print_plain("  \"failures\": " + std::to_string(totals[check_status::fail]));
+  logger::print_plain("}");
 }
 
 }  // namespace cforge
 
 cforge_int_t cforge_cmd_doctor(const cforge_context_t *ctx) {
-  bool verbose = false;
+  bool verbose     = false;
+  bool json_output = false;
 
   // Parse arguments
   for (cforge_int_t i = 0; i < ctx->args.arg_count; ++i) {
     std::string arg = ctx->args.args[i];
     if (arg == "-v" || arg == "--verbose") {
       verbose = true;
+    } else if (arg == "--json") {
+      json_output = true;
     } else if (arg == "-h" || arg == "--help") {
       cforge::command_registry::instance().print_command_help("doctor");
       return 0;
     }
   }
 
-  cforge::logger::print_blank();
-  cforge::logger::print_section("Environment Check");
-  cforge::logger::print_blank();
+  std::filesystem::path project_dir = ctx->working_dir;
+  bool in_project = std::filesystem::exists(project_dir / CFORGE_FILE)
+                 && !cforge::workspace::is_workspace_dir(project_dir);
+  cforge::toml_reader project_config;
+  bool has_project_config = in_project
+                         && project_config.load((project_dir / CFORGE_FILE).string());
 
-  cforge_int_t passed   = 0;
-  cforge_int_t warnings = 0;
+  std::vector<cforge::doctor_check> checks;
   // Tools the doctor flagged as missing; surfaced at the end with a single
   // interactive install offer per tool. Keeps `cforge doctor` actionable
   // instead of just descriptive.
   std::vector<std::string> missing_tools;
+  auto add_tool_check = [&](cforge::doctor_check check, const std::string &tool) {
+    if (check.status != cforge::check_status::pass) {
+      missing_tools.push_back(tool);
+    }
+    checks.push_back(check);
+  };
 
-  // Check CMake
-  bool cmake_ok         = cforge::is_command_available("cmake", 5);
-  std::string cmake_ver = cmake_ok ? cforge::get_tool_version("cmake", {"--version"}) : "";
-  cforge::print_check_result(
-      "CMake", cmake_ok, cmake_ver, "install from https://cmake.org/download/");
-  if (cmake_ok) {
-    passed++;
-  } else {
-    warnings++;
-    missing_tools.push_back("cmake");
-  }
-
-  // Check Ninja
-  bool ninja_ok         = cforge::is_command_available("ninja", 5);
-  std::string ninja_ver = ninja_ok ? cforge::get_tool_version("ninja", {"--version"}) : "";
-  cforge::print_check_result("Ninja",
-                             ninja_ok,
-                             ninja_ver,
-                             "install with 'choco install ninja' or 'apt install ninja-build'");
-  if (ninja_ok) {
-    passed++;
-  } else {
-    warnings++;
-    missing_tools.push_back("ninja");
+  add_tool_check(cforge::check_cmake(), "cmake");
+  checks.push_back(cforge::check_compiler());
+  checks.push_back(cforge::check_generator_tool(has_project_config ? &project_config : nullptr,
+                                                missing_tools));
+  add_tool_check(cforge::check_tool(
+                     "Git", "git", false, "install from https://git-scm.com/downloads"),
+                 "git");
+
+  // Check ccache/sccache
+  cforge::doctor_check cache_check = cforge::check_tool(
+      "ccache", "ccache", false, "install with 'choco install ccache' or 'apt install ccache'");
+  if (cache_check.status != cforge::check_status::pass
+      && cforge::is_command_available("sccache", 5)) {
+    cache_check = cforge::check_tool("sccache", "sccache", false, "");
   }
+  add_tool_check(cache_check, "ccache");
 
-  // Check for C++ compiler
-  bool compiler_ok = false;
-  std::string compiler_name;
-  std::string compiler_ver;
+  add_tool_check(cforge::check_tool("clang-format",
+                                    "clang-format",
+                                    false,
+                                    "install LLVM or use 'cforge install clang-format'"),
+                 "clang-format");
+  add_tool_check(cforge::check_tool("clang-tidy",
+                                    "clang-tidy",
+                                    false,
+                                    "install LLVM or use 'cforge install clang-tidy'"),
+                 "clang-tidy");
 
-#ifdef _WIN32
-  // Check for MSVC or MinGW
-  if (cforge::is_command_available("cl", 5)) {
-    compiler_ok   = true;
-    compiler_name = "MSVC";
-  } else if (cforge::is_command_available("g++", 5)) {
-    compiler_ok   = true;
-    compiler_name = "g++ (MinGW)";
-    compiler_ver  = cforge::get_tool_version("g++", {"--version"});
-  } else if (cforge::is_command_available("clang++", 5)) {
-    compiler_ok   = true;
-    compiler_name = "clang++";
-    compiler_ver  = cforge::get_tool_version("clang++", {"--version"});
-  }
-#else
-  if (cforge::is_command_available("g++", 5)) {
-    compiler_ok   = true;
-    compiler_name = "g++";
-    compiler_ver  = cforge::get_tool_version("g++", {"--version"});
-  } else if (cforge::is_command_available("clang++", 5)) {
-    compiler_ok   = true;
-    compiler_name = "clang++";
-    compiler_ver  = cforge::get_tool_version("clang++", {"--version"});
-  }
-#endif
+  checks.push_back(cforge::check_disk_space(
+      has_project_config ? cforge::get_build_base_dir(project_dir, &project_config)
+                         : project_dir));
+  checks.push_back(cforge::check_path_conflicts());
 
-  cforge::print_check_result(compiler_ok ? compiler_name : "C++ Compiler",
-                             compiler_ok,
-                             compiler_ver,
-                             "install a C++ compiler (g++, clang++, or MSVC)");
-  if (compiler_ok) {
-    passed++;
-  } else {
-    warnings++;
+  cforge_size_t environment_checks = checks.size();
+  if (in_project) {
+    cforge::check_project(project_dir, checks);
   }
 
-  // Check ccache/sccache
-  bool cache_ok = cforge::is_command_available("ccache", 5)
-               || cforge::is_command_available("sccache", 5);
-  std::string cache_name = cforge::is_command_available("ccache", 5) ? "ccache" : "sccache";
-  std::string cache_ver  = cache_ok ? cforge::get_tool_version(cache_name, {"--version"}) : "";
-  cforge::print_check_result(cache_ok ? cache_name : "ccache/sccache",
-                             cache_ok,
-                             cache_ver,
-                             "install with 'choco install ccache' or 'apt install ccache'");
-  if (cache_ok) {
-    passed++;
-  } else {
-    warnings++;
-    missing_tools.push_back("ccache");
+  cforge_size_t passed = 0, warnings = 0, failures = 0;
+  for (const auto &check : checks) {
+    passed += check.status == cforge::check_status::pass ? 1 : 0;
+    warnings += check.status == cforge::check_status::warn ? 1 : 0;
+    failures += check.status == cforge::check_status::fail ? 1 : 0;
   }
 
-  // Check vcpkg
-  bool vcpkg_ok = false;
-  std::string vcpkg_path;
-  cforge_cstring_t vcpkg_root = std::getenv("VCPKG_ROOT");
-  if (vcpkg_root) {
-    std::filesystem::path vcpkg_exe = std::filesystem::path(vcpkg_root) / "vcpkg";
-#ifdef _WIN32
-    vcpkg_exe += ".exe";
-#endif
-    if (std::filesystem::exists(vcpkg_exe)) {
-      vcpkg_ok   = true;
-      vcpkg_path = vcpkg_root;
-    }
-  }
-  cforge::print_check_result(
-      "vcpkg", vcpkg_ok, vcpkg_ok ? vcpkg_path : "", "set VCPKG_ROOT environment variable");
-  if (vcpkg_ok) {
-    passed++;
-  } else {
-    warnings++;
+  if (json_output) {
+    cforge::print_checks_json(checks);
+    return failures > 0 ? 1 : 0;
   }
 
-  // Check Git
-  bool git_ok         = cforge::is_command_available("git", 5);
-  std::string git_ver = git_ok ? cforge::get_tool_version("git", {"--version"}) : "";
-  cforge::print_check_result("Git", git_ok, git_ver, "install from https://git-scm.com/downloads");
-  if (git_ok) {
-    passed++;
-  } else {
-    warnings++;
-    missing_tools.push_back("git");
-  }
-
-  // Check clang-format
-  bool clang_format_ok = cforge::is_command_available("clang-format", 5);
-  std::string clang_format_ver =
-      clang_format_ok ? cforge::get_tool_version("clang-format", {"--version"}) : "";
-  cforge::print_check_result("clang-format",
-                             clang_format_ok,
-                             clang_format_ver,
-                             "install LLVM or use 'cforge install clang-format'");
-  if (clang_format_ok) {
-    passed++;
-  } else {
-    warnings++;
-    missing_tools.push_back("clang-format");
-  }
-
-  // Check clang-tidy
-  bool clang_tidy_ok         = cforge::is_command_available("clang-tidy", 5);
-  std::string clang_tidy_ver = clang_tidy_ok ? cforge::get_tool_version("clang-tidy", {"--version"})
-                                             : "";
-  cforge::print_check_result("clang-tidy",
-                             clang_tidy_ok,
-                             clang_tidy_ver,
-                             "install LLVM or use 'cforge install clang-tidy'");
-  if (clang_tidy_ok) {
-    passed++;
-  } else {
-    warnings++;
-    missing_tools.push_back("clang-tidy");
+  cforge::logger::print_blank();
+  cforge::logger::print_section("Environment Check");
+  cforge::logger::print_blank();
+  for (cforge_size_t i = 0; i < checks.size(); ++i) {
+    if (i == environment_checks) {
+      cforge::logger::print_blank();
+      cforge::logger::print_section("Project Check");
+      cforge::logger::print_blank();
+    }
+    cforge::print_check(checks[i]);
   }
 
   // Summary
   cforge::logger::print_blank();
-  if (warnings == 0) {
+  if (warnings == 0 && failures == 0) {
     cforge::logger::print_action("Summary", std::to_string(passed) + " checks passed");
   } else {
-    cforge::logger::print_action(
-        "Summary", std::to_string(passed) + " passed, " + std::to_string(warnings) + " warnings");
+    cforge::logger::print_action("Summary",
+                                 std::to_string(passed) + " passed, " + std::to_string(warnings)
+                                     + " warnings, " + std::to_string(failures) + " failed");
 
     // For each missing tool, offer to install it via the platform's package
     // manager. The prompt is skipped automatically in non-interactive sessions
@@ -256,5 +662,5 @@ cforge_int_t cforge_cmd_doctor(const cforge_context_t *ctx) {
     cforge::logger::print_dim("Detailed tool information available with verbose output.");
   }
 
-  return 0;
+  return failures > 0 ? 1 : 0;
 }
@@ -0,0 +1,175 @@
+/**
+ * @file command_export_presets.cpp
+ * @brief Implementation of the 'export-presets' command to write CMakePresets.json
+ */
+
+#include "cforge/log.hpp"
+
+#include "core/build_config.hpp"
+#include "core/build_utils.hpp"
+#include "core/cmake_presets.hpp"
+#include "core/command_registry.hpp"
+#include "core/commands.hpp"
+#include "core/constants.h"
+#include "core/toml_reader.hpp"
+#include "core/types.h"
+
+#include <filesystem>
+#include <fstream>
+#include <sstream>
+#include <string>
+#include <vector>
+
+/**
+ * @brief Path of a build directory as written in a preset
+ *
+ * Directories inside the project are written relative to `${sourceDir}` so
+ * the file can be committed.
+ */
+static std::string preset_binary_dir(const std::filesystem::path &project_dir,
+                                     const std::filesystem::path &build_dir) {
+  std::error_code ec;
+  std::filesystem::path relative = std::filesystem::relative(build_dir, project_dir, ec);
+  if (ec || relative.empty() || *relative.begin() == "..") {
+    return build_dir.generic_string();
+  }
+  return "${sourceDir}/" + relative.generic_string();
+}
+
+/**
+ * @brief The configure arguments `cforge build` derives from cforge.toml
+ *
+ * Everything that depends only on cforge.toml. Settings of a particular
+ * invocation or machine (--profile, --arch, --define, compiler cache
+ * detection) are not part of a preset.
+ */
+static std::vector<std::string> preset_cmake_args(const cforge::toml_reader &project_config,
+                                                  const std::filesystem::path &project_dir,
+                                                  const std::string &generator,
+                                                  const std::string &config) {
+  std::vector<std::string> args = cforge::get_config_cmake_args(project_config, config);
+  auto compiler_args            = cforge::get_compiler_cmake_args(project_config);
+  args.insert(args.end(), compiler_args.begin(), compiler_args.end());
+  auto vcpkg_args = cforge::get_vcpkg_cmake_args(project_config, project_dir);
+  args.insert(args.end(), vcpkg_args.begin(), vcpkg_args.end());
+
+  std::string toolset = project_config.get_string("cmake.toolset", "");
+  if (generator.find("Ninja") != std::string::npos && !toolset.empty()) {
+    args.push_back("-DCMAKE_C_COMPILER=" + toolset);
+    args.push_back("-DCMAKE_CXX_COMPILER=" + toolset);
+  }
+
+  args.push_back("-G");
+  args.push_back(generator);
+  if (!cforge::is_multi_config_generator(generator)) {
+    args.push_back("-DCMAKE_BUILD_TYPE=" + cforge::canonical_build_config(config));
+  }
+  if (generator.rfind("Visual Studio", 0) == 0) {
+    args.push_back("-A");
+    args.push_back(project_config.get_string("cmake.platform", "x64"));
+    if (!toolset.empty()) {
+      args.push_back("-T");
+      args.push_back(toolset);
+    }
+  }
+  return args;
+}
+
+/**
+ * @brief Handle the 'export-presets' command
+ *
+ * @param ctx Context containing parsed arguments
+ * @return cforge_int_t Exit code (0 for success)
+ */
+cforge_int_t cforge_cmd_export_presets(const cforge_context_t *ctx) {
+  std::string output_file = "CMakePresets.json";
+  bool force              = false;
+
+  for (cforge_int_t i = 0; i < ctx->args.arg_count; ++i) {
+    std::string arg = ctx->args.args[i];
+    if (arg == "-h" || arg == "--help") {
+      cforge::command_registry::instance().print_command_help("export-presets");
+      return 0;
+    } else if ((arg == "--output" || arg == "-o") && i + 1 < ctx->args.arg_count) {
+      output_file = ctx->args.args[++i];
+    } else if (arg == "--force" || arg == "-f") {
+      force = true;
+    }
+  }
+
+  std::filesystem::path project_dir(ctx->working_dir);
+  std::filesystem::path toml_path = project_dir / CFORGE_FILE;
+  cforge::toml_reader project_config;
+  if (!std::filesystem::exists(toml_path) || !project_config.load(toml_path.string())) {
+    cforge::logger::print_error("No cforge.toml found in " + project_dir.string());
+    return 1;
+  }
+  if (!project_config.has_key("project.name")) {
+    cforge::logger::print_error("export-presets needs a project; run it in a project directory");
+    return 1;
+  }
+
+  std::string generator = project_config.get_string("cmake.generator", "");
+  if (generator.empty()) {
+    generator = cforge::get_cmake_generator();
+  }
+
+  // One preset per [build.config.<name>], or the two standard configurations
+  std::vector<std::string> configs = project_config.get_table_keys("build.config");
+  if (configs.empty()) {
+    configs = {"debug", "release"};
+  }
+
+  std::string binary_dir =
+      preset_binary_dir(project_dir,
+                        cforge::get_build_dir_for_config(
+                            cforge::get_build_base_dir(project_dir, &project_config).string(),
+                            "",
+                            false));
+
+  std::vector<cforge::cmake_configure_preset> presets;
+  for (const auto &config : configs) {
+    std::string cmake_config = cforge::canonical_build_config(config);
+    auto args                = preset_cmake_args(project_config, project_dir, generator, config);
+
+    cforge::cmake_configure_preset preset =
+        cforge::cmake_preset_from_args(cforge::cmake_preset_name(config), cmake_config, args);
+    preset.display_name = cmake_config;
+    preset.binary_dir   = binary_dir;
+    presets.push_back(preset);
+  }
+  std::string contents = cforge::generate_cmake_presets_json(presets);
+
+  std::filesystem::path output_path(output_file);
+  if (output_path.is_relative()) {
+    output_path = project_dir / output_path;
+  }
+  if (std::filesystem::exists(output_path)) {
+    std::ifstream existing_file(output_path, std::ios::binary);
+    std::stringstream existing;
+    existing << existing_file.rdbuf();
+    if (existing.str() == contents) {
+      cforge::logger::print_action("Unchanged", output_path.string());
+      return 0;
+    }
+    if (!force && !cforge::is_cforge_generated_presets(existing.str())) {
+      cforge::logger::print_error(output_path.filename().string()
+                                  + " exists and was not generated by cforge");
+      cforge::logger::print_hint("use --force to overwrite it, or --output to write elsewhere");
+      return 1;
+    }
+  }
+
+  std::ofstream out(output_path, std::ios::binary);
+  if (!out || !(out << contents)) {
+    cforge::logger::print_error("Failed to write " + output_path.string());
+    return 1;
+  }
+  cforge::logger::print_action("Generated", output_path.string());
+  for (const auto &preset : presets) {
+    cforge::logger::print_list_item(preset.name + "  (" + preset.configuration + ")");
+  }
+  cforge::logger::print_hint("configure with 'cmake --preset " + presets.front().name
+                             + "' or 'cforge build --use-presets'");
+  return 0;
+}
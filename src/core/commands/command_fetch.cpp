@@ -0,0 +1,482 @@
+/**
+ * @file command_fetch.cpp
+ * @brief Implementation of the 'fetch' command to download dependencies ahead of a build
+ */
+
+#include "cforge/log.hpp"
+
+#include "core/archive_format.hpp"
+#include "core/build_config.hpp"
+#include "core/build_utils.hpp"
+#include "core/command.h"
+#include "core/command_registry.hpp"
+#include "core/commands.hpp"
+#include "core/constants.h"
+#include "core/errors.hpp"
+#include "core/http_client.hpp"
+#include "core/process_utils.hpp"
+#include "core/registry.hpp"
+#include "core/toml_reader.hpp"
+#include "core/types.h"
+#include "core/workspace.hpp"
+
+#include <toml++/toml.hpp>
+
+#include <filesystem>
+#include <fstream>
+#include <string>
+#include <system_error>
+#include <vector>
+
+// Defined in command_build.cpp
+bool resolve_index_dependencies(const std::filesystem::path &project_dir,
+                                const cforge::toml_reader &project_config,
+                                bool verbose,
+                                bool skip_deps);
+bool clone_git_dependencies(const std::filesystem::path &project_dir,
+                            const cforge::toml_reader &project_config,
+                            bool verbose,
+                            bool skip_deps);
+
+/**
+ * @brief Clone FetchContent index dependencies into the build's _deps directory
+ *
+ * Uses the same repository and tag as the generated FetchContent_Declare, so
+ * a build with FETCHCONTENT_FULLY_DISCONNECTED finds the sources in place.
+ */
+static bool fetch_index_sources(const cforge::toml_reader &project_config,
+                                const std::filesystem::path &fetch_dir,
+                                cforge::registry &reg,
+                                bool verbose) {
+  bool all_success = true;
+  for (const auto &dep : cforge::get_index_dependencies_with_versions(project_config)) {
+    std::filesystem::path source = fetch_dir / (cforge::string_to_lower(dep.name) + "-src");
+    if (std::filesystem::exists(source)) {
+      cforge::logger::print_verbose("'" + dep.name + "' is already in " + source.string());
+      continue;
+    }
+
+    auto pkg = reg.get_package(dep.name);
+    if (!pkg || pkg->repository.empty()) {
+      cforge::logger::print_error("Package '" + dep.name + "' not found in registry");
+      all_success = false;
+      continue;
+    }
+
+    std::string version = dep.version;
+    if (version == "*" || version.empty()) {
+      if (pkg->versions.empty()) {
+        cforge::logger::print_error("Package '" + dep.name + "' has no versions in registry");
+        all_success = false;
+        continue;
+      }
+      version = pkg->versions.front().version;
+    }
+
+    cforge::logger::fetching(dep.name + "@" + version);
+    std::vector<std::string> clone_args = {"clone",
+                                           "--depth=1",
+                                           "--branch",
+                                           cforge::fetch_content_git_tag(*pkg, version),
+                                           pkg->repository,
+                                           source.string()};
+    if (!verbose) {
+      clone_args.push_back("--quiet");
+    }
+    if (!cforge::execute_tool("git", clone_args, "", "Git Clone for " + dep.name, verbose, 600)) {
+      std::error_code ec;
+      std::filesystem::remove_all(source, ec);
+      cforge::logger::print_error("Failed to clone package '" + dep.name + "' from "
+                                  + pkg->repository);
+      all_success = false;
+    }
+  }
+  return all_success;
+}
+
+/**
+ * @brief Compute a file's SHA-256 with `cmake -E sha256sum`
+ */
+static std::string file_sha256(const std::filesystem::path &file) {
+  auto result = cforge::execute_process(
+      "cmake", {"-E", "sha256sum", file.string()}, "", nullptr, nullptr, 60);
+  if (!result.success) {
+    return "";
+  }
+  return cforge::string_to_lower(result.stdout_output.substr(0, 64));
+}
+
+/**
+ * @brief Download and extract an archive into the build's _deps directory
+ *
+ * Mirrors what FetchContent does with a URL: the hash is checked before
+ * extracting, and an archive with a single top-level directory has that
+ * directory's contents become the source directory. The format is taken from
+ * the file's content, so URLs without an extension work. Extraction happens
+ * in a scratch directory that is only renamed to `<name>-src` once complete,
+ * so an interrupted fetch is simply downloaded again.
+ *
+ * @param dep Dependency name
+ * @param url Archive URL
+ * @param expected Expected SHA-256 in lower case, empty to skip the check
+ * @param refresh Download again even if the source directory exists
+ */
+static bool fetch_archive_source(const std::string &dep,
+                                 const std::string &url,
+                                 const std::string &expected,
+                                 const std::filesystem::path &fetch_dir,
+                                 bool refresh,
+                                 bool verbose) {
+  std::string lower            = cforge::string_to_lower(dep);
+  std::filesystem::path source = fetch_dir / (lower + "-src");
+  if (!refresh && std::filesystem::exists(source)) {
+    return true;
+  }
+
+  std::filesystem::path work    = fetch_dir / (lower + "-fetch");
+  std::filesystem::path archive = work / "archive";
+  std::filesystem::path extract = work / "extract";
+  std::error_code ec;
+  std::filesystem::remove_all(work, ec);
+  if (refresh) {
+    std::filesystem::remove_all(source, ec);
+  }
+  std::filesystem::create_directories(extract, ec);
+
+  cforge::logger::fetching(dep + " from " + url);
+  cforge::http_client client;
+  cforge::http_request_options options;
+  options.timeout_seconds = 600;
+  if (!client.download_file(url, archive, options)) {
+    cforge::logger::print_error("Failed to download dependency '" + dep
+                                + "': " + client.last_error());
+    std::filesystem::remove_all(work, ec);
+    return false;
+  }
+
+  if (!expected.empty()) {
+    std::string actual = file_sha256(archive);
+    if (actual != expected) {
+      cforge::cforge_error::make(cforge::error_code::DEP_INTEGRITY_MISMATCH,
+                                 "dependency '" + dep + "' does not match its sha256",
+                                 "expected " + expected + ", found "
+                                     + (actual.empty() ? "no hash" : actual))
+          .print();
+      std::filesystem::remove_all(work, ec);
+      return false;
+    }
+  }
+
+  std::string header(262, '\0');
+  {
+    std::ifstream in(archive, std::ios::binary);
+    in.read(&header[0], static_cast<std::streamsize>(header.size()));
+    header.resize(static_cast<cforge_size_t>(in.gcount()));
+  }
+  std::string format = cforge::detect_archive_format_from_header(header);
+  if (format.empty()) {
+    cforge::logger::print_error("Dependency '" + dep
+                                + "': the download is not a zip, 7z or tar archive");
+    cforge::logger::print_hint("check that " + url + " points at the file itself, "
+                               "not a download page");
+    std::filesystem::remove_all(work, ec);
+    return false;
+  }
+  cforge::logger::print_verbose("Dependency '" + dep + "' is a " + format + " archive");
+
+  if (!cforge::execute_tool("cmake",
+                            {"-E", "tar", "xf", archive.string()},
+                            extract.string(),
+                            "Extract " + dep,
+                            verbose,
+                            600)) {
+    cforge::logger::print_error("Failed to extract dependency '" + dep + "'");
+    std::filesystem::remove_all(work, ec);
+    return false;
+  }
+
+  std::vector<std::filesystem::directory_entry> entries(
+      std::filesystem::directory_iterator(extract), std::filesystem::directory_iterator{});
+  std::filesystem::path root = extract;
+  if (entries.size() == 1 && entries[0].is_directory()) {
+    root = entries[0].path();
+  }
+  std::filesystem::rename(root, source, ec);
+  if (ec) {
+    cforge::logger::print_error("Failed to move '" + dep + "' to " + source.string() + ": "
+                                + ec.message());
+  }
+  std::error_code cleanup;
+  std::filesystem::remove_all(work, cleanup);
+  return !ec;
+}
+
+/**
+ * @brief Download and extract archive dependencies into the build's _deps directory
+ *
+ * @param refresh Download again even if the source directory exists
+ */
+static bool fetch_archive_sources(const cforge::toml_reader &project_config,
+                                  const std::filesystem::path &fetch_dir,
+                                  bool refresh,
+                                  bool verbose) {
+  bool all_success = true;
+  for (const auto &dep : project_config.get_table_keys("dependencies.archive")) {
+    std::string dep_key = "dependencies.archive." + dep;
+    std::string url     = project_config.get_string(dep_key + ".url", "");
+    if (url.empty()) {
+      continue;
+    }
+    std::string sha256 = cforge::string_to_lower(
+        project_config.get_string(dep_key + ".sha256", ""));
+    all_success = fetch_archive_source(dep, url, sha256, fetch_dir, refresh, verbose)
+                  && all_success;
+  }
+  return all_success;
+}
+
+/**
+ * @brief Download [dependencies.fetchcontent] entries into the build's _deps directory
+ *
+ * Git entries are cloned at their tag, as FetchContent_Declare would; a
+ * commit SHA can't be cloned by name, so those get a full clone and a
+ * checkout. URL entries are downloaded like archive dependencies.
+ *
+ * @param refresh Download again even if the source directory exists
+ */
+static bool fetch_fetchcontent_sources(const cforge::toml_reader &project_config,
+                                       const std::filesystem::path &fetch_dir,
+                                       bool refresh,
+                                       bool verbose) {
+  bool all_success = true;
+  for (const auto &dep : cforge::get_fetchcontent_dependencies(project_config)) {
+    if (dep.git.empty()) {
+      all_success = fetch_archive_source(dep.name, dep.url, dep.sha256, fetch_dir, refresh, verbose)
+                    && all_success;
+      continue;
+    }
+
+    std::filesystem::path source = fetch_dir / (cforge::string_to_lower(dep.name) + "-src");
+    std::error_code ec;
+    if (refresh) {
+      std::filesystem::remove_all(source, ec);
+    } else if (std::filesystem::exists(source)) {
+      cforge::logger::print_verbose("'" + dep.name + "' is already in " + source.string());
+      continue;
+    }
+
+    bool commit = cforge::is_git_commit_sha(dep.tag);
+    cforge::logger::fetching(dep.name + (dep.tag.empty() ? "" : "@" + dep.tag));
+    std::vector<std::string> clone_args = {"clone"};
+    if (!commit) {
+      clone_args.push_back("--depth=1");
+      if (!dep.tag.empty()) {
+        clone_args.push_back("--branch");
+        clone_args.push_back(dep.tag);
+      }
+    }
+    clone_args.push_back(dep.git);
+    clone_args.push_back(source.string());
+    if (!verbose) {
+      clone_args.push_back("--quiet");
+    }
+    bool cloned = cforge::execute_tool(
+        "git", clone_args, "", "Git Clone for " + dep.name, verbose, 600);
+    if (cloned && commit) {
+      cloned = cforge::execute_tool("git",
+                                    {"checkout", "--quiet", dep.tag},
+                                    source.string(),
+                                    "Git Checkout for " + dep.name,
+                                    verbose,
+                                    120);
+    }
+    if (!cloned) {
+      std::filesystem::remove_all(source, ec);
+      cforge::logger::print_error("Failed to clone dependency '" + dep.name + "' from " + dep.git);
+      all_success = false;
+    }
+  }
+  return all_success;
+}
+
+/**
+ * @brief Install a project's vcpkg ports that aren't installed yet
+ */
+static bool fetch_vcpkg_ports(const std::filesystem::path &project_dir,
+                              const cforge::toml_reader &project_config,
+                              bool verbose) {
+  auto ports = cforge::get_vcpkg_packages(project_dir, project_config);
+  if (ports.empty()) {
+    return true;
+  }
+  if (cforge::is_vcpkg_manifest_mode(project_config)) {
+    cforge::logger::print_verbose("vcpkg ports are installed from vcpkg.json when the project is "
+                                  "configured");
+    return cforge::write_vcpkg_manifest(project_dir, project_config);
+  }
+
+  // Ports are installed for every triplet a configuration builds with, so an
+  // offline build of any configuration finds them
+  std::string root                  = cforge::get_vcpkg_root(project_config, project_dir);
+  std::vector<std::string> triplets = cforge::get_vcpkg_triplets(project_config);
+  for (const auto &triplet : triplets) {
+    if (!cforge::is_valid_vcpkg_triplet(triplet)) {
+      cforge::logger::print_error("invalid vcpkg triplet '" + triplet + "'");
+      cforge::logger::print_hint("triplets are lower-case words separated by dashes, "
+                                 "e.g. x64-windows-static");
+      return false;
+    }
+  }
+  if (triplets.empty()) {
+    triplets.push_back("");
+  }
+#ifdef _WIN32
+  std::filesystem::path vcpkg_exe = std::filesystem::path(root) / "vcpkg.exe";
+#else
+  std::filesystem::path vcpkg_exe = std::filesystem::path(root) / "vcpkg";
+#endif
+  if (!std::filesystem::exists(vcpkg_exe)) {
+    cforge::logger::print_error("vcpkg not found at " + vcpkg_exe.string());
+    cforge::logger::print_hint("run 'cforge vcpkg' to set up vcpkg, or set VCPKG_ROOT");
+    return false;
+  }
+
+  cforge::apply_vcpkg_binary_cache(project_config, project_dir);
+  bool all_success = true;
+  for (const auto &triplet : triplets) {
+    for (const auto &port : ports) {
+      std::string spec = triplet.empty() ? port : port + ":" + triplet;
+      if (cforge::is_vcpkg_port_installed(root, port, triplet)) {
+        cforge::logger::print_verbose("vcpkg port '" + spec + "' is already installed");
+        continue;
+      }
+      cforge::logger::installing(spec);
+      if (!cforge::execute_tool(vcpkg_exe.string(),
+                                {"install", spec},
+                                root,
+                                "vcpkg install " + spec,
+                                verbose,
+                                3600)) {
+        cforge::logger::print_error("Failed to install vcpkg port '" + spec + "'");
+        all_success = false;
+      }
+    }
+  }
+  return all_success;
+}
+
+/**
+ * @brief Download everything one project needs to build
+ *
+ * @param project_dir Project directory
+ * @param fetch_dir FetchContent download directory (`<build>/_deps`)
+ * @param reg Package registry
+ * @param refresh Download archive and FetchContent dependencies again
+ * @param verbose Verbose output flag
+ * @return bool Success flag
+ */
+static bool fetch_project(const std::filesystem::path &project_dir,
+                          const std::filesystem::path &fetch_dir,
+                          cforge::registry &reg,
+                          bool refresh,
+                          bool verbose) {
+  cforge::toml_reader project_config;
+  if (!project_config.load((project_dir / CFORGE_FILE).string())) {
+    cforge::logger::print_error("Failed to load " + (project_dir / CFORGE_FILE).string());
+    return false;
+  }
+
+  bool ok = true;
+  if (project_config.get_bool("dependencies.fetch_content", true)) {
+    ok = fetch_index_sources(project_config, fetch_dir, reg, verbose) && ok;
+  } else {
+    ok = resolve_index_dependencies(project_dir, project_config, verbose, false) && ok;
+  }
+  if (project_config.has_key("dependencies.git")) {
+    ok = clone_git_dependencies(project_dir, project_config, verbose, false) && ok;
+  }
+  ok = fetch_archive_sources(project_config, fetch_dir, refresh, verbose) && ok;
+  ok = fetch_fetchcontent_sources(project_config, fetch_dir, refresh, verbose) && ok;
+  ok = fetch_vcpkg_ports(project_dir, project_config, verbose) && ok;
+  std::string build_type = cforge::canonical_build_config(
+      cforge::get_build_config(nullptr, 0, nullptr, &project_config));
+  ok = cforge::install_conan_packages(project_dir, build_type, verbose) && ok;
+  return ok;
+}
+
+/**
+ * @brief Handle the 'fetch' command
+ *
+ * @param ctx Context containing parsed arguments
+ * @return cforge_int_t Exit code (0 for success)
+ */
+cforge_int_t cforge_cmd_fetch(const cforge_context_t *ctx) {
+  bool verbose = cforge::logger::get_verbosity() == cforge::log_verbosity::VERBOSITY_VERBOSE;
+  bool refresh = false;
+  for (cforge_int_t i = 0; i < ctx->args.arg_count; ++i) {
+    std::string arg = ctx->args.args[i];
+    if (arg == "-h" || arg == "--help") {
+      cforge::command_registry::instance().print_command_help("fetch");
+      return 0;
+    } else if (arg == "-v" || arg == "--verbose") {
+      verbose = true;
+    } else if (arg == "--refresh") {
+      refresh = true;
+    }
+  }
+
+  if (cforge_is_offline()) {
+    cforge::logger::print_error("'cforge fetch' downloads dependencies and can't run offline");
+    cforge::logger::print_hint("run it without --offline or CFORGE_OFFLINE");
+    return 1;
+  }
+
+  std::vector<std::filesystem::path> project_dirs;
+  std::filesystem::path fetch_dir;
+  auto [is_workspace, workspace_dir] = cforge::is_in_workspace(ctx->working_dir);
+  if (is_workspace) {
+    cforge::workspace ws;
+    if (!ws.load(workspace_dir)) {
+      cforge::logger::print_error("Failed to load workspace configuration");
+      return 1;
+    }
+    for (const auto &project : ws.get_projects()) {
+      if (std::filesystem::exists(project.path / CFORGE_FILE)) {
+        project_dirs.push_back(project.path);
+      }
+    }
+    fetch_dir = cforge::get_build_base_dir(workspace_dir, nullptr) / "_deps";
+  } else {
+    std::filesystem::path project_dir = ctx->working_dir;
+    if (!std::filesystem::exists(project_dir / CFORGE_FILE)) {
+      cforge::logger::print_error("No " + std::string(CFORGE_FILE) + " found in "
+                                  + project_dir.string());
+      return 1;
+    }
+    cforge::toml_reader project_config;
+    project_config.load((project_dir / CFORGE_FILE).string());
+    project_dirs.push_back(project_dir);
+    fetch_dir = cforge::get_build_base_dir(project_dir, &project_config) / "_deps";
+  }
+
+  cforge::registry reg;
+  if (reg.needs_update()) {
+    cforge::logger::print_action("Updating", "package index");
+    if (!reg.update()) {
+      cforge::logger::print_warning("Failed to update package index, using cached version");
+    }
+  }
+
+  std::filesystem::create_directories(fetch_dir);
+  bool ok = true;
+  for (const auto &project_dir : project_dirs) {
+    ok = fetch_project(project_dir, fetch_dir, reg, refresh, verbose) && ok;
+  }
+
+  if (!ok) {
+    cforge::logger::print_error("Some dependencies could not be fetched");
+    return 1;
+  }
+  cforge::logger::print_action("Finished", "all dependencies fetched; build with --offline");
+  return 0;
+}
@@ -0,0 +1,275 @@
+/**
+ * @file command_foreach.cpp
+ * @brief Implementation of the 'foreach' command to run a command in every
+ *        workspace project
+ */
+
+#include "cforge/log.hpp"
+
+#include "core/build_log.hpp"
+#include "core/command_registry.hpp"
+#include "core/commands.hpp"
+#include "core/process_utils.hpp"
+#include "core/types.h"
+#include "core/workspace.hpp"
+#include "core/workspace_selection.hpp"
+
+#include <algorithm>
+#include <atomic>
+#include <cstdio>
+#include <filesystem>
+#include <functional>
+#include <map>
+#include <mutex>
+#include <string>
+#include <thread>
+#include <vector>
+
+namespace {
+
+/**
+ * @brief Result of the command in one project
+ */
+struct foreach_result {
+  cforge_int_t exit_code = 0;
+  std::string output;  ///< Captured output, for parallel runs
+};
+
+/**
+ * @brief Run the command in one project directory
+ *
+ * A single argument is a shell command line (so it may use pipes and &&);
+ * several arguments are a program and its arguments.
+ *
+ * @param command Command after '--'
+ * @param dir Project directory
+ * @param stream Print the output as it arrives instead of capturing it
+ */
+foreach_result run_in_project(const std::vector<std::string> &command,
+                              const std::filesystem::path &dir,
+                              bool stream) {
+  std::string program;
+  std::vector<std::string> args;
+  if (command.size() == 1) {
+#ifdef _WIN32
+    program = "cmd";
+    args    = {"/c", command[0]};
+#else
+    program = "sh";
+    args    = {"-c", command[0]};
+#endif
+  } else {
+    program = command[0];
+    args.assign(command.begin() + 1, command.end());
+  }
+
+  foreach_result result;
+  std::function<void(const std::string &)> print = nullptr;
+  if (stream) {
+    print = [](const std::string &chunk) {
+      if (!chunk.empty()) {
+        std::fwrite(chunk.data(), 1, chunk.size(), stdout);
+        std::fflush(stdout);
+      }
+    };
+  }
+  cforge::process_result process =
+      cforge::execute_process(program, args, dir.string(), print, print, 0);
+  result.exit_code = process.exit_code;
+  if (!stream) {
+    result.output = cforge::build_log_text(process.stdout_output, process.stderr_output);
+  }
+  return result;
+}
+
+}  // namespace
+
+/**
+ * @brief Handle the 'foreach' command
+ *
+ * @param ctx Context containing parsed arguments
+ * @return cforge_int_t Exit code (0 for success)
+ */
+cforge_int_t cforge_cmd_foreach(const cforge_context_t *ctx) {
+  std::vector<std::string> command;
+  std::vector<std::string> filters;
+  bool parallel          = false;
+  bool continue_on_error = false;
+  cforge_int_t jobs      = 0;
+  bool after_separator   = false;
+
+  for (cforge_int_t i = 0; i < ctx->args.arg_count; ++i) {
+    std::string arg = ctx->args.args[i];
+    if (after_separator) {
+      command.push_back(arg);
+    } else if (arg == "--") {
+      after_separator = true;
+    } else if (arg == "-h" || arg == "--help") {
+      cforge::command_registry::instance().print_command_help("foreach");
+      return 0;
+    } else if (arg == "--parallel") {
+      parallel = true;
+    } else if (arg == "-j" || arg == "--jobs") {
+      if (i + 1 >= ctx->args.arg_count) {
+        cforge::logger::print_error(arg + " requires a number");
+        return 1;
+      }
+      try {
+        jobs = std::stoi(ctx->args.args[++i]);
+      } catch (...) {
+        cforge::logger::print_error(arg + " requires a number");
+        return 1;
+      }
+      parallel = true;
+    } else if (arg == "--continue-on-error" || arg == "-k") {
+      continue_on_error = true;
+    } else if (arg == "--filter" || arg == "-f") {
+      if (i + 1 >= ctx->args.arg_count) {
+        cforge::logger::print_error(arg + " requires a project name or pattern");
+        return 1;
+      }
+      auto names = cforge::split_project_list(ctx->args.args[++i]);
+      filters.insert(filters.end(), names.begin(), names.end());
+    } else if (arg.rfind("--filter=", 0) == 0) {
+      auto names = cforge::split_project_list(arg.substr(9));
+      filters.insert(filters.end(), names.begin(), names.end());
+    } else {
+      cforge::logger::print_error("Unknown option: " + arg);
+      cforge::logger::print_hint("put the command to run after '--', e.g. cforge foreach -- git "
+                                 "status");
+      return 1;
+    }
+  }
+
+  if (command.empty()) {
+    cforge::logger::print_error("No command given");
+    cforge::logger::print_hint("usage: cforge foreach [options] -- <command...>");
+    return 1;
+  }
+
+  auto [is_workspace, workspace_dir] = cforge::is_in_workspace(ctx->working_dir);
+  if (!is_workspace) {
+    cforge::logger::print_error("Not in a workspace");
+    cforge::logger::print_hint("'cforge foreach' runs a command in every workspace project");
+    return 1;
+  }
+
+  cforge::workspace ws;
+  if (!ws.load(workspace_dir)) {
+    cforge::logger::print_error("Failed to load workspace configuration");
+    return 1;
+  }
+
+  std::map<std::string, std::vector<std::string>> project_deps;
+  std::map<std::string, std::filesystem::path> project_dirs;
+  for (const auto &proj : ws.get_projects()) {
+    auto &deps = project_deps[proj.name];
+    deps       = proj.dependencies;
+    deps.insert(
+        deps.end(), proj.order_only_dependencies.begin(), proj.order_only_dependencies.end());
+    project_dirs[proj.name] = proj.path;
+  }
+
+  auto selection =
+      cforge::select_workspace_projects(ws.get_build_order(), project_deps, filters, {}, false);
+  for (const auto &pattern : selection.unmatched) {
+    cforge::logger::print_warning("No workspace project matches '" + pattern + "'");
+  }
+  if (selection.projects.empty()) {
+    cforge::logger::print_status("No projects to run in");
+    return selection.unmatched.empty() ? 0 : 1;
+  }
+
+  // Dependencies run before the projects that need them; a parallel run
+  // starts a wave once the previous one has finished
+  std::vector<std::vector<std::string>> waves;
+  if (parallel) {
+    waves = cforge::workspace_project_waves(selection.projects, project_deps);
+  } else {
+    for (const auto &name : selection.projects) {
+      waves.push_back({name});
+    }
+  }
+  if (jobs <= 0) {
+    jobs = static_cast<cforge_int_t>(std::max(1u, std::thread::hardware_concurrency()));
+  }
+
+  cforge::interrupt_guard interrupt_guard;
+  std::map<std::string, foreach_result> results;
+  std::mutex output_mutex;
+  bool failed = false;
+
+  for (const auto &wave : waves) {
+    if (failed && !continue_on_error) {
+      break;
+    }
+
+    if (!parallel) {
+      const std::string &name = wave.front();
+      cforge::logger::print_action("Running", name);
+      results[name] = run_in_project(command, project_dirs.at(name), true);
+    } else {
+      std::vector<foreach_result> wave_results(wave.size());
+      std::atomic<cforge_size_t> next{0};
+      auto worker = [&]() {
+        for (cforge_size_t i = next++; i < wave.size(); i = next++) {
+          wave_results[i] = run_in_project(command, project_dirs.at(wave[i]), false);
+          std::lock_guard<std::mutex> lock(output_mutex);
+          cforge::logger::print_action(wave_results[i].exit_code == 0 ? "Finished" : "Failed",
+                                       wave[i]);
+          if (!wave_results[i].output.empty()) {
+            std::fwrite(wave_results[i].output.data(), 1, wave_results[i].output.size(), stdout);
+            std::fflush(stdout);
+          }
+        }
+      };
+      cforge_size_t workers =
+          std::min(wave.size(), static_cast<cforge_size_t>(std::max<cforge_int_t>(1, jobs)));
+      std::vector<std::thread> threads;
+      for (cforge_size_t i = 0; i < workers; ++i) {
+        threads.emplace_back(worker);
+      }
+      for (auto &thread : threads) {
+        thread.join();
+      }
+      for (cforge_size_t i = 0; i < wave.size(); ++i) {
+        results[wave[i]] = wave_results[i];
+      }
+    }
+
+    for (const auto &name : wave) {
+      failed = failed || results[name].exit_code != 0;
+    }
+    if (cforge::was_interrupted()) {
+      break;
+    }
+  }
+
+  // Summary of every selected project, in dependency order
+  cforge::logger::print_blank();
+  cforge::logger::print_section("Summary:");
+  cforge_int_t failures = 0;
+  for (const auto &name : selection.projects) {
+    auto it = results.find(name);
+    if (it == results.end()) {
+      cforge::logger::print_kv_colored(name, "skipped", fmt::color::gray, 20);
+    } else if (it->second.exit_code == 0) {
+      cforge::logger::print_kv_colored(name, "exit 0", fmt::color::green, 20);
+    } else {
+      ++failures;
+      cforge::logger::print_kv_colored(
+          name, "exit " + std::to_string(it->second.exit_code), fmt::color::red, 20);
+    }
+  }
+
+  if (failures > 0) {
+    cforge::logger::print_error(std::to_string(failures) + " of "
+                                + std::to_string(selection.projects.size())
+                                + " projects failed");
+    if (!continue_on_error && results.size() < selection.projects.size()) {
+      cforge::logger::print_hint("use --continue-on-error to run in the remaining projects");
+    }
+    return 1;
+  }
+  return 0;
+}
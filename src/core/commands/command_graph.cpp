@@ -0,0 +1,226 @@
+/**
+ * @file command_graph.cpp
+ * @brief Implementation of the graph command for workspace project dependencies
+ */
+
+#include "cforge/log.hpp"
+
+#include "core/command_registry.hpp"
+#include "core/commands.hpp"
+#include "core/types.h"
+#include "core/workspace.hpp"
+
+#include <fmt/color.h>
+#include <fmt/core.h>
+
+#include <algorithm>
+#include <filesystem>
+#include <fstream>
+#include <set>
+#include <string>
+#include <vector>
+
+namespace {
+
+bool is_order_only(const cforge::workspace_project &project, const std::string &dep) {
+  return std::find(project.order_only_dependencies.begin(),
+                   project.order_only_dependencies.end(),
+                   dep)
+      != project.order_only_dependencies.end();
+}
+
+/**
+ * @brief Print one project and its workspace dependencies
+ *
+ * Projects already expanded elsewhere are marked with (*), and a dependency
+ * that is already on the current path is marked as a cycle instead of being
+ * followed again.
+ */
+void print_project_branch(const cforge::workspace &ws,
+                          const std::string &name,
+                          const std::string &annotation,
+                          const std::string &prefix,
+                          bool is_last,
+                          bool is_root,
+                          std::vector<std::string> &path,
+                          std::set<std::string> &expanded) {
+  std::string branch       = is_root ? prefix : prefix + (is_last ? "`-- " : "|-- ");
+  std::string child_prefix = is_root ? prefix : prefix + (is_last ? "    " : "|   ");
+
+  const cforge::workspace_project *project = ws.get_project_by_name(name);
+  if (!project) {
+    cforge::logger::print_plain(branch + fmt::format(
+        cforge::logger::style(fg(fmt::color::red)), "{}", name)
+                                + " (not a workspace member)");
+    return;
+  }
+
+  std::string label = fmt::format(
+      cforge::logger::style(fg(fmt::color::green) | fmt::emphasis::bold), "{}", name);
+  if (std::find(path.begin(), path.end(), name) != path.end()) {
+    cforge::logger::print_plain(branch + label + annotation
+                                + fmt::format(
+                                    cforge::logger::style(fg(fmt::color::red)), " (cycle)"));
+    return;
+  }
+
+  bool has_children =
+      !project->dependencies.empty() || !project->missing_dependencies.empty();
+  if (!expanded.insert(name).second && has_children) {
+    cforge::logger::print_plain(branch + label + annotation + " (*)");
+    return;
+  }
+  cforge::logger::print_plain(branch + label + annotation);
+
+  std::vector<std::string> children = project->dependencies;
+  children.insert(
+      children.end(), project->missing_dependencies.begin(), project->missing_dependencies.end());
+
+  path.push_back(name);
+  for (cforge_size_t i = 0; i < children.size(); ++i) {
+    std::string child_annotation;
+    if (is_order_only(*project, children[i])) {
+      child_annotation = fmt::format(
+          cforge::logger::style(fg(fmt::color::gray)), " [build order only]");
+    }
+    print_project_branch(ws,
+                         children[i],
+                         child_annotation,
+                         child_prefix,
+                         i + 1 == children.size(),
+                         false,
+                         path,
+                         expanded);
+  }
+  path.pop_back();
+}
+
+/**
+ * @brief Write the project graph in Graphviz DOT format
+ *
+ * Edges point from a project to its dependency. Linked dependencies are solid;
+ * build-order-only dependencies (link = false) are dashed.
+ */
+bool write_dot(const cforge::workspace &ws, const std::filesystem::path &out_path) {
+  std::ofstream out(out_path);
+  if (!out) {
+    return false;
+  }
+
+  auto projects = ws.get_projects();
+  out << "digraph \"" << ws.get_name() << "\" {\n";
+  out << "  rankdir=LR;\n";
+  out << "  node [shape=box];\n\n";
+
+  std::set<std::string> missing;
+  for (const auto &project : projects) {
+    out << "  \"" << project.name << "\";\n";
+    missing.insert(project.missing_dependencies.begin(), project.missing_dependencies.end());
+  }
+  for (const auto &name : missing) {
+    out << "  \"" << name << "\" [style=dashed, color=red, label=\"" << name
+        << "\\n(not a member)\"];\n";
+  }
+  out << "\n";
+
+  for (const auto &project : projects) {
+    for (const auto &dep : project.dependencies) {
+      if (is_order_only(project, dep)) {
+        out << "  \"" << project.name << "\" -> \"" << dep
+            << "\" [label=\"build order\", style=dashed];\n";
+      } else {
+        out << "  \"" << project.name << "\" -> \"" << dep << "\" [label=\"link\"];\n";
+      }
+    }
+    for (const auto &dep : project.missing_dependencies) {
+      out << "  \"" << project.name << "\" -> \"" << dep << "\" [color=red];\n";
+    }
+  }
+
+  out << "}\n";
+  return static_cast<bool>(out);
+}
+
+}  // anonymous namespace
+
+/**
+ * @brief Handle the 'graph' command for visualizing workspace project dependencies
+ */
+cforge_int_t cforge_cmd_graph(const cforge_context_t *ctx) {
+  std::string dot_path;
+
+  for (cforge_int_t i = 0; i < ctx->args.arg_count; ++i) {
+    std::string arg = ctx->args.args[i];
+    if (arg == "-h" || arg == "--help") {
+      cforge::command_registry::instance().print_command_help("graph");
+      return 0;
+    } else if (arg == "--dot") {
+      if (i + 1 >= ctx->args.arg_count) {
+        cforge::logger::print_error("--dot requires an output file");
+        return 1;
+      }
+      dot_path = ctx->args.args[++i];
+    } else if (arg.rfind("--dot=", 0) == 0) {
+      dot_path = arg.substr(6);
+    }
+  }
+
+  auto [is_workspace, workspace_dir] = cforge::is_in_workspace(ctx->working_dir);
+  if (!is_workspace) {
+    cforge::logger::print_error("Not in a workspace");
+    cforge::logger::print_hint("'cforge graph' shows dependencies between workspace projects; "
+                               "use 'cforge deps tree' for a single project");
+    return 1;
+  }
+
+  cforge::workspace ws;
+  if (!ws.load(workspace_dir)) {
+    cforge::logger::print_error("Failed to load workspace configuration");
+    return 1;
+  }
+
+  auto projects = ws.get_projects();
+
+  // Roots are the projects no other project depends on
+  std::set<std::string> depended_on;
+  for (const auto &project : projects) {
+    depended_on.insert(project.dependencies.begin(), project.dependencies.end());
+  }
+  std::vector<std::string> roots;
+  for (const auto &project : projects) {
+    if (!depended_on.count(project.name)) {
+      roots.push_back(project.name);
+    }
+  }
+
+  cforge::logger::print_emphasis(ws.get_name() + " (workspace)");
+  std::vector<std::string> path;
+  std::set<std::string> expanded;
+  for (const auto &root : roots) {
+    print_project_branch(ws, root, "", "  ", true, true, path, expanded);
+  }
+
+  // Projects that are only reachable through a cycle have no root
+  for (const auto &project : projects) {
+    if (!expanded.count(project.name)) {
+      print_project_branch(ws, project.name, "", "  ", true, true, path, expanded);
+    }
+  }
+
+  if (!dot_path.empty()) {
+    if (!write_dot(ws, dot_path)) {
+      cforge::logger::print_error("Failed to write " + dot_path);
+      return 1;
+    }
+    cforge::logger::print_blank();
+    cforge::logger::print_action("Wrote", dot_path);
+  }
+
+  auto graph = ws.check_dependency_graph();
+  if (graph.is_error()) {
+    cforge::logger::print_blank();
+    graph.error().print();
+    return 1;
+  }
+  return 0;
+}
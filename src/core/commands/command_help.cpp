@@ -32,7 +32,7 @@ cforge_int_t cforge_cmd_help(const cforge_context_t *ctx) {
   }
 
   // Handle deprecated commands that are now subcommands of deps
-  if (cmd == "add" || cmd == "remove" || cmd == "search" || cmd == "info" || cmd == "list") {
+  if (cmd == "add" || cmd == "search" || cmd == "info") {
     logger::print_warning("'" + cmd + "' is now a subcommand of 'deps'");
     logger::print_blank();
     logger::print_hint("Use 'cforge deps " + cmd + "' instead");
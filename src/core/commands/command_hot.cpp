@@ -437,8 +437,10 @@ cforge_int_t cforge_cmd_hot(const cforge_context_t *ctx) {
   }
 
   // Warn if binary_type != shared_lib (hot reload requires a shared library)
-  std::string binary_type = reader.get_string("project.binary_type");
-  if (!binary_type.empty() && binary_type != "shared_lib" && binary_type != "shared_library") {
+  std::string binary_type = reader.has_key("project.binary_type")
+                              ? cforge::get_binary_type(reader)
+                              : std::string();
+  if (!binary_type.empty() && binary_type != "shared_lib") {
     cforge::logger::print_warning("binary_type = \"" + binary_type
                                   + "\" — hot reload requires binary_type = \"shared_lib\"");
     cforge::logger::print_hint(
@@ -5,6 +5,8 @@
 
 #include "cforge/log.hpp"
 
+#include "core/build_utils.hpp"
+#include "core/cmake_file_api.hpp"
 #include "core/command_registry.hpp"
 #include "core/commands.hpp"
 #include "core/constants.h"
@@ -13,12 +15,14 @@
 #include "core/types.h"
 #include "core/workspace.hpp"
 
+#include <algorithm>
 #include <cstdint>
 #include <filesystem>
 #include <fstream>
 #include <iomanip>
 #include <map>
 #include <random>
+#include <regex>
 #include <set>
 #include <sstream>
 #include <string>
@@ -231,7 +235,7 @@ static bool write_vcxproj(const std::filesystem::path &proj_dir,
   cforge_platform = "linux";
 #endif
 
-  std::string binary_type = cfg.get_string("project.binary_type", "executable");
+  std::string binary_type = cforge::get_binary_type(cfg);
   std::string configurationType;
   if (binary_type == "executable") {
     configurationType = "Application";
@@ -760,6 +764,462 @@ static bool generate_vs_project_direct(const std::filesystem::path &project_dir,
   return true;
 }
 
+// ============================================================================
+// VS Code and CLion configuration
+// ============================================================================
+
+/**
+ * @brief An executable the generated launch configurations can start
+ */
+struct ide_launch_target {
+  std::string name;     ///< Target name
+  std::string program;  ///< Path to the binary, relative to ${workspaceFolder} when possible
+};
+
+/**
+ * @brief Build configurations to generate IDE entries for
+ *
+ * Debug and Release, plus anything declared under [build.config.<cfg>] or
+ * its [build.configs.<cfg>] alias, with CMake's spelling for known names.
+ */
+static std::vector<std::string> get_ide_build_configs(const cforge::toml_reader &cfg) {
+  static const std::map<std::string, std::string> canonical = {
+      {"debug",          "Debug"         },
+      {"release",        "Release"       },
+      {"relwithdebinfo", "RelWithDebInfo"},
+      {"minsizerel",     "MinSizeRel"    },
+  };
+
+  std::vector<std::string> configs = {"Debug", "Release"};
+  for (const std::string section : {"build.config", "build.configs"}) {
+    for (const auto &key : cfg.get_table_keys(section)) {
+      auto it          = canonical.find(cforge::string_to_lower(key));
+      std::string name = it != canonical.end() ? it->second : key;
+      if (std::find(configs.begin(), configs.end(), name) == configs.end()) {
+        configs.push_back(name);
+      }
+    }
+  }
+  return configs;
+}
+
+/**
+ * @brief Express a path relative to the project for VS Code's ${workspaceFolder}
+ */
+static std::string to_workspace_folder_path(const std::filesystem::path &project_dir,
+                                            const std::filesystem::path &path) {
+  std::filesystem::path rel = path.lexically_normal().lexically_relative(project_dir);
+  if (rel.empty() || *rel.begin() == "..") {
+    return path.generic_string();
+  }
+  return "${workspaceFolder}/" + rel.generic_string();
+}
+
+/**
+ * @brief Find the executables to generate launch configurations for
+ *
+ * Uses the artifact paths CMake reported for `config` when the build
+ * directory has a file API reply, and otherwise the project executable in
+ * cforge's bin/<config> output directory.
+ */
+static std::vector<ide_launch_target>
+get_ide_launch_targets(const std::filesystem::path &project_dir,
+                       const cforge::toml_reader &cfg,
+                       const std::filesystem::path &build_dir,
+                       const std::string &config) {
+  std::vector<ide_launch_target> targets;
+
+  if (auto reply = cforge::read_cmake_file_api_targets(build_dir, config)) {
+    for (const auto &target : *reply) {
+      if (target.type != "EXECUTABLE") {
+        continue;
+      }
+      for (const auto &artifact : target.artifacts) {
+        if (artifact.extension() != ".pdb") {
+          targets.push_back({target.name, to_workspace_folder_path(project_dir, artifact)});
+          break;
+        }
+      }
+    }
+    if (!targets.empty()) {
+      return targets;
+    }
+  }
+
+  if (cforge::get_binary_type(cfg) == "executable") {
+    std::string name = cfg.get_string("project.name", project_dir.filename().string());
+#ifdef _WIN32
+    std::string exe = name + ".exe";
+#else
+    std::string exe = name;
+#endif
+    targets.push_back(
+        {name, to_workspace_folder_path(project_dir, build_dir / "bin" / config / exe)});
+  }
+  return targets;
+}
+
+/**
+ * @brief Find the closing bracket of the JSON array stored under `key`
+ *
+ * Strings and // or block comments are skipped, since VS Code files are JSONC.
+ *
+ * @return Offset of the closing ']', or std::string::npos if not found
+ */
+static cforge_size_t find_json_array_end(const std::string &text, const std::string &key) {
+  cforge_size_t pos = text.find("\"" + key + "\"");
+  if (pos == std::string::npos) {
+    return std::string::npos;
+  }
+  pos = text.find('[', pos);
+  if (pos == std::string::npos) {
+    return std::string::npos;
+  }
+
+  cforge_int_t depth = 0;
+  for (cforge_size_t i = pos; i < text.size(); ++i) {
+    char c = text[i];
+    if (c == '"') {
+      for (++i; i < text.size() && text[i] != '"'; ++i) {
+        if (text[i] == '\\') {
+          ++i;
+        }
+      }
+    } else if (c == '/' && i + 1 < text.size() && text[i + 1] == '/') {
+      i = text.find('\n', i);
+      if (i == std::string::npos) {
+        return std::string::npos;
+      }
+    } else if (c == '/' && i + 1 < text.size() && text[i + 1] == '*') {
+      i = text.find("*/", i + 2);
+      if (i == std::string::npos) {
+        return std::string::npos;
+      }
+      ++i;
+    } else if (c == '[' || c == '{') {
+      ++depth;
+    } else if (c == ']' || c == '}') {
+      if (--depth == 0) {
+        return c == ']' ? i : std::string::npos;
+      }
+    }
+  }
+  return std::string::npos;
+}
+
+/**
+ * @brief Write a VS Code JSON file whose main content is an array of entries
+ *
+ * A new file (or any file with `force`) is written from scratch. Otherwise
+ * entries whose `id_field` value is not in the file yet are appended to the
+ * existing array, and everything the user already has is left alone.
+ *
+ * @param path File to write
+ * @param header Text before the array's entries, ending with `"<array_key>": [`
+ * @param array_key Key of the array that holds the entries
+ * @param id_field Field identifying an entry ("label" or "name")
+ * @param entries Pairs of (id, entry JSON indented for the array)
+ * @param force Overwrite an existing file
+ * @return false if the file could not be written
+ */
+static bool write_vscode_json(const std::filesystem::path &path,
+                              const std::string &header,
+                              const std::string &array_key,
+                              const std::string &id_field,
+                              const std::vector<std::pair<std::string, std::string>> &entries,
+                              bool force) {
+  std::string text;
+  bool exists = std::filesystem::exists(path);
+  if (!exists || force) {
+    text = header;
+    for (cforge_size_t i = 0; i < entries.size(); ++i) {
+      text += (i == 0 ? "\n" : ",\n") + entries[i].second;
+    }
+    text += "\n  ]\n}\n";
+  } else {
+    std::ifstream in(path, std::ios::binary);
+    std::stringstream buffer;
+    buffer << in.rdbuf();
+    text = buffer.str();
+
+    cforge_size_t end = find_json_array_end(text, array_key);
+    if (end == std::string::npos) {
+      cforge::logger::print_warning("Could not find \"" + array_key + "\" in " + path.string()
+                                    + "; leaving it unchanged (use --force to overwrite)");
+      return true;
+    }
+
+    std::string added;
+    for (const auto &[id, entry] : entries) {
+      std::regex existing("\"" + id_field + "\"\\s*:\\s*\"" + std::regex_replace(
+          id, std::regex(R"([.^$|()\[\]{}*+?\\])"), R"(\$&)") + "\"");
+      if (!std::regex_search(text, existing)) {
+        added += ",\n" + entry;
+      }
+    }
+    if (added.empty()) {
+      cforge::logger::print_verbose(path.string() + " is up to date");
+      return true;
+    }
+
+    // An empty array gets the entries without the leading comma
+    cforge_size_t open = text.rfind('[', end);
+    if (text.find_first_not_of(" \t\r\n", open + 1) == end) {
+      text.replace(open + 1, end - open - 1, added.substr(1) + "\n  ");
+    } else {
+      text.insert(text.find_last_not_of(" \t\r\n", end - 1) + 1, added);
+    }
+  }
+
+  std::ofstream out(path, std::ios::binary);
+  if (!out) {
+    cforge::logger::print_error("Failed to write " + path.string());
+    return false;
+  }
+  out << text;
+  cforge::logger::print_action(exists && !force ? "Updated" : "Created", path.string());
+  return true;
+}
+
+/**
+ * @brief Generate .vscode configuration for a single project
+ *
+ * Writes c_cpp_properties.json (IntelliSense from compile_commands.json),
+ * tasks.json with a `cforge build` task per configuration and launch.json
+ * with a debug configuration per executable and configuration.
+ *
+ * @param project_dir Project directory
+ * @param cfg Project configuration
+ * @param force Overwrite existing files instead of merging
+ * @return bool Success flag
+ */
+static bool generate_vscode_project(const std::filesystem::path &project_dir,
+                                    const cforge::toml_reader &cfg,
+                                    bool force) {
+  std::filesystem::path vscode_dir = project_dir / ".vscode";
+  std::filesystem::path build_dir  = cforge::get_build_base_dir(project_dir, &cfg);
+  std::vector<std::string> configs = get_ide_build_configs(cfg);
+  std::string compile_commands =
+      to_workspace_folder_path(project_dir, build_dir / "compile_commands.json");
+
+  std::error_code ec;
+  std::filesystem::create_directories(vscode_dir, ec);
+  if (ec) {
+    cforge::logger::print_error("Failed to create " + vscode_dir.string() + ": " + ec.message());
+    return false;
+  }
+
+#ifdef _WIN32
+  const std::string platform_name   = "Win32";
+  const std::string debugger_type   = "cppvsdbg";
+  const std::string problem_matcher = "$msCompile";
+#elif defined(__APPLE__)
+  const std::string platform_name   = "Mac";
+  const std::string debugger_type   = "cppdbg";
+  const std::string problem_matcher = "$gcc";
+#else
+  const std::string platform_name   = "Linux";
+  const std::string debugger_type   = "cppdbg";
+  const std::string problem_matcher = "$gcc";
+#endif
+  auto json_quote = [](const std::string &value) { return cforge::logger::json_quote(value); };
+
+  // IntelliSense
+  std::string properties_header = "{\n  \"version\": 4,\n  \"configurations\": [";
+  std::string properties_entry  = "    {\n"
+                                  "      \"name\": " + json_quote(platform_name) + ",\n"
+                                  "      \"compileCommands\": " + json_quote(compile_commands)
+                                + "\n    }";
+  if (!write_vscode_json(vscode_dir / "c_cpp_properties.json",
+                         properties_header,
+                         "configurations",
+                         "name",
+                         {{platform_name, properties_entry}},
+                         force)) {
+    return false;
+  }
+
+  // Build tasks
+  std::vector<std::pair<std::string, std::string>> tasks;
+  for (cforge_size_t i = 0; i < configs.size(); ++i) {
+    std::string label = "cforge: build " + configs[i];
+    std::string group = i == 0 ? "{ \"kind\": \"build\", \"isDefault\": true }" : "\"build\"";
+    tasks.push_back({label,
+                     "    {\n"
+                     "      \"label\": " + json_quote(label) + ",\n"
+                     "      \"type\": \"shell\",\n"
+                     "      \"command\": \"cforge\",\n"
+                     "      \"args\": [\"build\", \"--config\", " + json_quote(configs[i])
+                         + "],\n"
+                     "      \"options\": { \"cwd\": \"${workspaceFolder}\" },\n"
+                     "      \"group\": " + group + ",\n"
+                     "      \"problemMatcher\": [" + json_quote(problem_matcher) + "]\n"
+                     "    }"});
+  }
+  if (!write_vscode_json(vscode_dir / "tasks.json",
+                         "{\n  \"version\": \"2.0.0\",\n  \"tasks\": [",
+                         "tasks",
+                         "label",
+                         tasks,
+                         force)) {
+    return false;
+  }
+
+  // Debug configurations
+  std::vector<std::pair<std::string, std::string>> launches;
+  for (const auto &config : configs) {
+    for (const auto &target : get_ide_launch_targets(project_dir, cfg, build_dir, config)) {
+      std::string name  = target.name + " (" + config + ")";
+      std::string entry = "    {\n"
+                          "      \"name\": " + json_quote(name) + ",\n"
+                          "      \"type\": " + json_quote(debugger_type) + ",\n"
+                          "      \"request\": \"launch\",\n"
+                          "      \"program\": " + json_quote(target.program) + ",\n"
+                          "      \"args\": [],\n"
+                          "      \"cwd\": \"${workspaceFolder}\",\n";
+#ifdef _WIN32
+      entry += "      \"console\": \"integratedTerminal\",\n";
+#elif defined(__APPLE__)
+      entry += "      \"MIMode\": \"lldb\",\n";
+#else
+      entry += "      \"MIMode\": \"gdb\",\n";
+#endif
+      entry += "      \"preLaunchTask\": " + json_quote("cforge: build " + config) + "\n    }";
+      launches.push_back({name, entry});
+    }
+  }
+  if (launches.empty()) {
+    cforge::logger::print_verbose("No executable targets; skipping launch.json");
+  } else if (!write_vscode_json(vscode_dir / "launch.json",
+                                "{\n  \"version\": \"0.2.0\",\n  \"configurations\": [",
+                                "configurations",
+                                "name",
+                                launches,
+                                force)) {
+    return false;
+  }
+
+  if (!cfg.get_bool("build.export_compile_commands", false)) {
+    cforge::logger::print_hint("set build.export_compile_commands = true so IntelliSense can "
+                               "read " + compile_commands);
+  }
+  return true;
+}
+
+/**
+ * @brief Generate .vscode configuration for every member and a multi-root
+ *        <workspace>.code-workspace file listing them
+ */
+static bool generate_vscode_workspace(const std::filesystem::path &workspace_dir, bool force) {
+  cforge::workspace ws;
+  if (!ws.load(workspace_dir)) {
+    cforge::logger::print_error("Failed to load workspace configuration");
+    return false;
+  }
+
+  bool success = true;
+  std::string folders;
+  for (const auto &project : ws.get_projects()) {
+    std::filesystem::path project_dir = workspace_dir / project.path;
+    cforge::toml_reader cfg;
+    if (!cfg.load((project_dir / CFORGE_FILE).string())) {
+      cforge::logger::print_warning("Skipping '" + project.name + "': failed to load "
+                                    + std::string(CFORGE_FILE));
+      continue;
+    }
+    success = generate_vscode_project(project_dir, cfg, force) && success;
+
+    std::string rel = std::filesystem::relative(project_dir, workspace_dir).generic_string();
+    folders += std::string(folders.empty() ? "" : ",\n") + "    { \"name\": "
+             + cforge::logger::json_quote(project.name)
+             + ", \"path\": " + cforge::logger::json_quote(rel) + " }";
+  }
+
+  std::filesystem::path ws_file = workspace_dir / (ws.get_name() + ".code-workspace");
+  if (std::filesystem::exists(ws_file) && !force) {
+    cforge::logger::print_status(ws_file.string() + " already exists (use --force to overwrite)");
+    return success;
+  }
+  std::ofstream out(ws_file);
+  if (!out) {
+    cforge::logger::print_error("Failed to write " + ws_file.string());
+    return false;
+  }
+  out << "{\n  \"folders\": [\n" << folders << "\n  ]\n}\n";
+  cforge::logger::print_action("Created", ws_file.string());
+  cforge::logger::print_status("Open " + ws_file.string() + " in VS Code");
+  return success;
+}
+
+/**
+ * @brief Write CLion CMake profiles (.idea/cmake.xml) for each configuration
+ *
+ * Each profile gets its own generation directory so switching profiles in
+ * CLion doesn't reconfigure cforge's own build directory. Existing profiles
+ * are kept unless `force` is set.
+ */
+static bool write_clion_profiles(const std::filesystem::path &project_dir,
+                                 const cforge::toml_reader &cfg,
+                                 bool force) {
+  std::filesystem::path idea_dir = project_dir / ".idea";
+  std::filesystem::path xml_path = idea_dir / "cmake.xml";
+  std::filesystem::path build_dir = cforge::get_build_base_dir(project_dir, &cfg);
+  std::filesystem::path rel_build = build_dir.lexically_normal().lexically_relative(project_dir);
+  if (rel_build.empty() || *rel_build.begin() == "..") {
+    rel_build = build_dir;
+  }
+
+  std::string text;
+  bool exists = std::filesystem::exists(xml_path);
+  if (exists && !force) {
+    std::ifstream in(xml_path, std::ios::binary);
+    std::stringstream buffer;
+    buffer << in.rdbuf();
+    text = buffer.str();
+    if (text.find("</configurations>") == std::string::npos) {
+      cforge::logger::print_warning(xml_path.string() + " has no CMake profiles section; leaving "
+                                    "it unchanged (use --force to overwrite)");
+      return true;
+    }
+  } else {
+    text = "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n"
+           "<project version=\"4\">\n"
+           "  <component name=\"CMakeSharedSettings\">\n"
+           "    <configurations>\n"
+           "    </configurations>\n"
+           "  </component>\n"
+           "</project>\n";
+  }
+
+  std::string added;
+  for (const auto &config : get_ide_build_configs(cfg)) {
+    if (text.find("PROFILE_NAME=\"" + config + "\"") != std::string::npos) {
+      continue;
+    }
+    std::string gen_dir = (rel_build / ("clion-" + cforge::string_to_lower(config)))
+                              .generic_string();
+    added += "      <configuration PROFILE_NAME=\"" + config + "\" ENABLED=\"true\" CONFIG_NAME=\""
+           + config + "\" GENERATION_DIR=\"" + gen_dir + "\" />\n";
+  }
+  if (added.empty()) {
+    cforge::logger::print_verbose(xml_path.string() + " is up to date");
+    return true;
+  }
+  cforge_size_t close = text.find("</configurations>");
+  text.insert(text.rfind('\n', close) + 1, added);
+
+  std::error_code ec;
+  std::filesystem::create_directories(idea_dir, ec);
+  std::ofstream out(xml_path, std::ios::binary);
+  if (!out) {
+    cforge::logger::print_error("Failed to write " + xml_path.string());
+    return false;
+  }
+  out << text;
+  cforge::logger::print_action(exists && !force ? "Updated" : "Created", xml_path.string());
+  return true;
+}
+
 /**
  * @brief Handle the 'ide' command
  *
@@ -781,10 +1241,16 @@ cforge_int_t cforge_cmd_ide(const cforge_context_t *ctx) {
 
   // Determine verbosity
   bool verbose = cforge::logger::get_verbosity() == cforge::log_verbosity::VERBOSITY_VERBOSE;
-  // Get IDE type from arguments
+  // Get IDE type and flags from arguments
   std::string ide_type;
-  if (ctx->args.args && ctx->args.args[0]) {
-    ide_type = ctx->args.args[0];
+  bool force = false;
+  for (cforge_int_t i = 0; i < ctx->args.arg_count; i++) {
+    std::string arg = ctx->args.args[i];
+    if (arg == "--force" || arg == "-f") {
+      force = true;
+    } else if (ide_type.empty() && !arg.empty() && arg[0] != '-') {
+      ide_type = arg;
+    }
   }
   // If IDE type is not specified, detect based on platform
   if (ide_type.empty()) {
@@ -796,10 +1262,14 @@ cforge_int_t cforge_cmd_ide(const cforge_context_t *ctx) {
     ide_type = "codeblocks";
 #endif
   }
-  // Workspace mode: bypass CMake and generate VS solution
+  // Workspace mode: bypass CMake and generate VS solution or VS Code workspace
   if (ctx->is_workspace) {
+    if (ide_type == "vscode" || ide_type == "code") {
+      return generate_vscode_workspace(project_dir, force) ? 0 : 1;
+    }
     if (ide_type != "" && ide_type != "vs" && ide_type != "visual-studio") {
-      cforge::logger::print_error("Workspace IDE only supports Visual Studio (vs)");
+      cforge::logger::print_error("Workspace IDE only supports Visual Studio (vs) and VS Code "
+                                  "(vscode)");
       return 1;
     }
     return generate_vs_workspace_solution(project_dir, verbose) ? 0 : 1;
@@ -835,11 +1305,17 @@ cforge_int_t cforge_cmd_ide(const cforge_context_t *ctx) {
   } else if (ide_type == "xcode") {
     success = generate_xcode_project(project_dir, build_dir, verbose);
   } else if (ide_type == "clion") {
-    success = generate_clion_project(project_dir, build_dir, verbose);
+    success = write_clion_profiles(project_dir, project_config, force)
+           && generate_clion_project(project_dir, build_dir, verbose);
+  } else if (ide_type == "vscode" || ide_type == "code") {
+    success = generate_vscode_project(project_dir, project_config, force);
+    if (success) {
+      cforge::logger::generated("VS Code configuration");
+    }
   } else {
     cforge::logger::print_error("Unknown IDE type: " + ide_type);
     cforge::logger::print_status("Available IDE types: vs (Visual Studio), cb "
-                                 "(CodeBlocks), xcode, clion");
+                                 "(CodeBlocks), xcode, clion, vscode");
     return 1;
   }
 
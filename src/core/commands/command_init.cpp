@@ -5,11 +5,15 @@
 
 #include "cforge/log.hpp"
 
+#include "core/build_utils.hpp"
 #include "core/command_registry.hpp"
 #include "core/commands.hpp"
 #include "core/constants.h"
 #include "core/file_system.h"
+#include "core/init_manifest.hpp"
+#include "core/package_export.hpp"
 #include "core/process_utils.hpp"
+#include "core/project_templates.hpp"
 #include "core/toml_reader.hpp"
 #include "core/types.h"
 #include "core/utils/terminal_prompt.hpp"
@@ -29,6 +33,11 @@ static std::string g_template_name = "executable";
 // Add a flag to force overwrite existing files
 static bool g_force_overwrite = false;
 
+namespace cforge {
+// Defined in command_migrate.cpp
+cforge_int_t import_cmake_project(const std::filesystem::path &project_dir, bool overwrite);
+}  // namespace cforge
+
 /**
  * @brief Split a comma-separated list of project names
  *
@@ -86,86 +95,6 @@ static std::vector<std::string> parse_project_list(const std::string &project_li
   return result;
 }
 
-/**
- * @brief Create default .gitignore file
- *
- * @param project_path Path to project directory
- * @return bool Success flag
- */
-[[maybe_unused]] static bool create_gitignore(const std::filesystem::path &project_path) {
-  std::filesystem::path gitignore_path = project_path / ".gitignore";
-
-  if (std::filesystem::exists(gitignore_path) && !g_force_overwrite) {
-    cforge::logger::print_warning(".gitignore already exists, skipping");
-    return true;
-  } else if (std::filesystem::exists(gitignore_path) && g_force_overwrite) {
-    cforge::logger::print_action("Overwriting", ".gitignore");
-  }
-
-  std::ofstream gitignore(gitignore_path);
-
-  if (!gitignore.is_open()) {
-    cforge::logger::print_error("Failed to create .gitignore file");
-    return false;
-  }
-
-  gitignore << "# Build directory\n";
-  gitignore << "build/\n";
-  gitignore << "out/\n\n";
-
-  gitignore << "# CMake build files\n";
-  gitignore << "CMakeFiles/\n";
-  gitignore << "cmake_install.cmake\n";
-  gitignore << "CMakeCache.txt\n";
-  gitignore << "*.cmake\n";
-  gitignore << "!CMakeLists.txt\n\n";
-
-  gitignore << "# IDEs\n";
-  gitignore << ".vs/\n";
-  gitignore << ".vscode/\n";
-  gitignore << ".idea/\n";
-  gitignore << "*.swp\n";
-  gitignore << "*.swo\n\n";
-
-  gitignore << "# Prerequisites\n";
-  gitignore << "*.d\n\n";
-
-  gitignore << "# Compiled Object files\n";
-  gitignore << "*.slo\n";
-  gitignore << "*.lo\n";
-  gitignore << "*.o\n";
-  gitignore << "*.obj\n\n";
-
-  gitignore << "# Precompiled Headers\n";
-  gitignore << "*.gch\n";
-  gitignore << "*.pch\n\n";
-
-  gitignore << "# Compiled Dynamic libraries\n";
-  gitignore << "*.so\n";
-  gitignore << "*.dylib\n";
-  gitignore << "*.dll\n\n";
-
-  gitignore << "# Fortran module files\n";
-  gitignore << "*.mod\n";
-  gitignore << "*.smod\n\n";
-
-  gitignore << "# Compiled Static libraries\n";
-  gitignore << "*.lai\n";
-  gitignore << "*.la\n";
-  gitignore << "*.a\n";
-  gitignore << "*.lib\n\n";
-
-  gitignore << "# Executables\n";
-  gitignore << "*.exe\n";
-  gitignore << "*.out\n";
-  gitignore << "*.app\n";
-
-  gitignore.close();
-
-  cforge::logger::created(".gitignore");
-  return true;
-}
-
 /**
  * @brief Create default README.md file
  *
@@ -330,6 +259,8 @@ static bool create_cmakelists(const std::filesystem::path &project_path,
     cmakelists << "# This is a shared library project\n";
     cmakelists << "add_library(${TARGET_NAME} SHARED ${SOURCES})\n";
     cmakelists << "set(PROJECT_TYPE \"shared_library\")\n\n";
+    cmakelists << cforge::generate_export_header_cmake(
+        "${TARGET_NAME}", cforge::export_header_base_name(project_name), false);
   } else if (g_template_name == "header-only" || g_template_name == "header_only") {
     cmakelists << "# This is a header-only interface library project\n";
     cmakelists << "add_library(${TARGET_NAME} INTERFACE)\n";
@@ -368,17 +299,18 @@ static bool create_cmakelists(const std::filesystem::path &project_path,
   cmakelists << "include(GNUInstallDirs)\n";
   cmakelists << "if(PROJECT_TYPE STREQUAL \"executable\")\n";
   cmakelists << "    install(TARGETS ${TARGET_NAME}\n";
-  cmakelists << "        RUNTIME DESTINATION ${CMAKE_INSTALL_BINDIR}\n";
+  cmakelists << "        RUNTIME DESTINATION ${CMAKE_INSTALL_BINDIR} COMPONENT Runtime\n";
   cmakelists << "    )\n";
   cmakelists << "else()\n";
   cmakelists << "    install(TARGETS ${TARGET_NAME}\n";
-  cmakelists << "        ARCHIVE DESTINATION ${CMAKE_INSTALL_LIBDIR}\n";
-  cmakelists << "        LIBRARY DESTINATION ${CMAKE_INSTALL_LIBDIR}\n";
-  cmakelists << "        PUBLIC_HEADER DESTINATION ${CMAKE_INSTALL_INCLUDEDIR}\n";
+  cmakelists << "        ARCHIVE DESTINATION ${CMAKE_INSTALL_LIBDIR} COMPONENT Runtime\n";
+  cmakelists << "        LIBRARY DESTINATION ${CMAKE_INSTALL_LIBDIR} COMPONENT Runtime\n";
+  cmakelists << "        PUBLIC_HEADER DESTINATION ${CMAKE_INSTALL_INCLUDEDIR} COMPONENT Headers\n";
   cmakelists << "    )\n";
   cmakelists << "    # Install headers\n";
   cmakelists << "    install(DIRECTORY ${CMAKE_CURRENT_SOURCE_DIR}/include/\n";
   cmakelists << "        DESTINATION ${CMAKE_INSTALL_INCLUDEDIR}\n";
+  cmakelists << "        COMPONENT Headers\n";
   cmakelists << "        FILES_MATCHING PATTERN \"*.h\" PATTERN \"*.hpp\"\n";
   cmakelists << "    )\n";
   cmakelists << "endif()\n\n";
@@ -434,312 +366,14 @@ static bool create_cforge_toml(const std::filesystem::path &project_path,
     return false;
   }
 
-  config << "# Project configuration for " << project_name << "\n\n";
-
-  // Embedded template generates a completely different cforge.toml
+  cforge::write_init_manifest(config, project_name, cpp_version, g_template_name, with_tests);
+  config.close();
   if (g_template_name == "embedded" || g_template_name == "bare-metal"
       || g_template_name == "bare_metal") {
-    config << "[project]\n";
-    config << "name = \"" << project_name << "\"\n";
-    config << "version = \"0.1.0\"\n";
-    config << "description = \"An embedded project created with cforge\"\n";
-    config << "binary_type = \"executable\"\n";
-    config << "c_standard = \"99\"\n";
-    config << "c_extensions = true         # Enable GNU extensions (e.g., "
-              "gnu99)\n";
-    config << "languages = [\"C\", \"ASM\"]   # Enable C and assembly support\n";
-    config << "authors = [\"Your Name <your.email@example.com>\"]\n";
-    config << "license = \"MIT\"\n\n";
-
-    config << "[build]\n";
-    config << "build_type = \"Release\"\n";
-    config << "directory = \"build\"\n";
-    config << "source_dirs = [\"src\"]\n";
-    config << "include_dirs = [\"include\"]\n";
-    config << "export_compile_commands = true\n";
-    config << "# Add hardware-specific defines here\n";
-    config << "# defines = [\"F_CPU=16000000UL\", \"MY_MCU\"]\n\n";
-
-    config << "[build.config.release]\n";
-    config << "optimize = \"size\"          # Optimize for code size\n";
-    config << "warnings = \"all\"\n";
-    config << "defines = [\"NDEBUG\"]\n\n";
-
-    config << "[build.config.debug]\n";
-    config << "optimize = \"debug\"\n";
-    config << "debug_info = true\n";
-    config << "warnings = \"all\"\n";
-    config << "defines = [\"DEBUG=1\"]\n\n";
-
-    config << "# Compiler flags (adjust for your target MCU)\n";
-    config << "[compiler.gcc]\n";
-    config << "# flags = [\"-mmcu=YOUR_MCU\", \"-funsigned-char\", "
-              "\"-ffunction-sections\", \"-fdata-sections\"]\n\n";
-
-    config << "# Linker configuration\n";
-    config << "[linker]\n";
-    config << "# scripts = [\"link/linker.ld\"]   # Custom linker script\n";
-    config << "# flags = [\"-mmcu=YOUR_MCU\"]     # MCU-specific linker flags\n";
-    config << "dead_code_strip = true              # Remove unused code "
-              "sections\n";
-    config << "map_file = true                     # Generate memory map file\n\n";
-
-    config << "# Cross-compilation profile (configure for your target)\n";
-    config << "[cross.profile.target]\n";
-    config << "system = \"Generic\"                # Bare-metal target\n";
-    config << "# processor = \"avr\"              # Target processor "
-              "architecture\n";
-    config << "# compilers = { c = \"avr-gcc\", cxx = \"avr-g++\" }\n";
-    config << "# variables = { CMAKE_ASM_COMPILER = \"avr-gcc\" }\n";
-    config << "nostdlib = true                     # No standard library\n";
-    config << "nostartfiles = true                 # No default startup code\n";
-    config << "nodefaultlibs = true                # No default libraries\n";
-    config << "\n";
-    config << "# Post-build commands (e.g., generate .hex from .elf)\n";
-    config << "# post_build = [\n";
-    config << "#     \"avr-objcopy -R .eeprom -O ihex "
-              "$<TARGET_FILE:${PROJECT_NAME}> "
-              "$<TARGET_FILE_DIR:${PROJECT_NAME}>/${PROJECT_NAME}.hex\",\n";
-    config << "#     \"avr-size --mcu=YOUR_MCU -C "
-              "$<TARGET_FILE:${PROJECT_NAME}>\"\n";
-    config << "# ]\n";
-    config << "\n";
-    config << "# Flash/upload command\n";
-    config << "# flash = \"avrdude -c arduino -p YOUR_MCU -P /dev/ttyUSB0 -b "
-              "115200 -D -U "
-              "flash:w:$<TARGET_FILE_DIR:${PROJECT_NAME}>/${PROJECT_NAME}.hex\"\n";
-
-    config.close();
     cforge::logger::print_verbose("Created embedded cforge.toml");
-    return true;
-  }
-
-  // Standard (non-embedded) project header
-  config << "[project]\n";
-  config << "name = \"" << project_name << "\"\n";
-  config << "version = \"0.1.0\"\n";
-  config << "description = \"A C++ project created with cforge\"\n";
-  config << "cpp_standard = \"" << cpp_version << "\"\n";
-  config << "c_standard = \"11\"\n";
-
-  if (g_template_name == "executable" || g_template_name == "app"
-      || g_template_name == "application") {
-    config << "binary_type = \"executable\"  # executable, shared_lib, "
-              "static_lib, or header_only\n";
-  } else if (g_template_name == "shared-library" || g_template_name == "shared_library") {
-    config << "binary_type = \"shared_lib\"  # executable, shared_lib, "
-              "static_lib, or header_only\n";
-  } else if (g_template_name == "header-only" || g_template_name == "header_only") {
-    config << "binary_type = \"header_only\"  # executable, shared_lib, "
-              "static_lib, or header_only\n";
   } else {
-    config << "binary_type = \"static_lib\"  # executable, shared_lib, "
-              "static_lib, or header_only\n";
+    cforge::logger::created("cforge.toml");
   }
-
-  config << "authors = [\"Your Name <your.email@example.com>\"]\n";
-  config << "homepage = \"https://github.com/yourusername/" << project_name << "\"\n";
-  config << "repository = \"https://github.com/yourusername/" << project_name << ".git\"\n";
-  config << "license = \"MIT\"\n\n";
-
-  config << "[build]\n";
-  config << "build_type = \"Debug\"  # Debug, Release, RelWithDebInfo, "
-            "MinSizeRel\n";
-  config << "directory = \"build\"\n";
-  config << "source_dirs = [\"src\"]\n";
-  config << "include_dirs = [\"include\"]\n";
-  config << "export_compile_commands = true  # Generate compile_commands.json "
-            "for IDEs\n";
-  config << "# position_independent_code = true  # For shared libraries\n";
-  config << "# Uncomment to specify custom source patterns\n";
-  config << "# source_patterns = [\"src/*.cpp\", \"src/**/*.cpp\"]\n";
-  config << "# Uncomment to specify individual source files\n";
-  config << "# source_files = [\"src/main.cpp\", \"src/example.cpp\"]\n\n";
-
-  // Add build configuration for different build types
-  // Using portable options that work across MSVC, GCC, and Clang
-  config << "[build.config.debug]\n";
-  config << "optimize = \"debug\"      # Portable: maps to /Od (MSVC) or -Og "
-            "(GCC/Clang)\n";
-  config << "debug_info = true        # Portable: maps to /Zi (MSVC) or -g "
-            "(GCC/Clang)\n";
-  config << "warnings = \"all\"        # Portable: maps to /W4 (MSVC) or -Wall "
-            "-Wextra (GCC/Clang)\n";
-  config << "defines = [\"DEBUG=1\"]\n";
-  config << "# sanitizers = [\"address\"]  # Enable AddressSanitizer for debug "
-            "builds\n\n";
-
-  config << "[build.config.release]\n";
-  config << "optimize = \"speed\"      # Portable: maps to /O2 (MSVC) or -O2 "
-            "(GCC/Clang)\n";
-  config << "warnings = \"all\"        # Portable: maps to /W4 (MSVC) or -Wall "
-            "-Wextra (GCC/Clang)\n";
-  config << "lto = true              # Link-time optimization: /GL+/LTCG "
-            "(MSVC) or -flto (GCC/Clang)\n";
-  config << "defines = [\"NDEBUG\"]\n\n";
-
-  config << "[build.config.relwithdebinfo]\n";
-  config << "optimize = \"speed\"\n";
-  config << "debug_info = true\n";
-  config << "warnings = \"all\"\n";
-  config << "defines = [\"NDEBUG\"]\n\n";
-
-  config << "[build.config.minsizerel]\n";
-  config << "optimize = \"size\"       # Portable: maps to /O1 /Os (MSVC) or "
-            "-Os (GCC/Clang)\n";
-  config << "defines = [\"NDEBUG\"]\n\n";
-
-  config << "[test]\n";
-  config << "enabled = " << (with_tests ? "true" : "false") << "\n";
-
-  config << "[package]\n";
-  config << "enabled = true\n";
-  config << "generators = []  # Package generators\n";
-  config << "# Windows generators: ZIP, NSIS\n";
-  config << "# Linux generators: TGZ, DEB, RPM\n";
-  config << "# macOS generators: TGZ\n";
-  config << "vendor = \"Your Organization\"\n";
-  config << "contact = \"Your Name <your.email@example.com>\"\n\n";
-
-  // Dependencies section
-  config << "# Dependencies section\n";
-  config << "# [dependencies]\n\n";
-
-  config << "# Git dependencies\n";
-  config << "# [dependencies.git.json]\n";
-  config << "# url = \"https://github.com/nlohmann/json.git\"\n";
-  config << "# tag = \"v3.11.2\"\n";
-  config << "# link = true\n";
-  config << "# include = true\n\n";
-
-  config << "# vcpkg dependencies\n";
-  config << "# [dependencies.vcpkg]\n";
-  config << "# path = \"C:/vcpkg\"  # Optional: path to vcpkg\n";
-  config << "# triplet = \"x64-windows\"  # Optional: vcpkg triplet\n";
-  config << "#\n";
-  config << "# [dependencies.vcpkg.fmt]\n";
-  config << "# version = \"9.1.0\"\n";
-  config << "#\n";
-  config << "# [dependencies.vcpkg.curl]\n";
-  config << "# version = \"7.80.0\"\n";
-  config << "# features = [\"ssl\", \"http2\"]\n";
-  config << "# target_name = \"CURL::libcurl\"\n\n";
-
-  config << "# System dependencies (find_package, pkg_config, or manual)\n";
-  config << "# [dependencies.system.OpenGL]\n";
-  config << "# method = \"find_package\"  # find_package, pkg_config, or manual\n";
-  config << "# required = true\n";
-  config << "# components = [\"GL\", \"GLU\"]\n";
-  config << "#\n";
-  config << "# [dependencies.system.custom_lib]\n";
-  config << "# method = \"manual\"\n";
-  config << "# include_dirs = [\"/usr/local/include/custom\"]\n";
-  config << "# library_dirs = [\"/usr/local/lib\"]\n";
-  config << "# libraries = [\"custom\"]\n";
-  config << "# platforms = [\"linux\", \"macos\"]  # Optional: limit to "
-            "platforms\n\n";
-
-  config << "# Subdirectory dependencies (for existing CMake projects)\n";
-  config << "# [dependencies.subdirectory.spdlog]\n";
-  config << "# path = \"extern/spdlog\"\n";
-  config << "# target = \"spdlog::spdlog\"\n";
-  config << "# options = { SPDLOG_BUILD_TESTS = \"OFF\" }\n\n";
-
-  // Platform-specific configuration
-  config << "# Platform-specific configuration\n";
-  config << "# Portable options: optimize, warnings, debug_info, sanitizers, "
-            "lto,\n";
-  config << "#                   exceptions, rtti, hardening, visibility\n";
-  config << "# [platform.windows]\n";
-  config << "# hardening = \"full\"      # Portable: /GS /sdl /GUARD:CF (MSVC)\n";
-  config << "# defines = [\"WIN32\", \"_WINDOWS\"]\n";
-  config << "# links = [\"kernel32\", \"user32\"]\n";
-  config << "#\n";
-  config << "# [platform.linux]\n";
-  config << "# hardening = \"basic\"     # Portable: -fstack-protector-strong "
-            "-D_FORTIFY_SOURCE=2\n";
-  config << "# defines = [\"LINUX\"]\n";
-  config << "# links = [\"pthread\", \"dl\"]\n";
-  config << "#\n";
-  config << "# [platform.macos]\n";
-  config << "# stdlib = \"libc++\"       # Portable: use libc++ on macOS\n";
-  config << "# defines = [\"MACOS\"]\n";
-  config << "# frameworks = [\"Cocoa\", \"IOKit\"]\n\n";
-
-  // Compiler-specific configuration
-  config << "# Compiler-specific configuration\n";
-  config << "# Portable options work here too - they translate to the right "
-            "flags\n";
-  config << "# [compiler.msvc]\n";
-  config << "# warnings = \"strict\"     # Portable: /W4 /WX\n";
-  config << "# defines = [\"_CRT_SECURE_NO_WARNINGS\"]\n";
-  config << "#\n";
-  config << "# [compiler.gcc]\n";
-  config << "# warnings = \"pedantic\"   # Portable: -Wall -Wextra -Wpedantic "
-            "-Werror\n";
-  config << "#\n";
-  config << "# [compiler.clang]\n";
-  config << "# warnings = \"pedantic\"   # Portable: -Wall -Wextra -Wpedantic "
-            "-Werror\n";
-  config << "#\n";
-  config << "# [compiler.mingw]\n";
-  config << "# warnings = \"all\"        # Portable: -Wall -Wextra\n";
-  config << "# defines = [\"MINGW\"]\n\n";
-
-  // Platform + Compiler combination
-  config << "# Platform + Compiler combination\n";
-  config << "# [platform.windows.compiler.msvc]\n";
-  config << "# flags = [\"/W4\"]\n";
-  config << "# defines = [\"_CRT_SECURE_NO_WARNINGS\"]\n\n";
-
-  // Cross-compilation
-  config << "# Cross-compilation (optional)\n";
-  config << "# [cross]\n";
-  config << "# enabled = true\n";
-  config << "#\n";
-  config << "# [cross.target]\n";
-  config << "# system = \"Linux\"           # CMAKE_SYSTEM_NAME\n";
-  config << "# processor = \"aarch64\"      # CMAKE_SYSTEM_PROCESSOR\n";
-  config << "# toolchain = \"path/to/toolchain.cmake\"  # Optional\n";
-  config << "#\n";
-  config << "# [cross.compilers]\n";
-  config << "# c = \"/usr/bin/aarch64-linux-gnu-gcc\"\n";
-  config << "# cxx = \"/usr/bin/aarch64-linux-gnu-g++\"\n";
-  config << "#\n";
-  config << "# [cross.paths]\n";
-  config << "# sysroot = \"/path/to/sysroot\"\n";
-  config << "# find_root = \"/path/to/find/root\"\n";
-  config << "#\n";
-  config << "# [cross.variables]  # Custom CMake variables\n";
-  config << "# ANDROID_ABI = \"arm64-v8a\"\n";
-  config << "#\n";
-  config << "# Cross-compilation profiles (use with: cforge build --profile "
-            "<name>)\n";
-  config << "# [cross.profile.android-arm64]\n";
-  config << "# system = \"Android\"\n";
-  config << "# processor = \"aarch64\"\n";
-  config << "# toolchain = "
-            "\"${ANDROID_NDK}/build/cmake/android.toolchain.cmake\"\n";
-  config << "# variables = { ANDROID_ABI = \"arm64-v8a\", ANDROID_PLATFORM = "
-            "\"android-24\" }\n";
-  config << "#\n";
-  config << "# [cross.profile.raspberry-pi]\n";
-  config << "# system = \"Linux\"\n";
-  config << "# processor = \"armv7l\"\n";
-  config << "# compilers = { c = \"arm-linux-gnueabihf-gcc\", cxx = "
-            "\"arm-linux-gnueabihf-g++\" }\n";
-  config << "# sysroot = \"/path/to/rpi-sysroot\"\n\n";
-
-  // CMake integration
-  config << "# CMake integration\n";
-  config << "# [cmake]\n";
-  config << "# generator = \"Ninja\"  # Optional: override CMake generator\n";
-  config << "# includes = [\"cmake/custom.cmake\"]  # Custom CMake files to "
-            "include\n";
-  config << "# module_paths = [\"cmake/modules\"]  # Custom module paths\n";
-
-  config.close();
-  cforge::logger::created("cforge.toml");
   return true;
 }
 
@@ -901,12 +535,23 @@ static bool create_include_files(const std::filesystem::path &project_path,
   example_header << " * @brief Example header file for " << project_name << "\n";
   example_header << " */\n\n";
   example_header << "#pragma once\n\n";
+
+  // Shared libraries mark their API with the macro from the generated
+  // export header, everything else is hidden
+  std::string export_macro;
+  if (g_template_name == "shared-library" || g_template_name == "shared_library") {
+    export_macro = project_name;
+    std::transform(export_macro.begin(), export_macro.end(), export_macro.begin(), ::toupper);
+    export_macro += "_EXPORT ";
+    example_header << "#include \"" << project_name << "/export.h\"\n\n";
+  }
+
   example_header << "namespace " << project_name << " {\n\n";
   example_header << "/**\n";
   example_header << " * @brief Get an example message\n";
   example_header << " * @return const char* The message\n";
   example_header << " */\n";
-  example_header << "const char* get_example_message();\n\n";
+  example_header << export_macro << "const char* get_example_message();\n\n";
   example_header << "} // namespace " << project_name << "\n";
 
   return true;
@@ -967,53 +612,16 @@ static bool create_example_implementation(const std::filesystem::path &project_p
  */
 static bool create_test_files(const std::filesystem::path &project_path,
                               const std::string &project_name) {
-  std::filesystem::path tests_dir = project_path / "tests";
-
-  if (!std::filesystem::exists(tests_dir)) {
-    std::filesystem::create_directories(tests_dir);
-  }
-
   // No `tests/CMakeLists.txt` is needed — cforge's test_runner discovers
   // test files in tests/ at `cforge test` time, drops the builtin framework
   // header into the build dir, and auto-generates a main if the user hasn't
   // written one. Pulling in GoogleTest by default (the previous behavior)
   // failed offline / when no GIT was configured, and it ran against cforge's
   // own zero-config testing design.
-
-  // Create a single example test using the builtin framework.
-  std::filesystem::path test_example_path = tests_dir / "test_example.cpp";
-  if (std::filesystem::exists(test_example_path) && !g_force_overwrite) {
-    cforge::logger::print_warning("test_example.cpp already exists, skipping");
-  } else {
-    if (std::filesystem::exists(test_example_path) && g_force_overwrite) {
-      cforge::logger::print_action("Overwriting", "test_example.cpp");
-    }
-    std::ofstream test_example(test_example_path);
-    if (!test_example.is_open()) {
-      return false;
-    }
-    test_example << "/**\n"
-                 << " * @file test_example.cpp\n"
-                 << " * @brief Example tests for " << project_name << "\n"
-                 << " *\n"
-                 << " * Run with: `cforge test`. cforge provides the test framework —\n"
-                 << " * no main() needed, no extra config, no external dependencies.\n"
-                 << " */\n\n"
-                 << "#include \"test_framework.h\"\n\n"
-                 << "TEST(Example, BasicAssertions) {\n"
-                 << "    cf_assert(1 + 1 == 2);\n"
-                 << "    cf_assert_eq(2 * 3, 6);\n"
-                 << "    return 0;\n"
-                 << "}\n\n"
-                 << "TEST(Example, StringCompare) {\n"
-                 << "    const char *a = \"hello\";\n"
-                 << "    cf_assert(a != nullptr);\n"
-                 << "    return 0;\n"
-                 << "}\n";
-    test_example.close();
-  }
-
-  return true;
+  return cforge::write_template_file(project_path,
+                                     cforge::sample_test_template(),
+                                     cforge::make_template_variables(project_name, ""),
+                                     g_force_overwrite);
 }
 
 /**
@@ -1175,81 +783,6 @@ static bool create_license_file(const std::filesystem::path &project_path,
   return true;
 }
 
-/**
- * @brief Check if git is available on the system
- *
- * @return bool True if git is available
- */
-static bool is_git_available() {
-  return cforge::is_command_available("git");
-}
-
-/**
- * @brief Init a new git repository if requested and git is available
- *
- * @param project_path Path to project directory
- * @param verbose Verbose output flag
- * @return bool Success flag (true if successful or git not available/not
- * requested)
- */
-[[maybe_unused]] static bool init_git_repository(const std::filesystem::path &project_path,
-                                                 bool verbose) {
-  // If Git initialization wasn't explicitly requested, just return success
-  if (!verbose) {
-    cforge::logger::print_action("Skipped", "git initialization (use --git flag to enable)");
-    return true;
-  }
-
-  // First check if git is available
-  if (!is_git_available()) {
-    cforge::logger::print_warning("Git not found in PATH, skipping git initialization");
-    return true;  // Not critical for project creation
-  }
-
-  cforge::logger::print_action("Initializing", "git repository");
-
-  std::vector<std::string> git_args = {"init"};
-
-  // Use a shorter timeout for git init
-  bool result = cforge::execute_tool("git", git_args, project_path.string(), "Git", verbose, 20);
-
-  if (result) {
-    cforge::logger::created("git repository");
-
-    // Create an initial commit (optional)
-    if (verbose) {
-      cforge::logger::print_action("Creating", "initial commit");
-
-      // Add all files
-      std::vector<std::string> git_add_args = {"add", "."};
-      bool add_result =
-          cforge::execute_tool("git", git_add_args, project_path.string(), "Git add", verbose, 10);
-
-      if (add_result) {
-        // Commit
-        std::vector<std::string> git_commit_args = {"commit", "-m", "Initial commit"};
-        bool commit_result                       = cforge::execute_tool(
-            "git", git_commit_args, project_path.string(), "Git commit", verbose, 10);
-
-        if (commit_result) {
-          cforge::logger::created("initial commit");
-        } else {
-          cforge::logger::print_warning("Failed to create initial commit. This is not critical");
-        }
-      } else {
-        cforge::logger::print_warning("Failed to add files to git. This is not critical");
-      }
-    }
-  } else {
-    cforge::logger::print_warning("Failed to initialize git repository. This is not "
-                                  "critical for project creation");
-  }
-
-  // Return true regardless of git initialization result
-  // as this is not critical for project creation
-  return true;
-}
-
 /**
  * @brief Normalize a project name by replacing special characters with
  * underscores
@@ -1458,6 +991,18 @@ cforge_int_t cforge_cmd_init(const cforge_context_t *ctx) {
     }
   }
 
+  // Importing a CMake project takes everything from its CMakeLists.txt
+  bool from_cmake = false;
+  bool overwrite  = false;
+  for (cforge_int_t i = 0; i < ctx->args.arg_count; i++) {
+    std::string arg = ctx->args.args[i];
+    from_cmake      = from_cmake || arg == "--from-cmake";
+    overwrite       = overwrite || arg == "--overwrite";
+  }
+  if (from_cmake) {
+    return cforge::import_cmake_project(ctx->working_dir, overwrite);
+  }
+
   try {
     // Check if a workspace configuration file exists in the current directory
     std::filesystem::path workspace_file_path = std::filesystem::path(ctx->working_dir)
@@ -1672,6 +1217,35 @@ cforge_int_t cforge_cmd_init(const cforge_context_t *ctx) {
       }
     }
 
+    // Validate --template against the project types cforge.toml accepts, so
+    // a typo doesn't silently produce a static library
+    if (has_template_flag && template_name != "embedded" && template_name != "bare-metal"
+        && template_name != "bare_metal" && template_name != "app"
+        && template_name != "application") {
+      bool deprecated        = false;
+      std::string normalized = cforge::normalize_binary_type(template_name, &deprecated);
+      if (normalized.empty()) {
+        cforge::logger::print_error("unknown template '" + template_name + "'");
+        cforge::logger::print_hint(
+            "expected one of: executable, static-lib, shared-library, header-only, embedded");
+        return 1;
+      }
+      if (deprecated) {
+        cforge::logger::print_warning("template '" + template_name
+                                      + "' is deprecated; creating a static library");
+        cforge::logger::print_hint("use --template static-lib or --template shared-library");
+      }
+      if (normalized == "executable") {
+        template_name = "executable";
+      } else if (normalized == "shared_lib") {
+        template_name = "shared-library";
+      } else if (normalized == "header_only") {
+        template_name = "header-only";
+      } else {
+        template_name = "static-lib";
+      }
+    }
+
     // Apply selected template
     g_template_name = template_name;
 
@@ -1708,17 +1282,17 @@ cforge_int_t cforge_cmd_init(const cforge_context_t *ctx) {
       // Show summary and confirm
       cforge::logger::print_blank();
       cforge::logger::print_rule(34);
-      fmt::print(fmt::emphasis::bold, "{:>12}", "Name");
+      fmt::print(cforge::logger::style(fmt::emphasis::bold), "{:>12}", "Name");
       fmt::print(": {}\n", project_name);
-      fmt::print(fmt::emphasis::bold, "{:>12}", "Template");
+      fmt::print(cforge::logger::style(fmt::emphasis::bold), "{:>12}", "Template");
       fmt::print(": {}\n", template_name);
-      fmt::print(fmt::emphasis::bold, "{:>12}", "C++");
+      fmt::print(cforge::logger::style(fmt::emphasis::bold), "{:>12}", "C++");
       fmt::print(": {}\n", cpp_standard);
-      fmt::print(fmt::emphasis::bold, "{:>12}", "Tests");
+      fmt::print(cforge::logger::style(fmt::emphasis::bold), "{:>12}", "Tests");
       fmt::print(": {}\n", with_tests ? "yes" : "no");
-      fmt::print(fmt::emphasis::bold, "{:>12}", "Git");
+      fmt::print(cforge::logger::style(fmt::emphasis::bold), "{:>12}", "Git");
       fmt::print(": {}\n", with_git ? "yes" : "no");
-      fmt::print(fmt::emphasis::bold, "{:>12}", "License");
+      fmt::print(cforge::logger::style(fmt::emphasis::bold), "{:>12}", "License");
       fmt::print(": {}\n", license_type);
       cforge::logger::print_rule(34);
       cforge::logger::print_blank();
@@ -2017,6 +1591,14 @@ cforge_int_t cforge_cmd_init(const cforge_context_t *ctx) {
         return 1;
       }
 
+      if (with_git) {
+        cforge::write_template_file(project_dir,
+                                    cforge::gitignore_template(),
+                                    cforge::make_template_variables(project_name, cpp_standard),
+                                    g_force_overwrite);
+        cforge::init_project_git_repository(project_dir, false);
+      }
+
       cforge::logger::finished(project_name);
     }
 
@@ -6,6 +6,7 @@
 #include "cforge/log.hpp"
 
 #include "core/command_registry.hpp"
+#include "core/build_utils.hpp"
 #include "core/commands.hpp"
 #include "core/constants.h"
 #include "core/installer.hpp"
@@ -15,9 +16,11 @@
 #include "core/workspace.hpp"
 #include "core/workspace_utils.hpp"
 
+#include <algorithm>
 #include <cstring>
 #include <filesystem>
 #include <string>
+#include <vector>
 
 /**
  * @brief Handle the 'install' command: install the current project or specified
@@ -48,6 +51,8 @@ cforge_int_t cforge_cmd_install(const cforge_context_t *ctx) {
   std::string build_config;
   std::string env_var;
   bool skip_build = false;
+  cforge::cmake_install_options cmake_options;
+  bool use_cmake_install = false;
 
   if (ctx->args.args) {
     for (cforge_int_t i = 0; i < ctx->args.arg_count; ++i) {
@@ -79,6 +84,23 @@ cforge_int_t cforge_cmd_install(const cforge_context_t *ctx) {
       } else if ((arg == "--name" || arg == "-n") && i + 1 < ctx->args.arg_count) {
         project_name_override = ctx->args.args[++i];
         cforge::logger::print_action("Name", project_name_override);
+      } else if (arg == "--prefix" && i + 1 < ctx->args.arg_count) {
+        cmake_options.prefix = ctx->args.args[++i];
+        use_cmake_install    = true;
+        cforge::logger::print_action("Prefix", cmake_options.prefix);
+      } else if (arg == "--destdir" && i + 1 < ctx->args.arg_count) {
+        cmake_options.destdir = ctx->args.args[++i];
+        use_cmake_install     = true;
+        cforge::logger::print_action("Staging", cmake_options.destdir);
+      } else if (arg == "--component" && i + 1 < ctx->args.arg_count) {
+        cmake_options.component = ctx->args.args[++i];
+        use_cmake_install       = true;
+      } else if (arg == "--strip") {
+        cmake_options.strip = true;
+        use_cmake_install   = true;
+      } else if (arg == "--dry-run") {
+        cmake_options.dry_run = true;
+        use_cmake_install     = true;
       } else if (arg == "--env" && i + 1 < ctx->args.arg_count) {
         // Set environment variable name for installation
         env_var = ctx->args.args[++i];
@@ -111,6 +133,98 @@ cforge_int_t cforge_cmd_install(const cforge_context_t *ctx) {
     source_path = workspace_root;
   }
 
+  // Component names match the COMPONENT of the generated install() rules
+  if (!cmake_options.component.empty()) {
+    static const std::vector<std::string> components = {
+        "Runtime", "Headers", "Resources", "Debug"};
+    auto it = std::find_if(components.begin(), components.end(), [&](const std::string &c) {
+      return cforge::string_to_lower(c) == cforge::string_to_lower(cmake_options.component);
+    });
+    if (it == components.end()) {
+      cforge::logger::print_error("Unknown install component '" + cmake_options.component + "'");
+      cforge::logger::print_hint("expected one of: Runtime, Headers, Resources, Debug");
+      return 1;
+    }
+    cmake_options.component = *it;
+  }
+
+  // Builds the project or workspace through 'cforge build'
+  auto run_build = [&](const std::filesystem::path &dir) -> cforge_int_t {
+    cforge_context_t build_ctx;
+    memset(&build_ctx, 0, sizeof(build_ctx));
+    snprintf(build_ctx.working_dir, sizeof(build_ctx.working_dir), "%s", dir.string().c_str());
+    build_ctx.args.command = strdup("build");
+    if (!build_config.empty()) {
+      build_ctx.args.config = strdup(build_config.c_str());
+    }
+    if (cforge::logger::get_verbosity() == cforge::log_verbosity::VERBOSITY_VERBOSE) {
+      build_ctx.args.verbosity = strdup("verbose");
+    }
+    cforge_int_t build_res = cforge_cmd_build(&build_ctx);
+    free((cforge_pointer_t)build_ctx.args.command);
+    if (build_ctx.args.config) {
+      free((cforge_pointer_t)build_ctx.args.config);
+    }
+    if (build_ctx.args.verbosity) {
+      free((cforge_pointer_t)build_ctx.args.verbosity);
+    }
+    return build_res;
+  };
+
+  // --prefix/--destdir/--component/--strip/--dry-run run the generated
+  // CMake install rules instead of copying build artifacts
+  if (use_cmake_install) {
+    if (!std::filesystem::exists(source_path / CFORGE_FILE)
+        && !std::filesystem::exists(source_path / WORKSPACE_FILE)) {
+      cforge::logger::print_error("--prefix, --destdir, --component, --strip and --dry-run "
+                                  "need a local project or workspace");
+      return 1;
+    }
+
+    cforge::toml_reader source_cfg;
+    bool has_cfg = source_cfg.load((source_path / CFORGE_FILE).string());
+    std::string config = cforge::get_build_config(
+        build_config.empty() ? nullptr : build_config.c_str(),
+        0,
+        nullptr,
+        has_cfg ? &source_cfg : nullptr);
+    if (has_cfg) {
+      cmake_options.project =
+          source_cfg.get_string("project.name", source_cfg.get_string("workspace.name", ""));
+      cmake_options.version = source_cfg.get_string("project.version", "");
+    }
+    if (cmake_options.project.empty()) {
+      cmake_options.project = source_path.filename().string();
+    }
+
+    if (!skip_build) {
+      cforge::logger::print_action("Building", "before installation");
+      cforge_int_t build_res = run_build(source_path);
+      if (build_res != 0) {
+        cforge::logger::print_error("Build failed");
+        return build_res;
+      }
+    }
+
+    // Workspaces build in the base directory; projects use the per-config one
+    std::filesystem::path build_dir;
+    if (is_workspace) {
+      build_dir = cforge::get_build_base_dir(source_path, nullptr);
+    } else {
+      build_dir = cforge::get_build_dir_for_config(
+          cforge::get_build_base_dir(source_path, has_cfg ? &source_cfg : nullptr).string(),
+          config,
+          false);
+    }
+
+    if (!installer_instance.install_with_cmake(build_dir, config, cmake_options)) {
+      cforge::logger::print_error(cmake_options.dry_run ? "Install dry run failed"
+                                                        : "Project installation failed");
+      return 1;
+    }
+    return 0;
+  }
+
   // Helper to install a single project path
   auto install_proj = [&](const std::string &proj_dir) {
     installer_instance.install_project(proj_dir,
@@ -126,25 +240,7 @@ cforge_int_t cforge_cmd_install(const cforge_context_t *ctx) {
     if (!skip_build) {
       cforge::logger::print_action("Building", "workspace before installation");
       // Build the workspace
-      cforge_context_t build_ctx;
-      memset(&build_ctx, 0, sizeof(build_ctx));
-      // Use same working dir and config
-      snprintf(build_ctx.working_dir, sizeof(build_ctx.working_dir), "%s", ctx->working_dir);
-      build_ctx.args.command = strdup("build");
-      if (!build_config.empty()) {
-        build_ctx.args.config = strdup(build_config.c_str());
-      }
-      if (cforge::logger::get_verbosity() == cforge::log_verbosity::VERBOSITY_VERBOSE) {
-        build_ctx.args.verbosity = strdup("verbose");
-      }
-      cforge_int_t build_res = cforge_cmd_build(&build_ctx);
-      free((cforge_pointer_t)build_ctx.args.command);
-      if (build_ctx.args.config) {
-        free((cforge_pointer_t)build_ctx.args.config);
-      }
-      if (build_ctx.args.verbosity) {
-        free((cforge_pointer_t)build_ctx.args.verbosity);
-      }
+      cforge_int_t build_res = run_build(ctx->working_dir);
       if (build_res != 0) {
         cforge::logger::print_error("Workspace build failed");
         return build_res;
@@ -5,9 +5,13 @@
 
 #include "cforge/log.hpp"
 
+#include "core/build_utils.hpp"
+#include "core/build_variants.hpp"
+#include "core/cmake_file_api.hpp"
 #include "core/commands.hpp"
 #include "core/constants.h"
 #include "core/process_utils.hpp"
+#include "core/script_runner.hpp"
 #include "core/toml_reader.hpp"
 #include "core/workspace.hpp"
 
@@ -20,13 +24,53 @@
 /**
  * @brief Lists available build configurations
  */
-static void list_build_configs() {
+static void list_build_configs(const std::filesystem::path &working_dir) {
   cforge::logger::print_section("Available build configurations:");
   cforge::logger::print_list_item("Debug        (Development with debug symbols)");
   cforge::logger::print_list_item("Release      (Optimized release build)");
   cforge::logger::print_list_item("RelWithDebInfo (Release with debug information)");
   cforge::logger::print_list_item("MinSizeRel   (Minimal size release build)");
   cforge::logger::print_blank();
+
+  // Configurations and settings of the project in the current directory,
+  // including what it inherits from its workspace's [defaults]
+  std::filesystem::path toml_path = working_dir / CFORGE_FILE;
+  if (!std::filesystem::exists(toml_path)) {
+    return;
+  }
+  try {
+    toml::table config                 = toml::parse_file(toml_path.string());
+    std::vector<std::string> inherited = cforge::apply_workspace_defaults(config, toml_path);
+
+    auto from_workspace = [&](const std::string &key) {
+      return std::any_of(inherited.begin(), inherited.end(), [&](const std::string &entry) {
+        return entry == key || entry.rfind(key + ".", 0) == 0 || entry.rfind(key + " ", 0) == 0;
+      });
+    };
+
+    if (const toml::table *configs = config["build"]["config"].as_table()) {
+      cforge::logger::print_section("Project configurations:");
+      for (const auto &[key, value] : *configs) {
+        std::string name(key.str());
+        std::string item = name;
+        if (from_workspace("build.config." + name)) {
+          item += "  (workspace defaults)";
+        }
+        cforge::logger::print_list_item(item);
+      }
+      cforge::logger::print_blank();
+    }
+
+    if (!inherited.empty()) {
+      cforge::logger::print_section("Inherited from workspace defaults:");
+      for (const auto &key : inherited) {
+        cforge::logger::print_list_item(key);
+      }
+      cforge::logger::print_blank();
+    }
+  } catch (const toml::parse_error &) {
+    // Not a usable project configuration; the built-in list is still valid
+  }
 }
 
 /**
@@ -101,6 +145,244 @@ static void list_project_settings() {
   cforge::logger::print_blank();
 }
 
+/**
+ * @brief Describe how far a workspace project has been built for a config
+ *
+ * The project may have been built on its own or as part of a workspace
+ * build, so both build directories are checked. A directory whose
+ * CMakeCache.txt matches the configuration counts as configured; the project
+ * counts as built once every artifact the CMake file API lists for its target
+ * exists.
+ *
+ * @param workspace_dir Workspace directory
+ * @param project Workspace project
+ * @param config Build configuration
+ * @return "built", "configured" or "not built"
+ */
+static std::string project_build_status(const std::filesystem::path &workspace_dir,
+                                        const cforge::workspace_project &project,
+                                        const std::string &config) {
+  cforge::toml_reader project_config;
+  bool has_config = project_config.load((project.path / CFORGE_FILE).string());
+
+  std::vector<std::filesystem::path> build_dirs = {
+      cforge::get_build_base_dir(project.path, has_config ? &project_config : nullptr),
+      cforge::get_build_base_dir(workspace_dir, nullptr),
+  };
+
+  bool configured = false;
+  for (const auto &base : build_dirs) {
+    std::filesystem::path build_dir =
+        cforge::get_build_dir_for_config(base.string(), config, false);
+    if (!cforge::is_build_dir_configured_for(build_dir, config)) {
+      continue;
+    }
+    configured = true;
+
+    auto targets = cforge::read_cmake_file_api_targets(build_dir, config);
+    if (!targets) {
+      continue;
+    }
+    for (const auto &target : *targets) {
+      if (target.name != project.name) {
+        continue;
+      }
+      bool built = std::all_of(
+          target.artifacts.begin(), target.artifacts.end(), [](const std::filesystem::path &a) {
+            return std::filesystem::exists(a);
+          });
+      if (built) {
+        return "built";
+      }
+    }
+  }
+  return configured ? "configured" : "not built";
+}
+
+/**
+ * @brief Lists workspace projects with their type, build status and dependencies
+ *
+ * @param ctx Context containing parsed arguments
+ * @return cforge_int_t Exit code (0 for success)
+ */
+static cforge_int_t list_workspace_projects(const cforge_context_t *ctx) {
+  auto [is_workspace, workspace_dir] = cforge::is_in_workspace(ctx->working_dir);
+  if (!is_workspace) {
+    cforge::logger::print_error("Not in a workspace");
+    return 1;
+  }
+  cforge::workspace ws;
+  if (!ws.load(workspace_dir)) {
+    cforge::logger::print_error("Failed to load workspace configuration");
+    return 1;
+  }
+
+  // Status is reported for -c/--config, else the workspace's build type
+  std::string config;
+  for (cforge_int_t i = 1; i < ctx->args.arg_count; ++i) {
+    std::string arg = ctx->args.args[i];
+    if ((arg == "-c" || arg == "--config") && i + 1 < ctx->args.arg_count) {
+      config = ctx->args.args[++i];
+    } else if (arg.rfind("--config=", 0) == 0) {
+      config = arg.substr(9);
+    }
+  }
+  if (config.empty()) {
+    cforge::toml_reader workspace_config;
+    std::filesystem::path workspace_file = cforge::get_workspace_config_path(workspace_dir);
+    config = workspace_config.load(workspace_file.string())
+               ? workspace_config.get_string("workspace.build_type", "Debug")
+               : "Debug";
+  }
+
+  std::string startup = ws.get_startup_project().name;
+
+  cforge::logger::print_section("Workspace projects (" + config + "):");
+  std::vector<int> widths = {24, 14, 20, 30};
+  cforge::logger::print_table_header({"Project", "Type", "Status", "Depends on"}, widths, 2);
+  for (const auto &project : ws.get_projects()) {
+    cforge::toml_reader project_config;
+    std::string type = "executable";
+    if (project_config.load((project.path / CFORGE_FILE).string())) {
+      type = project_config.get_string("project.binary_type", type);
+    }
+
+    std::string name = project.name;
+    if (!startup.empty() && startup == project.name) {
+      name += " *";
+    }
+
+    std::string deps;
+    for (const auto &dep : project.dependencies) {
+      deps += (deps.empty() ? "" : ", ") + dep;
+    }
+
+    std::string project_config_name = ws.get_project_config(project.name, config);
+    std::string status              = project_build_status(
+        workspace_dir, project, project_config_name);
+    if (project_config_name != config) {
+      status += " (" + project_config_name + ")";
+    }
+    cforge::logger::print_table_row({name, type, status, deps.empty() ? "-" : deps}, widths, 2);
+  }
+  cforge::logger::print_blank();
+  if (!startup.empty()) {
+    cforge::logger::print_dim("* startup project", 2);
+    cforge::logger::print_blank();
+  }
+  return 0;
+}
+
+/**
+ * @brief Lists named scripts with their prerequisite chains, and build hooks
+ *
+ * @param ctx Context containing parsed arguments
+ * @return cforge_int_t Exit code (0 for success)
+ */
+static cforge_int_t list_scripts(const cforge_context_t *ctx) {
+  std::filesystem::path config_path =
+      cforge::get_script_config_path(ctx->working_dir, ctx->is_workspace);
+  toml::table config;
+  try {
+    config = toml::parse_file(config_path.string());
+  } catch (...) {
+    cforge::logger::print_error("Failed to load configuration: " + config_path.string());
+    return 1;
+  }
+
+  auto scripts = cforge::read_named_scripts(config);
+  cforge::logger::print_section("Scripts:");
+  if (scripts.empty()) {
+    cforge::logger::print_dim("none defined in [scripts.scripts]", 2);
+  }
+  for (const auto &[name, script] : scripts) {
+    cforge::logger::print_kv(name, script.command.empty() ? "-" : script.command);
+    if (script.needs.empty()) {
+      continue;
+    }
+    auto order = cforge::resolve_script_order(scripts, name);
+    if (order.is_error()) {
+      cforge::logger::print_dim("error: " + order.error().message, 4);
+      continue;
+    }
+    std::string chain;
+    for (const auto &step : *order) {
+      chain += (chain.empty() ? "" : " -> ") + step;
+    }
+    cforge::logger::print_dim("runs: " + chain, 4);
+  }
+  cforge::logger::print_blank();
+
+  bool has_hooks = false;
+  for (cforge_int_t i = static_cast<cforge_int_t>(cforge::script_phase::PRE_BUILD);
+       i <= static_cast<cforge_int_t>(cforge::script_phase::POST_INSTALL);
+       ++i) {
+    auto phase = static_cast<cforge::script_phase>(i);
+    auto hooks = cforge::read_hooks(config, cforge::phase_to_key(phase), true);
+    auto shell = cforge::read_hooks(config, cforge::phase_to_hook_key(phase), false);
+    hooks.insert(hooks.end(), shell.begin(), shell.end());
+    if (hooks.empty()) {
+      continue;
+    }
+    if (!has_hooks) {
+      cforge::logger::print_section("Hooks:");
+      has_hooks = true;
+    }
+    cforge::logger::print_plain("  " + cforge::phase_to_name(phase) + ":");
+    for (const auto &hook : hooks) {
+      cforge::logger::print_list_item(hook.command.empty() ? hook.script : hook.command, "-", 4);
+    }
+  }
+  if (has_hooks) {
+    cforge::logger::print_blank();
+  }
+  return 0;
+}
+
+/**
+ * @brief Lists build variants with their settings, merged with what they inherit
+ *
+ * @param ctx Context containing parsed arguments
+ * @return cforge_int_t Exit code (0 for success)
+ */
+static cforge_int_t list_variants(const cforge_context_t *ctx) {
+  std::filesystem::path config_path = std::filesystem::path(ctx->working_dir) / CFORGE_FILE;
+  cforge::toml_reader config;
+  if (!std::filesystem::exists(config_path) || !config.load(config_path.string())) {
+    cforge::logger::print_error("Failed to load configuration: " + config_path.string());
+    return 1;
+  }
+
+  auto variants               = cforge::read_build_variants(config);
+  std::string default_variant = config.get_string("variants.default");
+  cforge::logger::print_section("Build variants:");
+  if (variants.empty()) {
+    cforge::logger::print_dim("none defined in [variants.variants]", 2);
+  }
+  for (const auto &[name, variant] : variants) {
+    cforge::logger::print_kv(name + (name == default_variant ? " (default)" : ""),
+                             variant.description.empty() ? "-" : variant.description);
+    std::string error;
+    cforge::build_variant resolved = cforge::resolve_build_variant(variants, name, error);
+    if (!error.empty()) {
+      cforge::logger::print_dim("error: " + error, 4);
+      continue;
+    }
+    if (!variant.inherits.empty()) {
+      std::string inherits;
+      for (const auto &parent : variant.inherits) {
+        inherits += (inherits.empty() ? "" : ", ") + parent;
+      }
+      cforge::logger::print_dim("inherits: " + inherits, 4);
+    }
+    for (const auto &line : cforge::describe_build_variant(resolved)) {
+      cforge::logger::print_dim(line, 4);
+    }
+  }
+  cforge::logger::print_blank();
+  return 0;
+}
+
 /**
  * @brief Handle the 'list' command
  *
@@ -120,7 +402,7 @@ cforge_int_t cforge_cmd_list(const cforge_context_t *ctx) {
   // If category is specified, list only that category
   if (!category.empty()) {
     if (category == "configs" || category == "configurations") {
-      list_build_configs();
+      list_build_configs(ctx->working_dir);
     } else if (category == "generators" || category == "ides") {
       list_generators();
     } else if (category == "targets") {
@@ -130,6 +412,8 @@ cforge_int_t cforge_cmd_list(const cforge_context_t *ctx) {
     } else if (category == "settings") {
       list_project_settings();
     } else if (category == "projects") {
+      return list_workspace_projects(ctx);
+    } else if (category == "order" || category == "build-order") {
       if (!ctx->is_workspace) {
         cforge::logger::print_error("Not in a workspace");
         return 1;
@@ -139,24 +423,9 @@ cforge_int_t cforge_cmd_list(const cforge_context_t *ctx) {
         cforge::logger::print_error("Failed to load workspace configuration");
         return 1;
       }
-      cforge::logger::print_section("Workspace projects:");
-      for (const auto &proj : ws.get_projects()) {
-        std::string proj_info = proj.name;
-        if (proj.is_startup_project) {
-          proj_info += " (startup)";
-        }
-        proj_info += " (" + proj.path.string() + ")";
-        cforge::logger::print_list_item(proj_info);
-      }
-      cforge::logger::print_blank();
-    } else if (category == "order" || category == "build-order") {
-      if (!ctx->is_workspace) {
-        cforge::logger::print_error("Not in a workspace");
-        return 1;
-      }
-      cforge::workspace ws;
-      if (!ws.load(ctx->working_dir)) {
-        cforge::logger::print_error("Failed to load workspace configuration");
+      auto graph = ws.check_dependency_graph();
+      if (graph.is_error()) {
+        graph.error().print();
         return 1;
       }
       cforge::logger::print_section("Workspace build order:");
@@ -250,40 +519,20 @@ cforge_int_t cforge_cmd_list(const cforge_context_t *ctx) {
       }
       cforge::logger::print_blank();
     } else if (category == "scripts") {
-      // List configured scripts
-      std::filesystem::path toml_path = std::filesystem::path(ctx->working_dir)
-                                      / (ctx->is_workspace ? WORKSPACE_FILE : CFORGE_FILE);
-      cforge::toml_reader cfg;
-      if (!cfg.load(toml_path.string())) {
-        cforge::logger::print_error("Failed to load configuration: " + toml_path.string());
-        return 1;
-      }
-      cforge::logger::print_section("Configured scripts:");
-      if (cfg.has_key("scripts.pre_build")) {
-        auto scripts = cfg.get_string_array("scripts.pre_build");
-        cforge::logger::print_plain("  pre_build:");
-        for (const auto &s : scripts) {
-          cforge::logger::print_list_item(s, "-", 4);
-        }
-      }
-      if (cfg.has_key("scripts.post_build")) {
-        auto scripts = cfg.get_string_array("scripts.post_build");
-        cforge::logger::print_plain("  post_build:");
-        for (const auto &s : scripts) {
-          cforge::logger::print_list_item(s, "-", 4);
-        }
-      }
-      cforge::logger::print_blank();
+      return list_scripts(ctx);
+    } else if (category == "variants") {
+      return list_variants(ctx);
     } else {
       cforge::logger::print_error("Unknown list category: " + category);
       cforge::logger::print_plain("Available categories: configs, generators, targets, commands, "
-                                  "settings, projects, order, dependencies, graph, scripts");
+                                  "settings, projects, order, dependencies, graph, scripts, "
+                                  "variants");
       return 1;
     }
   } else {
     // List all categories
     list_commands();
-    list_build_configs();
+    list_build_configs(ctx->working_dir);
     list_generators();
     list_build_targets();
     list_project_settings();
@@ -184,7 +184,8 @@ cforge_int_t cforge_cmd_lock(const cforge_context_t *ctx) {
 
     for (const auto &key : dep_keys) {
       // Skip config keys
-      if (key == "fetch_content" || key == "directory" || key == "git" || key == "vcpkg") {
+      if (key == "fetch_content" || key == "directory" || key == "git" || key == "vcpkg"
+          || key == "archive" || key == "fetchcontent") {
         continue;
       }
 
@@ -0,0 +1,90 @@
+/**
+ * @file command_log.cpp
+ * @brief Implementation of the 'log' command to print the raw output of the
+ *        last configure or build
+ */
+
+#include "cforge/log.hpp"
+
+#include "core/build_log.hpp"
+#include "core/build_utils.hpp"
+#include "core/command_registry.hpp"
+#include "core/commands.hpp"
+#include "core/constants.h"
+#include "core/toml_reader.hpp"
+#include "core/types.h"
+
+#include <filesystem>
+#include <fstream>
+#include <sstream>
+#include <string>
+
+namespace fs = std::filesystem;
+
+/**
+ * @brief Handle the 'log' command
+ *
+ * @param ctx Context containing parsed arguments
+ * @return cforge_int_t Exit code (0 for success)
+ */
+cforge_int_t cforge_cmd_log(const cforge_context_t *ctx) {
+  std::string kind;
+  bool path_only     = false;
+  cforge_int_t lines = 0;
+  for (cforge_int_t i = 0; i < ctx->args.arg_count; ++i) {
+    std::string arg = ctx->args.args[i];
+    if (arg == "-h" || arg == "--help") {
+      cforge::command_registry::instance().print_command_help("log");
+      return 0;
+    } else if (arg == "--configure") {
+      kind = "configure";
+    } else if (arg == "--build") {
+      kind = "build";
+    } else if (arg == "--path") {
+      path_only = true;
+    } else if ((arg == "-n" || arg == "--lines") && i + 1 < ctx->args.arg_count) {
+      try {
+        lines = std::stoi(ctx->args.args[++i]);
+      } catch (...) {
+        cforge::logger::print_error("--lines needs a number");
+        return 1;
+      }
+    }
+  }
+
+  // The project (or workspace) whose build directory holds the logs
+  fs::path start       = fs::absolute(ctx->working_dir);
+  fs::path project_dir = start;
+  for (auto p = start;; p = p.parent_path()) {
+    if (fs::exists(p / CFORGE_FILE)) {
+      project_dir = p;
+      break;
+    }
+    if (p == p.parent_path()) {
+      break;
+    }
+  }
+  cforge::toml_reader config;
+  bool has_config    = config.load((project_dir / CFORGE_FILE).string());
+  fs::path build_dir = cforge::get_build_base_dir(project_dir, has_config ? &config : nullptr);
+
+  fs::path log = cforge::find_latest_build_log(build_dir, kind);
+  if (log.empty()) {
+    cforge::logger::print_warning("No " + (kind.empty() ? std::string("build") : kind)
+                                  + " logs in " + cforge::build_log_dir(build_dir).string());
+    cforge::logger::print_hint("logs are written by 'cforge build'");
+    return 1;
+  }
+  if (path_only) {
+    cforge::logger::print_plain(log.string());
+    return 0;
+  }
+
+  std::ifstream in(log, std::ios::binary);
+  std::stringstream contents;
+  contents << in.rdbuf();
+  cforge::logger::print_action("Log", log.string());
+  cforge::logger::print_plain(lines > 0 ? cforge::last_lines(contents.str(), lines)
+                                        : contents.str());
+  return 0;
+}
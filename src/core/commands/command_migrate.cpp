@@ -6,6 +6,7 @@
 
 #include "cforge/log.hpp"
 
+#include "core/cmake_import.hpp"
 #include "core/cmake_parser.hpp"
 #include "core/command_registry.hpp"
 #include "core/commands.hpp"
@@ -13,180 +14,84 @@
 
 #include <filesystem>
 #include <fstream>
-#include <sstream>
 #include <string>
 #include <vector>
 
 // ============================================================================
-// TOML generation helpers
+// cforge init --from-cmake
 // ============================================================================
 
-/// Quote a string for TOML output.
-static std::string toml_quote(const std::string &s) {
-  return "\"" + s + "\"";
-}
-
-/// Format a TOML array of strings on one line.
-static std::string toml_array(const std::vector<std::string> &items) {
-  std::string result = "[";
-  for (size_t i = 0; i < items.size(); ++i) {
-    if (i > 0) {
-      result += ", ";
-    }
-    result += toml_quote(items[i]);
-  }
-  result += "]";
-  return result;
-}
-
-/// Generate the cforge.toml content from parse results and return as string.
-static std::string generate_cforge_toml(const cforge::cmake_parse_result &r) {
-  std::ostringstream out;
-
-  // -------------------------------------------------------------------------
-  // [project]
-  // -------------------------------------------------------------------------
-  out << "[project]\n";
-  out << "name = " << toml_quote(r.project_name.empty() ? "unknown" : r.project_name) << "\n";
-
-  if (!r.version.empty()) {
-    out << "version = " << toml_quote(r.version) << "\n";
-  }
-
-  out << "description = \"\"\n";
-
-  if (!r.cpp_standard.empty()) {
-    out << "cpp_standard = " << toml_quote(r.cpp_standard) << "\n";
-  }
-
-  if (!r.c_standard.empty()) {
-    out << "c_standard = " << toml_quote(r.c_standard) << "\n";
-  }
-
-  if (!r.binary_type.empty()) {
-    out << "binary_type = " << toml_quote(r.binary_type) << "\n";
-  }
+namespace cforge {
 
-  out << "\n";
-
-  // -------------------------------------------------------------------------
-  // [build]
-  // -------------------------------------------------------------------------
-  bool has_build_content = !r.source_dirs.empty() || !r.include_dirs.empty();
-  if (has_build_content) {
-    out << "[build]\n";
-
-    if (!r.source_dirs.empty()) {
-      out << "source_dirs = " << toml_array(r.source_dirs) << "\n";
-    }
-
-    if (!r.include_dirs.empty()) {
-      out << "include_dirs = " << toml_array(r.include_dirs) << "\n";
-    }
+/**
+ * @brief Import the CMake project in `project_dir` as a cforge project
+ *
+ * Writes cforge.toml from the top-level CMakeLists.txt, which is kept as
+ * CMakeLists.imported.txt since cforge generates its own CMakeLists.txt on
+ * every build. Prints what was and wasn't translated.
+ *
+ * @param project_dir Directory with the CMakeLists.txt
+ * @param overwrite Replace an existing cforge.toml
+ * @return Exit code (0 for success)
+ */
+cforge_int_t import_cmake_project(const std::filesystem::path &project_dir, bool overwrite) {
+  std::filesystem::path cmake_path    = project_dir / "CMakeLists.txt";
+  std::filesystem::path imported_path = project_dir / "CMakeLists.imported.txt";
+  std::filesystem::path manifest_path = project_dir / "cforge.toml";
 
-    out << "\n";
+  if (!std::filesystem::exists(cmake_path)) {
+    logger::print_error("CMakeLists.txt not found in " + project_dir.string());
+    return 1;
   }
-
-  // -------------------------------------------------------------------------
-  // [build.config.debug]
-  // -------------------------------------------------------------------------
-  if (!r.compile_definitions.empty()) {
-    out << "[build.config.debug]\n";
-    out << "defines = " << toml_array(r.compile_definitions) << "\n";
-    out << "\n";
+  if (std::filesystem::exists(manifest_path) && !overwrite) {
+    logger::print_error("cforge.toml already exists in " + project_dir.string());
+    logger::print_hint("pass --overwrite to replace it");
+    return 1;
   }
-
-  // -------------------------------------------------------------------------
-  // [dependencies]
-  // -------------------------------------------------------------------------
-  // Separate find_package deps from FetchContent deps
-  std::vector<const cforge::cmake_dependency *> registry_deps;
-  std::vector<const cforge::cmake_dependency *> git_deps;
-
-  for (const auto &dep : r.dependencies) {
-    if (dep.is_fetch_content) {
-      git_deps.push_back(&dep);
-    } else if (dep.is_find_package) {
-      registry_deps.push_back(&dep);
-    }
-    // add_subdirectory deps are written as comments (no direct cforge mapping)
+  if (std::filesystem::exists(imported_path)) {
+    logger::print_error("CMakeLists.imported.txt already exists in " + project_dir.string());
+    logger::print_hint("move it away first, so the CMakeLists.txt being imported can be kept");
+    return 1;
   }
 
-  bool has_deps_section = !registry_deps.empty();
-  if (has_deps_section) {
-    out << "[dependencies]\n";
+  cmake_parse_result parse_result = parse_cmake_file(cmake_path.string());
+  cmake_import_report report;
+  std::string toml_content = generate_imported_manifest(parse_result, report);
 
-    for (const auto *dep : registry_deps) {
-      std::string ver = dep->version.empty() ? "*" : dep->version;
-      out << dep->name << " = " << toml_quote(ver) << "\n";
-    }
-
-    out << "\n";
+  std::error_code ec;
+  std::filesystem::rename(cmake_path, imported_path, ec);
+  if (ec) {
+    logger::print_error("Failed to rename CMakeLists.txt: " + ec.message());
+    return 1;
   }
-
-  // FetchContent deps as [dependencies.<name>] inline tables
-  for (const auto *dep : git_deps) {
-    out << "[dependencies." << dep->name << "]\n";
-    if (!dep->git_url.empty()) {
-      out << "git = " << toml_quote(dep->git_url) << "\n";
-    }
-    if (!dep->git_tag.empty()) {
-      out << "tag = " << toml_quote(dep->git_tag) << "\n";
-    }
-    out << "\n";
+  std::ofstream out_file(manifest_path);
+  out_file << toml_content;
+  out_file.close();
+  if (!out_file) {
+    logger::print_error("Failed to write " + manifest_path.string());
+    std::filesystem::rename(imported_path, cmake_path, ec);
+    return 1;
   }
 
-  // Subdirectory deps as comments
-  bool has_subdir_deps = false;
-  for (const auto &dep : r.dependencies) {
-    if (dep.is_subdirectory) {
-      has_subdir_deps = true;
-      break;
-    }
+  logger::print_action("Imported", cmake_path.string());
+  for (const auto &[setting, value] : report.translated) {
+    logger::print_kv(setting, value);
   }
-  if (has_subdir_deps) {
-    out << "# add_subdirectory dependencies detected (no automatic cforge "
-           "mapping):\n";
-    for (const auto &dep : r.dependencies) {
-      if (dep.is_subdirectory) {
-        out << "# subdirectory: " << dep.name << "\n";
-      }
+  if (!report.untranslated.empty()) {
+    logger::print_blank();
+    for (const auto &item : report.untranslated) {
+      logger::print_warning(item);
     }
-    out << "\n";
-  }
-
-  // -------------------------------------------------------------------------
-  // Comment blocks for things cforge doesn't map directly
-  // -------------------------------------------------------------------------
-  if (!r.link_libraries.empty()) {
-    out << "# Additional link libraries detected (review and add manually if "
-           "needed):\n";
-    out << "# link_libraries = " << toml_array(r.link_libraries) << "\n";
-    out << "\n";
   }
-
-  if (!r.compile_options.empty()) {
-    out << "# Compiler options detected (add to build.config sections manually "
-           "if needed):\n";
-    out << "# compile_options = " << toml_array(r.compile_options) << "\n";
-    out << "\n";
-  }
-
-  // -------------------------------------------------------------------------
-  // Known limitations footer
-  // -------------------------------------------------------------------------
-  out << "# ---- Migration Notes ----\n";
-  out << "# Variables (${VAR}) were not expanded; paths may contain variable "
-         "references.\n";
-  out << "# Generator expressions ($<...>) were not parsed.\n";
-  out << "# Conditional if()/else() blocks were skipped.\n";
-  out << "# Only the first add_executable/add_library target was captured.\n";
-  out << "# ExternalProject_Add is not handled (only FetchContent_Declare).\n";
-  out << "# Review and adjust this file before running 'cforge build'.\n";
-
-  return out.str();
+  logger::print_blank();
+  logger::created(manifest_path.string());
+  logger::print_action("Renamed", "CMakeLists.txt -> CMakeLists.imported.txt");
+  logger::print_hint("review the TODO comments in cforge.toml, then run 'cforge build'");
+  return 0;
 }
 
+}  // namespace cforge
+
 // ============================================================================
 // cforge_cmd_migrate
 // ============================================================================
@@ -304,19 +209,20 @@ cforge_int_t cforge_cmd_migrate(const cforge_context_t *ctx) {
         + (parse_result.compile_definitions.size() == 1 ? "definition" : "definitions"));
   }
 
-  // Print warnings
-  if (!parse_result.warnings.empty()) {
+  // -------------------------------------------------------------------------
+  // Generate TOML
+  // -------------------------------------------------------------------------
+  cforge::cmake_import_report report;
+  std::string toml_content = cforge::generate_imported_manifest(parse_result, report);
+
+  // Print what needs review
+  if (!report.untranslated.empty()) {
     cforge::logger::print_blank();
-    for (const auto &w : parse_result.warnings) {
+    for (const auto &w : report.untranslated) {
       cforge::logger::print_warning(w);
     }
   }
 
-  // -------------------------------------------------------------------------
-  // Generate TOML
-  // -------------------------------------------------------------------------
-  std::string toml_content = generate_cforge_toml(parse_result);
-
   // -------------------------------------------------------------------------
   // Dry run: print and exit
   // -------------------------------------------------------------------------
@@ -1,11 +1,13 @@
 /**
  * @file command_new.cpp
- * @brief Implementation of the new command for creating files from templates
+ * @brief Implementation of the new command for creating projects and files
+ * from templates
  */
 
 #include "cforge/log.hpp"
 
 #include "core/commands.hpp"
+#include "core/project_templates.hpp"
 #include "core/toml_reader.hpp"
 #include "core/types.h"
 
@@ -13,6 +15,7 @@
 #include <filesystem>
 #include <fstream>
 #include <sstream>
+#include <vector>
 
 namespace fs = std::filesystem;
 
@@ -357,6 +360,64 @@ bool generate_interface(const fs::path &path,
   return true;
 }
 
+/**
+ * @brief Print the embedded project templates
+ */
+void list_project_templates() {
+  cforge::logger::print_help_section("PROJECT TEMPLATES");
+  for (const auto &tmpl : cforge::project_templates()) {
+    cforge::logger::print_subcommand(tmpl.name, tmpl.description, 14);
+  }
+  cforge::logger::print_blank();
+}
+
+/**
+ * @brief Check whether a template name is one of the file templates
+ */
+bool is_file_template(const std::string &name) {
+  static const std::vector<std::string> file_templates = {
+      "class", "header", "struct", "interface", "test", "main"};
+  return std::find(file_templates.begin(), file_templates.end(), name) != file_templates.end();
+}
+
+/**
+ * @brief Create a new project directory from a project template
+ */
+cforge_int_t create_project_from_template(const fs::path &parent_dir,
+                                          const std::string &name,
+                                          const std::string &template_name,
+                                          const std::string &cpp_standard,
+                                          bool with_git,
+                                          bool force) {
+  const cforge::project_template *tmpl = cforge::find_project_template(template_name);
+  if (!tmpl) {
+    cforge::logger::print_error("unknown project template '" + template_name + "'");
+    cforge::logger::print_hint("run 'cforge new --list-templates' to see the available templates");
+    return 1;
+  }
+
+  fs::path dir = parent_dir / fs::u8path(name);
+  std::error_code ec;
+  if (fs::exists(dir) && !fs::is_empty(dir, ec) && !force) {
+    cforge::logger::print_error("directory '" + name + "' already exists and is not empty");
+    cforge::logger::print_hint("use --force to write the template into it anyway");
+    return 1;
+  }
+
+  cforge::logger::creating(name + " (" + tmpl->name + ")");
+  if (!cforge::write_project_template(
+          dir, *tmpl, cforge::make_template_variables(name, cpp_standard), force)) {
+    return 1;
+  }
+  if (with_git) {
+    cforge::init_project_git_repository(dir, false);
+  }
+
+  cforge::logger::finished(name);
+  cforge::logger::print_hint("cd " + name + " && cforge build && cforge test");
+  return 0;
+}
+
 }  // anonymous namespace
 
 /**
@@ -370,7 +431,11 @@ cforge_int_t cforge_cmd_new(const cforge_context_t *ctx) {
   std::string name;
   std::string namespace_name;
   std::string output_dir;
-  bool force = false;
+  std::string project_template;
+  std::string cpp_standard = "17";
+  bool force               = false;
+  bool with_git            = false;
+  bool list_templates      = false;
 
   for (cforge_int_t i = 0; i < ctx->args.arg_count; i++) {
     std::string arg = ctx->args.args[i];
@@ -384,6 +449,20 @@ cforge_int_t cforge_cmd_new(const cforge_context_t *ctx) {
       }
     } else if (arg == "-f" || arg == "--force") {
       force = true;
+    } else if (arg == "-t" || arg == "--template") {
+      if (i + 1 < ctx->args.arg_count) {
+        project_template = ctx->args.args[++i];
+      }
+    } else if (arg.rfind("--template=", 0) == 0) {
+      project_template = arg.substr(11);
+    } else if (arg == "--std") {
+      if (i + 1 < ctx->args.arg_count) {
+        cpp_standard = ctx->args.args[++i];
+      }
+    } else if (arg == "--git") {
+      with_git = true;
+    } else if (arg == "--list-templates") {
+      list_templates = true;
     } else if (template_type.empty()) {
       template_type = arg;
     } else if (name.empty()) {
@@ -391,11 +470,35 @@ cforge_int_t cforge_cmd_new(const cforge_context_t *ctx) {
     }
   }
 
+  if (list_templates) {
+    list_project_templates();
+    return 0;
+  }
+
+  // `cforge new <name>` creates a project; `cforge new <file-template> <name>`
+  // creates files in the current one
+  if (!project_template.empty() || (name.empty() && !template_type.empty()
+                                    && !is_file_template(template_type))) {
+    if (template_type.empty()) {
+      cforge::logger::print_error("Please specify a name for the project");
+      return 1;
+    }
+    return create_project_from_template(project_dir,
+                                        template_type,
+                                        project_template.empty() ? "app" : project_template,
+                                        cpp_standard,
+                                        with_git,
+                                        force);
+  }
+
   if (template_type.empty()) {
-    cforge::logger::print_cmd_header("new", "Create files from templates");
+    cforge::logger::print_cmd_header("new", "Create projects and files from templates");
+    cforge::logger::print_usage("cforge new <name> [--template <project-template>]");
     cforge::logger::print_usage("cforge new <template> <name> [options]");
 
-    cforge::logger::print_help_section("TEMPLATES");
+    list_project_templates();
+
+    cforge::logger::print_help_section("FILE TEMPLATES");
     cforge::logger::print_subcommand("class", "Create a class with header and source files", 12);
     cforge::logger::print_subcommand("header", "Create a header-only file", 12);
     cforge::logger::print_subcommand("struct", "Create a struct header file", 12);
@@ -408,9 +511,16 @@ cforge_int_t cforge_cmd_new(const cforge_context_t *ctx) {
     cforge::logger::print_option("-n, --namespace <name>", "Wrap in namespace");
     cforge::logger::print_option("-o, --output <dir>", "Output directory");
     cforge::logger::print_option("-f, --force", "Overwrite existing files");
+    cforge::logger::print_option("-t, --template <name>", "Project template (default: app)");
+    cforge::logger::print_option("--std <version>", "C++ standard for a new project");
+    cforge::logger::print_option("--git", "Run git init in the new project");
+    cforge::logger::print_option("--list-templates", "List the project templates");
     cforge::logger::print_blank();
 
     cforge::logger::print_help_section("EXAMPLES");
+    cforge::logger::print_example("cforge new my_app", "Create an application project");
+    cforge::logger::print_example("cforge new mylib --template lib --git",
+                                  "Library project with a git repository");
     cforge::logger::print_example("cforge new class MyClass", "Create class files");
     cforge::logger::print_example("cforge new class MyClass -n myproject", "With namespace");
     cforge::logger::print_example("cforge new header utils -o include/myproject",
@@ -6,11 +6,15 @@
 
 #include "cforge/log.hpp"
 
+#include "core/build_config.hpp"
+#include "core/build_utils.hpp"
+#include "core/command.h"
 #include "core/command_registry.hpp"
 #include "core/commands.hpp"
 #include "core/constants.h"
 #include "core/error_format.hpp"
 #include "core/file_system.h"
+#include "core/package_formats.hpp"
 #include "core/process_utils.hpp"
 #include "core/toml_reader.hpp"
 #include "core/types.h"
@@ -696,6 +700,13 @@ static std::vector<std::string> get_default_generators() {
  * @return bool Success flag
  */
 static bool download_and_install_nsis(bool verbose) {
+  if (cforge_is_offline()) {
+    cforge::logger::print_error("NSIS is not installed and can't be downloaded in offline mode");
+    cforge::logger::print_hint("install NSIS from https://nsis.sourceforge.io/Download, or use "
+                               "--type ZIP");
+    return false;
+  }
+
 #ifdef _WIN32
   cforge::logger::print_status("NSIS not found. Attempting to download and install "
                                "NSIS automatically");
@@ -991,14 +1002,19 @@ struct process_result_t {
  * @param verbose Verbose flag
  * @param project_name Project name
  * @param project_version Project version
+ * @param output_dir Directory for the packages; defaults to packages/ next to
+ * the build directory
+ * @param variables Extra `NAME=value` CPack variables
  * @return bool Success flag
  */
 static bool run_cpack(const std::filesystem::path &build_dir,
                       const std::vector<std::string> &generators,
                       const std::string &config_name,
                       bool verbose,
-                      const std::string &project_name    = "",
-                      const std::string &project_version = "") {
+                      const std::string &project_name           = "",
+                      const std::string &project_version        = "",
+                      const std::filesystem::path &output_dir   = "",
+                      const std::vector<std::string> &variables = {}) {
   // Find cpack executable
   std::string cpack_command = find_cpack_path();
 
@@ -1130,7 +1146,8 @@ static bool run_cpack(const std::filesystem::path &build_dir,
   }
 
   // Specify a simpler package output path
-  std::filesystem::path package_dir = build_dir.parent_path() / "packages";
+  std::filesystem::path package_dir =
+      output_dir.empty() ? build_dir.parent_path() / "packages" : output_dir;
 
   // Create the package directory if it doesn't exist
   if (!std::filesystem::exists(package_dir)) {
@@ -1143,9 +1160,6 @@ static bool run_cpack(const std::filesystem::path &build_dir,
 
   // IMPORTANT: Clean up any existing packages with the same base name
   try {
-    std::string config_lower = config_name;
-    std::transform(config_lower.begin(), config_lower.end(), config_lower.begin(), ::tolower);
-
     // Get project info for cleanup
     std::string pkg_name    = project_name;
     std::string pkg_version = project_version;
@@ -1187,30 +1201,22 @@ static bool run_cpack(const std::filesystem::path &build_dir,
       pkg_version = "1.0.0";
     }
 
-    // First cleanup ALL packages and stray executables in the output directory
-    cforge::logger::print_verbose("Cleaning package directory: " + package_dir.string());
+    // Remove earlier packages of this configuration, including CPack's
+    // per-component ones; output_dir may hold unrelated files, so nothing
+    // else is touched
+    std::string stem = cforge::package_file_stem(pkg_name, pkg_version, config_name);
+    cforge::logger::print_verbose("Cleaning " + stem + " packages from: " + package_dir.string());
     for (const auto &entry : std::filesystem::directory_iterator(package_dir)) {
-      if (entry.is_regular_file()) {
-        std::string filename = entry.path().filename().string();
-        std::string ext      = entry.path().extension().string();
-
-        // Remove files matching the project name (old packages)
-        if (filename.find(pkg_name) != std::string::npos) {
-          cforge::logger::print_verbose("Removing existing package file: " + entry.path().string());
+      if (!entry.is_regular_file()) {
+        continue;
+      }
+      std::string filename = entry.path().filename().string();
+      for (const auto &gen : generators) {
+        if (cforge::is_package_file(filename, stem, gen)) {
+          cforge::logger::print_verbose("Removing existing package file: "
+                                        + entry.path().string());
           std::filesystem::remove(entry.path());
-        }
-        // Also remove any stray executables that don't match the project name
-        // These can be leftover from tests or other builds
-        else if (ext == ".exe" || ext == "") {
-          // Check if it's actually an executable (not a package archive)
-          std::string ext_lower = ext;
-          std::transform(ext_lower.begin(), ext_lower.end(), ext_lower.begin(), ::tolower);
-          if (ext_lower != ".zip" && ext_lower != ".tar" && ext_lower != ".gz"
-              && ext_lower != ".deb" && ext_lower != ".rpm" && ext_lower != ".msi"
-              && ext_lower != ".nupkg") {
-            cforge::logger::print_verbose("Removing stray executable: " + entry.path().string());
-            std::filesystem::remove(entry.path());
-          }
+          break;
         }
       }
     }
@@ -1234,9 +1240,6 @@ static bool run_cpack(const std::filesystem::path &build_dir,
 
   // Add config to package filename - use proper format
   if (!config_name.empty()) {
-    std::string config_lower = config_name;
-    std::transform(config_lower.begin(), config_lower.end(), config_lower.begin(), ::tolower);
-
     // Use project name/version from arguments if provided
     std::string pkg_name    = project_name;
     std::string pkg_version = project_version;
@@ -1280,42 +1283,21 @@ static bool run_cpack(const std::filesystem::path &build_dir,
 
     // Direct file name pattern instead of using placeholders (use dynamic
     // system name)
-    std::string system_name;
-#ifdef _WIN32
-    system_name = "win64";
-#elif defined(__APPLE__)
-    system_name = "macos";
-#else
-    system_name = "linux";
-#endif
-    std::string package_file_name = pkg_name + "-" + pkg_version + "-" + system_name + "-"
-                                  + config_lower;
+    std::string package_file_name = cforge::package_file_stem(pkg_name, pkg_version, config_name);
     cforge::logger::print_verbose("Package file name: " + package_file_name);
 
     // Clean up existing packages with the same name to prevent "exists" errors
     try {
       cforge::logger::print_verbose("Cleaning up any existing packages with the same name pattern");
       for (const auto &gen : generators) {
-        std::string gen_lower = gen;
-        std::transform(gen_lower.begin(), gen_lower.end(), gen_lower.begin(), ::tolower);
-
-        // Construct common package filenames to check
-        std::vector<std::string> patterns_to_check = {package_file_name + ".zip",
-                                                      package_file_name + ".exe",
-                                                      package_file_name + ".msi",
-                                                      package_file_name + ".deb",
-                                                      package_file_name + ".rpm",
-                                                      package_file_name + ".dmg",
-                                                      package_file_name + ".tar.gz",
-                                                      package_file_name + ".tar.bz2",
-                                                      package_file_name + ".7z"};
-
-        for (const auto &pattern : patterns_to_check) {
-          std::filesystem::path file_to_check = package_dir / pattern;
-          if (std::filesystem::exists(file_to_check)) {
-            cforge::logger::print_verbose("Removing existing package: " + file_to_check.string());
-            std::filesystem::remove(file_to_check);
-          }
+        std::string extension = cforge::package_file_extension(gen);
+        if (extension.empty()) {
+          continue;
+        }
+        std::filesystem::path file_to_check = package_dir / (package_file_name + extension);
+        if (std::filesystem::exists(file_to_check)) {
+          cforge::logger::print_verbose("Removing existing package: " + file_to_check.string());
+          std::filesystem::remove(file_to_check);
         }
       }
     } catch (const std::exception &ex) {
@@ -1430,6 +1412,12 @@ static bool run_cpack(const std::filesystem::path &build_dir,
   cpack_args.push_back("CPACK_PACKAGE_IGNORE_FILES=CMakeFiles;_CPack_Packages;"
                        "recipe;obj;ilk;pdb;vcxproj;sln");
 
+  // Per-format settings from [package.<format>]
+  for (const auto &variable : variables) {
+    cpack_args.push_back("-D");
+    cpack_args.push_back(variable);
+  }
+
   // Add verbose flag if needed
   if (verbose) {
     cpack_args.push_back("--verbose");
@@ -1955,7 +1943,7 @@ static cforge_int_t move_files_to_directory(
                                 + project_version);
 
   // Get base build directory
-  std::string base_build_dir = project_config.get_string("build.build_dir", "build");
+  std::string base_build_dir = cforge::get_build_base_dir(project_dir, &project_config).string();
   cforge::logger::print_verbose("Base build directory: " + base_build_dir);
 
   // Get the config-specific build directory
@@ -2468,12 +2456,7 @@ cforge_int_t cforge_cmd_package(const cforge_context_t *ctx) {
   }
 
   // Normalize standard config names
-  std::string config_lower = cforge::string_to_lower(config_name);
-  if (config_lower == "debug" || config_lower == "release" || config_lower == "relwithdebinfo"
-      || config_lower == "minsizerel") {
-    config_name    = config_lower;
-    config_name[0] = std::toupper(config_name[0]);
-  }
+  config_name = cforge::canonical_build_config(config_name);
 
   cforge::logger::print_action("Config", config_name);
 
@@ -2491,17 +2474,27 @@ cforge_int_t cforge_cmd_package(const cforge_context_t *ctx) {
   // Get verbose flag
   bool verbose = cforge::logger::get_verbosity() == cforge::log_verbosity::VERBOSITY_VERBOSE;
 
-  // Get generators from command line if specified
+  // Get formats from command line if specified (--format deb,zip); they
+  // replace package.formats
   std::vector<std::string> generators;
   if (ctx->args.args) {
     for (cforge_int_t i = 0; i < ctx->args.arg_count; ++i) {
-      if ((strcmp(ctx->args.args[i], "--type") == 0 || strcmp(ctx->args.args[i], "-t") == 0)
+      std::string arg = ctx->args.args[i];
+      if ((arg == "--format" || arg == "--generator" || arg == "--type" || arg == "-t")
           && i + 1 < ctx->args.arg_count) {
-        std::string gen = ctx->args.args[i + 1];
-        // Uppercase the generator name
-        std::transform(gen.begin(), gen.end(), gen.begin(), ::toupper);
-        generators.push_back(gen);
-        cforge::logger::print_verbose("Using generator from command line: " + gen);
+        std::stringstream formats(ctx->args.args[i + 1]);
+        std::string format;
+        while (std::getline(formats, format, ',')) {
+          std::string gen = cforge::package_generator_for_format(format);
+          if (gen.empty()) {
+            cforge::logger::print_error("Unknown package format '" + format + "'");
+            cforge::logger::print_status("Supported formats: zip, tgz, txz, tbz2, 7z, deb, rpm, "
+                                         "nsis, wix, dmg");
+            return 1;
+          }
+          generators.push_back(gen);
+          cforge::logger::print_verbose("Using generator from command line: " + gen);
+        }
         break;
       }
     }
@@ -2628,18 +2621,26 @@ cforge_int_t cforge_cmd_package(const cforge_context_t *ctx) {
         cforge::logger::print_action("Skipping", "build as requested");
       }
 
-      // If no generators specified, check project
+      // If no generators specified, check project: package.formats, or the
+      // older package.generators
       if (generators.empty()) {
-        generators = project_config.get_string_array("package.generators");
+        std::vector<std::string> formats = project_config.get_string_array("package.formats");
+        if (formats.empty()) {
+          formats = project_config.get_string_array("package.generators");
+        }
 
+        for (const auto &format : formats) {
+          std::string gen = cforge::package_generator_for_format(format);
+          if (gen.empty()) {
+            cforge::logger::print_error("Unknown package format '" + format + "' in "
+                                        + std::string(CFORGE_FILE));
+            return 1;
+          }
+          generators.push_back(gen);
+        }
         if (generators.empty()) {
           // Use default generators for the platform
           generators = get_default_generators();
-        } else {
-          // Make sure all generators are uppercase
-          for (auto &gen : generators) {
-            std::transform(gen.begin(), gen.end(), gen.begin(), ::toupper);
-          }
         }
       }
 
@@ -2670,17 +2671,92 @@ cforge_int_t cforge_cmd_package(const cforge_context_t *ctx) {
       }
 
       // Determine build directory
-      std::string build_dir_name      = project_config.get_string("build.build_dir", "build");
-      std::filesystem::path build_dir = current_dir / build_dir_name;
+      std::filesystem::path build_dir = cforge::get_build_base_dir(current_dir, &project_config);
       std::filesystem::path config_build_dir = get_build_dir_for_config(build_dir.string(),
                                                                         config_name);
 
-      // Run CPack
-      bool result = run_cpack(
-          config_build_dir, generators, config_name, verbose, project_name, project_version);
+      // Output directory: package.output_dir (with ${CONFIG} and the other
+      // tokens expanded), or packages/ next to the build directory
+      std::filesystem::path output_dir = config_build_dir.parent_path() / "packages";
+      std::string output_setting       = project_config.get_string("package.output_dir", "");
+      if (!output_setting.empty()) {
+        output_dir = cforge::expand_cforge_tokens(
+            output_setting, cforge::get_cforge_tokens(project_config, config_name));
+        if (output_dir.is_relative()) {
+          output_dir = current_dir / output_dir;
+        }
+      }
+      output_dir = std::filesystem::absolute(output_dir).lexically_normal();
+      std::error_code ec;
+      std::filesystem::create_directories(output_dir, ec);
+      if (ec) {
+        cforge::logger::print_error("Failed to create " + output_dir.string() + ": "
+                                    + ec.message());
+        return 1;
+      }
+
+      // Run CPack once per format, so one failing format doesn't stop the rest
+      std::string package_stem =
+          cforge::package_file_stem(project_name, project_version, config_name);
+      std::vector<std::string> failed;
+      for (cforge_size_t i = 0; i < generators.size(); ++i) {
+        const std::string &gen = generators[i];
+
+        std::string section = "package." + std::string(gen == "NSIS64" ? "NSIS" : gen);
+        std::transform(section.begin(), section.end(), section.begin(), ::tolower);
+        std::map<std::string, std::string> settings;
+        for (const auto &key : project_config.get_table_keys(section)) {
+          auto values   = project_config.get_string_array(section + "." + key);
+          settings[key] = values.empty() ? project_config.get_string(section + "." + key, "")
+                                         : cforge::join_strings(values, ", ");
+        }
+
+        cforge::logger::print_action("Format", gen);
+        bool result = run_cpack(config_build_dir,
+                                {gen},
+                                config_name,
+                                verbose,
+                                project_name,
+                                project_version,
+                                output_dir,
+                                cforge::package_format_variables(gen, settings, current_dir));
+
+        // Check that the package was written where we asked for it
+        std::filesystem::path package_path;
+        std::string extension = cforge::package_file_extension(gen);
+        if (result && !extension.empty()) {
+          for (const auto &entry : std::filesystem::directory_iterator(output_dir, ec)) {
+            if (entry.is_regular_file()
+                && cforge::is_package_file(entry.path().filename().string(), package_stem, gen)) {
+              package_path = entry.path();
+              break;
+            }
+          }
+          if (package_path.empty()) {
+            cforge::logger::print_error("CPack did not create " + package_stem + extension
+                                        + " in " + output_dir.string());
+            result = false;
+          }
+        }
+
+        if (!result) {
+          failed.push_back(gen);
+          continue;
+        }
+        if (!package_path.empty()) {
+          cforge::logger::print_action(
+              "Created",
+              package_path.string() + " ("
+                  + cforge::format_package_size(std::filesystem::file_size(package_path, ec))
+                  + ")");
+        }
+      }
 
-      if (!result) {
-        cforge::logger::print_error("Packaging failed");
+      if (!failed.empty()) {
+        cforge::logger::print_error(std::to_string(failed.size()) + " of "
+                                    + std::to_string(generators.size())
+                                    + " package formats failed: "
+                                    + cforge::join_strings(failed, ", "));
         return 1;
       }
 
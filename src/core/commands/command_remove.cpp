@@ -9,6 +9,7 @@
 #include "core/commands.hpp"
 #include "core/constants.h"
 #include "core/file_system.h"
+#include "core/manifest_edit.hpp"
 #include "core/process_utils.hpp"
 #include "core/toml_reader.hpp"
 #include "core/types.h"
@@ -21,105 +22,6 @@
 #include <string>
 #include <vector>
 
-/**
- * @brief Remove a dependency from the project configuration
- *
- * @param config_file Path to the configuration file
- * @param package_name Name of the package to remove
- * @param verbose Show verbose output
- * @return true if successful, false otherwise
- */
-[[maybe_unused]] static bool remove_dependency_from_config(const std::filesystem::path &config_file,
-                                                           const std::string &package_name,
-                                                           bool verbose) {
-  // Read existing config file
-  std::string content;
-  std::ifstream file(config_file);
-  if (!file) {
-    cforge::logger::print_error("Failed to read configuration file: " + config_file.string());
-    return false;
-  }
-
-  std::stringstream buffer;
-  buffer << file.rdbuf();
-  content = buffer.str();
-  file.close();
-
-  // Create a regex pattern to match the dependency entry
-  std::regex pattern("^\\s*" + package_name + "\\s*=\\s*\"[^\"]*\"\\s*$");
-
-  // Replace the entry with an empty string
-  std::string result;
-  std::string line;
-  std::istringstream iss(content);
-
-  while (std::getline(iss, line)) {
-    std::regex line_pattern("\\s*" + package_name + "\\s*=\\s*\"[^\"]*\"\\s*");
-    if (!std::regex_match(line, line_pattern)) {
-      result += line + "\n";
-    }
-  }
-
-  // Check if the content was changed
-  if (result == content) {
-    cforge::logger::print_warning("Dependency '" + package_name
-                                  + "' not found in configuration file");
-    return false;
-  }
-
-  // Remove empty dependency section if needed
-  std::istringstream iss_result(result);
-  std::string line_result;
-  bool in_dependencies_section    = false;
-  bool dependencies_section_empty = true;
-  std::stringstream cleaned;
-
-  while (std::getline(iss_result, line_result)) {
-    // Check for section header
-    if (line_result.find("[dependencies]") != std::string::npos) {
-      in_dependencies_section    = true;
-      dependencies_section_empty = true;
-
-      // Don't add the section yet, wait to see if it's empty
-      continue;
-    } else if (in_dependencies_section && line_result.find("[") != std::string::npos) {
-      // New section, end of dependencies
-      in_dependencies_section = false;
-
-      // If dependencies section was empty, don't add it
-      if (!dependencies_section_empty) {
-        cleaned << "[dependencies]" << std::endl;
-      }
-    }
-
-    // Skip empty lines in dependencies section
-    if (in_dependencies_section && !line_result.empty()
-        && line_result.find_first_not_of(" \t\r\n") != std::string::npos) {
-      dependencies_section_empty = false;
-      cleaned << line_result << std::endl;
-    } else if (!in_dependencies_section) {
-      cleaned << line_result << std::endl;
-    }
-  }
-
-  // Write back to file
-  std::ofstream outfile(config_file);
-  if (!outfile) {
-    cforge::logger::print_error("Failed to open configuration file for writing: "
-                                + config_file.string());
-    return false;
-  }
-
-  outfile << cleaned.str();
-  outfile.close();
-
-  if (verbose) {
-    cforge::logger::print_action("Removed", package_name);
-  }
-
-  return true;
-}
-
 /**
  * @brief Run vcpkg to remove the package
  *
@@ -224,104 +126,66 @@ static bool remove_git_repo(const std::filesystem::path &project_dir,
   return true;
 }
 
-// Helpers to remove a dependency entry from a specific TOML section
+// Helpers to remove a dependency entry from a specific TOML section. The file
+// is edited as text, so the user's comments and layout are kept
 static bool remove_dependency_from_section(const std::filesystem::path &config_file,
                                            const std::string &section,
                                            const std::string &package_name,
                                            bool verbose) {
-  // Read existing config file
-  std::ifstream file(config_file);
-  if (!file) {
-    cforge::logger::print_error("Failed to read configuration file: " + config_file.string());
+  // Only a `name = ...` entry: a table such as [dependencies.git] holds a kind
+  // of dependency, not a package
+  std::string path  = section + "." + package_name;
+  auto remove_entry = [&](std::string &content) {
+    return !cforge::manifest_value_text(content, path).empty()
+        && cforge::unset_manifest_value(content, path);
+  };
+  if (!cforge::is_editable_manifest_path(path)
+      || !cforge::write_manifest_edit(config_file, remove_entry, path, false)) {
+    cforge::logger::print_verbose("Dependency '" + package_name + "' not found in section ["
+                                  + section + "]");
     return false;
   }
 
-  std::vector<std::string> lines;
-  std::string line;
-  bool in_section             = false;
-  bool found_dependency       = false;
-  cforge_int_t line_to_remove = -1;
-
-  // Read all lines and find the dependency
-  while (std::getline(file, line)) {
-    // Check if this is our section
-    if (line.find("[" + section + "]") != std::string::npos) {
-      in_section = true;
-      lines.push_back(line);
-      continue;
-    }
-
-    // Check if we're leaving the section (new section starts)
-    if (in_section && !line.empty() && line[0] == '[') {
-      in_section = false;
-    }
-
-    // If we're in the right section, look for the dependency
-    if (in_section) {
-      // Extract package name from the line
-      std::string line_copy = line;
-      line_copy             = line_copy.substr(0, line_copy.find('='));  // Get everything before =
-      line_copy.erase(0, line_copy.find_first_not_of(" \t"));            // Trim left
-      line_copy.erase(line_copy.find_last_not_of(" \t") + 1);            // Trim right
-
-      if (line_copy == package_name) {
-        found_dependency = true;
-        line_to_remove   = static_cast<cforge_int_t>(lines.size());
-      }
-    }
-
-    lines.push_back(line);
-  }
-  file.close();
-
-  if (!found_dependency) {
-    cforge::logger::print_warning("Dependency '" + package_name + "' not found in section ["
+  if (verbose) {
+    cforge::logger::print_verbose("Removed dependency '" + package_name + "' from section ["
                                   + section + "]");
-    return false;
   }
 
-  // Remove the dependency line
-  if (line_to_remove >= 0) {
-    lines.erase(lines.begin() + line_to_remove);
-
-    // Clean up empty lines around the removed dependency
-    if (static_cast<cforge_size_t>(line_to_remove) < lines.size() && lines[line_to_remove].empty()
-        && (line_to_remove == 0 || lines[line_to_remove - 1].empty())) {
-      lines.erase(lines.begin() + line_to_remove);
-    }
-  }
+  return true;
+}
 
-  // Write back to file
-  std::ofstream outfile(config_file);
-  if (!outfile) {
-    cforge::logger::print_error("Failed to write configuration file: " + config_file.string());
+// Helper to remove a whole [table] (its header, keys and sub-tables) from the config
+static bool remove_table_from_config(const std::filesystem::path &config_file,
+                                     const std::string &table,
+                                     bool verbose) {
+  auto remove_table = [&](std::string &content) {
+    return cforge::unset_manifest_value(content, table);
+  };
+  if (!cforge::is_editable_manifest_path(table)
+      || !cforge::write_manifest_edit(config_file, remove_table, table, false)) {
     return false;
   }
 
-  bool last_was_empty = false;
-  for (cforge_size_t i = 0; i < lines.size(); ++i) {
-    const auto &l = lines[i];
-    // Avoid multiple consecutive empty lines
-    if (l.empty()) {
-      if (!last_was_empty) {
-        outfile << l << "\n";
-      }
-      last_was_empty = true;
-    } else {
-      outfile << l << "\n";
-      last_was_empty = false;
-    }
-  }
-  outfile.close();
-
   if (verbose) {
-    cforge::logger::print_verbose("Removed dependency '" + package_name + "' from section ["
-                                  + section + "]");
+    cforge::logger::print_verbose("Removed table [" + table + "]");
   }
-
   return true;
 }
 
+/**
+ * @brief Remove a dependency from the project configuration
+ *
+ * @param config_file Path to the configuration file
+ * @param package_name Name of the package to remove
+ * @param verbose Show verbose output
+ * @return true if successful, false otherwise
+ */
+[[maybe_unused]] static bool remove_dependency_from_config(const std::filesystem::path &config_file,
+                                                           const std::string &package_name,
+                                                           bool verbose) {
+  return remove_dependency_from_section(config_file, "dependencies", package_name, verbose);
+}
+
 [[maybe_unused]] static bool remove_vcpkg_dependency_from_config(
     const std::filesystem::path &config_file, const std::string &package_name, bool verbose) {
   return remove_dependency_from_section(config_file, "dependencies.vcpkg", package_name, verbose);
@@ -358,15 +222,34 @@ cforge_int_t cforge_cmd_remove(const cforge_context_t *ctx) {
     return 1;
   }
 
+  // Work out where the dependency came from before its entry is removed, so
+  // consolidated entries (name = { source = "vcpkg" }) get the same cleanup
+  std::string consolidated_source;
+  bool consolidated_git = false;
+  {
+    cforge::toml_reader config;
+    if (config.load(config_file.string())) {
+      consolidated_source = config.get_string("dependencies." + package_name + ".source", "");
+      consolidated_git    = config.has_key("dependencies." + package_name + ".git");
+    }
+  }
+
   // Try to remove from git, vcpkg, and main dependencies sections
   bool git_removed =
       remove_dependency_from_section(config_file, "dependencies.git", package_name, verbose);
+  git_removed = remove_table_from_config(config_file, "dependencies.git." + package_name, verbose)
+             || git_removed;
   bool vcpkg_removed =
       remove_dependency_from_section(config_file, "dependencies.vcpkg", package_name, verbose);
   // Also try the main [dependencies] section for index dependencies
   bool index_removed =
       remove_dependency_from_section(config_file, "dependencies", package_name, verbose);
 
+  if (index_removed) {
+    git_removed   = git_removed || consolidated_git || consolidated_source == "git";
+    vcpkg_removed = vcpkg_removed || consolidated_source == "vcpkg";
+  }
+
   // If it was in git dependencies, also remove the cloned repository
   if (git_removed) {
     if (!remove_git_repo(project_dir, package_name, verbose)) {
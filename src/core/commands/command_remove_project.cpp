@@ -0,0 +1,150 @@
+/**
+ * @file command_remove_project.cpp
+ * @brief Implementation of the 'remove' command to take a project out of a workspace
+ *        or a dependency out of a project
+ */
+
+#include "cforge/log.hpp"
+
+#include "core/command_registry.hpp"
+#include "core/commands.hpp"
+#include "core/manifest_edit.hpp"
+#include "core/types.h"
+#include "core/utils/terminal_prompt.hpp"
+#include "core/workspace.hpp"
+#include "core/workspace_edit.hpp"
+
+#include <algorithm>
+#include <filesystem>
+#include <string>
+
+/**
+ * @brief Check that a project directory lies strictly inside the workspace
+ *
+ * --delete must never take the workspace root (a project at ".") or anything
+ * outside it with it.
+ */
+static bool is_inside_workspace(const std::filesystem::path &workspace_dir,
+                                const std::filesystem::path &project_dir) {
+  std::filesystem::path relative =
+      project_dir.lexically_normal().lexically_relative(workspace_dir.lexically_normal());
+  return !relative.empty() && *relative.begin() != ".." && relative != ".";
+}
+
+/**
+ * @brief Handle the 'remove' command
+ *
+ * @param ctx Context containing parsed arguments
+ * @return cforge_int_t Exit code (0 for success)
+ */
+cforge_int_t cforge_cmd_remove_project(const cforge_context_t *ctx) {
+  std::string project_name;
+  std::string unknown_option;
+  bool delete_dir = false;
+  bool assume_yes = false;
+
+  for (cforge_int_t i = 0; i < ctx->args.arg_count; ++i) {
+    std::string arg = ctx->args.args[i];
+    if (arg == "-h" || arg == "--help") {
+      cforge::command_registry::instance().print_command_help("remove");
+      return 0;
+    } else if (arg == "--delete") {
+      delete_dir = true;
+    } else if (arg == "-y" || arg == "--yes") {
+      assume_yes = true;
+    } else if (!arg.empty() && arg[0] == '-') {
+      unknown_option = unknown_option.empty() ? arg : unknown_option;
+    } else if (project_name.empty()) {
+      project_name = arg;
+    }
+  }
+
+  if (project_name.empty()) {
+    cforge::logger::print_error("Project or package name not specified");
+    cforge::logger::print_status("Usage: cforge remove <project|package> [--delete]");
+    return 1;
+  }
+
+  // Outside a workspace, or for a name that is not a workspace project,
+  // 'cforge remove' removes a dependency, like 'cforge deps remove'
+  auto [is_workspace, workspace_dir] = cforge::is_in_workspace(ctx->working_dir);
+  if (!is_workspace) {
+    return cforge_cmd_remove(ctx);
+  }
+
+  cforge::workspace ws;
+  if (!ws.load(workspace_dir)) {
+    cforge::logger::print_error("Failed to load workspace configuration");
+    return 1;
+  }
+
+  const cforge::workspace_project *project = ws.get_project_by_name(project_name);
+  if (!project) {
+    return cforge_cmd_remove(ctx);
+  }
+  if (!unknown_option.empty()) {
+    cforge::logger::print_error("Unknown option: " + unknown_option);
+    return 1;
+  }
+  std::filesystem::path project_dir = project->path;
+
+  if (delete_dir && !is_inside_workspace(workspace_dir, project_dir)) {
+    cforge::logger::print_error("Refusing to delete " + project_dir.string()
+                                + ": it is not inside the workspace directory");
+    return 1;
+  }
+
+  // Rewrite the manifest
+  std::filesystem::path config_file = cforge::get_workspace_config_path(workspace_dir);
+  std::string relative_path =
+      project_dir.lexically_normal().lexically_relative(workspace_dir.lexically_normal())
+          .generic_string();
+  bool found        = false;
+  auto remove_entry = [&](std::string &content) {
+    found = cforge::remove_workspace_project_entries(content, project_name, relative_path);
+    return found;
+  };
+  if (!cforge::write_manifest_edit(config_file, remove_entry)) {
+    if (!found && std::filesystem::exists(config_file)) {
+      cforge::logger::print_error("No entry for '" + project_name + "' found in "
+                                  + config_file.string());
+      cforge::logger::print_hint("remove the project from the workspace configuration by hand");
+    }
+    return 1;
+  }
+
+  cforge::logger::print_action("Removed", project_name + " from workspace " + ws.get_name());
+
+  for (const auto &p : ws.get_projects()) {
+    if (std::find(p.dependencies.begin(), p.dependencies.end(), project_name)
+        != p.dependencies.end()) {
+      cforge::logger::print_warning("'" + p.name + "' still depends on '" + project_name + "'");
+    }
+  }
+
+  if (!delete_dir) {
+    return 0;
+  }
+  if (!std::filesystem::exists(project_dir)) {
+    cforge::logger::print_verbose("Project directory " + project_dir.string()
+                                  + " does not exist, nothing to delete");
+    return 0;
+  }
+
+  if (!assume_yes) {
+    cforge::logger::print_warning("This permanently deletes " + project_dir.string());
+    if (!cforge::prompt_confirm("Delete", false)) {
+      cforge::logger::print_action("Kept", project_dir.string());
+      return 0;
+    }
+  }
+
+  std::error_code ec;
+  std::filesystem::remove_all(project_dir, ec);
+  if (ec) {
+    cforge::logger::print_error("Failed to delete " + project_dir.string() + ": " + ec.message());
+    return 1;
+  }
+  cforge::logger::print_action("Deleted", project_dir.string());
+  return 0;
+}
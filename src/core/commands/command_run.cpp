@@ -7,16 +7,18 @@
 #include "cforge/log.hpp"
 
 #include "core/build_utils.hpp"
+#include "core/cmake_file_api.hpp"
 #include "core/command_registry.hpp"
 #include "core/commands.hpp"
 #include "core/constants.h"
 #include "core/error_format.hpp"
 #include "core/file_system.h"
-#include "core/platform.hpp"
 #include "core/process_utils.hpp"
 #include "core/script_runner.hpp"
 #include "core/toml_reader.hpp"
 #include "core/types.h"
+#include "core/utils/terminal_prompt.hpp"
+#include "core/wasm_run.hpp"
 #include "core/workspace.hpp"
 
 #include <algorithm>
@@ -83,16 +85,27 @@ static std::filesystem::path find_project_executable(const std::filesystem::path
       project_name + "_r"         // Release convention
   };
 
+  // Emscripten builds link <name>.js (or <name>.html) next to <name>.wasm
+  std::vector<std::string> wasm_patterns;
+  for (const auto &pattern : executable_patterns) {
+    wasm_patterns.push_back(pattern + ".js");
+    wasm_patterns.push_back(pattern + ".html");
+  }
+
 #ifdef _WIN32
   // Add .exe extension for Windows
   for (auto &pattern : executable_patterns) {
     pattern += ".exe";
   }
 #endif
+  executable_patterns.insert(executable_patterns.end(), wasm_patterns.begin(), wasm_patterns.end());
 
   // Function to check if a file is a valid executable
   auto is_valid_executable = [](const std::filesystem::path &path) -> bool {
     try {
+      if (cforge::is_wasm_executable(path)) {
+        return true;
+      }
 #ifdef _WIN32
       return path.extension() == ".exe";
 #else
@@ -218,6 +231,47 @@ static std::filesystem::path find_project_executable(const std::filesystem::path
   return std::filesystem::path();
 }
 
+/**
+ * @brief Set sanitizer runtime defaults for a sanitizer-enabled configuration
+ *
+ * When [build.config.<config>] (or [build.configs.<config>]) lists
+ * sanitizers, ASAN_OPTIONS / UBSAN_OPTIONS get defaults so failures abort
+ * with a stack trace. Values already set by the user are left alone.
+ *
+ * @param project_config Project configuration
+ * @param config Build configuration
+ */
+static void apply_sanitizer_environment(const cforge::toml_reader &project_config,
+                                        const std::string &config) {
+  std::string config_lower = config;
+  std::transform(config_lower.begin(), config_lower.end(), config_lower.begin(), ::tolower);
+
+  auto sanitizers = project_config.get_string_array("build.config." + config_lower + ".sanitizers");
+  if (sanitizers.empty()) {
+    sanitizers = project_config.get_string_array("build.configs." + config_lower + ".sanitizers");
+  }
+
+  auto set_default = [](const char *name, const char *value) {
+    if (std::getenv(name) != nullptr) {
+      return;
+    }
+#ifdef _WIN32
+    _putenv_s(name, value);
+#else
+    setenv(name, value, 0);
+#endif
+    cforge::logger::print_verbose(std::string("Environment: ") + name + "=" + value);
+  };
+
+  for (const auto &san : sanitizers) {
+    if (san == "address") {
+      set_default("ASAN_OPTIONS", "abort_on_error=1");
+    } else if (san == "undefined") {
+      set_default("UBSAN_OPTIONS", "print_stacktrace=1:halt_on_error=1");
+    }
+  }
+}
+
 /**
  * @brief Build a project before running it (with smart rebuild detection)
  *
@@ -228,11 +282,11 @@ static std::filesystem::path find_project_executable(const std::filesystem::path
  * 4. Always run build (CMake handles incremental builds)
  */
 static bool build_project_for_run(const std::filesystem::path &project_dir,
+                                  const std::filesystem::path &build_base,
                                   const std::string &config,
                                   bool verbose) {
   // Determine build directory
-  std::filesystem::path build_dir =
-      cforge::get_build_dir_for_config((project_dir / "build").string(), config);
+  std::filesystem::path build_dir = cforge::get_build_dir_for_config(build_base.string(), config);
 
   // Use smart rebuild detection to prepare the project
   cforge::build_preparation_result prep_result =
@@ -262,50 +316,266 @@ static bool build_project_for_run(const std::filesystem::path &project_dir,
   return true;
 }
 
-// Spawn a command in a new terminal window across platforms
-static bool spawn_in_terminal(const std::string &cmd) {
-  if constexpr (cforge::platform::is_windows) {
-    // Use start to open a new Command Prompt
-    std::string winCmd = "start \"CForge Run\" cmd /k \"" + cmd + "\"";
-    return std::system(winCmd.c_str()) == 0;
-  } else if constexpr (cforge::platform::is_macos) {
-    // Use AppleScript to open a new Terminal window
-    std::string osa = "osascript -e 'tell application \"Terminal\" to do script \"" + cmd + "\"'";
-    return std::system(osa.c_str()) == 0;
+/**
+ * @brief Run a built executable in the foreground
+ *
+ * @param executable Path to the executable
+ * @param extra_args Arguments for the program
+ * @param working_dir Directory to run the program in
+ * @param config Build configuration, for the closing status line
+ * @param browser Open a WebAssembly build in a browser instead of node
+ * @return cforge_int_t The program's exit code
+ */
+static cforge_int_t run_executable(const std::filesystem::path &executable,
+                                   const std::vector<std::string> &extra_args,
+                                   const std::filesystem::path &working_dir,
+                                   const std::string &config,
+                                   bool browser) {
+  std::string program           = executable.string();
+  std::vector<std::string> args = extra_args;
+
+  // An Emscripten build runs its .js loader in node, or its page in a browser
+  if (cforge::is_wasm_executable(executable) || executable.extension() == ".wasm") {
+    std::filesystem::path entry = cforge::wasm_entry_point(executable, browser);
+    if (entry.empty()) {
+      cforge::logger::print_error("no ." + std::string(browser ? "html page" : "js loader")
+                                  + " next to " + executable.string());
+      if (browser) {
+        cforge::logger::print_hint("set CFORGE_WASM_SUFFIX = \".html\" in the variables of "
+                                   "[cross.targets.wasm] and rebuild");
+      }
+      return 1;
+    }
+    std::vector<std::string> command = cforge::wasm_run_command(entry, browser, extra_args);
+    if (!cforge::is_command_available(command.front(), 10)) {
+      cforge::logger::print_error(command.front() + " not found in PATH");
+      cforge::logger::print_hint(browser ? "emrun comes with the Emscripten SDK; run 'emsdk_env'"
+                                         : "install Node.js to run WebAssembly builds, or use "
+                                           "--browser");
+      return 1;
+    }
+    program = command.front();
+    args.assign(command.begin() + 1, command.end());
+  } else if (browser) {
+    cforge::logger::print_error("--browser only applies to WebAssembly builds");
+    cforge::logger::print_hint("build with 'cforge build --profile wasm' first");
+    return 1;
+  }
+
+  // A Windows executable cross-built with MinGW runs through Wine elsewhere
+#ifndef _WIN32
+  if (executable.extension() == ".exe") {
+    if (!cforge::is_command_available("wine", 10)) {
+      cforge::logger::print_error(executable.filename().string() + " is a Windows executable");
+      cforge::logger::print_hint("install Wine to run it here, or copy it to a Windows machine");
+      return 1;
+    }
+    args.insert(args.begin(), program);
+    program = "wine";
+  }
+#endif
+
+  cforge::logger::running(executable.string());
+  cforge::logger::print_blank();
+
+  // Forward the program's stdout bytes verbatim — print_plain would append
+  // its own '\n' to each chunk, doubling every blank line the program
+  // emits. fwrite preserves the exact byte stream.
+  std::function<void(const std::string &)> stdout_callback = [](const std::string &chunk) {
+    if (!chunk.empty()) {
+      std::fwrite(chunk.data(), 1, chunk.size(), stdout);
+      std::fflush(stdout);
+    }
+  };
+
+  // Capture stderr for later formatting - don't print immediately to avoid
+  // duplication
+  std::string captured_stderr;
+  std::function<void(const std::string &)> stderr_callback =
+      [&captured_stderr](const std::string &chunk) {
+        captured_stderr += chunk;
+      };
+
+  // Execute the program with output handling
+  cforge::process_result result = cforge::execute_process(program,
+                                                          args,
+                                                          working_dir.string(),
+                                                          stdout_callback,
+                                                          stderr_callback,
+                                                          0  // No timeout
+  );
+
+  // Add a separator line after program output
+  cforge::logger::print_blank();
+
+  // Use captured_stderr for error analysis
+  std::string error_output = captured_stderr;
+  if (error_output.empty()) {
+    error_output = result.stderr_output;
+  }
+
+  if (result.success) {
+    // On success, just print any stderr warnings directly
+    if (!error_output.empty()) {
+      cforge::logger::print_warning(error_output);
+    }
+    cforge::logger::finished(config);
+    return 0;
   } else {
-    // Linux: Try multiple terminal emulators in order of preference
-    auto terminals = cforge::platform::get_linux_terminals();
-
-    for (const auto &terminal : terminals) {
-      if (cforge::is_command_available(terminal, 3)) {
-        cforge::logger::print_verbose("Using terminal emulator: " + terminal);
-
-        std::string termCmd;
-        // Different terminals have different argument formats
-        if (terminal == "gnome-terminal" || terminal == "mate-terminal") {
-          termCmd = terminal + " -- " + cmd + " &";
-        } else if (terminal == "konsole") {
-          termCmd = terminal + " -e " + cmd + " &";
-        } else if (terminal == "alacritty" || terminal == "kitty") {
-          termCmd = terminal + " -e " + cmd + " &";
-        } else {
-          // Default format works for most terminals
-          termCmd = terminal + " -e '" + cmd + "' &";
-        }
+    // On failure, try to format errors nicely first
+    std::string formatted = cforge::format_build_errors(error_output);
+    if (!formatted.empty()) {
+      // Print formatted errors (don't print raw stderr to avoid
+      // duplication)
+      cforge::logger::print_blank();
+      cforge::logger::print_plain(formatted);
+    } else if (!error_output.empty()) {
+      // Fallback: print raw stderr if formatter didn't produce anything
+      cforge::logger::print_error(error_output);
+    }
 
-        cforge_int_t result = std::system(termCmd.c_str());
-        if (result == 0) {
-          return true;
-        }
-        // If this terminal failed, try the next one
-        cforge::logger::print_verbose("Terminal " + terminal + " failed, trying next");
-      }
+    cforge::logger::print_error("program exited with code: "
+                                + std::to_string(result.exit_code));
+    return result.exit_code;
+  }
+}
+
+/**
+ * @brief Get the project named on a workspace `cforge run` command line
+ *
+ * Takes `-p/--project <name>` or the first positional argument before `--`.
+ * @return Project name, empty if none was given
+ */
+static std::string requested_run_project(const cforge_context_t *ctx) {
+  static const std::set<std::string> options_with_values = {
+      "-c", "--config", "--variant", "-t", "--target", "--verbosity", "--format"};
+  for (cforge_int_t i = 0; i < ctx->args.arg_count; ++i) {
+    std::string arg = ctx->args.args[i];
+    if (arg == "--") {
+      break;
+    }
+    if ((arg == "-p" || arg == "--project") && i + 1 < ctx->args.arg_count) {
+      return ctx->args.args[i + 1];
     }
+    if (options_with_values.count(arg)) {
+      ++i;
+    } else if (!arg.empty() && arg[0] != '-') {
+      return arg;
+    }
+  }
+  return "";
+}
 
-    // All terminals failed
-    cforge::logger::print_warning("No suitable terminal emulator found");
-    return false;
+/**
+ * @brief Get the target named with `-t/--target` on a `cforge run` command line
+ *
+ * @return Target name, empty if none was given
+ */
+static std::string requested_run_target(const cforge_context_t *ctx) {
+  for (cforge_int_t i = 0; i < ctx->args.arg_count; ++i) {
+    std::string arg = ctx->args.args[i];
+    if (arg == "--") {
+      break;
+    }
+    if ((arg == "-t" || arg == "--target") && i + 1 < ctx->args.arg_count) {
+      return ctx->args.args[i + 1];
+    }
+    if (arg.rfind("--target=", 0) == 0) {
+      return arg.substr(9);
+    }
+  }
+  return "";
+}
+
+/**
+ * @brief Find the executable of the target `cforge run` starts
+ *
+ * The project's own target may have been moved by [output] bin_dir; any
+ * target is looked up in the CMake file API reply, which also validates the
+ * name, and only searched for in the build tree when there is no reply
+ * (CMake older than 3.14).
+ *
+ * @param build_dir Build directory of the configuration
+ * @param search_dir Directory searched when there is no file API reply
+ * @return Path to the executable, empty after reporting an error
+ */
+static std::filesystem::path find_run_executable(const cforge::toml_reader &project_config,
+                                                 const std::filesystem::path &project_dir,
+                                                 const std::filesystem::path &build_dir,
+                                                 const std::string &search_dir,
+                                                 const std::string &config,
+                                                 const std::string &project_name,
+                                                 const std::string &target) {
+  std::filesystem::path exe;
+  if (target == project_name) {
+    exe = cforge::find_output_executable(project_config, project_dir, config, project_name);
+  }
+  if (exe.empty()) {
+    if (auto api_exe = cforge::find_cmake_executable(build_dir, config, target)) {
+      return *api_exe;
+    }
+    exe = find_project_executable(project_dir, search_dir, config, target);
+  }
+  if (exe.empty()) {
+    cforge::logger::print_error("executable not found for target: " + target);
   }
+  return exe;
+}
+
+/**
+ * @brief Choose the workspace project `cforge run` starts when none is named
+ *
+ * Uses the configured startup project, asks when several are configured, and
+ * otherwise falls back to the workspace's only executable.
+ * @return Project name, empty after reporting an error
+ */
+static std::string choose_startup_project(const cforge::workspace &ws) {
+  std::vector<std::string> candidates = ws.get_startup_candidates();
+  if (candidates.size() == 1) {
+    return candidates[0];
+  }
+
+  if (candidates.size() > 1) {
+    if (cforge::is_interactive_terminal()) {
+      cforge_int_t choice = cforge::prompt_select("Run", candidates, 0);
+      return candidates[static_cast<cforge_size_t>(choice)];
+    }
+    std::string names;
+    for (const auto &name : candidates) {
+      names += (names.empty() ? "" : ", ") + name;
+    }
+    cforge::logger::print_error("workspace has several startup projects: " + names);
+    cforge::logger::print_hint("run one with 'cforge run <project>', or set a default with "
+                               "'cforge set-startup <project>'");
+    return "";
+  }
+
+  // Nothing configured: only unambiguous if there is a single executable
+  std::vector<std::string> executables;
+  for (const auto &proj : ws.get_projects()) {
+    cforge::toml_reader pconf;
+    if (pconf.load((proj.path / CFORGE_FILE).string())
+        && cforge::get_binary_type(pconf) == "executable") {
+      executables.push_back(proj.name);
+    }
+  }
+  if (executables.size() == 1) {
+    return executables[0];
+  }
+
+  if (executables.empty()) {
+    cforge::logger::print_error("workspace '" + ws.get_name() + "' has no executable projects");
+    return "";
+  }
+  std::string names;
+  for (const auto &name : executables) {
+    names += (names.empty() ? "" : ", ") + name;
+  }
+  cforge::logger::print_error("no startup project set, and workspace '" + ws.get_name()
+                              + "' has several executables: " + names);
+  cforge::logger::print_hint("pick one with 'cforge set-startup <project>', or run one with "
+                             "'cforge run <project>'");
+  return "";
 }
 
 cforge_int_t cforge_cmd_run(const cforge_context_t *ctx) {
@@ -354,17 +624,14 @@ cforge_int_t cforge_cmd_run(const cforge_context_t *ctx) {
       }
     }
 
-    // Check for specific project
-    std::string specific_project;
-    if (ctx->args.project) {
-      specific_project = ctx->args.project;
-    } else if (ctx->args.args) {
-      for (cforge_int_t i = 0; i < ctx->args.arg_count; ++i) {
-        if ((strcmp(ctx->args.args[i], "--project") == 0 || strcmp(ctx->args.args[i], "-p") == 0)
-            && i + 1 < ctx->args.arg_count) {
-          specific_project = ctx->args.args[i + 1];
-          break;
-        }
+    // --browser opens a WebAssembly build in a browser instead of node
+    bool browser = false;
+    for (cforge_int_t i = 0; i < ctx->args.arg_count; ++i) {
+      if (strcmp(ctx->args.args[i], "--") == 0) {
+        break;
+      }
+      if (strcmp(ctx->args.args[i], "--browser") == 0) {
+        browser = true;
       }
     }
 
@@ -394,6 +661,27 @@ cforge_int_t cforge_cmd_run(const cforge_context_t *ctx) {
     if (is_workspace && project_dir == workspace_root) {
       cforge::logger::print_action("Running", "in workspace context: " + project_dir.string());
 
+      cforge::workspace ws;
+      if (!ws.load(workspace_root)) {
+        cforge::logger::print_error("failed to load workspace configuration");
+        return 1;
+      }
+
+      // Pick the project before building so a bad choice fails fast
+      std::string proj_name = requested_run_project(ctx);
+      if (proj_name.empty()) {
+        proj_name = choose_startup_project(ws);
+        if (proj_name.empty()) {
+          return 1;
+        }
+      }
+      const cforge::workspace_project *proj = ws.get_project_by_name(proj_name);
+      if (!proj) {
+        cforge::logger::print_error("'" + proj_name + "' is not a project in workspace '"
+                                    + ws.get_name() + "'");
+        return 1;
+      }
+
       // Ensure workspace CMakeLists.txt exists (generate if needed)
       std::filesystem::path ws_cmake = project_dir / "CMakeLists.txt";
       if (!std::filesystem::exists(ws_cmake)) {
@@ -411,7 +699,7 @@ cforge_int_t cforge_cmd_run(const cforge_context_t *ctx) {
       }
 
       // Determine workspace-level build directory
-      std::filesystem::path ws_build_base = project_dir / DEFAULT_BUILD_DIR;
+      std::filesystem::path ws_build_base = cforge::get_build_base_dir(project_dir, nullptr);
       std::filesystem::path ws_build_dir  = cforge::get_build_dir_for_config(ws_build_base.string(),
                                                                             config);
       cforge::logger::print_verbose("Using workspace build directory: " + ws_build_dir.string());
@@ -448,71 +736,39 @@ cforge_int_t cforge_cmd_run(const cforge_context_t *ctx) {
       } else {
         cforge::logger::print_action("Skipping", "workspace build as requested");
       }
-      auto workspace_file = cforge::get_workspace_config_path(project_dir);
-      if (workspace_file.empty()) {
-        cforge::logger::print_error("No workspace configuration found");
+      if (!std::filesystem::exists(proj->path / CFORGE_FILE)) {
+        cforge::logger::print_error("project directory is missing: " + proj->path.string());
         return 1;
       }
-      cforge::toml_reader workspace_config(toml::parse_file(workspace_file.string()));
-      if (config.empty()) {
-        config = workspace_config.get_string("workspace.build_type", "Debug");
-      }
-      // Determine startup projects using workspace API
-      cforge::workspace ws;
-      ws.load(workspace_root);
-      // Collect all projects flagged as startup
-      std::vector<cforge::workspace_project> projects = ws.get_projects();
-      std::vector<std::string> to_run;
-      for (const auto &proj : projects) {
-        if (proj.is_startup) {
-          to_run.push_back(proj.name);
-        }
-      }
-      // Fallback to main_project if none marked
-      if (to_run.empty()) {
-        cforge::workspace_project main_proj = ws.get_startup_project();
-        if (!main_proj.name.empty()) {
-          to_run.push_back(main_proj.name);
-        }
+      // Load project config to get real name
+      cforge::toml_reader pconf(toml::parse_file((proj->path / CFORGE_FILE).string()));
+      std::string real_name   = pconf.get_string("project.name", proj_name);
+      std::string run_target  = requested_run_target(ctx);
+      if (run_target.empty()) {
+        run_target = pconf.get_string("project.default_run_target", real_name);
       }
-      if (to_run.empty()) {
-        cforge::logger::print_error("no startup project set in workspace");
+      std::string binary_type = cforge::get_binary_type(pconf);
+      if (run_target == real_name && binary_type != "executable") {
+        cforge::logger::print_error("project '" + proj_name
+                                    + "' is not an executable (binary_type is '" + binary_type
+                                    + "')");
         return 1;
       }
-      // Run each startup project
-      bool overall_success = true;
-      for (const auto &proj_name : to_run) {
-        // Determine project directory
-        std::filesystem::path proj_path = project_dir / proj_name;
-        if (!std::filesystem::exists(proj_path / CFORGE_FILE)) {
-          cforge::logger::print_warning("skipping missing project: " + proj_name);
-          overall_success = false;
-          continue;
-        }
-        // Load project config to get real name
-        cforge::toml_reader pconf(toml::parse_file((proj_path / CFORGE_FILE).string()));
-        std::string real_name = pconf.get_string("project.name", proj_name);
-        // Find executable
-        auto exe = find_project_executable(proj_path, ws_build_dir.string(), config, real_name);
-        if (exe.empty()) {
-          cforge::logger::print_error("executable not found: " + proj_name);
-          overall_success = false;
-          continue;
-        }
-        // Build command line
-        std::ostringstream oss;
-        // Quote the executable path to handle spaces
-        oss << "\"" << exe.string() << "\"";
-        for (const auto &arg : extra_args) {
-          oss << " " << arg;
-        }
-        // Spawn in new terminal
-        if (!spawn_in_terminal(oss.str())) {
-          cforge::logger::print_error("failed to spawn terminal for: " + proj_name);
-          overall_success = false;
-        }
+      std::string proj_config = ws.get_project_config(proj_name, config);
+      cforge::logger::print_action("Project", proj_name);
+      apply_sanitizer_environment(pconf, proj_config);
+      cforge::scoped_environment project_env(cforge::get_project_environment(pconf, proj_config));
+      std::filesystem::path exe = find_run_executable(pconf,
+                                                      proj->path,
+                                                      ws_build_dir,
+                                                      ws_build_dir.string(),
+                                                      proj_config,
+                                                      real_name,
+                                                      run_target);
+      if (exe.empty()) {
+        return 1;
       }
-      return overall_success ? 0 : 1;
+      return run_executable(exe, extra_args, proj->path, proj_config, browser);
     } else {
       // Handle single project run
       cforge::logger::print_action("Running", "in single project context");
@@ -547,20 +803,30 @@ cforge_int_t cforge_cmd_run(const cforge_context_t *ctx) {
       cforge::logger::print_action("Project", project_name);
       cforge::logger::print_action("Configuration", config);
 
+      // --target, then [project] default_run_target, then the project's own target
+      std::string run_target = requested_run_target(ctx);
+      if (run_target.empty()) {
+        run_target = project_config.get_string("project.default_run_target", project_name);
+      }
+      if (run_target != project_name) {
+        cforge::logger::print_action("Target", run_target);
+      }
+
       // Check binary type
-      std::string binary_type = project_config.get_string("project.binary_type", "executable");
-      if (binary_type != "executable") {
+      std::string binary_type = cforge::get_binary_type(project_config);
+      if (run_target == project_name && binary_type != "executable") {
         cforge::logger::print_error("project is not an executable (binary_type is '" + binary_type
                                     + "')");
         return 1;
       }
 
       // Determine build directory
-      std::string build_dir_name = project_config.get_string("build.build_dir", "build");
+      std::filesystem::path build_base = cforge::get_build_base_dir(project_dir, &project_config);
+      std::string build_dir_name       = build_base.string();
 
       // Build the project if needed
       if (!skip_build) {
-        if (!build_project_for_run(project_dir, config, verbose)) {
+        if (!build_project_for_run(project_dir, build_base, config, verbose)) {
           cforge::logger::print_error("failed to build project");
           return 1;
         }
@@ -568,78 +834,24 @@ cforge_int_t cforge_cmd_run(const cforge_context_t *ctx) {
         cforge::logger::print_action("Skipping", "build step as requested");
       }
 
-      // Find the executable
-      std::filesystem::path executable =
-          find_project_executable(project_dir, build_dir_name, config, project_name);
-
+      std::filesystem::path build_dir =
+          cforge::get_build_dir_for_config(build_base.string(), config, false);
+      std::filesystem::path executable = find_run_executable(project_config,
+                                                             project_dir,
+                                                             build_dir,
+                                                             build_dir_name,
+                                                             config,
+                                                             project_name,
+                                                             run_target);
       if (executable.empty()) {
-        cforge::logger::print_error("executable not found for project: " + project_name);
         return 1;
       }
 
-      cforge::logger::running(executable.string());
-      cforge::logger::print_blank();
+      apply_sanitizer_environment(project_config, config);
+      cforge::scoped_environment project_env(
+          cforge::get_project_environment(project_config, config));
 
-      // Forward the program's stdout bytes verbatim — print_plain would append
-      // its own '\n' to each chunk, doubling every blank line the program
-      // emits. fwrite preserves the exact byte stream.
-      std::function<void(const std::string &)> stdout_callback = [](const std::string &chunk) {
-        if (!chunk.empty()) {
-          std::fwrite(chunk.data(), 1, chunk.size(), stdout);
-          std::fflush(stdout);
-        }
-      };
-
-      // Capture stderr for later formatting - don't print immediately to avoid
-      // duplication
-      std::string captured_stderr;
-      std::function<void(const std::string &)> stderr_callback =
-          [&captured_stderr](const std::string &chunk) {
-            captured_stderr += chunk;
-          };
-
-      // Execute the program with output handling
-      cforge::process_result result = cforge::execute_process(executable.string(),
-                                                              extra_args,
-                                                              project_dir.string(),
-                                                              stdout_callback,
-                                                              stderr_callback,
-                                                              0  // No timeout
-      );
-
-      // Add a separator line after program output
-      cforge::logger::print_blank();
-
-      // Use captured_stderr for error analysis
-      std::string error_output = captured_stderr;
-      if (error_output.empty()) {
-        error_output = result.stderr_output;
-      }
-
-      if (result.success) {
-        // On success, just print any stderr warnings directly
-        if (!error_output.empty()) {
-          cforge::logger::print_warning(error_output);
-        }
-        cforge::logger::finished(config);
-        return 0;
-      } else {
-        // On failure, try to format errors nicely first
-        std::string formatted = cforge::format_build_errors(error_output);
-        if (!formatted.empty()) {
-          // Print formatted errors (don't print raw stderr to avoid
-          // duplication)
-          cforge::logger::print_blank();
-          cforge::logger::print_plain(formatted);
-        } else if (!error_output.empty()) {
-          // Fallback: print raw stderr if formatter didn't produce anything
-          cforge::logger::print_error(error_output);
-        }
-
-        cforge::logger::print_error("program exited with code: "
-                                    + std::to_string(result.exit_code));
-        return result.exit_code;
-      }
+      return run_executable(executable, extra_args, project_dir, config, browser);
     }
   } catch (const std::exception &ex) {
     cforge::logger::print_error("exception: " + std::string(ex.what()));
@@ -0,0 +1,121 @@
+/**
+ * @file command_script.cpp
+ * @brief Implementation of the 'script' command to run named scripts
+ */
+
+#include "cforge/log.hpp"
+
+#include "core/command_registry.hpp"
+#include "core/commands.hpp"
+#include "core/script_runner.hpp"
+#include "core/types.h"
+
+#include <toml++/toml.hpp>
+
+#include <filesystem>
+#include <map>
+#include <string>
+#include <vector>
+
+/**
+ * @brief Print the named scripts and what each one needs
+ */
+static void print_named_scripts(const std::map<std::string, cforge::named_script> &scripts) {
+  cforge::logger::print_section("Available scripts:");
+  for (const auto &[name, script] : scripts) {
+    std::string line = script.command.empty() ? "(runs its prerequisites)" : script.command;
+    if (!script.needs.empty()) {
+      std::string needs;
+      for (const auto &need : script.needs) {
+        needs += (needs.empty() ? "" : ", ") + need;
+      }
+      line += "  [needs " + needs + "]";
+    }
+    cforge::logger::print_kv(name, line);
+  }
+  cforge::logger::print_blank();
+}
+
+/**
+ * @brief Handle the 'script' command
+ *
+ * @param ctx Context containing parsed arguments
+ * @return cforge_int_t Exit code (0 for success)
+ */
+cforge_int_t cforge_cmd_script(const cforge_context_t *ctx) {
+  std::string script_name;
+  std::vector<std::string> forwarded;
+  bool after_separator = false;
+
+  for (cforge_int_t i = 0; i < ctx->args.arg_count; ++i) {
+    std::string arg = ctx->args.args[i];
+    if (after_separator) {
+      forwarded.push_back(arg);
+    } else if (arg == "--") {
+      after_separator = true;
+    } else if (arg == "-h" || arg == "--help") {
+      cforge::command_registry::instance().print_command_help("script");
+      return 0;
+    } else if (!arg.empty() && arg[0] == '-') {
+      cforge::logger::print_error("Unknown option: " + arg);
+      cforge::logger::print_hint("pass arguments to the script after '--'");
+      return 1;
+    } else if (script_name.empty()) {
+      script_name = arg;
+    } else {
+      forwarded.push_back(arg);
+    }
+  }
+
+  std::filesystem::path project_dir = ctx->working_dir;
+  std::filesystem::path config_path =
+      cforge::get_script_config_path(project_dir, ctx->is_workspace);
+  if (!std::filesystem::exists(config_path)) {
+    cforge::logger::print_error("No " + std::string(CFORGE_FILE) + " found in "
+                                + project_dir.string());
+    return 1;
+  }
+
+  toml::table config;
+  try {
+    config = toml::parse_file(config_path.string());
+  } catch (const toml::parse_error &e) {
+    cforge::logger::print_error("Failed to parse " + config_path.string() + ": "
+                                + std::string(e.description()));
+    return 1;
+  }
+
+  auto scripts = cforge::read_named_scripts(config);
+  if (script_name.empty()) {
+    if (scripts.empty()) {
+      cforge::logger::print_status("No scripts defined in " + config_path.string());
+      cforge::logger::print_hint("add commands under [scripts.scripts]");
+      return 0;
+    }
+    print_named_scripts(scripts);
+    return 0;
+  }
+
+  auto order = cforge::resolve_script_order(scripts, script_name);
+  if (order.is_error()) {
+    order.error().print();
+    if (!scripts.count(script_name) && !scripts.empty()) {
+      print_named_scripts(scripts);
+    }
+    return 1;
+  }
+
+  // Forwarded arguments go to the requested script only, not its prerequisites
+  for (const auto &name : *order) {
+    bool is_target                 = name == script_name;
+    std::vector<std::string> extra = is_target ? forwarded : std::vector<std::string>{};
+    if (!cforge::run_named_script(scripts.at(name), project_dir, extra)) {
+      if (!is_target) {
+        cforge::logger::print_error("Not running '" + script_name + "' because '" + name
+                                    + "' failed");
+      }
+      return 1;
+    }
+  }
+  return 0;
+}
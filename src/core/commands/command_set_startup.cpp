@@ -0,0 +1,87 @@
+/**
+ * @file command_set_startup.cpp
+ * @brief Implementation of the 'set-startup' command to pick a workspace's startup project
+ */
+
+#include "cforge/log.hpp"
+
+#include "core/command_registry.hpp"
+#include "core/commands.hpp"
+#include "core/manifest_edit.hpp"
+#include "core/types.h"
+#include "core/workspace.hpp"
+#include "core/workspace_edit.hpp"
+
+#include <filesystem>
+#include <string>
+
+/**
+ * @brief Handle the 'set-startup' command
+ *
+ * @param ctx Context containing parsed arguments
+ * @return cforge_int_t Exit code (0 for success)
+ */
+cforge_int_t cforge_cmd_set_startup(const cforge_context_t *ctx) {
+  std::string project_name;
+
+  for (cforge_int_t i = 0; i < ctx->args.arg_count; ++i) {
+    std::string arg = ctx->args.args[i];
+    if (arg == "-h" || arg == "--help") {
+      cforge::command_registry::instance().print_command_help("set-startup");
+      return 0;
+    } else if (!arg.empty() && arg[0] == '-') {
+      cforge::logger::print_error("Unknown option: " + arg);
+      return 1;
+    } else if (project_name.empty()) {
+      project_name = arg;
+    }
+  }
+
+  if (project_name.empty()) {
+    cforge::logger::print_error("Project name not specified");
+    cforge::logger::print_status("Usage: cforge set-startup <project>");
+    return 1;
+  }
+
+  auto [is_workspace, workspace_dir] = cforge::is_in_workspace(ctx->working_dir);
+  if (!is_workspace) {
+    cforge::logger::print_error("Not in a workspace");
+    cforge::logger::print_hint("startup projects only apply to workspaces");
+    return 1;
+  }
+
+  cforge::workspace ws;
+  if (!ws.load(workspace_dir)) {
+    cforge::logger::print_error("Failed to load workspace configuration");
+    return 1;
+  }
+
+  if (!ws.get_project_by_name(project_name)) {
+    cforge::logger::print_error("'" + project_name + "' is not a project in workspace '"
+                                + ws.get_name() + "'");
+    std::string members;
+    for (const auto &p : ws.get_projects()) {
+      members += (members.empty() ? "" : ", ") + p.name;
+    }
+    if (!members.empty()) {
+      cforge::logger::print_hint("workspace projects: " + members);
+    }
+    return 1;
+  }
+
+  std::filesystem::path config_file = cforge::get_workspace_config_path(workspace_dir);
+  bool found       = false;
+  auto set_startup = [&](std::string &content) {
+    found = cforge::set_default_startup_project(content, project_name);
+    return found;
+  };
+  if (!cforge::write_manifest_edit(config_file, set_startup)) {
+    if (!found && std::filesystem::exists(config_file)) {
+      cforge::logger::print_error("No [workspace] table found in " + config_file.string());
+    }
+    return 1;
+  }
+
+  cforge::logger::print_action("Set", project_name + " as the startup project of " + ws.get_name());
+  return 0;
+}
@@ -25,6 +25,7 @@
 #include <cstring>
 #include <filesystem>
 #include <fstream>
+#include <regex>
 #include <sstream>
 #include <string>
 #include <vector>
@@ -42,12 +43,18 @@ namespace {
 struct TestOptions {
   std::string build_config = "Debug";
   std::string filter;
+  std::string framework;
   bool native_output   = false;
   bool no_build        = false;
   bool list_only       = false;
+  bool init            = false;
   bool verbose         = false;
   cforge_int_t jobs    = 0;
   cforge_int_t timeout = 0;
+  std::string repeat_mode;
+  cforge_int_t repeat_count = 1;
+  std::string label;
+  std::string exclude;
 };
 
 TestOptions parse_test_options(const cforge_context_t *ctx) {
@@ -68,16 +75,48 @@ TestOptions parse_test_options(const cforge_context_t *ctx) {
       opts.no_build = true;
     } else if (arg == "--list") {
       opts.list_only = true;
+    } else if (arg == "--init") {
+      opts.init = true;
+    } else if (arg == "--framework" && i + 1 < ctx->args.arg_count) {
+      opts.framework = ctx->args.args[++i];
     } else if (arg == "-v" || arg == "--verbose") {
       opts.verbose = true;
     } else if ((arg == "-f" || arg == "--filter") && i + 1 < ctx->args.arg_count) {
       opts.filter = ctx->args.args[++i];
-    } else if ((arg == "-j" || arg == "--jobs") && i + 1 < ctx->args.arg_count) {
+    } else if ((arg == "-j" || arg == "--jobs" || arg == "--parallel")
+               && i + 1 < ctx->args.arg_count) {
       opts.jobs = std::stoi(ctx->args.args[++i]);
+    } else if ((arg == "-L" || arg == "--label") && i + 1 < ctx->args.arg_count) {
+      opts.label = ctx->args.args[++i];
+    } else if ((arg == "-E" || arg == "--exclude") && i + 1 < ctx->args.arg_count) {
+      opts.exclude = ctx->args.args[++i];
+    } else if (arg == "--repeat" && i + 1 < ctx->args.arg_count) {
+      // Same syntax as ctest: <mode>:<n>; a bare count hunts for flaky tests
+      std::string spec = ctx->args.args[++i];
+      if (!spec.empty() && spec.find_first_not_of("0123456789") == std::string::npos) {
+        spec = "until-fail:" + spec;
+      }
+      cforge_size_t pos = spec.find(':');
+      std::string mode  = spec.substr(0, pos);
+      if ((mode == "until-pass" || mode == "until-fail" || mode == "after-timeout")
+          && pos != std::string::npos) {
+        try {
+          opts.repeat_mode  = mode;
+          opts.repeat_count = std::max(1, std::stoi(spec.substr(pos + 1)));
+        } catch (...) {
+          opts.repeat_mode.clear();
+        }
+      }
+      if (opts.repeat_mode.empty()) {
+        cforge::logger::print_warning("Ignoring invalid --repeat '" + spec
+                                      + "' (expected N, until-pass:N, until-fail:N or "
+                                        "after-timeout:N)");
+      }
     } else if (arg == "--timeout" && i + 1 < ctx->args.arg_count) {
       opts.timeout = std::stoi(ctx->args.args[++i]);
-    } else if (arg == "-c" || arg == "--config") {
-      // Skip - handled by ctx->args.config
+    } else if (arg == "-c" || arg == "--config" || arg == "--variant" || arg == "--target"
+               || arg == "--verbosity" || arg == "--format") {
+      // Skip - global options handled through ctx->args
       if (i + 1 < ctx->args.arg_count) {
         ++i;
       }
@@ -95,6 +134,141 @@ TestOptions parse_test_options(const cforge_context_t *ctx) {
   return opts;
 }
 
+/**
+ * @brief Check that --label and --exclude are valid regular expressions
+ */
+bool check_test_selection(const TestOptions &opts) {
+  for (const auto &[flag, pattern] : {std::make_pair("--label", opts.label),
+                                      std::make_pair("--exclude", opts.exclude)}) {
+    try {
+      std::regex re(pattern);
+    } catch (const std::regex_error &e) {
+      cforge::logger::print_error("Invalid " + std::string(flag) + " pattern '" + pattern
+                                  + "': " + e.what());
+      return false;
+    }
+  }
+  return true;
+}
+
+/**
+ * @brief Get the contents of a sample test file for a framework
+ *
+ * @param fw Test framework
+ * @param project_name Project name used in the file header
+ * @return std::string Sample test source
+ */
+std::string get_sample_test_source(cforge::test_framework fw, const std::string &project_name) {
+  std::ostringstream ss;
+  ss << "/**\n"
+     << " * @file test_example.cpp\n"
+     << " * @brief Example tests for " << project_name << "\n"
+     << " *\n"
+     << " * Run with: `cforge test`\n"
+     << " */\n\n";
+
+  switch (fw) {
+    case cforge::test_framework::GTest:
+      ss << "#include <gtest/gtest.h>\n\n"
+         << "TEST(Example, BasicAssertions) {\n"
+         << "  EXPECT_EQ(1 + 1, 2);\n"
+         << "  EXPECT_TRUE(2 * 3 == 6);\n"
+         << "}\n";
+      break;
+    case cforge::test_framework::Catch2:
+      ss << "#include <catch2/catch_test_macros.hpp>\n\n"
+         << "TEST_CASE(\"Example basic assertions\", \"[example]\") {\n"
+         << "  REQUIRE(1 + 1 == 2);\n"
+         << "  CHECK(2 * 3 == 6);\n"
+         << "}\n";
+      break;
+    case cforge::test_framework::Doctest:
+      ss << "#define DOCTEST_CONFIG_IMPLEMENT_WITH_MAIN\n"
+         << "#include <doctest/doctest.h>\n\n"
+         << "TEST_CASE(\"Example basic assertions\") {\n"
+         << "  CHECK(1 + 1 == 2);\n"
+         << "  CHECK(2 * 3 == 6);\n"
+         << "}\n";
+      break;
+    case cforge::test_framework::BoostTest:
+      ss << "#define BOOST_TEST_MODULE " << project_name << "_tests\n"
+         << "#include <boost/test/included/unit_test.hpp>\n\n"
+         << "BOOST_AUTO_TEST_CASE(example_basic_assertions) {\n"
+         << "  BOOST_CHECK_EQUAL(1 + 1, 2);\n"
+         << "  BOOST_CHECK(2 * 3 == 6);\n"
+         << "}\n";
+      break;
+    default:
+      ss << "#include \"test_framework.h\"\n\n"
+         << "TEST(Example, BasicAssertions) {\n"
+         << "  cf_assert(1 + 1 == 2);\n"
+         << "  cf_assert_eq(2 * 3, 6);\n"
+         << "  return 0;\n"
+         << "}\n";
+      break;
+  }
+
+  return ss.str();
+}
+
+/**
+ * @brief Scaffold the test directory with a sample test file
+ *
+ * The framework is taken from --framework, then test.framework in
+ * cforge.toml, and falls back to the builtin framework.
+ *
+ * @param project_dir Project directory
+ * @param cfg Project configuration
+ * @param opts Test options
+ * @return int Exit code (0 for success)
+ */
+cforge_int_t init_tests(const std::filesystem::path &project_dir,
+                        const cforge::toml_reader &cfg,
+                        const TestOptions &opts) {
+  namespace fs = std::filesystem;
+
+  std::string fw_name = opts.framework;
+  if (fw_name.empty()) {
+    fw_name = cfg.get_string("test.framework", "builtin");
+  }
+  cforge::test_framework fw = cforge::string_to_test_framework(cforge::string_to_lower(fw_name));
+  if (fw == cforge::test_framework::Auto && fw_name != "auto") {
+    cforge::logger::print_error("Unknown test framework: " + fw_name);
+    cforge::logger::print_status("Supported frameworks: builtin, gtest, catch2, doctest, boost");
+    return 1;
+  }
+
+  std::string test_dir = cfg.get_string("test.directory", "tests");
+  fs::path tests_dir   = project_dir / test_dir;
+  if (!fs::exists(tests_dir)) {
+    cforge::logger::print_action("Creating", "test directory: " + tests_dir.string());
+    fs::create_directories(tests_dir);
+  }
+
+  fs::path sample_path = tests_dir / "test_example.cpp";
+  if (fs::exists(sample_path)) {
+    cforge::logger::print_warning(test_dir + "/test_example.cpp already exists, skipping");
+    return 0;
+  }
+
+  std::ofstream out(sample_path);
+  if (!out) {
+    cforge::logger::print_error("Failed to write " + sample_path.string());
+    return 1;
+  }
+  out << get_sample_test_source(fw, cfg.get_string("project.name", "project"));
+  out.close();
+
+  cforge::logger::created(test_dir + "/test_example.cpp");
+  if (fw != cforge::test_framework::Builtin && fw != cforge::test_framework::Auto
+      && !cfg.has_key("test.framework")) {
+    cforge::logger::print_status("Set framework = \"" + cforge::test_framework_to_string(fw)
+                                 + "\" under [test] in " CFORGE_FILE " to pin the framework");
+  }
+  cforge::logger::print_status("Run 'cforge test' to build and run the tests");
+  return 0;
+}
+
 /**
  * @brief Run tests for a single project
  *
@@ -150,6 +324,11 @@ cforge_int_t run_tests_for_project(const std::filesystem::path &project_dir,
     cforge::logger::print_verbose("No test targets found in " + project_name);
     return 0;  // Not an error - project just has no tests
   }
+  if (runner.select_targets(opts.label, opts.exclude) == 0) {
+    cforge::logger::print_verbose("No test targets in " + project_name
+                                  + " match --label/--exclude");
+    return 0;
+  }
 
   // List mode
   if (opts.list_only) {
@@ -169,6 +348,8 @@ cforge_int_t run_tests_for_project(const std::filesystem::path &project_dir,
   run_opts.verbose          = opts.verbose;
   run_opts.jobs             = opts.jobs;
   run_opts.timeout_override = opts.timeout;
+  run_opts.repeat_mode      = opts.repeat_mode;
+  run_opts.repeat_count     = opts.repeat_count;
 
   // Execute tests
   summary_out = runner.run_tests(run_opts);
@@ -191,12 +372,18 @@ cforge_int_t run_tests_for_project(const std::filesystem::path &project_dir,
  *
  * OPTIONS:
  *   -c, --config <CONFIG>    Build configuration (Debug/Release)
- *   -j, --jobs <N>           Parallel test jobs
+ *   -j, --jobs <N>           Parallel test jobs (also --parallel)
  *   -f, --filter <PATTERN>   Filter tests by pattern
  *   --list                   List tests without running
+ *   --init                   Scaffold a sample test file
+ *   --framework <NAME>       Framework for --init (builtin, gtest, catch2, ...)
  *   --native                 Use framework's native output
  *   --no-build               Skip build step
  *   --timeout <SECONDS>      Override test timeout
+ *   --repeat <MODE:N>        Re-run targets (until-pass, until-fail, after-timeout);
+ *                            a bare N means until-fail:N
+ *   -L, --label <REGEX>      Only run targets with a matching label
+ *   -E, --exclude <REGEX>    Skip targets whose name matches
  *
  * FILTER:
  *   Positional filter, e.g., "math::*" or "Math.Add*"
@@ -220,6 +407,9 @@ cforge_int_t cforge_cmd_test(const cforge_context_t *ctx) {
 
   // Parse options first
   TestOptions opts = parse_test_options(ctx);
+  if (!check_test_selection(opts)) {
+    return 1;
+  }
 
   // Check if we're in a workspace
   auto [is_ws, workspace_dir] = cforge::is_in_workspace(current_dir);
@@ -234,6 +424,12 @@ cforge_int_t cforge_cmd_test(const cforge_context_t *ctx) {
 
     cforge::logger::print_header("Running tests for workspace: " + ws.get_name());
 
+    auto graph = ws.check_dependency_graph();
+    if (graph.is_error()) {
+      graph.error().print();
+      return 1;
+    }
+
     // Get projects in build order (respects dependencies)
     auto build_order = ws.get_build_order();
     auto projects    = ws.get_projects();
@@ -293,6 +489,9 @@ cforge_int_t cforge_cmd_test(const cforge_context_t *ctx) {
       total_summary.skipped        += project_summary.skipped;
       total_summary.timeout        += project_summary.timeout;
       total_summary.total_duration += project_summary.total_duration;
+      for (const auto &name : project_summary.failed_tests) {
+        total_summary.failed_tests.push_back(project.name + "::" + name);
+      }
 
       // Copy results with project prefix
       for (auto &r : project_results) {
@@ -337,6 +536,9 @@ cforge_int_t cforge_cmd_test(const cforge_context_t *ctx) {
 
     // Return failure if any tests failed
     if (total_summary.failed > 0 || total_summary.timeout > 0) {
+      if (!total_summary.failed_tests.empty()) {
+        cforge::logger::print_error(cforge::describe_failed_tests(total_summary.failed_tests));
+      }
       return 1;
     }
 
@@ -358,6 +560,11 @@ cforge_int_t cforge_cmd_test(const cforge_context_t *ctx) {
     return 1;
   }
 
+  // Scaffold a sample test and exit
+  if (opts.init) {
+    return init_tests(project_dir, cfg, opts);
+  }
+
   // Determine test directory
   std::string test_dir = cfg.get_string("test.directory", "tests");
   fs::path tests_dir   = project_dir / test_dir;
@@ -391,6 +598,11 @@ cforge_int_t cforge_cmd_test(const cforge_context_t *ctx) {
     cforge::logger::print_status("Or add [[test.targets]] to cforge.toml");
     return 0;
   }
+  if (runner.select_targets(opts.label, opts.exclude) == 0) {
+    cforge::logger::print_warning("No test targets match --label/--exclude");
+    cforge::logger::print_hint("labels are set with labels = [...] in [[test.targets]]");
+    return 0;
+  }
 
   // List mode
   if (opts.list_only) {
@@ -409,6 +621,8 @@ cforge_int_t cforge_cmd_test(const cforge_context_t *ctx) {
   run_opts.verbose          = opts.verbose;
   run_opts.jobs             = opts.jobs;
   run_opts.timeout_override = opts.timeout;
+  run_opts.repeat_mode      = opts.repeat_mode;
+  run_opts.repeat_count     = opts.repeat_count;
 
   // Execute tests
   cforge::test_summary summary = runner.run_tests(run_opts);
@@ -438,6 +652,9 @@ cforge_int_t cforge_cmd_test(const cforge_context_t *ctx) {
 
   // Return appropriate exit code
   if (summary.failed > 0 || summary.timeout > 0) {
+    if (!summary.failed_tests.empty()) {
+      cforge::logger::print_error(cforge::describe_failed_tests(summary.failed_tests));
+    }
     return 1;
   }
 
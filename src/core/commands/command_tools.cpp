@@ -5,19 +5,29 @@
 
 #include "cforge/log.hpp"
 
+#include "core/build_utils.hpp"
 #include "core/commands.hpp"
+#include "core/constants.h"
+#include "core/error_format.hpp"
 #include "core/process_utils.hpp"
 #include "core/toml_reader.hpp"
 #include "core/tool_installer.hpp"
 #include "core/types.h"
+#include "core/workspace.hpp"
 
 #include <fmt/core.h>
 
 #include <algorithm>
+#include <atomic>
 #include <chrono>
+#include <condition_variable>
+#include <deque>
 #include <filesystem>
 #include <fstream>
+#include <mutex>
+#include <set>
 #include <sstream>
+#include <thread>
 #include <vector>
 
 namespace fs = std::filesystem;
@@ -62,6 +72,81 @@ std::vector<fs::path> find_source_files(const fs::path &dir, bool include_header
   return files;
 }
 
+/**
+ * @brief Check whether a path lies in a dependency or build output directory
+ */
+bool is_excluded_from_formatting(const fs::path &path, const fs::path &project_dir) {
+  for (const auto &part : path.lexically_relative(project_dir)) {
+    std::string name = part.string();
+    if (name == "deps" || name == "vendor" || name == "build" || name.rfind("build-", 0) == 0) {
+      return true;
+    }
+  }
+  return false;
+}
+
+/**
+ * @brief Collect the files `cforge fmt` should touch in one project
+ *
+ * Uses build.source_dirs and build.include_dirs from cforge.toml (plus the
+ * conventional src/include/source/lib directories), skipping deps/ and
+ * build output directories.
+ */
+std::vector<fs::path> find_format_files(const fs::path &project_dir) {
+  std::vector<std::string> dirs = {"src", "include", "source", "lib"};
+
+  fs::path config_path = project_dir / CFORGE_FILE;
+  if (fs::exists(config_path)) {
+    cforge::toml_reader config;
+    if (config.load(config_path.string())) {
+      for (const auto &key : {"build.source_dirs", "build.include_dirs"}) {
+        auto extra = config.get_string_array(key);
+        dirs.insert(dirs.end(), extra.begin(), extra.end());
+      }
+    }
+  }
+
+  std::set<fs::path> seen;
+  std::vector<fs::path> files;
+  for (const auto &dir : dirs) {
+    fs::path dir_path = (project_dir / dir).lexically_normal();
+    if (is_excluded_from_formatting(dir_path, project_dir)) {
+      continue;
+    }
+    for (const auto &file : find_source_files(dir_path, true)) {
+      if (!is_excluded_from_formatting(file, project_dir) && seen.insert(file).second) {
+        files.push_back(file);
+      }
+    }
+  }
+
+  return files;
+}
+
+/**
+ * @brief Write a .clang-format for the given style using clang-format -dump-config
+ *
+ * @return true on success
+ */
+bool write_clang_format_config(const std::string &clang_format,
+                               const fs::path &dir,
+                               const std::string &style) {
+  auto result = cforge::execute_process(clang_format, {"-style=" + style, "-dump-config"}, "");
+  if (!result.success || result.stdout_output.empty()) {
+    cforge::logger::print_error("clang-format could not dump the '" + style + "' style");
+    return false;
+  }
+
+  std::ofstream out(dir / ".clang-format");
+  if (!out) {
+    cforge::logger::print_error("Failed to write " + (dir / ".clang-format").string());
+    return false;
+  }
+  out << result.stdout_output;
+  cforge::logger::created(".clang-format (" + style + " style)");
+  return true;
+}
+
 /**
  * @brief Check if a tool is available in PATH
  */
@@ -405,6 +490,7 @@ cforge_int_t cforge_cmd_fmt(const cforge_context_t *ctx) {
   // Parse arguments
   bool check_only   = false;
   bool dry_run      = false;
+  bool init_config  = false;
   std::string style = "file";  // Default: use .clang-format file
 
   for (cforge_int_t i = 0; i < ctx->args.arg_count; i++) {
@@ -413,11 +499,28 @@ cforge_int_t cforge_cmd_fmt(const cforge_context_t *ctx) {
       check_only = true;
     } else if (arg == "--dry-run") {
       dry_run = true;
+    } else if (arg == "--init") {
+      init_config = true;
     } else if (arg == "--style" && i + 1 < ctx->args.arg_count) {
       style = ctx->args.args[++i];
     }
   }
 
+  // In a workspace root, format every member project
+  std::vector<fs::path> project_dirs;
+  auto [in_workspace, workspace_root] = cforge::is_in_workspace(project_dir);
+  if (in_workspace && fs::equivalent(project_dir, workspace_root)) {
+    cforge::workspace ws;
+    if (ws.load(workspace_root)) {
+      for (const auto &project : ws.get_projects()) {
+        project_dirs.push_back(project.path);
+      }
+    }
+  }
+  if (project_dirs.empty()) {
+    project_dirs.push_back(project_dir);
+  }
+
   // Find clang-format. If missing, offer to install via the platform's
   // package manager. After a successful install, prefer the absolute path the
   // installer dropped (PATH in this process won't reflect the new install
@@ -441,16 +544,29 @@ cforge_int_t cforge_cmd_fmt(const cforge_context_t *ctx) {
     }
   }
 
+  // Respect an existing .clang-format; otherwise create one with --init, or
+  // on the first run that actually rewrites files
+  bool has_config =
+      fs::exists(project_dir / ".clang-format") || fs::exists(project_dir / "_clang-format");
+  if (init_config) {
+    if (has_config) {
+      cforge::logger::print_status(".clang-format already exists, leaving it unchanged");
+      return 0;
+    }
+    std::string init_style = style == "file" ? "LLVM" : style;
+    return write_clang_format_config(clang_format, project_dir, init_style) ? 0 : 1;
+  }
+  if (!has_config && style == "file" && !check_only && !dry_run) {
+    write_clang_format_config(clang_format, project_dir, "LLVM");
+  }
+
   cforge::logger::print_action("Formatting", "source files with " + clang_format);
 
   // Find source files
   std::vector<fs::path> files;
-
-  // Check src and include directories
-  for (const auto &dir : {"src", "include", "source", "lib"}) {
-    fs::path dir_path = project_dir / dir;
-    auto dir_files    = find_source_files(dir_path, true);
-    files.insert(files.end(), dir_files.begin(), dir_files.end());
+  for (const auto &dir : project_dirs) {
+    auto project_files = find_format_files(dir);
+    files.insert(files.end(), project_files.begin(), project_files.end());
   }
 
   if (files.empty()) {
@@ -544,11 +660,12 @@ cforge_int_t cforge_cmd_fmt(const cforge_context_t *ctx) {
  */
 cforge_int_t cforge_cmd_lint(const cforge_context_t *ctx) {
   fs::path project_dir = ctx->working_dir;
-  fs::path build_dir   = project_dir / "build";
 
   // Parse arguments
   bool fix           = false;
   std::string checks = "";
+  cforge_int_t jobs  = 0;
+  std::vector<std::string> file_filters;
 
   for (cforge_int_t i = 0; i < ctx->args.arg_count; i++) {
     std::string arg = ctx->args.args[i];
@@ -556,9 +673,51 @@ cforge_int_t cforge_cmd_lint(const cforge_context_t *ctx) {
       fix = true;
     } else if (arg == "--checks" && i + 1 < ctx->args.arg_count) {
       checks = ctx->args.args[++i];
+    } else if ((arg == "-j" || arg == "--jobs") && i + 1 < ctx->args.arg_count) {
+      try {
+        jobs = std::stoi(ctx->args.args[++i]);
+      } catch (...) {
+        cforge::logger::print_warning("Invalid --jobs value, using the default");
+      }
+    } else if ((arg == "--config" || arg == "-c") && i + 1 < ctx->args.arg_count) {
+      ++i;  // Handled by get_build_config below
+    } else if (!arg.empty() && arg[0] != '-') {
+      file_filters.push_back(fs::path(arg).lexically_normal().generic_string());
     }
   }
 
+  // Analyze the active build directory, and default the check list from
+  // [lint] in cforge.toml
+  cforge::toml_reader project_config;
+  bool has_project_config = fs::exists(project_dir / CFORGE_FILE)
+                         && project_config.load((project_dir / CFORGE_FILE).string());
+  const cforge::toml_reader *config_ptr = has_project_config ? &project_config : nullptr;
+
+  std::string config = cforge::get_build_config(
+      ctx->args.config, ctx->args.arg_count, ctx->args.args, config_ptr);
+  fs::path build_dir = cforge::get_build_dir_for_config(
+      cforge::get_build_base_dir(project_dir, config_ptr).string(), config, false);
+
+  if (checks.empty() && has_project_config) {
+    checks = project_config.get_string("lint.checks", "");
+    if (checks.empty()) {
+      for (const auto &check : project_config.get_string_array("lint.checks")) {
+        checks += (checks.empty() ? "" : ",") + check;
+      }
+    }
+  }
+  if (jobs <= 0 && has_project_config) {
+    jobs = static_cast<cforge_int_t>(project_config.get_int("lint.jobs", 0));
+  }
+  if (jobs <= 0) {
+    jobs = static_cast<cforge_int_t>(std::max(1u, std::thread::hardware_concurrency()));
+  }
+  if (fix && jobs > 1) {
+    // Parallel -fix runs can race on headers shared between translation units
+    cforge::logger::print_verbose("Running clang-tidy serially because --fix was given");
+    jobs = 1;
+  }
+
   // Find clang-tidy. If missing, offer to install via the platform's package
   // manager. On install success we prefer the absolute path returned by the
   // installer — the current process's PATH won't reflect the new entry until
@@ -603,8 +762,20 @@ cforge_int_t cforge_cmd_lint(const cforge_context_t *ctx) {
     cforge::logger::print_verbose(
         "Re-running cmake configure with CMAKE_EXPORT_COMPILE_COMMANDS=ON…");
 
-    std::vector<std::string> cmake_args = {
-        "-B", build_dir.string(), "-S", project_dir.string(), "-DCMAKE_EXPORT_COMPILE_COMMANDS=ON"};
+    // Configure from scratch if the project has never been built
+    if (has_project_config && !fs::exists(project_dir / "CMakeLists.txt")
+        && !cforge::generate_cmakelists_from_toml(project_dir, project_config, false)) {
+      cforge::logger::print_error("Failed to generate CMakeLists.txt");
+      return 1;
+    }
+
+    std::vector<std::string> cmake_args = {"-B",
+                                           build_dir.string(),
+                                           "-S",
+                                           project_dir.string(),
+                                           "-DCMAKE_BUILD_TYPE="
+                                               + cforge::canonical_build_config(config),
+                                           "-DCMAKE_EXPORT_COMPILE_COMMANDS=ON"};
     auto result = cforge::execute_process("cmake", cmake_args, project_dir.string());
     if (result.exit_code != 0 || !cdb_usable()) {
       cforge::logger::print_error("Could not generate a usable compile_commands.json");
@@ -628,6 +799,23 @@ cforge_int_t cforge_cmd_lint(const cforge_context_t *ctx) {
     files.insert(files.end(), dir_files.begin(), dir_files.end());
   }
 
+  // Optional file filter: keep files whose path contains any of the given
+  // fragments (e.g. `cforge lint src/net` or `cforge lint main.cpp`)
+  if (!file_filters.empty()) {
+    files.erase(std::remove_if(files.begin(),
+                               files.end(),
+                               [&](const fs::path &f) {
+                                 std::string path = f.generic_string();
+                                 return std::none_of(
+                                     file_filters.begin(),
+                                     file_filters.end(),
+                                     [&](const std::string &filter) {
+                                       return path.find(filter) != std::string::npos;
+                                     });
+                               }),
+                files.end());
+  }
+
   if (files.empty()) {
     cforge::logger::print_warning("No source files found to analyze");
     return 0;
@@ -751,8 +939,8 @@ cforge_int_t cforge_cmd_lint(const cforge_context_t *ctx) {
                                   + effective_checks);
   }
 
-  for (cforge_size_t i = 0; i < files.size(); ++i) {
-    const auto &file = files[i];
+  // Run clang-tidy on one file and return its output lines
+  auto run_clang_tidy = [&](const fs::path &file) {
     std::vector<std::string> args;
     args.push_back("-p");
     args.push_back(build_dir.string());
@@ -767,13 +955,11 @@ cforge_int_t cforge_cmd_lint(const cforge_context_t *ctx) {
 
     args.push_back(file.string());
 
-    // Collect lines silently while clang-tidy runs, then emit after we
-    // print the cargo-style step + bar so the order is:
-    //   Analyzing X [i/N]
-    //   Analyzing [=====>     ] i/N (t)
-    //   <any warnings/errors for X>
+    // Collect lines silently while clang-tidy runs; the main thread prints
+    // them once the file is done so output from parallel workers never
+    // interleaves.
     std::vector<std::string> deferred;
-    auto result = cforge::execute_process(
+    cforge::execute_process(
         clang_tidy, args, project_dir.string(), [&deferred](const std::string &chunk) {
           // Split into lines but keep them buffered until the file finishes.
           std::string buf     = chunk;
@@ -793,6 +979,58 @@ cforge_int_t cforge_cmd_lint(const cforge_context_t *ctx) {
             }
           }
         });
+    return deferred;
+  };
+
+  // Worker pool: each worker takes the next file index and hands its output
+  // back to the main thread, which owns all terminal output.
+  struct lint_result {
+    cforge_size_t index = 0;
+    std::vector<std::string> lines;
+  };
+  std::mutex results_mutex;
+  std::condition_variable results_cv;
+  std::deque<lint_result> results;
+  std::atomic<cforge_size_t> next_file{0};
+  std::atomic<bool> stop_workers{false};
+
+  std::vector<std::thread> workers;
+  cforge_size_t worker_count = std::min(files.size(), static_cast<cforge_size_t>(jobs));
+  for (cforge_size_t w = 0; w < worker_count; ++w) {
+    workers.emplace_back([&]() {
+      while (!stop_workers) {
+        cforge_size_t index = next_file++;
+        if (index >= files.size()) {
+          break;
+        }
+        lint_result result;
+        result.index = index;
+        result.lines = run_clang_tidy(files[index]);
+        {
+          std::lock_guard<std::mutex> lock(results_mutex);
+          results.push_back(std::move(result));
+        }
+        results_cv.notify_one();
+      }
+    });
+  }
+  auto join_workers = [&]() {
+    stop_workers = true;
+    for (auto &worker : workers) {
+      worker.join();
+    }
+  };
+
+  for (cforge_size_t i = 0; i < files.size(); ++i) {
+    lint_result result;
+    {
+      std::unique_lock<std::mutex> lock(results_mutex);
+      results_cv.wait(lock, [&]() { return !results.empty(); });
+      result = std::move(results.front());
+      results.pop_front();
+    }
+    const auto &file                         = files[result.index];
+    const std::vector<std::string> &deferred = result.lines;
 
     cforge::logger::progress_step(
         "Analyzing", file.filename().string(), static_cast<cforge_int_t>(i + 1), total);
@@ -816,6 +1054,7 @@ cforge_int_t cforge_cmd_lint(const cforge_context_t *ctx) {
       fmt::print(stderr, "\r\033[K");
       std::fflush(stderr);
       cforge::logger::reset_progress_display();
+      join_workers();
       cforge::logger::print_error("clang-tidy could not analyze " + file.filename().string()
                                   + " (printed its help text instead of running)");
       cforge::logger::print_hint(
@@ -873,18 +1112,38 @@ cforge_int_t cforge_cmd_lint(const cforge_context_t *ctx) {
       std::fflush(stderr);
       cforge::logger::reset_progress_display();
     }
+    // Render through the same Rust-style pipeline as compiler diagnostics;
+    // fall back to the raw lines if nothing could be parsed
+    std::string kept_text;
     for (const auto &line : kept) {
-      if (line.find("warning:") != std::string::npos) {
-        warnings++;
-        cforge::logger::print_warning(line);
-      } else if (line.find("error:") != std::string::npos) {
-        errors++;
-        cforge::logger::print_error(line);
-      } else {
-        cforge::logger::print_plain(line);
+      kept_text += line + "\n";
+    }
+    auto diagnostics = kept.empty() ? std::vector<cforge::diagnostic>()
+                                    : cforge::extract_diagnostics(kept_text);
+    if (!diagnostics.empty()) {
+      for (const auto &diag : diagnostics) {
+        if (diag.level == cforge::diagnostic_level::ERROR) {
+          errors++;
+        } else if (diag.level == cforge::diagnostic_level::WARNING) {
+          warnings++;
+        }
+        cforge::print_diagnostic(diag);
+      }
+    } else {
+      for (const auto &line : kept) {
+        if (line.find("warning:") != std::string::npos) {
+          warnings++;
+          cforge::logger::print_warning(line);
+        } else if (line.find("error:") != std::string::npos) {
+          errors++;
+          cforge::logger::print_error(line);
+        } else {
+          cforge::logger::print_plain(line);
+        }
       }
     }
   }
+  join_workers();
 
   if (total > 0) {
     fmt::print(stderr, "\r\033[K");
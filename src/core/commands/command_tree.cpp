@@ -6,8 +6,12 @@
 #include "cforge/log.hpp"
 
 #include "core/commands.hpp"
+#include "core/constants.h"
+#include "core/fetchcontent_deps.hpp"
+#include "core/registry.hpp"
 #include "core/toml_reader.hpp"
 #include "core/types.h"
+#include "core/vcpkg_manifest.hpp"
 #include "core/workspace.hpp"
 
 #include <filesystem>
@@ -21,9 +25,11 @@ namespace {
 
 struct dependency_info {
   std::string name;
-  std::string type;  // "git", "vcpkg", "system", "project"
+  std::string type;  // "git", "vcpkg", "conan", "system", "archive", "fetchcontent",
+                     // "subdirectory", "project"
   std::string version;
   std::string url;
+  std::string detail;  // Shown next to the type, e.g. the vcpkg package spec
   std::vector<std::string> children;
 };
 
@@ -81,10 +87,20 @@ void print_tree_branch(const std::string &name,
   } else if (info.type == "system") {
     color          = fmt::color::yellow;
     type_indicator = " (system)";
+  } else if (info.type == "conan") {
+    color          = fmt::color::orchid;
+    type_indicator = " (conan)";
+  } else if (info.type == "archive" || info.type == "fetchcontent"
+             || info.type == "subdirectory") {
+    color          = fmt::color::light_gray;
+    type_indicator = " (" + info.type + ")";
   } else if (info.type == "project") {
     color          = fmt::color::green;
     type_indicator = " (project)";
   }
+  if (!info.detail.empty() && !type_indicator.empty()) {
+    type_indicator.insert(type_indicator.size() - 1, ": " + info.detail);
+  }
 
   std::string version_str;
   if (!info.version.empty()) {
@@ -94,7 +110,8 @@ void print_tree_branch(const std::string &name,
   // Use logger for output with colored name
   std::string branch = prefix + (is_last ? "`-- " : "|-- ");
   std::string output = fmt::format(
-      "{}{}{}{}", branch, fmt::format(fg(color), "{}", name), version_str, type_indicator);
+      "{}{}{}{}", branch, fmt::format(
+          cforge::logger::style(fg(color)), "{}", name), version_str, type_indicator);
   cforge::logger::print_plain(output);
 
   // Print children
@@ -119,15 +136,61 @@ void print_tree_branch(const std::string &name,
   }
 }
 
+/**
+ * @brief Format features as a vcpkg package spec suffix ("[ssl,http2]")
+ */
+std::string feature_suffix(const std::vector<std::string> &features) {
+  if (features.empty()) {
+    return "";
+  }
+  std::string suffix = "[";
+  for (cforge_size_t i = 0; i < features.size(); i++) {
+    suffix += (i > 0 ? "," : "") + features[i];
+  }
+  return suffix + "]";
+}
+
+/**
+ * @brief Print the per-type dependency count line
+ */
+void print_dependency_summary(const std::map<std::string, dependency_info> &all_deps) {
+  static const std::vector<std::string> types = {
+      "index", "git", "vcpkg", "conan", "system", "archive", "fetchcontent", "subdirectory",
+      "project"};
+
+  std::map<std::string, cforge_int_t> counts;
+  for (const auto &[name, info] : all_deps) {
+    counts[info.type]++;
+  }
+
+  std::string summary;
+  for (const auto &type : types) {
+    if (counts[type] > 0) {
+      summary += (summary.empty() ? "Dependencies: " : ", ") + std::to_string(counts[type]) + " "
+               + type;
+    }
+  }
+  if (!summary.empty()) {
+    cforge::logger::print_plain(summary);
+  }
+}
+
 /**
  * @brief Collect dependencies from a project
  */
-[[maybe_unused]] void collect_dependencies(const fs::path & /*project_dir*/,
-                                           const cforge::toml_reader &config,
-                                           std::map<std::string, dependency_info> &deps) {
+void collect_dependencies(const fs::path &project_dir,
+                          const cforge::toml_reader &config,
+                          std::map<std::string, dependency_info> &deps) {
   // Special keys that are not package names
-  static const std::set<std::string> special_keys = {
-      "directory", "git", "vcpkg", "system", "project", "fetch_content"};
+  static const std::set<std::string> special_keys = {"directory",
+                                                     "git",
+                                                     "vcpkg",
+                                                     "system",
+                                                     "project",
+                                                     "archive",
+                                                     "fetchcontent",
+                                                     "subdirectory",
+                                                     "fetch_content"};
 
   // First, collect registry/index packages (direct key = "version" entries)
   if (config.has_key("dependencies")) {
@@ -245,6 +308,81 @@ void print_tree_branch(const std::string &name,
     }
   }
 
+  // vcpkg dependencies ([dependencies.vcpkg.<name>] tables)
+  for (const auto &dep : config.get_table_keys("dependencies.vcpkg")) {
+    if (cforge::is_vcpkg_setting_key(dep)) {
+      continue;
+    }
+    std::string key = "dependencies.vcpkg." + dep;
+    dependency_info info;
+    info.name    = dep;
+    info.type    = "vcpkg";
+    info.version = config.get_string(key + ".version", "");
+    info.detail  = dep;
+    info.detail += feature_suffix(config.get_string_array(key + ".features"));
+    std::string triplet = config.get_string("dependencies.vcpkg.triplet", "");
+    if (!triplet.empty()) {
+      info.detail += ":" + triplet;
+    }
+    deps[dep] = info;
+  }
+
+  // vcpkg and conan packages from the unified syntax (fmt = { vcpkg = "fmt" })
+  for (const auto &spec : cforge::parse_dependencies(project_dir / CFORGE_FILE)) {
+    if (spec.source != cforge::dependency_source::VCPKG
+        && spec.source != cforge::dependency_source::CONAN) {
+      continue;
+    }
+    dependency_info info;
+    info.name    = spec.name;
+    info.type    = spec.source == cforge::dependency_source::VCPKG ? "vcpkg" : "conan";
+    info.version = spec.version == "*" ? "" : spec.version;
+    info.detail  = spec.vcpkg_name.empty() ? spec.name : spec.vcpkg_name;
+    info.detail += feature_suffix(spec.features);
+    deps[spec.name] = info;
+  }
+
+  // Archive dependencies
+  for (const auto &dep : config.get_table_keys("dependencies.archive")) {
+    dependency_info info;
+    info.name = dep;
+    info.type = "archive";
+    info.url  = config.get_string("dependencies.archive." + dep + ".url", "");
+    deps[dep] = info;
+  }
+
+  // FetchContent dependencies
+  for (const auto &dep : config.get_table_keys("dependencies.fetchcontent")) {
+    std::string key = "dependencies.fetchcontent." + dep;
+    dependency_info info;
+    info.name    = dep;
+    info.type    = "fetchcontent";
+    info.url     = config.get_string(key + ".git", config.get_string(key + ".url", ""));
+    info.version = config.get_string(key + ".tag", "");
+    if (cforge::is_git_commit_sha(info.version)) {
+      info.version = info.version.substr(0, 8);
+    }
+    deps[dep] = info;
+  }
+
+  // Subdirectory dependencies
+  for (const auto &dep : config.get_table_keys("dependencies.subdirectory")) {
+    dependency_info info;
+    info.name   = dep;
+    info.type   = "subdirectory";
+    info.detail = config.get_string("dependencies.subdirectory." + dep + ".path", "");
+    deps[dep]   = info;
+  }
+
+  // System dependencies ([dependencies.system.<name>] tables)
+  for (const auto &dep : config.get_table_keys("dependencies.system")) {
+    dependency_info info;
+    info.name   = dep;
+    info.type   = "system";
+    info.detail = config.get_string("dependencies.system." + dep + ".method", "");
+    deps[dep]   = info;
+  }
+
   // System dependencies (old style)
   if (config.has_key("dependencies.system")) {
     auto sys_deps = config.get_string_array("dependencies.system");
@@ -254,6 +392,14 @@ void print_tree_branch(const std::string &name,
       info.type = "system";
       deps[dep] = info;
     }
+    for (const auto &entry : config.get_table_array("dependencies.system")) {
+      dependency_info info;
+      info.name = entry.get_string("name", "");
+      info.type = "system";
+      if (!info.name.empty()) {
+        deps[info.name] = info;
+      }
+    }
   }
 
   // Project dependencies (old style: [dependencies.project])
@@ -363,18 +509,21 @@ void print_project_graph(const std::vector<std::string> &project_names,
 
   cforge::logger::print_plain("  Project graph:");
   for (const auto &n : project_names) {
-    std::string padded = fmt::format("{:>{}}", "", max_len - n.size())
-                       + fmt::format(fg(fmt::color::green) | fmt::emphasis::bold, "{}", n);
+    std::string padded =
+        fmt::format("{:>{}}", "", max_len - n.size())
+        + fmt::format(cforge::logger::style(fg(fmt::color::green) | fmt::emphasis::bold), "{}", n);
     const auto &targets = adj[n];
     std::string rhs;
     if (targets.empty()) {
-      rhs = fmt::format(fg(fmt::color::gray) | fmt::emphasis::faint, "(none)");
+      rhs = fmt::format(
+          cforge::logger::style(fg(fmt::color::gray) | fmt::emphasis::faint), "(none)");
     } else {
       for (size_t i = 0; i < targets.size(); ++i) {
         if (i) {
           rhs += ", ";
         }
-        rhs += fmt::format(fg(fmt::color::green) | fmt::emphasis::bold, "{}", targets[i]);
+        rhs += fmt::format(
+            cforge::logger::style(fg(fmt::color::green) | fmt::emphasis::bold), "{}", targets[i]);
       }
     }
     cforge::logger::print_plain("  " + padded + " -> " + rhs);
@@ -610,48 +759,7 @@ cforge_int_t cforge_cmd_tree(const cforge_context_t *ctx) {
 
     // Print summary
     cforge::logger::print_blank();
-    cforge_int_t index_count = 0, git_count = 0, vcpkg_count = 0, sys_count = 0, proj_count = 0;
-    for (const auto &[name, info] : all_deps) {
-      if (info.type == "index") {
-        index_count++;
-      } else if (info.type == "git") {
-        git_count++;
-      } else if (info.type == "vcpkg") {
-        vcpkg_count++;
-      } else if (info.type == "system") {
-        sys_count++;
-      } else if (info.type == "project") {
-        proj_count++;
-      }
-    }
-
-    std::vector<std::string> summary_parts;
-    if (index_count > 0) {
-      summary_parts.push_back(std::to_string(index_count) + " index");
-    }
-    if (git_count > 0) {
-      summary_parts.push_back(std::to_string(git_count) + " git");
-    }
-    if (vcpkg_count > 0) {
-      summary_parts.push_back(std::to_string(vcpkg_count) + " vcpkg");
-    }
-    if (sys_count > 0) {
-      summary_parts.push_back(std::to_string(sys_count) + " system");
-    }
-    if (proj_count > 0) {
-      summary_parts.push_back(std::to_string(proj_count) + " project");
-    }
-
-    if (!summary_parts.empty()) {
-      std::string summary = "Dependencies: ";
-      for (cforge_size_t i = 0; i < summary_parts.size(); i++) {
-        if (i > 0) {
-          summary += ", ";
-        }
-        summary += summary_parts[i];
-      }
-      cforge::logger::print_plain(summary);
-    }
+    print_dependency_summary(all_deps);
 
     // 5. Conflict detection (always run after summary)
     auto conflicts = detect_conflicts(roots, all_deps);
@@ -727,48 +835,7 @@ cforge_int_t cforge_cmd_tree(const cforge_context_t *ctx) {
 
     // Print summary
     cforge::logger::print_blank();
-    cforge_int_t index_count = 0, git_count = 0, vcpkg_count = 0, sys_count = 0, proj_count = 0;
-    for (const auto &[name, info] : all_deps) {
-      if (info.type == "index") {
-        index_count++;
-      } else if (info.type == "git") {
-        git_count++;
-      } else if (info.type == "vcpkg") {
-        vcpkg_count++;
-      } else if (info.type == "system") {
-        sys_count++;
-      } else if (info.type == "project") {
-        proj_count++;
-      }
-    }
-
-    std::vector<std::string> summary_parts;
-    if (index_count > 0) {
-      summary_parts.push_back(std::to_string(index_count) + " index");
-    }
-    if (git_count > 0) {
-      summary_parts.push_back(std::to_string(git_count) + " git");
-    }
-    if (vcpkg_count > 0) {
-      summary_parts.push_back(std::to_string(vcpkg_count) + " vcpkg");
-    }
-    if (sys_count > 0) {
-      summary_parts.push_back(std::to_string(sys_count) + " system");
-    }
-    if (proj_count > 0) {
-      summary_parts.push_back(std::to_string(proj_count) + " project");
-    }
-
-    if (!summary_parts.empty()) {
-      std::string summary = "Dependencies: ";
-      for (cforge_size_t i = 0; i < summary_parts.size(); i++) {
-        if (i > 0) {
-          summary += ", ";
-        }
-        summary += summary_parts[i];
-      }
-      cforge::logger::print_plain(summary);
-    }
+    print_dependency_summary(all_deps);
 
     return 0;
   }
@@ -0,0 +1,245 @@
+/**
+ * @file command_uninstall.cpp
+ * @brief Implementation of the 'uninstall' command to remove the files of a
+ *        `cforge install --prefix`
+ */
+
+#include "cforge/log.hpp"
+
+#include "core/command_registry.hpp"
+#include "core/commands.hpp"
+#include "core/constants.h"
+#include "core/install_manifest.hpp"
+#include "core/installer.hpp"
+#include "core/toml_reader.hpp"
+#include "core/types.h"
+
+#include <filesystem>
+#include <fstream>
+#include <sstream>
+#include <string>
+#include <utility>
+#include <vector>
+
+namespace {
+
+/**
+ * @brief An install record and the file it was read from
+ */
+struct stored_install_record {
+  std::filesystem::path path;
+  cforge::install_record record;
+};
+
+/**
+ * @brief Read every install record
+ */
+std::vector<stored_install_record> load_install_records() {
+  std::vector<stored_install_record> records;
+  std::error_code ec;
+  for (const auto &entry :
+       std::filesystem::directory_iterator(cforge::installer::get_install_records_dir(), ec)) {
+    if (entry.path().extension() != ".manifest") {
+      continue;
+    }
+    std::ifstream in(entry.path());
+    std::stringstream text;
+    text << in.rdbuf();
+    if (auto record = cforge::parse_install_record(text.str())) {
+      records.push_back({entry.path(), std::move(*record)});
+    }
+  }
+  return records;
+}
+
+/**
+ * @brief Name of the project or workspace in a directory, empty if there is none
+ */
+std::string local_project_name(const std::filesystem::path &dir) {
+  cforge::toml_reader config;
+  if (!std::filesystem::exists(dir / CFORGE_FILE) || !config.load((dir / CFORGE_FILE).string())) {
+    return "";
+  }
+  return config.get_string("project.name", config.get_string("workspace.name", ""));
+}
+
+}  // namespace
+
+/**
+ * @brief Handle the 'uninstall' command
+ *
+ * @param ctx Context containing parsed arguments
+ * @return cforge_int_t Exit code (0 for success)
+ */
+cforge_int_t cforge_cmd_uninstall(const cforge_context_t *ctx) {
+  std::string project;
+  std::string prefix;
+  bool force   = false;
+  bool dry_run = false;
+  bool list    = false;
+
+  for (cforge_int_t i = 0; i < ctx->args.arg_count; ++i) {
+    std::string arg = ctx->args.args[i];
+    if (arg == "-h" || arg == "--help") {
+      cforge::command_registry::instance().print_command_help("uninstall");
+      return 0;
+    } else if (arg == "--prefix") {
+      if (i + 1 >= ctx->args.arg_count) {
+        cforge::logger::print_error("--prefix requires a path");
+        return 1;
+      }
+      prefix = ctx->args.args[++i];
+    } else if (arg.rfind("--prefix=", 0) == 0) {
+      prefix = arg.substr(9);
+    } else if (arg == "--force") {
+      force = true;
+    } else if (arg == "--dry-run") {
+      dry_run = true;
+    } else if (arg == "--list") {
+      list = true;
+    } else if (arg.rfind("-", 0) != 0 && project.empty()) {
+      project = arg;
+    } else {
+      cforge::logger::print_error("Unknown option: " + arg);
+      return 1;
+    }
+  }
+
+  std::vector<stored_install_record> records = load_install_records();
+
+  if (list) {
+    if (records.empty()) {
+      cforge::logger::print_status("No recorded installs");
+      return 0;
+    }
+    cforge::logger::print_section("Recorded installs:");
+    for (const auto &stored : records) {
+      const auto &record = stored.record;
+      std::string name   = record.version.empty() ? record.project
+                                                  : record.project + " " + record.version;
+      cforge::logger::print_kv(name,
+                               record.prefix + " (" + std::to_string(record.files.size())
+                                   + " files, " + record.date + ")",
+                               24);
+    }
+    return 0;
+  }
+
+  if (project.empty()) {
+    project = local_project_name(ctx->working_dir);
+  }
+  if (project.empty() && prefix.empty()) {
+    cforge::logger::print_error("No project given and no cforge project in the current directory");
+    cforge::logger::print_hint("run 'cforge uninstall --list' to see the recorded installs");
+    return 1;
+  }
+
+  std::string wanted_prefix;
+  if (!prefix.empty()) {
+    wanted_prefix = std::filesystem::absolute(prefix).lexically_normal().string();
+  }
+  std::vector<const stored_install_record *> matches;
+  for (const auto &stored : records) {
+    if ((project.empty() || stored.record.project == project)
+        && (wanted_prefix.empty() || stored.record.prefix == wanted_prefix)) {
+      matches.push_back(&stored);
+    }
+  }
+
+  std::string what = project.empty() ? "prefix " + wanted_prefix : "'" + project + "'";
+  if (matches.empty()) {
+    cforge::logger::print_error("No recorded install of " + what
+                                + (project.empty() || wanted_prefix.empty()
+                                       ? ""
+                                       : " in " + wanted_prefix));
+    cforge::logger::print_hint("only installs made with 'cforge install --prefix' are recorded; "
+                               "run 'cforge uninstall --list' to see them");
+    return 1;
+  }
+  if (matches.size() > 1) {
+    cforge::logger::print_error("Several recorded installs of " + what + ":");
+    for (const auto *stored : matches) {
+      cforge::logger::print_list_item(stored->record.project + " in " + stored->record.prefix);
+    }
+    cforge::logger::print_hint(project.empty() ? "name the project to uninstall"
+                                               : "pick one with --prefix");
+    return 1;
+  }
+
+  const stored_install_record &stored  = *matches.front();
+  const cforge::install_record &record = stored.record;
+
+  // A manifest pointing outside its prefix was edited or came from an
+  // install with absolute DESTINATIONs; removing those files needs consent
+  std::vector<std::string> outside = cforge::files_outside_prefix(record);
+  if (!outside.empty() && !force) {
+    cforge::logger::print_error("The install record of '" + record.project + "' lists "
+                                + std::to_string(outside.size()) + " file"
+                                + (outside.size() == 1 ? "" : "s") + " outside "
+                                + record.prefix + ":");
+    for (const auto &file : outside) {
+      cforge::logger::print_list_item(file);
+    }
+    cforge::logger::print_hint("pass --force to remove them as well");
+    return 1;
+  }
+
+  std::string label = record.version.empty() ? record.project
+                                             : record.project + " " + record.version;
+  if (dry_run) {
+    cforge::logger::print_status("Dry run: uninstalling " + label + " from " + record.prefix
+                                 + " would remove:");
+    for (const auto &file : record.files) {
+      cforge::logger::print_plain("  " + file);
+    }
+    return 0;
+  }
+
+  cforge::logger::removing(label + " from " + record.prefix);
+  std::vector<std::string> failed;
+  cforge_size_t removed = 0;
+  for (const auto &file : record.files) {
+    std::error_code ec;
+    if (!std::filesystem::exists(std::filesystem::symlink_status(file, ec))) {
+      cforge::logger::print_verbose("Already gone: " + file);
+      continue;
+    }
+    if (std::filesystem::remove(file, ec)) {
+      ++removed;
+    } else {
+      failed.push_back(file);
+      cforge::logger::print_verbose("Could not remove " + file + ": " + ec.message());
+    }
+  }
+
+  // Directories the install created are removed once they are empty
+  for (const auto &dir : cforge::install_record_directories(record)) {
+    std::error_code ec;
+    if (std::filesystem::is_directory(dir, ec) && std::filesystem::is_empty(dir, ec)) {
+      std::filesystem::remove(dir, ec);
+    }
+  }
+
+  std::error_code ec;
+  if (!failed.empty()) {
+    // Keep the files that are left, so a second attempt can finish the job
+    cforge::install_record remaining = record;
+    remaining.files                  = failed;
+    std::ofstream out(stored.path);
+    out << cforge::format_install_record(remaining);
+
+    cforge::logger::print_error("Could not remove " + std::to_string(failed.size()) + " file"
+                                + (failed.size() == 1 ? "" : "s") + ":");
+    for (const auto &file : failed) {
+      cforge::logger::print_list_item(file);
+    }
+    cforge::logger::print_hint("check the permissions of " + record.prefix
+                               + " and run 'cforge uninstall' again");
+    return 1;
+  }
+
+  std::filesystem::remove(stored.path, ec);
+  cforge::logger::finished("uninstall of " + label + " (" + std::to_string(removed) + " file"
+                           + (removed == 1 ? "" : "s") + " removed)");
+  return 0;
+}
@@ -1,25 +1,361 @@
 /**
  * @file command_version.cpp
- * @brief Implementation of the 'version' command to display cforge version
+ * @brief Implementation of the 'version' command to show cforge's version and
+ *        show or bump the project's version
  */
 
 #include "cforge/log.hpp"
 
+#include "core/build_utils.hpp"
+#include "core/command_registry.hpp"
 #include "core/commands.hpp"
 #include "core/constants.h"
+#include "core/git_utils.hpp"
+#include "core/manifest_edit.hpp"
+#include "core/toml_reader.hpp"
+#include "core/types.h"
+#include "core/version.hpp"
+#include "core/workspace.hpp"
 
+#include <filesystem>
+#include <optional>
 #include <string>
+#include <vector>
+
+namespace {
+
+/**
+ * @brief A project whose version is shown or bumped
+ */
+struct versioned_project {
+  std::string name;
+  std::filesystem::path dir;
+  std::string version;  ///< project.version, empty if not set
+};
 
 /**
- * @brief Display cforge version information
+ * @brief Read a project's name and version from its cforge.toml
  *
- * @param ctx Context containing parsed arguments
- * @return cforge_int_t Exit code (0 for success)
+ * @return false if the directory has no cforge.toml with a [project] table
+ */
+bool load_versioned_project(const std::filesystem::path &dir, versioned_project &project) {
+  cforge::toml_reader config;
+  if (!config.load((dir / CFORGE_FILE).string()) || !config.has_key("project")) {
+    return false;
+  }
+  project.dir     = dir;
+  project.name    = config.get_string("project.name", dir.filename().string());
+  project.version = config.get_string("project.version", "");
+  return true;
+}
+
+/**
+ * @brief Check that a release can be tagged from `dir`
+ *
+ * The bump is committed before it is tagged, so the work tree must have no
+ * other changes to tracked files, and the tag must not exist yet.
+ *
+ * @return An empty string if tagging can go ahead, otherwise the reason it can't
+ */
+std::string check_taggable(const std::filesystem::path &dir, const std::string &tag) {
+  if (!cforge::is_git_available()) {
+    return "git is not installed";
+  }
+  cforge::git_result inside = cforge::git_execute({"rev-parse", "--is-inside-work-tree"},
+                                                  dir.string());
+  if (!inside.success) {
+    return dir.string() + " is not in a git repository";
+  }
+  cforge::git_result status =
+      cforge::git_execute({"status", "--porcelain", "--untracked-files=no"}, dir.string());
+  if (!status.success) {
+    return "git status failed: " + status.error;
+  }
+  if (status.output.find_first_not_of(" \n\r\t") != std::string::npos) {
+    return "the work tree has uncommitted changes";
+  }
+  if (cforge::git_execute({"rev-parse", "-q", "--verify", "refs/tags/" + tag}, dir.string())
+          .success) {
+    return "tag " + tag + " already exists";
+  }
+  return "";
+}
+
+/**
+ * @brief Regenerate the files derived from project.version
+ *
+ * CMakeLists.txt passes the version to project(), which sets the version
+ * write_basic_package_version_file() puts in <name>ConfigVersion.cmake, so
+ * it is regenerated if the project has one. cforge_build_info.h is rewritten
+ * in every build directory that already has one.
+ */
+void refresh_versioned_files(const versioned_project &project) {
+  cforge::toml_reader config;
+  if (!config.load((project.dir / CFORGE_FILE).string())) {
+    return;
+  }
+
+  if (std::filesystem::exists(project.dir / "CMakeLists.txt")
+      && !cforge::generate_cmakelists_from_toml(project.dir, config, false)) {
+    cforge::logger::print_warning("Failed to regenerate CMakeLists.txt for " + project.name);
+  }
+
+  if (!cforge::is_build_info_enabled(config)) {
+    return;
+  }
+  // Single-config generators build into <build>-<config>, next to <build>
+  std::filesystem::path base = cforge::get_build_base_dir(project.dir, &config);
+  std::error_code ec;
+  for (const auto &entry : std::filesystem::directory_iterator(base.parent_path(), ec)) {
+    std::string name = entry.path().filename().string();
+    if (name != base.filename().string() && name.rfind(base.filename().string() + "-", 0) != 0) {
+      continue;
+    }
+    if (std::filesystem::exists(entry.path() / "cforge_generated" / "cforge_build_info.h", ec)) {
+      cforge::write_build_info_header(project.dir, entry.path(), config);
+    }
+  }
+}
+
+/**
+ * @brief Point other members' [dependencies.project.<name>] version
+ *        constraints at the new version
+ *
+ * @param projects Every project being bumped
+ * @param version The new version
  */
-cforge_int_t cforge_cmd_version([[maybe_unused]] const cforge_context_t *ctx) {
+void retarget_member_constraints(const std::vector<versioned_project> &projects,
+                                 const std::string &version) {
+  for (const auto &project : projects) {
+    cforge::toml_reader config;
+    if (!config.load((project.dir / CFORGE_FILE).string())) {
+      continue;
+    }
+    for (const auto &member : projects) {
+      std::string key = "dependencies.project." + member.name + ".version";
+      if (!config.has_key(key)) {
+        continue;
+      }
+      std::string constraint = config.get_string(key, "");
+      auto retargeted        = cforge::retarget_version_constraint(constraint, version);
+      if (!retargeted) {
+        cforge::logger::print_warning(project.name + ": left " + key + " = \"" + constraint
+                                      + "\" unchanged");
+        continue;
+      }
+      auto set_constraint = [&](std::string &content) {
+        cforge::set_manifest_value(content, key, "\"" + *retargeted + "\"");
+        return true;
+      };
+      if (*retargeted != constraint
+          && cforge::write_manifest_edit(project.dir / CFORGE_FILE, set_constraint, key)) {
+        cforge::logger::print_action("Updated",
+                                     project.name + ": " + key + " = \"" + *retargeted + "\"");
+      }
+    }
+  }
+}
+
+/**
+ * @brief Print cforge's version, and the versions of the projects here
+ */
+cforge_int_t print_versions(const std::filesystem::path &working_dir) {
   cforge::logger::print_plain("cforge version " + std::string(CFORGE_VERSION));
   cforge::logger::print_dim("C++ Project Management Tool");
   cforge::logger::print_dim("Copyright (c) 2023-2024");
 
+  std::vector<versioned_project> projects;
+  versioned_project project;
+  if (load_versioned_project(working_dir, project)) {
+    projects.push_back(project);
+  } else {
+    auto [is_workspace, workspace_dir] = cforge::is_in_workspace(working_dir);
+    cforge::workspace ws;
+    std::error_code ec;
+    if (is_workspace && std::filesystem::equivalent(workspace_dir, working_dir, ec)
+        && ws.load(workspace_dir)) {
+      for (const auto &member : ws.get_projects()) {
+        if (load_versioned_project(member.path, project)) {
+          projects.push_back(project);
+        }
+      }
+    }
+  }
+
+  if (!projects.empty()) {
+    cforge::logger::print_blank();
+  }
+  for (const auto &p : projects) {
+    cforge::logger::print_kv(p.name, p.version.empty() ? "(no version set)" : p.version);
+  }
+  return 0;
+}
+
+}  // namespace
+
+/**
+ * @brief Handle the 'version' command
+ *
+ * `cforge version` prints cforge's version and the project's;
+ * `cforge version bump <major|minor|patch>` or `--set <version>` rewrites
+ * project.version and the files generated from it.
+ *
+ * @param ctx Context containing parsed arguments
+ * @return cforge_int_t Exit code (0 for success)
+ */
+cforge_int_t cforge_cmd_version(const cforge_context_t *ctx) {
+  std::vector<std::string> positional;
+  std::string set_version;
+  bool use_workspace = false;
+  bool tag           = false;
+
+  for (cforge_int_t i = 0; i < ctx->args.arg_count; ++i) {
+    std::string arg = ctx->args.args[i];
+    if (arg == "-h" || arg == "--help") {
+      cforge::command_registry::instance().print_command_help("version");
+      return 0;
+    } else if (arg == "--workspace") {
+      use_workspace = true;
+    } else if (arg == "--tag") {
+      tag = true;
+    } else if (arg == "--set" && i + 1 < ctx->args.arg_count) {
+      set_version = ctx->args.args[++i];
+    } else if (arg.rfind("--set=", 0) == 0) {
+      set_version = arg.substr(6);
+    } else if (arg.rfind("-", 0) == 0) {
+      cforge::logger::print_error("Unknown option: " + arg);
+      return 1;
+    } else {
+      positional.push_back(arg);
+    }
+  }
+
+  std::filesystem::path working_dir(ctx->working_dir);
+  if (positional.empty() && set_version.empty() && !use_workspace && !tag) {
+    return print_versions(working_dir);
+  }
+  if (positional.empty() || positional[0] != "bump") {
+    cforge::logger::print_error(positional.empty() ? "No action given"
+                                                   : "Unknown action '" + positional[0] + "'");
+    cforge::logger::print_hint("usage: cforge version bump <major|minor|patch> | --set <version>");
+    return 1;
+  }
+  std::string part = positional.size() > 1 ? positional[1] : "";
+  if (positional.size() > 2 || part.empty() == set_version.empty()) {
+    cforge::logger::print_error("Give either major, minor or patch, or --set <version>");
+    return 1;
+  }
+  if (!set_version.empty()) {
+    auto parsed = cforge::semver::parse(set_version);
+    if (!parsed || parsed->major < 0 || parsed->minor < 0 || parsed->patch < 0) {
+      cforge::logger::print_error("'" + set_version + "' is not a version");
+      cforge::logger::print_hint("expected MAJOR.MINOR.PATCH, e.g. 2.1.0");
+      return 1;
+    }
+    set_version = parsed->to_string();
+  }
+
+  // The projects to bump: this one, or every workspace member in lockstep
+  std::vector<versioned_project> projects;
+  std::filesystem::path repo_dir = working_dir;
+  if (use_workspace) {
+    auto [is_workspace, workspace_dir] = cforge::is_in_workspace(working_dir);
+    cforge::workspace ws;
+    if (!is_workspace || !ws.load(workspace_dir)) {
+      cforge::logger::print_error("Not in a workspace");
+      return 1;
+    }
+    repo_dir = workspace_dir;
+    for (const auto &member : ws.get_projects()) {
+      versioned_project project;
+      if (load_versioned_project(member.path, project)) {
+        projects.push_back(project);
+      }
+    }
+  } else {
+    versioned_project project;
+    if (!load_versioned_project(working_dir, project)) {
+      cforge::logger::print_error("No project in " + working_dir.string());
+      cforge::logger::print_hint("run inside a project, or pass --workspace to bump every "
+                                 "workspace project");
+      return 1;
+    }
+    projects.push_back(project);
+  }
+  if (projects.empty()) {
+    cforge::logger::print_error("The workspace has no projects");
+    return 1;
+  }
+
+  // Lockstep bumps start from the highest version among the projects
+  std::string new_version = set_version;
+  if (new_version.empty()) {
+    std::optional<cforge::semver> current;
+    for (const auto &project : projects) {
+      auto version = cforge::semver::parse(project.version);
+      if (!version) {
+        cforge::logger::print_error(project.name + ": project.version '" + project.version
+                                    + "' is not a version");
+        cforge::logger::print_hint("set one with 'cforge version bump --set <version>'");
+        return 1;
+      }
+      if (!current || *version > *current) {
+        current = version;
+      }
+    }
+    auto bumped = cforge::bump_version(current->to_string(), part);
+    if (!bumped) {
+      cforge::logger::print_error("Unknown version part '" + part + "'");
+      cforge::logger::print_hint("expected major, minor or patch");
+      return 1;
+    }
+    new_version = *bumped;
+  }
+
+  std::string tag_name = "v" + new_version;
+  if (tag) {
+    std::string problem = check_taggable(repo_dir, tag_name);
+    if (!problem.empty()) {
+      cforge::logger::print_error("Can't tag " + tag_name + ": " + problem);
+      return 1;
+    }
+  }
+
+  for (const auto &project : projects) {
+    auto set_version_text = [&](std::string &content) {
+      cforge::set_manifest_value(content, "project.version", "\"" + new_version + "\"");
+      return true;
+    };
+    if (!cforge::write_manifest_edit(project.dir / CFORGE_FILE, set_version_text,
+                                     "project.version")) {
+      return 1;
+    }
+    cforge::logger::print_action("Bumped",
+                                 project.name + " "
+                                     + (project.version.empty() ? "(none)" : project.version)
+                                     + " -> " + new_version);
+  }
+  if (use_workspace) {
+    retarget_member_constraints(projects, new_version);
+  }
+  for (const auto &project : projects) {
+    refresh_versioned_files(project);
+  }
+
+  if (tag) {
+    cforge::git_result commit = cforge::git_execute(
+        {"commit", "-a", "-m", "Bump version to " + new_version}, repo_dir.string());
+    if (!commit.success) {
+      cforge::logger::print_error("git commit failed: " + commit.error);
+      return 1;
+    }
+    cforge::git_result created = cforge::git_execute(
+        {"tag", "-a", tag_name, "-m", "Version " + new_version}, repo_dir.string());
+    if (!created.success) {
+      cforge::logger::print_error("git tag failed: " + created.error);
+      return 1;
+    }
+    cforge::logger::print_action("Tagged", tag_name);
+  }
   return 0;
-}
\ No newline at end of file
+}
@@ -61,6 +61,8 @@ std::string error_code_name(error_code code) {
       return "CONFIG_MISSING_FIELD";
     case error_code::CONFIG_INVALID_TOML:
       return "CONFIG_INVALID_TOML";
+    case error_code::CONFIG_UNKNOWN_KEY:
+      return "CONFIG_UNKNOWN_KEY";
     case error_code::WORKSPACE_NOT_FOUND:
       return "WORKSPACE_NOT_FOUND";
     case error_code::WORKSPACE_INVALID:
@@ -80,6 +82,8 @@ std::string error_code_name(error_code code) {
       return "DEP_INVALID_SPEC";
     case error_code::DEP_CIRCULAR:
       return "DEP_CIRCULAR";
+    case error_code::DEP_INTEGRITY_MISMATCH:
+      return "DEP_INTEGRITY_MISMATCH";
     case error_code::REGISTRY_UPDATE_FAILED:
       return "REGISTRY_UPDATE_FAILED";
     case error_code::REGISTRY_OFFLINE:
@@ -101,6 +105,8 @@ std::string error_code_name(error_code code) {
       return "BUILD_TARGET_NOT_FOUND";
     case error_code::BUILD_BINARY_NOT_FOUND:
       return "BUILD_BINARY_NOT_FOUND";
+    case error_code::BUILD_COMPILER_TOO_OLD:
+      return "BUILD_COMPILER_TOO_OLD";
 
     case error_code::CACHE_NOT_FOUND:
       return "CACHE_NOT_FOUND";
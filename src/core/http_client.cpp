@@ -5,6 +5,7 @@
 
 #include "core/http_client.hpp"
 
+#include "core/command.h"
 #include "core/types.h"
 
 #include <algorithm>
@@ -353,11 +354,19 @@ std::optional<http_response> http_client::perform_request(const std::string &met
 
 std::optional<http_response> http_client::get(const std::string &url,
                                               const http_request_options &options) {
+  if (cforge_is_offline()) {
+    last_error_ = "offline mode: not downloading " + url;
+    return std::nullopt;
+  }
   return perform_request("GET", url, {}, options);
 }
 
 std::optional<http_response> http_client::head(const std::string &url,
                                                const http_request_options &options) {
+  if (cforge_is_offline()) {
+    last_error_ = "offline mode: not contacting " + url;
+    return std::nullopt;
+  }
   return perform_request("HEAD", url, {}, options);
 }
 
@@ -365,6 +374,10 @@ std::optional<http_response> http_client::put(const std::string &url,
                                               const std::vector<char> &body,
                                               const std::string &content_type,
                                               const http_request_options &options) {
+  if (cforge_is_offline()) {
+    last_error_ = "offline mode: not uploading to " + url;
+    return std::nullopt;
+  }
   auto opts                    = options;
   opts.headers["Content-Type"] = content_type;
   return perform_request("PUT", url, body, opts);
@@ -7,6 +7,7 @@
 
 #include "cforge/log.hpp"
 
+#include "core/command.h"
 #include "core/process_utils.hpp"
 #include "core/toml_reader.hpp"
 #include "core/types.h"
@@ -92,6 +93,11 @@ bool registry::update(bool force) {
     return true;
   }
 
+  if (cforge_is_offline()) {
+    logger::print_verbose("Offline mode: using the cached package index");
+    return std::filesystem::exists(index_dir_ / "packages");
+  }
+
   // Create cache directory
   std::filesystem::create_directories(cache_dir_);
 
@@ -818,6 +824,10 @@ std::optional<resolved_dependency> registry::resolve_dependency(const dependency
     case dependency_source::PROJECT:
       resolved.path = spec.path;
       break;
+
+    case dependency_source::CONAN:
+      resolved.version = spec.version;
+      break;
   }
 
   return resolved;
@@ -860,6 +870,9 @@ dependency_source registry::parse_source(const std::string &source) {
   if (source == "project") {
     return dependency_source::PROJECT;
   }
+  if (source == "conan") {
+    return dependency_source::CONAN;
+  }
   return dependency_source::INDEX;
 }
 
@@ -875,6 +888,8 @@ std::string registry::source_to_string(dependency_source source) {
       return "system";
     case dependency_source::PROJECT:
       return "project";
+    case dependency_source::CONAN:
+      return "conan";
   }
   return "index";
 }
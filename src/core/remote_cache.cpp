@@ -7,6 +7,8 @@
 
 #include "cforge/log.hpp"
 
+#include "core/command.h"
+#include "core/process_utils.hpp"
 #include "core/types.h"
 
 #include <cstdlib>
@@ -141,7 +143,7 @@ std::string remote_cache::get_package_url(const cache_key &key) const {
 }
 
 bool remote_cache::is_available() const {
-  return config_.is_valid() && http_client::is_available();
+  return config_.is_valid() && !cforge_is_offline() && http_client::is_available();
 }
 
 bool remote_cache::test_connection() const {
@@ -179,19 +181,19 @@ bool remote_cache::has(const cache_key &key) const {
 
 bool remote_cache::create_archive(const std::filesystem::path &source,
                                   const std::filesystem::path &dest) {
-  // Use system tar command for portability
-  std::stringstream cmd;
-
-#ifdef _WIN32
-  // On Windows, try to use tar if available (Windows 10+)
-  cmd << "tar -czf \"" << dest.string() << "\" -C \"" << source.parent_path().string() << "\" \""
-      << source.filename().string() << "\"";
-#else
-  cmd << "tar -czf '" << dest.string() << "' -C '" << source.parent_path().string() << "' '"
-      << source.filename().string() << "'";
-#endif
-
-  return system(cmd.str().c_str()) == 0;
+  // Use the tar command for portability (bundled with Windows 10+). Paths are
+  // passed as separate arguments, so no shell quoting is involved.
+  process_result result = execute_process("tar",
+                                          {"-czf",
+                                           dest.string(),
+                                           "-C",
+                                           source.parent_path().string(),
+                                           source.filename().string()},
+                                          "",
+                                          nullptr,
+                                          nullptr,
+                                          600);
+  return result.success;
 }
 
 bool remote_cache::extract_archive(const std::filesystem::path &source,
@@ -199,15 +201,9 @@ bool remote_cache::extract_archive(const std::filesystem::path &source,
   // Create destination directory
   std::filesystem::create_directories(dest);
 
-  std::stringstream cmd;
-
-#ifdef _WIN32
-  cmd << "tar -xzf \"" << source.string() << "\" -C \"" << dest.string() << "\"";
-#else
-  cmd << "tar -xzf '" << source.string() << "' -C '" << dest.string() << "'";
-#endif
-
-  return system(cmd.str().c_str()) == 0;
+  process_result result = execute_process(
+      "tar", {"-xzf", source.string(), "-C", dest.string()}, "", nullptr, nullptr, 600);
+  return result.success;
 }
 
 bool remote_cache::fetch(const cache_key &key,
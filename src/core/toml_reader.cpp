@@ -7,7 +7,9 @@
 
 #include "cforge/log.hpp"
 
+#include "core/config_interpolation.hpp"
 #include "core/types.h"
+#include "core/workspace.hpp"
 
 #include <filesystem>
 #include <fstream>
@@ -67,8 +69,13 @@ bool toml_reader::load(const std::string &filepath) {
       return false;
     }
 
-    // Parse the file
+    // Parse the file; a project inside a workspace inherits its [defaults]
     toml_data = std::make_unique<toml::table>(toml::parse_file(filepath));
+    apply_workspace_defaults(*toml_data, filepath);
+    if (!interpolate_config_values(*toml_data, filepath)) {
+      toml_data.reset();
+      return false;
+    }
     return true;
   } catch (const toml::parse_error &err) {
     std::stringstream ss;
@@ -82,6 +89,18 @@ bool toml_reader::load(const std::string &filepath) {
   }
 }
 
+bool interpolate_config_values(toml::table &config, const std::string &filepath) {
+  std::vector<config_interpolation_error> errors = interpolate_config_table(config);
+  for (const auto &error : errors) {
+    logger::print_error(filepath + ": " + error.key + " uses ${env:" + error.variable
+                        + "}, which is not set");
+  }
+  if (!errors.empty()) {
+    logger::print_hint("set the variable, or give a default with ${env:NAME:-default}");
+  }
+  return errors.empty();
+}
+
 std::string toml_reader::get_string(const std::string &key,
                                     const std::string &default_value) const {
   if (!toml_data) {
@@ -173,6 +192,23 @@ bool toml_reader::has_key(const std::string &key) const {
   }
 }
 
+cforge_size_t toml_reader::get_line(const std::string &key) const {
+  if (!toml_data) {
+    return 0;
+  }
+
+  try {
+    auto &table = *toml_data;
+    auto view   = table.at_path(key);
+    if (!view || !view.node()) {
+      return 0;
+    }
+    return static_cast<cforge_size_t>(view.node()->source().begin.line);
+  } catch (...) {
+    return 0;
+  }
+}
+
 std::vector<std::string> toml_reader::get_table_keys(const std::string &table_name) const {
   std::vector<std::string> result;
   if (!toml_data) {
@@ -8,6 +8,7 @@
 #include "cforge/log.hpp"
 
 #include "core/build_utils.hpp"
+#include "core/platform_settings.hpp"
 #include "core/process_utils.hpp"
 #include "core/workspace.hpp"
 
@@ -538,6 +539,11 @@ public:
 
     std::ostringstream cmake;
     cmake << "# Google Benchmark\n";
+    if (!config.fetch) {
+      // Provided by vcpkg/conan/the system (see benchmark_runner::load_config)
+      cmake << "find_package(benchmark CONFIG REQUIRED)\n\n";
+      return cmake.str();
+    }
     cmake << "include(FetchContent)\n";
     cmake << "FetchContent_Declare(\n";
     cmake << "  benchmark\n";
@@ -766,28 +772,39 @@ benchmark_runner::benchmark_runner(const fs::path &project_dir, const toml_reade
 benchmark_runner::~benchmark_runner() = default;
 
 bool benchmark_runner::load_config() {
+  m_section = benchmark_config_section(m_project_config);
+
   // Load benchmark directory
   m_bench_config.directory = m_project_dir
-                           / m_project_config.get_string("benchmark.directory", "bench");
+                           / m_project_config.get_string(m_section + ".directory", "bench");
 
   // Resolve the project's build directory (so bench output sits next to test
   // output instead of in a parallel hidden tree).
-  std::string build_dir_str = m_project_config.get_string("build.build_dir", "");
-  if (build_dir_str.empty()) {
-    build_dir_str = m_project_config.get_string("build.directory", "build");
-  }
-  m_build_base_dir = m_project_dir / build_dir_str;
+  m_build_base_dir = get_build_base_dir(m_project_dir, &m_project_config);
 
   // Load framework
-  std::string fw_str               = m_project_config.get_string("benchmark.framework", "auto");
+  std::string fw_str = m_project_config.get_string(m_section + ".framework", "auto");
   m_bench_config.default_framework = string_to_benchmark_framework(fw_str);
 
   // Load build type
-  m_bench_config.default_build_type = m_project_config.get_string("benchmark.build_type",
+  m_bench_config.default_build_type = m_project_config.get_string(m_section + ".build_type",
                                                                   "Release");
 
   // Load auto-link setting
-  m_bench_config.auto_link_project = m_project_config.get_bool("benchmark.auto_link_project", true);
+  m_bench_config.auto_link_project = m_project_config.get_bool(m_section + ".auto_link_project",
+                                                               true);
+
+  m_bench_config.sources = m_project_config.get_string_array(m_section + ".sources");
+  m_bench_config.links   = m_project_config.get_string_array(m_section + ".links");
+
+  // Google Benchmark comes from vcpkg/conan/the system when cforge.toml lists
+  // it there; otherwise it is fetched with FetchContent
+  bool from_package_manager =
+      is_package_manager_dependency(m_project_dir, m_project_config, {"benchmark"});
+  benchmark_config::FrameworkConfig google;
+  google.fetch   = m_project_config.get_bool(m_section + ".fetch", !from_package_manager);
+  google.version = m_project_config.get_string(m_section + ".version", "");
+  m_bench_config.framework_configs[benchmark_framework::GoogleBench] = google;
 
   return true;
 }
@@ -841,12 +858,88 @@ fs::path benchmark_runner::generate_main_if_needed(const benchmark_target &targe
   return main_path;
 }
 
+// Match a file name against a pattern where '*' matches any run of characters
+static bool wildcard_match(const std::string &pattern, const std::string &name) {
+  cforge_size_t p = 0, n = 0, star = std::string::npos, resume = 0;
+  while (n < name.size()) {
+    if (p < pattern.size() && (pattern[p] == name[n] || pattern[p] == '?')) {
+      ++p;
+      ++n;
+    } else if (p < pattern.size() && pattern[p] == '*') {
+      star   = p++;
+      resume = n;
+    } else if (star != std::string::npos) {
+      p = star + 1;
+      n = ++resume;
+    } else {
+      return false;
+    }
+  }
+  while (p < pattern.size() && pattern[p] == '*') {
+    ++p;
+  }
+  return p == pattern.size();
+}
+
+// Resolve source entries against base_dir. A '*' in the file name part is a
+// wildcard over that directory (e.g. "benches/*.cpp"); plain paths must exist.
+static std::vector<fs::path> expand_source_globs(const fs::path &base_dir,
+                                                 const std::vector<std::string> &sources) {
+  std::vector<fs::path> files;
+  for (const auto &src : sources) {
+    fs::path src_path = src;
+    if (src_path.is_relative()) {
+      src_path = base_dir / src_path;
+    }
+
+    if (src.find('*') == std::string::npos && src.find('?') == std::string::npos) {
+      if (fs::exists(src_path)) {
+        files.push_back(src_path);
+      }
+      continue;
+    }
+
+    fs::path search_dir      = src_path.parent_path();
+    std::string file_pattern = src_path.filename().string();
+    if (!fs::is_directory(search_dir)) {
+      continue;
+    }
+    std::vector<fs::path> matched;
+    for (const auto &entry : fs::directory_iterator(search_dir)) {
+      if (entry.is_regular_file()
+          && wildcard_match(file_pattern, entry.path().filename().string())) {
+        matched.push_back(entry.path());
+      }
+    }
+    std::sort(matched.begin(), matched.end());
+    files.insert(files.end(), matched.begin(), matched.end());
+  }
+  return files;
+}
+
 std::vector<benchmark_target> benchmark_runner::discover_targets() {
   std::vector<benchmark_target> targets;
 
-  // Auto-discover from benchmark directory
-  auto auto_targets = auto_discover_targets();
-  targets.insert(targets.end(), auto_targets.begin(), auto_targets.end());
+  if (!m_bench_config.sources.empty()) {
+    // Section-level `sources` replaces auto-discovery with a single target
+    benchmark_target target;
+    target.name         = "benchmarks";
+    target.sources      = m_bench_config.sources;
+    target.source_files = expand_source_globs(m_project_dir, target.sources);
+    if (target.source_files.empty()) {
+      logger::print_warning(m_section + ".sources matched no files");
+    } else {
+      target.framework = detect_framework(target.source_files.front());
+      if (target.framework == benchmark_framework::Auto) {
+        target.framework = m_bench_config.default_framework;
+      }
+      targets.push_back(target);
+    }
+  } else {
+    // Auto-discover from benchmark directory
+    auto auto_targets = auto_discover_targets();
+    targets.insert(targets.end(), auto_targets.begin(), auto_targets.end());
+  }
 
   // Load explicit targets from [[benchmark.targets]]
   auto explicit_targets = load_explicit_targets();
@@ -932,21 +1025,22 @@ std::vector<benchmark_target> benchmark_runner::load_explicit_targets() {
   std::vector<benchmark_target> targets;
 
   // Load explicitly defined targets from [[benchmark.targets]] array
-  auto target_tables = m_project_config.get_table_array("benchmark.targets");
+  auto target_tables = m_project_config.get_table_array(m_section + ".targets");
   for (const auto &table : target_tables) {
     benchmark_target target;
 
     // Name is required
     target.name = table.get_string("name", "");
     if (target.name.empty()) {
-      logger::print_warning("[[benchmark.targets]] entry missing 'name', skipping");
+      logger::print_warning("[[" + m_section + ".targets]] entry missing 'name', skipping");
       continue;
     }
 
     // Load source files
     target.sources = table.get_string_array("sources");
     if (target.sources.empty()) {
-      logger::print_warning("[[benchmark.targets]] '" + target.name + "' has no sources, skipping");
+      logger::print_warning("[[" + m_section + ".targets]] '" + target.name
+                            + "' has no sources, skipping");
       continue;
     }
 
@@ -955,47 +1049,20 @@ std::vector<benchmark_target> benchmark_runner::load_explicit_targets() {
     target.defines      = table.get_string_array("defines");
     target.includes     = table.get_string_array("includes");
     target.enabled      = table.get_bool("enabled", true);
+    std::vector<std::string> unknown_platforms;
+    target.platform_defines = read_platform_lists(table, "platform_defines", unknown_platforms);
+    target.platform_flags   = read_platform_lists(table, "platform_flags", unknown_platforms);
+    for (const auto &os : unknown_platforms) {
+      logger::print_warning("[[" + m_section + ".targets]] '" + target.name
+                            + "': unknown platform '" + os + "' (use windows, linux or macos)");
+    }
 
     // Parse framework (default to auto-detection)
     std::string fw_str = table.get_string("framework", "auto");
     target.framework   = string_to_benchmark_framework(fw_str);
 
     // Expand source globs and resolve paths
-    for (const auto &src : target.sources) {
-      fs::path src_path = src;
-      if (src_path.is_relative()) {
-        src_path = m_bench_config.directory / src_path;
-      }
-
-      // Handle glob patterns
-      if (src.find('*') != std::string::npos) {
-        // Simple glob expansion for common patterns
-        std::string pattern      = src_path.string();
-        fs::path search_dir      = src_path.parent_path();
-        std::string file_pattern = src_path.filename().string();
-
-        if (fs::exists(search_dir)) {
-          for (const auto &entry : fs::directory_iterator(search_dir)) {
-            if (!entry.is_regular_file()) {
-              continue;
-            }
-
-            std::string filename = entry.path().filename().string();
-            // Simple wildcard matching (*.cpp matches any .cpp file)
-            if (file_pattern == "*.cpp" || file_pattern == "*.cc" || file_pattern == "*.cxx") {
-              std::string ext = entry.path().extension().string();
-              if ((file_pattern == "*.cpp" && ext == ".cpp")
-                  || (file_pattern == "*.cc" && ext == ".cc")
-                  || (file_pattern == "*.cxx" && ext == ".cxx")) {
-                target.source_files.push_back(entry.path());
-              }
-            }
-          }
-        }
-      } else if (fs::exists(src_path)) {
-        target.source_files.push_back(src_path);
-      }
-    }
+    target.source_files = expand_source_globs(m_bench_config.directory, target.sources);
 
     // Detect framework from sources if set to auto
     if (target.framework == benchmark_framework::Auto && !target.source_files.empty()) {
@@ -1090,6 +1157,10 @@ bool benchmark_runner::generate_benchmark_cmake(const benchmark_target &target)
 
   // Framework-specific CMake setup (FetchContent etc.) — empty for builtin.
   benchmark_config::FrameworkConfig fw_config;
+  auto fw_config_it = m_bench_config.framework_configs.find(target.framework);
+  if (fw_config_it != m_bench_config.framework_configs.end()) {
+    fw_config = fw_config_it->second;
+  }
   std::string fw_setup = adapter->generate_cmake_setup(fw_config);
   if (!fw_setup.empty()) {
     out << fw_setup << "\n";
@@ -1139,6 +1210,9 @@ bool benchmark_runner::generate_benchmark_cmake(const benchmark_target &target)
     }
   }
 
+  for (const auto &dep : m_bench_config.links) {
+    out << "  " << dep << "\n";
+  }
   for (const auto &dep : target.dependencies) {
     out << "  " << dep << "\n";
   }
@@ -1151,6 +1225,8 @@ bool benchmark_runner::generate_benchmark_cmake(const benchmark_target &target)
     }
     out << ")\n\n";
   }
+  out << generate_platform_settings_cmake(
+      target.platform_defines, target.platform_flags, "${PROJECT_NAME}");
 
   // Register with CTest so `ctest -L bench` etc. surface benchmarks too.
   out << "add_test(NAME " << target.name << " COMMAND ${PROJECT_NAME})\n"
@@ -1169,7 +1245,11 @@ bool benchmark_runner::configure_cmake(const benchmark_target &target,
                                    to_cmake_path(gen_dir),
                                    "-B",
                                    to_cmake_path(build_dir),
-                                   "-DCMAKE_BUILD_TYPE=" + build_config};
+                                   "-DCMAKE_BUILD_TYPE=" + canonical_build_config(build_config)};
+
+  // Frameworks and project dependencies from vcpkg need its toolchain
+  auto vcpkg_args = get_vcpkg_cmake_args(m_project_config, m_project_dir, "", "", build_config);
+  args.insert(args.end(), vcpkg_args.begin(), vcpkg_args.end());
 
 #ifdef _WIN32
   // Use the same generator as the main project if specified; otherwise let
@@ -1292,6 +1372,7 @@ std::vector<benchmark_result> benchmark_runner::run_target(const benchmark_targe
     auto json_args = adapter->get_json_args();
     args.insert(args.end(), json_args.begin(), json_args.end());
   }
+  args.insert(args.end(), options.extra_args.begin(), options.extra_args.end());
 
   // Benchmarks run many iterations × multiple samples and can take a while.
   // Allow up to 30 minutes per target by default; CI / pathological cases
@@ -1396,10 +1477,9 @@ bool benchmark_runner::should_auto_link_project() const {
   if (!m_bench_config.auto_link_project) {
     return false;
   }
-  std::string type = m_project_config.get_string("project.binary_type", "executable");
+  std::string type = get_binary_type(m_project_config);
   // Same set of library-like binary types as test_runner.
-  return (type == "library" || type == "static_library" || type == "shared_library"
-          || type == "static" || type == "shared");
+  return type == "static_lib" || type == "shared_lib";
 }
 
 std::string benchmark_runner::get_project_link_target() const {
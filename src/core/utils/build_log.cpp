@@ -0,0 +1,123 @@
+/**
+ * @file build_log.cpp
+ * @brief Writing, rotating and finding the logs of configure and build steps
+ */
+
+#include "core/build_log.hpp"
+
+#include "cforge/log.hpp"
+
+#include "core/constants.h"
+#include "core/toml_reader.hpp"
+#include "core/types.h"
+
+#include <chrono>
+#include <ctime>
+#include <fstream>
+#include <iomanip>
+#include <sstream>
+
+namespace cforge {
+
+namespace {
+
+/**
+ * @brief Local time as `YYYYMMDD-HHMMSS-mmm`
+ */
+std::string log_timestamp() {
+  auto now       = std::chrono::system_clock::now();
+  std::time_t tt = std::chrono::system_clock::to_time_t(now);
+  auto millis =
+      std::chrono::duration_cast<std::chrono::milliseconds>(now.time_since_epoch()).count() % 1000;
+  std::tm local{};
+#ifdef _WIN32
+  localtime_s(&local, &tt);
+#else
+  localtime_r(&tt, &local);
+#endif
+  std::ostringstream out;
+  out << std::put_time(&local, "%Y%m%d-%H%M%S") << "-" << std::setw(3) << std::setfill('0')
+      << millis;
+  return out.str();
+}
+
+std::vector<std::string> log_file_names(const std::filesystem::path &dir) {
+  std::vector<std::string> names;
+  std::error_code ec;
+  for (const auto &entry : std::filesystem::directory_iterator(dir, ec)) {
+    names.push_back(entry.path().filename().string());
+  }
+  return names;
+}
+
+}  // namespace
+
+std::filesystem::path write_build_log(const std::filesystem::path &build_dir,
+                                      const std::string &kind,
+                                      const std::string &command_line,
+                                      const std::string &output,
+                                      cforge_int_t keep) {
+  if (keep <= 0 || build_dir.empty()) {
+    return {};
+  }
+  std::filesystem::path dir = build_log_dir(build_dir);
+  std::error_code ec;
+  std::filesystem::create_directories(dir, ec);
+
+  // Two steps can finish within the same millisecond in a workspace build;
+  // "_<n>" sorts after the first log of that millisecond
+  std::string stamp          = log_timestamp();
+  std::filesystem::path path = dir / (kind + "-" + stamp + ".log");
+  for (cforge_int_t n = 1; std::filesystem::exists(path, ec); ++n) {
+    path = dir / (kind + "-" + stamp + "_" + std::to_string(n) + ".log");
+  }
+
+  std::ofstream out(path, std::ios::binary);
+  if (!out || !(out << "$ " << command_line << "\n\n" << output)) {
+    logger::print_verbose("Could not write build log: " + path.string());
+    return {};
+  }
+  out.close();
+
+  for (const auto &stale : stale_build_logs(log_file_names(dir), kind, keep)) {
+    std::filesystem::remove(dir / stale, ec);
+  }
+  return path;
+}
+
+std::filesystem::path find_latest_build_log(const std::filesystem::path &build_dir,
+                                            const std::string &kind) {
+  std::filesystem::path dir = build_log_dir(build_dir);
+  std::string newest        = newest_build_log(log_file_names(dir), kind);
+  return newest.empty() ? std::filesystem::path() : dir / newest;
+}
+
+cforge_int_t configured_build_log_limit(const std::filesystem::path &start_dir) {
+  std::error_code ec;
+  for (auto dir = start_dir;; dir = dir.parent_path()) {
+    if (std::filesystem::exists(dir / CFORGE_FILE, ec)) {
+      toml_reader config;
+      if (!config.load((dir / CFORGE_FILE).string())) {
+        break;
+      }
+      return static_cast<cforge_int_t>(
+          config.get_int("build.keep_logs", DEFAULT_KEPT_BUILD_LOGS));
+    }
+    if (dir == dir.parent_path()) {
+      break;
+    }
+  }
+  return DEFAULT_KEPT_BUILD_LOGS;
+}
+
+void print_build_log_tail(const std::filesystem::path &log, const std::string &output) {
+  const cforge_size_t tail_lines = 30;
+  std::string tail               = last_lines(output, tail_lines);
+  if (!tail.empty()) {
+    logger::print_dim("Last " + std::to_string(tail_lines) + " lines of output:");
+    logger::print_plain(tail);
+  }
+  logger::print_hint("full output in " + log.string() + " ('cforge log' prints it)");
+}
+
+}  // namespace cforge
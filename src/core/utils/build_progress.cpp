@@ -20,13 +20,19 @@ namespace cforge {
 void build_progress::reset() {
   std::lock_guard<std::mutex> lock(mutex_);
   current_file_.clear();
-  current_step_ = 0;
-  total_steps_  = 0;
-  has_progress_ = false;
+  current_action_.clear();
+  current_step_   = 0;
+  total_steps_    = 0;
+  expected_steps_ = 0;
+  has_progress_   = false;
   timings_.clear();
+  warnings_.clear();
 }
 
 bool build_progress::parse_line(const std::string &line) {
+  // Warnings are collected but are not progress information
+  parse_warning(line);
+
   // Try each parser in order
   if (parse_ninja_progress(line)) {
     return true;
@@ -40,6 +46,26 @@ bool build_progress::parse_line(const std::string &line) {
   return false;
 }
 
+bool build_progress::parse_warning(const std::string &line) {
+  // GCC/Clang: file.cpp:10:5: warning: ...
+  // MSVC:      file.cpp(10): warning C4996: ...
+  static std::regex warning_regex(R"((^|[:)]\s*)warning( C\d+)?:)");
+
+  if (!std::regex_search(line, warning_regex)) {
+    return false;
+  }
+
+  std::string trimmed = line;
+  trimmed.erase(trimmed.find_last_not_of(" \r\n\t") + 1);
+
+  std::lock_guard<std::mutex> lock(mutex_);
+  // Make and MSBuild may echo the same diagnostic more than once
+  if (std::find(warnings_.begin(), warnings_.end(), trimmed) == warnings_.end()) {
+    warnings_.push_back(trimmed);
+  }
+  return true;
+}
+
 bool build_progress::parse_ninja_progress(const std::string &line) {
   // Ninja format: [1/15] Building CXX object src/main.cpp.obj
   // Also matches: [1/15] Linking CXX executable bin/app.exe
@@ -56,6 +82,7 @@ bool build_progress::parse_ninja_progress(const std::string &line) {
     if (has_progress_ && new_step > current_step_ && !current_file_.empty()) {
       file_timing timing;
       timing.filename   = current_file_;
+      timing.action     = current_action_;
       timing.start_time = current_file_start_;
       timing.end_time   = std::chrono::steady_clock::now();
       timing.duration_seconds =
@@ -70,6 +97,7 @@ bool build_progress::parse_ninja_progress(const std::string &line) {
     // Extract the action and file
     std::string action  = match[3].str();
     current_file_       = extract_filename(action);
+    current_action_     = action;
     current_file_start_ = std::chrono::steady_clock::now();
 
     return true;
@@ -96,6 +124,7 @@ bool build_progress::parse_make_progress(const std::string &line) {
     if (has_progress_ && new_step > current_step_ && !current_file_.empty()) {
       file_timing timing;
       timing.filename   = current_file_;
+      timing.action     = current_action_;
       timing.start_time = current_file_start_;
       timing.end_time   = std::chrono::steady_clock::now();
       timing.duration_seconds =
@@ -109,6 +138,7 @@ bool build_progress::parse_make_progress(const std::string &line) {
 
     std::string action  = match[2].str();
     current_file_       = extract_filename(action);
+    current_action_     = action;
     current_file_start_ = std::chrono::steady_clock::now();
 
     return true;
@@ -154,6 +184,7 @@ bool build_progress::parse_msbuild_progress(const std::string &line) {
   if (!current_file_.empty()) {
     file_timing timing;
     timing.filename   = current_file_;
+    timing.action     = current_action_;
     timing.start_time = current_file_start_;
     timing.end_time   = std::chrono::steady_clock::now();
     timing.duration_seconds =
@@ -162,8 +193,13 @@ bool build_progress::parse_msbuild_progress(const std::string &line) {
   }
 
   current_step_++;
+  if (expected_steps_ > 0) {
+    // Link steps come on top of the compiled files
+    total_steps_ = std::max(expected_steps_, current_step_);
+  }
   has_progress_       = true;
   current_file_       = captured;
+  current_action_     = line;
   current_file_start_ = std::chrono::steady_clock::now();
 
   return true;
@@ -233,6 +269,32 @@ bool build_progress::has_progress() const {
   return has_progress_;
 }
 
+void build_progress::set_expected_steps(cforge_int_t steps) {
+  std::lock_guard<std::mutex> lock(mutex_);
+  expected_steps_ = steps;
+}
+
+void build_progress::finish(bool succeeded) {
+  std::lock_guard<std::mutex> lock(mutex_);
+  if (succeeded && total_steps_ > 0) {
+    current_step_ = total_steps_;
+  }
+  if (!has_progress_ || current_file_.empty()) {
+    return;
+  }
+
+  file_timing timing;
+  timing.filename   = current_file_;
+  timing.action     = current_action_;
+  timing.start_time = current_file_start_;
+  timing.end_time   = std::chrono::steady_clock::now();
+  timing.duration_seconds =
+      std::chrono::duration<double>(timing.end_time - timing.start_time).count();
+  timings_.emplace_back(timing);
+  current_file_.clear();
+  current_action_.clear();
+}
+
 const std::vector<file_timing> &build_progress::get_timings() const {
   return timings_;
 }
@@ -251,6 +313,10 @@ std::vector<file_timing> build_progress::get_slowest_files(cforge_size_t count)
   return sorted;
 }
 
+const std::vector<std::string> &build_progress::get_warnings() const {
+  return warnings_;
+}
+
 void build_progress::file_started(const std::string &filename) {
   std::lock_guard<std::mutex> lock(mutex_);
   current_file_       = filename;
@@ -273,7 +339,7 @@ void display_progress_bar(cforge_int_t current,
                           cforge_int_t total,
                           cforge_int_t width,
                           bool show_percentage) {
-  if (total <= 0) {
+  if (total <= 0 || !cforge::logger::is_interactive()) {
     return;
   }
 
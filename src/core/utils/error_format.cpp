@@ -4,6 +4,9 @@
  */
 
 #include "core/error_format.hpp"
+#include "core/linker_diagnostics.hpp"
+
+#include "cforge/log.hpp"
 
 #include "core/types.h"
 
@@ -33,6 +36,11 @@ const auto LOCATION_COLOR  = fmt::fg(fmt::color::light_blue);
 const auto HIGHLIGHT_COLOR = fmt::fg(fmt::color::red);
 const auto CARET_COLOR     = fmt::fg(fmt::color::orange_red);
 
+// Diagnostics are written to stderr, so colors follow its terminal state
+static fmt::text_style diag_style(fmt::text_style style) {
+  return logger::style(style, stderr);
+}
+
 // Define error code prefixes for different tools to use when original codes
 // aren't available
 namespace error_code_prefix {
@@ -87,7 +95,10 @@ std::string format_build_errors(const std::string &error_output) {
       // Try to extract symbol and suggest library
       std::regex symbol_regex(R"((?:undefined|unresolved)[^`'\"]*[`'\"]([^`'\"]+)[`'\"])");
       std::smatch match;
-      if (std::regex_search(diag.message, match, symbol_regex)) {
+      bool has_library = std::any_of(diag.notes.begin(), diag.notes.end(), [](const auto &note) {
+        return note.rfind("try linking: ", 0) == 0;
+      });
+      if (!has_library && std::regex_search(diag.message, match, symbol_regex)) {
         std::string suggested_lib = suggest_library_for_symbol(match[1].str());
         if (!suggested_lib.empty()) {
           diag.notes.push_back("try linking: " + suggested_lib);
@@ -103,8 +114,8 @@ std::string format_build_errors(const std::string &error_output) {
   // Format the diagnostics to a string
   std::stringstream ss;
   for (const auto &diag : filtered_diagnostics) {
-    // Add occurrence count to message if > 1
-    if (diag.occurrence_count > 1) {
+    // Add occurrence count to message if > 1 (JSON carries it as a field)
+    if (diag.occurrence_count > 1 && !logger::is_json()) {
       diagnostic diag_copy = diag;
       diag_copy.message    = diag.message + " (" + std::to_string(diag.occurrence_count)
                         + " occurrences)";
@@ -114,6 +125,11 @@ std::string format_build_errors(const std::string &error_output) {
     }
   }
 
+  // JSON mode emits one event per diagnostic and no human summary
+  if (logger::is_json()) {
+    return ss.str();
+  }
+
   // Calculate and append summary
   error_summary summary   = calculate_error_summary(filtered_diagnostics);
   std::string summary_str = format_error_summary(summary);
@@ -151,6 +167,60 @@ static std::string read_line_from_file(const std::string &file_path, cforge_int_
   return "";
 }
 
+// Format a diagnostic as a JSON event for --format json
+std::string format_diagnostic_to_json(const diagnostic &diag) {
+  std::string level;
+  switch (diag.level) {
+    case diagnostic_level::ERROR:
+      level = "error";
+      break;
+    case diagnostic_level::WARNING:
+      level = "warning";
+      break;
+    case diagnostic_level::NOTE:
+      level = "note";
+      break;
+    case diagnostic_level::HELP:
+      level = "help";
+      break;
+  }
+
+  std::string notes = "[";
+  for (const auto &note : diag.notes) {
+    if (notes.size() > 1) {
+      notes += ",";
+    }
+    notes += logger::json_quote(note);
+  }
+  notes += "]";
+
+  std::string fixes = "[";
+  for (const auto &fix : diag.fixes) {
+    if (fixes.size() > 1) {
+      fixes += ",";
+    }
+    fixes += "{\"description\":" + logger::json_quote(fix.description)
+           + ",\"replacement\":" + logger::json_quote(fix.replacement) + "}";
+  }
+  fixes += "]";
+
+  std::string help = diag.help.empty() ? diag.help_text : diag.help;
+
+  std::stringstream ss;
+  ss << "{\"event\":\"diagnostic\""
+     << ",\"level\":" << logger::json_quote(level)
+     << ",\"code\":" << logger::json_quote(diag.code)
+     << ",\"message\":" << logger::json_quote(diag.message)
+     << ",\"file\":" << logger::json_quote(diag.file_path)
+     << ",\"line\":" << diag.line_number
+     << ",\"column\":" << diag.column_number
+     << ",\"occurrences\":" << diag.occurrence_count
+     << ",\"help\":" << logger::json_quote(help)
+     << ",\"notes\":" << notes
+     << ",\"fixes\":" << fixes << "}";
+  return ss.str();
+}
+
 // Format a diagnostic in Cargo/Rust style
 // NOTE: This function builds a string version of the diagnostic.
 // For direct output, use print_diagnostic() which uses logger utilities.
@@ -158,44 +228,57 @@ static std::string read_line_from_file(const std::string &file_path, cforge_int_
 // string for use in contexts where we need to collect/process output before
 // printing.
 std::string format_diagnostic_to_string(const diagnostic &diag) {
+  if (logger::is_json()) {
+    return format_diagnostic_to_json(diag) + "\n";
+  }
+
   std::stringstream ss;
 
   // Print error/warning header - matches logger::print_error_header() format
   switch (diag.level) {
     case diagnostic_level::ERROR:
-      ss << fmt::format(fg(fmt::color::red) | fmt::emphasis::bold, "error");
+      ss << fmt::format(diag_style(fg(fmt::color::red) | fmt::emphasis::bold), "error");
       if (!diag.code.empty()) {
-        ss << fmt::format(fg(fmt::color::red) | fmt::emphasis::bold, "[{}]", diag.code);
+        ss << fmt::format(diag_style(fg(fmt::color::red) | fmt::emphasis::bold), "[{}]", diag.code);
       }
-      ss << fmt::format(fg(fmt::color::white) | fmt::emphasis::bold, ": {}\n", diag.message);
+      ss << fmt::format(
+          diag_style(fg(fmt::color::white) | fmt::emphasis::bold), ": {}\n", diag.message);
       break;
     case diagnostic_level::WARNING:
-      ss << fmt::format(fg(fmt::color::yellow) | fmt::emphasis::bold, "warning");
+      ss << fmt::format(diag_style(fg(fmt::color::yellow) | fmt::emphasis::bold), "warning");
       if (!diag.code.empty()) {
-        ss << fmt::format(fg(fmt::color::yellow) | fmt::emphasis::bold, "[{}]", diag.code);
+        ss << fmt::format(
+            diag_style(fg(fmt::color::yellow) | fmt::emphasis::bold), "[{}]", diag.code);
       }
-      ss << fmt::format(fg(fmt::color::white) | fmt::emphasis::bold, ": {}\n", diag.message);
+      ss << fmt::format(
+          diag_style(fg(fmt::color::white) | fmt::emphasis::bold), ": {}\n", diag.message);
       break;
     case diagnostic_level::NOTE:
-      ss << fmt::format(fg(fmt::color::cyan) | fmt::emphasis::bold, "note");
+      ss << fmt::format(diag_style(fg(fmt::color::cyan) | fmt::emphasis::bold), "note");
       if (!diag.code.empty()) {
-        ss << fmt::format(fg(fmt::color::cyan) | fmt::emphasis::bold, "[{}]", diag.code);
+        ss << fmt::format(
+            diag_style(fg(fmt::color::cyan) | fmt::emphasis::bold), "[{}]", diag.code);
       }
-      ss << fmt::format(fg(fmt::color::white) | fmt::emphasis::bold, ": {}\n", diag.message);
+      ss << fmt::format(
+          diag_style(fg(fmt::color::white) | fmt::emphasis::bold), ": {}\n", diag.message);
       break;
     case diagnostic_level::HELP:
-      ss << fmt::format(fg(fmt::color::green) | fmt::emphasis::bold, "help");
+      ss << fmt::format(diag_style(fg(fmt::color::green) | fmt::emphasis::bold), "help");
       if (!diag.code.empty()) {
-        ss << fmt::format(fg(fmt::color::green) | fmt::emphasis::bold, "[{}]", diag.code);
+        ss << fmt::format(
+            diag_style(fg(fmt::color::green) | fmt::emphasis::bold), "[{}]", diag.code);
       }
-      ss << fmt::format(fg(fmt::color::white) | fmt::emphasis::bold, ": {}\n", diag.message);
+      ss << fmt::format(
+          diag_style(fg(fmt::color::white) | fmt::emphasis::bold), ": {}\n", diag.message);
       break;
     default:
-      ss << fmt::format(fg(fmt::color::white) | fmt::emphasis::bold, "unknown");
+      ss << fmt::format(diag_style(fg(fmt::color::white) | fmt::emphasis::bold), "unknown");
       if (!diag.code.empty()) {
-        ss << fmt::format(fg(fmt::color::white) | fmt::emphasis::bold, "[{}]", diag.code);
+        ss << fmt::format(
+            diag_style(fg(fmt::color::white) | fmt::emphasis::bold), "[{}]", diag.code);
       }
-      ss << fmt::format(fg(fmt::color::white) | fmt::emphasis::bold, ": {}\n", diag.message);
+      ss << fmt::format(
+          diag_style(fg(fmt::color::white) | fmt::emphasis::bold), ": {}\n", diag.message);
       break;
   }
 
@@ -208,7 +291,7 @@ std::string format_diagnostic_to_string(const diagnostic &diag) {
                    + display_path.substr(display_path.length() - 32);
     }
 
-    ss << fmt::format(fg(fmt::color::steel_blue), "   --> ");
+    ss << fmt::format(diag_style(fg(fmt::color::steel_blue)), "   --> ");
     ss << display_path;
     if (diag.line_number > 0) {
       ss << ":" << diag.line_number;
@@ -235,14 +318,15 @@ std::string format_diagnostic_to_string(const diagnostic &diag) {
     }
 
     // Empty gutter line - matches logger::print_gutter_line()
-    ss << fmt::format(fg(fmt::color::steel_blue), "   |\n");
+    ss << fmt::format(diag_style(fg(fmt::color::steel_blue)), "   |\n");
 
     // The actual code line - matches logger::print_code_line()
-    ss << fmt::format(fg(fmt::color::steel_blue), "{:>{}} | ", diag.line_number, gutter_width);
+    ss << fmt::format(
+        diag_style(fg(fmt::color::steel_blue)), "{:>{}} | ", diag.line_number, gutter_width);
     ss << line_content << "\n";
 
     // The error pointer line - matches logger::print_error_pointer()
-    ss << fmt::format(fg(fmt::color::steel_blue), "{:>{}} | ", "", gutter_width);
+    ss << fmt::format(diag_style(fg(fmt::color::steel_blue)), "{:>{}} | ", "", gutter_width);
 
     if (diag.column_number > 0) {
       cforge_size_t col          = static_cast<cforge_size_t>(diag.column_number - 1);
@@ -267,43 +351,43 @@ std::string format_diagnostic_to_string(const diagnostic &diag) {
 
       // Print spaces then carets
       ss << std::string(col, ' ');
-      ss << fmt::format(fg(fmt::color::red) | fmt::emphasis::bold,
+      ss << fmt::format(diag_style(fg(fmt::color::red) | fmt::emphasis::bold),
                         "{}\n",
                         std::string(token_length, '^'));
     } else {
-      ss << fmt::format(fg(fmt::color::red) | fmt::emphasis::bold, "^\n");
+      ss << fmt::format(diag_style(fg(fmt::color::red) | fmt::emphasis::bold), "^\n");
     }
   }
 
   // Notes/help/fix all share the cargo "   = label: msg" layout so they hang
   // visually beneath the gutter of the code snippet.
   for (const auto &note : diag.notes) {
-    ss << fmt::format(fg(fmt::color::steel_blue), "   = ");
-    ss << fmt::format(fg(fmt::color::cyan) | fmt::emphasis::bold, "note");
-    ss << fmt::format(fg(fmt::color::light_gray), ": {}\n", note);
+    ss << fmt::format(diag_style(fg(fmt::color::steel_blue)), "   = ");
+    ss << fmt::format(diag_style(fg(fmt::color::cyan) | fmt::emphasis::bold), "note");
+    ss << fmt::format(diag_style(fg(fmt::color::light_gray)), ": {}\n", note);
   }
 
   std::string help = diag.help.empty() ? diag.help_text : diag.help;
   if (!help.empty()) {
-    ss << fmt::format(fg(fmt::color::steel_blue), "   = ");
-    ss << fmt::format(fg(fmt::color::medium_sea_green) | fmt::emphasis::bold, "help");
-    ss << fmt::format(fg(fmt::color::light_gray), ": {}\n", help);
+    ss << fmt::format(diag_style(fg(fmt::color::steel_blue)), "   = ");
+    ss << fmt::format(diag_style(fg(fmt::color::medium_sea_green) | fmt::emphasis::bold), "help");
+    ss << fmt::format(diag_style(fg(fmt::color::light_gray)), ": {}\n", help);
   }
 
   if (!diag.fixes.empty()) {
     for (cforge_size_t i = 0; i < diag.fixes.size() && i < 3; ++i) {
       const auto &fix = diag.fixes[i];
-      ss << fmt::format(fg(fmt::color::steel_blue), "   = ");
-      ss << fmt::format(fg(fmt::color::magenta) | fmt::emphasis::bold, "fix");
-      ss << fmt::format(fg(fmt::color::light_gray), ": {}", fix.description);
+      ss << fmt::format(diag_style(fg(fmt::color::steel_blue)), "   = ");
+      ss << fmt::format(diag_style(fg(fmt::color::magenta) | fmt::emphasis::bold), "fix");
+      ss << fmt::format(diag_style(fg(fmt::color::light_gray)), ": {}", fix.description);
       if (!fix.replacement.empty() && fix.replacement.length() < 80) {
-        ss << fmt::format(fg(fmt::color::gray), " -> ");
-        ss << fmt::format(fg(fmt::color::lime_green), "`{}`", fix.replacement);
+        ss << fmt::format(diag_style(fg(fmt::color::gray)), " -> ");
+        ss << fmt::format(diag_style(fg(fmt::color::lime_green)), "`{}`", fix.replacement);
       }
       ss << "\n";
     }
     if (diag.fixes.size() > 3) {
-      ss << fmt::format(fg(fmt::color::gray),
+      ss << fmt::format(diag_style(fg(fmt::color::gray)),
                         "     ... and {} more suggestion(s)\n",
                         diag.fixes.size() - 3);
     }
@@ -314,6 +398,11 @@ std::string format_diagnostic_to_string(const diagnostic &diag) {
 }
 
 void print_diagnostic(const diagnostic &diag) {
+  if (logger::is_json()) {
+    logger::print_plain(format_diagnostic_to_json(diag));
+    return;
+  }
+
   // Use logger utilities directly instead of building strings
 
   // Print error/warning header
@@ -519,7 +608,6 @@ std::vector<diagnostic> parse_compiler_errors(const std::string &error_output) {
   std::regex missing_header_regex(R"(fatal error: ([^:]+): No such file or directory)");
   std::regex include_error_regex(R"(fatal error: ([^:]+): Cannot open include file)");
   std::regex syntax_error_regex(R"(error: expected ([^:]+) before ([^:]+))");
-  std::regex undefined_reference_regex(R"(undefined reference to `([^']+)')");
   std::regex redefinition_regex(R"(redefinition of '([^']+)')");
   std::regex type_mismatch_regex(R"(error: cannot convert '([^']+)' to '([^']+)')");
   std::regex undeclared_identifier_regex(R"(error: '([^']+)' was not declared in this scope)");
@@ -565,17 +653,6 @@ std::vector<diagnostic> parse_compiler_errors(const std::string &error_output) {
       continue;
     }
 
-    // Check for undefined references
-    if (std::regex_search(line, matches, undefined_reference_regex)) {
-      diag.level     = diagnostic_level::ERROR;
-      diag.code      = "COMPILER-UNDEFINED-REFERENCE";
-      diag.message   = "Undefined reference to: " + matches[1].str();
-      diag.help_text = "Make sure the function or variable is defined and linked properly. "
-                       "Check that all required libraries are linked in your cforge.toml.";
-      diagnostics.push_back(diag);
-      continue;
-    }
-
     // Check for redefinitions
     if (std::regex_search(line, matches, redefinition_regex)) {
       diag.level     = diagnostic_level::ERROR;
@@ -611,6 +688,29 @@ std::vector<diagnostic> parse_compiler_errors(const std::string &error_output) {
     }
   }
 
+  // Undefined symbols from GNU ld, lld, ld64 and MSVC LINK are grouped over
+  // the whole output, so each symbol is one diagnostic however many times it
+  // is referenced
+  for (const auto &symbol : find_undefined_symbols(error_output)) {
+    // System libraries are only looked up for C names such as MessageBoxW
+    std::string system_library;
+    if (symbol.symbol.find_first_of(":( ") == std::string::npos) {
+      system_library = suggest_library_for_symbol(symbol.symbol);
+    }
+    undefined_symbol_advice advice = advise_undefined_symbol(symbol, system_library);
+
+    diagnostic diag;
+    diag.level            = diagnostic_level::ERROR;
+    diag.code             = "LNK-UNDEFINED";
+    diag.message          = "undefined reference to `" + symbol.symbol + "'";
+    diag.line_number      = 0;
+    diag.column_number    = 0;
+    diag.occurrence_count = symbol.count;
+    diag.notes            = advice.notes;
+    diag.help_text        = advice.help;
+    diagnostics.push_back(diag);
+  }
+
   return diagnostics;
 }
 
@@ -1192,15 +1292,6 @@ std::vector<diagnostic> parse_linker_errors(const std::string &error_output) {
   // function ..."
   std::regex msvc_function_context_regex(R"(function\s+[\"']?([^\"'\s]+)[\"']?)");
 
-  // GCC/Clang undefined reference: "file.o:(.text+0x...): undefined reference
-  // to `symbol'"
-  std::regex gcc_undefined_ref_regex(
-      R"(([^\s:]+\.o(?:bj)?)\s*:\s*(?:\([^)]+\)\s*:\s*)?undefined reference to [`']([^'`]+)[`'])");
-
-  // GCC/Clang undefined reference (simpler format): "undefined reference to
-  // `symbol'"
-  std::regex simple_undefined_ref_regex(R"(undefined reference to [`']([^'`]+)[`'])");
-
   // collect2 error: "collect2: error: ld returned 1 exit status"
   std::regex collect2_error_regex(R"(collect2:\s*error:\s*(.*))");
 
@@ -1218,6 +1309,7 @@ std::vector<diagnostic> parse_linker_errors(const std::string &error_output) {
   std::string line;
   std::istringstream stream(error_output);
   diagnostic *current_diag = nullptr;
+  bool saw_undefined       = false;
 
   // Helper to add linker-specific help text
   auto add_linker_help = [](diagnostic &diag, const std::string &error_code) {
@@ -1308,6 +1400,13 @@ std::vector<diagnostic> parse_linker_errors(const std::string &error_output) {
       continue;
     }
 
+    // Undefined symbols are grouped by parse_compiler_errors()
+    if (is_undefined_symbol_line(line)) {
+      saw_undefined = true;
+      current_diag  = nullptr;
+      continue;
+    }
+
     // MSVC object file linker errors (most common on Windows)
 
     if (std::regex_search(line, matches, msvc_obj_error_regex)) {
@@ -1394,56 +1493,6 @@ std::vector<diagnostic> parse_linker_errors(const std::string &error_output) {
       continue;
     }
 
-    // GCC/Clang undefined reference with file context
-
-    if (std::regex_search(line, matches, gcc_undefined_ref_regex)) {
-      diagnostic diag;
-      diag.level         = diagnostic_level::ERROR;
-      diag.code          = "LNK-UNDEFINED";
-      diag.file_path     = matches[1].str();
-      std::string symbol = matches[2].str();
-      diag.message       = "undefined reference to `" + symbol + "'";
-      diag.notes.push_back("Missing symbol: " + symbol);
-      diag.line_number   = 0;
-      diag.column_number = 0;
-
-      add_linker_help(diag, diag.code);
-      diagnostics.push_back(diag);
-      current_diag = &diagnostics.back();
-      continue;
-    }
-
-    // Simple undefined reference (without file context)
-
-    if (std::regex_search(line, matches, simple_undefined_ref_regex)) {
-      // Only create a new diagnostic if we don't already have one for this
-      bool already_captured = false;
-      std::string symbol    = matches[1].str();
-
-      for (const auto &existing : diagnostics) {
-        if (existing.message.find(symbol) != std::string::npos) {
-          already_captured = true;
-          break;
-        }
-      }
-
-      if (!already_captured) {
-        diagnostic diag;
-        diag.level   = diagnostic_level::ERROR;
-        diag.code    = "LNK-UNDEFINED";
-        diag.message = "undefined reference to `" + symbol + "'";
-        diag.notes.push_back("Missing symbol: " + symbol);
-        diag.file_path     = "";
-        diag.line_number   = 0;
-        diag.column_number = 0;
-
-        add_linker_help(diag, diag.code);
-        diagnostics.push_back(diag);
-        current_diag = &diagnostics.back();
-      }
-      continue;
-    }
-
     // Clang linker wrapper errors
 
     if (std::regex_search(line, matches, clang_linker_error_regex)) {
@@ -1481,7 +1530,7 @@ std::vector<diagnostic> parse_linker_errors(const std::string &error_output) {
 
       // collect2 is a summary error; we prefer the more specific errors above
       // Only add if we haven't captured any linker errors yet
-      if (diagnostics.empty()) {
+      if (diagnostics.empty() && !saw_undefined) {
         diagnostic diag;
         diag.level         = diagnostic_level::ERROR;
         diag.code          = "LNK-LD";
@@ -2058,48 +2107,48 @@ std::string format_error_summary(const error_summary &summary) {
   // Main summary line
   if (summary.total_errors > 0) {
     ss << "\n";
-    ss << fmt::format(fg(fmt::color::red) | fmt::emphasis::bold, "error");
-    ss << fmt::format(fg(fmt::color::white) | fmt::emphasis::bold, ": build failed\n");
+    ss << fmt::format(diag_style(fg(fmt::color::red) | fmt::emphasis::bold), "error");
+    ss << fmt::format(diag_style(fg(fmt::color::white) | fmt::emphasis::bold), ": build failed\n");
   }
 
   // Breakdown by source
-  ss << fmt::format(fg(fmt::color::steel_blue), "   |\n");
+  ss << fmt::format(diag_style(fg(fmt::color::steel_blue)), "   |\n");
 
   if (summary.compiler_errors > 0) {
-    ss << fmt::format(fg(fmt::color::steel_blue), "   |  ");
-    ss << fmt::format(fg(fmt::color::red),
+    ss << fmt::format(diag_style(fg(fmt::color::steel_blue)), "   |  ");
+    ss << fmt::format(diag_style(fg(fmt::color::red)),
                       "{} compiler error{}\n",
                       summary.compiler_errors,
                       summary.compiler_errors == 1 ? "" : "s");
   }
 
   if (summary.linker_errors > 0) {
-    ss << fmt::format(fg(fmt::color::steel_blue), "   |  ");
-    ss << fmt::format(fg(fmt::color::red),
+    ss << fmt::format(diag_style(fg(fmt::color::steel_blue)), "   |  ");
+    ss << fmt::format(diag_style(fg(fmt::color::red)),
                       "{} linker error{}\n",
                       summary.linker_errors,
                       summary.linker_errors == 1 ? "" : "s");
   }
 
   if (summary.template_errors > 0) {
-    ss << fmt::format(fg(fmt::color::steel_blue), "   |  ");
-    ss << fmt::format(fg(fmt::color::red),
+    ss << fmt::format(diag_style(fg(fmt::color::steel_blue)), "   |  ");
+    ss << fmt::format(diag_style(fg(fmt::color::red)),
                       "{} template error{}\n",
                       summary.template_errors,
                       summary.template_errors == 1 ? "" : "s");
   }
 
   if (summary.cmake_errors > 0) {
-    ss << fmt::format(fg(fmt::color::steel_blue), "   |  ");
-    ss << fmt::format(fg(fmt::color::red),
+    ss << fmt::format(diag_style(fg(fmt::color::steel_blue)), "   |  ");
+    ss << fmt::format(diag_style(fg(fmt::color::red)),
                       "{} CMake error{}\n",
                       summary.cmake_errors,
                       summary.cmake_errors == 1 ? "" : "s");
   }
 
   if (summary.total_warnings > 0) {
-    ss << fmt::format(fg(fmt::color::steel_blue), "   |  ");
-    ss << fmt::format(fg(fmt::color::yellow),
+    ss << fmt::format(diag_style(fg(fmt::color::steel_blue)), "   |  ");
+    ss << fmt::format(diag_style(fg(fmt::color::yellow)),
                       "{} warning{}\n",
                       summary.total_warnings,
                       summary.total_warnings == 1 ? "" : "s");
@@ -2269,16 +2318,16 @@ std::string suggest_library_for_symbol(const std::string &symbol) {
     clean_symbol = clean_symbol.substr(1);
   }
 
-  // Check Windows libs first
+  // Check Windows libs first; a prefix also matches the A/W variants
   for (const auto &pair : windows_libs) {
-    if (clean_symbol.find(pair.first) != std::string::npos) {
+    if (clean_symbol.rfind(pair.first, 0) == 0) {
       return pair.second;
     }
   }
 
   // Check Unix libs
   for (const auto &pair : unix_libs) {
-    if (clean_symbol.find(pair.first) != std::string::npos) {
+    if (clean_symbol.rfind(pair.first, 0) == 0) {
       return pair.second;
     }
   }
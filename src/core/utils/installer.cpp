@@ -9,15 +9,21 @@
 
 #include "core/constants.h"
 #include "core/file_system.h"
+#include "core/install_manifest.hpp"
 #include "core/process.h"
 #include "core/process_utils.hpp"
+#include "core/script_runner.hpp"
 #include "core/types.h"
 
 #include <algorithm>
 #include <cstdlib>
 #include <cstring>
+#include <ctime>
 #include <fstream>
+#include <iomanip>
+#include <map>
 #include <regex>
+#include <sstream>
 
 #ifdef _WIN32
 #include <direct.h>
@@ -695,6 +701,245 @@ bool installer::install_project(const std::string &project_path,
   return true;
 }
 
+// Read one entry from CMakeCache.txt, e.g. "CMAKE_INSTALL_PREFIX:PATH=/usr/local"
+static std::string read_cmake_cache_value(const std::filesystem::path &build_dir,
+                                          const std::string &name) {
+  std::ifstream cache(build_dir / "CMakeCache.txt");
+  std::string line;
+  while (std::getline(cache, line)) {
+    if (line.rfind(name + ":", 0) != 0) {
+      continue;
+    }
+    cforge_size_t eq = line.find('=');
+    if (eq == std::string::npos) {
+      continue;
+    }
+    std::string value = line.substr(eq + 1);
+    if (!value.empty() && value.back() == '\r') {
+      value.pop_back();
+    }
+    return value;
+  }
+  return "";
+}
+
+std::filesystem::path installer::get_install_records_dir() {
+#ifdef _WIN32
+  cforge_cstring_t appdata = std::getenv("LOCALAPPDATA");
+  if (appdata) {
+    return std::filesystem::path(appdata) / "cforge" / "installs";
+  }
+  cforge_cstring_t userprofile = std::getenv("USERPROFILE");
+  if (userprofile) {
+    return std::filesystem::path(userprofile) / ".cforge" / "installs";
+  }
+#else
+  // Use XDG_DATA_HOME if set, otherwise ~/.local/share/cforge
+  cforge_cstring_t xdg_data = std::getenv("XDG_DATA_HOME");
+  if (xdg_data) {
+    return std::filesystem::path(xdg_data) / "cforge" / "installs";
+  }
+  cforge_cstring_t home = std::getenv("HOME");
+  if (!home) {
+    struct passwd *pw = getpwuid(getuid());
+    home              = pw ? pw->pw_dir : ".";
+  }
+  return std::filesystem::path(home) / ".local" / "share" / "cforge" / "installs";
+#endif
+  return std::filesystem::current_path() / ".cforge" / "installs";
+}
+
+// Keep CMake's install manifest so 'cforge uninstall' can remove the files
+// again. A component install only lists that component, so it is merged into
+// the earlier record of the same prefix; otherwise the new record replaces it.
+static void save_install_record(const std::filesystem::path &build_dir,
+                                const std::string &build_config,
+                                const std::filesystem::path &prefix,
+                                const cmake_install_options &options) {
+  std::string manifest_name = options.component.empty()
+                                  ? "install_manifest.txt"
+                                  : "install_manifest_" + options.component + ".txt";
+  std::ifstream manifest(build_dir / manifest_name);
+  if (!manifest) {
+    logger::print_warning("CMake did not write " + manifest_name
+                          + "; 'cforge uninstall' will not know about this install");
+    return;
+  }
+  std::stringstream manifest_text;
+  manifest_text << manifest.rdbuf();
+
+  install_record record;
+  record.project = options.project.empty() ? build_dir.parent_path().filename().string()
+                                           : options.project;
+  record.version = options.version;
+  record.config  = build_config;
+  record.prefix  = prefix.lexically_normal().string();
+  record.files   = parse_cmake_install_manifest(manifest_text.str());
+
+  std::time_t now = std::time(nullptr);
+  std::ostringstream date;
+  date << std::put_time(std::gmtime(&now), "%Y-%m-%dT%H:%M:%SZ");
+  record.date = date.str();
+
+  std::error_code ec;
+  std::filesystem::path records_dir = installer::get_install_records_dir();
+  std::filesystem::create_directories(records_dir, ec);
+  std::vector<std::filesystem::path> replaced;
+  for (const auto &entry : std::filesystem::directory_iterator(records_dir, ec)) {
+    if (entry.path().extension() != ".manifest") {
+      continue;
+    }
+    std::ifstream in(entry.path());
+    std::stringstream text;
+    text << in.rdbuf();
+    in.close();
+    auto existing = parse_install_record(text.str());
+    if (!existing || existing->project != record.project || existing->prefix != record.prefix) {
+      continue;
+    }
+    if (!options.component.empty()) {
+      for (const auto &file : existing->files) {
+        if (std::find(record.files.begin(), record.files.end(), file) == record.files.end()) {
+          record.files.push_back(file);
+        }
+      }
+    }
+    replaced.push_back(entry.path());
+  }
+  for (const auto &path : replaced) {
+    std::filesystem::remove(path, ec);
+  }
+
+  std::filesystem::path record_path =
+      records_dir / install_record_file_name(record.project, record.version, record.prefix);
+  std::ofstream out(record_path);
+  out << format_install_record(record);
+  if (!out) {
+    logger::print_warning("Could not write the install record " + record_path.string());
+    return;
+  }
+  print_verbose("Recorded " + std::to_string(record.files.size()) + " installed files in "
+                + record_path.string());
+}
+
+bool installer::install_with_cmake(const std::filesystem::path &build_dir,
+                                   const std::string &build_config,
+                                   const cmake_install_options &options) const {
+  if (!std::filesystem::exists(build_dir / "CMakeCache.txt")) {
+    logger::print_error("Build directory is not configured: " + build_dir.string());
+    logger::print_hint("Run 'cforge build' first, or install without --no-build");
+    return false;
+  }
+
+  std::filesystem::path prefix = options.prefix;
+  if (prefix.empty()) {
+    prefix = read_cmake_cache_value(build_dir, "CMAKE_INSTALL_PREFIX");
+  }
+  if (!prefix.empty() && prefix.is_relative()) {
+    prefix = std::filesystem::absolute(prefix);
+  }
+
+  // A dry run installs into a throwaway staging root and lists the result
+  std::error_code ec;
+  std::filesystem::path staging = options.destdir;
+  std::filesystem::path dry_run_root;
+  if (options.dry_run) {
+    dry_run_root = std::filesystem::temp_directory_path() / "cforge_install_dry_run";
+    std::filesystem::remove_all(dry_run_root, ec);
+    staging = dry_run_root;
+  }
+  if (!staging.empty()) {
+    staging = std::filesystem::absolute(staging);
+  }
+
+  std::vector<std::string> args = {"--install", build_dir.string(), "--config", build_config};
+  std::map<std::string, std::string> env;
+#ifdef _WIN32
+  if (!staging.empty()) {
+    if (prefix.empty()) {
+      logger::print_error("Cannot determine the install prefix for " + build_dir.string());
+      logger::print_hint("Pass --prefix together with --destdir");
+      return false;
+    }
+    args.push_back("--prefix");
+    args.push_back((staging / prefix.relative_path()).string());
+  } else if (!prefix.empty()) {
+    args.push_back("--prefix");
+    args.push_back(prefix.string());
+  }
+#else
+  if (!prefix.empty()) {
+    args.push_back("--prefix");
+    args.push_back(prefix.string());
+  }
+  if (!staging.empty()) {
+    env["DESTDIR"] = staging.string();
+  }
+#endif
+  if (!options.component.empty()) {
+    args.push_back("--component");
+    args.push_back(options.component);
+  }
+  if (options.strip) {
+    args.push_back("--strip");
+  }
+
+  bool success = false;
+  {
+    scoped_environment scoped_env(env);
+    success = execute_tool("cmake",
+                           args,
+                           "",
+                           "CMake Install",
+                           logger::get_verbosity() == log_verbosity::VERBOSITY_VERBOSE,
+                           300);
+  }
+
+  // Where the staged files would really end up
+  std::filesystem::path display_root =
+      options.destdir.empty() ? prefix.root_path() : std::filesystem::absolute(options.destdir);
+
+  if (!options.dry_run) {
+    if (success) {
+      std::filesystem::path target =
+          options.destdir.empty() ? prefix : display_root / prefix.relative_path();
+      // Staged files are packaged elsewhere, so only real installs are recorded
+      if (options.destdir.empty() && !prefix.empty()) {
+        save_install_record(build_dir, build_config, prefix, options);
+      }
+      logger::finished("install to " + target.string());
+    }
+    return success;
+  }
+
+  std::vector<std::string> files;
+  if (success && std::filesystem::exists(dry_run_root)) {
+    for (const auto &entry : std::filesystem::recursive_directory_iterator(dry_run_root)) {
+      if (!entry.is_directory()) {
+        files.push_back(
+            (display_root / entry.path().lexically_relative(dry_run_root)).generic_string());
+      }
+    }
+  }
+  std::filesystem::remove_all(dry_run_root, ec);
+  if (!success) {
+    return false;
+  }
+
+  std::sort(files.begin(), files.end());
+  std::string scope = options.component.empty() ? "" : " (component " + options.component + ")";
+  if (files.empty()) {
+    logger::print_warning("Nothing would be installed" + scope);
+    return true;
+  }
+  logger::print_status("Dry run: " + std::to_string(files.size()) + " file"
+                       + (files.size() == 1 ? "" : "s") + " would be installed" + scope);
+  for (const auto &file : files) {
+    logger::print_plain("  " + file);
+  }
+  return true;
+}
+
 std::string installer::get_default_install_path() const {
   return get_platform_specific_path();
 }
@@ -41,6 +41,11 @@ portable_options parse_portable_options(const toml_reader &config, const std::st
   opts.hardening  = config.get_string(section + ".hardening", "");
   opts.visibility = config.get_string(section + ".visibility", "");
 
+  // `warning_level` is accepted as an alias for `warnings`
+  if (opts.warnings.empty()) {
+    opts.warnings = config.get_string(section + ".warning_level", "");
+  }
+
   // Parse boolean options
   opts.warnings_as_errors = config.get_bool(section + ".warnings_as_errors", false);
   opts.debug_info         = config.get_bool(section + ".debug_info", false);
@@ -195,7 +200,7 @@ std::vector<std::string> translate_to_msvc(const portable_options &opts) {
     flags.push_back("/W0");
   } else if (opts.warnings == "default") {
     flags.push_back("/W3");
-  } else if (opts.warnings == "all") {
+  } else if (opts.warnings == "all" || opts.warnings == "extra") {
     flags.push_back("/W4");
   } else if (opts.warnings == "strict") {
     flags.push_back("/W4");
@@ -293,7 +298,7 @@ std::vector<std::string> translate_to_gcc(const portable_options &opts) {
   // Warnings
   if (opts.warnings == "none") {
     flags.push_back("-w");
-  } else if (opts.warnings == "all") {
+  } else if (opts.warnings == "all" || opts.warnings == "extra") {
     flags.push_back("-Wall");
     flags.push_back("-Wextra");
   } else if (opts.warnings == "strict") {
@@ -424,7 +429,7 @@ std::vector<std::string> translate_to_clang(const portable_options &opts) {
   // Warnings (same as GCC)
   if (opts.warnings == "none") {
     flags.push_back("-w");
-  } else if (opts.warnings == "all") {
+  } else if (opts.warnings == "all" || opts.warnings == "extra") {
     flags.push_back("-Wall");
     flags.push_back("-Wextra");
   } else if (opts.warnings == "strict") {
@@ -569,9 +574,8 @@ std::vector<std::string> translate_linker_to_msvc(const linker_options &opts) {
     flags.push_back("/OPT:ICF");
   }
 
-  // Static runtime
-  // Note: This is typically handled by CMAKE_MSVC_RUNTIME_LIBRARY
-  // but we can force it with linker flags if needed
+  // Static runtime: MSVC selects it with CMAKE_MSVC_RUNTIME_LIBRARY, set
+  // from [build] runtime, not with linker flags
 
   // Generate map file
   if (opts.map_file) {
@@ -831,8 +835,21 @@ std::string generate_portable_flags_cmake(const portable_options &opts,
   auto clang_flags = translate_to_clang(opts);
   auto clang_link  = translate_to_clang_link(opts);
 
+  // Sanitizers the compiler family cannot provide are dropped with a
+  // configure-time warning rather than failing the build
+  auto warn_unsupported_sanitizers = [&](const std::vector<std::string> &supported,
+                                         const std::string &compiler) {
+    for (const auto &san : opts.sanitizers) {
+      if (std::find(supported.begin(), supported.end(), san) == supported.end()) {
+        cmake << indent << "    message(WARNING \"Sanitizer '" << san << "' is not supported by "
+              << compiler << " and is ignored\")\n";
+      }
+    }
+  };
+
   cmake << indent << "# Portable compiler flags\n";
   cmake << indent << "if(MSVC AND NOT CMAKE_CXX_COMPILER_ID STREQUAL \"Clang\")\n";
+  warn_unsupported_sanitizers({"address"}, "MSVC");
 
   if (!msvc_flags.empty()) {
     cmake << indent << "    target_compile_options(" << target_name << " PRIVATE";
@@ -851,6 +868,7 @@ std::string generate_portable_flags_cmake(const portable_options &opts,
   }
 
   cmake << indent << "elseif(CMAKE_CXX_COMPILER_ID STREQUAL \"GNU\")\n";
+  warn_unsupported_sanitizers({"address", "undefined", "thread", "leak"}, "GCC");
 
   if (!gcc_flags.empty()) {
     cmake << indent << "    target_compile_options(" << target_name << " PRIVATE";
@@ -869,6 +887,7 @@ std::string generate_portable_flags_cmake(const portable_options &opts,
   }
 
   cmake << indent << "elseif(CMAKE_CXX_COMPILER_ID MATCHES \"Clang\")\n";
+  warn_unsupported_sanitizers({"address", "undefined", "thread", "memory", "leak"}, "Clang");
 
   if (!clang_flags.empty()) {
     cmake << indent << "    target_compile_options(" << target_name << " PRIVATE";
@@ -944,6 +963,54 @@ std::string generate_config_portable_flags_cmake(const std::string &config_name,
   return cmake.str();
 }
 
+std::string generate_config_universal_flags_cmake(const std::string &config_name,
+                                                  const std::vector<std::string> &tokens,
+                                                  const std::string &target_name) {
+  auto msvc_flags = parse_universal_flags(tokens, true);
+  auto gcc_flags  = parse_universal_flags(tokens, false, false);
+  if (msvc_flags.empty() && gcc_flags.empty()) {
+    return "";
+  }
+
+  std::vector<std::string> msvc_link;
+  if (std::find(msvc_flags.begin(), msvc_flags.end(), "/GL") != msvc_flags.end()) {
+    msvc_link.push_back("/LTCG");
+  }
+  std::vector<std::string> gcc_link;
+  for (const auto &flag : gcc_flags) {
+    if (flag == "-flto" || flag.rfind("-fsanitize=", 0) == 0) {
+      gcc_link.push_back(flag);
+    }
+  }
+
+  // Generator expressions work for single- and multi-config generators alike
+  auto emit = [&](std::ostringstream &out,
+                  const char *command,
+                  const std::vector<std::string> &flags) {
+    if (flags.empty()) {
+      return;
+    }
+    out << "    " << command << "(" << target_name << " PRIVATE";
+    for (const auto &flag : flags) {
+      out << " \"$<$<CONFIG:" << config_name << ">:" << flag << ">\"";
+    }
+    out << ")\n";
+  };
+
+  // clang-cl takes MSVC-style flags, so test the frontend, not the compiler
+  std::ostringstream cmake;
+  cmake << "# " << config_name << " configuration flags\n";
+  cmake << "if(CMAKE_CXX_COMPILER_FRONTEND_VARIANT STREQUAL \"MSVC\")\n";
+  emit(cmake, "target_compile_options", msvc_flags);
+  emit(cmake, "target_link_options", msvc_link);
+  cmake << "else()\n";
+  emit(cmake, "target_compile_options", gcc_flags);
+  emit(cmake, "target_link_options", gcc_link);
+  cmake << "endif()\n\n";
+
+  return cmake.str();
+}
+
 std::string generate_linker_flags_cmake(const linker_options &opts,
                                         const std::string &target_name,
                                         const std::string &indent) {
@@ -5,8 +5,11 @@
 
 #include "core/process_utils.hpp"
 
+#include "core/build_log.hpp"
 #include "core/build_progress.hpp"
+#include "core/build_units.hpp"
 #include "core/error_format.hpp"
+#include "core/git_utils.hpp"
 #include "core/types.h"
 
 #include <fmt/color.h>
@@ -14,17 +17,106 @@
 
 #include <algorithm>
 #include <array>
+#include <atomic>
 #include <chrono>
 #include <cstdlib>
+#include <cstring>
 #include <filesystem>
+#include <fstream>
+#include <map>
+#include <mutex>
 #include <set>
 #include <sstream>
+#include <thread>
 
 namespace cforge {
 
 // Global flag to suppress build warnings
 bool g_suppress_warnings = false;
 
+// Warning count from the most recent build tool invocation
+static cforge_int_t g_last_build_warning_count = 0;
+
+cforge_int_t get_last_build_warning_count() {
+  return g_last_build_warning_count;
+}
+
+// Only the main thread draws the in-place "Running ..." timer; commands run
+// from worker threads (parallel dependency fetches) would redraw each other's
+// line
+static const std::thread::id g_main_thread_id = std::this_thread::get_id();
+
+static bool can_show_command_timer() {
+  return std::this_thread::get_id() == g_main_thread_id;
+}
+
+// Step timings from the most recent build invocation
+static std::vector<file_timing> g_last_build_timings;
+
+const std::vector<file_timing> &get_last_build_timings() {
+  return g_last_build_timings;
+}
+
+// Ctrl+C state shared by interrupt_guard and execute_process(). The handler
+// only raises the flag; the process loops notice it and stop their children.
+static std::atomic<bool> g_interrupted{false};
+static std::atomic<cforge_int_t> g_interrupt_guard_depth{0};
+
+static bool interrupts_forwarded() {
+  return g_interrupt_guard_depth > 0;
+}
+
+#ifdef _WIN32
+static BOOL WINAPI interrupt_console_handler(DWORD ctrl_type) {
+  if (ctrl_type == CTRL_C_EVENT || ctrl_type == CTRL_BREAK_EVENT) {
+    g_interrupted = true;
+    return TRUE;
+  }
+  return FALSE;
+}
+#else
+static struct sigaction g_previous_sigint;
+static struct sigaction g_previous_sigterm;
+
+static void interrupt_signal_handler(cforge_int_t) {
+  g_interrupted = true;
+}
+#endif
+
+interrupt_guard::interrupt_guard() {
+  if (g_interrupt_guard_depth++ > 0) {
+    return;
+  }
+  g_interrupted = false;
+#ifdef _WIN32
+  SetConsoleCtrlHandler(interrupt_console_handler, TRUE);
+#else
+  struct sigaction action;
+  std::memset(&action, 0, sizeof(action));
+  action.sa_handler = interrupt_signal_handler;
+  action.sa_flags   = SA_RESTART;
+  sigemptyset(&action.sa_mask);
+  sigaction(SIGINT, &action, &g_previous_sigint);
+  sigaction(SIGTERM, &action, &g_previous_sigterm);
+#endif
+}
+
+interrupt_guard::~interrupt_guard() {
+  if (--g_interrupt_guard_depth > 0) {
+    return;
+  }
+#ifdef _WIN32
+  SetConsoleCtrlHandler(interrupt_console_handler, FALSE);
+#else
+  sigaction(SIGINT, &g_previous_sigint, nullptr);
+  sigaction(SIGTERM, &g_previous_sigterm, nullptr);
+#endif
+}
+
+bool was_interrupted() {
+  return g_interrupted;
+}
+
 #ifdef _WIN32
 // Windows-specific implementation
 process_result execute_process(const std::string &command,
@@ -37,17 +129,16 @@ process_result execute_process(const std::string &command,
   result.exit_code = -1;
   result.success   = false;
 
-  // Build command line string
-  std::string cmd_line = command;
-  for (const auto &arg : args) {
-    // Add quotes if there are spaces
-    if (arg.find(' ') != std::string::npos) {
-      cmd_line += " \"" + arg + "\"";
-    } else {
-      cmd_line += " " + arg;
-    }
+  bool forward_interrupts = interrupts_forwarded();
+  if (forward_interrupts && was_interrupted()) {
+    result.exit_code     = 130;
+    result.stderr_output = "Interrupted";
+    return result;
   }
 
+  // Build command line string; each argument survives as one argv entry
+  std::string cmd_line = build_windows_command_line(command, args);
+
   // Log command being executed in verbose mode
   logger::print_verbose("Executing command: " + cmd_line);
 
@@ -79,10 +170,22 @@ process_result execute_process(const std::string &command,
     return result;
   }
 
+  // Arguments and paths are UTF-8; the ANSI API would mangle anything
+  // outside the active code page
+  auto to_wstring = [](const std::string &s) {
+    cforge_int_t len = MultiByteToWideChar(CP_UTF8, 0, s.c_str(), -1, nullptr, 0);
+    std::wstring ws(len, 0);
+    MultiByteToWideChar(CP_UTF8, 0, s.c_str(), -1, &ws[0], len);
+    ws.resize(len - 1);  // Remove null terminator
+    return ws;
+  };
+  std::wstring wide_cmd_line    = to_wstring(cmd_line);
+  std::wstring wide_working_dir = to_wstring(working_dir);
+
   // Set up process startup info
-  STARTUPINFOA si;
-  ZeroMemory(&si, sizeof(STARTUPINFOA));
-  si.cb         = sizeof(STARTUPINFOA);
+  STARTUPINFOW si;
+  ZeroMemory(&si, sizeof(STARTUPINFOW));
+  si.cb         = sizeof(STARTUPINFOW);
   si.dwFlags    = STARTF_USESTDHANDLES;
   si.hStdInput  = GetStdHandle(STD_INPUT_HANDLE);
   si.hStdOutput = stdout_write;
@@ -92,16 +195,22 @@ process_result execute_process(const std::string &command,
   PROCESS_INFORMATION pi;
   ZeroMemory(&pi, sizeof(PROCESS_INFORMATION));
 
+  // Under an interrupt_guard the child and everything it spawns (cl.exe under
+  // Ninja or MSBuild) go into a job object so an interrupt can stop the whole
+  // tree. The child starts suspended until it is in the job.
+  HANDLE job = forward_interrupts ? CreateJobObjectW(NULL, NULL) : NULL;
+  DWORD creation_flags = CREATE_NO_WINDOW | (job ? CREATE_SUSPENDED : 0);
+
   // Create the process
-  BOOL success = CreateProcessA(NULL,  // No module name (use command line)
-                                const_cast<LPSTR>(cmd_line.c_str()),  // Command line
-                                NULL,              // Process handle not inheritable
-                                NULL,              // Thread handle not inheritable
-                                TRUE,              // Set handle inheritance to TRUE
-                                CREATE_NO_WINDOW,  // Do not create console window
-                                NULL,              // Use parent's environment block
+  BOOL success = CreateProcessW(NULL,                  // No module name (use command line)
+                                wide_cmd_line.data(),  // Command line (must be writable)
+                                NULL,                  // Process handle not inheritable
+                                NULL,                  // Thread handle not inheritable
+                                TRUE,                  // Set handle inheritance to TRUE
+                                creation_flags,        // No console window
+                                NULL,                  // Use parent's environment block
                                 working_dir.empty() ? NULL
-                                                    : working_dir.c_str(),  // Working directory
+                                                    : wide_working_dir.c_str(),  // Working dir
                                 &si,  // Pointer to STARTUPINFO structure
                                 &pi   // Pointer to PROCESS_INFORMATION structure
   );
@@ -114,12 +223,23 @@ process_result execute_process(const std::string &command,
     DWORD error_code = GetLastError();
     CloseHandle(stdout_read);
     CloseHandle(stderr_read);
+    if (job) {
+      CloseHandle(job);
+    }
     result.stderr_output = "Failed to create process: " + std::to_string(error_code);
     // Don't print error here - let the caller decide how to handle it
     // The error is captured in result.stderr_output
     return result;
   }
 
+  if (job) {
+    if (!AssignProcessToJobObject(job, pi.hProcess)) {
+      CloseHandle(job);
+      job = NULL;
+    }
+    ResumeThread(pi.hThread);
+  }
+
   // Read output from the child process's pipes
   const cforge_int_t BUFFER_SIZE = 4096;
   std::array<char, BUFFER_SIZE> buffer;
@@ -134,7 +254,7 @@ process_result execute_process(const std::string &command,
 
   // Status indicator for long-running commands (only if no external progress
   // callback)
-  bool show_status      = timeout_seconds > 10 && !stdout_callback;
+  bool show_status      = timeout_seconds > 10 && !stdout_callback && can_show_command_timer();
   bool showed_timer     = false;
   auto last_status_time = start_time;
 
@@ -159,6 +279,18 @@ process_result execute_process(const std::string &command,
       break;
     }
 
+    // Ctrl+C under an interrupt_guard: stop the child's whole process tree
+    if (forward_interrupts && was_interrupted()) {
+      if (job) {
+        TerminateJobObject(job, 130);
+      } else {
+        TerminateProcess(pi.hProcess, 130);
+      }
+      WaitForSingleObject(pi.hProcess, 2000);
+      result.exit_code = 130;
+      break;
+    }
+
     // Show status indicator with timer for long-running commands with no output
     if (show_status && since_last_activity > 3) {
       auto since_last_status =
@@ -260,11 +392,12 @@ process_result execute_process(const std::string &command,
   result.stderr_output = stderr_stream.str();
   result.success       = (result.exit_code == 0);
 
-  // Clear running timer line if we were showing one
+  // Clear (or, when not interactive, finish) the running timer line
   if (showed_timer) {
-    logger::clear_line();
-    fmt::print(stderr, "\n");
-    std::fflush(stderr);
+    auto elapsed_ms = std::chrono::duration_cast<std::chrono::milliseconds>(
+                          std::chrono::steady_clock::now() - start_time)
+                          .count();
+    logger::stop_timer(static_cast<double>(elapsed_ms) / 1000.0, result.success);
   }
 
   // Clean up handles
@@ -272,12 +405,42 @@ process_result execute_process(const std::string &command,
   CloseHandle(stderr_read);
   CloseHandle(pi.hProcess);
   CloseHandle(pi.hThread);
+  if (job) {
+    CloseHandle(job);
+  }
 
   return result;
 }
 
 #else
 // Unix-specific implementation
+
+/**
+ * @brief Stop an interrupted child together with its process group
+ *
+ * The group gets SIGTERM so tools like Ninja can stop their own children and
+ * remove half-written outputs. Anything still running after two seconds, or
+ * left behind once the child has exited, is killed.
+ *
+ * @param pid Child process, also the id of its process group
+ */
+static void stop_process_group(pid_t pid) {
+  kill(-pid, SIGTERM);
+  kill(-pid, SIGCONT);  // Stopped members cannot act on SIGTERM
+
+  auto deadline = std::chrono::steady_clock::now() + std::chrono::seconds(2);
+  cforge_int_t status;
+  while (waitpid(pid, &status, WNOHANG) == 0) {
+    if (std::chrono::steady_clock::now() >= deadline) {
+      kill(-pid, SIGKILL);
+      waitpid(pid, &status, 0);
+      break;
+    }
+    usleep(10000);  // 10ms
+  }
+  kill(-pid, SIGKILL);
+}
+
 process_result execute_process(const std::string &command,
                                const std::vector<std::string> &args,
                                const std::string &working_dir,
@@ -288,6 +451,13 @@ process_result execute_process(const std::string &command,
   result.exit_code = -1;
   result.success   = false;
 
+  bool forward_interrupts = interrupts_forwarded();
+  if (forward_interrupts && was_interrupted()) {
+    result.exit_code     = 130;
+    result.stderr_output = "Interrupted";
+    return result;
+  }
+
   // Create pipes for stdout and stderr
   cforge_int_t stdout_pipe[2];
   cforge_int_t stderr_pipe[2];
@@ -309,6 +479,19 @@ process_result execute_process(const std::string &command,
   } else if (pid == 0) {
     // Child process
 
+    // Under an interrupt_guard the child leads its own process group, so an
+    // interrupt reaches the compilers it spawns as well. That group is in the
+    // background, and reading the terminal would stop it with SIGTTIN, so git
+    // and ssh must fail instead of prompting for credentials.
+    if (forward_interrupts) {
+      setpgid(0, 0);
+      setenv("GIT_TERMINAL_PROMPT", "0", 1);
+      setenv("GCM_INTERACTIVE", "never", 1);
+      if (!getenv("GIT_SSH") && !getenv("GIT_SSH_COMMAND")) {
+        setenv("GIT_SSH_COMMAND", "ssh -o BatchMode=yes", 1);
+      }
+    }
+
     // Change working directory if specified
     if (!working_dir.empty()) {
       if (chdir(working_dir.c_str()) == -1) {
@@ -342,6 +525,11 @@ process_result execute_process(const std::string &command,
   } else {
     // Parent process
 
+    // Also set from this side; whichever runs first wins the race with exec
+    if (forward_interrupts) {
+      setpgid(pid, pid);
+    }
+
     // Close write ends of pipes
     close(stdout_pipe[1]);
     close(stderr_pipe[1]);
@@ -361,12 +549,19 @@ process_result execute_process(const std::string &command,
     auto start_time         = std::chrono::steady_clock::now();
     auto last_activity_time = start_time;
     auto last_status_time   = start_time;
-    bool show_status        = timeout_seconds > 10 && !stdout_callback;
+    bool show_status        = timeout_seconds > 10 && !stdout_callback && can_show_command_timer();
     bool showed_timer       = false;
 
     while (child_running) {
       auto current_time = std::chrono::steady_clock::now();
 
+      // Ctrl+C under an interrupt_guard: stop the child's whole process group
+      if (forward_interrupts && was_interrupted()) {
+        stop_process_group(pid);
+        result.exit_code = 130;
+        break;
+      }
+
       // Check child process status
       cforge_int_t status;
       pid_t wait_result = waitpid(pid, &status, WNOHANG);
@@ -451,11 +646,12 @@ process_result execute_process(const std::string &command,
       }
     }
 
-    // Clear running timer line if we were showing one
+    // Clear (or, when not interactive, finish) the running timer line
     if (showed_timer) {
-      logger::clear_line();
-      fmt::print(stderr, "\n");
-      std::fflush(stderr);
+      auto elapsed_ms = std::chrono::duration_cast<std::chrono::milliseconds>(
+                            std::chrono::steady_clock::now() - start_time)
+                            .count();
+      logger::stop_timer(static_cast<double>(elapsed_ms) / 1000.0, result.exit_code == 0);
     }
 
     // Close read ends of pipes
@@ -480,6 +676,131 @@ std::string string_to_lower(const std::string &str) {
   return result;
 }
 
+static std::string read_text_file(const std::filesystem::path &path) {
+  std::ifstream file(path, std::ios::binary);
+  std::stringstream contents;
+  contents << file.rdbuf();
+  return contents.str();
+}
+
+/**
+ * @brief Number of translation units a `cmake --build` invocation compiles
+ *
+ * Read from the configured build system: the Ninja file of the
+ * configuration, compile_commands.json, or the generated .vcxproj files. The
+ * count is cached per build directory and configuration until the file it
+ * came from changes (i.e. CMake regenerated the build system).
+ *
+ * @return Translation unit count, or 0 if the build tree has none of these
+ */
+static cforge_int_t expected_build_units(const std::vector<std::string> &args,
+                                         const std::string &working_dir) {
+  namespace fs = std::filesystem;
+  fs::path build_dir;
+  std::string config;
+  for (cforge_size_t i = 0; i + 1 < args.size(); ++i) {
+    if (args[i] == "--build") {
+      build_dir = args[i + 1];
+    } else if (args[i] == "--config") {
+      config = args[i + 1];
+    }
+  }
+  if (build_dir.empty()) {
+    return 0;
+  }
+  if (build_dir.is_relative()) {
+    build_dir = (working_dir.empty() ? fs::current_path() : fs::path(working_dir)) / build_dir;
+  }
+
+  std::vector<fs::path> sources;
+  std::error_code ec;
+  fs::path multi_config_ninja = build_dir / "CMakeFiles" / ("impl-" + config + ".ninja");
+  if (!config.empty() && fs::exists(multi_config_ninja, ec)) {
+    sources.push_back(multi_config_ninja);
+  } else if (fs::exists(build_dir / "build.ninja", ec)) {
+    sources.push_back(build_dir / "build.ninja");
+  } else if (fs::exists(build_dir / "compile_commands.json", ec)) {
+    sources.push_back(build_dir / "compile_commands.json");
+  } else {
+    // CMakeFiles holds the compiler-identification projects, not the build's
+    fs::recursive_directory_iterator it(build_dir, ec), end;
+    for (; !ec && it != end; it.increment(ec)) {
+      if (it->path().filename() == "CMakeFiles") {
+        it.disable_recursion_pending();
+      } else if (it->path().extension() == ".vcxproj") {
+        sources.push_back(it->path());
+      }
+    }
+  }
+  if (sources.empty()) {
+    return 0;
+  }
+
+  struct cached_count {
+    fs::file_time_type written;
+    cforge_int_t units = 0;
+  };
+  static std::map<std::string, cached_count> cache;
+  static std::mutex cache_mutex;
+
+  std::string key           = build_dir.lexically_normal().string() + "|" + config;
+  fs::file_time_type newest = fs::file_time_type::min();
+  for (const auto &source : sources) {
+    newest = std::max(newest, fs::last_write_time(source, ec));
+  }
+
+  std::lock_guard<std::mutex> lock(cache_mutex);
+  auto cached = cache.find(key);
+  if (cached != cache.end() && cached->second.written == newest) {
+    return cached->second.units;
+  }
+
+  cforge_int_t units = 0;
+  for (const auto &source : sources) {
+    std::string contents = read_text_file(source);
+    if (source.extension() == ".ninja") {
+      units += count_ninja_compile_units(contents);
+    } else if (source.extension() == ".json") {
+      units += count_compile_database_units(contents);
+    } else {
+      units += count_msbuild_compile_units(contents);
+    }
+  }
+  cache[key] = {newest, units};
+  return units;
+}
+
+/**
+ * @brief Build directory of a CMake configure or build command
+ *
+ * @param kind Receives "configure" for `-B <dir>`, "build" for `--build <dir>`
+ * @return The build directory, empty for other commands
+ */
+static std::filesystem::path logged_step_build_dir(const std::vector<std::string> &args,
+                                                   const std::string &working_dir,
+                                                   std::string &kind) {
+  namespace fs = std::filesystem;
+  fs::path build_dir;
+  for (cforge_size_t i = 0; i < args.size(); ++i) {
+    if (args[i] == "--build" && i + 1 < args.size()) {
+      build_dir = args[i + 1];
+      kind      = "build";
+      break;
+    }
+    if (args[i] == "-B" && i + 1 < args.size()) {
+      build_dir = args[i + 1];
+      kind      = "configure";
+    } else if (args[i].size() > 2 && args[i].rfind("-B", 0) == 0) {
+      build_dir = args[i].substr(2);
+      kind      = "configure";
+    }
+  }
+  if (!build_dir.empty() && build_dir.is_relative()) {
+    build_dir = (working_dir.empty() ? fs::current_path() : fs::path(working_dir)) / build_dir;
+  }
+  return build_dir;
+}
+
 // Common implementation for both platforms
 bool execute_tool(const std::string &command,
                   const std::vector<std::string> &args,
@@ -527,12 +848,14 @@ bool execute_tool(const std::string &command,
   std::stringstream stdout_collect, stderr_collect;
 
   // Build progress tracking - enabled for CMake Build commands
-  bool show_progress = !verbose && is_build_tool
+  bool is_build_step = is_build_tool
                     && (tool_name_lower == "cmake build"
                         || tool_name.find("Build") != std::string::npos);
+  bool show_progress = !verbose && is_build_step;
   build_progress progress;
   if (show_progress) {
     progress.reset();
+    progress.set_expected_steps(expected_build_units(args, working_dir));
   }
 
   // Process stdout/stderr in real-time
@@ -582,21 +905,29 @@ bool execute_tool(const std::string &command,
     stdout_callback = handle_chunk;
     stderr_callback = handle_chunk;
   } else if (verbose) {
-    stdout_callback = [&tool_name](const std::string &chunk) {
+    // Build steps are still timed as they appear so the build report has
+    // per-step durations in verbose mode too.
+    stdout_callback = [&progress, is_build_step](const std::string &chunk) {
       std::string line;
       std::istringstream ss(chunk);
       while (std::getline(ss, line)) {
         if (!line.empty()) {
           logger::print_action("Output", line);
+          if (is_build_step) {
+            progress.parse_line(line);
+          }
         }
       }
     };
-    stderr_callback = [&tool_name](const std::string &chunk) {
+    stderr_callback = [&progress, is_build_step](const std::string &chunk) {
       std::string line;
       std::istringstream ss(chunk);
       while (std::getline(ss, line)) {
         if (!line.empty()) {
           logger::print_action("Output", line);
+          if (is_build_step) {
+            progress.parse_line(line);
+          }
         }
       }
     };
@@ -616,6 +947,53 @@ bool execute_tool(const std::string &command,
     logger::reset_progress_display();
   }
 
+  // An interrupted tool's output is not a diagnostic worth reporting
+  if (interrupts_forwarded() && was_interrupted()) {
+    return false;
+  }
+
+  if (!result.success && command == "git" && is_git_authentication_error(result.stderr_output)) {
+    logger::print_error(tool_name_to_use + " failed: the repository needs credentials");
+    logger::print_hint("Set up a git credential helper or an SSH key, or run the git command "
+                       "once yourself so the credentials are stored");
+  }
+
+  // Configure and build steps keep their raw output, which the progress
+  // display and the formatter below only show part of
+  std::filesystem::path log_path;
+  std::string log_output;
+  if (is_build_tool) {
+    std::string log_kind;
+    std::filesystem::path log_build_dir = logged_step_build_dir(args, working_dir, log_kind);
+    if (!log_build_dir.empty()) {
+      std::filesystem::path start = working_dir.empty() ? std::filesystem::current_path()
+                                                        : std::filesystem::path(working_dir);
+      cforge_int_t keep           = configured_build_log_limit(start);
+      log_output = build_log_text(result.stdout_output, result.stderr_output);
+      log_path   = write_build_log(log_build_dir, log_kind, cmd_str, log_output, keep);
+    }
+  }
+
+  // Record how many warnings the build produced for the build summary. The
+  // progress callbacks already saw every line; otherwise scan the output now.
+  if (is_build_tool) {
+    bool parsed_live = show_progress || (verbose && is_build_step);
+    if (!parsed_live) {
+      for (const auto *output : {&result.stderr_output, &result.stdout_output}) {
+        std::string line;
+        std::istringstream ss(*output);
+        while (std::getline(ss, line)) {
+          progress.parse_line(line);
+        }
+      }
+    }
+    g_last_build_warning_count = static_cast<cforge_int_t>(progress.get_warnings().size());
+    if (is_build_step) {
+      progress.finish(result.success);
+      g_last_build_timings = progress.get_timings();
+    }
+  }
+
   // Persist build-tool stderr+stdout so `cforge errors` / `cforge warnings`
   // can re-display the diagnostics later. We only do this for build tools so
   // the log reflects compile/link output, not e.g. ad-hoc `cmake --version`.
@@ -831,6 +1209,14 @@ bool execute_tool(const std::string &command,
     }
   }
 
+  if (!result.success && !log_path.empty()) {
+    if (verbose) {
+      logger::print_verbose("Full output in " + log_path.string());
+    } else {
+      print_build_log_tail(log_path, log_output);
+    }
+  }
+
   return result.exit_code == 0;
 }
 
@@ -897,6 +1283,8 @@ bool is_command_available(const std::string &command, cforge_int_t timeout_secon
     args = {"--version"};
   } else if (command == "python" || command == "python3") {
     args = {"--version"};
+  } else if (command == "emrun") {
+    args = {"--help"};  // emrun has no --version
   } else {
     // Default to --version for most commands
     args = {"--version"};
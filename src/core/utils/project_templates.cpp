@@ -0,0 +1,80 @@
+/**
+ * @file project_templates.cpp
+ * @brief Writing embedded project templates to disk
+ */
+
+#include "core/project_templates.hpp"
+
+#include "cforge/log.hpp"
+#include "core/process_utils.hpp"
+
+#include <fstream>
+
+namespace cforge {
+
+bool write_template_file(const std::filesystem::path &project_dir,
+                         const template_file &file,
+                         const template_variables &variables,
+                         bool overwrite) {
+  std::string relative       = render_template(file.path, variables);
+  std::filesystem::path path = project_dir / std::filesystem::u8path(relative);
+
+  if (std::filesystem::exists(path)) {
+    if (!overwrite) {
+      logger::print_warning(relative + " already exists, skipping");
+      return true;
+    }
+    logger::print_action("Overwriting", relative);
+  }
+
+  std::error_code ec;
+  std::filesystem::create_directories(path.parent_path(), ec);
+  std::ofstream out(path, std::ios::binary);
+  if (!out) {
+    logger::print_error("Failed to create " + relative);
+    return false;
+  }
+  out << render_template(file.content, variables);
+  out.close();
+
+  logger::created(relative);
+  return true;
+}
+
+bool write_project_template(const std::filesystem::path &project_dir,
+                            const project_template &tmpl,
+                            const template_variables &variables,
+                            bool overwrite) {
+  std::error_code ec;
+  std::filesystem::create_directories(project_dir, ec);
+  if (ec) {
+    logger::print_error("Failed to create " + project_dir.string() + ": " + ec.message());
+    return false;
+  }
+
+  bool ok = true;
+  for (const auto &file : tmpl.files) {
+    ok = write_template_file(project_dir, file, variables, overwrite) && ok;
+  }
+  return write_template_file(project_dir, gitignore_template(), variables, overwrite) && ok;
+}
+
+void init_project_git_repository(const std::filesystem::path &project_dir, bool verbose) {
+  if (std::filesystem::exists(project_dir / ".git")) {
+    logger::print_verbose("Git repository already exists, skipping git init");
+    return;
+  }
+  if (!is_command_available("git")) {
+    logger::print_warning("Git not found in PATH, skipping git initialization");
+    return;
+  }
+
+  if (execute_tool("git", {"init", "--quiet"}, project_dir.string(), "Git", verbose, 20)) {
+    logger::created("git repository");
+  } else {
+    logger::print_warning("Failed to initialize git repository. This is not critical for "
+                          "project creation");
+  }
+}
+
+}  // namespace cforge
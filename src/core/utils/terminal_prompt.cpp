@@ -14,6 +14,8 @@
 
 #include "core/utils/terminal_prompt.hpp"
 
+#include "cforge/log.hpp"
+
 #include <fmt/color.h>
 #include <fmt/core.h>
 
@@ -313,8 +315,11 @@ static void print_option_line(const std::string &option, bool selected, bool cle
   }
   if (selected) {
     // Bold green ">" indicator.
-    fmt::print(fg(fmt::color::green) | fmt::emphasis::bold, "  {:>{}} ", ">", STATUS_WIDTH - 2);
-    fmt::print(fmt::emphasis::bold, "{}\n", option);
+    fmt::print(logger::style(fg(fmt::color::green) | fmt::emphasis::bold),
+               "  {:>{}} ",
+               ">",
+               STATUS_WIDTH - 2);
+    fmt::print(logger::style(fmt::emphasis::bold), "{}\n", option);
   } else {
     // Unselected: plain indentation matching STATUS_WIDTH columns.
     fmt::print("  {:>{}} {}\n", "", STATUS_WIDTH - 2, option);
@@ -339,7 +344,8 @@ cforge_int_t prompt_select(const std::string &label,
       (default_index >= 0 && default_index < static_cast<int>(options.size())) ? default_index : 0;
 
   // Print label right-aligned to STATUS_WIDTH chars, bold green.
-  fmt::print(fg(fmt::color::green) | fmt::emphasis::bold, "{:>{}}", label, STATUS_WIDTH);
+  fmt::print(
+      logger::style(fg(fmt::color::green) | fmt::emphasis::bold), "{:>{}}", label, STATUS_WIDTH);
   fmt::print(":\n");
 
   // Initial render of all options.
@@ -399,12 +405,13 @@ std::string prompt_text(const std::string &label, const std::string &default_val
   }
 
   // Print label right-aligned, bold green, followed by ": ".
-  fmt::print(fg(fmt::color::green) | fmt::emphasis::bold, "{:>{}}", label, STATUS_WIDTH);
+  fmt::print(
+      logger::style(fg(fmt::color::green) | fmt::emphasis::bold), "{:>{}}", label, STATUS_WIDTH);
   fmt::print(": ");
 
   // Show default in dim gray.
   if (!default_value.empty()) {
-    fmt::print(fg(fmt::color::gray), "({})", default_value);
+    fmt::print(logger::style(fg(fmt::color::gray)), "({})", default_value);
     fmt::print(" ");
   }
 
@@ -440,7 +447,8 @@ bool prompt_confirm(const std::string &label, bool default_yes) {
   }
 
   // Print label right-aligned, bold green.
-  fmt::print(fg(fmt::color::green) | fmt::emphasis::bold, "{:>{}}", label, STATUS_WIDTH);
+  fmt::print(
+      logger::style(fg(fmt::color::green) | fmt::emphasis::bold), "{:>{}}", label, STATUS_WIDTH);
 
   // Show (Y/n) or (y/N) hint.
   if (default_yes) {
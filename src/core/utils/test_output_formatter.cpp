@@ -257,16 +257,16 @@ void test_output_formatter::print_test_result(const test_result &result, cforge_
 
   switch (result.status) {
     case test_status::PASSED:
-      fmt::print(fg(fmt::color::green), "ok");
+      fmt::print(logger::style(fg(fmt::color::green)), "ok");
       break;
     case test_status::FAILED:
-      fmt::print(fmt::emphasis::bold | fg(fmt::color::red), "FAILED");
+      fmt::print(logger::style(fmt::emphasis::bold | fg(fmt::color::red)), "FAILED");
       break;
     case test_status::SKIPPED:
-      fmt::print(fg(fmt::color::yellow), "ignored");
+      fmt::print(logger::style(fg(fmt::color::yellow)), "ignored");
       break;
     case test_status::TIMEOUT:
-      fmt::print(fmt::emphasis::bold | fg(fmt::color::red), "TIMEOUT");
+      fmt::print(logger::style(fmt::emphasis::bold | fg(fmt::color::red)), "TIMEOUT");
       break;
     default:
       fmt::print("???");
@@ -274,7 +274,7 @@ void test_output_formatter::print_test_result(const test_result &result, cforge_
   }
 
   if (result.duration.count() > 0) {
-    fmt::print(fg(fmt::color::dim_gray), " ({})", format_duration(result.duration));
+    fmt::print(logger::style(fg(fmt::color::dim_gray)), " ({})", format_duration(result.duration));
   }
 
   fmt::print("\n");
@@ -294,7 +294,7 @@ void test_output_formatter::print_failure_details(const test_result &result) {
 
   // Format error in Rust-style
   if (!result.failure_message.empty() || !result.file_path.empty()) {
-    fmt::print(fmt::emphasis::bold | fg(fmt::color::red), "error");
+    fmt::print(logger::style(fmt::emphasis::bold | fg(fmt::color::red)), "error");
     fmt::print("[TEST]: ");
     if (!result.failure_message.empty()) {
       fmt::print("{}", result.failure_message);
@@ -304,7 +304,7 @@ void test_output_formatter::print_failure_details(const test_result &result) {
     fmt::print("\n");
 
     if (!result.file_path.empty()) {
-      fmt::print(fg(fmt::color::cyan), "  --> ");
+      fmt::print(logger::style(fg(fmt::color::cyan)), "  --> ");
       fmt::print("{}", shorten_path(result.file_path));
       if (result.line_number > 0) {
         fmt::print(":{}", result.line_number);
@@ -318,38 +318,38 @@ void test_output_formatter::print_failure_details(const test_result &result) {
       if (result.line_number > 0) {
         std::string source_line = read_source_line(result.file_path, result.line_number);
         if (!source_line.empty()) {
-          fmt::print(fg(fmt::color::cyan), "   |\n");
-          fmt::print(fg(fmt::color::cyan), "{:4} |   ", result.line_number);
+          fmt::print(logger::style(fg(fmt::color::cyan)), "   |\n");
+          fmt::print(logger::style(fg(fmt::color::cyan)), "{:4} |   ", result.line_number);
           fmt::print("{}\n", source_line);
-          fmt::print(fg(fmt::color::cyan), "   |   ");
+          fmt::print(logger::style(fg(fmt::color::cyan)), "   |   ");
 
           // Add carets under the line
-          fmt::print(fg(fmt::color::red), "");
+          fmt::print(logger::style(fg(fmt::color::red)), "");
           for (cforge_size_t i = 0; i < source_line.length() && i < 40; ++i) {
-            fmt::print(fg(fmt::color::red), "^");
+            fmt::print(logger::style(fg(fmt::color::red)), "^");
           }
           fmt::print("\n");
         }
       }
 
-      fmt::print(fg(fmt::color::cyan), "   |\n");
+      fmt::print(logger::style(fg(fmt::color::cyan)), "   |\n");
     }
 
     // Show expected vs actual
     if (!result.expected_value.empty() || !result.actual_value.empty()) {
       if (!result.expected_value.empty()) {
-        fmt::print(fg(fmt::color::cyan), "   = ");
+        fmt::print(logger::style(fg(fmt::color::cyan)), "   = ");
         fmt::print("expected: {}\n", result.expected_value);
       }
       if (!result.actual_value.empty()) {
-        fmt::print(fg(fmt::color::cyan), "   = ");
+        fmt::print(logger::style(fg(fmt::color::cyan)), "   = ");
         fmt::print("actual: {}\n", result.actual_value);
       }
     }
 
     // Show notes
     for (const auto &note : result.notes) {
-      fmt::print(fg(fmt::color::cyan), "   = ");
+      fmt::print(logger::style(fg(fmt::color::cyan)), "   = ");
       fmt::print("note: {}\n", note);
     }
   }
@@ -377,7 +377,7 @@ void test_output_formatter::print_all_failures(const std::vector<test_result> &r
   }
 
   fmt::print("\n");
-  fmt::print(fmt::emphasis::bold, "failures:\n\n");
+  fmt::print(logger::style(fmt::emphasis::bold), "failures:\n\n");
 
   for (const auto &result : results) {
     print_failure_details(result);
@@ -385,7 +385,7 @@ void test_output_formatter::print_all_failures(const std::vector<test_result> &r
 
   // Cargo also prints a flat name list right before the result line.
   fmt::print("\n");
-  fmt::print(fmt::emphasis::bold, "failures:\n");
+  fmt::print(logger::style(fmt::emphasis::bold), "failures:\n");
   for (const auto &result : results) {
     if (result.status == test_status::FAILED || result.status == test_status::TIMEOUT) {
       fmt::print("    {}\n", result.name);
@@ -400,9 +400,9 @@ void test_output_formatter::print_summary(const test_summary &summary) {
   // T"
   fmt::print("\ntest result: ");
   if (ok) {
-    fmt::print(fg(fmt::color::green) | fmt::emphasis::bold, "ok");
+    fmt::print(logger::style(fg(fmt::color::green) | fmt::emphasis::bold), "ok");
   } else {
-    fmt::print(fg(fmt::color::red) | fmt::emphasis::bold, "FAILED");
+    fmt::print(logger::style(fg(fmt::color::red) | fmt::emphasis::bold), "FAILED");
   }
   fmt::print(". ");
   fmt::print("{} passed", summary.passed);
@@ -423,7 +423,8 @@ void test_output_formatter::print_test_list(const std::vector<std::string> &test
 
   for (const auto &[suite, suite_tests] : grouped) {
     if (!suite.empty()) {
-      fmt::print(fg(fmt::color::cyan) | fmt::emphasis::bold, "{:>{}}", suite, STATUS_WIDTH);
+      fmt::print(
+          logger::style(fg(fmt::color::cyan) | fmt::emphasis::bold), "{:>{}}", suite, STATUS_WIDTH);
       fmt::print("::\n");
       for (const auto &test : suite_tests) {
         fmt::print("{:>{}} {}\n", "", STATUS_WIDTH, test);
@@ -8,15 +8,19 @@
 #include "cforge/log.hpp"
 
 #include "core/build_utils.hpp"
+#include "core/pch.hpp"
+#include "core/platform_settings.hpp"
 #include "core/process_utils.hpp"
 #include "core/test_adapters.hpp"
 #include "core/types.h"
 #include "core/workspace.hpp"
 
 #include <algorithm>
+#include <atomic>
 #include <fstream>
 #include <regex>
 #include <sstream>
+#include <thread>
 
 namespace cforge {
 
@@ -477,8 +481,7 @@ test_runner::~test_runner() = default;
 
 bool test_runner::load_config() {
   // Load build directory from config (same as main build uses)
-  std::string build_dir_str = m_project_config.get_string("build.build_dir", "build");
-  m_build_base_dir          = m_project_dir / build_dir_str;
+  m_build_base_dir = get_build_base_dir(m_project_dir, &m_project_config);
 
   // Load [test] section defaults
   m_test_config.directory = m_project_config.get_string("test.directory", "tests");
@@ -487,6 +490,8 @@ bool test_runner::load_config() {
   m_test_config.jobs = static_cast<cforge_int_t>(m_project_config.get_int("test.jobs", 0));
   m_test_config.auto_link_project = m_project_config.get_bool("test.auto_link_project", true);
   m_test_config.discovery_mode    = m_project_config.get_string("test.discovery", "both");
+  m_test_config.sources           = m_project_config.get_string_array("test.sources");
+  m_test_config.defines           = m_project_config.get_string_array("test.defines");
 
   // Parse output style
   std::string output_style         = m_project_config.get_string("test.output_style", "cargo");
@@ -505,13 +510,35 @@ bool test_runner::load_config() {
   return true;
 }
 
+// True when cforge.toml already pulls the framework in through vcpkg, conan
+// or the system package manager, so the test build should find_package() it
+// instead of fetching its own copy
+static bool is_framework_from_package_manager(const fs::path &project_dir,
+                                              const toml_reader &project_config,
+                                              test_framework fw) {
+  switch (fw) {
+    case test_framework::GTest:
+      return is_package_manager_dependency(project_dir, project_config, {"gtest", "googletest"});
+    case test_framework::Catch2:
+      return is_package_manager_dependency(project_dir, project_config, {"catch2"});
+    case test_framework::Doctest:
+      return is_package_manager_dependency(project_dir, project_config, {"doctest"});
+    case test_framework::BoostTest:
+      return is_package_manager_dependency(project_dir, project_config, {"boost-test", "boost"});
+    default:
+      return false;
+  }
+}
+
 void test_runner::load_framework_config(test_framework fw, const std::string &section) {
-  if (!m_project_config.has_key(section)) {
+  bool from_package_manager =
+      is_framework_from_package_manager(m_project_dir, m_project_config, fw);
+  if (!m_project_config.has_key(section) && !from_package_manager) {
     return;
   }
 
   test_config::FrameworkConfig fc;
-  fc.fetch   = m_project_config.get_bool(section + ".fetch", true);
+  fc.fetch   = m_project_config.get_bool(section + ".fetch", !from_package_manager);
   fc.version = m_project_config.get_string(section + ".version", "");
 
   // Load additional options from the section
@@ -533,8 +560,11 @@ fs::path test_runner::get_test_gen_dir(const std::string &target_name) const {
   return m_build_base_dir / "tests" / target_name;
 }
 
-fs::path test_runner::get_test_build_dir(const std::string &target_name) const {
-  return get_test_gen_dir(target_name) / "build";
+fs::path test_runner::get_test_build_dir(const std::string &target_name,
+                                         const std::string &build_config) const {
+  // Same per-config rule as the main project build directory
+  return get_build_dir_for_config(
+      (get_test_gen_dir(target_name) / "build").string(), build_config, false);
 }
 
 // ============================================================================
@@ -590,6 +620,14 @@ std::vector<test_target> test_runner::load_explicit_targets() {
     target.dependencies = table.get_string_array("dependencies");
     target.defines      = table.get_string_array("defines");
     target.includes     = table.get_string_array("includes");
+    target.labels       = table.get_string_array("labels");
+    std::vector<std::string> unknown_platforms;
+    target.platform_defines = read_platform_lists(table, "platform_defines", unknown_platforms);
+    target.platform_flags   = read_platform_lists(table, "platform_flags", unknown_platforms);
+    for (const auto &os : unknown_platforms) {
+      logger::print_warning("Test target '" + target.name + "': unknown platform '" + os
+                            + "' (use windows, linux or macos)");
+    }
     target.timeout_seconds =
         static_cast<cforge_int_t>(table.get_int("timeout", m_test_config.default_timeout));
     target.enabled = table.get_bool("enabled", true);
@@ -620,36 +658,44 @@ std::vector<test_target> test_runner::auto_discover_targets() {
   std::vector<test_target> targets;
 
   fs::path test_dir = m_project_dir / m_test_config.directory;
-  logger::print_verbose("Looking for tests in: " + test_dir.string());
-  if (!fs::exists(test_dir) || !fs::is_directory(test_dir)) {
-    logger::print_verbose("Test directory does not exist: " + test_dir.string());
-    return targets;
-  }
-
-  // Look for test source files
   std::vector<fs::path> test_files;
-  for (const auto &entry : fs::recursive_directory_iterator(test_dir)) {
-    if (!entry.is_regular_file()) {
-      continue;
+  if (!m_test_config.sources.empty()) {
+    // test.sources replaces the scan of the test directory
+    logger::print_verbose("Looking for tests matching test.sources");
+    test_files = expand_globs(m_test_config.sources, m_project_dir);
+  } else {
+    logger::print_verbose("Looking for tests in: " + test_dir.string());
+    if (!fs::exists(test_dir) || !fs::is_directory(test_dir)) {
+      logger::print_verbose("Test directory does not exist: " + test_dir.string());
+      return targets;
     }
 
-    auto ext = entry.path().extension().string();
-    if (ext == ".cpp" || ext == ".cxx" || ext == ".cc") {
-      test_files.push_back(entry.path());
+    // Look for test source files
+    for (const auto &entry : fs::recursive_directory_iterator(test_dir)) {
+      if (!entry.is_regular_file()) {
+        continue;
+      }
+
+      auto ext = entry.path().extension().string();
+      if (ext == ".cpp" || ext == ".cxx" || ext == ".cc") {
+        test_files.push_back(entry.path());
+      }
     }
   }
 
   if (test_files.empty()) {
-    logger::print_verbose("No test source files found in " + test_dir.string());
+    logger::print_verbose("No test source files found");
     return targets;
   }
 
   logger::print_verbose("Found " + std::to_string(test_files.size()) + " test source files");
 
-  // Group files by directory to create targets
+  // Group files by directory to create targets; files picked by test.sources
+  // always form a single target
   std::map<fs::path, std::vector<fs::path>> files_by_dir;
   for (const auto &file : test_files) {
-    fs::path rel = fs::relative(file.parent_path(), test_dir);
+    fs::path rel =
+        m_test_config.sources.empty() ? fs::relative(file.parent_path(), test_dir) : fs::path(".");
     files_by_dir[rel].push_back(file);
   }
 
@@ -864,13 +910,6 @@ bool test_runner::generate_test_cmake(const test_target &target) {
   // uses the builtin framework. Auto-registration via TEST() does the rest.
   fs::path generated_main = generate_main_if_needed(target, gen_dir);
 
-  fs::path cmake_file = gen_dir / "CMakeLists.txt";
-  std::ofstream out(cmake_file);
-  if (!out) {
-    m_error = "FAILED to create " + cmake_file.string();
-    return false;
-  }
-
   // Get adapter for framework
   auto *adapter = get_adapter(target.framework);
   if (!adapter) {
@@ -878,6 +917,17 @@ bool test_runner::generate_test_cmake(const test_target &target) {
     return false;
   }
 
+  // Write to a scratch file first; it replaces CMakeLists.txt only when the
+  // content changed so an up-to-date test build is not reconfigured just
+  // because the file's timestamp moved
+  fs::path cmake_file   = gen_dir / "CMakeLists.txt";
+  fs::path pending_file = gen_dir / "CMakeLists.txt.new";
+  std::ofstream out(pending_file, std::ios::binary);
+  if (!out) {
+    m_error = "FAILED to create " + cmake_file.string();
+    return false;
+  }
+
   // Get framework config
   test_config::FrameworkConfig fw_config;
   auto it = m_test_config.framework_configs.find(target.framework);
@@ -917,6 +967,12 @@ bool test_runner::generate_test_cmake(const test_target &target) {
   // Create executable
   out << "add_executable(${PROJECT_NAME} ${TEST_SOURCES})\n\n";
 
+  // Share the project's precompiled header unless tests opt out
+  pch_config pch = read_pch_config(m_project_config);
+  if (pch_applies_to(pch, "tests")) {
+    out << generate_pch_cmake(pch, "${PROJECT_NAME}", to_cmake_path(m_project_dir));
+  }
+
   // Include directories
   out << "target_include_directories(${PROJECT_NAME} PRIVATE\n"
       << "  \"" << to_cmake_path(m_project_dir / "include") << "\"\n"
@@ -956,24 +1012,43 @@ bool test_runner::generate_test_cmake(const test_target &target) {
   }
   out << ")\n\n";
 
-  // Defines
-  if (!target.defines.empty()) {
+  // Defines: test.defines, then the target's own
+  if (!m_test_config.defines.empty() || !target.defines.empty()) {
     out << "target_compile_definitions(${PROJECT_NAME} PRIVATE\n";
+    for (const auto &def : m_test_config.defines) {
+      out << "  " << def << "\n";
+    }
     for (const auto &def : target.defines) {
       out << "  " << def << "\n";
     }
     out << ")\n\n";
   }
+  out << generate_platform_settings_cmake(
+      target.platform_defines, target.platform_flags, "${PROJECT_NAME}");
 
-  // CTest integration — for every framework. GTest/Catch2 use their CMake
-  // helpers for per-test discovery; for the others we register the binary as
-  // a single test so plain `ctest` still works.
+  // CTest integration — for every framework. GTest, Catch2 and doctest use
+  // their CMake helpers for per-test discovery; for the others we register the
+  // binary as a single test so plain `ctest` still works.
   if (target.framework == test_framework::GTest) {
     out << "include(GoogleTest)\n"
         << "gtest_discover_tests(${PROJECT_NAME})\n";
   } else if (target.framework == test_framework::Catch2) {
     out << "include(Catch)\n"
         << "catch_discover_tests(${PROJECT_NAME})\n";
+  } else if (target.framework == test_framework::Doctest) {
+    // doctest.cmake ships in scripts/cmake of the source tree and next to
+    // doctestConfig.cmake when installed
+    out << "if(DEFINED doctest_SOURCE_DIR)\n"
+        << "  list(APPEND CMAKE_MODULE_PATH \"${doctest_SOURCE_DIR}/scripts/cmake\")\n"
+        << "elseif(DEFINED doctest_DIR)\n"
+        << "  list(APPEND CMAKE_MODULE_PATH \"${doctest_DIR}\")\n"
+        << "endif()\n"
+        << "include(doctest OPTIONAL RESULT_VARIABLE CFORGE_DOCTEST_MODULE)\n"
+        << "if(CFORGE_DOCTEST_MODULE)\n"
+        << "  doctest_discover_tests(${PROJECT_NAME})\n"
+        << "else()\n"
+        << "  add_test(NAME " << target.name << " COMMAND ${PROJECT_NAME})\n"
+        << "endif()\n";
   } else {
     out << "add_test(NAME " << target.name << " COMMAND ${PROJECT_NAME})\n";
     if (target.timeout_seconds > 0) {
@@ -982,6 +1057,25 @@ bool test_runner::generate_test_cmake(const test_target &target) {
     }
   }
 
+  out.close();
+
+  auto read_file = [](const fs::path &path) {
+    std::ifstream file(path, std::ios::binary);
+    std::stringstream buffer;
+    buffer << file.rdbuf();
+    return buffer.str();
+  };
+
+  std::error_code ec;
+  if (fs::exists(cmake_file) && read_file(cmake_file) == read_file(pending_file)) {
+    fs::remove(pending_file, ec);
+    return true;
+  }
+  fs::rename(pending_file, cmake_file, ec);
+  if (ec) {
+    m_error = "FAILED to create " + cmake_file.string() + ": " + ec.message();
+    return false;
+  }
   return true;
 }
 
@@ -990,9 +1084,8 @@ bool test_runner::should_auto_link_project() const {
     return false;
   }
 
-  std::string type = m_project_config.get_string("project.binary_type", "executable");
-  return (type == "library" || type == "static_library" || type == "shared_library"
-          || type == "static" || type == "shared");
+  std::string type = get_binary_type(m_project_config);
+  return type == "static_lib" || type == "shared_lib";
 }
 
 std::string test_runner::get_project_link_target() const {
@@ -1009,7 +1102,14 @@ std::string test_runner::get_project_link_target() const {
 
 bool test_runner::configure_cmake(const test_target &target, const std::string &build_config) {
   fs::path gen_dir   = get_test_gen_dir(target.name);
-  fs::path build_dir = get_test_build_dir(target.name);
+  fs::path build_dir = get_test_build_dir(target.name, build_config);
+
+  // An existing cache for this configuration is reused; CMake re-runs
+  // configure on its own when the generated CMakeLists.txt changes
+  if (is_build_dir_configured_for(build_dir, build_config)) {
+    logger::print_verbose("Reusing configured test build directory: " + build_dir.string());
+    return true;
+  }
 
   // Ensure build directory exists
   fs::create_directories(build_dir);
@@ -1018,7 +1118,13 @@ bool test_runner::configure_cmake(const test_target &target, const std::string &
                                    to_cmake_path(gen_dir),
                                    "-B",
                                    to_cmake_path(build_dir),
-                                   "-DCMAKE_BUILD_TYPE=" + build_config};
+                                   "-DCMAKE_BUILD_TYPE=" + canonical_build_config(build_config)};
+
+  // Frameworks installed through vcpkg are found with the project's toolchain
+  for (const auto &arg :
+       get_vcpkg_cmake_args(m_project_config, m_project_dir, "", "", build_config)) {
+    args.push_back(arg);
+  }
 
 #ifdef _WIN32
   // Use same generator as main project if specified
@@ -1026,6 +1132,10 @@ bool test_runner::configure_cmake(const test_target &target, const std::string &
   if (!generator.empty()) {
     args.push_back("-G");
     args.push_back(generator);
+    if (uses_msvc_environment(generator, m_project_config.get_string("cmake.cxx_compiler", ""))) {
+      setup_msvc_environment(
+          normalize_msvc_arch(m_project_config.get_string("cmake.platform", "")), false);
+    }
   }
 #endif
 
@@ -1048,7 +1158,7 @@ bool test_runner::configure_cmake(const test_target &target, const std::string &
 bool test_runner::build_target(const test_target &target,
                                const std::string &build_config,
                                bool verbose) {
-  fs::path build_dir = get_test_build_dir(target.name);
+  fs::path build_dir = get_test_build_dir(target.name, build_config);
 
   // Use run_cmake_build so we get the same UX as `cforge build`:
   //   - non-verbose: a progress bar, no raw MSBuild noise
@@ -1072,6 +1182,18 @@ bool test_runner::build_target(const test_target &target,
   return true;
 }
 
+cforge_size_t test_runner::select_targets(const std::string &label, const std::string &exclude) {
+  cforge_size_t selected = 0;
+  for (auto &target : m_test_config.targets) {
+    if (target.enabled && !test_target_selected(target, label, exclude)) {
+      logger::print_verbose("Skipping test target " + target.name);
+      target.enabled = false;
+    }
+    selected += target.enabled ? 1 : 0;
+  }
+  return selected;
+}
+
 bool test_runner::build_tests(const std::string &config, bool verbose) {
   // Discover targets if not already done
   if (m_test_config.targets.empty()) {
@@ -1115,7 +1237,7 @@ bool test_runner::build_tests(const std::string &config, bool verbose) {
 
 fs::path test_runner::find_test_executable(const test_target &target,
                                            const std::string &build_config) {
-  fs::path build_dir = get_test_build_dir(target.name);
+  fs::path build_dir = get_test_build_dir(target.name, build_config);
 
   // Try the well-known paths first (fast path), then fall back to a bounded
   // recursive walk so we cope with whatever directory layout the configured
@@ -1224,6 +1346,31 @@ std::vector<test_result> test_runner::run_target(const test_target &target,
   return results;
 }
 
+std::vector<test_result> test_runner::run_target_repeated(const test_target &target,
+                                                          const test_run_options &options) {
+  auto has_status = [](const std::vector<test_result> &results, test_status status) {
+    return std::any_of(results.begin(), results.end(), [status](const test_result &r) {
+      return r.status == status;
+    });
+  };
+
+  std::vector<test_result> results = run_target(target, options);
+  for (cforge_int_t run = 1; run < options.repeat_count; ++run) {
+    bool failed    = has_status(results, test_status::FAILED);
+    bool timed_out = has_status(results, test_status::TIMEOUT);
+    bool again     = (options.repeat_mode == "until-pass" && (failed || timed_out))
+                  || (options.repeat_mode == "until-fail" && !failed && !timed_out)
+                  || (options.repeat_mode == "after-timeout" && timed_out);
+    if (!again) {
+      break;
+    }
+    logger::print_verbose("Repeating " + target.name + " (run " + std::to_string(run + 1) + " of "
+                          + std::to_string(options.repeat_count) + ")");
+    results = run_target(target, options);
+  }
+  return results;
+}
+
 test_summary test_runner::run_tests(const test_run_options &options) {
   test_summary summary;
   m_results.clear();
@@ -1239,13 +1386,47 @@ test_summary test_runner::run_tests(const test_run_options &options) {
     }
   }
 
-  // Run each target
+  std::vector<const test_target *> targets;
   for (const auto &target : m_test_config.targets) {
-    if (!target.enabled) {
-      continue;
+    if (target.enabled) {
+      targets.push_back(&target);
+      get_adapter(target.framework);  // Create adapters before workers share them
+    }
+  }
+
+  // Run targets in parallel; native output is printed as each target
+  // finishes, so keep it serial to avoid interleaving
+  cforge_int_t jobs = options.jobs > 0 ? options.jobs : m_test_config.jobs;
+  if (jobs <= 0) {
+    jobs = static_cast<cforge_int_t>(std::max(1u, std::thread::hardware_concurrency()));
+  }
+  if (options.native_output) {
+    jobs = 1;
+  }
+
+  std::vector<std::vector<test_result>> target_results(targets.size());
+  std::atomic<cforge_size_t> next_target{0};
+  auto worker = [&]() {
+    for (cforge_size_t i = next_target++; i < targets.size(); i = next_target++) {
+      target_results[i] = run_target_repeated(*targets[i], options);
+    }
+  };
+
+  cforge_size_t worker_count = std::min(targets.size(), static_cast<cforge_size_t>(jobs));
+  if (worker_count <= 1) {
+    worker();
+  } else {
+    std::vector<std::thread> workers;
+    for (cforge_size_t w = 0; w < worker_count; ++w) {
+      workers.emplace_back(worker);
     }
+    for (auto &t : workers) {
+      t.join();
+    }
+  }
 
-    auto results = run_target(target, options);
+  // Results are kept in target order regardless of completion order
+  for (const auto &results : target_results) {
     m_results.insert(m_results.end(), results.begin(), results.end());
   }
 
@@ -7,6 +7,7 @@
 
 #include "cforge/log.hpp"
 
+#include "core/command.h"
 #include "core/platform.hpp"
 #include "core/process_utils.hpp"
 #include "core/utils/terminal_prompt.hpp"
@@ -54,7 +55,7 @@ struct package_spec {
 };
 
 // clang-format off
-constexpr std::array<package_spec, 34> kRegistry = {{
+constexpr std::array<package_spec, 40> kRegistry = {{
   // ---- Windows ---------------------------------------------------------
   {"git",          "winget", "install --silent --accept-package-agreements --accept-source-agreements Git.Git", false,
    "C:\\Program Files\\Git\\bin\\git.exe;C:\\Program Files (x86)\\Git\\bin\\git.exe"},
@@ -80,6 +81,7 @@ constexpr std::array<package_spec, 34> kRegistry = {{
   {"cmake",        "choco",  "install -y cmake", false,
    "C:\\Program Files\\CMake\\bin\\cmake.exe"},
   {"ninja",        "choco",  "install -y ninja", false, nullptr},
+  {"pkg-config",   "choco",  "install -y pkgconfiglite", false, nullptr},
 
   {"clang-tidy",   "scoop",  "install llvm", false,
    "${USERPROFILE}\\scoop\\shims\\clang-tidy.exe"},
@@ -91,6 +93,8 @@ constexpr std::array<package_spec, 34> kRegistry = {{
    "${USERPROFILE}\\scoop\\shims\\cmake.exe"},
   {"ninja",        "scoop",  "install ninja", false,
    "${USERPROFILE}\\scoop\\shims\\ninja.exe"},
+  {"pkg-config",   "scoop",  "install pkg-config", false,
+   "${USERPROFILE}\\scoop\\shims\\pkg-config.exe"},
 
   // ---- macOS -----------------------------------------------------------
   {"git",          "brew",   "install git", false,
@@ -107,6 +111,8 @@ constexpr std::array<package_spec, 34> kRegistry = {{
    "/opt/homebrew/bin/ninja;/usr/local/bin/ninja"},
   {"ccache",       "brew",   "install ccache", false,
    "/opt/homebrew/bin/ccache;/usr/local/bin/ccache"},
+  {"pkg-config",   "brew",   "install pkg-config", false,
+   "/opt/homebrew/bin/pkg-config;/usr/local/bin/pkg-config"},
 
   // ---- Linux (Debian/Ubuntu) -------------------------------------------
   {"git",          "apt-get", "install -y git", true, nullptr},
@@ -116,10 +122,13 @@ constexpr std::array<package_spec, 34> kRegistry = {{
   {"cmake",        "apt-get", "install -y cmake", true, nullptr},
   {"ninja",        "apt-get", "install -y ninja-build", true, nullptr},
   {"ccache",       "apt-get", "install -y ccache", true, nullptr},
+  {"pkg-config",   "apt-get", "install -y pkg-config", true, nullptr},
 
   // ---- Linux (Fedora/RHEL) ---------------------------------------------
   {"clang-tidy",   "dnf",    "install -y clang-tools-extra", true, nullptr},
   {"clang-format", "dnf",    "install -y clang-tools-extra", true, nullptr},
+  {"ninja",        "dnf",    "install -y ninja-build", true, nullptr},
+  {"pkg-config",   "dnf",    "install -y pkgconf-pkg-config", true, nullptr},
 }};
 // clang-format on
 
@@ -222,6 +231,11 @@ std::string probe_post_install_path(const package_spec &spec) {
 }  // namespace
 
 install_outcome offer_install_tool(const std::string &tool) {
+  if (cforge_is_offline()) {
+    logger::print_status("Offline mode: not offering to install " + tool);
+    return {install_result::offline, ""};
+  }
+
   // 1. Find the first package manager that's both available on this system
   //    and has a recipe for the requested tool.
   const package_spec *spec = nullptr;
@@ -7,30 +7,78 @@
 
 #include "cforge/log.hpp"
 
+#include "core/archive_format.hpp"
+#include "core/build_config.hpp"
+#include "core/build_variants.hpp"
+#include "core/cforge_tokens.hpp"
+#include "core/cmake_file_api.hpp"
+#include "core/command.h"
 #include "core/config_resolver.hpp"
 #include "core/constants.h"
 #include "core/dependency_hash.hpp"
+#include "core/fetchcontent_deps.hpp"
+#include "core/lockfile.hpp"
+#include "core/package_export.hpp"
+#include "core/package_formats.hpp"
+#include "core/pch.hpp"
+#include "core/pkg_config.hpp"
 #include "core/portable_flags.hpp"
 #include "core/process_utils.hpp"
 #include "core/registry.hpp"
+#include "core/scoped_environment.hpp"
+#include "core/source_globs.hpp"
 #include "core/toml_reader.hpp"
 #include "core/types.h"
+#include "core/vcpkg_manifest.hpp"
+#include "core/workspace_defaults.hpp"
+#include "core/workspace_members.hpp"
 
 // Forward declare from build_utils.hpp to avoid platform namespace conflict
 namespace cforge {
 std::string get_cmake_generator();
+std::vector<std::string> get_cmake_cache_args(const toml_reader &project_config);
+std::filesystem::path get_build_base_dir(const std::filesystem::path &project_dir,
+                                         const toml_reader *project_config);
+bool is_build_info_enabled(const toml_reader &project_config);
+bool write_build_info_header(const std::filesystem::path &project_dir,
+                             const std::filesystem::path &build_dir,
+                             const toml_reader &project_config);
+std::map<std::string, std::string> get_project_environment(const toml_reader &project_config,
+                                                           const std::string &config);
+std::map<std::string, std::string> get_cforge_tokens(const toml_reader &project_config,
+                                                     const std::string &config);
+std::filesystem::path find_output_executable(const toml_reader &project_config,
+                                             const std::filesystem::path &project_dir,
+                                             const std::string &config,
+                                             const std::string &name);
+std::string normalize_binary_type(const std::string &type, bool *deprecated_alias);
+std::string get_binary_type(const toml_reader &project_config);
+bool validate_binary_type(const toml_reader &project_config,
+                          const std::string &config_file,
+                          const std::string &key);
+bool validate_manifest_keys(const toml_reader &project_config, const std::string &config_file);
+std::vector<fetchcontent_dependency> get_fetchcontent_dependencies(
+    const toml_reader &project_config);
 }  // namespace cforge
 
 #include <algorithm>
 #include <fstream>
 #include <functional>
 #include <iostream>
+#include <map>
 #include <queue>
 #include <set>
 #include <sstream>
 
 namespace cforge {
 
+// Convert a path from cforge.toml to CMake style; inside a quoted CMake
+// argument a backslash starts an escape sequence
+static std::string to_cmake_path(std::string path) {
+  std::replace(path.begin(), path.end(), '\\', '/');
+  return path;
+}
+
 // Helper to get generator for a project, respecting cmake.generator config
 static std::string get_project_generator(const toml_reader &project_config) {
   // Check if project has a specific generator configured
@@ -95,6 +143,9 @@ static bool run_cmake_configure(const std::vector<std::string> &cmake_args,
   cforge_int_t timeout = 120;  // 2 minutes for other platforms
 #endif
 
+  // Ask CMake to describe its targets so `cforge run` can find artifacts
+  write_cmake_file_api_query(build_dir);
+
   // Run the CMake command with appropriate timeout
   bool result = execute_tool("cmake", cmake_args, "", "CMake Configure", verbose, timeout);
 
@@ -195,12 +246,13 @@ bool workspace::load(const std::filesystem::path &workspace_path) {
     workspace_name_ = workspace_path.filename().string();
   }
 
+  // Get the default startup project before loading projects, which mark it
+  startup_project_ = config_->get_string("workspace.default_startup_project",
+                                         config_->get_string("workspace.main_project", ""));
+
   // Load projects
   load_projects();
 
-  // Get the default startup project
-  startup_project_ = config_->get_string("workspace.main_project", "");
-
   return true;
 }
 
@@ -220,6 +272,15 @@ std::vector<workspace_project> workspace::get_projects() const {
   return projects_;
 }
 
+std::string workspace::get_project_config(const std::string &project_name,
+                                          const std::string &fallback) const {
+  if (!config_) {
+    return fallback;
+  }
+  std::string config = config_->get_string("workspace.project_configs." + project_name, "");
+  return config.empty() ? fallback : config;
+}
+
 workspace_project workspace::get_startup_project() const {
   // Find the startup project
   for (const auto &project : projects_) {
@@ -248,6 +309,35 @@ workspace_project workspace::get_startup_project() const {
   return workspace_project{};
 }
 
+std::vector<std::string> workspace::get_startup_candidates() const {
+  if (!startup_project_.empty()) {
+    if (get_project_by_name(startup_project_)) {
+      return {startup_project_};
+    }
+    logger::print_warning("Startup project '" + startup_project_ + "' is not a project in "
+                          + "workspace '" + workspace_name_ + "'");
+  }
+
+  std::vector<std::string> candidates;
+  if (config_) {
+    for (const auto &name : config_->get_string_array("workspace.startup_projects")) {
+      if (get_project_by_name(name)) {
+        candidates.push_back(name);
+      } else {
+        logger::print_warning("Ignoring unknown startup project '" + name + "'");
+      }
+    }
+  }
+  if (candidates.empty()) {
+    for (const auto &project : projects_) {
+      if (project.is_startup_project) {
+        candidates.push_back(project.name);
+      }
+    }
+  }
+  return candidates;
+}
+
 bool workspace::set_startup_project(const std::string &project_name) {
   // Find the project by name
   bool found = false;
@@ -286,7 +376,7 @@ bool workspace::set_startup_project(const std::string &project_name) {
  * @brief Find the executable file for a project
  *
  * @param project_path Path to the project directory
- * @param build_dir Build directory name
+ * @param build_dir Build directory, absolute or relative to the project
  * @param config Build configuration
  * @param project_name Project name
  * @return std::filesystem::path Path to executable, empty if not found
@@ -472,19 +562,20 @@ static std::filesystem::path find_project_executable(const std::filesystem::path
       const auto &dep = *it;
 
       // Add include directory
-      options.push_back("-DCMAKE_INCLUDE_PATH=" + (dep.path / "include").string());
+      options.push_back("-DCMAKE_INCLUDE_PATH=" + (dep.path / "include").generic_string());
 
       // Add library directory
-      options.push_back("-DCMAKE_LIBRARY_PATH=" + (dep.path / "lib").string());
+      options.push_back("-DCMAKE_LIBRARY_PATH=" + (dep.path / "lib").generic_string());
 
       // Add as a dependency
       options.push_back("-DCFORGE_DEP_" + dep.name + "=ON");
 
       // Add dependency's include path
-      options.push_back("-DCFORGE_" + dep.name + "_INCLUDE=" + (dep.path / "include").string());
+      options.push_back("-DCFORGE_" + dep.name
+                        + "_INCLUDE=" + (dep.path / "include").generic_string());
 
       // Add dependency's library path
-      options.push_back("-DCFORGE_" + dep.name + "_LIB=" + (dep.path / "lib").string());
+      options.push_back("-DCFORGE_" + dep.name + "_LIB=" + (dep.path / "lib").generic_string());
     }
   }
 
@@ -541,7 +632,30 @@ std::pair<bool, std::filesystem::path> is_in_workspace(const std::filesystem::pa
   return {false, {}};
 }
 
-void configure_git_dependencies_in_cmake(const toml_reader &project_config,
+std::filesystem::path find_member_workspace(const std::filesystem::path &project_dir) {
+  std::error_code ec;
+  std::filesystem::path dir          = std::filesystem::absolute(project_dir, ec);
+  auto [is_workspace, workspace_dir] = is_in_workspace(dir);
+  if (!is_workspace) {
+    return {};
+  }
+  std::filesystem::path workspace_file = get_workspace_config_path(workspace_dir);
+  if (workspace_file.empty()) {
+    return {};
+  }
+  try {
+    toml::table workspace_config = toml::parse_file(workspace_file.string());
+    if (is_workspace_member_dir(workspace_dir, workspace_member_paths(workspace_config), dir)) {
+      return workspace_dir;
+    }
+  } catch (const toml::parse_error &) {
+    // Reported when the workspace itself is loaded
+  }
+  return {};
+}
+
+void configure_git_dependencies_in_cmake(const std::filesystem::path &project_dir,
+                                         const toml_reader &project_config,
                                          const std::string &deps_dir,
                                          std::ofstream &cmakelists) {
   // Check if we have Git dependencies
@@ -561,6 +675,10 @@ void configure_git_dependencies_in_cmake(const toml_reader &project_config,
   cmakelists << "# Configure Git to prefer HTTPS but allow other protocols\n";
   cmakelists << "set(FETCHCONTENT_GIT_PROTOCOL \"https\")\n\n";
 
+  // Commits resolved by a previous build take precedence over moving refs
+  lockfile lock;
+  bool has_lock = lock.load(project_dir);
+
   // Loop through all git dependencies
   auto git_deps = project_config.get_table_keys("dependencies.git");
   for (const auto &dep : git_deps) {
@@ -591,9 +709,14 @@ void configure_git_dependencies_in_cmake(const toml_reader &project_config,
     cmakelists << "message(STATUS \"Setting up " << dep << " dependency from " << url << "\")\n";
 
     // FetchContent declaration
+    std::string ref        = !tag.empty() ? tag : !branch.empty() ? branch : commit;
+    std::string locked_sha = has_lock ? locked_git_commit(lock, dep, url, ref) : "";
+
     cmakelists << "FetchContent_Declare(" << dep << "\n";
     cmakelists << "    GIT_REPOSITORY " << url << "\n";
-    if (!tag.empty()) {
+    if (!locked_sha.empty()) {
+      cmakelists << "    GIT_TAG " << locked_sha << "  # " << ref << ", locked in cforge.lock\n";
+    } else if (!tag.empty()) {
       cmakelists << "    GIT_TAG " << tag << "\n";
     } else if (!branch.empty()) {
       cmakelists << "    GIT_TAG " << branch << "\n";
@@ -602,13 +725,24 @@ void configure_git_dependencies_in_cmake(const toml_reader &project_config,
     }
 
     // Use custom directory if specified
-    cmakelists << "    SOURCE_DIR ${CMAKE_CURRENT_SOURCE_DIR}/" << dep_dir << "/" << dep << "\n";
+    cmakelists << "    SOURCE_DIR \"${CMAKE_CURRENT_SOURCE_DIR}/" << dep_dir << "/" << dep
+               << "\"\n";
 
-    // Add shallow clone option if configured
+    // Add shallow clone option if configured; a pinned commit needs full history
     bool shallow = project_config.get_bool("dependencies.git." + dep + ".shallow", false);
+    if (shallow && tag.empty() && branch.empty() && !commit.empty()) {
+      logger::print_warning("'" + dep + "' pins a commit; ignoring shallow = true");
+      shallow = false;
+    }
+    if (!locked_sha.empty()) {
+      shallow = false;
+    }
     if (shallow) {
       cmakelists << "    GIT_SHALLOW 1\n";
     }
+    if (project_config.get_bool("dependencies.git." + dep + ".submodules", false)) {
+      cmakelists << "    GIT_SUBMODULES_RECURSE ON\n";
+    }
 
     cmakelists << ")\n";
 
@@ -628,8 +762,8 @@ void configure_git_dependencies_in_cmake(const toml_reader &project_config,
       }
 
       for (const auto &inc_dir : include_dirs) {
-        cmakelists << "include_directories(${CMAKE_CURRENT_SOURCE_DIR}/" << dep_dir << "/" << dep
-                   << "/" << inc_dir << ")\n";
+        cmakelists << "include_directories(\"${CMAKE_CURRENT_SOURCE_DIR}/" << dep_dir << "/" << dep
+                   << "/" << inc_dir << "\")\n";
       }
       cmakelists << "\n";
     }
@@ -673,24 +807,133 @@ void configure_git_dependencies_in_cmake(const toml_reader &project_config,
         cmakelists << "endif()\n\n";
       }
     }
+
+    // Fail the configure if the checkout isn't the pinned commit
+    std::string expected_sha = string_to_lower(
+        project_config.get_string("dependencies.git." + dep + ".expected_sha", ""));
+    if (!expected_sha.empty()) {
+      cmakelists << "execute_process(COMMAND git rev-parse HEAD\n";
+      cmakelists << "    WORKING_DIRECTORY \"${CMAKE_CURRENT_SOURCE_DIR}/" << dep_dir << "/" << dep
+                 << "\"\n";
+      cmakelists << "    OUTPUT_VARIABLE CFORGE_" << dep << "_HEAD\n";
+      cmakelists << "    OUTPUT_STRIP_TRAILING_WHITESPACE)\n";
+      cmakelists << "string(FIND \"${CFORGE_" << dep << "_HEAD}\" \"" << expected_sha
+                 << "\" CFORGE_" << dep << "_SHA_POS)\n";
+      cmakelists << "if(NOT CFORGE_" << dep << "_SHA_POS EQUAL 0)\n";
+      cmakelists << "    message(FATAL_ERROR \"git dependency '" << dep << "' is not at the "
+                 << "expected commit: expected " << expected_sha << ", found ${CFORGE_" << dep
+                 << "_HEAD}\")\n";
+      cmakelists << "endif()\n\n";
+    }
   }
 }
 
 /**
- * @brief Helper to get list of index dependencies (from cforge-index)
- */
-/**
- * @brief Index dependency info for CMake generation
+ * @brief Add archive dependencies ([dependencies.archive.<name>]) to CMakeLists.txt
+ *
+ * Archives are downloaded with FetchContent. With `sha256`, CMake hashes the
+ * download before extracting it and fails the configure on a mismatch; an
+ * archive that is already downloaded and matches is not fetched again.
  */
-struct index_dep_info {
-  std::string name;
-  std::string version;
-};
+static void configure_archive_dependencies_in_cmake(const toml_reader &project_config,
+                                                    std::ofstream &cmakelists) {
+  auto archive_deps = project_config.get_table_keys("dependencies.archive");
+  if (archive_deps.empty()) {
+    return;
+  }
+
+  cmakelists << "# Archive dependencies\n";
+  cmakelists << "include(FetchContent)\n";
+  for (const auto &dep : archive_deps) {
+    std::string dep_key = "dependencies.archive." + dep;
+    std::string url     = project_config.get_string(dep_key + ".url", "");
+    if (url.empty()) {
+      logger::print_warning("Archive dependency '" + dep + "' is missing a URL, skipping");
+      continue;
+    }
+    std::string sha256 = string_to_lower(project_config.get_string(dep_key + ".sha256", ""));
+
+    cmakelists << "# " << dep << " dependency\n";
+    if (!sha256.empty()
+        && (sha256.size() != 64
+            || sha256.find_first_not_of("0123456789abcdef") != std::string::npos)) {
+      cmakelists << "message(FATAL_ERROR \"archive dependency '" << dep
+                 << "': sha256 must be 64 hex characters\")\n\n";
+      continue;
+    }
+    if (sha256.empty()) {
+      logger::print_verbose("Archive dependency '" + dep + "' has no sha256; not verified");
+    }
+
+    // CMake chooses the extractor from the file name, so a URL that doesn't
+    // end in the archive extension needs an explicit download name, or is
+    // downloaded first and recognized by its content
+    std::string format_key = project_config.get_string(dep_key + ".format", "");
+    std::string format     = format_key.empty() ? detect_archive_format(url)
+                                                : normalize_archive_format(format_key);
+    if (format.empty() && !format_key.empty()) {
+      std::string reason = "unsupported format '" + format_key + "'";
+      logger::print_warning("Archive dependency '" + dep + "': " + reason);
+      logger::print_hint("supported formats are zip, 7z, tar, tar.gz, tar.bz2 and tar.xz");
+      cmakelists << "message(FATAL_ERROR \"archive dependency '" << dep << "': " << reason
+                 << "\")\n\n";
+      continue;
+    }
+    bool sniff_format        = format.empty();
+    bool has_query           = url.find_first_of("?#") != std::string::npos;
+    bool needs_download_name = !sniff_format && (!format_key.empty() || has_query);
+
+    if (sniff_format) {
+      cmakelists << generate_archive_sniff_cmake(dep, url, sha256);
+    }
+    cmakelists << "FetchContent_Declare(" << dep << "\n";
+    if (sniff_format) {
+      cmakelists << "    URL \"${CFORGE_ARCHIVE_" << dep << "}\"\n";
+    } else {
+      cmakelists << "    URL \"" << url << "\"\n";
+    }
+    if (needs_download_name) {
+      cmakelists << "    DOWNLOAD_NAME \"" << dep << "." << format << "\"\n";
+    }
+    if (!sha256.empty()) {
+      cmakelists << "    URL_HASH SHA256=" << sha256 << "\n";
+    }
+    cmakelists << ")\n";
+    cmakelists << "FetchContent_MakeAvailable(" << dep << ")\n";
+
+    std::vector<std::string> include_dirs = {"include", "."};
+    if (project_config.has_key(dep_key + ".include_dirs")) {
+      include_dirs = project_config.get_string_array(dep_key + ".include_dirs");
+    }
+    for (const auto &inc_dir : include_dirs) {
+      cmakelists << "include_directories(\"${" << string_to_lower(dep) << "_SOURCE_DIR}/"
+                 << inc_dir << "\")\n";
+    }
+    cmakelists << "\n";
+  }
+}
 
 /**
- * @brief Get list of index dependencies with their versions
+ * @brief Add FetchContent dependencies ([dependencies.fetchcontent.<name>]) to CMakeLists.txt
+ *
+ * Each dependency's options are set as cache variables between its
+ * FetchContent_Declare() and FetchContent_MakeAvailable().
  */
-static std::vector<index_dep_info> get_index_dependencies_with_versions(
+static void configure_fetchcontent_dependencies_in_cmake(const toml_reader &project_config,
+                                                         std::ofstream &cmakelists) {
+  auto deps = get_fetchcontent_dependencies(project_config);
+  if (deps.empty()) {
+    return;
+  }
+
+  cmakelists << "# FetchContent dependencies\n";
+  cmakelists << "include(FetchContent)\n";
+  for (const auto &dep : deps) {
+    cmakelists << generate_fetchcontent_dependency_cmake(dep);
+  }
+}
+
+std::vector<index_dep_info> get_index_dependencies_with_versions(
     const toml_reader &project_config) {
   std::vector<index_dep_info> index_deps;
 
@@ -707,7 +950,8 @@ static std::vector<index_dep_info> get_index_dependencies_with_versions(
 
     // Skip known special sections
     if (dep == "directory" || dep == "git" || dep == "vcpkg" || dep == "subdirectory"
-        || dep == "system" || dep == "project" || dep == "fetch_content") {
+        || dep == "system" || dep == "project" || dep == "fetch_content" || dep == "archive"
+        || dep == "fetchcontent") {
       logger::print_verbose("    Skipping (special key)");
       continue;
     }
@@ -790,7 +1034,7 @@ void configure_index_dependencies_phase1(const std::filesystem::path &project_di
   registry reg;  // Use default cache directory
 
   // Ensure registry is up to date
-  if (reg.needs_update()) {
+  if (!cforge_is_offline() && reg.needs_update()) {
     logger::print_action("Updating", "package index");
     if (!reg.update()) {
       logger::print_warning("Failed to update package index, using cached version");
@@ -924,6 +1168,25 @@ void configure_index_dependencies_phase2(const std::filesystem::path &project_di
   }
 }
 
+std::string fetch_content_git_tag(const package_info &pkg, const std::string &resolved_version) {
+  for (const auto &ver : pkg.versions) {
+    if (ver.version == resolved_version) {
+      return ver.tag;
+    }
+  }
+
+  // No tag recorded for the version; build one from the package's pattern
+  std::string git_tag = resolved_version;
+  if (!pkg.tags.pattern.empty()) {
+    git_tag           = pkg.tags.pattern;
+    cforge_size_t pos = git_tag.find("{version}");
+    if (pos != std::string::npos) {
+      git_tag.replace(pos, 9, resolved_version);
+    }
+  }
+  return git_tag;
+}
+
 /**
  * @brief Configure index dependencies using FetchContent - Phase 1
  * This generates FetchContent_Declare calls BEFORE the target is created
@@ -946,7 +1209,7 @@ void configure_index_dependencies_fetchcontent_phase1(const std::filesystem::pat
   registry reg;  // Use default cache directory
 
   // Ensure registry is up to date
-  if (reg.needs_update()) {
+  if (!cforge_is_offline() && reg.needs_update()) {
     logger::print_action("Updating", "package index");
     if (!reg.update()) {
       logger::print_warning("Failed to update package index, using cached version");
@@ -989,23 +1252,7 @@ void configure_index_dependencies_fetchcontent_phase1(const std::filesystem::pat
       }
     }
 
-    // Resolve version to a git tag
-    std::string git_tag = resolved_version;
-    for (const auto &ver : pkg_info->versions) {
-      if (ver.version == resolved_version) {
-        git_tag = ver.tag;
-        break;
-      }
-    }
-
-    // If using tag_pattern, construct the tag
-    if (git_tag == resolved_version && !pkg_info->tags.pattern.empty()) {
-      git_tag           = pkg_info->tags.pattern;
-      cforge_size_t pos = git_tag.find("{version}");
-      if (pos != std::string::npos) {
-        git_tag.replace(pos, 9, resolved_version);
-      }
-    }
+    std::string git_tag = fetch_content_git_tag(*pkg_info, resolved_version);
 
     cmakelists << "# " << dep.name << " v" << resolved_version << "\n";
 
@@ -1261,6 +1508,86 @@ void configure_index_dependencies_fetchcontent_phase2(const std::filesystem::pat
   }
 }
 
+/**
+ * @brief Conventional imported target(s) for a system find_package dependency
+ *
+ * With components, each component is linked as `<pkg>::<component>`
+ * (Boost::filesystem, Qt6::Core, OpenGL::GLU). Without, a few well-known
+ * packages whose target isn't `<pkg>::<pkg>` are special-cased.
+ */
+static std::string default_system_target(const std::string &package_name,
+                                         const std::vector<std::string> &components) {
+  if (!components.empty()) {
+    std::string targets;
+    for (const auto &comp : components) {
+      targets += (targets.empty() ? "" : " ") + package_name + "::" + comp;
+    }
+    return targets;
+  }
+
+  static const std::map<std::string, std::string> known_targets = {
+      {"OpenGL",  "OpenGL::GL"                  },
+      {"OpenSSL", "OpenSSL::SSL OpenSSL::Crypto"},
+      {"CURL",    "CURL::libcurl"               },
+      {"GTest",   "GTest::gtest"                },
+  };
+  auto it = known_targets.find(package_name);
+  return it != known_targets.end() ? it->second : package_name + "::" + package_name;
+}
+
+/**
+ * @brief Emit find_package() for a system dependency and link its target
+ *
+ * Optional dependencies are linked only if the package was found.
+ */
+static void write_system_find_package(std::ofstream &cmakelists,
+                                      const std::string &package_name,
+                                      const std::vector<std::string> &components,
+                                      std::string target,
+                                      bool required) {
+  if (target.empty()) {
+    target = default_system_target(package_name, components);
+  }
+
+  if (package_name == "Threads") {
+    cmakelists << "set(THREADS_PREFER_PTHREAD_FLAG ON)\n";
+  }
+  cmakelists << "find_package(" << package_name;
+  if (!components.empty()) {
+    cmakelists << " COMPONENTS";
+    for (const auto &comp : components) {
+      cmakelists << " " << comp;
+    }
+  }
+  if (required) {
+    cmakelists << " REQUIRED";
+  }
+  cmakelists << ")\n";
+
+  if (required) {
+    cmakelists << "target_link_libraries(${PROJECT_NAME} PUBLIC " << target << ")\n";
+  } else {
+    cmakelists << "if(" << package_name << "_FOUND)\n";
+    cmakelists << "    target_link_libraries(${PROJECT_NAME} PUBLIC " << target << ")\n";
+    cmakelists << "endif()\n";
+  }
+}
+
+/**
+ * @brief Check whether the project links any [dependencies]
+ *
+ * Exported targets may only link targets that are exported too, so projects
+ * with dependencies don't export a package unless they opt in.
+ */
+static bool has_linked_dependencies(const toml_reader &project_config) {
+  for (const auto &key : project_config.get_table_keys("dependencies")) {
+    if (key != "directory") {
+      return true;
+    }
+  }
+  return false;
+}
+
 /**
  * @brief Generate a CMakeLists.txt file from cforge.toml configuration
  *
@@ -1285,6 +1612,17 @@ bool generate_cmakelists_from_toml(const std::filesystem::path &project_dir,
     return false;
   }
 
+  // Reject unknown project types before generating a CMakeLists.txt that
+  // would silently build the wrong kind of target
+  if (!validate_binary_type(project_config, toml_path.string(), "project.binary_type")) {
+    return false;
+  }
+
+  // A misspelled key would otherwise be ignored without a word
+  if (!validate_manifest_keys(project_config, toml_path.string())) {
+    return false;
+  }
+
   // Path to CMakeLists.txt in project directory
   std::filesystem::path cmakelists_path = project_dir / "CMakeLists.txt";
   bool file_exists                      = std::filesystem::exists(cmakelists_path);
@@ -1405,7 +1743,7 @@ bool generate_cmakelists_from_toml(const std::filesystem::path &project_dir,
   cmakelists << "# Generated by cforge - C++ project management tool\n\n";
 
   // Get CMake minimum version from config or use default
-  std::string cmake_min_version = project_config.get_string("cmake.version", "3.15");
+  std::string cmake_min_version = project_config.get_string("cmake.version", CMAKE_MIN_VERSION);
   cmakelists << "cmake_minimum_required(VERSION " << cmake_min_version << ")\n\n";
 
   // Determine project languages
@@ -1491,6 +1829,22 @@ bool generate_cmakelists_from_toml(const std::filesystem::path &project_dir,
   cmakelists << "set(PROJECT_AUTHOR \"" << author_string << "\")\n\n";
   cmakelists << "string(TOUPPER ${PROJECT_NAME} PROJECT_NAME_UPPER)\n";
 
+  // Generated build info header (written by cforge before configure)
+  if (is_build_info_enabled(project_config)) {
+    cmakelists << "\n# Build info header (cforge_build_info.h)\n";
+    cmakelists << "set(CFORGE_BUILD_INFO_DIR \"${CMAKE_BINARY_DIR}/cforge_generated\")\n";
+    cmakelists << "if(NOT EXISTS \"${CFORGE_BUILD_INFO_DIR}/cforge_build_info.h\")\n";
+    cmakelists << "    file(WRITE \"${CFORGE_BUILD_INFO_DIR}/cforge_build_info.h\"\n";
+    cmakelists << "        \"#pragma once\\n\"\n";
+    cmakelists << "        \"#define CFORGE_PROJECT_NAME \\\"${PROJECT_NAME}\\\"\\n\"\n";
+    cmakelists << "        \"#define CFORGE_PROJECT_VERSION \\\"${PROJECT_VERSION}\\\"\\n\"\n";
+    cmakelists << "        \"#define CFORGE_GIT_SHA \\\"unknown\\\"\\n\"\n";
+    cmakelists << "        \"#define CFORGE_GIT_DIRTY \\\"unknown\\\"\\n\"\n";
+    cmakelists << "        \"#define CFORGE_BUILD_TIMESTAMP \\\"unknown\\\"\\n\")\n";
+    cmakelists << "endif()\n";
+    cmakelists << "include_directories(\"${CFORGE_BUILD_INFO_DIR}\")\n";
+  }
+
   // Set C++ standard
   if (!cpp_standard.empty()) {
     bool cpp_ext = project_config.get_bool("project.cpp_extensions", false);
@@ -1545,10 +1899,11 @@ bool generate_cmakelists_from_toml(const std::filesystem::path &project_dir,
   }
 
   // Get binary type (executable, shared_lib, static_lib, or header_only)
-  std::string binary_type = project_config.get_string("project.binary_type", "executable");
+  std::string binary_type = get_binary_type(project_config);
 
   // Get build settings
-  std::string build_type = project_config.get_string("build.build_type", "Debug");
+  std::string build_type =
+      canonical_build_config(project_config.get_string("build.build_type", "Debug"));
 
   // Set up build configurations
   cmakelists << "# Build configurations\n";
@@ -1559,33 +1914,106 @@ bool generate_cmakelists_from_toml(const std::filesystem::path &project_dir,
   cmakelists << "message(STATUS \"Building with ${CMAKE_BUILD_TYPE} "
                 "configuration\")\n\n";
 
-  // Configure output directories for all configurations using generator
-  // expressions
+  // Custom configurations that declare cmake_build_type start from the
+  // flags of that built-in type
+  std::map<std::string, std::string> config_build_types;
+  for (const std::string section : {"build.configs", "build.config"}) {
+    for (const auto &cfg : project_config.get_table_keys(section)) {
+      std::string base = project_config.get_string(section + "." + cfg + ".cmake_build_type", "");
+      if (base.empty()) {
+        continue;
+      }
+      if (!is_standard_cmake_build_type(base)) {
+        logger::print_warning("[" + section + "." + cfg + "] cmake_build_type '" + base
+                              + "' is not Debug, Release, RelWithDebInfo or MinSizeRel");
+      } else if (is_standard_cmake_build_type(cfg)) {
+        logger::print_warning("[" + section + "." + cfg + "] cmake_build_type is ignored: '"
+                              + cfg + "' is already a CMake build type");
+      } else {
+        config_build_types[string_to_lower(cfg)] = base;
+      }
+    }
+  }
+  cmakelists << generate_config_build_type_cmake(config_build_types);
+
+  // MSVC runtime library: [build] runtime, overridable per configuration
+  auto read_runtime = [&](const std::string &key) {
+    std::string runtime = string_to_lower(project_config.get_string(key, ""));
+    if (!runtime.empty() && runtime != "static" && runtime != "dynamic") {
+      logger::print_warning(key + " '" + runtime
+                            + "' is not \"static\" or \"dynamic\"; ignoring it");
+      return std::string();
+    }
+    return runtime;
+  };
+  std::map<std::string, std::string> config_runtimes;
+  for (const std::string section : {"build.configs", "build.config"}) {
+    for (const auto &cfg : project_config.get_table_keys(section)) {
+      std::string runtime = read_runtime(section + "." + cfg + ".runtime");
+      if (!runtime.empty()) {
+        config_runtimes[string_to_lower(cfg)] = runtime;
+      }
+    }
+  }
+  cmakelists << generate_msvc_runtime_cmake(
+      read_runtime("build.runtime"), config_runtimes, config_build_types);
+
+  // Configure output directories for all configurations. [output] bin_dir
+  // and lib_dir replace the defaults; ${CONFIG} becomes the configuration
+  // CMake is generating for.
+  auto output_dir = [&](const std::string &key, const std::string &config_var) {
+    std::string dir = project_config.get_string("output." + key, "");
+    if (dir.empty()) {
+      return "${CMAKE_BINARY_DIR}/" + std::string(key == "bin_dir" ? "bin/" : "lib/") + config_var;
+    }
+    std::string expanded =
+        expand_cforge_tokens(dir, get_cforge_tokens(project_config, config_var));
+    std::replace(expanded.begin(), expanded.end(), '\\', '/');
+    if (expanded.rfind("${", 0) != 0 && !std::filesystem::path(expanded).is_absolute()) {
+      expanded = "${CMAKE_CURRENT_SOURCE_DIR}/" + expanded;
+    }
+    return expanded;
+  };
+  if (project_config.has_key("output.obj_dir")) {
+    logger::print_warning("[output] obj_dir is ignored: CMake keeps object files in the build "
+                          "directory");
+  }
+  std::string lib_multi  = output_dir("lib_dir", "${cfg}");
+  std::string bin_multi  = output_dir("bin_dir", "${cfg}");
+  std::string lib_single = output_dir("lib_dir", "${CMAKE_BUILD_TYPE}");
+  std::string bin_single = output_dir("bin_dir", "${CMAKE_BUILD_TYPE}");
+
   cmakelists << "# Configure output directories\n";
   cmakelists << "if(DEFINED CMAKE_CONFIGURATION_TYPES)\n";
   cmakelists << "  foreach(cfg IN LISTS CMAKE_CONFIGURATION_TYPES)\n";
   cmakelists << "    string(TOUPPER ${cfg} CFG_UPPER)\n";
-  cmakelists << "    set(CMAKE_ARCHIVE_OUTPUT_DIRECTORY_${CFG_UPPER} "
-                "\"${CMAKE_BINARY_DIR}/lib/${cfg}\")\n";
-  cmakelists << "    set(CMAKE_LIBRARY_OUTPUT_DIRECTORY_${CFG_UPPER} "
-                "\"${CMAKE_BINARY_DIR}/lib/${cfg}\")\n";
-  cmakelists << "    set(CMAKE_RUNTIME_OUTPUT_DIRECTORY_${CFG_UPPER} "
-                "\"${CMAKE_BINARY_DIR}/bin/${cfg}\")\n";
+  cmakelists << "    set(CMAKE_ARCHIVE_OUTPUT_DIRECTORY_${CFG_UPPER} \"" << lib_multi << "\")\n";
+  cmakelists << "    set(CMAKE_LIBRARY_OUTPUT_DIRECTORY_${CFG_UPPER} \"" << lib_multi << "\")\n";
+  cmakelists << "    set(CMAKE_RUNTIME_OUTPUT_DIRECTORY_${CFG_UPPER} \"" << bin_multi << "\")\n";
   cmakelists << "  endforeach()\n";
   cmakelists << "else()\n";
-  cmakelists << "  set(CMAKE_ARCHIVE_OUTPUT_DIRECTORY "
-                "\"${CMAKE_BINARY_DIR}/lib/${CMAKE_BUILD_TYPE}\")\n";
-  cmakelists << "  set(CMAKE_LIBRARY_OUTPUT_DIRECTORY "
-                "\"${CMAKE_BINARY_DIR}/lib/${CMAKE_BUILD_TYPE}\")\n";
-  cmakelists << "  set(CMAKE_RUNTIME_OUTPUT_DIRECTORY "
-                "\"${CMAKE_BINARY_DIR}/bin/${CMAKE_BUILD_TYPE}\")\n";
+  cmakelists << "  set(CMAKE_ARCHIVE_OUTPUT_DIRECTORY \"" << lib_single << "\")\n";
+  cmakelists << "  set(CMAKE_LIBRARY_OUTPUT_DIRECTORY \"" << lib_single << "\")\n";
+  cmakelists << "  set(CMAKE_RUNTIME_OUTPUT_DIRECTORY \"" << bin_single << "\")\n";
+  cmakelists << "endif()\n\n";
+
+  // The wasm cross target picks .js (run with node) or .html executables
+  cmakelists << "# WebAssembly executables: .js, or .html with CFORGE_WASM_SUFFIX\n";
+  cmakelists << "if(EMSCRIPTEN AND CFORGE_WASM_SUFFIX)\n";
+  cmakelists << "  set(CMAKE_EXECUTABLE_SUFFIX \"${CFORGE_WASM_SUFFIX}\")\n";
   cmakelists << "endif()\n\n";
 
   // Get dependencies directory (default: deps)
   std::string deps_dir = project_config.get_string("dependencies.directory", "deps");
 
   // Handle Git dependencies
-  configure_git_dependencies_in_cmake(project_config, deps_dir, cmakelists);
+  configure_git_dependencies_in_cmake(project_dir, project_config, deps_dir, cmakelists);
+
+  // Handle archive dependencies
+  configure_archive_dependencies_in_cmake(project_config, cmakelists);
+
+  // Handle FetchContent dependencies
+  configure_fetchcontent_dependencies_in_cmake(project_config, cmakelists);
 
   // Handle index dependencies phase 1 (before target)
   // Check if fetch_content mode is enabled (default: true)
@@ -1635,6 +2063,9 @@ bool generate_cmakelists_from_toml(const std::filesystem::path &project_dir,
     if (!additional_sources.empty()) {
       cmakelists << "# Add additional source files\n";
       for (const auto &source : additional_sources) {
+        if (is_negated_source_pattern(source)) {
+          continue;
+        }
         cmakelists << "file(GLOB_RECURSE ADDITIONAL_SOURCES_" << source << " \"${SOURCE_DIR}/"
                    << source << "\")\n";
         cmakelists << "list(APPEND SOURCES ${ADDITIONAL_SOURCES_" << source << "})\n";
@@ -1643,6 +2074,92 @@ bool generate_cmakelists_from_toml(const std::filesystem::path &project_dir,
     }
   }
 
+  // Platform- and configuration-specific sources: [platform.<plat>] sources
+  // and [build.config.<cfg>] sources. Matching files are removed from the
+  // common list first, so a file in src/ is only built where it belongs.
+  std::ostringstream conditional_sources;
+  std::vector<std::string> config_source_names;
+  auto glob_conditional_sources = [&](const std::string &key, const std::string &var) {
+    auto globs = project_config.get_string_array(key);
+    if (globs.empty()) {
+      return false;
+    }
+    conditional_sources << "file(GLOB_RECURSE " << var;
+    for (const auto &glob : globs) {
+      conditional_sources << " \"${SOURCE_DIR}/" << glob << "\"";
+    }
+    conditional_sources << ")\n";
+    conditional_sources << "if(" << var << ")\n";
+    conditional_sources << "  list(REMOVE_ITEM SOURCES ${" << var << "})\n";
+    conditional_sources << "endif()\n";
+    return true;
+  };
+
+  std::vector<std::string> source_platforms;
+  for (const std::string plat : {"windows", "linux", "macos"}) {
+    if (glob_conditional_sources("platform." + plat + ".sources",
+                                 "CFORGE_PLATFORM_SOURCES_" + plat)) {
+      source_platforms.push_back(plat);
+    }
+  }
+  for (const std::string section : {"build.config", "build.configs"}) {
+    for (const auto &cfg : project_config.get_table_keys(section)) {
+      if (glob_conditional_sources(section + "." + cfg + ".sources",
+                                   "CFORGE_CONFIG_SOURCES_" + cfg)) {
+        config_source_names.push_back(cfg);
+      }
+    }
+  }
+  for (const auto &plat : source_platforms) {
+    conditional_sources << "if(CFORGE_PLATFORM STREQUAL \"" << plat << "\")\n";
+    conditional_sources << "  list(APPEND SOURCES ${CFORGE_PLATFORM_SOURCES_" << plat << "})\n";
+    conditional_sources << "endif()\n";
+  }
+  if (!conditional_sources.str().empty()) {
+    cmakelists << "# Platform- and configuration-specific sources\n";
+    cmakelists << conditional_sources.str();
+    cmakelists << "list(REMOVE_DUPLICATES SOURCES)\n\n";
+  }
+
+  // `!` patterns in additional_sources remove what they match, whatever the
+  // order they are listed in, so they run after every source has been added
+  std::ostringstream removed_sources;
+  for (const auto &pattern : project_config.get_string_array("project.additional_sources")) {
+    if (is_negated_source_pattern(pattern)) {
+      removed_sources << " \"${SOURCE_DIR}/" << pattern.substr(1) << "\"";
+    }
+  }
+  if (!removed_sources.str().empty()) {
+    cmakelists << "# Sources removed with ! patterns\n";
+    cmakelists << "file(GLOB_RECURSE CFORGE_REMOVED_SOURCES" << removed_sources.str() << ")\n";
+    cmakelists << "if(CFORGE_REMOVED_SOURCES)\n";
+    cmakelists << "  list(REMOVE_ITEM SOURCES ${CFORGE_REMOVED_SOURCES})\n";
+    cmakelists << "endif()\n\n";
+  }
+
+  // Exclude patterns ([build] exclude_sources) follow .gitignore rules and are
+  // matched against paths relative to the project directory. Dependencies and
+  // build trees are always excluded, in case a source directory contains them.
+  std::vector<std::string> exclude_sources = default_source_excludes(deps_dir, DEFAULT_BUILD_DIR);
+  for (const auto &pattern : project_config.get_string_array("build.exclude_sources")) {
+    exclude_sources.push_back(pattern);
+  }
+  cmakelists << "# Excluded sources\n";
+  cmakelists << "string(REGEX REPLACE \"([][+.*()^$?|\\\\\\\\])\" \"\\\\\\\\\\\\1\" "
+                "CFORGE_SOURCE_DIR_REGEX \"${SOURCE_DIR}\")\n";
+  for (const auto &pattern : exclude_sources) {
+    std::string regex;
+    for (char c : glob_to_regex(pattern)) {
+      if (c == '\\' || c == '"') {
+        regex += '\\';
+      }
+      regex += c;
+    }
+    cmakelists << "list(FILTER SOURCES EXCLUDE REGEX \"^${CFORGE_SOURCE_DIR_REGEX}/" << regex
+               << "$\")\n";
+  }
+  cmakelists << "\n";
+
   // CMake inject_before_target
   if (project_config.has_key("cmake.inject_before_target")) {
     std::string inject_code = project_config.get_string("cmake.inject_before_target", "");
@@ -1658,10 +2175,12 @@ bool generate_cmakelists_from_toml(const std::filesystem::path &project_dir,
     cmakelists << "add_executable(${PROJECT_NAME} ${SOURCES})\n\n";
   } else if (binary_type == "shared_lib") {
     cmakelists << "add_library(${PROJECT_NAME} SHARED ${SOURCES})\n";
-    // Enable automatic symbol export for WINDOWS DLLs (creates .lib import
-    // library)
-    cmakelists << "set_target_properties(${PROJECT_NAME} PROPERTIES "
-                  "WINDOWS_EXPORT_ALL_SYMBOLS ON)\n\n";
+    // export_all_symbols keeps the older behavior of exporting everything
+    // from the DLL instead of only what is marked with the export macro
+    cmakelists << generate_export_header_cmake(
+        "${PROJECT_NAME}",
+        export_header_base_name(project_name),
+        project_config.get_bool("build.export_all_symbols", true));
   } else if (binary_type == "static_lib") {
     cmakelists << "add_library(${PROJECT_NAME} STATIC ${SOURCES})\n\n";
   } else if (binary_type == "header_only") {
@@ -1671,6 +2190,24 @@ bool generate_cmakelists_from_toml(const std::filesystem::path &project_dir,
     cmakelists << "add_executable(${PROJECT_NAME} ${SOURCES})\n\n";
   }
 
+  // Configuration-specific sources are selected at build time, which also
+  // works with multi-config generators
+  if (binary_type != "header_only") {
+    for (const auto &cfg : config_source_names) {
+      cmakelists << "target_sources(${PROJECT_NAME} PRIVATE \"$<$<CONFIG:" << cfg
+                 << ">:${CFORGE_CONFIG_SOURCES_" << cfg << "}>\")\n";
+    }
+    if (!config_source_names.empty()) {
+      cmakelists << "\n";
+    }
+  }
+
+  // Same <name>::<name> spelling in-tree as in the exported package
+  if (binary_type == "shared_lib" || binary_type == "static_lib"
+      || binary_type == "header_only") {
+    cmakelists << generate_library_alias_cmake("${PROJECT_NAME}", project_name);
+  }
+
   // CMake inject_after_target
   if (project_config.has_key("cmake.inject_after_target")) {
     std::string inject_code = project_config.get_string("cmake.inject_after_target", "");
@@ -1697,9 +2234,17 @@ bool generate_cmakelists_from_toml(const std::filesystem::path &project_dir,
   cmakelists << "    PROJECT_VERSION_PATCH=${PROJECT_VERSION_PATCH}\n";
   cmakelists << ")\n\n";
 
+  // Tokens such as ${OS} in include directories, defines, flags and links;
+  // ${CONFIG} is the configuration being built
+  const std::map<std::string, std::string> value_tokens =
+      get_cforge_tokens(project_config, "$<CONFIG>");
+  auto user_strings = [&](const std::string &key) {
+    return expand_cforge_tokens(project_config.get_string_array(key), value_tokens);
+  };
+
   // Add include directories - use configured include directories or default to
   // include/
-  auto include_dirs = project_config.get_string_array("build.include_dirs");
+  auto include_dirs = user_strings("build.include_dirs");
   if (include_dirs.empty()) {
     include_dirs.push_back("include");
   }
@@ -1708,8 +2253,10 @@ bool generate_cmakelists_from_toml(const std::filesystem::path &project_dir,
   cmakelists << "# Include directories\n";
   cmakelists << "target_include_directories(${PROJECT_NAME} " << inc_visibility << "\n";
   for (const auto &inc_dir : include_dirs) {
-    cmakelists << "    \"${SOURCE_DIR}/" << inc_dir << "\"\n";
+    cmakelists << "    \"$<BUILD_INTERFACE:${SOURCE_DIR}/" << inc_dir << ">\"\n";
   }
+  // Installed headers land in <prefix>/include (see the install rules below)
+  cmakelists << "    \"$<INSTALL_INTERFACE:include>\"\n";
   cmakelists << ")\n\n";
 
   // Handle index dependencies phase 2 (target_link_libraries - after target)
@@ -1754,14 +2301,14 @@ bool generate_cmakelists_from_toml(const std::filesystem::path &project_dir,
           auto inc_dirs = project_config.get_string_array(dirs_key);
           for (const auto &inc_dir : inc_dirs) {
             cmakelists << "target_include_directories(${PROJECT_NAME} PUBLIC "
-                          "\"${CMAKE_CURRENT_SOURCE_DIR}/../"
-                       << dep << "/" << inc_dir << "\")\n";
+                          "\"$<BUILD_INTERFACE:${CMAKE_CURRENT_SOURCE_DIR}/../"
+                       << dep << "/" << inc_dir << ">\")\n";
           }
         } else {
           // Default to include/<dep>/include
           cmakelists << "target_include_directories(${PROJECT_NAME} PUBLIC "
-                        "\"${CMAKE_CURRENT_SOURCE_DIR}/../"
-                     << dep << "/include\")\n";
+                        "\"$<BUILD_INTERFACE:${CMAKE_CURRENT_SOURCE_DIR}/../"
+                     << dep << "/include>\")\n";
         }
       }
       cmakelists << "\n";
@@ -1770,18 +2317,18 @@ bool generate_cmakelists_from_toml(const std::filesystem::path &project_dir,
 
   // Add additional include directories
   if (project_config.has_key("project.additional_includes")) {
-    auto additional_includes = project_config.get_string_array("project.additional_includes");
+    auto additional_includes = user_strings("project.additional_includes");
     if (!additional_includes.empty()) {
       cmakelists << "# Add additional include directories\n";
       for (const auto &include : additional_includes) {
         if (binary_type == "header_only") {
           cmakelists << "target_include_directories(${PROJECT_NAME} INTERFACE "
-                        "\"${SOURCE_DIR}/"
-                     << include << "\")\n";
+                        "\"$<BUILD_INTERFACE:${SOURCE_DIR}/"
+                     << include << ">\")\n";
         } else {
           cmakelists << "target_include_directories(${PROJECT_NAME} PUBLIC "
-                        "\"${SOURCE_DIR}/"
-                     << include << "\")\n";
+                        "\"$<BUILD_INTERFACE:${SOURCE_DIR}/"
+                     << include << ">\")\n";
         }
       }
       cmakelists << "\n";
@@ -1790,7 +2337,7 @@ bool generate_cmakelists_from_toml(const std::filesystem::path &project_dir,
 
   // Add global build.defines
   if (project_config.has_key("build.defines")) {
-    auto build_defs = project_config.get_string_array("build.defines");
+    auto build_defs = user_strings("build.defines");
     if (!build_defs.empty()) {
       cmakelists << "# Global compiler definitions\n";
       for (const auto &d : build_defs) {
@@ -1827,13 +2374,13 @@ bool generate_cmakelists_from_toml(const std::filesystem::path &project_dir,
       cmakelists << "if(CFORGE_PLATFORM STREQUAL \"" << plat << "\")\n";
 
       // platform defines
-      auto plat_defines = project_config.get_string_array(prefix + ".defines");
+      auto plat_defines = user_strings(prefix + ".defines");
       for (const auto &def : plat_defines) {
         cmakelists << "    target_compile_definitions(${PROJECT_NAME} PUBLIC " << def << ")\n";
       }
 
       // platform flags - separate MSVC-style flags from GCC-style flags
-      auto plat_flags = project_config.get_string_array(prefix + ".flags");
+      auto plat_flags = user_strings(prefix + ".flags");
       std::vector<std::string> msvc_flags, gcc_flags;
       for (const auto &flag : plat_flags) {
         if (!flag.empty() && flag[0] == '/') {
@@ -1858,7 +2405,7 @@ bool generate_cmakelists_from_toml(const std::filesystem::path &project_dir,
       }
 
       // platform links
-      auto plat_links = project_config.get_string_array(prefix + ".links");
+      auto plat_links = user_strings(prefix + ".links");
       for (const auto &link : plat_links) {
         cmakelists << "    target_link_libraries(${PROJECT_NAME} PUBLIC " << link << ")\n";
       }
@@ -1900,19 +2447,19 @@ bool generate_cmakelists_from_toml(const std::filesystem::path &project_dir,
       cmakelists << "if(CFORGE_COMPILER STREQUAL \"" << comp << "\")\n";
 
       // compiler defines
-      auto comp_defines = project_config.get_string_array(prefix + ".defines");
+      auto comp_defines = user_strings(prefix + ".defines");
       for (const auto &def : comp_defines) {
         cmakelists << "    target_compile_definitions(${PROJECT_NAME} PUBLIC " << def << ")\n";
       }
 
       // compiler flags
-      auto comp_flags = project_config.get_string_array(prefix + ".flags");
+      auto comp_flags = user_strings(prefix + ".flags");
       for (const auto &flag : comp_flags) {
         cmakelists << "    target_compile_options(${PROJECT_NAME} PUBLIC " << flag << ")\n";
       }
 
       // compiler links
-      auto comp_links = project_config.get_string_array(prefix + ".links");
+      auto comp_links = user_strings(prefix + ".links");
       for (const auto &link : comp_links) {
         cmakelists << "    target_link_libraries(${PROJECT_NAME} PUBLIC " << link << ")\n";
       }
@@ -1935,17 +2482,17 @@ bool generate_cmakelists_from_toml(const std::filesystem::path &project_dir,
       cmakelists << "if(CFORGE_PLATFORM STREQUAL \"" << plat << "\" AND CFORGE_COMPILER STREQUAL \""
                  << comp << "\")\n";
 
-      auto nested_defines = project_config.get_string_array(prefix + ".defines");
+      auto nested_defines = user_strings(prefix + ".defines");
       for (const auto &def : nested_defines) {
         cmakelists << "    target_compile_definitions(${PROJECT_NAME} PUBLIC " << def << ")\n";
       }
 
-      auto nested_flags = project_config.get_string_array(prefix + ".flags");
+      auto nested_flags = user_strings(prefix + ".flags");
       for (const auto &flag : nested_flags) {
         cmakelists << "    target_compile_options(${PROJECT_NAME} PUBLIC " << flag << ")\n";
       }
 
-      auto nested_links = project_config.get_string_array(prefix + ".links");
+      auto nested_links = user_strings(prefix + ".links");
       for (const auto &link : nested_links) {
         cmakelists << "    target_link_libraries(${PROJECT_NAME} PUBLIC " << link << ")\n";
       }
@@ -1959,9 +2506,14 @@ bool generate_cmakelists_from_toml(const std::filesystem::path &project_dir,
     std::vector<std::string> configs = {"debug", "release", "relwithdebinfo", "minsizerel"};
     bool has_any_config_portable     = false;
 
+    // [build.configs.<cfg>] is accepted as an alias for [build.config.<cfg>]
+    auto config_section = [&](const std::string &cfg) {
+      std::string section = "build.config." + cfg;
+      return project_config.has_key(section) ? section : "build.configs." + cfg;
+    };
+
     for (const auto &cfg : configs) {
-      std::string section   = "build.config." + cfg;
-      portable_options opts = parse_portable_options(project_config, section);
+      portable_options opts = parse_portable_options(project_config, config_section(cfg));
       if (opts.has_any()) {
         has_any_config_portable = true;
         break;
@@ -1971,18 +2523,27 @@ bool generate_cmakelists_from_toml(const std::filesystem::path &project_dir,
     if (has_any_config_portable && binary_type != "header_only") {
       cmakelists << "# Portable compiler flags per configuration\n";
       for (const auto &cfg : configs) {
-        std::string section   = "build.config." + cfg;
-        portable_options opts = parse_portable_options(project_config, section);
+        portable_options opts = parse_portable_options(project_config, config_section(cfg));
         if (opts.has_any()) {
-          // Capitalize first letter for CMake build type
-          std::string cmake_cfg = cfg;
-          cmake_cfg[0] = static_cast<char>(std::toupper(static_cast<unsigned char>(cmake_cfg[0])));
-          cmakelists << generate_config_portable_flags_cmake(cmake_cfg, opts, "${PROJECT_NAME}");
+          cmakelists << generate_config_portable_flags_cmake(
+              canonical_build_config(cfg), opts, "${PROJECT_NAME}");
         }
       }
     }
   }
 
+  // Universal flag tokens from build.config.<config>.flags
+  if (binary_type != "header_only") {
+    for (const std::string cfg : {"debug", "release", "relwithdebinfo", "minsizerel"}) {
+      auto tokens = user_strings("build.config." + cfg + ".flags");
+      if (tokens.empty()) {
+        continue;
+      }
+      cmakelists << generate_config_universal_flags_cmake(
+          canonical_build_config(cfg), tokens, "${PROJECT_NAME}");
+    }
+  }
+
   // Portable flags from platform sections
   {
     bool has_any_platform_portable = false;
@@ -2124,11 +2685,7 @@ bool generate_cmakelists_from_toml(const std::filesystem::path &project_dir,
         if (project_config.has_key(section)) {
           linker_options cfg_linker = parse_linker_options(project_config, section);
           if (cfg_linker.has_any()) {
-            // Capitalize first letter for CMake build type
-            std::string cmake_cfg = cfg;
-            cmake_cfg[0] =
-                static_cast<char>(std::toupper(static_cast<unsigned char>(cmake_cfg[0])));
-            cmakelists << generate_config_linker_flags_cmake(cmake_cfg,
+            cmakelists << generate_config_linker_flags_cmake(canonical_build_config(cfg),
                                                              cfg_linker,
                                                              "${PROJECT_NAME}");
           }
@@ -2224,27 +2781,101 @@ bool generate_cmakelists_from_toml(const std::filesystem::path &project_dir,
     }
   }
 
-  // Add config-specific build.config.<config>.defines
+  // Add config-specific build.config.<config>.defines. Every configuration
+  // is emitted, not just build.build_type, since `-c` picks one at build time.
   {
-    std::string defs_key = "build.config." + string_to_lower(build_type) + ".defines";
-    if (project_config.has_key(defs_key)) {
-      auto cfg_defs = project_config.get_string_array(defs_key);
-      if (!cfg_defs.empty()) {
-        cmakelists << "# Definitions for config '" << build_type << "'\n";
-        cmakelists << "if(CMAKE_BUILD_TYPE STREQUAL \"" << build_type << "\")\n";
-        for (const auto &d : cfg_defs) {
-          cmakelists << "  target_compile_definitions(${PROJECT_NAME} PUBLIC " << d << ")\n";
+    std::map<std::string, std::vector<std::string>> config_defines;
+    for (const std::string section : {"build.configs", "build.config"}) {
+      for (const auto &cfg : project_config.get_table_keys(section)) {
+        if (project_config.has_key(section + "." + cfg + ".defines")) {
+          config_defines[string_to_lower(cfg)] = user_strings(section + "." + cfg + ".defines");
         }
-        cmakelists << "endif()\n\n";
       }
     }
+    cmakelists << generate_config_defines_cmake(
+        config_defines, "${PROJECT_NAME}", binary_type == "header_only" ? "INTERFACE" : "PRIVATE");
+
+    // Variants go on top of the configuration's flags and defines
+    if (binary_type != "header_only") {
+      std::map<std::string, build_variant> variants = read_build_variants(project_config);
+      std::vector<build_variant> resolved;
+      for (const auto &[name, variant] : variants) {
+        std::string error;
+        build_variant merged = resolve_build_variant(variants, name, error);
+        if (!error.empty()) {
+          logger::print_error(error + " in " + toml_path.string());
+          return false;
+        }
+        merged.defines = expand_cforge_tokens(merged.defines, value_tokens);
+        merged.flags   = expand_cforge_tokens(merged.flags, value_tokens);
+        resolved.push_back(std::move(merged));
+      }
+      cmakelists << generate_build_variants_cmake(resolved, "${PROJECT_NAME}");
+    }
+
+    // `cforge build --define` values come last so they can override the above
+    cmakelists << "# Definitions from cforge build --define\n";
+    cmakelists << "if(CFORGE_CLI_DEFINES)\n";
+    cmakelists << "    target_compile_definitions(${PROJECT_NAME} "
+               << (binary_type == "header_only" ? "INTERFACE" : "PRIVATE")
+               << " ${CFORGE_CLI_DEFINES})\n";
+    cmakelists << "endif()\n\n";
   }
 
   // NOTE: Platform-specific defines are already handled via CMake-side
   // CFORGE_PLATFORM detection (see platform-specific configuration section
   // above). Using CMake-side detection is correct for cross-compilation.
 
-  // System dependencies (find_package, pkg_config, manual)
+  // System dependencies as a list: system = ["Threads", { name = "Boost", components = [...] }]
+  auto system_dep_names  = project_config.get_string_array("dependencies.system");
+  auto system_dep_tables = project_config.get_table_array("dependencies.system");
+  if (!system_dep_names.empty() || !system_dep_tables.empty()) {
+    cmakelists << "# System dependencies\n";
+    for (const auto &name : system_dep_names) {
+      write_system_find_package(cmakelists, name, {}, "", true);
+    }
+    for (const auto &entry : system_dep_tables) {
+      std::string name = entry.get_string("name", "");
+      if (name.empty()) {
+        logger::print_warning("System dependency entry has no name; skipping");
+        continue;
+      }
+      auto platforms = entry.get_string_array("platforms");
+      if (!platforms.empty() && !matches_current_platform(platforms)) {
+        continue;
+      }
+      write_system_find_package(cmakelists,
+                                name,
+                                entry.get_string_array("components"),
+                                entry.get_string("target", ""),
+                                entry.get_bool("required", true));
+    }
+    cmakelists << "\n";
+  }
+
+  // Conan dependencies, found through the files conan generates in <build dir>/conan
+  bool wrote_conan_header = false;
+  for (const auto &dep : project_config.get_table_keys("dependencies")) {
+    std::string prefix = "dependencies." + dep;
+    if (project_config.get_string(prefix + ".source", "") != "conan"
+        || !project_config.get_bool(prefix + ".link", true)) {
+      continue;
+    }
+    if (!wrote_conan_header) {
+      cmakelists << "# Conan dependencies\n";
+      wrote_conan_header = true;
+    }
+    write_system_find_package(cmakelists,
+                              project_config.get_string(prefix + ".package", dep),
+                              project_config.get_string_array(prefix + ".components"),
+                              project_config.get_string(prefix + ".target", ""),
+                              true);
+  }
+  if (wrote_conan_header) {
+    cmakelists << "\n";
+  }
+
+  // System dependencies as tables (find_package, pkg_config, manual)
   if (project_config.has_key("dependencies.system")) {
     auto system_deps = project_config.get_table_keys("dependencies.system");
     if (!system_deps.empty()) {
@@ -2259,36 +2890,15 @@ bool generate_cmakelists_from_toml(const std::filesystem::path &project_dir,
           continue;
         }
 
-        std::string method       = project_config.get_string(prefix + ".method", "find_package");
-        bool required            = project_config.get_bool(prefix + ".required", true);
-        std::string required_str = required ? "REQUIRED" : "";
+        std::string method = project_config.get_string(prefix + ".method", "find_package");
+        bool required      = project_config.get_bool(prefix + ".required", true);
 
         if (method == "find_package") {
-          // Use CMake find_package
-          std::string package_name = project_config.get_string(prefix + ".package", dep);
-          auto components          = project_config.get_string_array(prefix + ".components");
-
-          cmakelists << "find_package(" << package_name;
-          if (!components.empty()) {
-            cmakelists << " COMPONENTS";
-            for (const auto &comp : components) {
-              cmakelists << " " << comp;
-            }
-          }
-          if (required) {
-            cmakelists << " REQUIRED";
-          }
-          cmakelists << ")\n";
-
-          // Get target name for linking
-          std::string target = project_config.get_string(prefix + ".target", "");
-          if (target.empty()) {
-            // Default target naming convention
-            target = package_name + "::" + package_name;
-          }
-          cmakelists << "if(" << package_name << "_FOUND)\n";
-          cmakelists << "    target_link_libraries(${PROJECT_NAME} PUBLIC " << target << ")\n";
-          cmakelists << "endif()\n";
+          write_system_find_package(cmakelists,
+                                    project_config.get_string(prefix + ".package", dep),
+                                    project_config.get_string_array(prefix + ".components"),
+                                    project_config.get_string(prefix + ".target", ""),
+                                    required);
 
         } else if (method == "pkg_config") {
           // Use pkg-config
@@ -2315,23 +2925,23 @@ bool generate_cmakelists_from_toml(const std::filesystem::path &project_dir,
 
         } else if (method == "manual") {
           // Manual specification
-          auto include_dirs = project_config.get_string_array(prefix + ".include_dirs");
+          auto include_dirs = user_strings(prefix + ".include_dirs");
           auto library_dirs = project_config.get_string_array(prefix + ".library_dirs");
-          auto libraries    = project_config.get_string_array(prefix + ".libraries");
-          auto defines      = project_config.get_string_array(prefix + ".defines");
+          auto libraries    = user_strings(prefix + ".libraries");
+          auto defines      = user_strings(prefix + ".defines");
 
           cmakelists << "# Manual dependency: " << dep << "\n";
 
           if (!include_dirs.empty()) {
             for (const auto &dir : include_dirs) {
-              cmakelists << "target_include_directories(${PROJECT_NAME} PUBLIC \"" << dir
-                         << "\")\n";
+              cmakelists << "target_include_directories(${PROJECT_NAME} PUBLIC \""
+                         << to_cmake_path(dir) << "\")\n";
             }
           }
 
           if (!library_dirs.empty()) {
             for (const auto &dir : library_dirs) {
-              cmakelists << "link_directories(\"" << dir << "\")\n";
+              cmakelists << "link_directories(\"" << to_cmake_path(dir) << "\")\n";
             }
           }
 
@@ -2395,6 +3005,9 @@ bool generate_cmakelists_from_toml(const std::filesystem::path &project_dir,
     if (project_config.has_key("dependencies.vcpkg")) {
       auto vcpkg_deps = project_config.get_table_keys("dependencies.vcpkg");
       for (const auto &dep : vcpkg_deps) {
+        if (is_vcpkg_setting_key(dep)) {
+          continue;
+        }
         std::string target = project_config.get_string("dependencies.vcpkg." + dep + ".target_name",
                                                        dep);
         if (target.find("::") == std::string::npos) {
@@ -2415,9 +3028,25 @@ bool generate_cmakelists_from_toml(const std::filesystem::path &project_dir,
         cmakelists << "    " << target << "\n";
       }
     }
+    // Link archive dependencies that name a target
+    for (const auto &dep : project_config.get_table_keys("dependencies.archive")) {
+      std::string target =
+          project_config.get_string("dependencies.archive." + dep + ".target_name", "");
+      if (!target.empty()) {
+        cmakelists << "    " << target << "\n";
+      }
+    }
+    // Link FetchContent dependencies that name a target
+    for (const auto &dep : project_config.get_table_keys("dependencies.fetchcontent")) {
+      std::string target =
+          project_config.get_string("dependencies.fetchcontent." + dep + ".target_name", "");
+      if (!target.empty()) {
+        cmakelists << "    " << target << "\n";
+      }
+    }
     // Add additional libraries from build.libraries
     if (project_config.has_key("build.libraries")) {
-      auto libraries = project_config.get_string_array("build.libraries");
+      auto libraries = user_strings("build.libraries");
       for (const auto &lib : libraries) {
         cmakelists << "    " << lib << "\n";
       }
@@ -2429,6 +3058,9 @@ bool generate_cmakelists_from_toml(const std::filesystem::path &project_dir,
     if (project_config.has_key("dependencies.vcpkg")) {
       auto vcpkg_deps = project_config.get_table_keys("dependencies.vcpkg");
       for (const auto &dep : vcpkg_deps) {
+        if (is_vcpkg_setting_key(dep)) {
+          continue;
+        }
         std::string target = project_config.get_string("dependencies.vcpkg." + dep + ".target_name",
                                                        dep);
         if (target.find("::") == std::string::npos) {
@@ -2449,9 +3081,25 @@ bool generate_cmakelists_from_toml(const std::filesystem::path &project_dir,
         cmakelists << "    " << target << "\n";
       }
     }
+    // Link archive dependencies that name a target
+    for (const auto &dep : project_config.get_table_keys("dependencies.archive")) {
+      std::string target =
+          project_config.get_string("dependencies.archive." + dep + ".target_name", "");
+      if (!target.empty()) {
+        cmakelists << "    " << target << "\n";
+      }
+    }
+    // Link FetchContent dependencies that name a target
+    for (const auto &dep : project_config.get_table_keys("dependencies.fetchcontent")) {
+      std::string target =
+          project_config.get_string("dependencies.fetchcontent." + dep + ".target_name", "");
+      if (!target.empty()) {
+        cmakelists << "    " << target << "\n";
+      }
+    }
     // Add additional libraries from build.libraries
     if (project_config.has_key("build.libraries")) {
-      auto libraries = project_config.get_string_array("build.libraries");
+      auto libraries = user_strings("build.libraries");
       for (const auto &lib : libraries) {
         cmakelists << "    " << lib << "\n";
       }
@@ -2459,6 +3107,44 @@ bool generate_cmakelists_from_toml(const std::filesystem::path &project_dir,
     cmakelists << ")\n\n";
   }
 
+  // pkg-config modules and raw system libraries from [build]
+  {
+    std::string link_scope = binary_type == "header_only" ? "INTERFACE" : "PUBLIC";
+    auto pkg_modules       = project_config.get_string_array("build.pkg_config");
+    if (!pkg_modules.empty()) {
+      std::string module_list;
+      for (const auto &spec : pkg_modules) {
+        module_list += (module_list.empty() ? "" : ", ") + pkg_config_module(spec);
+      }
+      cmakelists << "# pkg-config modules\n";
+      cmakelists << "find_package(PkgConfig QUIET)\n";
+      cmakelists << "if(NOT PKG_CONFIG_FOUND)\n";
+      cmakelists << "    message(FATAL_ERROR \"pkg-config was not found but is needed for: "
+                 << module_list
+                 << ". Install pkg-config (cforge build offers to) or set "
+                    "PKG_CONFIG_EXECUTABLE.\")\n";
+      cmakelists << "endif()\n";
+      for (const auto &spec : pkg_modules) {
+        std::string variable = pkg_config_variable(spec);
+        cmakelists << "pkg_check_modules(" << variable << " REQUIRED IMPORTED_TARGET \""
+                   << pkg_config_spec(spec) << "\")\n";
+        cmakelists << "target_link_libraries(${PROJECT_NAME} " << link_scope << " PkgConfig::"
+                   << variable << ")\n";
+      }
+      cmakelists << "\n";
+    }
+
+    auto system_links = user_strings("build.system_links");
+    if (!system_links.empty()) {
+      cmakelists << "# System libraries\n";
+      cmakelists << "target_link_libraries(${PROJECT_NAME} " << link_scope << "\n";
+      for (const auto &lib : system_links) {
+        cmakelists << "    " << lib << "\n";
+      }
+      cmakelists << ")\n\n";
+    }
+  }
+
   // Handle workspace project dependencies linking
   {
     std::vector<std::string> deps = project_config.get_table_keys("dependencies");
@@ -2504,7 +3190,7 @@ bool generate_cmakelists_from_toml(const std::filesystem::path &project_dir,
   {
     std::string plat_links_key = std::string("platform.") + cforge_platform + ".links";
     if (project_config.has_key(plat_links_key)) {
-      auto plat_links = project_config.get_string_array(plat_links_key);
+      auto plat_links = user_strings(plat_links_key);
       if (!plat_links.empty()) {
         cmakelists << "# platform-specific links\n";
         cmakelists << "target_link_libraries(${PROJECT_NAME} PUBLIC\n";
@@ -2538,19 +3224,30 @@ bool generate_cmakelists_from_toml(const std::filesystem::path &project_dir,
     }
   }
 
-  // Add tests if available
+  // Add tests only if the user maintains their own tests/CMakeLists.txt.
+  // Otherwise `cforge test` generates and builds the test targets itself, and
+  // an add_subdirectory() on a directory without a CMakeLists.txt would break
+  // configuration.
   cmakelists << "# Tests\n";
-  std::filesystem::path tests_dir = project_dir / "tests";
-  if (std::filesystem::exists(tests_dir) && std::filesystem::is_directory(tests_dir)) {
+  std::string test_dir_name       = project_config.get_string("test.directory", "tests");
+  std::filesystem::path tests_dir = project_dir / test_dir_name;
+  if (std::filesystem::exists(tests_dir / "CMakeLists.txt")) {
     cmakelists << "if(BUILD_TESTING)\n";
     cmakelists << "    enable_testing()\n";
-    cmakelists << "    add_subdirectory(\"${SOURCE_DIR}/tests\" "
-                  "${CMAKE_BINARY_DIR}/tests)\n";
+    cmakelists << "    add_subdirectory(\"${SOURCE_DIR}/" << test_dir_name << "\" "
+               << "${CMAKE_BINARY_DIR}/" << test_dir_name << ")\n";
     cmakelists << "endif()\n\n";
   } else {
-    cmakelists << "# No tests directory found\n\n";
+    cmakelists << "# Tests are built by 'cforge test'\n\n";
   }
 
+  // Libraries export a CMake package (find_package support) unless they link
+  // dependencies that are not part of an export set themselves
+  bool is_library     = binary_type != "executable";
+  bool export_package = is_library
+                     && project_config.get_bool("package.cmake_config",
+                                                !has_linked_dependencies(project_config));
+
   // Installation configuration
   if (binary_type == "executable") {
     cmakelists << "# Installation configuration\n";
@@ -2587,7 +3284,7 @@ bool generate_cmakelists_from_toml(const std::filesystem::path &project_dir,
           cmakelists << "install(FILES \"${CMAKE_CURRENT_SOURCE_DIR}/" << file << "\"\n";
           cmakelists << "        DESTINATION "
                         "${CMAKE_INSTALL_DATADIR}/${PROJECT_NAME}\n";
-          cmakelists << "        COMPONENT Runtime\n";
+          cmakelists << "        COMPONENT Resources\n";
           cmakelists << ")\n";
         }
         cmakelists << "\n";
@@ -2599,6 +3296,9 @@ bool generate_cmakelists_from_toml(const std::filesystem::path &project_dir,
 
     // Install the library
     cmakelists << "install(TARGETS ${PROJECT_NAME}\n";
+    if (export_package) {
+      cmakelists << "    EXPORT ${PROJECT_NAME}Targets\n";
+    }
     cmakelists << "    RUNTIME\n";
     cmakelists << "        DESTINATION ${CMAKE_INSTALL_BINDIR}\n";
     cmakelists << "        COMPONENT Runtime\n";
@@ -2608,23 +3308,49 @@ bool generate_cmakelists_from_toml(const std::filesystem::path &project_dir,
     cmakelists << "    ARCHIVE\n";
     cmakelists << "        DESTINATION ${CMAKE_INSTALL_LIBDIR}\n";
     cmakelists << "        COMPONENT Runtime\n";
+    if (export_package) {
+      cmakelists << "    INCLUDES DESTINATION ${CMAKE_INSTALL_INCLUDEDIR}\n";
+    }
     cmakelists << ")\n\n";
+  }
 
-    // Install headers
+  // Install headers for every kind of library
+  if (is_library
+      && (binary_type != "header_only" || std::filesystem::exists(project_dir / "include"))) {
+    if (binary_type == "header_only") {
+      cmakelists << "# Installation configuration\n";
+      cmakelists << "include(GNUInstallDirs)\n\n";
+    }
     cmakelists << "install(DIRECTORY \"${CMAKE_CURRENT_SOURCE_DIR}/include/\"\n";
     cmakelists << "    DESTINATION ${CMAKE_INSTALL_INCLUDEDIR}\n";
-    cmakelists << "    COMPONENT Development\n";
+    cmakelists << "    COMPONENT Headers\n";
     cmakelists << "    FILES_MATCHING PATTERN \"*.h\" PATTERN \"*.hpp\"\n";
     cmakelists << ")\n\n";
   }
 
+  if (binary_type == "shared_lib") {
+    std::string export_base = export_header_base_name(project_name);
+    cmakelists << "install(FILES \"${CMAKE_CURRENT_BINARY_DIR}/export/" << export_base
+               << "/export.h\"\n";
+    cmakelists << "    DESTINATION ${CMAKE_INSTALL_INCLUDEDIR}/" << export_base << "\n";
+    cmakelists << "    COMPONENT Headers\n";
+    cmakelists << ")\n\n";
+  }
+
+  if (export_package) {
+    cmakelists << generate_package_config_cmake(
+        project_dir, "${PROJECT_NAME}", project_name, binary_type);
+  }
+
   // CPack configuration
   cmakelists << "# CPack configuration\n";
   cmakelists << "set(CPACK_PACKAGE_NAME \"${PROJECT_NAME}\")\n";
   cmakelists << "set(CPACK_PACKAGE_VENDOR \""
              << project_config.get_string("package.vendor", "Unknown") << "\")\n";
-  cmakelists << "set(CPACK_PACKAGE_DESCRIPTION_SUMMARY \""
-             << project_config.get_string("project.description", "A C++ project") << "\")\n";
+  std::string description = project_config.get_string("project.description", "");
+  if (!description.empty()) {
+    cmakelists << "set(CPACK_PACKAGE_DESCRIPTION_SUMMARY \"" << description << "\")\n";
+  }
   cmakelists << "set(CPACK_PACKAGE_VERSION \""
              << project_config.get_string("project.version", "1.0.0") << "\")\n";
   cmakelists << "set(CPACK_PACKAGE_INSTALL_DIRECTORY \"${PROJECT_NAME}\")\n";
@@ -2661,9 +3387,14 @@ bool generate_cmakelists_from_toml(const std::filesystem::path &project_dir,
 
   // Configure components (names must match install() COMPONENT names exactly)
   cmakelists << "# Package components\n";
+  bool has_resources = binary_type == "executable"
+                    && !project_config.get_string_array("package.include_files").empty();
   cmakelists << "set(CPACK_COMPONENTS_ALL Runtime)\n";
-  if (binary_type == "shared_lib" || binary_type == "static_lib") {
-    cmakelists << "list(APPEND CPACK_COMPONENTS_ALL Development)\n";
+  if (is_library) {
+    cmakelists << "list(APPEND CPACK_COMPONENTS_ALL Headers)\n";
+  }
+  if (has_resources) {
+    cmakelists << "list(APPEND CPACK_COMPONENTS_ALL Resources)\n";
   }
   if (binary_type == "executable" && project_config.get_bool("package.include_debug", false)) {
     cmakelists << "list(APPEND CPACK_COMPONENTS_ALL Debug)\n";
@@ -2674,12 +3405,15 @@ bool generate_cmakelists_from_toml(const std::filesystem::path &project_dir,
   cmakelists << "set(CPACK_COMPONENT_RUNTIME_DISPLAY_NAME \"Runtime Files\")\n";
   cmakelists << "set(CPACK_COMPONENT_RUNTIME_DESCRIPTION \"Runtime libraries "
                 "and executables\")\n";
-  if (binary_type == "shared_lib" || binary_type == "static_lib") {
-    cmakelists << "set(CPACK_COMPONENT_DEVELOPMENT_DISPLAY_NAME \"Development "
-                  "Files\")\n";
-    cmakelists << "set(CPACK_COMPONENT_DEVELOPMENT_DESCRIPTION \"Development "
-                  "headers and libraries\")\n";
-    cmakelists << "set(CPACK_COMPONENT_DEVELOPMENT_DEPENDS Runtime)\n";
+  if (is_library) {
+    cmakelists << "set(CPACK_COMPONENT_HEADERS_DISPLAY_NAME \"Headers\")\n";
+    cmakelists << "set(CPACK_COMPONENT_HEADERS_DESCRIPTION \"Public headers for "
+                  "building against the library\")\n";
+    cmakelists << "set(CPACK_COMPONENT_HEADERS_DEPENDS Runtime)\n";
+  }
+  if (has_resources) {
+    cmakelists << "set(CPACK_COMPONENT_RESOURCES_DISPLAY_NAME \"Resources\")\n";
+    cmakelists << "set(CPACK_COMPONENT_RESOURCES_DESCRIPTION \"Additional data files\")\n";
   }
   cmakelists << "\n";
 
@@ -2698,7 +3432,21 @@ bool generate_cmakelists_from_toml(const std::filesystem::path &project_dir,
   cmakelists << "    set(CPACK_DEBIAN_PACKAGE_MAINTAINER "
                 "\"${CPACK_PACKAGE_VENDOR}\")\n";
   cmakelists << "    set(CPACK_DEBIAN_PACKAGE_SHLIBDEPS ON)\n";
-  cmakelists << "endif()\n\n";
+  cmakelists << "endif()\n";
+  {
+    // package.formats replaces the platform defaults for a plain `cpack`
+    std::vector<std::string> package_generators;
+    for (const auto &format : project_config.get_string_array("package.formats")) {
+      std::string generator = package_generator_for_format(format);
+      if (!generator.empty()) {
+        package_generators.push_back(generator);
+      }
+    }
+    if (!package_generators.empty()) {
+      cmakelists << "set(CPACK_GENERATOR \"" << join_strings(package_generators, ";") << "\")\n";
+    }
+  }
+  cmakelists << "\n";
 
   // Packaging directory settings
   cmakelists << "# Packaging directory settings\n";
@@ -2726,16 +3474,19 @@ bool generate_cmakelists_from_toml(const std::filesystem::path &project_dir,
     }
   }
 
-  // Precompiled headers
-  if (project_config.has_key("build.precompiled_headers")) {
-    auto pch_list = project_config.get_string_array("build.precompiled_headers");
-    if (!pch_list.empty()) {
-      cmakelists << "# Precompiled headers\n";
-      cmakelists << "target_precompile_headers(${PROJECT_NAME} PRIVATE";
-      for (const auto &pch : pch_list) {
-        cmakelists << " \"" << pch << "\"";
+  // Precompiled headers from [pch] (or legacy build.precompiled_headers)
+  {
+    pch_config pch           = read_pch_config(project_config);
+    std::string project_name = project_config.get_string("project.name", "");
+    if (pch.enabled && binary_type == "header_only") {
+      logger::print_verbose("Skipping precompiled headers for header-only project");
+    } else if (pch_applies_to(pch, project_name)) {
+      for (const auto &header : pch.headers) {
+        if (header.front() != '<' && !std::filesystem::exists(project_dir / header)) {
+          logger::print_warning("Precompiled header not found: " + header);
+        }
       }
-      cmakelists << ")\n\n";
+      cmakelists << generate_pch_cmake(pch, "${PROJECT_NAME}", "${CMAKE_CURRENT_SOURCE_DIR}");
     }
   }
 
@@ -2792,36 +3543,16 @@ bool workspace::build_all(const std::string &config, cforge_int_t num_jobs, bool
     return false;
   }
 
-  // Get build order respecting dependencies
-  std::vector<std::string> build_order;
-  std::set<std::string> visited;
-
-  // Topological sort for dependency-aware build order
-  std::function<void(const std::string &)> visit = [&](const std::string &project_name) {
-    if (visited.find(project_name) != visited.end()) {
-      return;
-    }
-
-    visited.insert(project_name);
-
-    // Find project dependencies
-    for (const auto &project : projects_) {
-      if (project.name == project_name) {
-        for (const auto &dep : project.dependencies) {
-          visit(dep);
-        }
-        break;
-      }
-    }
-
-    build_order.push_back(project_name);
-  };
-
-  // Visit all projects
-  for (const auto &project : projects_) {
-    visit(project.name);
+  // Refuse to start on a broken dependency graph
+  auto graph = check_dependency_graph();
+  if (graph.is_error()) {
+    graph.error().print();
+    return false;
   }
 
+  // Get build order respecting dependencies
+  std::vector<std::string> build_order = get_build_order();
+
   logger::print_action("Building",
                        std::to_string(build_order.size())
                            + " projects in workspace: " + workspace_name_);
@@ -2850,8 +3581,9 @@ bool workspace::build_all(const std::string &config, cforge_int_t num_jobs, bool
       continue;
     }
 
-    const auto &project = *it;
-    logger::print_action("Building", project.name);
+    const auto &project              = *it;
+    std::string project_build_config = get_project_config(project.name, config);
+    logger::print_action("Building", project.name + " [" + project_build_config + "]");
 
     // Create build directory if it doesn't exist
     std::filesystem::path build_dir = project.path / "build";
@@ -2903,7 +3635,7 @@ bool workspace::build_all(const std::string &config, cforge_int_t num_jobs, bool
 
     // Add build type for non-multi-config generators
     if (!is_multi_config) {
-      cmake_args.push_back("-DCMAKE_BUILD_TYPE=" + config);
+      cmake_args.push_back("-DCMAKE_BUILD_TYPE=" + canonical_build_config(project_build_config));
     }
 
     // Add export_compile_commands if enabled in project config
@@ -2911,9 +3643,15 @@ bool workspace::build_all(const std::string &config, cforge_int_t num_jobs, bool
       cmake_args.push_back("-DCMAKE_EXPORT_COMPILE_COMMANDS=ON");
     }
 
+    // Add typed cache entries from [cmake.cache]
+    if (has_project_config) {
+      auto cache_args = get_cmake_cache_args(project_config);
+      cmake_args.insert(cmake_args.end(), cache_args.begin(), cache_args.end());
+    }
+
     // Add dependency linking options
     std::vector<std::string> link_options =
-        generate_cmake_linking_options(project, projects_, config);
+        generate_cmake_linking_options(project, projects_, project_build_config);
     cmake_args.insert(cmake_args.end(), link_options.begin(), link_options.end());
 
     // Set jobs if specified
@@ -2922,6 +3660,7 @@ bool workspace::build_all(const std::string &config, cforge_int_t num_jobs, bool
     }
 
     // Run CMake configure
+    write_cmake_file_api_query(build_dir);
     logger::configuring(project.name);
     bool configure_success = execute_tool("cmake", cmake_args, "", "CMake Configure", verbose);
 
@@ -2937,7 +3676,7 @@ bool workspace::build_all(const std::string &config, cforge_int_t num_jobs, bool
     // Add config for multi-config generators
     if (is_multi_config) {
       build_args.push_back("--config");
-      build_args.push_back(config);
+      build_args.push_back(project_build_config);
     }
 
     // Set parallel jobs for build
@@ -3000,16 +3739,8 @@ bool workspace::build_project(const std::string &project_name,
       return false;
     }
 
-    // Determine build directory
-    std::string base_build_dir;
-    if (project_config.has_key("build.directory")) {
-      base_build_dir = project_config.get_string("build.directory");
-    } else if (project_config.has_key("build.build_dir")) {
-      // Legacy key support
-      base_build_dir = project_config.get_string("build.build_dir");
-    } else {
-      base_build_dir = "build";
-    }
+    // Determine build directory (--build-dir, CFORGE_BUILD_DIR, or config)
+    std::string base_build_dir = get_build_base_dir(project->path, &project_config).string();
 
     // Determine generator - respect project's cmake.generator setting
     std::string generator = get_project_generator(project_config);
@@ -3042,6 +3773,10 @@ bool workspace::build_project(const std::string &project_name,
 
     logger::finished("CMakeLists.txt for " + project->name);
 
+    if (is_build_info_enabled(project_config)) {
+      write_build_info_header(project->path, build_dir, project_config);
+    }
+
     // Run CMake configure
     std::vector<std::string> cmake_args;
     cmake_args.push_back("-S");
@@ -3056,7 +3791,7 @@ bool workspace::build_project(const std::string &project_name,
                         || generator.find("Visual Studio") != std::string::npos
                         || generator.find("Xcode") != std::string::npos;
     if (!is_multi_config) {
-      cmake_args.push_back("-DCMAKE_BUILD_TYPE=" + config);
+      cmake_args.push_back("-DCMAKE_BUILD_TYPE=" + canonical_build_config(config));
     }
 
     // Add export_compile_commands if enabled in project config
@@ -3073,7 +3808,12 @@ bool workspace::build_project(const std::string &project_name,
       }
     }
 
-    // Run cmake configure
+    // Add typed cache entries from [cmake.cache]
+    auto cache_args = get_cmake_cache_args(project_config);
+    cmake_args.insert(cmake_args.end(), cache_args.begin(), cache_args.end());
+
+    // Run cmake configure and build with the project's [env] exported
+    scoped_environment project_env(get_project_environment(project_config, config));
     if (!run_cmake_configure(cmake_args, build_dir.string(), verbose)) {
       logger::print_error("CMake configure failed for project '" + project->name + "'");
       return false;
@@ -3156,7 +3896,7 @@ bool workspace::run_startup_project(const std::vector<std::string> &args,
 
 bool workspace::run_project(const std::string &project_name,
                             const std::vector<std::string> &args,
-                            const std::string &config,
+                            const std::string &requested_config,
                             bool verbose) const {
   // Find the project by name
   auto it =
@@ -3170,6 +3910,7 @@ bool workspace::run_project(const std::string &project_name,
   }
 
   const auto &project = *it;
+  std::string config  = get_project_config(project.name, requested_config);
   logger::print_action("Running", project.name);
 
   // Make sure the project is built
@@ -3178,15 +3919,31 @@ bool workspace::run_project(const std::string &project_name,
     return false;
   }
 
-  // Find the executable
-  std::filesystem::path executable =
-      find_project_executable(project.path, "build", config, project.name);
+  // Look in [output] bin_dir, then the CMake file API reply, falling back to
+  // a search of the build tree when CMake did not write one
+  std::filesystem::path executable;
+  toml_reader project_config;
+  project_config.load((project.path / CFORGE_FILE).string());
+  std::filesystem::path build_dir =
+      get_build_dir_for_config(get_build_base_dir(project.path, &project_config).string(),
+                               config,
+                               get_project_generator(project_config));
+  executable = find_output_executable(project_config, project.path, config, project.name);
+  if (executable.empty()) {
+    if (auto api_exe = find_cmake_executable(build_dir, config, project.name)) {
+      executable = *api_exe;
+    } else {
+      executable = find_project_executable(project.path, build_dir.string(), config, project.name);
+    }
+  }
 
   if (executable.empty()) {
     logger::print_error("Executable not found for project: " + project.name);
     return false;
   }
 
+  scoped_environment project_env(get_project_environment(project_config, config));
+
   logger::print_action("Running", executable.string());
 
   // Display program output header
@@ -3403,24 +4160,45 @@ void workspace::load_projects() {
                               + config_project_name + "' in project config");
       }
 
+      auto is_member = [this](const std::string &name) {
+        return std::any_of(projects_.begin(), projects_.end(), [&](const workspace_project &p) {
+          return p.name == name;
+        });
+      };
+      auto add_dependency = [&](const std::string &dep, const std::string &key) {
+        if (std::find(project.dependencies.begin(), project.dependencies.end(), dep)
+            != project.dependencies.end()) {
+          return;
+        }
+        project.dependencies.push_back(dep);
+        if (!project_config.get_bool(key + ".link", true)) {
+          project.order_only_dependencies.push_back(dep);
+        }
+        logger::print_verbose("Added dependency: " + project.name + " -> " + dep);
+      };
+
       // Try to find dependencies for this project
       if (project_config.has_key("dependencies")) {
         std::vector<std::string> deps = project_config.get_table_keys("dependencies");
         for (const auto &dep : deps) {
           // Check if this dependency is another project in the workspace
-          for (const auto &other_project : projects_) {
-            if (other_project.name == dep) {
-              // Add as dependency if it's not already there
-              if (std::find(project.dependencies.begin(), project.dependencies.end(), dep)
-                  == project.dependencies.end()) {
-                project.dependencies.push_back(dep);
-                logger::print_verbose("Added dependency: " + project.name + " -> " + dep);
-              }
-              break;
-            }
+          if (is_member(dep)) {
+            add_dependency(dep, "dependencies." + dep);
           }
         }
       }
+
+      // [dependencies.project.<name>] always refers to a workspace member
+      for (const auto &dep : project_config.get_table_keys("dependencies.project")) {
+        if (is_member(dep)) {
+          add_dependency(dep, "dependencies.project." + dep);
+        } else if (std::find(project.missing_dependencies.begin(),
+                             project.missing_dependencies.end(),
+                             dep)
+                   == project.missing_dependencies.end()) {
+          project.missing_dependencies.push_back(dep);
+        }
+      }
     }
   }
 }
@@ -3569,7 +4347,25 @@ bool generate_workspace_cmakelists(const std::filesystem::path &workspace_dir,
             // Keep absolute path if relative conversion fails
           }
         }
-        cmakelists << "add_subdirectory(" << rel_path.string() << ")\n";
+
+        // Single-config generators read CMAKE_BUILD_TYPE per directory, so a
+        // project pinned in [workspace.project_configs] gets its own value;
+        // multi-config builds are handled by 'cforge build'
+        std::string pinned_config = ws.get_project_config(proj.name, "");
+        if (!pinned_config.empty()) {
+          cmakelists << "# " << proj.name << " always builds " << pinned_config
+                     << " ([workspace.project_configs])\n";
+          cmakelists << "if(NOT CMAKE_CONFIGURATION_TYPES)\n";
+          cmakelists << "  set(CFORGE_WORKSPACE_BUILD_TYPE \"${CMAKE_BUILD_TYPE}\")\n";
+          cmakelists << "  set(CMAKE_BUILD_TYPE \"" << pinned_config << "\")\n";
+          cmakelists << "endif()\n";
+        }
+        cmakelists << "add_subdirectory(\"" << rel_path.generic_string() << "\")\n";
+        if (!pinned_config.empty()) {
+          cmakelists << "if(NOT CMAKE_CONFIGURATION_TYPES)\n";
+          cmakelists << "  set(CMAKE_BUILD_TYPE \"${CFORGE_WORKSPACE_BUILD_TYPE}\")\n";
+          cmakelists << "endif()\n";
+        }
       }
     }
     cmakelists << "\n";
@@ -3673,6 +4469,20 @@ bool workspace_config::load(const std::string &workspace_file) {
         // cforge.toml
         std::filesystem::path local_toml = project.path / CFORGE_FILE;
         bool has_local_toml              = std::filesystem::exists(local_toml);
+        if (auto inline_type = tbl["binary_type"].value<std::string>()) {
+          bool deprecated = false;
+          if (normalize_binary_type(*inline_type, &deprecated).empty()) {
+            logger::print_error("invalid binary_type '" + *inline_type + "' for project '"
+                                + project.name + "' in " + workspace_file);
+            logger::print_hint("expected one of: executable, static_lib, shared_lib, header_only");
+            return false;
+          }
+          if (deprecated) {
+            logger::print_warning("binary_type = \"" + *inline_type + "\" for project '"
+                                  + project.name + "' is deprecated; use \"static_lib\"");
+          }
+        }
+
         bool has_inline_settings = tbl.contains("cpp_standard") || tbl.contains("binary_type")
                                 || tbl.contains("version") || tbl.contains("sources")
                                 || tbl.contains("build");
@@ -3834,7 +4644,8 @@ bool workspace_config::save(const std::string &workspace_file) const {
     } else {
       path_to_save = project.path;
     }
-    file << "path = \"" << path_to_save.string() << "\"\n";
+    // Forward slashes: a backslash starts an escape in a TOML string
+    file << "path = \"" << path_to_save.generic_string() << "\"\n";
     file << "startup = " << (project.is_startup_project ? "true" : "false") << "\n\n";
   }
 
@@ -4008,6 +4819,121 @@ std::vector<std::string> workspace::get_build_order() const {
   return build_order;
 }
 
+result<void> workspace::check_dependency_graph() const {
+  auto config_hint = [this](const workspace_project &project) {
+    std::error_code ec;
+    std::filesystem::path rel = std::filesystem::relative(project.path, workspace_path_, ec);
+    return "--> " + ((ec || rel.empty()) ? project.path : rel).generic_string() + "/" + CFORGE_FILE;
+  };
+
+  for (const auto &project : projects_) {
+    if (std::find(project.dependencies.begin(), project.dependencies.end(), project.name)
+        != project.dependencies.end()) {
+      return cforge_error::make(error_code::DEP_CIRCULAR,
+                                "project '" + project.name + "' depends on itself",
+                                config_hint(project))
+          .with_help("remove [dependencies." + project.name + "] from the project's "
+                     + std::string(CFORGE_FILE));
+    }
+    if (!project.missing_dependencies.empty()) {
+      const std::string &dep = project.missing_dependencies.front();
+      return cforge_error::make(error_code::DEP_NOT_FOUND,
+                                "project '" + project.name + "' depends on '" + dep
+                                    + "', which is not a member of workspace '" + workspace_name_
+                                    + "'",
+                                config_hint(project))
+          .with_help("add '" + dep + "' to workspace.members, or remove the dependency")
+          .with_help("run 'cforge list projects' to see the workspace members");
+    }
+  }
+
+  // Depth-first search; a dependency that is still on the path closes a cycle
+  enum class mark { NONE, ACTIVE, DONE };
+  std::map<std::string, mark> marks;
+  std::vector<const workspace_project *> path;
+  std::vector<std::string> cycle;
+
+  std::function<bool(const workspace_project &)> visit = [&](const workspace_project &project) {
+    marks[project.name] = mark::ACTIVE;
+    path.push_back(&project);
+    for (const auto &dep : project.dependencies) {
+      const workspace_project *next = get_project_by_name(dep);
+      if (!next || marks[dep] == mark::DONE) {
+        continue;
+      }
+      if (marks[dep] == mark::ACTIVE) {
+        auto start = std::find_if(path.begin(), path.end(), [&](const workspace_project *p) {
+          return p->name == dep;
+        });
+        for (auto it = start; it != path.end(); ++it) {
+          cycle.push_back((*it)->name);
+        }
+        cycle.push_back(dep);
+        return false;
+      }
+      if (!visit(*next)) {
+        return false;
+      }
+    }
+    path.pop_back();
+    marks[project.name] = mark::DONE;
+    return true;
+  };
+
+  for (const auto &project : projects_) {
+    if (marks[project.name] != mark::NONE || visit(project)) {
+      continue;
+    }
+
+    std::string chain;
+    for (const auto &name : cycle) {
+      chain += (chain.empty() ? "" : " -> ") + name;
+    }
+    auto err = cforge_error::make(error_code::DEP_CIRCULAR, "dependency cycle detected: " + chain);
+    for (cforge_size_t i = 0; i + 1 < cycle.size(); ++i) {
+      const workspace_project *from = get_project_by_name(cycle[i]);
+      err.with_help(cycle[i] + " depends on " + cycle[i + 1] + " ("
+                    + config_hint(*from).substr(4) + ")");
+    }
+    err.with_help("remove one of these dependencies to break the cycle");
+    return err;
+  }
+
+  return {};
+}
+
+std::vector<std::string> apply_workspace_defaults(toml::table &project,
+                                                  const std::filesystem::path &project_file) {
+  if (project_file.filename() != CFORGE_FILE) {
+    return {};
+  }
+
+  // Only projects the workspace lists inherit its defaults, not other
+  // cforge.toml files below it such as dependencies cloned into deps/
+  std::error_code ec;
+  std::filesystem::path project_dir   = std::filesystem::absolute(project_file, ec).parent_path();
+  std::filesystem::path workspace_dir = find_member_workspace(project_dir);
+  if (workspace_dir.empty()) {
+    return {};
+  }
+  std::filesystem::path workspace_file = get_workspace_config_path(workspace_dir);
+  if (workspace_file.empty() || std::filesystem::equivalent(workspace_file, project_file, ec)) {
+    return {};
+  }
+
+  try {
+    toml::table workspace_config = toml::parse_file(workspace_file.string());
+    const toml::table *defaults  = workspace_config["defaults"].as_table();
+    if (defaults == nullptr) {
+      return {};
+    }
+    return merge_workspace_defaults(project, *defaults);
+  } catch (const toml::parse_error &) {
+    // Reported when the workspace itself is loaded
+    return {};
+  }
+}
+
 std::filesystem::path get_workspace_config_path(const std::filesystem::path &workspace_path) {
   // First priority: Check for cforge.toml with [workspace] section
   std::filesystem::path unified_config_path = workspace_path / CFORGE_FILE;
@@ -5,6 +5,7 @@
 
 #include "cforge/log.hpp"
 
+#include "core/build_utils.hpp"
 #include "core/command.h"
 #include "core/commands.hpp"
 #include "core/file_system.h"
@@ -38,6 +39,36 @@ cforge_int_t cforge_init_context(cforge_int_t argc, cforge_string_t argv[], cfor
     cforge_set_verbosity(ctx->args.verbosity);
   }
 
+  // Machine-readable output (--format json). Other values are left to
+  // commands that define their own --format (e.g. `deps tree --format dot`).
+  if (ctx->args.format && strcmp(ctx->args.format, "json") == 0) {
+    cforge::logger::set_output_format(cforge::output_format::JSON);
+  }
+
+  // Colors: --color wins over NO_COLOR, which logger::use_color checks in auto mode
+  if (ctx->args.color) {
+    if (strcmp(ctx->args.color, "always") == 0) {
+      cforge::logger::set_color_mode(cforge::color_mode::ALWAYS);
+    } else if (strcmp(ctx->args.color, "never") == 0) {
+      cforge::logger::set_color_mode(cforge::color_mode::NEVER);
+    } else if (strcmp(ctx->args.color, "auto") != 0) {
+      cforge::logger::print_warning(std::string("unknown --color value '") + ctx->args.color
+                                    + "', expected auto, always or never");
+    }
+  }
+
+  // Offline mode (--offline or CFORGE_OFFLINE=1)
+  cforge_cstring_t env_offline = getenv("CFORGE_OFFLINE");
+  if (ctx->args.offline
+      || (env_offline && (strcmp(env_offline, "1") == 0 || strcmp(env_offline, "true") == 0))) {
+    cforge_set_offline(true);
+  }
+
+  // Base build directory override (--build-dir)
+  if (ctx->args.build_dir) {
+    cforge::set_build_dir_override(ctx->args.build_dir);
+  }
+
   // Get current working directory
   if (getcwd(ctx->working_dir, sizeof(ctx->working_dir)) == NULL) {
     cforge_print_error("Failed to get current directory");
@@ -0,0 +1,63 @@
+/**
+ * @file test_archive_format.cpp
+ * @brief Unit tests for archive dependency format detection
+ */
+
+#include "test_framework.h"
+#include "core/archive_format.hpp"
+
+using namespace cforge;
+
+TEST(ArchiveFormat, FullExtension) {
+    cf_assert(detect_archive_format("https://example.com/lib-1.0.tar.gz") == "tar.gz");
+    cf_assert(detect_archive_format("lib-1.0.tgz") == "tar.gz");
+    cf_assert(detect_archive_format("lib-1.0.tar.xz") == "tar.xz");
+    cf_assert(detect_archive_format("lib-1.0.TAR.BZ2") == "tar.bz2");
+    cf_assert(detect_archive_format("lib-1.0.7z") == "7z");
+    cf_assert(detect_archive_format("lib-1.0.zip?raw=true") == "zip");
+    return 0;
+}
+
+TEST(ArchiveFormat, UnsupportedOrMissing) {
+    // A bare compressed stream is not an archive
+    cf_assert(detect_archive_format("lib-1.0.xz").empty());
+    cf_assert(detect_archive_format("https://example.com/download?file=lib.tar.gz").empty());
+    cf_assert(detect_archive_format("lib-1.0.rar").empty());
+    return 0;
+}
+
+TEST(ArchiveFormat, ExplicitFormat) {
+    cf_assert(normalize_archive_format("tar.xz") == "tar.xz");
+    cf_assert(normalize_archive_format(".tgz") == "tar.gz");
+    cf_assert(normalize_archive_format("rar").empty());
+    return 0;
+}
+
+TEST(ArchiveFormat, DetectFromContent) {
+    cf_assert(detect_archive_format_from_header(std::string("PK\x03\x04rest", 8)) == "zip");
+    cf_assert(detect_archive_format_from_header(std::string("\x1F\x8B\x08\x00", 4)) == "tar.gz");
+    cf_assert(detect_archive_format_from_header("BZh91AY") == "tar.bz2");
+    cf_assert(detect_archive_format_from_header(std::string("\xFD" "7zXZ\x00\x00", 7)) == "tar.xz");
+
+    std::string tar(300, '\0');
+    tar.replace(257, 5, "ustar");
+    cf_assert(detect_archive_format_from_header(tar) == "tar");
+
+    // An HTML error page or a truncated file is not an archive
+    cf_assert(detect_archive_format_from_header("<!DOCTYPE html>").empty());
+    cf_assert(detect_archive_format_from_header("P").empty());
+    return 0;
+}
+
+TEST(ArchiveFormat, SniffCMakeMatchesSignatures) {
+    std::string cmake = generate_archive_sniff_cmake("lib", "https://example.com/download/42", "");
+    cf_assert(cmake.find("\"504b0304\"") != std::string::npos);
+    cf_assert(cmake.find("\"fd377a585a00\"") != std::string::npos);
+    cf_assert(cmake.find("OFFSET 257 LIMIT 5 HEX") != std::string::npos);
+    cf_assert(cmake.find("set(CFORGE_ARCHIVE_lib ") != std::string::npos);
+    cf_assert(cmake.find("EXPECTED_HASH") == std::string::npos);
+
+    std::string hashed = generate_archive_sniff_cmake("lib", "https://example.com/x", "ab12");
+    cf_assert(hashed.find("EXPECTED_HASH SHA256=ab12") != std::string::npos);
+    return 0;
+}
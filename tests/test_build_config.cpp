@@ -0,0 +1,102 @@
+/**
+ * @file test_build_config.cpp
+ * @brief Unit tests for build configuration names and per-config defines
+ */
+
+#include "test_framework.h"
+#include "core/build_config.hpp"
+
+#include <map>
+#include <string>
+#include <vector>
+
+using namespace cforge;
+
+static size_t count_occurrences(const std::string &text, const std::string &needle) {
+    size_t count = 0;
+    for (size_t pos = text.find(needle); pos != std::string::npos;
+         pos = text.find(needle, pos + needle.size())) {
+        ++count;
+    }
+    return count;
+}
+
+TEST(BuildConfig, CanonicalNames) {
+    cf_assert_eq(canonical_build_config("debug"), std::string("Debug"));
+    cf_assert_eq(canonical_build_config("RELEASE"), std::string("Release"));
+    cf_assert_eq(canonical_build_config("relwithdebinfo"), std::string("RelWithDebInfo"));
+    cf_assert_eq(canonical_build_config("minsizerel"), std::string("MinSizeRel"));
+    cf_assert_eq(canonical_build_config("Profile"), std::string("Profile"));
+    return 0;
+}
+
+TEST(BuildConfig, DefinesHaveOneSourcePerConfig) {
+    std::map<std::string, std::vector<std::string>> defines = {
+        {"debug", {"DEBUG=1"}},
+        {"relwithdebinfo", {"NDEBUG=1", "PROFILE"}},
+    };
+    std::string cmake = generate_config_defines_cmake(defines, "${PROJECT_NAME}", "PRIVATE");
+
+    cf_assert_eq(count_occurrences(cmake, "$<$<CONFIG:Debug>:DEBUG=1>"), static_cast<size_t>(1));
+    cf_assert_eq(count_occurrences(cmake, "$<$<CONFIG:RelWithDebInfo>:NDEBUG=1>"),
+                 static_cast<size_t>(1));
+    cf_assert_eq(count_occurrences(cmake, "target_compile_definitions"), static_cast<size_t>(2));
+    cf_assert_eq(count_occurrences(cmake, "CMAKE_BUILD_TYPE"), static_cast<size_t>(0));
+    cf_assert_eq(count_occurrences(cmake, "_FLAGS_"), static_cast<size_t>(0));
+    cf_assert(generate_config_defines_cmake({}, "${PROJECT_NAME}", "PRIVATE").empty());
+    return 0;
+}
+
+TEST(BuildConfig, CliDefines) {
+    cf_assert_eq(normalize_cli_define("TRACE"), std::string("TRACE=1"));
+    cf_assert_eq(normalize_cli_define("LEVEL=2"), std::string("LEVEL=2"));
+    cf_assert_eq(cli_defines_cache_arg({"TRACE=1", "LEVEL=2"}),
+                 std::string("-DCFORGE_CLI_DEFINES=TRACE=1;LEVEL=2"));
+    cf_assert_eq(cli_defines_cache_arg({}), std::string("-DCFORGE_CLI_DEFINES="));
+    return 0;
+}
+
+TEST(BuildConfig, CustomConfigBuildsOnStandardType) {
+    cf_assert(is_standard_cmake_build_type("relwithdebinfo"));
+    cf_assert(!is_standard_cmake_build_type("profiling"));
+
+    std::string cmake =
+        generate_config_build_type_cmake({{"profiling", "relwithdebinfo"}, {"checked", "Debug"}});
+    cf_assert(cmake.find("set(CMAKE_CXX_FLAGS_PROFILING \"${CMAKE_CXX_FLAGS_RELWITHDEBINFO}\")")
+              != std::string::npos);
+    cf_assert(cmake.find("set(CMAKE_EXE_LINKER_FLAGS_CHECKED \"${CMAKE_EXE_LINKER_FLAGS_DEBUG}\")")
+              != std::string::npos);
+    cf_assert(cmake.find("set(CMAKE_MAP_IMPORTED_CONFIG_PROFILING RelWithDebInfo Release "
+                         "MinSizeRel Debug)")
+              != std::string::npos);
+    cf_assert(cmake.find("list(APPEND CMAKE_CONFIGURATION_TYPES \"profiling\")")
+              != std::string::npos);
+    cf_assert(cmake.find("$<OR:$<CONFIG:Debug>,$<CONFIG:checked>>") != std::string::npos);
+    cf_assert(generate_config_build_type_cmake({}).empty());
+    return 0;
+}
+
+TEST(BuildConfig, MsvcRuntime) {
+    std::string cmake = generate_msvc_runtime_cmake("static", {}, {});
+    cf_assert(cmake.find("set(CMAKE_MSVC_RUNTIME_LIBRARY "
+                         "\"MultiThreaded$<$<CONFIG:Debug>:Debug>\")")
+              != std::string::npos);
+    cf_assert(cmake.find("message(WARNING") != std::string::npos);
+
+    cmake = generate_msvc_runtime_cmake("dynamic", {}, {});
+    cf_assert(cmake.find("\"MultiThreaded$<$<CONFIG:Debug>:Debug>DLL\"") != std::string::npos);
+
+    // Only Release is static; checked builds on Debug
+    cmake = generate_msvc_runtime_cmake(
+        "", {{"release", "static"}, {"debug", "dynamic"}}, {{"checked", "Debug"}});
+    cf_assert(cmake.find("\"MultiThreaded$<$<OR:$<CONFIG:Debug>,$<CONFIG:checked>>:Debug>"
+                         "$<$<NOT:$<OR:$<CONFIG:Release>>>:DLL>\"")
+              != std::string::npos);
+
+    cmake = generate_msvc_runtime_cmake("static", {{"debug", "dynamic"}}, {});
+    cf_assert(cmake.find("\"MultiThreaded$<$<CONFIG:Debug>:Debug>$<$<OR:$<CONFIG:Debug>>:DLL>\"")
+              != std::string::npos);
+
+    cf_assert(generate_msvc_runtime_cmake("", {}, {}).empty());
+    return 0;
+}
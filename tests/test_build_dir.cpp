@@ -0,0 +1,105 @@
+/**
+ * @file test_build_dir.cpp
+ * @brief Unit tests for build directory resolution and reuse checks
+ */
+
+#include "test_framework.h"
+#include "core/build_utils.hpp"
+#include "core/workspace_members.hpp"
+
+#include <filesystem>
+#include <fstream>
+#include <string>
+#include <vector>
+
+namespace fs = std::filesystem;
+using namespace cforge;
+
+// Helper to create a build directory with the given CMakeCache.txt lines
+static fs::path create_build_dir(const std::string &cache_content) {
+    fs::path dir = fs::temp_directory_path() / ("cforge_build_test_" + std::to_string(std::rand()));
+    fs::create_directories(dir);
+    if (!cache_content.empty()) {
+        std::ofstream cache(dir / "CMakeCache.txt");
+        cache << cache_content;
+    }
+    return dir;
+}
+
+TEST(BuildDir, MissingCacheNeedsConfigure) {
+    fs::path dir = create_build_dir("");
+    bool configured = is_build_dir_configured_for(dir, "Debug");
+    fs::remove_all(dir);
+
+    cf_assert(!configured);
+    return 0;
+}
+
+TEST(BuildDir, MatchingBuildType) {
+    fs::path dir = create_build_dir("# This is the CMakeCache file.\n"
+                                    "CMAKE_BUILD_TYPE:STRING=Debug\n");
+    bool debug   = is_build_dir_configured_for(dir, "Debug");
+    bool lower   = is_build_dir_configured_for(dir, "debug");
+    bool release = is_build_dir_configured_for(dir, "Release");
+    fs::remove_all(dir);
+
+    cf_assert(debug);
+    cf_assert(lower);
+    cf_assert(!release);
+    return 0;
+}
+
+TEST(BuildDir, MultiConfigGenerator) {
+    fs::path dir = create_build_dir("CMAKE_CONFIGURATION_TYPES:STRING=Debug;Release\n"
+                                    "CMAKE_BUILD_TYPE:STRING=\n");
+    bool configured = is_build_dir_configured_for(dir, "Release");
+    fs::remove_all(dir);
+
+    cf_assert(configured);
+    return 0;
+}
+
+TEST(BuildDir, CachedGeneratorSwitch) {
+    // Ninja -> Visual Studio -> Ninja on the same build directory
+    fs::path dir = create_build_dir("CMAKE_GENERATOR:INTERNAL=Ninja\n");
+    std::string first = get_cached_cmake_generator(dir);
+
+    std::ofstream(dir / "CMakeCache.txt") << "CMAKE_GENERATOR:INTERNAL=Visual Studio 17 2022\n"
+                                          << "CMAKE_CONFIGURATION_TYPES:STRING=Debug;Release\n";
+    std::string second = get_cached_cmake_generator(dir);
+
+    std::ofstream(dir / "CMakeCache.txt") << "CMAKE_GENERATOR:INTERNAL=Ninja\n";
+    std::string third = get_cached_cmake_generator(dir);
+    fs::remove_all(dir);
+
+    cf_assert(first == "Ninja");
+    cf_assert(second == "Visual Studio 17 2022");
+    cf_assert(third == "Ninja");
+    cf_assert(get_cached_cmake_generator(dir).empty());
+    return 0;
+}
+
+TEST(BuildDir, AbsoluteOverrideNestedPerWorkspaceMember) {
+    fs::path workspace = create_build_dir("");
+    fs::create_directories(workspace / "app");
+    fs::create_directories(workspace / "libs" / "core");
+    std::vector<std::string> members = {"app", "libs/core"};
+    fs::path shared = fs::temp_directory_path() / "cforge_shared_build";
+
+    auto resolve = [&](const fs::path &project, const std::string &name) {
+        bool member = is_workspace_member_dir(workspace, members, project);
+        return resolve_build_base_dir(project, shared.string(), member ? name : "");
+    };
+    fs::path app  = resolve(workspace / "app", "app");
+    fs::path core = resolve(workspace / "libs" / "core", "core");
+    fs::path dep  = resolve(workspace / "app" / "deps" / "fmt", "fmt");
+    fs::remove_all(workspace);
+
+    cf_assert(app == shared / "app");
+    cf_assert(core == shared / "core");
+    cf_assert(app != core);
+    cf_assert(dep == shared);
+    cf_assert(resolve_build_base_dir(workspace / "app", "build", "")
+              == workspace / "app" / "build");
+    return 0;
+}
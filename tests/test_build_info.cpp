@@ -0,0 +1,121 @@
+/**
+ * @file test_build_info.cpp
+ * @brief Unit tests for the generated build info header
+ */
+
+#include "test_framework.h"
+#include "core/build_info.hpp"
+
+#include <filesystem>
+#include <fstream>
+#include <sstream>
+#include <string>
+#include <vector>
+
+namespace fs = std::filesystem;
+using namespace cforge;
+
+static fs::path create_temp_dir() {
+    fs::path dir = fs::temp_directory_path() / ("cforge_build_info_" + std::to_string(std::rand()));
+    fs::create_directories(dir);
+    return dir;
+}
+
+static std::string read_file(const fs::path &path) {
+    std::ifstream in(path);
+    std::ostringstream content;
+    content << in.rdbuf();
+    return content.str();
+}
+
+static build_info_values sample_values() {
+    build_info_values values;
+    values.project_name    = "app";
+    values.project_version = "1.0.0";
+    values.git_sha         = "abc1234";
+    values.git_dirty       = "0";
+    return values;
+}
+
+TEST(BuildInfo, UnchangedValuesKeepHeader) {
+    fs::path dir    = create_temp_dir();
+    fs::path header = dir / "cforge_generated" / "cforge_build_info.h";
+
+    build_info_update first =
+        update_build_info_header(header, sample_values(), "2024-01-01T00:00:00Z");
+    std::string content = read_file(header);
+    auto written_time   = fs::last_write_time(header);
+
+    build_info_update second =
+        update_build_info_header(header, sample_values(), "2024-01-02T00:00:00Z");
+    std::string content_after = read_file(header);
+    auto time_after           = fs::last_write_time(header);
+    fs::remove_all(dir);
+
+    cf_assert(first == build_info_update::written);
+    cf_assert(second == build_info_update::unchanged);
+    cf_assert_eq(content_after, content);
+    cf_assert(time_after == written_time);
+    cf_assert(content.find("#define CFORGE_BUILD_TIMESTAMP \"2024-01-01T00:00:00Z\"")
+              != std::string::npos);
+    return 0;
+}
+
+TEST(BuildInfo, ChangedVersionRewritesHeader) {
+    fs::path dir    = create_temp_dir();
+    fs::path header = dir / "cforge_build_info.h";
+
+    update_build_info_header(header, sample_values(), "2024-01-01T00:00:00Z");
+    build_info_values values = sample_values();
+    values.project_version   = "1.1.0";
+    build_info_update result = update_build_info_header(header, values, "2024-01-02T00:00:00Z");
+    std::string content      = read_file(header);
+    fs::remove_all(dir);
+
+    cf_assert(result == build_info_update::written);
+    cf_assert(content.find("#define CFORGE_PROJECT_VERSION \"1.1.0\"") != std::string::npos);
+    cf_assert(content.find("\"1.0.0\"") == std::string::npos);
+    cf_assert(content.find("2024-01-02T00:00:00Z") != std::string::npos);
+    return 0;
+}
+
+TEST(BuildInfo, NonGitDirectoryIsUnknown) {
+    fs::path dir = create_temp_dir();
+    bool called  = false;
+    build_info_values values;
+    values.project_name    = "app";
+    values.project_version = "1.0.0";
+    read_build_info_git(values, dir, [&](const std::vector<std::string> &) {
+        called = true;
+        return git_result{};
+    });
+    fs::remove_all(dir);
+
+    cf_assert(!called);
+    cf_assert_eq(values.git_sha, std::string("unknown"));
+    cf_assert_eq(values.git_dirty, std::string("unknown"));
+    std::string defines = build_info_defines(values);
+    cf_assert(defines.find("#define CFORGE_GIT_SHA \"unknown\"") != std::string::npos);
+    cf_assert(defines.find("#define CFORGE_GIT_DIRTY \"unknown\"") != std::string::npos);
+    return 0;
+}
+
+TEST(BuildInfo, GitRepositoryValues) {
+    fs::path dir = create_temp_dir();
+    fs::create_directories(dir / ".git");
+    build_info_values values;
+    read_build_info_git(values, dir, [](const std::vector<std::string> &args) {
+        git_result result;
+        result.success   = true;
+        result.exit_code = 0;
+        result.output    = args[0] == "rev-parse" ? "abc1234\n" : " M src/main.cpp\n";
+        return result;
+    });
+    fs::remove_all(dir);
+
+    cf_assert_eq(values.git_sha, std::string("abc1234"));
+    cf_assert_eq(values.git_dirty, std::string("1"));
+    cf_assert(build_info_defines(values).find("#define CFORGE_GIT_DIRTY 1\n")
+              != std::string::npos);
+    return 0;
+}
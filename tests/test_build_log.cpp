@@ -0,0 +1,52 @@
+/**
+ * @file test_build_log.cpp
+ * @brief Unit tests for build log naming, rotation and tails
+ */
+
+#include "test_framework.h"
+#include "core/build_log.hpp"
+
+#include <string>
+#include <vector>
+
+using namespace cforge;
+
+TEST(BuildLog, RotationKeepsNewestOfEachKind) {
+    std::vector<std::string> files = {
+        "build-20261016-101500-000.log",
+        "configure-20261016-101400-000.log",
+        "build-20261016-101000-500_1.log",
+        "build-20261016-101000-500.log",
+        "build-20261016-102000-000.log",
+        "notes.txt"};
+
+    // Oldest first; a same-millisecond log sorts after the first one
+    std::vector<std::string> expected = {
+        "build-20261016-101000-500.log", "build-20261016-101000-500_1.log"};
+    cf_assert(stale_build_logs(files, "build", 2) == expected);
+    cf_assert(stale_build_logs(files, "configure", 5).empty());
+    cf_assert_eq(stale_build_logs(files, "build", 0).size(), static_cast<size_t>(4));
+
+    cf_assert_eq(newest_build_log(files, "build"), std::string("build-20261016-102000-000.log"));
+    cf_assert_eq(newest_build_log(files, "configure"),
+                 std::string("configure-20261016-101400-000.log"));
+    cf_assert_eq(newest_build_log(files, ""), std::string("build-20261016-102000-000.log"));
+    cf_assert(newest_build_log({"notes.txt"}, "").empty());
+    return 0;
+}
+
+TEST(BuildLog, LastLines) {
+    cf_assert_eq(last_lines("a\nb\nc\n", 2), std::string("b\nc"));
+    cf_assert_eq(last_lines("a\r\nb\r\n", 5), std::string("a\r\nb"));
+    cf_assert_eq(last_lines("only", 30), std::string("only"));
+    cf_assert_eq(last_lines("a\nb", 0), std::string(""));
+    cf_assert_eq(last_lines("", 3), std::string(""));
+    return 0;
+}
+
+TEST(BuildLog, TextAppendsStderr) {
+    cf_assert_eq(build_log_text("out", ""), std::string("out"));
+    cf_assert_eq(build_log_text("out", "err\n"), std::string("out\n--- stderr ---\nerr\n"));
+    cf_assert_eq(build_log_text("", "err"), std::string("--- stderr ---\nerr"));
+    return 0;
+}
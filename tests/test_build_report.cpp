@@ -0,0 +1,94 @@
+/**
+ * @file test_build_report.cpp
+ * @brief Unit tests for the per-project build timing report
+ */
+
+#include "test_framework.h"
+#include "core/build_report.hpp"
+
+#include <string>
+#include <vector>
+
+using namespace cforge;
+
+static file_timing make_timing(const std::string &action, double seconds) {
+    file_timing timing;
+    timing.action = action;
+    timing.duration_seconds = seconds;
+    return timing;
+}
+
+static build_report make_report(const std::vector<std::string> &names) {
+    build_report report;
+    for (const auto &name : names) {
+        project_build_times times;
+        times.name = name;
+        report.projects.push_back(times);
+    }
+    return report;
+}
+
+TEST(BuildReport, ClassifySteps) {
+    build_step compile =
+        classify_build_step("Building CXX object core/CMakeFiles/core.dir/src/core.cpp.o");
+    cf_assert(compile.kind == build_step_kind::COMPILE);
+    cf_assert(compile.target == "core");
+
+    build_step link = classify_build_step("Linking CXX static library lib/Debug/libcore.a");
+    cf_assert(link.kind == build_step_kind::LINK);
+    cf_assert(link.target == "libcore");
+
+    build_step msbuild = classify_build_step("  app.vcxproj -> C:\\work\\build\\bin\\app.exe");
+    cf_assert(msbuild.kind == build_step_kind::LINK);
+    cf_assert(msbuild.target == "app");
+
+    cf_assert(classify_build_step("Built target core").kind == build_step_kind::OTHER);
+    return 0;
+}
+
+TEST(BuildReport, AttributesStepsToProjects) {
+    build_report report = make_report({"core", "app", "tools"});
+    std::vector<file_timing> timings = {
+        make_timing("Building CXX object core/CMakeFiles/core.dir/src/a.cpp.o", 1.0),
+        make_timing("Building CXX object core/CMakeFiles/core.dir/src/b.cpp.o", 2.0),
+        make_timing("Linking CXX static library libcore.a", 0.5),
+        make_timing("Building CXX object app/CMakeFiles/app_tests.dir/tests/t.cpp.o", 1.5),
+        make_timing("Linking CXX executable bin/app_tests", 0.25),
+        make_timing("Building CXX object _deps/fmt-build/CMakeFiles/fmt.dir/format.cc.o", 4.0),
+    };
+    apply_build_timings(report, timings, true);
+
+    cf_assert(report.projects[0].compile_seconds == 3.0);
+    cf_assert(report.projects[0].link_seconds == 0.5);
+    cf_assert(report.projects[0].compiled_files == 2);
+    cf_assert(report.projects[1].compile_seconds == 1.5);
+    cf_assert(report.projects[1].status == project_build_status::BUILT);
+    cf_assert(report.projects[2].status == project_build_status::CACHED);
+    cf_assert(build_report_summary(report) == "2 built, 1 cached, 0 failed");
+    return 0;
+}
+
+TEST(BuildReport, FailedBuild) {
+    build_report report = make_report({"core", "app"});
+    std::vector<file_timing> timings = {
+        make_timing("Building CXX object core/CMakeFiles/core.dir/src/a.cpp.o", 1.0),
+        make_timing("Linking CXX static library libcore.a", 0.5),
+        make_timing("Building CXX object app/CMakeFiles/app.dir/src/main.cpp.o", 1.0),
+    };
+    apply_build_timings(report, timings, false);
+
+    cf_assert(report.projects[0].status == project_build_status::BUILT);
+    cf_assert(report.projects[1].status == project_build_status::FAILED);
+    cf_assert(build_report_summary(report) == "1 built, 0 cached, 1 failed");
+    return 0;
+}
+
+TEST(BuildReport, NoWorkIsCached) {
+    build_report report = make_report({"app"});
+    apply_build_timings(report, {make_timing("Built target app", 0.1)}, true);
+
+    cf_assert(report.projects[0].status == project_build_status::CACHED);
+    cf_assert(format_build_seconds(report.projects[0].compile_seconds) == "-");
+    cf_assert(format_build_seconds(1.234) == "1.23s");
+    return 0;
+}
@@ -0,0 +1,49 @@
+/**
+ * @file test_build_units.cpp
+ * @brief Unit tests for counting translation units in generated build systems
+ */
+
+#include "test_framework.h"
+#include "core/build_units.hpp"
+
+#include <string>
+
+using namespace cforge;
+
+TEST(BuildUnits, NinjaCompileStatements) {
+    std::string ninja =
+        "rule CXX_COMPILER__app_unscanned_Debug\n"
+        "  command = c++ $DEFINES $FLAGS -o $out -c $in\n"
+        "build CMakeFiles/app.dir/src/main.cpp.o: CXX_COMPILER__app_unscanned_Debug "
+        "/p/src/main.cpp || cmake_object_order_depends_target_app\n"
+        "build CMakeFiles/app.dir/gen/version.c.o: C_COMPILER__app_unscanned_Debug "
+        "/p/build/gen/version.c\n"
+        "build C$:/p/app.exe: CXX_EXECUTABLE_LINKER__app_Debug CMakeFiles/app.dir/src/main.cpp.o\n"
+        "build all: phony app\n";
+    cf_assert_eq(count_ninja_compile_units(ninja), 2);
+    cf_assert_eq(count_ninja_compile_units(""), 0);
+    return 0;
+}
+
+TEST(BuildUnits, CompileDatabaseCountsDistinctFiles) {
+    std::string json = R"([
+{ "directory": "/p/build", "command": "c++ -c main.cpp", "file": "/p/src/main.cpp" },
+{ "directory": "/p/build", "command": "c++ -c util.cpp", "file" : "/p/src/util.cpp" },
+{ "directory": "/p/build", "command": "c++ -DTEST -c main.cpp", "file": "/p/src/main.cpp" },
+{ "directory": "C:\\p\\build", "file": "C:\\p\\src\\win.cpp" }
+])";
+    cf_assert_eq(count_compile_database_units(json), 3);
+    return 0;
+}
+
+TEST(BuildUnits, MsbuildClCompileItems) {
+    std::string vcxproj = R"(<ItemGroup>
+    <ClInclude Include="C:\p\include\app.hpp" />
+    <ClCompile Include="C:\p\src\main.cpp" />
+    <ClCompile Include="C:\p\src\util.cpp">
+      <PreprocessorDefinitions>X=1</PreprocessorDefinitions>
+    </ClCompile>
+  </ItemGroup>)";
+    cf_assert_eq(count_msbuild_compile_units(vcxproj), 2);
+    return 0;
+}
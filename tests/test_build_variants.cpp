@@ -0,0 +1,150 @@
+/**
+ * @file test_build_variants.cpp
+ * @brief Unit tests for build variant inheritance and stacking
+ */
+
+#include "test_framework.h"
+#include "core/build_variants.hpp"
+
+#include <map>
+#include <string>
+#include <vector>
+
+using namespace cforge;
+
+namespace {
+
+build_variant make_variant(const std::string &name, const std::vector<std::string> &inherits) {
+  build_variant variant;
+  variant.name     = name;
+  variant.inherits = inherits;
+  return variant;
+}
+
+// asan and profiling both extend ci-base
+std::map<std::string, build_variant> sample_variants() {
+  build_variant base      = make_variant("ci-base", {});
+  base.description        = "Settings every CI build uses";
+  base.options.warnings   = "all";
+  base.options.debug_info = true;
+  base.defines            = {"CI=1", "LOG_LEVEL=1"};
+  base.flags              = {"-fno-omit-frame-pointer"};
+
+  build_variant asan      = make_variant("asan", {"ci-base"});
+  asan.options.sanitizers = {"address", "undefined"};
+  asan.options.optimize   = "debug";
+  asan.defines            = {"LOG_LEVEL=2"};
+
+  build_variant profiling    = make_variant("profiling", {"ci-base"});
+  profiling.options.optimize = "speed";
+  profiling.options.rtti     = false;
+  profiling.flags            = {"-pg"};
+
+  return {{"ci-base", base}, {"asan", asan}, {"profiling", profiling}};
+}
+
+}  // namespace
+
+TEST(BuildVariants, InheritsSettings) {
+    auto variants = sample_variants();
+    std::string error;
+    build_variant asan = resolve_build_variant(variants, "asan", error);
+    cf_assert(error.empty());
+    cf_assert_eq(asan.name, std::string("asan"));
+    cf_assert_eq(asan.options.warnings, std::string("all"));
+    cf_assert_eq(asan.options.optimize, std::string("debug"));
+    cf_assert(asan.options.debug_info);
+    cf_assert_eq(asan.options.sanitizers.size(), static_cast<size_t>(2));
+    // The variant's own LOG_LEVEL replaces the inherited one
+    std::vector<std::string> defines = {"CI=1", "LOG_LEVEL=2"};
+    cf_assert(asan.defines == defines);
+    cf_assert_eq(asan.flags[0], std::string("-fno-omit-frame-pointer"));
+    std::vector<std::string> inherits = {"ci-base"};
+    cf_assert(asan.inherits == inherits);
+    // The base itself is unchanged
+    cf_assert(variants.at("ci-base").options.sanitizers.empty());
+    return 0;
+}
+
+TEST(BuildVariants, InheritsFromSeveralVariants) {
+    auto variants = sample_variants();
+    variants["nightly"] = make_variant("nightly", {"asan", "profiling"});
+    std::string error;
+    build_variant nightly = resolve_build_variant(variants, "nightly", error);
+    cf_assert(error.empty());
+    // profiling is listed last, so its optimize wins; ci-base's settings
+    // appear once though both parents inherit them
+    cf_assert_eq(nightly.options.optimize, std::string("speed"));
+    cf_assert(!nightly.options.rtti);
+    std::vector<std::string> flags = {"-fno-omit-frame-pointer", "-pg"};
+    cf_assert(nightly.flags == flags);
+    std::vector<std::string> defines = {"CI=1", "LOG_LEVEL=1"};
+    cf_assert(nightly.defines == defines);
+    return 0;
+}
+
+TEST(BuildVariants, DetectsCycles) {
+    auto variants = sample_variants();
+    variants["ci-base"].inherits = {"profiling"};
+    std::string error;
+    resolve_build_variant(variants, "asan", error);
+    cf_assert_eq(error, std::string("variant inheritance cycle detected: ci-base -> profiling -> "
+                                    "ci-base"));
+
+    variants["self"] = make_variant("self", {"self"});
+    error.clear();
+    resolve_build_variant(variants, "self", error);
+    cf_assert_eq(error, std::string("variant inheritance cycle detected: self -> self"));
+    return 0;
+}
+
+TEST(BuildVariants, ReportsUnknownVariants) {
+    auto variants = sample_variants();
+    std::string error;
+    resolve_build_variant(variants, "tsan", error);
+    cf_assert_eq(error, std::string("variant 'tsan' is not defined"));
+
+    variants["coverage"] = make_variant("coverage", {"ci-bsae"});
+    error.clear();
+    resolve_build_variant(variants, "coverage", error);
+    cf_assert_eq(error, std::string("variant 'ci-bsae' is not defined ('coverage' inherits it)"));
+    return 0;
+}
+
+TEST(BuildVariants, StacksInOrder) {
+    auto variants = sample_variants();
+    std::string error;
+    build_variant stacked = resolve_build_variants(variants, {"profiling", "asan"}, error);
+    cf_assert(error.empty());
+    cf_assert_eq(stacked.name, std::string("profiling,asan"));
+    cf_assert_eq(stacked.options.optimize, std::string("debug"));
+    cf_assert(!stacked.options.rtti);
+
+    stacked = resolve_build_variants(variants, {"asan", "profiling"}, error);
+    cf_assert_eq(stacked.options.optimize, std::string("speed"));
+
+    resolve_build_variants(variants, {"asan", "tsan"}, error);
+    cf_assert_eq(error, std::string("variant 'tsan' is not defined"));
+    return 0;
+}
+
+TEST(BuildVariants, DescribesSettings) {
+    auto variants = sample_variants();
+    std::string error;
+    auto lines = describe_build_variant(resolve_build_variant(variants, "profiling", error));
+    std::vector<std::string> expected = {
+        "optimize: speed",
+        "warnings: all",
+        "debug_info: true",
+        "rtti: false",
+        "defines: CI=1 LOG_LEVEL=1",
+        "flags: -fno-omit-frame-pointer -pg",
+    };
+    cf_assert(lines == expected);
+    cf_assert(describe_build_variant(build_variant()).empty());
+
+    cf_assert_eq(variants_cache_arg({"asan", "profiling"}),
+                 std::string("-DCFORGE_VARIANTS=asan;profiling"));
+    cf_assert_eq(variants_cache_arg({}), std::string("-DCFORGE_VARIANTS="));
+    return 0;
+}
@@ -0,0 +1,72 @@
+/**
+ * @file test_cforge_tokens.cpp
+ * @brief Unit tests for cforge.toml token expansion
+ */
+
+#include "test_framework.h"
+#include "core/cforge_tokens.hpp"
+
+#include <map>
+#include <string>
+#include <vector>
+
+using namespace cforge;
+
+TEST(CforgeTokens, ExpandsKnownTokens) {
+    std::map<std::string, std::string> tokens = {
+        {"PROJECT_NAME", "app"},
+        {"VERSION", "1.2.0"},
+        {"TARGET", "app"},
+        {"CONFIG", "Release"},
+        {"OS", "linux"},
+        {"ARCH", "x64"},
+    };
+    cf_assert_eq(expand_cforge_tokens("out/${OS}-${ARCH}/${CONFIG}", tokens),
+                 std::string("out/linux-x64/Release"));
+    cf_assert_eq(expand_cforge_tokens("dist/${PROJECT_NAME}-${VERSION}/${TARGET}", tokens),
+                 std::string("dist/app-1.2.0/app"));
+    cf_assert_eq(expand_cforge_tokens("bin", tokens), std::string("bin"));
+    return 0;
+}
+
+TEST(CforgeTokens, KeepsUnknownAndUnterminatedTokens) {
+    std::map<std::string, std::string> tokens = {
+        {"CONFIG", "${cfg}"},
+    };
+    // CMake variables pass through, and a replacement is not expanded again
+    cf_assert_eq(expand_cforge_tokens("${CMAKE_BINARY_DIR}/bin/${CONFIG}", tokens),
+                 std::string("${CMAKE_BINARY_DIR}/bin/${cfg}"));
+    cf_assert_eq(expand_cforge_tokens("bin/${CONFIG", tokens), std::string("bin/${CONFIG"));
+    cf_assert_eq(expand_cforge_tokens("", tokens), std::string(""));
+    return 0;
+}
+
+TEST(CforgeTokens, ProjectTokens) {
+    auto tokens = make_cforge_tokens("geometry", "2.1.0", "Debug", "windows", "arm64");
+    cf_assert_eq(expand_cforge_tokens("${PROJECT}", tokens), std::string("geometry"));
+    cf_assert_eq(expand_cforge_tokens("${PROJECT_NAME}", tokens), std::string("geometry"));
+    cf_assert_eq(expand_cforge_tokens("${VERSION}", tokens), std::string("2.1.0"));
+    cf_assert_eq(expand_cforge_tokens("${TARGET}", tokens), std::string("geometry"));
+    cf_assert_eq(expand_cforge_tokens("${CONFIG}", tokens), std::string("Debug"));
+    cf_assert_eq(expand_cforge_tokens("${OS}", tokens), std::string("windows"));
+    cf_assert_eq(expand_cforge_tokens("${ARCH}", tokens), std::string("arm64"));
+    cf_assert_eq(expand_cforge_tokens("GEOMETRY_VERSION=\"${VERSION}\"", tokens),
+                 std::string("GEOMETRY_VERSION=\"2.1.0\""));
+
+    // Without a configuration, ${CONFIG} is left for a later expansion
+    tokens = make_cforge_tokens("geometry", "2.1.0", "", "linux", "x64");
+    cf_assert_eq(expand_cforge_tokens("third_party/${OS}/${CONFIG}", tokens),
+                 std::string("third_party/linux/${CONFIG}"));
+    return 0;
+}
+
+TEST(CforgeTokens, ExpandsLists) {
+    auto tokens = make_cforge_tokens("geometry", "2.1.0", "$<CONFIG>", "linux", "x64");
+    std::vector<std::string> dirs     = {"include", "third_party/${OS}-${ARCH}/include",
+                                         "build/${CONFIG}/gen"};
+    std::vector<std::string> expected = {"include", "third_party/linux-x64/include",
+                                         "build/$<CONFIG>/gen"};
+    cf_assert(expand_cforge_tokens(dirs, tokens) == expected);
+    cf_assert(expand_cforge_tokens(std::vector<std::string>(), tokens).empty());
+    return 0;
+}
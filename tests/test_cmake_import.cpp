@@ -0,0 +1,129 @@
+/**
+ * @file test_cmake_import.cpp
+ * @brief Unit tests for writing cforge.toml from a parsed CMakeLists.txt
+ */
+
+#include "test_framework.h"
+#include "core/cmake_import.hpp"
+
+#include <toml++/toml.hpp>
+
+#include <algorithm>
+#include <string>
+#include <vector>
+
+using namespace cforge;
+
+namespace {
+
+// What cmake_parser.cpp extracts from a typical single-executable project
+cmake_parse_result sample_project() {
+  cmake_parse_result r;
+  r.project_name  = "geometry";
+  r.version       = "1.4.0";
+  r.cpp_standard  = "20";
+  r.binary_type   = "executable";
+  r.target_name   = "geometry";
+  r.other_targets = {"geometry_tests"};
+  r.source_dirs   = {"src"};
+  r.include_dirs  = {"include"};
+  r.compile_definitions = {"GEOMETRY_USE_DOUBLE"};
+  r.compile_options     = {"-Wall", "$<$<CONFIG:Debug>:-O0>"};
+  r.link_libraries      = {"m", "geometry_tests", "Eigen3::Eigen"};
+  r.dependency_targets  = {"Threads::Threads", "Boost::filesystem", "OpenSSL::SSL",
+                           "OpenSSL::Crypto", "fmt::fmt"};
+
+  cmake_dependency threads;
+  threads.name            = "Threads";
+  threads.is_find_package = true;
+  cmake_dependency boost;
+  boost.name            = "Boost";
+  boost.version         = "1.80";
+  boost.components      = {"filesystem"};
+  boost.is_find_package = true;
+  cmake_dependency openssl;
+  openssl.name            = "OpenSSL";
+  openssl.is_find_package = true;
+  cmake_dependency zlib;
+  zlib.name            = "ZLIB";
+  zlib.is_find_package = true;
+  cmake_dependency fmt;
+  fmt.name             = "fmt";
+  fmt.git_url          = "https://github.com/fmtlib/fmt.git";
+  fmt.git_tag          = "11.1.4";
+  fmt.is_fetch_content = true;
+  cmake_dependency vendored;
+  vendored.name            = "tinyxml";
+  vendored.is_subdirectory = true;
+  r.dependencies           = {threads, boost, openssl, zlib, fmt, vendored};
+  return r;
+}
+
+bool reported(const cmake_import_report &report, const std::string &text) {
+  return std::any_of(report.untranslated.begin(), report.untranslated.end(),
+                     [&](const std::string &item) {
+                         return item.find(text) != std::string::npos;
+                     });
+}
+
+}  // namespace
+
+TEST(CmakeImport, WritesManifest) {
+    cmake_import_report report;
+    std::string content = generate_imported_manifest(sample_project(), report);
+
+    toml::table config;
+    try {
+        config = toml::parse(content);
+    } catch (const toml::parse_error &) {
+        cf_assert(false);
+    }
+    cf_assert_eq(config["project"]["name"].value_or(std::string()), std::string("geometry"));
+    cf_assert_eq(config["project"]["version"].value_or(std::string()), std::string("1.4.0"));
+    cf_assert_eq(config["project"]["binary_type"].value_or(std::string()),
+                 std::string("executable"));
+
+    cf_assert_eq(config["build"]["source_dirs"][0].value_or(std::string()), std::string("src"));
+    cf_assert_eq(config["build"]["include_dirs"][0].value_or(std::string()),
+                 std::string("include"));
+    cf_assert_eq(config["build"]["system_links"][0].value_or(std::string()), std::string("m"));
+    cf_assert_eq(config["build"]["system_links"].as_array()->size(), static_cast<size_t>(1));
+    cf_assert(!config["build"]["flags"]);
+    cf_assert(content.find("# flags = [\"-Wall\"]") != std::string::npos);
+    cf_assert(content.find("# system_links = [\"Eigen3::Eigen\"]") != std::string::npos);
+
+    const toml::array *system = config["dependencies"]["system"].as_array();
+    cf_assert(system != nullptr);
+    cf_assert_eq(system->size(), static_cast<size_t>(4));
+    cf_assert_eq((*system)[0].value_or(std::string()), std::string("Threads"));
+    cf_assert_eq(config["dependencies"]["system"][1]["components"][0].value_or(std::string()),
+                 std::string("filesystem"));
+    cf_assert_eq(config["dependencies"]["system"][2]["target"].value_or(std::string()),
+                 std::string("OpenSSL::SSL OpenSSL::Crypto"));
+    cf_assert_eq((*system)[3].value_or(std::string()), std::string("ZLIB"));
+    cf_assert(content.find("find_package(Boost 1.80) required a version") != std::string::npos);
+
+    cf_assert_eq(config["dependencies"]["fetchcontent"]["fmt"]["tag"].value_or(std::string()),
+                 std::string("11.1.4"));
+    cf_assert_eq(
+        config["dependencies"]["fetchcontent"]["fmt"]["target_name"].value_or(std::string()),
+        std::string("fmt::fmt"));
+    return 0;
+}
+
+TEST(CmakeImport, ReportsWhatWasNotTranslated) {
+    cmake_import_report report;
+    generate_imported_manifest(sample_project(), report);
+
+    cf_assert(reported(report, "target 'geometry_tests' was not imported"));
+    cf_assert(reported(report, "link to target 'geometry_tests'"));
+    cf_assert(reported(report, "compile option '$<$<CONFIG:Debug>:-O0>'"));
+    cf_assert(reported(report, "compile options -Wall are left in a TODO"));
+    cf_assert(reported(report, "link to 'Eigen3::Eigen'"));
+    cf_assert(reported(report, "find_package(ZLIB) has no linked target"));
+    cf_assert(reported(report, "add_subdirectory(tinyxml)"));
+
+    cf_assert_eq(report.translated[0].first, std::string("project"));
+    cf_assert_eq(report.translated[0].second, std::string("geometry 1.4.0"));
+    return 0;
+}
@@ -0,0 +1,91 @@
+/**
+ * @file test_cmake_presets.cpp
+ * @brief Unit tests for CMakePresets.json generation
+ */
+
+#include "test_framework.h"
+#include "core/cmake_presets.hpp"
+
+#include <algorithm>
+#include <string>
+#include <vector>
+
+using namespace cforge;
+
+TEST(CMakePresets, ParseDefineArg) {
+    std::string name;
+    cmake_cache_value value;
+    cf_assert(parse_cmake_define_arg("-DFOO=1", name, value));
+    cf_assert_eq(name, std::string("FOO"));
+    cf_assert_eq(value.value, std::string("1"));
+    cf_assert(value.type.empty());
+
+    cf_assert(parse_cmake_define_arg("-DBUILD_TESTS:BOOL=ON", name, value));
+    cf_assert_eq(name, std::string("BUILD_TESTS"));
+    cf_assert_eq(value.type, std::string("BOOL"));
+    cf_assert_eq(value.value, std::string("ON"));
+
+    cf_assert(parse_cmake_define_arg("-DEMPTY=", name, value));
+    cf_assert_eq(value.value, std::string(""));
+
+    cf_assert(!parse_cmake_define_arg("-G", name, value));
+    cf_assert(!parse_cmake_define_arg("--warn-uninitialized", name, value));
+    return 0;
+}
+
+TEST(CMakePresets, RoundTripMatchesClassicArgs) {
+    // What `cforge build -c Release` passes to cmake for a Ninja project
+    std::vector<std::string> classic = {
+        "-S", "/p", "-B", "/p/build",
+        "-DRELEASE_ONLY=ON",
+        "-DCMAKE_CXX_STANDARD=17",
+        "-DOPT:STRING=fast",
+        "-DCMAKE_TOOLCHAIN_FILE=/vcpkg/scripts/buildsystems/vcpkg.cmake",
+        "-DOPT:STRING=faster",
+        "-G", "Ninja",
+        "-DCMAKE_BUILD_TYPE=Release"};
+
+    cmake_configure_preset preset = cmake_preset_from_args("release", "Release", classic);
+    cf_assert_eq(preset.generator, std::string("Ninja"));
+    cf_assert(preset.architecture.empty());
+    cf_assert_eq(preset.cache_variables.size(), static_cast<size_t>(5));
+
+    // The preset configures the same cache as the classic command line: the
+    // last definition of a variable wins in both
+    std::vector<std::string> expected = {
+        "-DCMAKE_BUILD_TYPE=Release",
+        "-DCMAKE_CXX_STANDARD=17",
+        "-DCMAKE_TOOLCHAIN_FILE=/vcpkg/scripts/buildsystems/vcpkg.cmake",
+        "-DOPT:STRING=faster",
+        "-DRELEASE_ONLY=ON"};
+    cf_assert(cmake_args_from_preset(preset) == expected);
+
+    cmake_configure_preset again =
+        cmake_preset_from_args("release", "Release", cmake_args_from_preset(preset));
+    cf_assert(again.cache_variables == preset.cache_variables);
+    return 0;
+}
+
+TEST(CMakePresets, JsonIsStableAndSorted) {
+    std::vector<std::string> args = {
+        "-G", "Visual Studio 17 2022", "-A", "x64", "-DZ=1", "-DA:BOOL=ON"};
+    cmake_configure_preset debug = cmake_preset_from_args("debug", "Debug", args);
+    debug.display_name = "Debug";
+    debug.binary_dir   = "${sourceDir}/build";
+
+    std::string json = generate_cmake_presets_json({debug});
+    cf_assert(json == generate_cmake_presets_json({debug}));
+    cf_assert(is_cforge_generated_presets(json));
+    cf_assert(!is_cforge_generated_presets("{\"version\": 3}"));
+
+    auto at = [&](const std::string &text) { return json.find(text); };
+    cf_assert(at("\"buildPresets\"") < at("\"cmakeMinimumRequired\""));
+    cf_assert(at("\"configurePresets\"") < at("\"testPresets\""));
+    cf_assert(at("\"testPresets\"") < at("\"version\": 3"));
+    cf_assert(at("\"A\": {") < at("\"Z\": \"1\""));
+    cf_assert(at("\"architecture\"") < at("\"binaryDir\": \"${sourceDir}/build\""));
+    cf_assert(at("\"generator\": \"Visual Studio 17 2022\"") != std::string::npos);
+    cf_assert(at("\"configurePreset\": \"debug\"") != std::string::npos);
+    cf_assert(at("\"configuration\": \"Debug\"") != std::string::npos);
+    return 0;
+}
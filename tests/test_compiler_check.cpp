@@ -0,0 +1,55 @@
+/**
+ * @file test_compiler_check.cpp
+ * @brief Tests for compiler identification and [project] min_compiler checks
+ */
+
+#include "core/compiler_check.hpp"
+#include "test_framework.h"
+
+TEST(CompilerCheck, IdentifiesCompilers) {
+    auto gcc = cforge::parse_compiler_identity(
+        "g++ (Ubuntu 12.3.0-1ubuntu1~22.04) 12.3.0\n"
+        "Copyright (C) 2022 Free Software Foundation, Inc.\n");
+    cf_assert_eq(gcc.family, std::string("gcc"));
+    cf_assert_eq(gcc.version, std::string("12.3.0"));
+
+    auto clang = cforge::parse_compiler_identity(
+        "Ubuntu clang version 15.0.7\nTarget: x86_64-pc-linux-gnu\n");
+    cf_assert_eq(clang.family, std::string("clang"));
+    cf_assert_eq(clang.version, std::string("15.0.7"));
+
+    auto apple = cforge::parse_compiler_identity(
+        "Apple clang version 14.0.3 (clang-1403.0.22.14.1)\n");
+    cf_assert_eq(apple.family, std::string("apple_clang"));
+
+    auto msvc = cforge::parse_compiler_identity(
+        "Microsoft (R) C/C++ Optimizing Compiler Version 19.36.32532 for x64\n"
+        "usage: cl [ option... ] filename... [ /link linkoption... ]\n");
+    cf_assert_eq(msvc.family, std::string("msvc"));
+    cf_assert_eq(msvc.version, std::string("19.36.32532"));
+
+    cf_assert(cforge::parse_compiler_identity("command not found").family.empty());
+    return 0;
+}
+
+TEST(CompilerCheck, ComparesVersions) {
+    cf_assert(cforge::compiler_version_at_least("12.3.0", "12.0"));
+    cf_assert(cforge::compiler_version_at_least("12", "12.0.0"));
+    cf_assert(cforge::compiler_version_at_least("19.36.32532", "19.36"));
+    cf_assert(!cforge::compiler_version_at_least("9.4.0", "12"));
+    cf_assert(!cforge::compiler_version_at_least("19.29.30133", "19.36"));
+
+    std::map<std::string, std::string> min_compiler = {{"gcc", "12.0"}, {"clang", "15"}};
+    cf_assert_eq(cforge::required_compiler_version(min_compiler, "gcc"), std::string("12.0"));
+    // Apple Clang versions don't follow LLVM's
+    cf_assert(cforge::required_compiler_version(min_compiler, "apple_clang").empty());
+    return 0;
+}
+
+TEST(CompilerCheck, StampRoundTrips) {
+    cforge::compiler_identity compiler{"gcc", "12.3.0", "/usr/bin/g++"};
+    auto read = cforge::parse_compiler_stamp(cforge::compiler_stamp_text(compiler));
+    cf_assert_eq(read.label(), std::string("gcc 12.3.0"));
+    cf_assert_eq(read.path, compiler.path);
+    return 0;
+}
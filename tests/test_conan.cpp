@@ -0,0 +1,45 @@
+/**
+ * @file test_conan.cpp
+ * @brief Tests for the generated conanfile.txt and conan install arguments
+ */
+
+#include "core/conan.hpp"
+#include "test_framework.h"
+
+TEST(Conan, ParsesMajorVersion) {
+    cf_assert_eq(cforge::parse_conan_major_version("Conan version 2.0.17\n"), 2);
+    cf_assert_eq(cforge::parse_conan_major_version("Conan version 1.62.0"), 1);
+    cf_assert_eq(cforge::parse_conan_major_version("conan: command not found"), 0);
+    return 0;
+}
+
+TEST(Conan, GeneratorsFollowMajorVersion) {
+    std::string v2 = cforge::generate_conanfile_txt(2, {"fmt/10.2.1", "zlib/1.3.1"});
+    cf_assert(v2.find("[requires]\nfmt/10.2.1\nzlib/1.3.1\n") != std::string::npos);
+    cf_assert(v2.find("[generators]\nCMakeDeps\nCMakeToolchain\n") != std::string::npos);
+
+    std::string v1 = cforge::generate_conanfile_txt(1, {"fmt/10.2.1"});
+    cf_assert(v1.find("[generators]\ncmake_find_package\ncmake_paths\n") != std::string::npos);
+    cf_assert(v1.find("CMakeDeps") == std::string::npos);
+
+    cf_assert_eq(cforge::conan_toolchain_file_name(2), std::string("conan_toolchain.cmake"));
+    cf_assert_eq(cforge::conan_toolchain_file_name(1), std::string("conan_paths.cmake"));
+    return 0;
+}
+
+TEST(Conan, InstallArgsFollowMajorVersion) {
+    std::filesystem::path dir = "build/conan";
+    auto v2 = cforge::conan_install_args(2, dir, "Debug");
+    cf_assert_eq(v2.size(), static_cast<size_t>(6));
+    cf_assert_eq(v2[1], dir.string());
+    cf_assert_eq(v2[2], "--output-folder=" + dir.string());
+    cf_assert_eq(v2[5], std::string("build_type=Debug"));
+
+    auto v1 = cforge::conan_install_args(1, dir, "Release");
+    cf_assert_eq(v1[2], "--install-folder=" + dir.string());
+
+    // Conan only knows the standard build types
+    auto custom = cforge::conan_install_args(2, dir, "Profile");
+    cf_assert_eq(custom.size(), static_cast<size_t>(4));
+    return 0;
+}
@@ -0,0 +1,159 @@
+/**
+ * @file test_config_interpolation.cpp
+ * @brief Unit tests for environment variable and ~ expansion in cforge.toml values
+ */
+
+#include "test_framework.h"
+#include "core/config_interpolation.hpp"
+
+#include <map>
+#include <string>
+#include <vector>
+
+using namespace cforge;
+
+namespace {
+
+env_lookup fake_env(std::map<std::string, std::string> vars) {
+    return [vars](const std::string &name) -> std::optional<std::string> {
+        auto it = vars.find(name);
+        if (it == vars.end()) {
+            return std::nullopt;
+        }
+        return it->second;
+    };
+}
+
+std::string expand(const std::string &value, const env_lookup &env) {
+    std::string out;
+    std::string missing;
+    interpolate_config_string(value, env, out, missing);
+    return out;
+}
+
+}  // namespace
+
+TEST(ConfigInterpolation, ExpandsSetVariables) {
+    env_lookup env = fake_env({{"SDK_ROOT", "/opt/sdk"}, {"TRIPLE", "arm64"}});
+
+    cf_assert_eq(expand("${SDK_ROOT}/include", env), std::string("/opt/sdk/include"));
+    cf_assert_eq(expand("${env:SDK_ROOT}/lib/$TRIPLE", env), std::string("/opt/sdk/lib/arm64"));
+    cf_assert_eq(expand("$TRIPLE-linux", env), std::string("arm64-linux"));
+    return 0;
+}
+
+TEST(ConfigInterpolation, LeavesUnsetAndCMakeReferences) {
+    env_lookup env = fake_env({});
+
+    cf_assert_eq(expand("${PROJECT_NAME}_lib", env), std::string("${PROJECT_NAME}_lib"));
+    cf_assert_eq(expand("$ORIGIN/../lib", env), std::string("$ORIGIN/../lib"));
+    cf_assert_eq(expand("$<CONFIG:Debug>", env), std::string("$<CONFIG:Debug>"));
+    cf_assert_eq(expand("cost: $5", env), std::string("cost: $5"));
+    cf_assert_eq(expand("trailing $", env), std::string("trailing $"));
+    return 0;
+}
+
+TEST(ConfigInterpolation, CforgeTokensAreNotTakenFromTheEnvironment) {
+    env_lookup env = fake_env({{"OS", "Windows_NT"}, {"CONFIG", "x"}});
+
+    cf_assert_eq(expand("${OS}-${ARCH}/data", env), std::string("${OS}-${ARCH}/data"));
+    cf_assert_eq(expand("$CONFIG", env), std::string("$CONFIG"));
+    cf_assert_eq(expand("${env:OS}", env), std::string("Windows_NT"));
+    return 0;
+}
+
+TEST(ConfigInterpolation, DefaultsApplyWhenUnset) {
+    env_lookup env = fake_env({{"SET", "yes"}});
+
+    cf_assert_eq(expand("${env:UNSET:-/usr/local}", env), std::string("/usr/local"));
+    cf_assert_eq(expand("${UNSET:-}", env), std::string(""));
+    cf_assert_eq(expand("${SET:-no}", env), std::string("yes"));
+    return 0;
+}
+
+TEST(ConfigInterpolation, RequiredVariableMustBeSet) {
+    std::string out;
+    std::string missing;
+
+    cf_assert(!interpolate_config_string("${env:VCPKG_ROOT}/x", fake_env({}), out, missing));
+    cf_assert_eq(missing, std::string("VCPKG_ROOT"));
+    return 0;
+}
+
+TEST(ConfigInterpolation, ExpandsLeadingTilde) {
+    env_lookup env = fake_env({{"HOME", "/home/dev"}});
+
+    cf_assert_eq(expand("~/sdk", env), std::string("/home/dev/sdk"));
+    cf_assert_eq(expand("~", env), std::string("/home/dev"));
+    cf_assert_eq(expand("~1.2", env), std::string("~1.2"));
+    cf_assert_eq(expand("a/~/b", env), std::string("a/~/b"));
+    cf_assert_eq(expand("~/sdk", fake_env({{"USERPROFILE", "C:\\Users\\dev"}})),
+                 std::string("C:\\Users\\dev/sdk"));
+    return 0;
+}
+
+TEST(ConfigInterpolation, TableValuesAreExpandedInPlace) {
+    toml::table config = toml::parse(R"(
+[project]
+name = "app"
+
+[build]
+cmake_args = ["-DSDK=${SDK_ROOT}", "-DNAME=${PROJECT_NAME}"]
+
+[platform.linux]
+includes = ["~/include"]
+
+[dependencies.git.foo]
+url = "${GIT_HOST}/foo.git"
+
+[cmake]
+inject_before_target = 'set(SDK $ENV{SDK_ROOT})'
+inject_after_target = """
+target_include_directories(${PROJECT_NAME} PRIVATE ${SDK_ROOT}/include)
+"""
+
+[scripts]
+setup = "echo $SDK_ROOT"
+)");
+
+    std::vector<config_interpolation_error> errors = interpolate_config_table(
+        config,
+        fake_env({{"SDK_ROOT", "/opt/sdk"}, {"HOME", "/home/dev"}, {"GIT_HOST", "https://git"}}));
+
+    cf_assert(errors.empty());
+    cf_assert_eq(config["build"]["cmake_args"][0].value_or(std::string()),
+                 std::string("-DSDK=/opt/sdk"));
+    cf_assert_eq(config["build"]["cmake_args"][1].value_or(std::string()),
+                 std::string("-DNAME=${PROJECT_NAME}"));
+    cf_assert_eq(config["platform"]["linux"]["includes"][0].value_or(std::string()),
+                 std::string("/home/dev/include"));
+    cf_assert_eq(config["dependencies"]["git"]["foo"]["url"].value_or(std::string()),
+                 std::string("https://git/foo.git"));
+    cf_assert_eq(config["scripts"]["setup"].value_or(std::string()),
+                 std::string("echo $SDK_ROOT"));
+    cf_assert_eq(config["cmake"]["inject_before_target"].value_or(std::string()),
+                 std::string("set(SDK $ENV{SDK_ROOT})"));
+    cf_assert_eq(config["cmake"]["inject_after_target"].value_or(std::string()),
+                 std::string("target_include_directories(${PROJECT_NAME} PRIVATE "
+                             "${SDK_ROOT}/include)\n"));
+    return 0;
+}
+
+TEST(ConfigInterpolation, ErrorsNameTheKey) {
+    toml::table config = toml::parse(R"(
+[build]
+cmake_args = ["-DOK=1", "-DTOOLS=${env:TOOLS_DIR}"]
+
+[dependencies.git.foo]
+url = "${env:GIT_HOST}/foo.git"
+)");
+
+    std::vector<config_interpolation_error> errors = interpolate_config_table(config, fake_env({}));
+
+    cf_assert_eq(errors.size(), static_cast<size_t>(2));
+    cf_assert_eq(errors[0].key, std::string("build.cmake_args[1]"));
+    cf_assert_eq(errors[0].variable, std::string("TOOLS_DIR"));
+    cf_assert_eq(errors[1].key, std::string("dependencies.git.foo.url"));
+    cf_assert_eq(errors[1].variable, std::string("GIT_HOST"));
+    return 0;
+}
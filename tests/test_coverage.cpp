@@ -0,0 +1,45 @@
+/**
+ * @file test_coverage.cpp
+ * @brief Unit tests for coverage flags and report arguments
+ */
+
+#include "test_framework.h"
+#include "core/coverage.hpp"
+
+#include <string>
+#include <vector>
+
+using namespace cforge;
+
+TEST(Coverage, CompilerFamilyPicksToolchain) {
+    cf_assert_eq(coverage_compiler_family("/usr/bin/clang++-17", "gcc"), std::string("clang"));
+    cf_assert_eq(coverage_compiler_family("x86_64-linux-gnu-g++-13", "clang"), std::string("gcc"));
+    cf_assert_eq(coverage_compiler_family("C:\\LLVM\\bin\\clang-cl.exe", "gcc"),
+                 std::string("msvc"));
+    cf_assert_eq(coverage_compiler_family("c++", "clang"), std::string("clang"));
+    cf_assert_eq(coverage_compiler_family("", "gcc"), std::string("gcc"));
+
+    cf_assert_eq(coverage_toolchain_for("gcc").flags, std::string("--coverage"));
+    cf_assert_eq(coverage_toolchain_for("gcc").report, std::string("gcovr"));
+    cf_assert_eq(coverage_toolchain_for("clang").flags,
+                 std::string("-fprofile-instr-generate -fcoverage-mapping"));
+    cf_assert(coverage_toolchain_for("msvc").flags.empty());
+    return 0;
+}
+
+TEST(Coverage, ReportArguments) {
+    auto gcovr = gcovr_report_args("/p/build/coverage", "/p/coverage", {"src", "include"});
+    std::vector<std::string> expected = {"--root", ".", "--object-directory", "/p/build/coverage",
+                                         "--filter", "src/", "--filter", "include/",
+                                         "--html-details", "/p/coverage/index.html",
+                                         "--txt", "/p/coverage/coverage.txt", "--print-summary"};
+    cf_assert(gcovr == expected);
+
+    auto show = llvm_cov_report_args("show", {"a_test", "b_test"}, "p.profdata", "out", "tests/");
+    expected  = {"show", "a_test", "-object", "b_test", "-instr-profile=p.profdata",
+                 "-ignore-filename-regex=tests/", "-format=html", "-output-dir=out"};
+    cf_assert(show == expected);
+    cf_assert_eq(llvm_cov_report_args("report", {"a_test"}, "p.profdata", "out", "x").size(),
+                 static_cast<size_t>(4));
+    return 0;
+}
@@ -0,0 +1,88 @@
+/**
+ * @file test_cross_targets.cpp
+ * @brief Unit tests for predefined cross targets and toolchain files
+ */
+
+#include "test_framework.h"
+#include "core/cross_targets.hpp"
+
+#include <string>
+
+using namespace cforge;
+
+TEST(CrossTargets, WindowsMingwToolchain) {
+    auto target = get_predefined_cross_target("windows-mingw");
+    cf_assert(target.has_value());
+    cf_assert(!get_predefined_cross_target("windows").has_value());
+
+    std::string file = generate_cross_toolchain_file("windows-mingw", *target);
+    auto has = [&](const std::string &text) { return file.find(text) != std::string::npos; };
+    cf_assert(has("set(CMAKE_SYSTEM_NAME Windows)\n"));
+    cf_assert(has("set(CMAKE_SYSTEM_PROCESSOR x86_64)\n"));
+    cf_assert(has("set(CMAKE_C_COMPILER \"x86_64-w64-mingw32-gcc\")\n"));
+    cf_assert(has("set(CMAKE_CXX_COMPILER \"x86_64-w64-mingw32-g++\")\n"));
+    cf_assert(has("set(CMAKE_RC_COMPILER \"x86_64-w64-mingw32-windres\")\n"));
+    cf_assert(has("set(CMAKE_FIND_ROOT_PATH \"/usr/x86_64-w64-mingw32\")\n"));
+    cf_assert(has("set(CMAKE_FIND_ROOT_PATH_MODE_PROGRAM NEVER)\n"));
+    return 0;
+}
+
+TEST(CrossTargets, FindRootDefaultsToSysroot) {
+    cross_target_settings target;
+    target.system           = "Linux";
+    target.sysroot          = "C:\\sysroots\\rpi";
+    target.variables["REV"] = "3";
+
+    std::string file = generate_cross_toolchain_file("rpi", target);
+    cf_assert(file.find("set(CMAKE_SYSROOT \"C:/sysroots/rpi\")\n") != std::string::npos);
+    cf_assert(file.find("set(CMAKE_FIND_ROOT_PATH \"C:/sysroots/rpi\")\n") != std::string::npos);
+    cf_assert(file.find("set(REV \"3\")\n") != std::string::npos);
+    cf_assert(file.find("CMAKE_C_COMPILER") == std::string::npos);
+    cf_assert(file.find("CMAKE_RC_COMPILER") == std::string::npos);
+    return 0;
+}
+
+TEST(CrossTargets, AndroidUsesNdkToolchainWhenKnown) {
+    auto target = get_predefined_cross_target("android-arm64", "/opt/android-ndk");
+    cf_assert(target.has_value());
+
+    std::string file   = generate_cross_toolchain_file("android-arm64", *target);
+    cforge_size_t abi  = file.find("set(ANDROID_ABI \"arm64-v8a\")\n");
+    cforge_size_t ndk  = file.find("include(\"/opt/android-ndk/build/cmake/android.toolchain");
+    cforge_size_t name = file.find("set(CMAKE_SYSTEM_NAME Android)\n");
+    cf_assert(abi != std::string::npos);
+    cf_assert(ndk != std::string::npos);
+    cf_assert(name != std::string::npos);
+    cf_assert(abi < ndk);
+    cf_assert(ndk < name);
+    return 0;
+}
+
+TEST(CrossTargets, AndroidFallsBackToCMakeSupport) {
+    auto target = get_predefined_cross_target("android-arm64");
+    cf_assert(target.has_value());
+    cf_assert(target->toolchain.empty());
+
+    std::string file = generate_cross_toolchain_file("android-arm64", *target);
+    cf_assert(file.find("include(") == std::string::npos);
+    cf_assert(file.find("set(CMAKE_SYSTEM_NAME Android)\n") != std::string::npos);
+    cf_assert(file.find("set(CMAKE_ANDROID_ARCH_ABI \"arm64-v8a\")\n") != std::string::npos);
+    return 0;
+}
+
+TEST(CrossTargets, WasmUsesEmscriptenToolchain) {
+    auto target = get_predefined_cross_target("wasm", "", "/emsdk/upstream/emscripten");
+    cf_assert(target.has_value());
+
+    std::string file = generate_cross_toolchain_file("wasm", *target);
+    cf_assert(file.find("include(\"/emsdk/upstream/emscripten/cmake/Modules/Platform/"
+                        "Emscripten.cmake\")\n")
+              != std::string::npos);
+    cf_assert(file.find("set(CFORGE_WASM_SUFFIX \".js\")\n") != std::string::npos);
+    cf_assert(file.find("CMAKE_C_COMPILER") == std::string::npos);
+
+    auto fallback = get_predefined_cross_target("wasm");
+    cf_assert(fallback->toolchain.empty());
+    cf_assert_eq(fallback->cxx, std::string("em++"));
+    return 0;
+}
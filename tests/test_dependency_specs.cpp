@@ -0,0 +1,68 @@
+/**
+ * @file test_dependency_specs.cpp
+ * @brief Unit tests for checking the dependency specs given to `cforge add`
+ */
+
+#include "test_framework.h"
+#include "core/dependency_specs.hpp"
+
+#include <string>
+#include <vector>
+
+using namespace cforge;
+
+TEST(DependencySpecs, GitUrls) {
+    cf_assert(is_valid_git_url("https://github.com/fmtlib/fmt.git"));
+    cf_assert(is_valid_git_url("ssh://git@example.com/team/lib"));
+    cf_assert(is_valid_git_url("git@github.com:fmtlib/fmt.git"));
+    cf_assert(is_valid_git_url("file:///srv/git/lib.git"));
+    cf_assert(!is_valid_git_url(""));
+    cf_assert(!is_valid_git_url("fmt"));
+    cf_assert(!is_valid_git_url("https://github.com"));
+    cf_assert(!is_valid_git_url("https://github.com/fmt lib/fmt"));
+    cf_assert(!is_valid_git_url("ftp://example.com/lib.git"));
+    return 0;
+}
+
+TEST(DependencySpecs, GitUrlToName) {
+    cf_assert_eq(git_url_to_name("https://github.com/fmtlib/fmt.git"), std::string("fmt"));
+    cf_assert_eq(git_url_to_name("https://github.com/gabime/spdlog/"), std::string("spdlog"));
+    cf_assert_eq(git_url_to_name("git@example.com:json.git"), std::string("json"));
+    return 0;
+}
+
+TEST(DependencySpecs, VcpkgSpec) {
+    std::string name;
+    std::vector<std::string> features;
+    std::string triplet;
+
+    cf_assert(parse_vcpkg_spec("fmt", name, features, triplet).empty());
+    cf_assert_eq(name, std::string("fmt"));
+    cf_assert(features.empty());
+    cf_assert(triplet.empty());
+
+    cf_assert(parse_vcpkg_spec("curl[ssl,http2]:x64-windows-static", name, features, triplet)
+                  .empty());
+    cf_assert_eq(name, std::string("curl"));
+    cf_assert_eq(features.size(), static_cast<size_t>(2));
+    cf_assert_eq(features[1], std::string("http2"));
+    cf_assert_eq(triplet, std::string("x64-windows-static"));
+
+    cf_assert(!parse_vcpkg_spec("Fmt", name, features, triplet).empty());
+    cf_assert(!parse_vcpkg_spec("fmt[ssl", name, features, triplet).empty());
+    cf_assert(!parse_vcpkg_spec("-fmt", name, features, triplet).empty());
+    return 0;
+}
+
+TEST(DependencySpecs, VcpkgSpecTriplet) {
+    std::string name;
+    std::vector<std::string> features;
+    std::string triplet;
+    cf_assert(parse_vcpkg_spec("fmt:arm64-osx", name, features, triplet).empty());
+    cf_assert(!parse_vcpkg_spec("fmt:x64", name, features, triplet).empty());
+    cf_assert(!parse_vcpkg_spec("fmt:X64-Windows", name, features, triplet).empty());
+    cf_assert(!parse_vcpkg_spec("fmt:", name, features, triplet).empty());
+    cf_assert(parse_vcpkg_spec("fmt:x64--windows", name, features, triplet).find("triplet")
+              != std::string::npos);
+    return 0;
+}
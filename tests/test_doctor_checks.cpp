@@ -0,0 +1,53 @@
+/**
+ * @file test_doctor_checks.cpp
+ * @brief Unit tests for `cforge doctor` version and cforge.toml checks
+ */
+
+#include "test_framework.h"
+#include "core/doctor_checks.hpp"
+
+#include <string>
+#include <vector>
+
+using namespace cforge;
+
+TEST(DoctorChecks, VersionAtLeast) {
+    cf_assert(version_at_least("3.28.1", "3.15"));
+    cf_assert(version_at_least("3.15", "3.15.0"));
+    cf_assert(version_at_least("3.16.0-rc1", "3.15"));
+    cf_assert(!version_at_least("3.10.2", "3.15"));
+    cf_assert(!version_at_least("", "3.15"));
+    return 0;
+}
+
+TEST(DoctorChecks, CMakeVersion) {
+    cf_assert_eq(parse_cmake_version("cmake version 3.28.1\n\nCMake suite maintained and "
+                                     "supported by Kitware (kitware.com/cmake).\n"),
+                 std::string("3.28.1"));
+    cf_assert_eq(parse_cmake_version("cmake version 3.31.0-rc2\n"), std::string("3.31.0"));
+    cf_assert_eq(parse_cmake_version("cmake: command not found"), std::string(""));
+
+    cf_assert_eq(required_cmake_version("3.15", ""), std::string("3.15"));
+    cf_assert_eq(required_cmake_version("3.15", "3.10"), std::string("3.15"));
+    cf_assert_eq(required_cmake_version("3.15", "3.25"), std::string("3.25"));
+    cf_assert(!version_at_least("3.13.4", required_cmake_version("3.15", "3.10")));
+    return 0;
+}
+
+TEST(DoctorChecks, UnknownManifestKeys) {
+    toml::table config = toml::parse(R"(
+[project]
+name = "app"
+
+[dependancies]
+fmt = "11.1.4"
+
+[frobnicate]
+enabled = true
+)");
+    std::vector<std::string> unknown = find_unknown_manifest_keys(config);
+    cf_assert_eq(unknown.size(), static_cast<size_t>(2));
+    cf_assert_eq(unknown[0], std::string("dependancies (did you mean 'dependencies'?)"));
+    cf_assert_eq(unknown[1], std::string("frobnicate"));
+    return 0;
+}
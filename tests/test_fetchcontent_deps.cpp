@@ -0,0 +1,76 @@
+/**
+ * @file test_fetchcontent_deps.cpp
+ * @brief Tests for the CMake generated for [dependencies.fetchcontent] entries
+ */
+
+#include "core/fetchcontent_deps.hpp"
+#include "test_framework.h"
+
+TEST(FetchContentDeps, RecognizesCommitSha) {
+    cf_assert(cforge::is_git_commit_sha("0123456789abcdef0123456789ABCDEF01234567"));
+    cf_assert(!cforge::is_git_commit_sha("v1.14.0"));
+    cf_assert(!cforge::is_git_commit_sha("0123456"));
+    cf_assert(!cforge::is_git_commit_sha(""));
+    return 0;
+}
+
+TEST(FetchContentDeps, OptionsAreTypedCacheVariables) {
+    cf_assert_eq(cforge::fetchcontent_option_cmake("GLFW_BUILD_TESTS", "OFF"),
+                 std::string("set(GLFW_BUILD_TESTS OFF CACHE BOOL \"\" FORCE)\n"));
+    cf_assert_eq(cforge::fetchcontent_option_cmake("SPDLOG_FMT_EXTERNAL", "on"),
+                 std::string("set(SPDLOG_FMT_EXTERNAL ON CACHE BOOL \"\" FORCE)\n"));
+    cf_assert_eq(cforge::fetchcontent_option_cmake("JSON_Install", "true"),
+                 std::string("set(JSON_Install TRUE CACHE BOOL \"\" FORCE)\n"));
+    cf_assert_eq(cforge::fetchcontent_option_cmake("LIB_BACKEND", "say \"hi\""),
+                 std::string("set(LIB_BACKEND \"say \\\"hi\\\"\" CACHE STRING \"\" FORCE)\n"));
+    return 0;
+}
+
+TEST(FetchContentDeps, GitDependencySetsOptionsBeforeMakeAvailable) {
+    cforge::fetchcontent_dependency dep;
+    dep.name                          = "googletest";
+    dep.git                           = "https://github.com/google/googletest.git";
+    dep.tag                           = "v1.14.0";
+    dep.options["INSTALL_GTEST"]      = "OFF";
+    dep.options["gtest_force_shared"] = "ON";
+
+    std::string cmake = cforge::generate_fetchcontent_dependency_cmake(dep);
+    cf_assert(cmake.find("FetchContent_Declare(googletest\n") != std::string::npos);
+    cf_assert(cmake.find("    GIT_REPOSITORY https://github.com/google/googletest.git\n")
+              != std::string::npos);
+    cf_assert(cmake.find("    GIT_TAG v1.14.0\n") != std::string::npos);
+    cf_assert(cmake.find("    GIT_SHALLOW 1\n") != std::string::npos);
+
+    size_t option    = cmake.find("set(INSTALL_GTEST OFF CACHE BOOL");
+    size_t available = cmake.find("FetchContent_MakeAvailable(googletest)");
+    cf_assert(option != std::string::npos);
+    cf_assert(available != std::string::npos);
+    cf_assert(cmake.find(")\n", cmake.find("FetchContent_Declare")) < option);
+    cf_assert(option < available);
+    return 0;
+}
+
+TEST(FetchContentDeps, CommitShaIsNotShallow) {
+    cforge::fetchcontent_dependency dep;
+    dep.name = "lib";
+    dep.git  = "https://example.com/lib.git";
+    dep.tag  = "0123456789abcdef0123456789abcdef01234567";
+
+    std::string cmake = cforge::generate_fetchcontent_dependency_cmake(dep);
+    cf_assert(cmake.find("GIT_TAG " + dep.tag) != std::string::npos);
+    cf_assert(cmake.find("GIT_SHALLOW") == std::string::npos);
+    return 0;
+}
+
+TEST(FetchContentDeps, UrlDependencyChecksHash) {
+    cforge::fetchcontent_dependency dep;
+    dep.name   = "json";
+    dep.url    = "https://example.com/json.tar.xz";
+    dep.sha256 = "abc123";
+
+    std::string cmake = cforge::generate_fetchcontent_dependency_cmake(dep);
+    cf_assert(cmake.find("    URL \"https://example.com/json.tar.xz\"\n") != std::string::npos);
+    cf_assert(cmake.find("    URL_HASH SHA256=abc123\n") != std::string::npos);
+    cf_assert(cmake.find("GIT_REPOSITORY") == std::string::npos);
+    return 0;
+}
@@ -0,0 +1,23 @@
+/**
+ * @file test_git_utils.cpp
+ * @brief Unit tests for recognizing git authentication failures
+ */
+
+#include "test_framework.h"
+#include "core/git_utils.hpp"
+
+#include <string>
+
+using namespace cforge;
+
+TEST(GitUtils, AuthenticationErrors) {
+    cf_assert(is_git_authentication_error(
+        "fatal: could not read Username for 'https://github.com': terminal prompts disabled\n"));
+    cf_assert(is_git_authentication_error(
+        "git@github.com: Permission denied (publickey).\nfatal: Could not read from remote\n"));
+    cf_assert(is_git_authentication_error("Host key verification failed.\n"));
+    cf_assert(!is_git_authentication_error(
+        "fatal: repository 'https://github.com/a/b.git/' not found\n"));
+    cf_assert(!is_git_authentication_error(""));
+    return 0;
+}
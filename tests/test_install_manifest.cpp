@@ -0,0 +1,99 @@
+/**
+ * @file test_install_manifest.cpp
+ * @brief Unit tests for the install records used by `cforge uninstall`
+ */
+
+#include "test_framework.h"
+#include "core/install_manifest.hpp"
+
+#include <filesystem>
+#include <string>
+#include <vector>
+
+using namespace cforge;
+
+TEST(InstallManifest, ParsesCMakeManifest) {
+    std::vector<std::string> files =
+        parse_cmake_install_manifest("/usr/local/bin/app\r\n/usr/local/lib/libcore.a\n\n");
+
+    cf_assert_eq(files.size(), static_cast<size_t>(2));
+    cf_assert_eq(files[0], std::string("/usr/local/bin/app"));
+    cf_assert_eq(files[1], std::string("/usr/local/lib/libcore.a"));
+    return 0;
+}
+
+TEST(InstallManifest, RecordRoundTrips) {
+    install_record record;
+    record.project = "app";
+    record.version = "1.2.0";
+    record.config  = "Release";
+    record.prefix  = "/home/dev/.local";
+    record.date    = "2026-10-16T12:00:00Z";
+    record.files   = {"/home/dev/.local/bin/app", "/home/dev/.local/include/app/app.hpp"};
+
+    auto parsed = parse_install_record(format_install_record(record));
+
+    cf_assert(parsed.has_value());
+    cf_assert_eq(parsed->project, record.project);
+    cf_assert_eq(parsed->version, record.version);
+    cf_assert_eq(parsed->config, record.config);
+    cf_assert_eq(parsed->prefix, record.prefix);
+    cf_assert_eq(parsed->date, record.date);
+    cf_assert(parsed->files == record.files);
+    cf_assert(!parse_install_record("version = 1.0\n\n/x\n").has_value());
+    return 0;
+}
+
+TEST(InstallManifest, RecordFileNameIsSafe) {
+    std::string name = install_record_file_name("app", "1.2.0", "/usr/local");
+    cf_assert_eq(name.rfind("app-1.2.0-", 0), static_cast<size_t>(0));
+    cf_assert_eq(name.size(), std::string("app-1.2.0-01234567.manifest").size());
+    cf_assert_eq(install_record_file_name("app", "", "/usr/local").rfind("app-", 0),
+                 static_cast<size_t>(0));
+    cf_assert_eq(install_record_file_name("a/b", "1 0", "/usr/local").rfind("a_b-1_0-", 0),
+                 static_cast<size_t>(0));
+    return 0;
+}
+
+TEST(InstallManifest, RecordFileNamePerPrefix) {
+    std::string usr_local = install_record_file_name("app", "1.2.0", "/usr/local");
+    std::string opt_app   = install_record_file_name("app", "1.2.0", "/opt/app");
+    cf_assert(usr_local != opt_app);
+    cf_assert_eq(install_record_file_name("app", "1.2.0", "/usr/local"), usr_local);
+    return 0;
+}
+
+TEST(InstallManifest, DetectsFilesOutsidePrefix) {
+    install_record record;
+    record.prefix = "/opt/app";
+    record.files  = {"/opt/app/bin/app",
+                     "/opt/app/../etc/app.conf",
+                     "/opt/application/bin/tool",
+                     "/etc/app.conf"};
+
+    std::vector<std::string> outside = files_outside_prefix(record);
+
+    cf_assert_eq(outside.size(), static_cast<size_t>(3));
+    cf_assert_eq(outside[0], std::string("/opt/app/../etc/app.conf"));
+    cf_assert_eq(outside[1], std::string("/opt/application/bin/tool"));
+    cf_assert_eq(outside[2], std::string("/etc/app.conf"));
+    return 0;
+}
+
+TEST(InstallManifest, DirectoriesAreDeepestFirstAndInsidePrefix) {
+    install_record record;
+    record.prefix = "/opt/app";
+    record.files  = {"/opt/app/include/app/detail/impl.hpp",
+                     "/opt/app/include/app/app.hpp",
+                     "/opt/app/bin/app",
+                     "/etc/app.conf"};
+
+    std::vector<std::filesystem::path> dirs = install_record_directories(record);
+
+    cf_assert_eq(dirs.size(), static_cast<size_t>(4));
+    cf_assert_eq(dirs[0].generic_string(), std::string("/opt/app/include/app/detail"));
+    cf_assert_eq(dirs[1].generic_string(), std::string("/opt/app/include/app"));
+    cf_assert_eq(dirs[2].generic_string(), std::string("/opt/app/bin"));
+    cf_assert_eq(dirs[3].generic_string(), std::string("/opt/app/include"));
+    return 0;
+}
@@ -0,0 +1,177 @@
+/**
+ * @file test_linker_diagnostics.cpp
+ * @brief Unit tests for grouping undefined symbols in linker output
+ */
+
+#include "test_framework.h"
+#include "core/linker_diagnostics.hpp"
+
+#include <string>
+#include <vector>
+
+using namespace cforge;
+
+namespace {
+
+// g++ 13 with GNU ld 2.42
+const char *GCC_OUTPUT = R"log(/usr/bin/ld: CMakeFiles/app.dir/src/main.cpp.o: in function `main':
+main.cpp:(.text+0x1d): undefined reference to `geometry::area(double)'
+main.cpp:(.text+0x3a): undefined reference to `geometry::area(double)'
+main.cpp:(.text+0x52): undefined reference to `fmt::v10::vformat(fmt::v10::basic_string_view<char>, fmt::v10::basic_format_args<fmt::v10::basic_format_context<fmt::v10::appender, char> >)'
+/usr/bin/ld: CMakeFiles/app.dir/src/report.cpp.o: in function `report()':
+report.cpp:(.text+0x11): undefined reference to `geometry::area(double)'
+report.cpp:(.text+0x2e): undefined reference to `checksum(unsigned char const*, unsigned long)'
+/usr/bin/ld: CMakeFiles/app.dir/src/shape.cpp.o:(.data.rel.ro._ZTI5Shape+0x10): undefined reference to `typeinfo for Shape'
+collect2: error: ld returned 1 exit status
+ninja: build stopped: subcommand failed.
+)log";
+
+// clang++ 17 with ld.lld
+const char *CLANG_LLD_OUTPUT = R"log([2/2] Linking CXX executable app
+FAILED: app
+ld.lld: error: undefined symbol: geometry::area(double)
+>>> referenced by main.cpp:7 (src/main.cpp:7)
+>>>               CMakeFiles/app.dir/src/main.cpp.o:(main)
+>>> referenced by main.cpp:8 (src/main.cpp:8)
+>>>               CMakeFiles/app.dir/src/main.cpp.o:(main)
+>>> referenced 2 more times
+
+ld.lld: error: undefined symbol: curl_easy_init
+>>> referenced by net.cpp:12 (src/net.cpp:12)
+>>>               CMakeFiles/app.dir/src/net.cpp.o:(fetch())
+clang++: error: linker command failed with exit code 1 (use -v to see invocation)
+)log";
+
+// Apple clang 15 with ld64
+const char *CLANG_LD64_OUTPUT = R"log(Undefined symbols for architecture arm64:
+  "geometry::area(double)", referenced from:
+      _main in main.cpp.o
+      report() in report.cpp.o
+  "_checksum", referenced from:
+      verify(char const*) in verify.c.o
+ld: symbol(s) not found for architecture arm64
+clang: error: linker command failed with exit code 1 (use -v to see invocation)
+)log";
+
+// MSVC 19.38 LINK
+const char *MSVC_OUTPUT =
+    R"log(main.obj : error LNK2019: unresolved external symbol "double __cdecl geometry::area(double)" (?area@geometry@@YANN@Z) referenced in function main
+report.obj : error LNK2001: unresolved external symbol "double __cdecl geometry::area(double)" (?area@geometry@@YANN@Z)
+main.obj : error LNK2019: unresolved external symbol __imp_MessageBoxW referenced in function main
+shape.obj : error LNK2001: unresolved external symbol ??_7Shape@@6B@
+app.exe : fatal error LNK1120: 3 unresolved externals
+)log";
+
+}  // namespace
+
+TEST(LinkerDiagnostics, DemangleMsvcNames) {
+    cf_assert_eq(demangle_msvc_name("?area@geometry@@YANN@Z"), std::string("geometry::area"));
+    cf_assert_eq(demangle_msvc_name("?helper@@YAXXZ"), std::string("helper"));
+    cf_assert_eq(demangle_msvc_name("?count@@3HA"), std::string("count"));
+    cf_assert_eq(demangle_msvc_name("??0Widget@ui@@QEAA@XZ"), std::string("ui::Widget::Widget"));
+    cf_assert_eq(demangle_msvc_name("??1Widget@@QEAA@XZ"), std::string("Widget::~Widget"));
+    cf_assert_eq(demangle_msvc_name("??_7Shape@@6B@"), std::string("Shape::`vftable'"));
+    cf_assert_eq(demangle_msvc_name("__imp_?run@app@@YAHXZ"), std::string("app::run"));
+    cf_assert_eq(demangle_msvc_name("?get@?A0x1b2c3d4e@@YAHXZ"),
+                 std::string("`anonymous namespace'::get"));
+    // Templates and plain C names are left alone
+    cf_assert(demangle_msvc_name("??$max@H@std@@YAAEBHAEBH0@Z").empty());
+    cf_assert(demangle_msvc_name("MessageBoxW").empty());
+    cf_assert(demangle_msvc_name("?broken").empty());
+    return 0;
+}
+
+TEST(LinkerDiagnostics, GroupsGnuLdReferences) {
+    auto symbols = find_undefined_symbols(GCC_OUTPUT);
+    cf_assert_eq(symbols.size(), static_cast<size_t>(4));
+
+    cf_assert_eq(symbols[0].symbol, std::string("geometry::area(double)"));
+    cf_assert_eq(symbols[0].count, 3);
+    std::vector<std::string> from = {"main", "report()"};
+    cf_assert(symbols[0].referenced_from == from);
+
+    cf_assert(symbols[1].symbol.rfind("fmt::v10::vformat(", 0) == 0);
+    cf_assert_eq(symbols[2].symbol, std::string("checksum(unsigned char const*, unsigned long)"));
+    cf_assert_eq(symbols[3].symbol, std::string("typeinfo for Shape"));
+    cf_assert_eq(symbols[3].referenced_from[0],
+                 std::string("CMakeFiles/app.dir/src/shape.cpp.o"));
+
+    cf_assert(is_undefined_symbol_line("/usr/bin/ld: main.o: in function `main':"));
+    cf_assert(!is_undefined_symbol_line("collect2: error: ld returned 1 exit status"));
+    return 0;
+}
+
+TEST(LinkerDiagnostics, GroupsLldReferences) {
+    auto symbols = find_undefined_symbols(CLANG_LLD_OUTPUT);
+    cf_assert_eq(symbols.size(), static_cast<size_t>(2));
+    cf_assert_eq(symbols[0].symbol, std::string("geometry::area(double)"));
+    cf_assert_eq(symbols[0].count, 4);
+    std::vector<std::string> from = {"main.cpp:7", "main.cpp:8"};
+    cf_assert(symbols[0].referenced_from == from);
+    cf_assert_eq(symbols[1].symbol, std::string("curl_easy_init"));
+    cf_assert_eq(symbols[1].count, 1);
+    return 0;
+}
+
+TEST(LinkerDiagnostics, GroupsLd64References) {
+    auto symbols = find_undefined_symbols(CLANG_LD64_OUTPUT);
+    cf_assert_eq(symbols.size(), static_cast<size_t>(2));
+    cf_assert_eq(symbols[0].symbol, std::string("geometry::area(double)"));
+    cf_assert_eq(symbols[0].count, 2);
+    std::vector<std::string> from = {"main", "report()"};
+    cf_assert(symbols[0].referenced_from == from);
+    cf_assert_eq(symbols[1].symbol, std::string("checksum"));
+    return 0;
+}
+
+TEST(LinkerDiagnostics, GroupsMsvcReferences) {
+    auto symbols = find_undefined_symbols(MSVC_OUTPUT);
+    cf_assert_eq(symbols.size(), static_cast<size_t>(3));
+    cf_assert_eq(symbols[0].symbol, std::string("double __cdecl geometry::area(double)"));
+    cf_assert_eq(symbols[0].decorated, std::string("?area@geometry@@YANN@Z"));
+    cf_assert_eq(symbols[0].count, 2);
+    cf_assert_eq(undefined_symbol_name(symbols[0]), std::string("geometry::area"));
+    cf_assert_eq(symbols[1].symbol, std::string("MessageBoxW"));
+    cf_assert_eq(symbols[2].symbol, std::string("Shape::`vftable'"));
+    return 0;
+}
+
+TEST(LinkerDiagnostics, Advice) {
+    auto gcc = find_undefined_symbols(GCC_OUTPUT);
+
+    // A missing source file in the project
+    auto advice = advise_undefined_symbol(gcc[0]);
+    cf_assert_eq(advice.notes.size(), static_cast<size_t>(1));
+    cf_assert_eq(advice.notes[0], std::string("referenced from: main, report()"));
+    cf_assert(advice.help.find("'geometry::area'") != std::string::npos);
+    cf_assert(advice.help.find("build.source_dirs") != std::string::npos);
+
+    // A known vcpkg package
+    advice = advise_undefined_symbol(gcc[1]);
+    cf_assert_eq(advice.notes.back(), std::string("try linking: fmt::fmt (vcpkg package 'fmt')"));
+    cf_assert(advice.help.find("cforge deps add fmt --vcpkg") != std::string::npos);
+
+    // A C function declared without extern "C"
+    advice = advise_undefined_symbol(gcc[2]);
+    cf_assert(advice.notes.back().find("extern \"C\"") != std::string::npos);
+
+    // A virtual function without a definition
+    advice = advise_undefined_symbol(gcc[3]);
+    cf_assert(advice.help.find("virtual function of 'Shape'") != std::string::npos);
+
+    auto msvc = find_undefined_symbols(MSVC_OUTPUT);
+    advice    = advise_undefined_symbol(msvc[1], "user32.lib");
+    cf_assert_eq(advice.notes.back(), std::string("try linking: user32.lib"));
+    advice = advise_undefined_symbol(msvc[2]);
+    cf_assert(advice.help.find("virtual function of 'Shape'") != std::string::npos);
+
+    // A C++ function called from C
+    auto ld64 = find_undefined_symbols(CLANG_LD64_OUTPUT);
+    advice    = advise_undefined_symbol(ld64[1]);
+    cf_assert(advice.notes.back().find("called from C") != std::string::npos);
+
+    cf_assert(find_symbol_library("myfmt::print()") == nullptr);
+    cf_assert_eq(std::string(find_symbol_library("curl_easy_init")->package),
+                 std::string("curl"));
+    return 0;
+}
@@ -317,3 +317,68 @@ TEST(Lockfile, VcpkgWithTriplet) {
 
     return 0;
 }
+
+// Test: A git dependency is only pinned while its URL and ref are unchanged
+TEST(Lockfile, LockedGitCommit) {
+    fs::path temp = create_temp_dir();
+
+    const char* content = R"(
+[dependency.fmt]
+source_type = "git"
+url = "https://github.com/fmtlib/fmt.git"
+version = "master"
+resolved = "0c9fce2ffefecfdce794e1859584e25877b7b592"
+
+[dependency.json]
+source = "git"
+url = "https://github.com/nlohmann/json.git"
+version = "develop"
+resolved = "develop"
+)";
+    write_test_lockfile(temp, content);
+
+    lockfile lock;
+    lock.load(temp);
+    cleanup_temp_dir(temp);
+
+    const std::string fmt_url = "https://github.com/fmtlib/fmt.git";
+    test_assert(locked_git_commit(lock, "fmt", fmt_url, "master")
+                == "0c9fce2ffefecfdce794e1859584e25877b7b592");
+    test_assert(locked_git_commit(lock, "fmt", fmt_url, "11.1.4").empty());
+    test_assert(locked_git_commit(lock, "fmt", "https://example.com/fmt.git", "master").empty());
+    // Not a commit hash, so there is nothing to pin to
+    test_assert(locked_git_commit(lock, "json", "https://github.com/nlohmann/json.git", "develop")
+                    .empty());
+    test_assert(locked_git_commit(lock, "spdlog", "", "v1.x").empty());
+
+    return 0;
+}
+
+// Test: Installed versions are read from `vcpkg list`
+TEST(Lockfile, ParseVcpkgList) {
+    const std::string output =
+        "curl:x64-linux                  8.6.0#2          A library for transferring data\n"
+        "curl[ssl]:x64-linux                              Default SSL backend\n"
+        "fmt:x64-linux                   10.2.1#1         Formatting library\n"
+        "No packages are installed.\n";
+
+    auto versions = parse_vcpkg_list(output);
+    test_assert(versions.size() == 2);
+    test_assert(versions["curl"] == "8.6.0#2");
+    test_assert(versions["fmt"] == "10.2.1#1");
+
+    return 0;
+}
+
+// Test: Conan dependencies resolve to a name/version reference
+TEST(Lockfile, ConanReference) {
+    lockfile lock;
+    lock.lock_conan_dependency("zlib", "1.3.1");
+
+    auto dep = lock.get_dependency("zlib");
+    test_assert(dep.has_value());
+    test_assert(dep->source_type == "conan");
+    test_assert(dep->resolved == "zlib/1.3.1");
+
+    return 0;
+}
@@ -0,0 +1,208 @@
+/**
+ * @file test_manifest_edit.cpp
+ * @brief Unit tests for the cforge.toml edits of `cforge config`, `add` and `remove`
+ */
+
+#include "test_framework.h"
+#include "core/manifest_edit.hpp"
+
+#include <filesystem>
+#include <fstream>
+#include <sstream>
+#include <string>
+
+using namespace cforge;
+
+TEST(ManifestEdit, ReplacesValueAndKeepsComment) {
+    std::string content = "# My project\n"
+                          "[project]\n"
+                          "name = \"app\"\n"
+                          "\n"
+                          "[build]\n"
+                          "  build_type = \"Debug\"  # local default\n"
+                          "source_dirs = [\n"
+                          "  \"src\",\n"
+                          "]\n";
+    cf_assert(set_manifest_value(content, "build.build_type", "\"Release\""));
+    cf_assert(set_manifest_value(content, "build.source_dirs", "[\"src\", \"gen\"]"));
+    cf_assert_eq(content,
+                 std::string("# My project\n"
+                             "[project]\n"
+                             "name = \"app\"\n"
+                             "\n"
+                             "[build]\n"
+                             "  build_type = \"Release\"  # local default\n"
+                             "source_dirs = [\"src\", \"gen\"]\n"));
+    return 0;
+}
+
+TEST(ManifestEdit, AddsKeysAndTables) {
+    std::string content = "[project]\n"
+                          "name = \"app\"\n"
+                          "\n"
+                          "[build]\n"
+                          "build_type = \"Debug\"\n"
+                          "\n"
+                          "[build.config.release]\n"
+                          "defines = [\"NDEBUG\"]\n";
+    cf_assert(set_manifest_value(content, "build.include_dirs", "[\"include\"]"));
+    cf_assert(set_manifest_value(content, "pch.enabled", "true"));
+    cf_assert(set_manifest_value(content, "build.config.release.vcpkg_triplet", "\"x64-linux\""));
+    cf_assert_eq(content,
+                 std::string("[project]\n"
+                             "name = \"app\"\n"
+                             "\n"
+                             "[build]\n"
+                             "build_type = \"Debug\"\n"
+                             "include_dirs = [\"include\"]\n"
+                             "\n"
+                             "[build.config.release]\n"
+                             "defines = [\"NDEBUG\"]\n"
+                             "vcpkg_triplet = \"x64-linux\"\n"
+                             "\n"
+                             "[pch]\n"
+                             "enabled = true\n"));
+    cf_assert(!set_manifest_value(content, "build.source_dirs[0]", "\"src\""));
+    return 0;
+}
+
+TEST(ManifestEdit, UnsetsKeysAndTables) {
+    std::string content = "[build]\n"
+                          "build_type = \"Debug\"\n"
+                          "source_dirs = [\n"
+                          "  \"src\", # main sources\n"
+                          "]\n"
+                          "\n"
+                          "[pch]\n"
+                          "enabled = true\n"
+                          "\n"
+                          "[pch.options]\n"
+                          "header = \"pch.hpp\"\n"
+                          "\n"
+                          "# Tests\n"
+                          "[test]\n"
+                          "framework = \"catch2\"\n";
+    cf_assert(unset_manifest_value(content, "pch"));
+    cf_assert(unset_manifest_value(content, "build.source_dirs"));
+    cf_assert(!unset_manifest_value(content, "lint"));
+    cf_assert_eq(content,
+                 std::string("[build]\n"
+                             "build_type = \"Debug\"\n"
+                             "\n"
+                             "# Tests\n"
+                             "[test]\n"
+                             "framework = \"catch2\"\n"));
+    return 0;
+}
+
+TEST(ManifestEdit, ValueTextAndLiterals) {
+    std::string content = "[build]\n"
+                          "source_dirs = [\"src\"]  # sources\n"
+                          "config.release.defines = [\"NDEBUG\"]\n";
+    cf_assert_eq(manifest_value_text(content, "build.source_dirs"), std::string("[\"src\"]"));
+    cf_assert_eq(manifest_value_text(content, "build.config.release.defines"),
+                 std::string("[\"NDEBUG\"]"));
+    cf_assert(manifest_value_text(content, "build.build_type").empty());
+
+    cf_assert_eq(manifest_value_literal("Release"), std::string("\"Release\""));
+    cf_assert_eq(manifest_value_literal("\"Release\""), std::string("\"Release\""));
+    cf_assert_eq(manifest_value_literal("17"), std::string("17"));
+    cf_assert_eq(manifest_value_literal("true"), std::string("true"));
+    cf_assert_eq(manifest_value_literal("[\"include\",\"third_party\"]"),
+                 std::string("[\"include\",\"third_party\"]"));
+    cf_assert_eq(manifest_value_literal("C:\\sdk"), std::string("\"C:\\\\sdk\""));
+    return 0;
+}
+
+TEST(ManifestEdit, DependencyEditsKeepComments) {
+    std::string content = "[dependencies]\n"
+                          "fmt = \"10.2.1\"  # formatting\n"
+                          "# needed for the CLI\n"
+                          "cli11 = \"2.4.1\"\n"
+                          "\n"
+                          "# Build settings\n"
+                          "[build]\n"
+                          "build_type = \"Debug\"\n";
+    cf_assert(set_manifest_value(content, "dependencies.zlib", "{ source = \"conan\" }"));
+    cf_assert(unset_manifest_value(content, "dependencies.fmt"));
+    cf_assert_eq(content,
+                 std::string("[dependencies]\n"
+                             "# needed for the CLI\n"
+                             "cli11 = \"2.4.1\"\n"
+                             "zlib = { source = \"conan\" }\n"
+                             "\n"
+                             "# Build settings\n"
+                             "[build]\n"
+                             "build_type = \"Debug\"\n"));
+    return 0;
+}
+
+TEST(ManifestEdit, EditsFileInPlace) {
+    std::filesystem::path file = std::filesystem::temp_directory_path()
+                               / "cforge_manifest_edit_test.toml";
+    {
+        std::ofstream out(file, std::ios::binary);
+        out << "# comment\n[dependencies]\nfmt = \"10\"  # pinned\n";
+    }
+    cf_assert(edit_manifest_file(file, [](std::string &content) {
+        return set_manifest_value(content, "dependencies.zlib", "\"1.3\"");
+    }));
+    cf_assert(!edit_manifest_file(file, [](std::string &) { return false; }));
+
+    std::ifstream in(file, std::ios::binary);
+    std::stringstream buffer;
+    buffer << in.rdbuf();
+    cf_assert_eq(buffer.str(),
+                 std::string("# comment\n"
+                             "[dependencies]\n"
+                             "fmt = \"10\"  # pinned\n"
+                             "zlib = \"1.3\"\n"));
+    in.close();
+    std::filesystem::remove(file);
+    return 0;
+}
+
+TEST(ManifestEdit, KeepsCrlfLineEndings) {
+    std::string content = "[project]\r\n"
+                          "name = \"app\"\r\n"
+                          "\r\n"
+                          "[build]\r\n"
+                          "build_type = \"Debug\"\r\n"
+                          "\r\n"
+                          "[pch]\r\n"
+                          "enabled = true\r\n";
+    cf_assert(set_manifest_value(content, "build.build_type", "\"Release\""));
+    cf_assert(set_manifest_value(content, "build.source_dirs", "[\"src\"]"));
+    cf_assert(set_manifest_value(content, "test.enabled", "false"));
+    cf_assert(unset_manifest_value(content, "pch"));
+    cf_assert_eq(content,
+                 std::string("[project]\r\n"
+                             "name = \"app\"\r\n"
+                             "\r\n"
+                             "[build]\r\n"
+                             "build_type = \"Release\"\r\n"
+                             "source_dirs = [\"src\"]\r\n"
+                             "\r\n"
+                             "[test]\r\n"
+                             "enabled = false\r\n"));
+    cf_assert_eq(manifest_value_text(content, "build.build_type"), std::string("\"Release\""));
+    return 0;
+}
+
+TEST(ManifestEdit, CheckRejectsEditThatMissesItsKey) {
+    std::string content = "[dependencies]\n"
+                          "fmt = { version = \"10.0.0\" }\n";
+    std::filesystem::path file = "cforge.toml";
+    toml::table parsed;
+    cf_assert_eq(check_manifest_edit(content, file, "dependencies.fmt", true, &parsed),
+                 std::string());
+    cf_assert(static_cast<bool>(parsed.at_path("dependencies.fmt.version")));
+
+    cf_assert(!check_manifest_edit(content, file, "dependencies.fmt.git").empty());
+    cf_assert(!check_manifest_edit(content, file, "dependencies.fmt", false).empty());
+    cf_assert_eq(check_manifest_edit(content, file, "dependencies.spdlog", false), std::string());
+
+    std::string broken = content + "[dependencies\n";
+    cf_assert(check_manifest_edit(broken, file).find("at line 3") != std::string::npos);
+    return 0;
+}
@@ -0,0 +1,95 @@
+/**
+ * @file test_manifest_schema.cpp
+ * @brief Unit tests for finding misspelled keys in cforge.toml
+ */
+
+#include "test_framework.h"
+#include "core/init_manifest.hpp"
+#include "core/manifest_schema.hpp"
+
+#include <algorithm>
+#include <sstream>
+#include <string>
+#include <vector>
+
+using namespace cforge;
+
+TEST(ManifestSchema, KeyDistance) {
+    cf_assert_eq(manifest_key_distance("defines", "defines"), static_cast<cforge_size_t>(0));
+    cf_assert_eq(manifest_key_distance("defins", "defines"), static_cast<cforge_size_t>(1));
+    cf_assert_eq(manifest_key_distance("cpp_standrad", "cpp_standard"),
+                 static_cast<cforge_size_t>(2));
+    cf_assert_eq(manifest_key_distance("", "name"), static_cast<cforge_size_t>(4));
+    return 0;
+}
+
+TEST(ManifestSchema, ClosestKey) {
+    const std::vector<std::string> &build = known_manifest_keys().at("build");
+    cf_assert_eq(closest_manifest_key("defins", build), std::string("defines"));
+    cf_assert_eq(closest_manifest_key("source_dir", build), std::string("source_dirs"));
+    cf_assert_eq(closest_manifest_key("frobnicate", build), std::string(""));
+
+    // Two-letter keys must match exactly, so "id" does not suggest "ld"
+    cf_assert_eq(closest_manifest_key("id", {"ld", "name"}), std::string(""));
+    return 0;
+}
+
+TEST(ManifestSchema, UnknownTableKeys) {
+    toml::table config = toml::parse(R"([project]
+name = "app"
+licence = "MIT"
+
+[build]
+defins = ["FOO"]
+source_dirs = ["src"]
+
+[build.config.debug]
+defines = ["DEBUG"]
+
+[dependencies]
+anything = "1.0"
+)");
+    std::vector<unknown_manifest_key> unknown = find_unknown_table_keys(config);
+    cf_assert_eq(unknown.size(), static_cast<size_t>(2));
+    cf_assert_eq(unknown[0].path, std::string("build.defins"));
+    cf_assert_eq(unknown[0].line, static_cast<cforge_size_t>(6));
+    cf_assert_eq(unknown[0].suggestion, std::string("defines"));
+    cf_assert_eq(unknown[1].path, std::string("project.licence"));
+    cf_assert_eq(unknown[1].line, static_cast<cforge_size_t>(3));
+    cf_assert_eq(unknown[1].suggestion, std::string("license"));
+    return 0;
+}
+
+TEST(ManifestSchema, InitManifestHasNoUnknownKeys) {
+    for (const char *template_name : {"executable", "shared-library", "header-only", "embedded"}) {
+        std::ostringstream manifest;
+        write_init_manifest(manifest, "app", "17", template_name, true);
+        toml::table config = toml::parse(manifest.str());
+        cf_assert(find_unknown_table_keys(config).empty());
+    }
+    return 0;
+}
+
+TEST(ManifestSchema, RepoManifestHasNoUnknownKeys) {
+    toml::table config = toml::parse_file(CFORGE_SOURCE_DIR "/cforge.toml");
+    cf_assert(find_unknown_table_keys(config).empty());
+    return 0;
+}
+
+TEST(ManifestSchema, ExpectedKeyTypes) {
+    cf_assert(expected_manifest_type("build.source_dirs") == toml::node_type::array);
+    cf_assert(expected_manifest_type("project.cpp_standard") == toml::node_type::string);
+    cf_assert(expected_manifest_type("test.timeout") == toml::node_type::integer);
+    cf_assert(expected_manifest_type("build.export_compile_commands") == toml::node_type::boolean);
+    cf_assert(expected_manifest_type("build.links") == toml::node_type::none);
+    cf_assert(expected_manifest_type("dependencies.fmt") == toml::node_type::none);
+
+    // Every typed key is one cforge knows
+    for (const auto &[path, type] : manifest_key_types()) {
+        std::string table = path.substr(0, path.find('.'));
+        const std::vector<std::string> &known = known_manifest_keys().at(table);
+        cf_assert(std::find(known.begin(), known.end(), path.substr(table.size() + 1))
+                  != known.end());
+    }
+    return 0;
+}
@@ -0,0 +1,38 @@
+/**
+ * @file test_msvc_env.cpp
+ * @brief Unit tests for loading the MSVC developer environment
+ */
+
+#include "test_framework.h"
+#include "core/build_utils.hpp"
+
+#include <string>
+
+using namespace cforge;
+
+TEST(MsvcEnv, ParseSetOutput) {
+    auto env = parse_environment_listing("INCLUDE=C:\\VC\\include;C:\\SDK\\ucrt\r\n"
+                                         "=C:=C:\\work\r\n"
+                                         "**********\r\n"
+                                         "Path=C:\\VC\\bin;C:\\Windows\r\n"
+                                         "VSCMD_ARG_TGT_ARCH=x64");
+    cf_assert(env.size() == 3);
+    cf_assert(env["INCLUDE"] == "C:\\VC\\include;C:\\SDK\\ucrt");
+    cf_assert(env["Path"] == "C:\\VC\\bin;C:\\Windows");
+    cf_assert(env["VSCMD_ARG_TGT_ARCH"] == "x64");
+    return 0;
+}
+
+TEST(MsvcEnv, ArchArguments) {
+    cf_assert(normalize_msvc_arch("AMD64") == "x64");
+    cf_assert(normalize_msvc_arch("Win32") == "x86");
+    cf_assert(normalize_msvc_arch("ARM64") == "arm64");
+    cf_assert(normalize_msvc_arch("mips").empty());
+
+    cf_assert(vcvars_arch_argument("x64", "x64") == "x64");
+    cf_assert(vcvars_arch_argument("x64", "x86") == "x64_x86");
+    cf_assert(vcvars_arch_argument("x64", "arm64") == "x64_arm64");
+    cf_assert(visual_studio_platform("x86") == "Win32");
+    cf_assert(visual_studio_platform("arm64") == "ARM64");
+    return 0;
+}
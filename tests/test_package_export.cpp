@@ -0,0 +1,59 @@
+/**
+ * @file test_package_export.cpp
+ * @brief Unit tests for CMake package export generation
+ */
+
+#include "test_framework.h"
+#include "core/package_export.hpp"
+
+#include <filesystem>
+#include <string>
+
+namespace fs = std::filesystem;
+using namespace cforge;
+
+static bool contains(const std::string &haystack, const std::string &needle) {
+    return haystack.find(needle) != std::string::npos;
+}
+
+TEST(PackageExport, AliasMatchesExportedName) {
+    // Project and target share a name: the alias and the export must both be core::core
+    std::string alias = generate_library_alias_cmake("core", "core");
+    cf_assert(contains(alias, "add_library(core::core ALIAS core)"));
+    cf_assert(contains(alias, "set_target_properties(core PROPERTIES EXPORT_NAME core)"));
+
+    std::string config = generate_package_config_cmake(
+        fs::temp_directory_path() / "cforge_no_such_project", "core", "core", "static_lib");
+    cf_assert(contains(config, "install(EXPORT coreTargets"));
+    cf_assert(contains(config, "NAMESPACE core::"));
+    cf_assert(!contains(alias + config, "core_lib"));
+    return 0;
+}
+
+TEST(PackageExport, HeaderOnlyInstallsTarget) {
+    fs::path dir = fs::temp_directory_path() / "cforge_no_such_project";
+    std::string config =
+        generate_package_config_cmake(dir, "${PROJECT_NAME}", "hdr", "header_only");
+    cf_assert(contains(config, "install(TARGETS ${PROJECT_NAME}\n    EXPORT hdrTargets"));
+    cf_assert(contains(config, "ARCH_INDEPENDENT"));
+    return 0;
+}
+
+TEST(PackageExport, SharedLibraryExportHeader) {
+    cf_assert_eq(export_header_base_name("my-lib"), std::string("my_lib"));
+    cf_assert_eq(export_header_base_name("3d"), std::string("_3d"));
+
+    std::string hidden = generate_export_header_cmake("${PROJECT_NAME}", "my_lib", false);
+    cf_assert(contains(hidden, "include(GenerateExportHeader)"));
+    cf_assert(contains(hidden, "DEFINE_SYMBOL MY_LIB_EXPORTS"));
+    cf_assert(contains(hidden, "/export/my_lib/export.h"));
+    cf_assert(contains(hidden, "$<BUILD_INTERFACE:${CMAKE_CURRENT_BINARY_DIR}/export>"));
+    cf_assert(contains(hidden, "CXX_VISIBILITY_PRESET hidden"));
+    cf_assert(contains(hidden, "VISIBILITY_INLINES_HIDDEN ON"));
+    cf_assert(!contains(hidden, "WINDOWS_EXPORT_ALL_SYMBOLS"));
+
+    std::string all = generate_export_header_cmake("${PROJECT_NAME}", "my_lib", true);
+    cf_assert(contains(all, "WINDOWS_EXPORT_ALL_SYMBOLS ON"));
+    cf_assert(!contains(all, "VISIBILITY_PRESET"));
+    return 0;
+}
@@ -0,0 +1,81 @@
+/**
+ * @file test_package_formats.cpp
+ * @brief Unit tests for [package] formats and their CPack settings
+ */
+
+#include "test_framework.h"
+#include "core/package_formats.hpp"
+
+#include <filesystem>
+#include <map>
+#include <string>
+#include <vector>
+
+using namespace cforge;
+
+TEST(PackageFormats, MapsFormatsToGenerators) {
+    cf_assert_eq(package_generator_for_format("zip"), std::string("ZIP"));
+    cf_assert_eq(package_generator_for_format("deb"), std::string("DEB"));
+    cf_assert_eq(package_generator_for_format("nsis"), std::string("NSIS"));
+    cf_assert_eq(package_generator_for_format("dmg"), std::string("DragNDrop"));
+    // Generator names from package.generators keep working
+    cf_assert_eq(package_generator_for_format("TGZ"), std::string("TGZ"));
+    cf_assert_eq(package_generator_for_format("DragNDrop"), std::string("DragNDrop"));
+    cf_assert(package_generator_for_format("appimage").empty());
+    return 0;
+}
+
+TEST(PackageFormats, FileNames) {
+    cf_assert_eq(package_file_extension("TGZ"), std::string(".tar.gz"));
+    cf_assert_eq(package_file_extension("NSIS"), std::string(".exe"));
+    cf_assert_eq(package_file_extension("WIX"), std::string(".msi"));
+    cf_assert(package_file_extension("External").empty());
+    cf_assert_eq(package_file_stem("app", "1.2.0", "Release"),
+                 "app-1.2.0-" + package_system_name() + "-release");
+    return 0;
+}
+
+TEST(PackageFormats, MatchesOnlyOwnPackageFiles) {
+    cf_assert(is_package_file("app-1.0-linux-release.tar.gz", "app-1.0-linux-release", "TGZ"));
+    cf_assert(is_package_file("app-1.0-linux-release-Runtime.deb", "app-1.0-linux-release", "DEB"));
+    cf_assert(!is_package_file("app-1.0-linux-release.zip", "app-1.0-linux-release", "TGZ"));
+    cf_assert(!is_package_file("app-1.0-linux-debug.tar.gz", "app-1.0-linux-release", "TGZ"));
+    cf_assert(!is_package_file("myapp-notes.txt", "app-1.0-linux-release", "ZIP"));
+    cf_assert(!is_package_file("README", "app-1.0-linux-release", "External"));
+    return 0;
+}
+
+TEST(PackageFormats, FormatVariables) {
+    std::map<std::string, std::string> deb = {
+        {"maintainer", "Jo Doe <jo@example.com>"},
+        {"depends",    "libc6 (>= 2.31), libssl3"},
+        {"section",    ""},
+        {"unknown",    "ignored"},
+    };
+    std::vector<std::string> expected = {
+        "CPACK_DEBIAN_PACKAGE_MAINTAINER=Jo Doe <jo@example.com>",
+        "CPACK_DEBIAN_PACKAGE_DEPENDS=libc6 (>= 2.31), libssl3",
+    };
+    cf_assert(package_format_variables("DEB", deb, "/work/app") == expected);
+
+    std::map<std::string, std::string> nsis = {
+        {"icon",    "assets/app.ico"},
+        {"license", "LICENSE.txt"},
+    };
+    auto variables = package_format_variables("NSIS", nsis, std::filesystem::path("/work/app"));
+    cf_assert_eq(variables.size(), static_cast<size_t>(2));
+    cf_assert_eq(variables[0], std::string("CPACK_NSIS_MUI_ICON=")
+                                   + (std::filesystem::path("/work/app") / "assets/app.ico")
+                                         .lexically_normal()
+                                         .generic_string());
+
+    cf_assert(package_format_variables("ZIP", deb, "/work/app").empty());
+    return 0;
+}
+
+TEST(PackageFormats, FormatsSizes) {
+    cf_assert_eq(format_package_size(512), std::string("512 B"));
+    cf_assert_eq(format_package_size(1536), std::string("1.5 KB"));
+    cf_assert_eq(format_package_size(3 * 1024 * 1024), std::string("3.0 MB"));
+    return 0;
+}
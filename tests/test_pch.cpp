@@ -0,0 +1,126 @@
+/**
+ * @file test_pch.cpp
+ * @brief Unit tests for [pch] parsing and CMake generation
+ */
+
+#include "test_framework.h"
+#include "core/pch.hpp"
+#include "core/toml_reader.cpp" // toml_reader is not part of the test target
+
+#include <filesystem>
+#include <fstream>
+#include <string>
+
+namespace fs = std::filesystem;
+using namespace cforge;
+
+// Helper to create a project with a pch.h that pulls in <vector>/<string>
+static fs::path create_pch_project(const std::string &pch_section) {
+    fs::path dir = fs::temp_directory_path() / ("cforge_pch_test_" + std::to_string(std::rand()));
+    fs::create_directories(dir / "include");
+    fs::create_directories(dir / "src");
+
+    std::ofstream header(dir / "include" / "pch.h");
+    header << "#pragma once\n#include <string>\n#include <vector>\n";
+    header.close();
+
+    std::ofstream config(dir / "cforge.toml");
+    config << "[project]\nname = \"app\"\nbinary_type = \"executable\"\n\n" << pch_section;
+    config.close();
+    return dir;
+}
+
+static pch_config load_pch_project(const fs::path &dir) {
+    toml_reader reader;
+    reader.load((dir / "cforge.toml").string());
+    return read_pch_config(reader);
+}
+
+static bool contains(const std::string &haystack, const std::string &needle) {
+    return haystack.find(needle) != std::string::npos;
+}
+
+TEST(Pch, NoSectionDisabled) {
+    fs::path dir = create_pch_project("");
+    pch_config cfg = load_pch_project(dir);
+    fs::remove_all(dir);
+
+    cf_assert(!cfg.enabled);
+    cf_assert(generate_pch_cmake(cfg, "${PROJECT_NAME}", "${CMAKE_CURRENT_SOURCE_DIR}").empty());
+    return 0;
+}
+
+TEST(Pch, HeaderPerTarget) {
+    fs::path dir = create_pch_project("[pch]\nheader = \"include/pch.h\"\n");
+    pch_config cfg = load_pch_project(dir);
+    fs::remove_all(dir);
+
+    cf_assert(cfg.enabled);
+    std::string app = generate_pch_cmake(cfg, "${PROJECT_NAME}", "${CMAKE_CURRENT_SOURCE_DIR}");
+    cf_assert(contains(app, "target_precompile_headers(${PROJECT_NAME} PRIVATE "
+                            "\"${CMAKE_CURRENT_SOURCE_DIR}/include/pch.h\")"));
+    cf_assert(contains(app, "VERSION_GREATER_EQUAL 3.16"));
+
+    std::string tests = generate_pch_cmake(cfg, "app_test", "/work/app");
+    cf_assert(contains(tests, "target_precompile_headers(app_test PRIVATE "
+                              "\"/work/app/include/pch.h\")"));
+    return 0;
+}
+
+TEST(Pch, DisabledFlag) {
+    fs::path dir = create_pch_project("[pch]\nenabled = false\nheader = \"include/pch.h\"\n");
+    pch_config cfg = load_pch_project(dir);
+    fs::remove_all(dir);
+
+    cf_assert(!cfg.enabled);
+    return 0;
+}
+
+TEST(Pch, ExcludeTargets) {
+    fs::path dir = create_pch_project(
+        "[pch]\nheader = \"include/pch.h\"\nexclude_targets = [\"tests\"]\n");
+    pch_config cfg = load_pch_project(dir);
+    fs::remove_all(dir);
+
+    cf_assert(pch_applies_to(cfg, "app"));
+    cf_assert(!pch_applies_to(cfg, "tests"));
+    return 0;
+}
+
+TEST(Pch, SystemHeadersAndSource) {
+    fs::path dir = create_pch_project(
+        "[pch]\nheaders = [\"<vector>\", \"<string>\"]\nsource = \"src/pch.cpp\"\n"
+        "exclude_sources = [\"src/c_api.c\"]\n");
+    pch_config cfg = load_pch_project(dir);
+    fs::remove_all(dir);
+
+    std::string out = generate_pch_cmake(cfg, "app", "${CMAKE_CURRENT_SOURCE_DIR}");
+    cf_assert(contains(out, "target_precompile_headers(app PRIVATE \"<vector>\" \"<string>\")"));
+    cf_assert(contains(out, "target_sources(app PRIVATE \"${CMAKE_CURRENT_SOURCE_DIR}/src/pch.cpp\")"));
+    cf_assert(contains(out, "\"${CMAKE_CURRENT_SOURCE_DIR}/src/c_api.c\" "
+                            "PROPERTIES SKIP_PRECOMPILE_HEADERS ON"));
+    return 0;
+}
+
+TEST(Pch, ReuseFromDependency) {
+    fs::path dir = create_pch_project("[pch]\nheader = \"include/pch.h\"\nreuse = \"core\"\n");
+    pch_config cfg = load_pch_project(dir);
+    fs::remove_all(dir);
+
+    std::string out = generate_pch_cmake(cfg, "app", "${CMAKE_CURRENT_SOURCE_DIR}");
+    cf_assert(contains(out, "if(TARGET core)"));
+    cf_assert(contains(out, "target_precompile_headers(app REUSE_FROM core)"));
+    cf_assert(contains(out, "target_precompile_headers(app PRIVATE"));
+    return 0;
+}
+
+TEST(Pch, LegacyPrecompiledHeaders) {
+    fs::path dir = create_pch_project("[build]\nprecompiled_headers = [\"include/pch.h\"]\n");
+    pch_config cfg = load_pch_project(dir);
+    fs::remove_all(dir);
+
+    cf_assert(cfg.enabled);
+    cf_assert(cfg.headers.size() == 1);
+    cf_assert(cfg.headers[0] == "include/pch.h");
+    return 0;
+}
@@ -0,0 +1,26 @@
+/**
+ * @file test_pkg_config.cpp
+ * @brief Unit tests for [build] pkg_config module specs
+ */
+
+#include "test_framework.h"
+#include "core/pkg_config.hpp"
+
+#include <string>
+
+using namespace cforge;
+
+TEST(PkgConfig, KeepsVersionConstraints) {
+    cf_assert_eq(pkg_config_spec("libcurl >= 7.68"), std::string("libcurl>=7.68"));
+    cf_assert_eq(pkg_config_spec("gtk4"), std::string("gtk4"));
+    cf_assert_eq(pkg_config_module("libcurl >= 7.68"), std::string("libcurl"));
+    cf_assert_eq(pkg_config_module("zlib=1.3"), std::string("zlib"));
+    return 0;
+}
+
+TEST(PkgConfig, DerivesVariableNames) {
+    cf_assert_eq(pkg_config_variable("gtk4"), std::string("GTK4"));
+    cf_assert_eq(pkg_config_variable("libcurl >= 7.68"), std::string("LIBCURL"));
+    cf_assert_eq(pkg_config_variable("gtk+-3.0"), std::string("GTK__3_0"));
+    return 0;
+}
@@ -0,0 +1,49 @@
+/**
+ * @file test_platform_settings.cpp
+ * @brief Unit tests for per-target, per-OS defines and flags
+ */
+
+#include "test_framework.h"
+#include "core/platform_settings.hpp"
+
+#include <map>
+#include <string>
+#include <vector>
+
+using namespace cforge;
+
+TEST(PlatformSettings, MapsPlatformIds) {
+    cf_assert_eq(cmake_platform_id("windows"), std::string("Windows"));
+    cf_assert_eq(cmake_platform_id("linux"), std::string("Linux"));
+    cf_assert_eq(cmake_platform_id("macos"), std::string("Darwin"));
+    cf_assert(cmake_platform_id("freebsd").empty());
+    return 0;
+}
+
+TEST(PlatformSettings, GuardsByPlatform) {
+    std::map<std::string, std::vector<std::string>> defines = {
+        {"windows", {"UNICODE", "_UNICODE"}},
+    };
+    std::map<std::string, std::vector<std::string>> flags = {
+        {"linux", {"-mavx2"}},
+        {"macos", {}},
+    };
+    std::string cmake = generate_platform_settings_cmake(defines, flags, "app_tests");
+    cf_assert_eq(cmake,
+                 std::string("target_compile_definitions(app_tests PRIVATE\n"
+                             "  \"$<$<PLATFORM_ID:Windows>:UNICODE>\"\n"
+                             "  \"$<$<PLATFORM_ID:Windows>:_UNICODE>\"\n"
+                             ")\n\n"
+                             "target_compile_options(app_tests PRIVATE\n"
+                             "  \"$<$<PLATFORM_ID:Linux>:-mavx2>\"\n"
+                             ")\n\n"));
+    return 0;
+}
+
+TEST(PlatformSettings, EmptyWithoutSettings) {
+    std::map<std::string, std::vector<std::string>> none;
+    std::map<std::string, std::vector<std::string>> empty_lists = {{"linux", {}}};
+    cf_assert(generate_platform_settings_cmake(none, none, "app_tests").empty());
+    cf_assert(generate_platform_settings_cmake(empty_lists, none, "app_tests").empty());
+    return 0;
+}
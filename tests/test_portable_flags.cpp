@@ -0,0 +1,76 @@
+/**
+ * @file test_portable_flags.cpp
+ * @brief Unit tests for universal flag token translation
+ */
+
+#include "test_framework.h"
+#include "core/portable_flags.hpp"
+
+#include <string>
+#include <vector>
+
+using namespace cforge;
+
+// ============================================================================
+// parse_universal_flags
+// ============================================================================
+
+TEST(UniversalFlags, OptimizeMsvc) {
+    auto flags = parse_universal_flags({"OPTIMIZE"}, true);
+    cf_assert(flags.size() == 1);
+    cf_assert(flags[0] == "/O2");
+    return 0;
+}
+
+TEST(UniversalFlags, OptimizeGcc) {
+    auto flags = parse_universal_flags({"OPTIMIZE"}, false);
+    cf_assert(flags.size() == 1);
+    cf_assert(flags[0] == "-O2");
+    return 0;
+}
+
+TEST(UniversalFlags, DebugNoOpt) {
+    auto msvc = parse_universal_flags({"DEBUG_INFO", "NO_OPT"}, true);
+    auto gcc  = parse_universal_flags({"DEBUG_INFO", "NO_OPT"}, false);
+    cf_assert(msvc == std::vector<std::string>({"/Zi", "/Od"}));
+    cf_assert(gcc == std::vector<std::string>({"-g", "-O0"}));
+    return 0;
+}
+
+TEST(UniversalFlags, OptimizeVariants) {
+    cf_assert(parse_universal_flags({"OPTIMIZE_MAX"}, true)[0] == "/Ox");
+    cf_assert(parse_universal_flags({"OPTIMIZE_MAX"}, false)[0] == "-O3");
+    cf_assert(parse_universal_flags({"OPTIMIZE_SIZE"}, true)[0] == "/O1");
+    cf_assert(parse_universal_flags({"OPTIMIZE_SIZE"}, false)[0] == "-Os");
+    return 0;
+}
+
+TEST(UniversalFlags, LtoSanitizersRtti) {
+    auto tokens = std::vector<std::string>{"LTO", "SANITIZE_ADDRESS", "RTTI_OFF"};
+    cf_assert(parse_universal_flags(tokens, true)
+              == std::vector<std::string>({"/GL", "/fsanitize=address", "/GR-"}));
+    cf_assert(parse_universal_flags(tokens, false)
+              == std::vector<std::string>({"-flto", "-fsanitize=address", "-fno-rtti"}));
+    return 0;
+}
+
+TEST(UniversalFlags, GccOnlyTokensDroppedForMsvc) {
+    auto tokens = std::vector<std::string>{"SANITIZE_UNDEFINED", "NATIVE_ARCH"};
+    cf_assert(parse_universal_flags(tokens, true).empty());
+    cf_assert(parse_universal_flags(tokens, false)
+              == std::vector<std::string>({"-fsanitize=undefined", "-march=native"}));
+    return 0;
+}
+
+TEST(UniversalFlags, RawAndUnknownPassThrough) {
+    auto flags = parse_universal_flags({"-fPIC", "/EHsc", "SOMETHING_ELSE"}, false, false);
+    cf_assert(flags == std::vector<std::string>({"-fPIC", "/EHsc", "SOMETHING_ELSE"}));
+    return 0;
+}
+
+TEST(UniversalFlags, EveryTokenHasATranslation) {
+    for (const auto &flag : get_universal_flags()) {
+        cf_assert(std::string(flag.msvc).size() + std::string(flag.gcc).size() > 0);
+    }
+    return 0;
+}
@@ -0,0 +1,51 @@
+/**
+ * @file test_process_args.cpp
+ * @brief Unit tests for Windows command-line argument quoting
+ */
+
+#include "test_framework.h"
+#include "core/process_utils.hpp"
+
+#include <string>
+#include <vector>
+
+using namespace cforge;
+
+TEST(ProcessArgs, PlainArgumentUnchanged) {
+    cf_assert(quote_windows_argument("--build") == "--build");
+    cf_assert(quote_windows_argument("C:\\src\\app") == "C:\\src\\app");
+    cf_assert(quote_windows_argument("-DCMAKE_BUILD_TYPE=Debug") == "-DCMAKE_BUILD_TYPE=Debug");
+    return 0;
+}
+
+TEST(ProcessArgs, EmptyArgumentQuoted) {
+    cf_assert(quote_windows_argument("") == "\"\"");
+    return 0;
+}
+
+TEST(ProcessArgs, SpacesAndUnicode) {
+    // UTF-8 bytes pass through untouched; only the spaces force quoting
+    std::string dir = "C:\\Users\\John Doe\\My Projects\\\xC3\xA4pp";
+    cf_assert(quote_windows_argument(dir) == "\"" + dir + "\"");
+    return 0;
+}
+
+TEST(ProcessArgs, TrailingBackslashDoubled) {
+    // Otherwise the backslash would escape the closing quote
+    cf_assert(quote_windows_argument("C:\\My Projects\\") == "\"C:\\My Projects\\\\\"");
+    return 0;
+}
+
+TEST(ProcessArgs, EmbeddedQuotesEscaped) {
+    cf_assert(quote_windows_argument("-DNAME=\"a b\"") == "\"-DNAME=\\\"a b\\\"\"");
+    cf_assert(quote_windows_argument("a\\\"b") == "\"a\\\\\\\"b\"");
+    return 0;
+}
+
+TEST(ProcessArgs, CommandLine) {
+    std::vector<std::string> args = {"-S", "C:\\My Projects\\app", "-B", "build"};
+    cf_assert(build_windows_command_line("C:\\Program Files\\CMake\\bin\\cmake.exe", args)
+              == "\"C:\\Program Files\\CMake\\bin\\cmake.exe\" "
+                 "-S \"C:\\My Projects\\app\" -B build");
+    return 0;
+}
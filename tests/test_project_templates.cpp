@@ -0,0 +1,42 @@
+/**
+ * @file test_project_templates.cpp
+ * @brief Unit tests for the embedded project templates
+ */
+
+#include "test_framework.h"
+#include "core/project_templates.hpp"
+
+using namespace cforge;
+
+TEST(ProjectTemplates, RenderPlaceholders) {
+    template_variables vars = make_template_variables("my-app", "20");
+    cf_assert(render_template("{{name}}/{{namespace}}", vars) == "my-app/my_app");
+    cf_assert(render_template("cpp_standard = \"{{cpp_standard}}\"", vars)
+              == "cpp_standard = \"20\"");
+    // Unknown and unterminated placeholders are kept as written
+    cf_assert(render_template("{{other}} {{name", vars) == "{{other}} {{name");
+    return 0;
+}
+
+TEST(ProjectTemplates, Namespace) {
+    cf_assert(template_namespace("my.lib") == "my_lib");
+    cf_assert(template_namespace("3d-engine") == "_3d_engine");
+    return 0;
+}
+
+TEST(ProjectTemplates, EveryTemplateHasManifestAndTest) {
+    for (const auto &tmpl : project_templates()) {
+        bool has_manifest = false;
+        bool has_test     = false;
+        for (const auto &file : tmpl.files) {
+            has_manifest = has_manifest || file.path == "cforge.toml";
+            has_test     = has_test || file.path.rfind("tests/", 0) == 0;
+        }
+        cf_assert(has_manifest);
+        cf_assert(has_test);
+        cf_assert(find_project_template(tmpl.name) == &tmpl);
+    }
+    cf_assert(find_project_template("app") != nullptr);
+    cf_assert(find_project_template("missing") == nullptr);
+    return 0;
+}
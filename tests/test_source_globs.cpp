@@ -0,0 +1,115 @@
+/**
+ * @file test_source_globs.cpp
+ * @brief Unit tests for the source exclude patterns of [build] exclude_sources
+ */
+
+#include "test_framework.h"
+#include "core/source_globs.hpp"
+
+#include <filesystem>
+#include <fstream>
+#include <regex>
+#include <string>
+#include <vector>
+
+using namespace cforge;
+
+namespace {
+
+bool glob_matches(const std::string &pattern, const std::string &path) {
+    return std::regex_match(path, std::regex(glob_to_regex(pattern)));
+}
+
+}  // namespace
+
+TEST(SourceGlobs, DoubleStarMatchesAnyDepth) {
+    cf_assert(glob_matches("src/**/impl/*.cpp", "src/impl/a.cpp"));
+    cf_assert(glob_matches("src/**/impl/*.cpp", "src/net/tcp/impl/a.cpp"));
+    cf_assert(!glob_matches("src/**/impl/*.cpp", "src/impl/detail/a.cpp"));
+    cf_assert(glob_matches("src/experimental/**", "src/experimental/x/y.cpp"));
+    cf_assert(!glob_matches("src/experimental/**", "src/experimental.cpp"));
+    return 0;
+}
+
+TEST(SourceGlobs, SingleStarStopsAtSlash) {
+    cf_assert(glob_matches("src/*.cpp", "src/main.cpp"));
+    cf_assert(!glob_matches("src/*.cpp", "src/sub/main.cpp"));
+    cf_assert(glob_matches("src/?.c", "src/a.c"));
+    cf_assert(!glob_matches("src/?.c", "src/ab.c"));
+    return 0;
+}
+
+TEST(SourceGlobs, AnchoringFollowsGitignore) {
+    cf_assert(glob_matches("*_disabled.cpp", "foo_disabled.cpp"));
+    cf_assert(glob_matches("*_disabled.cpp", "src/a/foo_disabled.cpp"));
+    cf_assert(glob_matches("generated/", "src/generated/a.cpp"));
+    cf_assert(glob_matches("/deps/", "deps/fmt/src/format.cc"));
+    cf_assert(!glob_matches("/deps/", "src/deps/a.cpp"));
+    cf_assert(!glob_matches("src/main.cpp", "lib/src/main.cpp"));
+    return 0;
+}
+
+TEST(SourceGlobs, WindowsSeparatorsAndSpecialCharacters) {
+    cf_assert(glob_matches("src\\platform\\win32\\*.cpp", "src/platform/win32/io.cpp"));
+    cf_assert(glob_matches("src/a+b (old).cpp", "src/a+b (old).cpp"));
+    cf_assert(!glob_matches("src/a.cpp", "src/abcpp"));
+    return 0;
+}
+
+TEST(SourceGlobs, CMakeStyleStarCrossesDirectories) {
+    std::regex regex(glob_to_regex("src/*.cpp", true));
+    cf_assert(std::regex_match(std::string("src/sub/main.cpp"), regex));
+    cf_assert(!std::regex_match(std::string("lib/src/main.cpp"), regex));
+    return 0;
+}
+
+TEST(SourceGlobs, CountSkipsExcludedAndDefaultDirectories) {
+    namespace fs = std::filesystem;
+    fs::path dir = fs::temp_directory_path() / "cforge_test_source_globs";
+    fs::remove_all(dir);
+    for (const char *file : {"main.cpp",
+                             "util.c",
+                             "notes.txt",
+                             "experimental/new.cpp",
+                             "old_disabled.cpp",
+                             "deps/fmt/format.cc",
+                             "build-release/CMakeFiles/x.cpp"}) {
+        fs::create_directories((dir / file).parent_path());
+        std::ofstream(dir / file) << "\n";
+    }
+
+    std::vector<std::string> excludes = default_source_excludes("deps", "build");
+    cf_assert_eq(count_project_source_files(dir, {"."}, excludes), static_cast<size_t>(4));
+
+    excludes.push_back("/experimental/");
+    excludes.push_back("*_disabled.cpp");
+    cf_assert_eq(count_project_source_files(dir, {".", "experimental"}, excludes),
+                 static_cast<size_t>(2));
+
+    fs::remove_all(dir);
+    return 0;
+}
+
+TEST(SourceGlobs, CountHonorsNegatedPatterns) {
+    namespace fs = std::filesystem;
+    fs::path dir = fs::temp_directory_path() / "cforge_test_negated_globs";
+    fs::remove_all(dir);
+    for (const char *file :
+         {"src/main.cpp", "src/app.cpp", "src/net/socket.cpp", "tools/gen.cpp"}) {
+        fs::create_directories((dir / file).parent_path());
+        std::ofstream(dir / file) << "\n";
+    }
+
+    cf_assert(is_negated_source_pattern("!src/main.cpp"));
+    cf_assert(!is_negated_source_pattern("src/main.cpp"));
+    cf_assert_eq(count_project_source_files(dir, {}, {}, {"src/**.cpp", "!src/main.cpp"}),
+                 static_cast<size_t>(2));
+    // Exclusions apply after every include, wherever they are listed
+    cf_assert_eq(count_project_source_files(dir, {}, {}, {"!src/main.cpp", "src/**.cpp"}),
+                 static_cast<size_t>(2));
+    cf_assert_eq(count_project_source_files(dir, {"src", "tools"}, {}, {"!src/net/*.cpp"}),
+                 static_cast<size_t>(3));
+
+    fs::remove_all(dir);
+    return 0;
+}
@@ -0,0 +1,34 @@
+/**
+ * @file test_test_selection.cpp
+ * @brief Unit tests for `cforge test` target selection and failure summaries
+ */
+
+#include "test_framework.h"
+#include "core/test_framework.hpp"
+
+#include <string>
+#include <vector>
+
+using namespace cforge;
+
+TEST(TestSelection, LabelAndExclude) {
+    test_target integration;
+    integration.name   = "db_integration";
+    integration.labels = {"integration", "slow"};
+    test_target unit;
+    unit.name = "math_unit";
+
+    cf_assert(test_target_selected(unit, "", ""));
+    cf_assert(test_target_selected(integration, "integ", ""));
+    cf_assert(!test_target_selected(unit, "integration", ""));
+    cf_assert(!test_target_selected(integration, "integration", "^db_"));
+    cf_assert(test_target_selected(unit, "", "^db_"));
+    return 0;
+}
+
+TEST(TestSelection, DescribeFailedTests) {
+    cf_assert_eq(describe_failed_tests({"Math.Add"}), std::string("1 test failed: Math.Add"));
+    cf_assert_eq(describe_failed_tests({"A.a", "A.b", "A.c"}, 2),
+                 std::string("3 tests failed: A.a, A.b, and 1 more"));
+    return 0;
+}
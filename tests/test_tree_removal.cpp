@@ -0,0 +1,54 @@
+/**
+ * @file test_tree_removal.cpp
+ * @brief Tests for removing build directories with locked files
+ */
+
+#include "core/tree_removal.hpp"
+#include "test_framework.h"
+
+#include <cstdlib>
+#include <fstream>
+#include <string>
+
+namespace fs = std::filesystem;
+
+TEST(TreeRemoval, RetryDelayDoubles) {
+    cf_assert_eq(cforge::removal_retry_delay(0).count(), 50);
+    cf_assert_eq(cforge::removal_retry_delay(1).count(), 100);
+    cf_assert_eq(cforge::removal_retry_delay(3).count(), 400);
+    return 0;
+}
+
+TEST(TreeRemoval, RemovesTreeAndCountsFiles) {
+    fs::path dir = fs::temp_directory_path() / ("cforge_clean_test_" + std::to_string(std::rand()));
+    fs::create_directories(dir / "bin" / "Debug");
+    fs::create_directories(dir / "CMakeFiles");
+    std::ofstream(dir / "CMakeCache.txt") << "cache";
+    std::ofstream(dir / "bin" / "Debug" / "app") << "binary";
+    std::ofstream(dir / "CMakeFiles" / "rules.make") << "rules";
+    fs::permissions(dir / "CMakeCache.txt", fs::perms::owner_read, fs::perm_options::replace);
+
+    cforge::tree_removal_result result = cforge::remove_tree_with_retries(dir, 0);
+    cf_assert_eq(result.removed, static_cast<cforge_size_t>(3));
+    cf_assert(result.skipped.empty());
+    cf_assert(!fs::exists(dir));
+    return 0;
+}
+
+TEST(TreeRemoval, MissingDirectoryIsNothingToDo) {
+    cforge::tree_removal_result result =
+        cforge::remove_tree_with_retries(fs::temp_directory_path() / "cforge_clean_missing", 0);
+    cf_assert_eq(result.removed, static_cast<cforge_size_t>(0));
+    cf_assert(result.skipped.empty());
+    return 0;
+}
+
+TEST(TreeRemoval, RecognizesProjectExecutable) {
+    std::vector<std::string> executables = {"myapp"};
+    cf_assert(cforge::is_project_executable("build/bin/Debug/myapp.exe", executables));
+    cf_assert(cforge::is_project_executable("build/bin/Debug/MyApp.EXE", {"MyApp"}));
+    cf_assert(cforge::is_project_executable("build/bin/myapp", executables));
+    cf_assert(!cforge::is_project_executable("build/bin/myapp.pdb", executables));
+    cf_assert(!cforge::is_project_executable("build/bin/other.exe", executables));
+    return 0;
+}
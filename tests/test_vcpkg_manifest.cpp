@@ -0,0 +1,62 @@
+/**
+ * @file test_vcpkg_manifest.cpp
+ * @brief Unit tests for vcpkg.json generation in manifest mode
+ */
+
+#include "test_framework.h"
+#include "core/vcpkg_manifest.hpp"
+
+#include <string>
+#include <vector>
+
+using namespace cforge;
+
+TEST(VcpkgManifest, ParsePackageSpec) {
+    vcpkg_manifest_dependency dep = parse_vcpkg_manifest_spec("fmt[header-only]@10.1.0");
+    cf_assert_eq(dep.name, std::string("fmt"));
+    cf_assert_eq(dep.features.size(), static_cast<size_t>(1));
+    cf_assert_eq(dep.features[0], std::string("header-only"));
+    cf_assert_eq(dep.version_min, std::string("10.1.0"));
+
+    dep = parse_vcpkg_manifest_spec("Boost-Asio[ssl, coroutine]:x64-windows");
+    cf_assert_eq(dep.name, std::string("boost-asio"));
+    cf_assert_eq(dep.features.size(), static_cast<size_t>(2));
+    cf_assert_eq(dep.features[1], std::string("coroutine"));
+    cf_assert(dep.version_min.empty());
+
+    cf_assert_eq(vcpkg_minimum_version("1.2.3"), std::string("1.2.3"));
+    cf_assert(vcpkg_minimum_version("1.2.*").empty());
+    cf_assert(vcpkg_minimum_version("^1.2").empty());
+    cf_assert(is_vcpkg_setting_key("manifest"));
+    cf_assert(is_vcpkg_setting_key("binary_cache"));
+    cf_assert(!is_vcpkg_setting_key("fmt"));
+    return 0;
+}
+
+TEST(VcpkgManifest, GeneratesSortedManifestWithBaseline) {
+    std::vector<vcpkg_manifest_dependency> deps;
+    add_vcpkg_manifest_dependency(deps, parse_vcpkg_manifest_spec("zlib"));
+    add_vcpkg_manifest_dependency(deps, parse_vcpkg_manifest_spec("fmt[header-only]"));
+    add_vcpkg_manifest_dependency(deps, parse_vcpkg_manifest_spec("fmt@10.1.0"));
+    cf_assert_eq(deps.size(), static_cast<size_t>(2));
+
+    std::string json = generate_vcpkg_manifest_json(deps, "0123abcd");
+    std::string expected = "{\n"
+                           "  \"$comment\": \"Generated by cforge from cforge.toml\",\n"
+                           "  \"builtin-baseline\": \"0123abcd\",\n"
+                           "  \"dependencies\": [\n"
+                           "    {\n"
+                           "      \"features\": [\"header-only\"],\n"
+                           "      \"name\": \"fmt\",\n"
+                           "      \"version>=\": \"10.1.0\"\n"
+                           "    },\n"
+                           "    \"zlib\"\n"
+                           "  ]\n"
+                           "}\n";
+    cf_assert_eq(json, expected);
+    cf_assert(is_cforge_generated_vcpkg_manifest(json));
+    cf_assert(!is_cforge_generated_vcpkg_manifest("{\"dependencies\": []}"));
+    cf_assert_eq(vcpkg_manifest_baseline(json), std::string("0123abcd"));
+    cf_assert(vcpkg_manifest_baseline(generate_vcpkg_manifest_json(deps, "")).empty());
+    return 0;
+}
@@ -0,0 +1,55 @@
+/**
+ * @file test_vcpkg_triplet.cpp
+ * @brief Unit tests for vcpkg triplet selection and binary caching
+ */
+
+#include "test_framework.h"
+#include "core/build_utils.hpp"
+
+using namespace cforge;
+
+TEST(VcpkgTriplet, DefaultForTarget) {
+    cf_assert(default_vcpkg_triplet("Windows", "arm64") == "arm64-windows");
+    cf_assert(default_vcpkg_triplet("Windows", "AMD64") == "x64-windows");
+    cf_assert(default_vcpkg_triplet("Linux", "aarch64") == "arm64-linux");
+    cf_assert(default_vcpkg_triplet("Linux", "armv7l") == "arm-linux");
+    cf_assert(default_vcpkg_triplet("Darwin", "x86_64") == "x64-osx");
+    cf_assert(default_vcpkg_triplet("Android", "aarch64") == "arm64-android");
+    // Bare-metal targets have no vcpkg triplet
+    cf_assert(default_vcpkg_triplet("Generic", "avr").empty());
+    cf_assert(!default_vcpkg_triplet("", "").empty());
+    return 0;
+}
+
+TEST(VcpkgTriplet, InstalledForExactTriplet) {
+    std::string output = "fmt:x64-windows          11.0.2    Formatting library\n"
+                         "curl[ssl]:x64-windows-static  8.9.1  ssl support\n"
+                         "curl:x64-windows-static  8.9.1     A library for transferring data\n";
+    cf_assert(vcpkg_list_has_package(output, "fmt", "x64-windows"));
+    cf_assert(!vcpkg_list_has_package(output, "fmt", "x64-windows-static"));
+    cf_assert(vcpkg_list_has_package(output, "curl", "x64-windows-static"));
+    cf_assert(!vcpkg_list_has_package(output, "curl", "x64-windows"));
+    return 0;
+}
+
+TEST(VcpkgTriplet, RejectsMalformedNames) {
+    cf_assert(is_valid_vcpkg_triplet("x64-windows-static"));
+    cf_assert(is_valid_vcpkg_triplet("arm64-android"));
+    cf_assert(is_valid_vcpkg_triplet("wasm32-emscripten"));
+    cf_assert(!is_valid_vcpkg_triplet(""));
+    cf_assert(!is_valid_vcpkg_triplet("x64_windows"));
+    cf_assert(!is_valid_vcpkg_triplet("x64-Windows"));
+    cf_assert(!is_valid_vcpkg_triplet("x64--windows"));
+    cf_assert(!is_valid_vcpkg_triplet("-x64-windows"));
+    cf_assert(!is_valid_vcpkg_triplet("fmt:x64-windows"));
+    cf_assert(!is_valid_vcpkg_triplet("x64-windows "));
+    return 0;
+}
+
+TEST(VcpkgBinaryCache, FilesSourceEscapesSeparators) {
+    cf_assert_eq(vcpkg_files_binary_source("/home/ci/.cache/cforge/vcpkg-archives"),
+                 std::string("files,/home/ci/.cache/cforge/vcpkg-archives,readwrite"));
+    cf_assert_eq(vcpkg_files_binary_source("C:\\cache;a,b`c"),
+                 std::string("files,C:\\cache`;a`,b``c,readwrite"));
+    return 0;
+}
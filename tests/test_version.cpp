@@ -273,3 +273,39 @@ TEST(Version, FindBestNone) {
     cf_assert(!best.has_value());
     return 0;
 }
+
+// ============================================================================
+// Version bump tests
+// ============================================================================
+
+TEST(Version, Bump) {
+    cf_assert(bump_version("1.4.2", "patch") == std::optional<std::string>("1.4.3"));
+    cf_assert(bump_version("1.4.2", "minor") == std::optional<std::string>("1.5.0"));
+    cf_assert(bump_version("1.4.2", "major") == std::optional<std::string>("2.0.0"));
+    cf_assert(bump_version("v1.4.2+build7", "patch") == std::optional<std::string>("1.4.3"));
+    cf_assert(!bump_version("1.4.2", "micro").has_value());
+    cf_assert(!bump_version("1.*", "patch").has_value());
+    cf_assert(!bump_version("", "patch").has_value());
+    return 0;
+}
+
+TEST(Version, BumpReleasesPrerelease) {
+    cf_assert(bump_version("2.0.0-rc1", "major") == std::optional<std::string>("2.0.0"));
+    cf_assert(bump_version("1.3.0-beta", "minor") == std::optional<std::string>("1.3.0"));
+    cf_assert(bump_version("1.3.1-beta", "minor") == std::optional<std::string>("1.4.0"));
+    cf_assert(bump_version("1.3.1-beta", "patch") == std::optional<std::string>("1.3.1"));
+    return 0;
+}
+
+TEST(Version, RetargetConstraint) {
+    cf_assert(retarget_version_constraint("1.2.0", "1.3.0")
+              == std::optional<std::string>("1.3.0"));
+    cf_assert(retarget_version_constraint("^1.2.0", "1.3.0")
+              == std::optional<std::string>("^1.3.0"));
+    cf_assert(retarget_version_constraint(">=1.2.0", "2.0.0")
+              == std::optional<std::string>(">=2.0.0"));
+    cf_assert(!retarget_version_constraint(">=1.0.0,<2.0.0", "1.3.0").has_value());
+    cf_assert(!retarget_version_constraint("1.*", "1.3.0").has_value());
+    cf_assert(!retarget_version_constraint("*", "1.3.0").has_value());
+    return 0;
+}
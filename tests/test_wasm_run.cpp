@@ -0,0 +1,68 @@
+/**
+ * @file test_wasm_run.cpp
+ * @brief Unit tests for running Emscripten builds with `cforge run`
+ */
+
+#include "test_framework.h"
+#include "core/wasm_run.hpp"
+
+#include <filesystem>
+#include <fstream>
+#include <string>
+#include <vector>
+
+using namespace cforge;
+
+namespace {
+
+std::filesystem::path make_build_dir(const std::vector<std::string> &files) {
+    std::filesystem::path dir = std::filesystem::temp_directory_path() / "cforge_test_wasm_run";
+    std::filesystem::remove_all(dir);
+    std::filesystem::create_directories(dir);
+    for (const auto &file : files) {
+        std::ofstream(dir / file) << "x";
+    }
+    return dir;
+}
+
+}  // namespace
+
+TEST(WasmRun, RecognizesLoaderNextToModule) {
+    std::filesystem::path dir = make_build_dir({"app.js", "app.wasm", "helper.js", "page.html"});
+
+    cf_assert(is_wasm_executable(dir / "app.js"));
+    cf_assert(!is_wasm_executable(dir / "app.wasm"));
+    cf_assert(!is_wasm_executable(dir / "helper.js"));
+    cf_assert(!is_wasm_executable(dir / "page.html"));
+    cf_assert(!is_wasm_executable(dir / "missing.js"));
+
+    std::filesystem::remove_all(dir);
+    return 0;
+}
+
+TEST(WasmRun, PicksEntryPointForNodeAndBrowser) {
+    std::filesystem::path dir = make_build_dir({"app.html", "app.js", "app.wasm"});
+
+    cf_assert(wasm_entry_point(dir / "app.html", false) == dir / "app.js");
+    cf_assert(wasm_entry_point(dir / "app.wasm", true) == dir / "app.html");
+    cf_assert(wasm_entry_point(dir / "app.js", true) == dir / "app.html");
+
+    std::filesystem::remove(dir / "app.html");
+    cf_assert(wasm_entry_point(dir / "app.js", true).empty());
+
+    std::filesystem::remove_all(dir);
+    return 0;
+}
+
+TEST(WasmRun, RunCommandPassesArguments) {
+    std::vector<std::string> node    = wasm_run_command("out/app.js", false, {"--size", "3"});
+    std::vector<std::string> browser = wasm_run_command("out/app.html", true, {});
+
+    cf_assert_eq(node.size(), static_cast<size_t>(4));
+    cf_assert_eq(node[0], std::string("node"));
+    cf_assert_eq(node[1], std::filesystem::path("out/app.js").string());
+    cf_assert_eq(node[3], std::string("3"));
+    cf_assert_eq(browser.size(), static_cast<size_t>(2));
+    cf_assert_eq(browser[0], std::string("emrun"));
+    return 0;
+}
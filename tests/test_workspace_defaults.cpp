@@ -0,0 +1,146 @@
+/**
+ * @file test_workspace_defaults.cpp
+ * @brief Unit tests for merging workspace [defaults] into project configurations
+ */
+
+#include "test_framework.h"
+#include "core/workspace_defaults.hpp"
+#include "core/workspace_members.hpp"
+
+#include <cstdlib>
+#include <filesystem>
+#include <fstream>
+#include <string>
+#include <vector>
+
+namespace fs = std::filesystem;
+using namespace cforge;
+
+TEST(WorkspaceDefaults, NestedTablesMergeKeyByKey) {
+    toml::table defaults = toml::parse(R"(
+[build]
+build_type = "Debug"
+
+[build.config.debug]
+defines = ["DEBUG=1"]
+optimize = "none"
+
+[build.config.release]
+optimize = "speed"
+)");
+    toml::table project = toml::parse(R"(
+[project]
+name = "app"
+
+[build.config.debug]
+optimize = "debug"
+)");
+
+    std::vector<std::string> inherited = merge_workspace_defaults(project, defaults);
+
+    cf_assert_eq(project["build"]["build_type"].value_or(std::string()), std::string("Debug"));
+    cf_assert_eq(project["build"]["config"]["debug"]["optimize"].value_or(std::string()),
+                 std::string("debug"));
+    cf_assert(project["build"]["config"]["debug"]["defines"].is_array());
+    cf_assert_eq(project["build"]["config"]["release"]["optimize"].value_or(std::string()),
+                 std::string("speed"));
+
+    cf_assert_eq(inherited.size(), static_cast<size_t>(3));
+    cf_assert_eq(inherited[0], std::string("build.build_type"));
+    cf_assert_eq(inherited[1], std::string("build.config.debug.defines"));
+    cf_assert_eq(inherited[2], std::string("build.config.release"));
+    return 0;
+}
+
+TEST(WorkspaceDefaults, ListsReplaceUnlessAnnotated) {
+    toml::table defaults = toml::parse(R"(
+[dependencies.vcpkg]
+packages = { merge = "append", value = ["fmt", "spdlog"] }
+
+[build]
+defines = { merge = "replace", value = ["SHARED=1"] }
+)");
+    toml::table project = toml::parse(R"(
+[build]
+defines = ["OWN=1"]
+
+[dependencies.vcpkg]
+packages = ["spdlog", "catch2"]
+)");
+
+    std::vector<std::string> inherited = merge_workspace_defaults(project, defaults);
+
+    const toml::array *defines = project["build"]["defines"].as_array();
+    cf_assert(defines != nullptr);
+    cf_assert_eq(defines->size(), static_cast<size_t>(1));
+    cf_assert_eq((*defines)[0].value_or(std::string()), std::string("OWN=1"));
+
+    const toml::array *packages = project["dependencies"]["vcpkg"]["packages"].as_array();
+    cf_assert(packages != nullptr);
+    cf_assert_eq(packages->size(), static_cast<size_t>(3));
+    cf_assert_eq((*packages)[0].value_or(std::string()), std::string("fmt"));
+    cf_assert_eq((*packages)[1].value_or(std::string()), std::string("spdlog"));
+    cf_assert_eq((*packages)[2].value_or(std::string()), std::string("catch2"));
+
+    cf_assert_eq(inherited.size(), static_cast<size_t>(1));
+    cf_assert_eq(inherited[0], std::string("dependencies.vcpkg.packages (appended)"));
+    return 0;
+}
+
+TEST(WorkspaceDefaults, ProjectNameIsNotInherited) {
+    toml::table defaults = toml::parse(R"(
+[project]
+name = "shared"
+cpp_standard = "20"
+)");
+    toml::table project = toml::parse(R"(
+[project]
+version = "1.0.0"
+)");
+
+    std::vector<std::string> inherited = merge_workspace_defaults(project, defaults);
+
+    cf_assert(!project["project"]["name"]);
+    cf_assert_eq(project["project"]["cpp_standard"].value_or(std::string()), std::string("20"));
+    cf_assert_eq(inherited.size(), static_cast<size_t>(1));
+    cf_assert_eq(inherited[0], std::string("project.cpp_standard"));
+    return 0;
+}
+
+TEST(WorkspaceDefaults, OnlyListedProjectsAreMembers) {
+    fs::path workspace = fs::temp_directory_path()
+                       / ("cforge_ws_defaults_" + std::to_string(std::rand()));
+    for (const auto &dir : {"app", "libs/core", "app/deps/x", "tools"}) {
+        fs::create_directories(workspace / dir);
+        std::ofstream(workspace / dir / "cforge.toml") << "[project]\nname = \"p\"\n";
+    }
+    toml::table config = toml::parse(R"(
+[workspace]
+name = "ws"
+members = ["app"]
+
+[[workspace.projects]]
+name = "core"
+path = "libs/core"
+)");
+
+    std::vector<std::string> members = workspace_member_paths(config);
+    bool app    = is_workspace_member_dir(workspace, members, workspace / "app");
+    bool core   = is_workspace_member_dir(workspace, members, workspace / "libs" / "core");
+    bool dep    = is_workspace_member_dir(workspace, members, workspace / "app" / "deps" / "x");
+    bool tools  = is_workspace_member_dir(workspace, members, workspace / "tools");
+    bool legacy = is_workspace_member_dir(
+        workspace, workspace_member_paths(toml::parse(R"(
+[workspace]
+projects = ["core:libs/core:true"]
+)")),
+        workspace / "libs" / "core");
+    fs::remove_all(workspace);
+
+    cf_assert(app);
+    cf_assert(core);
+    cf_assert(!dep);
+    cf_assert(!tools);
+    cf_assert(legacy);
+    return 0;
+}
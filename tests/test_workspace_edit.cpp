@@ -0,0 +1,102 @@
+/**
+ * @file test_workspace_edit.cpp
+ * @brief Unit tests for editing a workspace manifest
+ */
+
+#include "test_framework.h"
+#include "core/workspace_edit.hpp"
+
+using namespace cforge;
+
+TEST(WorkspaceEdit, RemovesProjectTable) {
+    std::string content = "[workspace]\n"
+                          "name = \"ws\"\n"
+                          "main_project = \"gui\"\n"
+                          "\n"
+                          "[[workspace.project]]\n"
+                          "name = \"core\"\n"
+                          "path = \"core\"\n"
+                          "\n"
+                          "[[workspace.project]]\n"
+                          "name = \"gui\"\n"
+                          "path = \"gui\"\n"
+                          "startup = true\n"
+                          "\n"
+                          "# Shared settings\n"
+                          "[build]\n"
+                          "build_type = \"Debug\"\n";
+    cf_assert(remove_workspace_project_entries(content, "gui", "gui"));
+    cf_assert_eq(content,
+                 std::string("[workspace]\n"
+                             "name = \"ws\"\n"
+                             "\n"
+                             "[[workspace.project]]\n"
+                             "name = \"core\"\n"
+                             "path = \"core\"\n"
+                             "\n"
+                             "# Shared settings\n"
+                             "[build]\n"
+                             "build_type = \"Debug\"\n"));
+    cf_assert(!remove_workspace_project_entries(content, "gui", "gui"));
+    return 0;
+}
+
+TEST(WorkspaceEdit, RemovesArrayEntries) {
+    std::string content = "[workspace]\n"
+                          "projects = [\"core\", \"gui\", \"tools\"]\n"
+                          "startup_projects = [\"gui\"]\n"
+                          "default_startup_project = \"gui\"\n"
+                          "members = [\n"
+                          "  \"libs/core\",\n"
+                          "  \"./apps/gui/\",\n"
+                          "]\n";
+    cf_assert(remove_workspace_project_entries(content, "gui", "apps/gui"));
+    cf_assert_eq(content,
+                 std::string("[workspace]\n"
+                             "projects = [\"core\", \"tools\"]\n"
+                             "startup_projects = []\n"
+                             "members = [\n"
+                             "  \"libs/core\",\n"
+                             "]\n"));
+    return 0;
+}
+
+TEST(WorkspaceEdit, AddsDefaultStartupProject) {
+    std::string content = "[workspace]\n"
+                          "name = \"ws\"\n"
+                          "members = [\n"
+                          "  \"core\",\n"
+                          "  \"gui\",\n"
+                          "]\n"
+                          "\n"
+                          "[build]\n"
+                          "build_type = \"Debug\"\n";
+    cf_assert(set_default_startup_project(content, "gui"));
+    cf_assert_eq(content,
+                 std::string("[workspace]\n"
+                             "name = \"ws\"\n"
+                             "members = [\n"
+                             "  \"core\",\n"
+                             "  \"gui\",\n"
+                             "]\n"
+                             "default_startup_project = \"gui\"\n"
+                             "\n"
+                             "[build]\n"
+                             "build_type = \"Debug\"\n"));
+    return 0;
+}
+
+TEST(WorkspaceEdit, ReplacesDefaultStartupProject) {
+    std::string content = "[workspace]\n"
+                          "default_startup_project = \"gui\"  # the app\n"
+                          "name = \"ws\"\n";
+    cf_assert(set_default_startup_project(content, "tools"));
+    cf_assert_eq(content,
+                 std::string("[workspace]\n"
+                             "default_startup_project = \"tools\"\n"
+                             "name = \"ws\"\n"));
+
+    std::string project = "[project]\nname = \"app\"\n";
+    cf_assert(!set_default_startup_project(project, "app"));
+    return 0;
+}
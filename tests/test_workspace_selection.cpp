@@ -0,0 +1,86 @@
+/**
+ * @file test_workspace_selection.cpp
+ * @brief Unit tests for selecting workspace projects by name
+ */
+
+#include "test_framework.h"
+#include "core/workspace_selection.hpp"
+
+#include <map>
+#include <string>
+#include <vector>
+
+using namespace cforge;
+
+namespace {
+
+// core <- net <- app, core <- experimental, tools stands alone
+const std::vector<std::string> order = {"core", "net", "app", "experimental", "tools"};
+const std::map<std::string, std::vector<std::string>> deps = {
+    {"net",          {"core"}       },
+    {"app",          {"net", "core"}},
+    {"experimental", {"core"}       },
+};
+
+}  // namespace
+
+TEST(WorkspaceSelection, GlobPatterns) {
+    cf_assert(project_pattern_matches("app", "app"));
+    cf_assert(!project_pattern_matches("app", "apps"));
+    cf_assert(project_pattern_matches("lib*", "libcore"));
+    cf_assert(project_pattern_matches("*-test", "net-test"));
+    cf_assert(project_pattern_matches("n?t", "net"));
+    cf_assert(project_pattern_matches("*", "anything"));
+    cf_assert(!project_pattern_matches("lib*x", "libcore"));
+    return 0;
+}
+
+TEST(WorkspaceSelection, OnlyPullsInDependencies) {
+    auto selection = select_workspace_projects(order, deps, {"app"}, {}, true);
+
+    cf_assert_eq(selection.projects.size(), static_cast<size_t>(3));
+    cf_assert_eq(selection.projects[0], std::string("core"));
+    cf_assert_eq(selection.projects[1], std::string("net"));
+    cf_assert_eq(selection.projects[2], std::string("app"));
+    cf_assert(selection.required_excluded.empty());
+    return 0;
+}
+
+TEST(WorkspaceSelection, ExcludedDependencyIsReported) {
+    auto selection = select_workspace_projects(order, deps, {}, {"core", "tools"}, true);
+
+    cf_assert_eq(selection.projects.size(), static_cast<size_t>(4));
+    cf_assert_eq(selection.projects[0], std::string("core"));
+    cf_assert_eq(selection.required_excluded.size(), static_cast<size_t>(1));
+    cf_assert_eq(selection.required_excluded[0].project, std::string("core"));
+    return 0;
+}
+
+TEST(WorkspaceSelection, FilterWithoutDependencies) {
+    auto selection = select_workspace_projects(order, deps, {"app", "t*", "missing"}, {}, false);
+
+    cf_assert_eq(selection.projects.size(), static_cast<size_t>(2));
+    cf_assert_eq(selection.projects[0], std::string("app"));
+    cf_assert_eq(selection.projects[1], std::string("tools"));
+    cf_assert_eq(selection.unmatched.size(), static_cast<size_t>(1));
+    cf_assert_eq(selection.unmatched[0], std::string("missing"));
+    return 0;
+}
+
+TEST(WorkspaceSelection, WavesFollowDependencies) {
+    auto waves = workspace_project_waves(order, deps);
+
+    cf_assert_eq(waves.size(), static_cast<size_t>(3));
+    cf_assert_eq(waves[0].size(), static_cast<size_t>(2));
+    cf_assert_eq(waves[0][0], std::string("core"));
+    cf_assert_eq(waves[0][1], std::string("tools"));
+    cf_assert_eq(waves[1][0], std::string("net"));
+    cf_assert_eq(waves[1][1], std::string("experimental"));
+    cf_assert_eq(waves[2][0], std::string("app"));
+
+    // Dependencies that were not selected don't hold a project back
+    auto partial = workspace_project_waves({"net", "app"}, deps);
+    cf_assert_eq(partial.size(), static_cast<size_t>(2));
+    cf_assert_eq(partial[0][0], std::string("net"));
+    return 0;
+}